@@ -0,0 +1,52 @@
+//! Implementations of `nalgebra::LinearOperator` for sparse matrices.
+
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+
+use crate::ops::serial::{spmm_csc_dense, spmm_csr_dense};
+use crate::ops::Op;
+use nalgebra::{ComplexField, DMatrix, LinearOperator};
+
+macro_rules! impl_linear_operator {
+    ($matrix_type:ident, $spmm_fn:ident) => {
+        impl<T: ComplexField> LinearOperator<T> for $matrix_type<T> {
+            fn nrows(&self) -> usize {
+                $matrix_type::nrows(self)
+            }
+
+            fn ncols(&self) -> usize {
+                $matrix_type::ncols(self)
+            }
+
+            fn apply(&self, x: &DMatrix<T>) -> DMatrix<T> {
+                let mut result = DMatrix::zeros($matrix_type::nrows(self), x.ncols());
+                $spmm_fn(
+                    T::zero(),
+                    &mut result,
+                    T::one(),
+                    Op::NoOp(self),
+                    Op::NoOp(x),
+                );
+                result
+            }
+
+            fn apply_adjoint(&self, x: &DMatrix<T>) -> DMatrix<T> {
+                // The kernels only support transposition, so use `Aᴴ x = conj(Aᵀ conj(x))`.
+                let mut result = DMatrix::zeros($matrix_type::ncols(self), x.ncols());
+                let x_conj = x.conjugate();
+                $spmm_fn(
+                    T::zero(),
+                    &mut result,
+                    T::one(),
+                    Op::Transpose(self),
+                    Op::NoOp(&x_conj),
+                );
+                result.conjugate_mut();
+                result
+            }
+        }
+    };
+}
+
+impl_linear_operator!(CsrMatrix, spmm_csr_dense);
+impl_linear_operator!(CscMatrix, spmm_csc_dense);
@@ -17,6 +17,24 @@
 //! either be used as-is or transposed. The notation `op(A)` is represented in code by the
 //! [`Op`] enum.
 //!
+//! # Matrix-free algorithms
+//!
+//! [`CsrMatrix`](crate::csr::CsrMatrix) and [`CscMatrix`](crate::csc::CscMatrix) implement
+//! `nalgebra::LinearOperator`, so they can be used by the algorithms of `nalgebra` which only need
+//! products of an operator with dense matrices, without being converted to dense matrices:
+//!
+//! ```
+//! # use nalgebra_sparse::csr::CsrMatrix;
+//! use nalgebra::{DMatrix, DominantEigen, LinearOperator};
+//!
+//! let a = CsrMatrix::<f64>::identity(100);
+//! let x = DMatrix::from_element(100, 2, 1.0);
+//! assert_eq!(a.apply(&x), x);
+//!
+//! let eigen = DominantEigen::try_new_with_operator(&a, 3, 1.0e-10, 100).unwrap();
+//! assert!(eigen.eigenvalues.iter().all(|e| (e - 1.0).abs() < 1.0e-10));
+//! ```
+//!
 //! # Available `std::ops` implementations
 //!
 //! ## Binary operators
@@ -125,6 +143,7 @@
 //! always be verified by performance profiling!
 
 mod impl_std_ops;
+mod linear_operator;
 pub mod serial;
 
 /// Determines whether a matrix should be transposed in a given operation.
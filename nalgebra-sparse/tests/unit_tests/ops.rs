@@ -13,7 +13,7 @@ use nalgebra_sparse::pattern::SparsityPattern;
 use nalgebra_sparse::proptest::{csc, csr, sparsity_pattern};
 
 use nalgebra::proptest::{matrix, vector};
-use nalgebra::{DMatrix, DMatrixSlice, DMatrixSliceMut, Scalar};
+use nalgebra::{DMatrix, DMatrixSlice, DMatrixSliceMut, LinearOperator, Scalar};
 
 use proptest::prelude::*;
 
@@ -1194,6 +1194,48 @@ proptest! {
         prop_assert_eq!(a.clone() * b.clone(), expected.clone());
     }
 
+    #[test]
+    fn csr_linear_operator(
+        // b and c have dimensions compatible for multiplication with a and its adjoint
+        (a, b, c)
+        in csr_strategy()
+            .prop_flat_map(|a| {
+                let b = matrix(PROPTEST_I32_VALUE_STRATEGY, a.ncols(), PROPTEST_MATRIX_DIM);
+                let c = matrix(PROPTEST_I32_VALUE_STRATEGY, a.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(a), b, c)
+            }))
+    {
+        // The values are small integers, so the floating-point products are exact.
+        let dense = DMatrix::from(&a).map(|x| x as f64);
+        let a = CsrMatrix::from(&dense);
+        let b = b.map(|x| x as f64);
+        let c = c.map(|x| x as f64);
+        prop_assert_eq!(LinearOperator::<f64>::shape(&a), (a.nrows(), a.ncols()));
+        prop_assert_eq!(a.apply(&b), &dense * &b);
+        prop_assert_eq!(a.apply_adjoint(&c), dense.tr_mul(&c));
+    }
+
+    #[test]
+    fn csc_linear_operator(
+        // b and c have dimensions compatible for multiplication with a and its adjoint
+        (a, b, c)
+        in csc_strategy()
+            .prop_flat_map(|a| {
+                let b = matrix(PROPTEST_I32_VALUE_STRATEGY, a.ncols(), PROPTEST_MATRIX_DIM);
+                let c = matrix(PROPTEST_I32_VALUE_STRATEGY, a.nrows(), PROPTEST_MATRIX_DIM);
+                (Just(a), b, c)
+            }))
+    {
+        // The values are small integers, so the floating-point products are exact.
+        let dense = DMatrix::from(&a).map(|x| x as f64);
+        let a = CscMatrix::from(&dense);
+        let b = b.map(|x| x as f64);
+        let c = c.map(|x| x as f64);
+        prop_assert_eq!(LinearOperator::<f64>::shape(&a), (a.nrows(), a.ncols()));
+        prop_assert_eq!(a.apply(&b), &dense * &b);
+        prop_assert_eq!(a.apply_adjoint(&c), dense.tr_mul(&c));
+    }
+
     #[test]
     fn csc_solve_lower_triangular_no_transpose(
         // A CSC matrix `a` and a dimensionally compatible dense matrix `b`
@@ -1,6 +1,8 @@
-#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::base::array_vec_storage::ArrayVecStorage;
 use crate::base::dimension::Dynamic;
 use crate::base::dimension::{U1, U2, U3, U4, U5, U6};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::row_major_storage::RowMajorVecStorage;
 use crate::base::storage::Owned;
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::base::vec_storage::VecStorage;
@@ -50,6 +52,23 @@ pub type SMatrix<T, const R: usize, const C: usize> =
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub type DMatrix<T> = Matrix<T, Dynamic, Dynamic, VecStorage<T, Dynamic, Dynamic>>;
 
+/// A dynamically sized row-major matrix.
+///
+/// It supports the same operations as `DMatrix`, but operations returning a new matrix return a
+/// column-major matrix. See `DMatrix::transpose_into_row_major` and
+/// `DMatrixRowMajor::transpose_into_column_major` to switch between both layouts without copy.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub type DMatrixRowMajor<T> = Matrix<T, Dynamic, Dynamic, RowMajorVecStorage<T, Dynamic, Dynamic>>;
+
+/// A dynamically sized column-major matrix with at most `CAP` components, stored without any
+/// heap allocation.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
+pub type DMatrixArrayVec<T, const CAP: usize> =
+    Matrix<T, Dynamic, Dynamic, ArrayVecStorage<T, Dynamic, Dynamic, CAP>>;
+
 /// A heap-allocated, column-major, matrix with a dynamic number of rows and 1 columns.
 ///
 /// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
@@ -278,6 +297,11 @@ pub type Matrix6x5<T> = Matrix<T, U6, U5, ArrayStorage<T, 6, 5>>;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub type DVector<T> = Matrix<T, Dynamic, U1, VecStorage<T, Dynamic, U1>>;
 
+/// A dynamically sized column vector with at most `CAP` components, stored without any heap
+/// allocation.
+pub type DVectorArrayVec<T, const CAP: usize> =
+    Matrix<T, Dynamic, U1, ArrayVecStorage<T, Dynamic, U1, CAP>>;
+
 /// An owned D-dimensional column vector.
 pub type OVector<T, D> = Matrix<T, D, U1, Owned<T, D, U1>>;
 /// A statically sized D-dimensional column vector.
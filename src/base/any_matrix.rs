@@ -0,0 +1,161 @@
+use crate::base::dimension::{Dim, Dynamic};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{DMatrixSlice, DMatrixSliceMut, Matrix};
+
+/// Any matrix with components of type `T`, whatever its dimensions and storage.
+///
+/// This is implemented by all matrices, owned or slices, statically or dynamically sized, and by
+/// references to them. Generic code can thus accept any matrix with a single `M: AnyMatrix<T>`
+/// bound instead of the dimension, storage, and allocator bounds required by `Matrix<T, R, C, S>`.
+/// The matrix can then be viewed as a `DMatrixSlice` with `.as_dynamic_slice()`, which supports
+/// most of the methods of matrices without further bounds.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{AnyMatrix, DMatrix, Matrix2, Matrix2x3};
+/// fn trace_of_product(a: impl AnyMatrix<f64>, b: impl AnyMatrix<f64>) -> f64 {
+///     (a.as_dynamic_slice() * b.as_dynamic_slice()).trace()
+/// }
+///
+/// let a = Matrix2x3::new(1.0, 2.0, 3.0,
+///                        4.0, 5.0, 6.0);
+/// let b = DMatrix::from_row_slice(3, 2, &[1.0, 0.0,
+///                                         0.0, 1.0,
+///                                         1.0, 1.0]);
+///
+/// assert_eq!(trace_of_product(&a, &b), 15.0);
+/// assert_eq!(trace_of_product(a.columns(0, 2), Matrix2::identity()), 6.0);
+/// ```
+pub trait AnyMatrix<T> {
+    /// The type of the number of rows of this matrix.
+    type R: Dim;
+    /// The type of the number of columns of this matrix.
+    type C: Dim;
+    /// The type of the storage of this matrix.
+    type S: RawStorage<T, Self::R, Self::C>;
+
+    /// This matrix with its actual type.
+    #[must_use]
+    fn as_matrix(&self) -> &Matrix<T, Self::R, Self::C, Self::S>;
+
+    /// The number of rows and columns of this matrix.
+    #[inline]
+    #[must_use]
+    fn shape(&self) -> (usize, usize) {
+        self.as_matrix().shape()
+    }
+
+    /// The number of rows of this matrix.
+    #[inline]
+    #[must_use]
+    fn nrows(&self) -> usize {
+        self.as_matrix().nrows()
+    }
+
+    /// The number of columns of this matrix.
+    #[inline]
+    #[must_use]
+    fn ncols(&self) -> usize {
+        self.as_matrix().ncols()
+    }
+
+    /// A slice of this whole matrix, with dynamic dimensions and strides.
+    #[inline]
+    #[must_use]
+    fn as_dynamic_slice(&self) -> DMatrixSlice<'_, T, Dynamic, Dynamic> {
+        self.as_matrix()
+            .slice_with_steps((0, 0), self.shape(), (0, 0))
+    }
+}
+
+/// Any mutable matrix with components of type `T`, whatever its dimensions and storage.
+///
+/// See `AnyMatrix` for details.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{AnyMatrixMut, DMatrix, Matrix2x3};
+/// fn normalize_columns(mut m: impl AnyMatrixMut<f64>) {
+///     for mut column in m.as_dynamic_slice_mut().column_iter_mut() {
+///         column.normalize_mut();
+///     }
+/// }
+///
+/// let mut m = Matrix2x3::new(3.0, 0.0, 1.0,
+///                            4.0, 2.0, 0.0);
+/// normalize_columns(&mut m);
+/// assert_eq!(m, Matrix2x3::new(0.6, 0.0, 1.0,
+///                              0.8, 1.0, 0.0));
+///
+/// let mut d = DMatrix::from_element(3, 3, 2.0);
+/// normalize_columns(d.columns_mut(1, 2));
+/// assert_eq!(d[(0, 0)], 2.0);
+/// assert_eq!(d[(0, 1)], 1.0 / 3.0f64.sqrt());
+/// ```
+pub trait AnyMatrixMut<T>: AnyMatrix<T> {
+    /// This matrix with its actual type.
+    #[must_use]
+    fn as_matrix_mut(&mut self) -> &mut Matrix<T, Self::R, Self::C, Self::S>;
+
+    /// A mutable slice of this whole matrix, with dynamic dimensions and strides.
+    #[must_use]
+    fn as_dynamic_slice_mut(&mut self) -> DMatrixSliceMut<'_, T, Dynamic, Dynamic>;
+}
+
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> AnyMatrix<T> for Matrix<T, R, C, S> {
+    type R = R;
+    type C = C;
+    type S = S;
+
+    #[inline]
+    fn as_matrix(&self) -> &Self {
+        self
+    }
+}
+
+impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> AnyMatrixMut<T> for Matrix<T, R, C, S> {
+    #[inline]
+    fn as_matrix_mut(&mut self) -> &mut Self {
+        self
+    }
+
+    #[inline]
+    fn as_dynamic_slice_mut(&mut self) -> DMatrixSliceMut<'_, T, Dynamic, Dynamic> {
+        let shape = self.shape();
+        self.slice_with_steps_mut((0, 0), shape, (0, 0))
+    }
+}
+
+impl<T, M: AnyMatrix<T> + ?Sized> AnyMatrix<T> for &M {
+    type R = M::R;
+    type C = M::C;
+    type S = M::S;
+
+    #[inline]
+    fn as_matrix(&self) -> &Matrix<T, M::R, M::C, M::S> {
+        (**self).as_matrix()
+    }
+}
+
+impl<T, M: AnyMatrix<T> + ?Sized> AnyMatrix<T> for &mut M {
+    type R = M::R;
+    type C = M::C;
+    type S = M::S;
+
+    #[inline]
+    fn as_matrix(&self) -> &Matrix<T, M::R, M::C, M::S> {
+        (**self).as_matrix()
+    }
+}
+
+impl<T, M: AnyMatrixMut<T> + ?Sized> AnyMatrixMut<T> for &mut M {
+    #[inline]
+    fn as_matrix_mut(&mut self) -> &mut Matrix<T, M::R, M::C, M::S> {
+        (**self).as_matrix_mut()
+    }
+
+    #[inline]
+    fn as_dynamic_slice_mut(&mut self) -> DMatrixSliceMut<'_, T, Dynamic, Dynamic> {
+        (**self).as_dynamic_slice_mut()
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+
+use crate::base::allocator::Allocator;
+use crate::base::default_allocator::DefaultAllocator;
+use crate::base::dimension::{Const, Dim, Dynamic, U1};
+use crate::base::storage::{IsContiguous, Owned, RawStorage, RawStorageMut, Storage};
+use num::Zero;
+
+use crate::base::{ArrayStorage, DMatrixArrayVec, DVectorArrayVec, Matrix, Scalar};
+
+/*
+ *
+ * RawStorage.
+ *
+ */
+/// An array-based matrix data storage with a capacity of `CAP` components known at
+/// compile-time, but a number of rows and columns known at run-time.
+///
+/// This allows dynamically-sized matrices, up to a maximum size, without any heap allocation.
+/// All the methods working in-place or writing their result to an existing matrix, e.g.,
+/// `gemm`, `gemv`, `solve_lower_triangular_mut`, norms, or slicing, work on matrices with this
+/// storage even in `no_std` environments without the `alloc` feature. Operations returning a
+/// new dynamically-sized matrix, e.g., products or decompositions, return a heap-allocated
+/// `DMatrix` and thus require the `alloc` or `std` features.
+///
+/// The components are stored in column-major order, and the components past the first
+/// `nrows × ncols` ones are ignored.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ArrayVecStorage<T, R: Dim, C: Dim, const CAP: usize> {
+    data: ArrayStorage<T, CAP, 1>,
+    nrows: R,
+    ncols: C,
+}
+
+/// Checks that a `nrows × ncols` matrix fits in `CAP` components, without overflowing.
+#[inline]
+fn fits_capacity<const CAP: usize>(nrows: usize, ncols: usize) -> bool {
+    matches!(nrows.checked_mul(ncols), Some(len) if len <= CAP)
+}
+
+impl<T: Scalar, R: Dim, C: Dim, const CAP: usize> ArrayVecStorage<T, R, C, CAP> {
+    /// Creates a new array-based matrix data storage of the given shape, with all its
+    /// components set to `elem`.
+    ///
+    /// # Panics
+    /// Panics if `nrows × ncols` exceeds the capacity `CAP`.
+    #[inline]
+    pub fn from_element(nrows: R, ncols: C, elem: T) -> Self {
+        assert!(
+            fits_capacity::<CAP>(nrows.value(), ncols.value()),
+            "Data storage capacity exceeded."
+        );
+        Self {
+            data: Matrix::from_element_generic(Const::<CAP>, Const::<1>, elem).data,
+            nrows,
+            ncols,
+        }
+    }
+}
+
+impl<T, R: Dim, C: Dim, const CAP: usize> ArrayVecStorage<T, R, C, CAP> {
+    /// The maximum number of components this storage can hold.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// The number of components of the matrix stored in this storage.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nrows.value() * self.ncols.value()
+    }
+
+    /// Returns true if the matrix stored in this storage contains no component.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A slice containing all the components of the matrix stored in this storage in
+    /// column-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data.as_slice()[..self.len()]
+    }
+
+    /// A mutable slice containing all the components of the matrix stored in this storage in
+    /// column-major order.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        &mut self.data.as_mut_slice()[..len]
+    }
+
+    /// Changes the shape of the matrix stored in this storage without moving any component.
+    ///
+    /// The components are reinterpreted in column-major order: components that were out of
+    /// the previous shape keep their previous value, which may be any value previously stored.
+    ///
+    /// # Panics
+    /// Panics if `nrows × ncols` exceeds the capacity `CAP`.
+    #[inline]
+    pub fn set_shape(&mut self, nrows: R, ncols: C) {
+        assert!(
+            fits_capacity::<CAP>(nrows.value(), ncols.value()),
+            "Data storage capacity exceeded."
+        );
+        self.nrows = nrows;
+        self.ncols = ncols;
+    }
+}
+
+impl<T: fmt::Debug, R: Dim, C: Dim, const CAP: usize> fmt::Debug for ArrayVecStorage<T, R, C, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayVecStorage")
+            .field("data", &self.as_slice())
+            .field("nrows", &self.nrows)
+            .field("ncols", &self.ncols)
+            .finish()
+    }
+}
+
+impl<T: PartialEq, R: Dim, C: Dim, const CAP: usize> PartialEq for ArrayVecStorage<T, R, C, CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape() && self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, R: Dim, C: Dim, const CAP: usize> Eq for ArrayVecStorage<T, R, C, CAP> {}
+
+unsafe impl<T, R: Dim, C: Dim, const CAP: usize> RawStorage<T, R, C>
+    for ArrayVecStorage<T, R, C, CAP>
+{
+    type RStride = U1;
+    type CStride = R;
+
+    #[inline]
+    fn ptr(&self) -> *const T {
+        self.data.ptr()
+    }
+
+    #[inline]
+    fn shape(&self) -> (R, C) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    fn strides(&self) -> (Self::RStride, Self::CStride) {
+        (Const::<1>, self.nrows)
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    unsafe fn as_slice_unchecked(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+unsafe impl<T: Scalar, R: Dim, C: Dim, const CAP: usize> Storage<T, R, C>
+    for ArrayVecStorage<T, R, C, CAP>
+{
+    #[inline]
+    fn into_owned(self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        self.clone_owned()
+    }
+
+    #[inline]
+    fn clone_owned(&self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let it = self.as_slice().iter().cloned();
+        DefaultAllocator::allocate_from_iterator(self.nrows, self.ncols, it)
+    }
+}
+
+unsafe impl<T, R: Dim, C: Dim, const CAP: usize> RawStorageMut<T, R, C>
+    for ArrayVecStorage<T, R, C, CAP>
+{
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut T {
+        self.data.ptr_mut()
+    }
+
+    #[inline]
+    unsafe fn as_mut_slice_unchecked(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+unsafe impl<T, R: Dim, C: Dim, const CAP: usize> IsContiguous for ArrayVecStorage<T, R, C, CAP> {}
+
+/// # Matrices with a fixed capacity
+impl<T: Scalar, const CAP: usize> DMatrixArrayVec<T, CAP> {
+    /// Creates a matrix with a capacity of `CAP` components and all its components set to
+    /// `elem`.
+    ///
+    /// # Panics
+    /// Panics if `nrows × ncols` exceeds the capacity `CAP`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrixArrayVec, Matrix2x3};
+    /// let mut m = DMatrixArrayVec::<f64, 16>::from_element_array_vec(2, 3, 1.0);
+    /// m[(1, 2)] = 5.0;
+    ///
+    /// assert_eq!(m, Matrix2x3::new(1.0, 1.0, 1.0, 1.0, 1.0, 5.0));
+    /// assert_eq!(m.data.capacity(), 16);
+    /// ```
+    #[inline]
+    pub fn from_element_array_vec(nrows: usize, ncols: usize, elem: T) -> Self {
+        Self::from_data(ArrayVecStorage::from_element(
+            Dynamic::new(nrows),
+            Dynamic::new(ncols),
+            elem,
+        ))
+    }
+
+    /// Creates a matrix with a capacity of `CAP` components and all its components set to zero.
+    ///
+    /// # Panics
+    /// Panics if `nrows × ncols` exceeds the capacity `CAP`.
+    #[inline]
+    pub fn zeros_array_vec(nrows: usize, ncols: usize) -> Self
+    where
+        T: Zero,
+    {
+        Self::from_element_array_vec(nrows, ncols, T::zero())
+    }
+}
+
+impl<T: Scalar, const CAP: usize> DVectorArrayVec<T, CAP> {
+    /// Creates a vector with a capacity of `CAP` components and all its components set to
+    /// `elem`.
+    ///
+    /// # Panics
+    /// Panics if `dim` exceeds the capacity `CAP`.
+    #[inline]
+    pub fn from_element_array_vec(dim: usize, elem: T) -> Self {
+        Self::from_data(ArrayVecStorage::from_element(
+            Dynamic::new(dim),
+            Const::<1>,
+            elem,
+        ))
+    }
+
+    /// Creates a vector with a capacity of `CAP` components and all its components set to zero.
+    ///
+    /// # Panics
+    /// Panics if `dim` exceeds the capacity `CAP`.
+    #[inline]
+    pub fn zeros_array_vec(dim: usize) -> Self
+    where
+        T: Zero,
+    {
+        Self::from_element_array_vec(dim, T::zero())
+    }
+}
+
+impl<T: Scalar + Zero, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Copies this matrix into a new matrix with the same dimensions, stored in an array with
+    /// a capacity of `CAP` components instead of being heap-allocated.
+    ///
+    /// # Panics
+    /// Panics if the number of components of `self` exceeds the capacity `CAP`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrixArrayVec, DMatrixSlice, DVectorArrayVec};
+    /// let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let a: DMatrixArrayVec<f64, 16> = DMatrixSlice::from_slice(&data, 3, 2).to_array_vec();
+    /// let x = DVectorArrayVec::<f64, 4>::from_element_array_vec(2, 1.0);
+    ///
+    /// // Products and solvers writing to an existing matrix do not need any heap allocation.
+    /// let mut y = DVectorArrayVec::<f64, 4>::zeros_array_vec(3);
+    /// y.gemv(1.0, &a, &x, 0.0);
+    ///
+    /// assert_eq!(y.as_slice(), &[5.0, 7.0, 9.0]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_array_vec<const CAP: usize>(&self) -> Matrix<T, R, C, ArrayVecStorage<T, R, C, CAP>> {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::from_data(ArrayVecStorage::from_element(nrows, ncols, T::zero()));
+        res.copy_from(self);
+        res
+    }
+}
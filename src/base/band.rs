@@ -0,0 +1,374 @@
+use num::Zero;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Const, Dim, DimMin, DimMinimum, Dynamic};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{
+    DVectorSlice, DVectorSliceMut, DefaultAllocator, Matrix, OMatrix, Scalar, SliceStorage,
+    SliceStorageMut, VectorSlice, VectorSliceMut,
+};
+
+/// An immutable view of the components of a matrix lying within a band around its diagonal.
+///
+/// The band contains the diagonal, the first `k_lower` subdiagonals, and the first `k_upper`
+/// superdiagonals of the matrix, i.e., the components `(i, j)` such that `i <= j + k_lower` and
+/// `j <= i + k_upper`. See `Matrix::band_view`.
+#[derive(Debug)]
+pub struct MatrixBand<'a, T, R: Dim, C: Dim, S> {
+    matrix: &'a Matrix<T, R, C, S>,
+    k_lower: usize,
+    k_upper: usize,
+}
+
+impl<'a, T, R: Dim, C: Dim, S> Clone for MatrixBand<'a, T, R, C, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S> Copy for MatrixBand<'a, T, R, C, S> {}
+
+/// A mutable view of the components of a matrix lying within a band around its diagonal.
+///
+/// See `MatrixBand` and `Matrix::band_view_mut`.
+#[derive(Debug)]
+pub struct MatrixBandMut<'a, T, R: Dim, C: Dim, S> {
+    matrix: &'a mut Matrix<T, R, C, S>,
+    k_lower: usize,
+    k_upper: usize,
+}
+
+/// # Diagonal, band, and triangle views
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// A view of the diagonal of this matrix, which does not copy its components unlike
+    /// `Matrix::diagonal`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// assert_eq!(m.diagonal_view(), Vector2::new(1, 5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn diagonal_view(&self) -> VectorSlice<'_, T, DimMinimum<R, C>, Dynamic, Dynamic>
+    where
+        R: DimMin<C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let len = nrows.min(ncols);
+        let strides = diagonal_strides(self.strides(), len.value());
+
+        unsafe {
+            let data = SliceStorage::from_raw_parts(self.data.ptr(), (len, Const::<1>), strides);
+            Matrix::from_data_statically_unchecked(data)
+        }
+    }
+
+    /// A view of the band of this matrix containing its diagonal, its first `k_lower`
+    /// subdiagonals, and its first `k_upper` superdiagonals.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3, Vector2};
+    /// let m = Matrix3::new(1, 2, 3,
+    ///                      4, 5, 6,
+    ///                      7, 8, 9);
+    /// let band = m.band_view(1, 0);
+    ///
+    /// assert_eq!(band.get(1, 0), Some(&4));
+    /// assert_eq!(band.get(0, 1), None);
+    /// assert_eq!(band.diagonal(-1), Vector2::new(4, 8));
+    /// assert_eq!(band.iter().sum::<i32>(), 1 + 4 + 5 + 8 + 9);
+    /// assert_eq!(band.clone_owned(), Matrix3::new(1, 0, 0,
+    ///                                             4, 5, 0,
+    ///                                             0, 8, 9));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn band_view(&self, k_lower: usize, k_upper: usize) -> MatrixBand<'_, T, R, C, S> {
+        MatrixBand {
+            matrix: self,
+            k_lower,
+            k_upper,
+        }
+    }
+
+    /// A view of the upper-triangular part of this matrix, including its diagonal.
+    ///
+    /// This does not copy the components of the matrix unlike `Matrix::upper_triangle`.
+    #[inline]
+    #[must_use]
+    pub fn upper_triangle_view(&self) -> MatrixBand<'_, T, R, C, S> {
+        self.band_view(0, self.ncols().saturating_sub(1))
+    }
+
+    /// A view of the lower-triangular part of this matrix, including its diagonal.
+    ///
+    /// This does not copy the components of the matrix unlike `Matrix::lower_triangle`.
+    #[inline]
+    #[must_use]
+    pub fn lower_triangle_view(&self) -> MatrixBand<'_, T, R, C, S> {
+        self.band_view(self.nrows().saturating_sub(1), 0)
+    }
+}
+
+impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// A mutable view of the diagonal of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let mut m = Matrix2::new(1.0, 2.0,
+    ///                          3.0, 4.0);
+    /// m.diagonal_view_mut().add_scalar_mut(10.0);
+    /// assert_eq!(m, Matrix2::new(11.0, 2.0,
+    ///                            3.0, 14.0));
+    /// ```
+    #[inline]
+    pub fn diagonal_view_mut(&mut self) -> VectorSliceMut<'_, T, DimMinimum<R, C>, Dynamic, Dynamic>
+    where
+        R: DimMin<C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let len = nrows.min(ncols);
+        let strides = diagonal_strides(self.strides(), len.value());
+
+        unsafe {
+            let data =
+                SliceStorageMut::from_raw_parts(self.data.ptr_mut(), (len, Const::<1>), strides);
+            Matrix::from_data_statically_unchecked(data)
+        }
+    }
+
+    /// A mutable view of the band of this matrix containing its diagonal, its first `k_lower`
+    /// subdiagonals, and its first `k_upper` superdiagonals.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let mut m = Matrix3::repeat(1);
+    /// // Scale the tridiagonal part of `m`.
+    /// m.band_view_mut(1, 1).apply(|e| *e *= 2);
+    /// assert_eq!(m, Matrix3::new(2, 2, 1,
+    ///                            2, 2, 2,
+    ///                            1, 2, 2));
+    /// ```
+    #[inline]
+    pub fn band_view_mut(
+        &mut self,
+        k_lower: usize,
+        k_upper: usize,
+    ) -> MatrixBandMut<'_, T, R, C, S> {
+        MatrixBandMut {
+            matrix: self,
+            k_lower,
+            k_upper,
+        }
+    }
+
+    /// A mutable view of the upper-triangular part of this matrix, including its diagonal.
+    #[inline]
+    pub fn upper_triangle_view_mut(&mut self) -> MatrixBandMut<'_, T, R, C, S> {
+        let k_upper = self.ncols().saturating_sub(1);
+        self.band_view_mut(0, k_upper)
+    }
+
+    /// A mutable view of the lower-triangular part of this matrix, including its diagonal.
+    #[inline]
+    pub fn lower_triangle_view_mut(&mut self) -> MatrixBandMut<'_, T, R, C, S> {
+        let k_lower = self.nrows().saturating_sub(1);
+        self.band_view_mut(k_lower, 0)
+    }
+}
+
+macro_rules! band_impl(
+    ($Band: ident, $Storage: ident) => {
+        impl<'a, T, R: Dim, C: Dim, S: $Storage<T, R, C>> $Band<'a, T, R, C, S> {
+            /// The number of subdiagonals within this band.
+            #[inline]
+            #[must_use]
+            pub fn k_lower(&self) -> usize {
+                self.k_lower
+            }
+
+            /// The number of superdiagonals within this band.
+            #[inline]
+            #[must_use]
+            pub fn k_upper(&self) -> usize {
+                self.k_upper
+            }
+
+            /// Indicates if the component `(i, j)` of the matrix lies within this band.
+            #[inline]
+            #[must_use]
+            pub fn contains(&self, i: usize, j: usize) -> bool {
+                let (nrows, ncols) = self.matrix.shape();
+                i < nrows
+                    && j < ncols
+                    && i - i.min(j) <= self.k_lower
+                    && j - j.min(i) <= self.k_upper
+            }
+
+            /// A reference to the component `(i, j)` of the matrix, or `None` if it lies outside
+            /// of this band.
+            #[inline]
+            #[must_use]
+            pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+                if self.contains(i, j) {
+                    Some(&self.matrix[(i, j)])
+                } else {
+                    None
+                }
+            }
+
+            /// A view of the `k`-th diagonal of the matrix, i.e., of its components `(i, j)` such
+            /// that `j = i + k`.
+            ///
+            /// # Panics
+            /// Panics if this diagonal lies outside of this band, i.e., if `k < -k_lower` or
+            /// `k > k_upper`.
+            #[inline]
+            #[must_use]
+            pub fn diagonal(&self, k: isize) -> DVectorSlice<'_, T, Dynamic, Dynamic> {
+                let (start, len) =
+                    band_diagonal(self.matrix.shape(), self.k_lower, self.k_upper, k);
+                let strides = diagonal_strides(self.matrix.strides(), len);
+
+                unsafe {
+                    let ptr = self.matrix.data.get_address_unchecked(start.0, start.1);
+                    let shape = (Dynamic::new(len), Const::<1>);
+                    let data = SliceStorage::from_raw_parts(ptr, shape, strides);
+                    Matrix::from_data_statically_unchecked(data)
+                }
+            }
+
+            /// Iterates through the components of the matrix lying within this band, in
+            /// column-major order.
+            #[inline]
+            pub fn iter(&self) -> impl Iterator<Item = &T> {
+                let matrix = &*self.matrix;
+                band_indices(matrix.shape(), self.k_lower, self.k_upper).map(move |ij| &matrix[ij])
+            }
+
+            /// Copies this band into a new matrix, with zeros outside of the band.
+            #[inline]
+            #[must_use]
+            pub fn clone_owned(&self) -> OMatrix<T, R, C>
+            where
+                T: Scalar + Zero,
+                DefaultAllocator: Allocator<T, R, C>,
+            {
+                let (nrows, ncols) = self.matrix.shape_generic();
+                OMatrix::from_fn_generic(nrows, ncols, |i, j| {
+                    self.get(i, j).cloned().unwrap_or_else(T::zero)
+                })
+            }
+        }
+    }
+);
+
+band_impl!(MatrixBand, RawStorage);
+band_impl!(MatrixBandMut, RawStorageMut);
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> MatrixBandMut<'a, T, R, C, S> {
+    /// A mutable reference to the component `(i, j)` of the matrix, or `None` if it lies outside
+    /// of this band.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if self.contains(i, j) {
+            Some(&mut self.matrix[(i, j)])
+        } else {
+            None
+        }
+    }
+
+    /// A mutable view of the `k`-th diagonal of the matrix, i.e., of its components `(i, j)` such
+    /// that `j = i + k`.
+    ///
+    /// # Panics
+    /// Panics if this diagonal lies outside of this band, i.e., if `k < -k_lower` or
+    /// `k > k_upper`.
+    #[inline]
+    pub fn diagonal_mut(&mut self, k: isize) -> DVectorSliceMut<'_, T, Dynamic, Dynamic> {
+        let (start, len) = band_diagonal(self.matrix.shape(), self.k_lower, self.k_upper, k);
+        let strides = diagonal_strides(self.matrix.strides(), len);
+
+        unsafe {
+            let ptr = self.matrix.data.get_address_unchecked_mut(start.0, start.1);
+            let data =
+                SliceStorageMut::from_raw_parts(ptr, (Dynamic::new(len), Const::<1>), strides);
+            Matrix::from_data_statically_unchecked(data)
+        }
+    }
+
+    /// Applies `f` to each component of the matrix lying within this band.
+    #[inline]
+    pub fn apply<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        for ij in band_indices(self.matrix.shape(), self.k_lower, self.k_upper) {
+            f(&mut self.matrix[ij])
+        }
+    }
+
+    /// Sets all the components of the matrix lying within this band to `val`.
+    #[inline]
+    pub fn fill(&mut self, val: T)
+    where
+        T: Clone,
+    {
+        self.apply(|e| *e = val.clone())
+    }
+}
+
+/// The strides of a view of a diagonal with `len` components of a matrix with the given strides.
+fn diagonal_strides((rstride, cstride): (usize, usize), len: usize) -> (Dynamic, Dynamic) {
+    let stride = rstride + cstride;
+    (Dynamic::new(stride), Dynamic::new(stride * len))
+}
+
+/// The first component and the length of the `k`-th diagonal of a matrix with the given shape.
+fn band_diagonal(
+    (nrows, ncols): (usize, usize),
+    k_lower: usize,
+    k_upper: usize,
+    k: isize,
+) -> ((usize, usize), usize) {
+    let offset = k.unsigned_abs();
+    assert!(
+        if k < 0 {
+            offset <= k_lower
+        } else {
+            offset <= k_upper
+        },
+        "Band view: the diagonal lies outside of the band."
+    );
+
+    let (start, len) = if k < 0 {
+        ((offset, 0), ncols.min(nrows.saturating_sub(offset)))
+    } else {
+        ((0, offset), nrows.min(ncols.saturating_sub(offset)))
+    };
+
+    // Don't compute the address of a component out of the matrix bounds.
+    if len == 0 {
+        ((0, 0), 0)
+    } else {
+        (start, len)
+    }
+}
+
+/// Iterates through the indices of the components of a band of a matrix with the given shape,
+/// in column-major order.
+fn band_indices(
+    (nrows, ncols): (usize, usize),
+    k_lower: usize,
+    k_upper: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    (0..ncols).flat_map(move |j| {
+        let end = j.saturating_add(k_lower).saturating_add(1).min(nrows);
+        (j.saturating_sub(k_upper)..end).map(move |i| (i, j))
+    })
+}
@@ -1,6 +1,6 @@
 use crate::{RawStorage, SimdComplexField};
 use num::{One, Zero};
-use simba::scalar::{ClosedAdd, ClosedMul};
+use simba::scalar::{ClosedAdd, ClosedMul, SupersetOf};
 
 use crate::base::allocator::Allocator;
 use crate::base::blas_uninit::{axcpy_uninit, gemm_uninit, gemv_uninit};
@@ -273,6 +273,83 @@ where
 
         res
     }
+
+    /// The trace of `self * rhs`, computed without forming the product.
+    ///
+    /// This is equal to `self.tr_dot(rhs)`, and requires `O(n²)` operations instead of the
+    /// `O(n³)` operations of `(self * rhs).trace()`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3x2};
+    /// let mat1 = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                           4.0, 5.0, 6.0);
+    /// let mat2 = Matrix3x2::new(0.1, 0.4,
+    ///                           0.2, 0.5,
+    ///                           0.3, 0.6);
+    /// assert_eq!(mat1.mul_trace(&mat2), (mat1 * mat2).trace());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_trace<R2: Dim, C2: Dim, SB>(&self, rhs: &Matrix<T, R2, C2, SB>) -> T
+    where
+        SB: RawStorage<T, R2, C2>,
+        ShapeConstraint: DimEq<C, R2> + DimEq<R, C2>,
+    {
+        self.tr_dot(rhs)
+    }
+
+    /// The trace of `self.transpose() * rhs`, i.e., the Frobenius inner product of `self` and
+    /// `rhs`, computed without forming the product.
+    ///
+    /// This is equal to `self.dot(rhs)`, and requires `O(n²)` operations instead of the
+    /// `O(n³)` operations of `self.tr_mul(rhs).trace()`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let mat1 = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                           4.0, 5.0, 6.0);
+    /// let mat2 = Matrix2x3::new(0.1, 0.2, 0.3,
+    ///                           0.4, 0.5, 0.6);
+    /// assert_eq!(mat1.tr_mul_trace(&mat2), mat1.tr_mul(&mat2).trace());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn tr_mul_trace<R2: Dim, C2: Dim, SB>(&self, rhs: &Matrix<T, R2, C2, SB>) -> T
+    where
+        SB: RawStorage<T, R2, C2>,
+        ShapeConstraint: DimEq<R, R2> + DimEq<C, C2>,
+    {
+        self.dot(rhs)
+    }
+
+    /// The trace of `self.adjoint() * rhs`, i.e., the Frobenius inner product of `self` and `rhs`
+    /// for complex matrices, computed without forming the product.
+    ///
+    /// This is equal to `self.dotc(rhs)`, and requires `O(n²)` operations instead of the
+    /// `O(n³)` operations of `self.ad_mul(rhs).trace()`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Complex, Vector2};
+    /// let vec1 = Vector2::new(Complex::new(1.0, 2.0), Complex::new(3.0, 4.0));
+    /// let vec2 = Vector2::new(Complex::new(0.4, 0.3), Complex::new(0.2, 0.1));
+    /// assert_eq!(vec1.ad_mul_trace(&vec2), vec1.ad_mul(&vec2).trace());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ad_mul_trace<R2: Dim, C2: Dim, SB>(&self, rhs: &Matrix<T, R2, C2, SB>) -> T
+    where
+        T: SimdComplexField,
+        SB: RawStorage<T, R2, C2>,
+        ShapeConstraint: DimEq<R, R2> + DimEq<C, C2>,
+    {
+        self.dotc(rhs)
+    }
 }
 
 /// # BLAS functions
@@ -717,6 +794,40 @@ where
         self.gerx(alpha, x, y, beta, SimdComplexField::simd_conjugate)
     }
 
+    /// Computes `self += alpha * x * y.transpose()`, i.e., adds a rank-one matrix to `self`.
+    ///
+    /// This is the same as `self.ger(alpha, x, y, T::one())`, and does not allocate any
+    /// temporary matrix for the outer product of `x` and `y`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let samples = [Vector2::new(1.0, 2.0), Vector2::new(-1.0, 0.0), Vector2::new(3.0, 1.0)];
+    /// let mut scatter = Matrix2::zeros();
+    ///
+    /// for sample in &samples {
+    ///     scatter.rank1_update(1.0, sample, sample);
+    /// }
+    ///
+    /// assert_eq!(scatter, Matrix2::new(11.0, 5.0,
+    ///                                  5.0, 5.0));
+    /// ```
+    #[inline]
+    pub fn rank1_update<D2: Dim, D3: Dim, SB, SC>(
+        &mut self,
+        alpha: T,
+        x: &Vector<T, D2, SB>,
+        y: &Vector<T, D3, SC>,
+    ) where
+        T: One,
+        SB: Storage<T, D2>,
+        SC: Storage<T, D3>,
+        ShapeConstraint: DimEq<R1, D2> + DimEq<C1, D3>,
+    {
+        self.gerx(alpha, x, y, T::one(), |e| e)
+    }
+
     /// Computes `self = alpha * a * b + beta * self`, where `a, b, self` are matrices.
     /// `alpha` and `beta` are scalar.
     ///
@@ -873,6 +984,84 @@ where
                 .gemv_ad(alpha.clone(), a, &b.column(j1), beta.clone());
         }
     }
+
+    /// Computes `self = alpha * a * b + beta * self`, where `a, b` are matrices with components of
+    /// type `T2`, converted to the type `T` of the components of `self` before being multiplied.
+    /// `alpha` and `beta` are scalar.
+    ///
+    /// The products are thus accumulated with the precision of `T`. This allows, e.g., to store
+    /// large matrices with `f32` components while accumulating their product with `f64`, or to
+    /// multiply matrices with `i8` components into an `i32` matrix without overflow.
+    ///
+    /// If `beta` is zero, `self` is never read.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3, Matrix3x2};
+    /// let a = Matrix2x3::<i8>::new(100, 100, 100,
+    ///                              -100, 50, 1);
+    /// let b = Matrix3x2::<i8>::new(100, 1,
+    ///                              100, 2,
+    ///                              100, 3);
+    /// let mut c = Matrix2::<i32>::identity();
+    ///
+    /// c.gemm_mixed(1, &a, &b, 10);
+    /// assert_eq!(c, Matrix2::new(30_010, 600, -4_900, 13));
+    /// ```
+    #[inline]
+    pub fn gemm_mixed<T2, R2: Dim, C2: Dim, R3: Dim, C3: Dim, SB, SC>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T2, R2, C2, SB>,
+        b: &Matrix<T2, R3, C3, SC>,
+        beta: T,
+    ) where
+        T: SupersetOf<T2>,
+        T2: Scalar,
+        SB: RawStorage<T2, R2, C2>,
+        SC: RawStorage<T2, R3, C3>,
+        ShapeConstraint: SameNumberOfRows<R1, R2>
+            + SameNumberOfColumns<C1, C3>
+            + AreMultipliable<R2, C2, R3, C3>,
+    {
+        let (nrows1, ncols1) = self.shape();
+        let (nrows2, ncols2) = a.shape();
+        let (nrows3, ncols3) = b.shape();
+
+        assert_eq!(
+            ncols2, nrows3,
+            "gemm: dimensions mismatch for multiplication."
+        );
+        assert_eq!(
+            (nrows1, ncols1),
+            (nrows2, ncols3),
+            "gemm: dimensions mismatch for addition."
+        );
+
+        for j1 in 0..ncols1 {
+            let mut col = self.column_mut(j1);
+
+            if beta.is_zero() {
+                col.fill(T::zero());
+            } else {
+                col.apply(|e| *e = e.clone() * beta.clone());
+            }
+
+            for k in 0..ncols2 {
+                // Safety: the indices are within range thanks to the assertions above.
+                unsafe {
+                    let b_kj = alpha.clone() * T::from_subset(b.get_unchecked((k, j1)));
+
+                    for i in 0..nrows1 {
+                        let a_ik = T::from_subset(a.get_unchecked((i, k)));
+                        let c_ij = col.get_unchecked_mut(i);
+                        *c_ij = c_ij.clone() + a_ik * b_kj.clone();
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl<T, R1: Dim, C1: Dim, S: StorageMut<T, R1, C1>> Matrix<T, R1, C1, S>
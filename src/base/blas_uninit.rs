@@ -8,11 +8,19 @@
  * them here.
  */
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "alloc"))]
 use matrixmultiply;
 use num::{One, Zero};
 use simba::scalar::{ClosedAdd, ClosedMul};
-#[cfg(feature = "std")]
+#[cfg(any(
+    feature = "std",
+    feature = "alloc",
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
 use std::mem;
 
 use crate::base::constraint::{
@@ -96,6 +104,7 @@ pub unsafe fn axcpy_uninit<Status, T, D1: Dim, D2: Dim, SA, SB>(
 {
     assert_eq!(y.nrows(), x.nrows(), "Axcpy: mismatched vector shapes.");
 
+    let len = x.nrows();
     let rstride1 = y.strides().0;
     let rstride2 = x.strides().0;
 
@@ -105,9 +114,9 @@ pub unsafe fn axcpy_uninit<Status, T, D1: Dim, D2: Dim, SA, SB>(
     let x = x.data.as_slice_unchecked();
 
     if !b.is_zero() {
-        array_axcpy(status, y, a, x, c, b, rstride1, rstride2, x.len());
+        array_axcpy(status, y, a, x, c, b, rstride1, rstride2, len);
     } else {
-        array_axc(status, y, a, x, c, rstride1, rstride2, x.len());
+        array_axc(status, y, a, x, c, rstride1, rstride2, len);
     }
 }
 
@@ -207,7 +216,40 @@ pub unsafe fn gemm_uninit<
 {
     let ncols1 = y.ncols();
 
-    #[cfg(feature = "std")]
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))]
+    {
+        // Products of 4×4 single-precision matrices, typically transformations in 3D graphics,
+        // with matrices or vectors are explicitly vectorized.
+        if TypeId::of::<T>() == TypeId::of::<f32>()
+            && a.shape() == (4, 4)
+            && b.nrows() == 4
+            && y.shape() == (4, b.ncols())
+            && a.strides().0 == 1
+            && b.strides().0 == 1
+            && y.strides().0 == 1
+        {
+            crate::base::matrix4_simd::gemm4(
+                mem::transmute_copy(&alpha),
+                a.data.ptr() as *const f32,
+                a.strides().1,
+                b.data.ptr() as *const f32,
+                b.strides().1,
+                mem::transmute_copy(&beta),
+                y.data.ptr_mut() as *mut f32,
+                y.strides().1,
+                ncols1,
+            );
+            return;
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
     {
         // We assume large matrices will be Dynamic but small matrices static.
         // We could use matrixmultiply for large statically-sized matrices but the performance
@@ -220,7 +262,7 @@ pub unsafe fn gemm_uninit<
             || R3::is::<Dynamic>()
             || C3::is::<Dynamic>()
         {
-            // matrixmultiply can be used only if the std feature is available.
+            // matrixmultiply can be used only if the std or alloc feature is available.
             let nrows1 = y.nrows();
             let (nrows2, ncols2) = a.shape();
             let (nrows3, ncols3) = b.shape();
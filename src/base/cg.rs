@@ -16,7 +16,7 @@ use crate::base::{
 };
 use crate::geometry::{
     Isometry, IsometryMatrix3, Orthographic3, Perspective3, Point, Point2, Point3, Rotation2,
-    Rotation3,
+    Rotation3, Shear3, Translation3, UnitQuaternion,
 };
 
 use simba::scalar::{ClosedAdd, ClosedMul, RealField};
@@ -206,6 +206,115 @@ impl<T: RealField> Matrix4<T> {
     }
 }
 
+/// # Decomposition of 3D affine transformations
+impl<T: RealField> Matrix4<T> {
+    /// Decomposes this homogeneous matrix into a translation, a rotation, a non-uniform scale,
+    /// and an optional shear.
+    ///
+    /// The returned components `(t, r, s, h)` are such that `self` is equal to the
+    /// composition `t * r * h * diag(s)`, i.e., the scale is applied first, followed by the
+    /// shear (if any), the rotation, and finally the translation. The shear is `None` if all
+    /// its factors are negligible. When it is not `None`, it is upper-triangular: only its
+    /// `xy`, `xz`, and `yz` factors can be non-zero.
+    ///
+    /// If the linear part of `self` has a negative determinant (i.e. if it contains a
+    /// reflection), the reflection is absorbed into the scale by negating its `x` component,
+    /// so the returned rotation is always a proper rotation.
+    ///
+    /// Returns `None` if the last row of `self` is not `[0, 0, 0, 1]`, or if its linear part
+    /// is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix4, Translation3, UnitQuaternion, Vector3};
+    /// let t = Translation3::new(1.0, 2.0, 3.0);
+    /// let r = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+    /// let s = Vector3::new(-2.0, 3.0, 4.0);
+    /// let m = t.to_homogeneous() * r.to_homogeneous() * Matrix4::new_nonuniform_scaling(&s);
+    ///
+    /// let (t2, r2, s2, shear) = m.decompose_trs().unwrap();
+    /// assert_relative_eq!(t2, t, epsilon = 1.0e-7);
+    /// assert_relative_eq!(s2, s, epsilon = 1.0e-7);
+    /// assert_relative_eq!(r2, r, epsilon = 1.0e-7);
+    /// assert!(shear.is_none());
+    /// ```
+    #[must_use]
+    pub fn decompose_trs(
+        &self,
+    ) -> Option<(
+        Translation3<T>,
+        UnitQuaternion<T>,
+        Vector3<T>,
+        Option<Shear3<T>>,
+    )> {
+        let eps = T::default_epsilon();
+        let last_row = self.fixed_rows::<1>(3);
+
+        if last_row.fixed_columns::<3>(0).amax() > eps
+            || (last_row[3].clone() - T::one()).abs() > eps
+        {
+            return None;
+        }
+
+        let translation = Translation3::from(self.fixed_slice::<3, 1>(0, 3).into_owned());
+        let mut linear = self.fixed_slice::<3, 3>(0, 0).into_owned();
+        let reflected = linear.determinant() < T::zero();
+
+        if reflected {
+            linear.column_mut(0).neg_mut();
+        }
+
+        // Gram-Schmidt orthonormalization of the columns, i.e., `linear = rot * upper` where
+        // `upper` is the upper-triangular matrix `shear * diag(scale)`.
+        let (c0, sx) = Unit::try_new_and_get(linear.column(0).into_owned(), eps.clone())?;
+        let xy = c0.dot(&linear.column(1));
+        let (c1, sy) =
+            Unit::try_new_and_get(linear.column(1) - c0.as_ref() * xy.clone(), eps.clone())?;
+        let xz = c0.dot(&linear.column(2));
+        let yz = c1.dot(&linear.column(2));
+        let (c2, sz) = Unit::try_new_and_get(
+            linear.column(2) - c0.as_ref() * xz.clone() - c1.as_ref() * yz.clone(),
+            eps,
+        )?;
+
+        let rot = Rotation3::from_matrix_unchecked(Matrix3::from_columns(&[
+            c0.into_inner(),
+            c1.into_inner(),
+            c2.into_inner(),
+        ]));
+        let rotation = UnitQuaternion::from_rotation_matrix(&rot);
+
+        let shear_xy = xy / sy.clone();
+        let shear_xz = xz / sz.clone();
+        let shear_yz = yz / sz.clone();
+        let shear_eps = T::default_epsilon().sqrt();
+        let shear = if shear_xy.clone().abs() <= shear_eps
+            && shear_xz.clone().abs() <= shear_eps
+            && shear_yz.clone().abs() <= shear_eps
+        {
+            None
+        } else {
+            Some(Shear3::new(
+                shear_xy,
+                shear_xz,
+                T::zero(),
+                shear_yz,
+                T::zero(),
+                T::zero(),
+            ))
+        };
+
+        let scale = if reflected {
+            Vector3::new(-sx, sy, sz)
+        } else {
+            Vector3::new(sx, sy, sz)
+        };
+
+        Some((translation, rotation, scale, shear))
+    }
+}
+
 /// # Append/prepend translation and scaling
 impl<T: Scalar + Zero + One + ClosedMul + ClosedAdd, D: DimName, S: Storage<T, D, D>>
     SquareMatrix<T, D, S>
@@ -0,0 +1,322 @@
+//! Componentwise comparison of matrices reporting their worst mismatches.
+//!
+//! See `compare_matrices` and the `assert_matrix_approx_eq!` macro for details.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::fmt;
+
+use approx::UlpsEq;
+
+use crate::base::dimension::Dim;
+use crate::base::storage::RawStorage;
+use crate::base::Matrix;
+use crate::{ComplexField, RealField};
+
+/// The maximum number of mismatches reported by a `MatrixComparisonError`.
+pub const MAX_REPORTED_MISMATCHES: usize = 10;
+
+/// The tolerance used by `compare_matrices` to decide whether two components are equal.
+///
+/// The components of complex matrices are compared based on the modulus of their difference,
+/// except with `Tolerance::Ulps` which compares their real and imaginary parts independently.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Tolerance<T> {
+    /// The components must be exactly equal.
+    Exact,
+    /// The modulus of the difference of the components must not exceed `epsilon`.
+    Absolute {
+        /// The maximum absolute difference.
+        epsilon: T,
+    },
+    /// The components must be equal as per `approx::RelativeEq`, i.e., the modulus of their
+    /// difference must not exceed either `epsilon` or `max_relative` times the largest of their
+    /// moduli.
+    Relative {
+        /// The maximum absolute difference.
+        epsilon: T,
+        /// The maximum difference relative to the largest component.
+        max_relative: T,
+    },
+    /// The components must be equal as per `approx::UlpsEq`, i.e., the modulus of their
+    /// difference must not exceed `epsilon`, or they must be at most `max_ulps` representable
+    /// floating-point numbers apart.
+    Ulps {
+        /// The maximum absolute difference.
+        epsilon: T,
+        /// The maximum number of units in the last place.
+        max_ulps: u32,
+    },
+}
+
+impl<T: fmt::Display> fmt::Display for Tolerance<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tolerance::Exact => write!(f, "exact"),
+            Tolerance::Absolute { epsilon } => write!(f, "epsilon = {}", epsilon),
+            Tolerance::Relative {
+                epsilon,
+                max_relative,
+            } => write!(f, "epsilon = {}, max_relative = {}", epsilon, max_relative),
+            Tolerance::Ulps { epsilon, max_ulps } => {
+                write!(f, "epsilon = {}, max_ulps = {}", epsilon, max_ulps)
+            }
+        }
+    }
+}
+
+/// Checks whether two components, whose difference has the modulus `distance`, are equal.
+fn accepts<T: ComplexField>(
+    tolerance: &Tolerance<T::RealField>,
+    left: &T,
+    right: &T,
+    distance: &T::RealField,
+) -> bool {
+    match tolerance {
+        Tolerance::Exact => left == right,
+        Tolerance::Absolute { epsilon } => distance <= epsilon,
+        Tolerance::Relative {
+            epsilon,
+            max_relative,
+        } => {
+            let largest = RealField::max(left.clone().modulus(), right.clone().modulus());
+            left == right || distance <= epsilon || *distance <= largest * max_relative.clone()
+        }
+        Tolerance::Ulps { epsilon, max_ulps } => {
+            let (left_re, left_im) = (left.clone().real(), left.clone().imaginary());
+            let (right_re, right_im) = (right.clone().real(), right.clone().imaginary());
+            left == right
+                || distance <= epsilon
+                || (left_re.ulps_eq(&right_re, epsilon.clone(), *max_ulps)
+                    && left_im.ulps_eq(&right_im, epsilon.clone(), *max_ulps))
+        }
+    }
+}
+
+/// Two components of the compared matrices that are not equal with the requested tolerance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentMismatch<T: ComplexField> {
+    /// The row of the components.
+    pub row: usize,
+    /// The column of the components.
+    pub col: usize,
+    /// The component of the left matrix.
+    pub left: T,
+    /// The component of the right matrix.
+    pub right: T,
+    /// The modulus of the difference of the components.
+    pub distance: T::RealField,
+}
+
+/// The reason why two matrices compared by `compare_matrices` are not equal.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MatrixComparisonError<T: ComplexField> {
+    /// The matrices don't have the same shape.
+    ShapeMismatch {
+        /// The shape of the left matrix.
+        left: (usize, usize),
+        /// The shape of the right matrix.
+        right: (usize, usize),
+    },
+    /// Some components of the matrices are not equal.
+    Mismatches {
+        /// The shape of the compared matrices.
+        shape: (usize, usize),
+        /// The tolerance used for the comparison.
+        tolerance: Tolerance<T::RealField>,
+        /// The total number of mismatching components.
+        count: usize,
+        /// The worst mismatches, by decreasing distance, up to `MAX_REPORTED_MISMATCHES` of them.
+        worst: Vec<ComponentMismatch<T>>,
+    },
+}
+
+impl<T: ComplexField> fmt::Display for MatrixComparisonError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixComparisonError::ShapeMismatch { left, right } => write!(
+                f,
+                "the matrices have different shapes: {:?} (left) and {:?} (right)",
+                left, right
+            ),
+            MatrixComparisonError::Mismatches {
+                shape,
+                tolerance,
+                count,
+                worst,
+            } => {
+                writeln!(
+                    f,
+                    "{} of the {} components differ ({}), the worst {} being:",
+                    count,
+                    shape.0 * shape.1,
+                    tolerance,
+                    worst.len()
+                )?;
+
+                for m in worst {
+                    writeln!(
+                        f,
+                        "  ({}, {}): left = {}, right = {}, |left - right| = {}",
+                        m.row, m.col, m.left, m.right, m.distance
+                    )?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ComplexField> Error for MatrixComparisonError<T> {}
+
+/// Compares two matrices componentwise with the given tolerance.
+///
+/// On failure, the returned error lists the indices and values of the worst mismatching
+/// components instead of the whole matrices, which makes it suitable for comparing large
+/// matrices. NaN components are always considered as mismatches, and as the worst ones.
+///
+/// # Example
+/// ```
+/// # use nalgebra::DMatrix;
+/// # use nalgebra::base::comparison::{compare_matrices, MatrixComparisonError, Tolerance};
+/// let a = DMatrix::<f64>::identity(100, 100);
+/// let mut b = a.clone();
+/// b[(3, 4)] = 1.0e-3;
+/// b[(50, 50)] = 1.1;
+///
+/// let tolerance = Tolerance::Absolute { epsilon: 1.0e-6 };
+/// match compare_matrices(&a, &b, tolerance) {
+///     Err(MatrixComparisonError::Mismatches { count, worst, .. }) => {
+///         assert_eq!(count, 2);
+///         assert_eq!((worst[0].row, worst[0].col), (50, 50));
+///         assert_eq!((worst[1].row, worst[1].col), (3, 4));
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// assert!(compare_matrices(&a, &b, Tolerance::Absolute { epsilon: 0.2 }).is_ok());
+/// ```
+pub fn compare_matrices<T, R1, C1, S1, R2, C2, S2>(
+    left: &Matrix<T, R1, C1, S1>,
+    right: &Matrix<T, R2, C2, S2>,
+    tolerance: Tolerance<T::RealField>,
+) -> Result<(), MatrixComparisonError<T>>
+where
+    T: ComplexField,
+    R1: Dim,
+    C1: Dim,
+    S1: RawStorage<T, R1, C1>,
+    R2: Dim,
+    C2: Dim,
+    S2: RawStorage<T, R2, C2>,
+{
+    let shape = left.shape();
+
+    if shape != right.shape() {
+        return Err(MatrixComparisonError::ShapeMismatch {
+            left: shape,
+            right: right.shape(),
+        });
+    }
+
+    let mut mismatches = Vec::new();
+
+    for j in 0..shape.1 {
+        for i in 0..shape.0 {
+            let l = &left[(i, j)];
+            let r = &right[(i, j)];
+            let distance = (l.clone() - r.clone()).modulus();
+
+            if !accepts(&tolerance, l, r, &distance) {
+                mismatches.push(ComponentMismatch {
+                    row: i,
+                    col: j,
+                    left: l.clone(),
+                    right: r.clone(),
+                    distance,
+                });
+            }
+        }
+    }
+
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+
+    let count = mismatches.len();
+    // Sort by decreasing distance, with NaNs first.
+    mismatches.sort_by(|a, b| {
+        b.distance
+            .partial_cmp(&a.distance)
+            .unwrap_or_else(|| a.distance.is_finite().cmp(&b.distance.is_finite()))
+    });
+    mismatches.truncate(MAX_REPORTED_MISMATCHES);
+
+    Err(MatrixComparisonError::Mismatches {
+        shape,
+        tolerance,
+        count,
+        worst: mismatches,
+    })
+}
+
+/// Asserts that two matrices are componentwise equal with some tolerance.
+///
+/// Unlike `assert_relative_eq!` and the like, the panic message only lists the indices and
+/// values of the worst mismatching components (see `compare_matrices`) instead of the whole
+/// matrices.
+///
+/// The tolerance is given with the same syntax as the assertion macros of the `approx` crate:
+///
+/// - `assert_matrix_approx_eq!(a, b)` requires exact equality.
+/// - `assert_matrix_approx_eq!(a, b, epsilon = e)` uses `Tolerance::Absolute`.
+/// - `assert_matrix_approx_eq!(a, b, epsilon = e, max_relative = r)` uses `Tolerance::Relative`.
+/// - `assert_matrix_approx_eq!(a, b, epsilon = e, max_ulps = u)` uses `Tolerance::Ulps`.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate nalgebra;
+/// # use nalgebra::Matrix2;
+/// # fn main() {
+/// let a = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+/// let b = a + Matrix2::repeat(1.0e-10);
+///
+/// assert_matrix_approx_eq!(a, a);
+/// assert_matrix_approx_eq!(a, b, epsilon = 1.0e-8);
+/// assert_matrix_approx_eq!(a, b, epsilon = 0.0, max_relative = 1.0e-8);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($left: expr, $right: expr $(,)?) => {
+        $crate::assert_matrix_approx_eq!(@tolerance $left, $right,
+            $crate::base::comparison::Tolerance::Exact)
+    };
+    ($left: expr, $right: expr, epsilon = $eps: expr $(,)?) => {
+        $crate::assert_matrix_approx_eq!(@tolerance $left, $right,
+            $crate::base::comparison::Tolerance::Absolute { epsilon: $eps })
+    };
+    ($left: expr, $right: expr, epsilon = $eps: expr, max_relative = $rel: expr $(,)?) => {
+        $crate::assert_matrix_approx_eq!(@tolerance $left, $right,
+            $crate::base::comparison::Tolerance::Relative { epsilon: $eps, max_relative: $rel })
+    };
+    ($left: expr, $right: expr, epsilon = $eps: expr, max_ulps = $ulps: expr $(,)?) => {
+        $crate::assert_matrix_approx_eq!(@tolerance $left, $right,
+            $crate::base::comparison::Tolerance::Ulps { epsilon: $eps, max_ulps: $ulps })
+    };
+    (@tolerance $left: expr, $right: expr, $tolerance: expr) => {
+        match $crate::base::comparison::compare_matrices(&$left, &$right, $tolerance) {
+            Ok(()) => {}
+            Err(e) => panic!(
+                "assertion failed: `{} ≈ {}`\n{}",
+                stringify!($left),
+                stringify!($right),
+                e
+            ),
+        }
+    };
+}
@@ -0,0 +1,157 @@
+use num_complex::Complex;
+use simba::scalar::ClosedNeg;
+
+use crate::allocator::Allocator;
+use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::storage::RawStorage;
+use crate::{Const, DefaultAllocator, Dim, DimMul, DimProd, Matrix, OMatrix, Scalar, U2};
+
+/// # Complex-to-real embeddings
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<Complex<T>, R, C>> Matrix<Complex<T>, R, C, S> {
+    /// Computes the real representation of this complex matrix.
+    ///
+    /// The complex matrix `A + iB` is represented by the real block matrix `[A, -B; B, A]`, which
+    /// has twice as many rows and columns. This representation preserves sums, products,
+    /// adjoints, and inverses, and the product of the real representation of a matrix with the
+    /// stacked real representation of a vector (see `to_real_stacked`) is the stacked real
+    /// representation of their complex product. Thus, real-only algorithms can be used to solve
+    /// complex problems.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Complex, Matrix1, Matrix2};
+    /// let m = Matrix1::new(Complex::new(1.0, 2.0));
+    /// assert_eq!(m.to_real_embedding(), Matrix2::new(1.0, -2.0,
+    ///                                                2.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn to_real_embedding(&self) -> OMatrix<T, DimProd<R, U2>, DimProd<C, U2>>
+    where
+        T: ClosedNeg,
+        R: DimMul<U2>,
+        C: DimMul<U2>,
+        DefaultAllocator: Allocator<T, DimProd<R, U2>, DimProd<C, U2>>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let (n, m) = (nrows.value(), ncols.value());
+
+        Matrix::from_fn_generic(nrows.mul(Const::<2>), ncols.mul(Const::<2>), |i, j| {
+            let z = &self[(i % n, j % m)];
+
+            match (i < n, j < m) {
+                (true, true) | (false, false) => z.re.clone(),
+                (false, true) => z.im.clone(),
+                (true, false) => -z.im.clone(),
+            }
+        })
+    }
+
+    /// Stacks the real parts of the components of this complex matrix on top of their imaginary
+    /// parts, i.e., represents `a + ib` as the real matrix `[a; b]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Complex, Vector2, Vector4};
+    /// let v = Vector2::new(Complex::new(1.0, 2.0), Complex::new(3.0, 4.0));
+    /// assert_eq!(v.to_real_stacked(), Vector4::new(1.0, 3.0, 2.0, 4.0));
+    /// ```
+    #[must_use]
+    pub fn to_real_stacked(&self) -> OMatrix<T, DimProd<R, U2>, C>
+    where
+        R: DimMul<U2>,
+        DefaultAllocator: Allocator<T, DimProd<R, U2>, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let n = nrows.value();
+
+        Matrix::from_fn_generic(nrows.mul(Const::<2>), ncols, |i, j| {
+            if i < n {
+                self[(i, j)].re.clone()
+            } else {
+                self[(i - n, j)].im.clone()
+            }
+        })
+    }
+}
+
+impl<T: Scalar, R: DimMul<U2>, C: DimMul<U2>> OMatrix<Complex<T>, R, C>
+where
+    DefaultAllocator: Allocator<Complex<T>, R, C>,
+{
+    /// Rebuilds a complex matrix from its real representation, i.e., inverts
+    /// `Matrix::to_real_embedding`.
+    ///
+    /// Only the first block column `[A; B]` of `real` is read, the other one is assumed to be
+    /// `[-B; A]`.
+    ///
+    /// # Panics
+    /// Panics if `real` has an odd number of rows or columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Complex, DMatrix, Matrix2};
+    /// let m = Matrix2::new(Complex::new(1.0, 2.0), Complex::new(3.0, -1.0),
+    ///                      Complex::new(0.0, 1.0), Complex::new(2.0, 0.5));
+    /// let real = m.to_real_embedding();
+    ///
+    /// assert_eq!(Matrix2::from_real_embedding(&real), m);
+    /// assert_eq!(DMatrix::from_real_embedding(&real), m);
+    /// ```
+    #[must_use]
+    pub fn from_real_embedding<R2, C2, S2>(real: &Matrix<T, R2, C2, S2>) -> Self
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+        ShapeConstraint:
+            SameNumberOfRows<R2, DimProd<R, U2>> + SameNumberOfColumns<C2, DimProd<C, U2>>,
+    {
+        let (nrows, ncols) = real.shape();
+        assert!(
+            nrows % 2 == 0 && ncols % 2 == 0,
+            "Complex embedding: the real representation must have even dimensions."
+        );
+
+        let n = nrows / 2;
+        Self::from_fn_generic(R::from_usize(n), C::from_usize(ncols / 2), |i, j| {
+            Complex::new(real[(i, j)].clone(), real[(i + n, j)].clone())
+        })
+    }
+}
+
+impl<T: Scalar, R: DimMul<U2>, C: Dim> OMatrix<Complex<T>, R, C>
+where
+    DefaultAllocator: Allocator<Complex<T>, R, C>,
+{
+    /// Rebuilds a complex matrix from the real matrix stacking the real parts of its components on
+    /// top of their imaginary parts, i.e., inverts `Matrix::to_real_stacked`.
+    ///
+    /// # Panics
+    /// Panics if `real` has an odd number of rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Complex, Vector2, Vector4};
+    /// let v = Vector2::from_real_stacked(&Vector4::new(1.0, 3.0, 2.0, 4.0));
+    /// assert_eq!(v, Vector2::new(Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)));
+    /// ```
+    #[must_use]
+    pub fn from_real_stacked<R2, C2, S2>(real: &Matrix<T, R2, C2, S2>) -> Self
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, DimProd<R, U2>> + SameNumberOfColumns<C2, C>,
+    {
+        let (nrows, ncols) = real.shape();
+        assert!(
+            nrows % 2 == 0,
+            "Complex embedding: the stacked representation must have an even number of rows."
+        );
+
+        let n = nrows / 2;
+        Self::from_fn_generic(R::from_usize(n), C::from_usize(ncols), |i, j| {
+            Complex::new(real[(i, j)].clone(), real[(i + n, j)].clone())
+        })
+    }
+}
@@ -3,14 +3,14 @@
 use num::{Signed, Zero};
 use std::ops::{Add, Mul};
 
-use simba::scalar::{ClosedDiv, ClosedMul};
+use simba::scalar::{ClosedDiv, ClosedMul, ClosedSub};
 use simba::simd::SimdPartialOrd;
 
 use crate::base::allocator::{Allocator, SameShapeAllocator};
 use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
-use crate::base::dimension::Dim;
+use crate::base::dimension::{Dim, U1};
 use crate::base::storage::{Storage, StorageMut};
-use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar};
+use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar, Vector};
 use crate::ClosedAdd;
 
 /// The type of the result of a matrix component-wise operation.
@@ -351,3 +351,196 @@ impl<T: Scalar, R1: Dim, C1: Dim, SA: Storage<T, R1, C1>> Matrix<T, R1, C1, SA>
         }
     }
 }
+
+macro_rules! broadcast_binop_impl(
+    ($($Trait: ident . $op_assign: ident,
+       $broadcast_rows: ident, $broadcast_rows_mut: ident,
+       $broadcast_columns: ident, $broadcast_columns_mut: ident,
+       $desc_rows: expr, $desc_rows_mut: expr, $desc_columns: expr, $desc_columns_mut: expr);* $(;)*) => {$(
+        #[doc = $desc_rows]
+        #[inline]
+        #[must_use]
+        pub fn $broadcast_rows<C2: Dim, SB>(&self, row: &Matrix<T, U1, C2, SB>)
+            -> OMatrix<T, R1, C1>
+            where T: $Trait,
+                  SB: Storage<T, U1, C2>,
+                  DefaultAllocator: Allocator<T, R1, C1>,
+                  ShapeConstraint: SameNumberOfColumns<C1, C2> {
+            let mut res = self.clone_owned();
+            res.$broadcast_rows_mut(row);
+            res
+        }
+
+        #[doc = $desc_rows_mut]
+        #[inline]
+        pub fn $broadcast_rows_mut<C2: Dim, SB>(&mut self, row: &Matrix<T, U1, C2, SB>)
+            where T: $Trait,
+                  SA: StorageMut<T, R1, C1>,
+                  SB: Storage<T, U1, C2>,
+                  ShapeConstraint: SameNumberOfColumns<C1, C2> {
+            assert_eq!(self.ncols(), row.ncols(), "Broadcast: mismatched number of columns.");
+
+            for j in 0 .. self.ncols() {
+                unsafe {
+                    let rhs = row.get_unchecked(j);
+
+                    for i in 0 .. self.nrows() {
+                        self.get_unchecked_mut((i, j)).$op_assign(rhs.clone());
+                    }
+                }
+            }
+        }
+
+        #[doc = $desc_columns]
+        #[inline]
+        #[must_use]
+        pub fn $broadcast_columns<R2: Dim, SB>(&self, column: &Vector<T, R2, SB>)
+            -> OMatrix<T, R1, C1>
+            where T: $Trait,
+                  SB: Storage<T, R2>,
+                  DefaultAllocator: Allocator<T, R1, C1>,
+                  ShapeConstraint: SameNumberOfRows<R1, R2> {
+            let mut res = self.clone_owned();
+            res.$broadcast_columns_mut(column);
+            res
+        }
+
+        #[doc = $desc_columns_mut]
+        #[inline]
+        pub fn $broadcast_columns_mut<R2: Dim, SB>(&mut self, column: &Vector<T, R2, SB>)
+            where T: $Trait,
+                  SA: StorageMut<T, R1, C1>,
+                  SB: Storage<T, R2>,
+                  ShapeConstraint: SameNumberOfRows<R1, R2> {
+            assert_eq!(self.nrows(), column.nrows(), "Broadcast: mismatched number of rows.");
+
+            for j in 0 .. self.ncols() {
+                for i in 0 .. self.nrows() {
+                    unsafe {
+                        self.get_unchecked_mut((i, j)).$op_assign(column.vget_unchecked(i).clone());
+                    }
+                }
+            }
+        }
+    )*}
+);
+
+/// # Broadcasting operations
+impl<T: Scalar, R1: Dim, C1: Dim, SA: Storage<T, R1, C1>> Matrix<T, R1, C1, SA> {
+    broadcast_binop_impl!(
+        ClosedAdd.add_assign,
+        add_broadcast_rows, add_broadcast_rows_mut,
+        add_broadcast_columns, add_broadcast_columns_mut,
+        r"
+        Adds `row` to each row of `self`.
+
+        # Example
+        ```
+        # use nalgebra::{Matrix2x3, RowVector3};
+        let m = Matrix2x3::new(1.0, 2.0, 3.0,
+                               4.0, 5.0, 6.0);
+        let bias = RowVector3::new(10.0, 20.0, 30.0);
+        let expected = Matrix2x3::new(11.0, 22.0, 33.0,
+                                      14.0, 25.0, 36.0);
+
+        assert_eq!(m.add_broadcast_rows(&bias), expected);
+        ```
+        ",
+        r"
+        Adds `row` to each row of `self` in-place.
+        ",
+        r"
+        Adds `column` to each column of `self`.
+
+        # Example
+        ```
+        # use nalgebra::{Matrix2x3, Vector2};
+        let m = Matrix2x3::new(1.0, 2.0, 3.0,
+                               4.0, 5.0, 6.0);
+        let expected = Matrix2x3::new(11.0, 12.0, 13.0,
+                                      24.0, 25.0, 26.0);
+
+        assert_eq!(m.add_broadcast_columns(&Vector2::new(10.0, 20.0)), expected);
+        ```
+        ",
+        r"
+        Adds `column` to each column of `self` in-place.
+        ";
+        ClosedSub.sub_assign,
+        sub_broadcast_rows, sub_broadcast_rows_mut,
+        sub_broadcast_columns, sub_broadcast_columns_mut,
+        r"
+        Subtracts `row` from each row of `self`.
+        ",
+        r"
+        Subtracts `row` from each row of `self` in-place.
+
+        # Example
+        ```
+        # use nalgebra::{Matrix3x2, RowVector2};
+        // Center the observations stored in the rows of a matrix.
+        let mut data = Matrix3x2::new(1.0, 4.0,
+                                      2.0, 5.0,
+                                      6.0, 0.0);
+        let mean = data.row_mean();
+        data.sub_broadcast_rows_mut(&mean);
+
+        assert_eq!(mean, RowVector2::new(3.0, 3.0));
+        assert_eq!(data, Matrix3x2::new(-2.0, 1.0,
+                                        -1.0, 2.0,
+                                         3.0, -3.0));
+        ```
+        ",
+        r"
+        Subtracts `column` from each column of `self`.
+        ",
+        r"
+        Subtracts `column` from each column of `self` in-place.
+        ";
+        ClosedMul.mul_assign,
+        component_mul_broadcast_rows, component_mul_broadcast_rows_mut,
+        component_mul_broadcast_columns, component_mul_broadcast_columns_mut,
+        r"
+        Multiplies componentwise each row of `self` by `row`, i.e., scales the `j`-th column of
+        `self` by `row[j]`.
+        ",
+        r"
+        Multiplies componentwise each row of `self` by `row` in-place.
+        ",
+        r"
+        Multiplies componentwise each column of `self` by `column`, i.e., scales the `i`-th row of
+        `self` by `column[i]`.
+
+        # Example
+        ```
+        # use nalgebra::{Matrix2x3, Vector2};
+        let m = Matrix2x3::new(1.0, 2.0, 3.0,
+                               4.0, 5.0, 6.0);
+        let expected = Matrix2x3::new(2.0, 4.0, 6.0,
+                                      -4.0, -5.0, -6.0);
+
+        assert_eq!(m.component_mul_broadcast_columns(&Vector2::new(2.0, -1.0)), expected);
+        ```
+        ",
+        r"
+        Multiplies componentwise each column of `self` by `column` in-place.
+        ";
+        ClosedDiv.div_assign,
+        component_div_broadcast_rows, component_div_broadcast_rows_mut,
+        component_div_broadcast_columns, component_div_broadcast_columns_mut,
+        r"
+        Divides componentwise each row of `self` by `row`, i.e., divides the `j`-th column of
+        `self` by `row[j]`.
+        ",
+        r"
+        Divides componentwise each row of `self` by `row` in-place.
+        ",
+        r"
+        Divides componentwise each column of `self` by `column`, i.e., divides the `i`-th row of
+        `self` by `column[i]`.
+        ",
+        r"
+        Divides componentwise each column of `self` by `column` in-place.
+        ";
+    );
+}
@@ -13,6 +13,8 @@ use rand::{
     Rng,
 };
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use std::borrow::Borrow;
 use std::iter;
 use typenum::{self, Cmp, Greater};
 
@@ -647,6 +649,102 @@ where
                    nrows, ncols);
 }
 
+/// # Constructors of fully dynamic matrices from iterators of rows or columns
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar> OMatrix<T, Dynamic, Dynamic> {
+    /// Builds a new matrix with `ncols` columns from an iterator of rows.
+    ///
+    /// Each row can be anything that can be iterated through, e.g., a slice, a `Vec`, or a
+    /// reference to a row vector. The rows are consumed as they are iterated through, without
+    /// intermediate allocations.
+    ///
+    /// Panics if a row does not have exactly `ncols` components.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, RowDVector};
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let m = DMatrix::from_rows_iter(3, &rows);
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+    ///
+    /// let row = RowDVector::from_row_slice(&[1, 2]);
+    /// let m = DMatrix::from_rows_iter(2, std::iter::repeat(&row).take(3));
+    /// assert_eq!(m, DMatrix::from_row_slice(3, 2, &[1, 2, 1, 2, 1, 2]));
+    /// ```
+    pub fn from_rows_iter<I>(ncols: usize, rows: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator,
+        <I::Item as IntoIterator>::Item: Borrow<T>,
+    {
+        let rows = rows.into_iter();
+        let mut data = Vec::with_capacity(rows.size_hint().0 * ncols);
+        let mut nrows = 0;
+
+        for row in rows {
+            extend_exactly(&mut data, row, ncols, ("Row", nrows));
+            nrows += 1;
+        }
+
+        // The data is in row-major order.
+        let mut res = Self::from_vec_generic(Dynamic::new(ncols), Dynamic::new(nrows), data);
+        res.transpose_in_place();
+        res
+    }
+
+    /// Builds a new matrix with `nrows` rows from an iterator of columns.
+    ///
+    /// Each column can be anything that can be iterated through, e.g., a slice, a `Vec`, or a
+    /// reference to a vector. The columns are consumed as they are iterated through, without
+    /// intermediate allocations.
+    ///
+    /// Panics if a column does not have exactly `nrows` components.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_columns_iter(2, (0..3).map(|j| [j, 10 * j]));
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[0, 1, 2, 0, 10, 20]));
+    /// ```
+    pub fn from_columns_iter<I>(nrows: usize, columns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator,
+        <I::Item as IntoIterator>::Item: Borrow<T>,
+    {
+        let columns = columns.into_iter();
+        let mut data = Vec::with_capacity(columns.size_hint().0 * nrows);
+        let mut ncols = 0;
+
+        for column in columns {
+            extend_exactly(&mut data, column, nrows, ("Column", ncols));
+            ncols += 1;
+        }
+
+        Self::from_vec_generic(Dynamic::new(nrows), Dynamic::new(ncols), data)
+    }
+}
+
+/// Pushes the `len` elements of `elts` to `data`, and panics if `elts`, which is the row or column
+/// identified by `(kind, index)`, does not have exactly `len` elements.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn extend_exactly<T: Clone, I>(data: &mut Vec<T>, elts: I, len: usize, (kind, index): (&str, usize))
+where
+    I: IntoIterator,
+    I::Item: Borrow<T>,
+{
+    let start = data.len();
+    // Take one extra element to detect iterators that are too long.
+    data.extend(elts.into_iter().take(len + 1).map(|e| e.borrow().clone()));
+    assert!(
+        data.len() - start == len,
+        "{} {} does not have exactly {} components.",
+        kind,
+        index,
+        len
+    );
+}
+
 /*
  *
  * Constructors that don't necessarily require all dimensions
@@ -55,6 +55,46 @@ impl<'a, T: Scalar, R: Dim, C: Dim, RStride: Dim, CStride: Dim>
             Self::from_slice_with_strides_generic_unchecked(data, 0, nrows, ncols, rstride, cstride)
         }
     }
+
+    /// Creates a matrix slice from a raw pointer, with dimensions and strides specified by generic
+    /// types instances.
+    ///
+    /// The component `(i, j)` of the slice is read at `ptr.add(i * rstride + j * cstride)`. This
+    /// allows to view memory owned by foreign code, e.g., a buffer allocated by a C library, as a
+    /// matrix without copying it. Prefer `from_slice_with_strides_generic` whenever the buffer is
+    /// available as a Rust slice.
+    ///
+    /// # Safety
+    /// - `ptr` must be non-null and properly aligned, even if the slice is empty.
+    /// - Every component `(i, j)` of the slice must be located within a single allocated object,
+    /// and be initialized.
+    /// - The memory must not be mutated, except inside of an `UnsafeCell`, for the whole lifetime
+    /// `'a`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrixSlice, Dynamic, Matrix2x3};
+    /// // A 2x3 matrix stored in row-major order, as is common in C libraries.
+    /// let buffer: Box<[f64]> = Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let ptr: *const f64 = buffer.as_ptr();
+    ///
+    /// let (nrows, ncols) = (Dynamic::new(2), Dynamic::new(3));
+    /// let m = unsafe {
+    ///     DMatrixSlice::from_raw_parts_generic(ptr, nrows, ncols, ncols, Dynamic::new(1))
+    /// };
+    /// assert_eq!(m, Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw_parts_generic(
+        ptr: *const T,
+        nrows: R,
+        ncols: C,
+        rstride: RStride,
+        cstride: CStride,
+    ) -> Self {
+        let data = SliceStorage::from_raw_parts(ptr, (nrows, ncols), (rstride, cstride));
+        Self::from_data(data)
+    }
 }
 
 impl<'a, T: Scalar, R: Dim, C: Dim> MatrixSlice<'a, T, R, C> {
@@ -215,6 +255,51 @@ impl<'a, T: Scalar, R: Dim, C: Dim, RStride: Dim, CStride: Dim>
             Self::from_slice_with_strides_generic_unchecked(data, 0, nrows, ncols, rstride, cstride)
         }
     }
+
+    /// Creates a mutable matrix slice from a raw pointer, with dimensions and strides specified by
+    /// generic types instances.
+    ///
+    /// The component `(i, j)` of the slice is located at `ptr.add(i * rstride + j * cstride)`.
+    /// This allows to operate in-place on memory owned by foreign code, e.g., a buffer allocated
+    /// by a C library or GPU staging memory. Prefer `from_slice_with_strides_generic` whenever
+    /// the buffer is available as a mutable Rust slice.
+    ///
+    /// # Safety
+    /// - `ptr` must be non-null and properly aligned, even if the slice is empty.
+    /// - Every component `(i, j)` of the slice must be located within a single allocated object,
+    /// and be initialized.
+    /// - The dimensions and strides must not result in two distinct components `(i, j)` sharing
+    /// the same memory location.
+    /// - The memory must not be accessed through any other pointer for the whole lifetime `'a`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrixSliceMut, Dynamic};
+    /// let mut buffer: Box<[f32]> = Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let ptr: *mut f32 = buffer.as_mut_ptr();
+    ///
+    /// {
+    ///     // View the buffer as a row-major 2x3 matrix, and scale its first column in-place.
+    ///     let (nrows, ncols) = (Dynamic::new(2), Dynamic::new(3));
+    ///     let mut m = unsafe {
+    ///         DMatrixSliceMut::from_raw_parts_generic(ptr, nrows, ncols, ncols, Dynamic::new(1))
+    ///     };
+    ///     m.column_mut(0).scale_mut(10.0);
+    /// }
+    ///
+    /// assert_eq!(&buffer[..], &[10.0, 2.0, 3.0, 40.0, 5.0, 6.0]);
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw_parts_generic(
+        ptr: *mut T,
+        nrows: R,
+        ncols: C,
+        rstride: RStride,
+        cstride: CStride,
+    ) -> Self {
+        let data = SliceStorageMut::from_raw_parts(ptr, (nrows, ncols), (rstride, cstride));
+        Self::from_data(data)
+    }
 }
 
 impl<'a, T: Scalar, R: Dim, C: Dim> MatrixSliceMutMN<'a, T, R, C> {
@@ -0,0 +1,420 @@
+//! Configurable formatting of matrices, and their conversion to LaTeX or Markdown.
+//!
+//! See `Matrix::display`, `Matrix::to_latex`, and `Matrix::to_markdown_table` for details.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use std::fmt;
+
+use crate::base::dimension::Dim;
+use crate::base::storage::RawStorage;
+use crate::base::{Matrix, Scalar};
+
+/// The notation used to format the components of a matrix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Notation {
+    /// The components are formatted with `{}` (or `{:.N}` if a precision is set).
+    Fixed,
+    /// The components are formatted with `{:e}` (or `{:.Ne}` if a precision is set).
+    Scientific,
+}
+
+/// The alignment of the components of a matrix within their column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    /// The components are aligned to the left of their column.
+    Left,
+    /// The components are aligned to the right of their column.
+    Right,
+    /// The components are centered within their column.
+    Center,
+}
+
+/// The LaTeX environment used by `Matrix::to_latex`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LatexFlavor {
+    /// The `bmatrix` environment, with square brackets.
+    BMatrix,
+    /// The `pmatrix` environment, with parentheses.
+    PMatrix,
+}
+
+impl LatexFlavor {
+    fn environment(self) -> &'static str {
+        match self {
+            LatexFlavor::BMatrix => "bmatrix",
+            LatexFlavor::PMatrix => "pmatrix",
+        }
+    }
+}
+
+/// A wrapper formatting a matrix with configurable options.
+///
+/// This is created by `Matrix::display`, and is meant to be used with `format!`, `println!`, etc.
+#[derive(Debug)]
+pub struct MatrixDisplay<'a, T, R: Dim, C: Dim, S> {
+    matrix: &'a Matrix<T, R, C, S>,
+    precision: Option<usize>,
+    notation: Notation,
+    alignment: Alignment,
+    max_rows: Option<usize>,
+    max_cols: Option<usize>,
+    index_labels: bool,
+}
+
+impl<'a, T, R: Dim, C: Dim, S> MatrixDisplay<'a, T, R, C, S> {
+    /// Sets the number of digits displayed after the decimal point.
+    ///
+    /// If no precision is set, the precision of the formatter (e.g. `{:.3}`) is used, if any.
+    #[inline]
+    #[must_use]
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the notation used to format the components.
+    #[inline]
+    #[must_use]
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Formats the components in scientific notation.
+    ///
+    /// This is a shorthand for `.notation(Notation::Scientific)`.
+    #[inline]
+    #[must_use]
+    pub fn scientific(self) -> Self {
+        self.notation(Notation::Scientific)
+    }
+
+    /// Sets the alignment of the components within their column.
+    #[inline]
+    #[must_use]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the maximum number of rows displayed.
+    ///
+    /// If the matrix has more rows, only its first and last rows are displayed, separated
+    /// by a row of ellipses.
+    #[inline]
+    #[must_use]
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Sets the maximum number of columns displayed.
+    ///
+    /// If the matrix has more columns, only its first and last columns are displayed, separated
+    /// by a column of ellipses.
+    #[inline]
+    #[must_use]
+    pub fn max_cols(mut self, max_cols: usize) -> Self {
+        self.max_cols = Some(max_cols);
+        self
+    }
+
+    /// Sets whether the row and column indices are displayed alongside the matrix.
+    #[inline]
+    #[must_use]
+    pub fn index_labels(mut self, index_labels: bool) -> Self {
+        self.index_labels = index_labels;
+        self
+    }
+}
+
+/// The indices of the rows (or columns) to display, where `None` stands for the hidden ones.
+fn displayed_indices(len: usize, max: Option<usize>) -> Vec<Option<usize>> {
+    match max {
+        Some(max) if len > max => {
+            let tail = max / 2;
+            let head = max - tail;
+            (0..head)
+                .map(Some)
+                .chain(std::iter::once(None))
+                .chain((len - tail..len).map(Some))
+                .collect()
+        }
+        _ => (0..len).map(Some).collect(),
+    }
+}
+
+fn write_aligned(
+    f: &mut fmt::Formatter<'_>,
+    s: &str,
+    width: usize,
+    alignment: Alignment,
+) -> fmt::Result {
+    match alignment {
+        Alignment::Left => write!(f, "{:<1$}", s, width),
+        Alignment::Right => write!(f, "{:>1$}", s, width),
+        Alignment::Center => write!(f, "{:^1$}", s, width),
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S> fmt::Display for MatrixDisplay<'a, T, R, C, S>
+where
+    T: Scalar + fmt::Display + fmt::LowerExp,
+    S: RawStorage<T, R, C>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (nrows, ncols) = self.matrix.shape();
+
+        if nrows == 0 || ncols == 0 {
+            return write!(f, "[ ]");
+        }
+
+        let precision = self.precision.or_else(|| f.precision());
+        let format_value = |val: &T| match (self.notation, precision) {
+            (Notation::Fixed, None) => format!("{}", val),
+            (Notation::Fixed, Some(p)) => format!("{:.1$}", val, p),
+            (Notation::Scientific, None) => format!("{:e}", val),
+            (Notation::Scientific, Some(p)) => format!("{:.1$e}", val, p),
+        };
+
+        let rows = displayed_indices(nrows, self.max_rows);
+        let cols = displayed_indices(ncols, self.max_cols);
+
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|i| {
+                cols.iter()
+                    .map(|j| match (i, j) {
+                        (Some(i), Some(j)) => format_value(&self.matrix[(*i, *j)]),
+                        (Some(_), None) => "…".to_string(),
+                        (None, Some(_)) => "⋮".to_string(),
+                        (None, None) => "⋱".to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let col_widths: Vec<usize> = cols
+            .iter()
+            .enumerate()
+            .map(|(k, j)| {
+                let label_width = match j {
+                    Some(j) if self.index_labels => j.to_string().chars().count(),
+                    _ => 0,
+                };
+                cells
+                    .iter()
+                    .map(|row| row[k].chars().count())
+                    .fold(label_width, usize::max)
+            })
+            .collect();
+
+        // The width of the content between the two vertical bars.
+        let inner_width = col_widths.iter().map(|w| w + 1).sum::<usize>() + 1;
+        let label_width = if self.index_labels {
+            (nrows - 1).to_string().chars().count()
+        } else {
+            0
+        };
+        let margin = " ".repeat(label_width + 2);
+
+        writeln!(f)?;
+
+        if self.index_labels {
+            write!(f, "{} ", margin)?;
+            for (j, width) in cols.iter().zip(&col_widths) {
+                let label = j.map(|j| j.to_string()).unwrap_or_default();
+                write!(f, " ")?;
+                write_aligned(f, &label, *width, self.alignment)?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f, "{}┌{:>width$}┐", margin, "", width = inner_width)?;
+
+        for (i, row) in rows.iter().zip(&cells) {
+            match i {
+                Some(i) if self.index_labels => write!(f, "{:>1$}  │", i, label_width)?,
+                _ => write!(f, "{}│", margin)?,
+            }
+
+            for (cell, width) in row.iter().zip(&col_widths) {
+                write!(f, " ")?;
+                write_aligned(f, cell, *width, self.alignment)?;
+            }
+
+            writeln!(f, " │")?;
+        }
+
+        writeln!(f, "{}└{:>width$}┘", margin, "", width = inner_width)?;
+        writeln!(f)
+    }
+}
+
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Returns a wrapper formatting this matrix with configurable options.
+    ///
+    /// By default, the output is similar to the one of `Display`, except that each column is
+    /// only as wide as its widest component. The precision, notation, and alignment of the
+    /// components can be changed, and large matrices can be truncated to their first and last
+    /// rows and columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_fn(1000, 1000, |i, j| (i * 1000 + j) as f64);
+    /// let s = format!("{}", m.display().precision(1).max_rows(4).max_cols(4));
+    ///
+    /// let expected = "
+    ///   ┌                                       ┐
+    ///   │      0.0      1.0 …    998.0    999.0 │
+    ///   │   1000.0   1001.0 …   1998.0   1999.0 │
+    ///   │        ⋮        ⋮ ⋱        ⋮        ⋮ │
+    ///   │ 998000.0 998001.0 … 998998.0 998999.0 │
+    ///   │ 999000.0 999001.0 … 999998.0 999999.0 │
+    ///   └                                       ┘
+    ///
+    /// ";
+    /// assert_eq!(s, expected);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn display(&self) -> MatrixDisplay<'_, T, R, C, S> {
+        MatrixDisplay {
+            matrix: self,
+            precision: None,
+            notation: Notation::Fixed,
+            alignment: Alignment::Right,
+            max_rows: None,
+            max_cols: None,
+            index_labels: false,
+        }
+    }
+}
+
+impl<T: Scalar + fmt::Display, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    fn formatted_rows(&self, precision: Option<usize>) -> Vec<Vec<String>> {
+        (0..self.nrows())
+            .map(|i| {
+                (0..self.ncols())
+                    .map(|j| match precision {
+                        Some(p) => format!("{:.1$}", self[(i, j)], p),
+                        None => format!("{}", self[(i, j)]),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Formats this matrix as a LaTeX matrix environment (requires the `amsmath` package).
+    ///
+    /// If `precision` is set, each component is formatted with this number of digits after
+    /// the decimal point.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// # use nalgebra::base::display::LatexFlavor;
+    /// let m = Matrix2::new(1.0, 2.5, -3.0, 4.0);
+    ///
+    /// assert_eq!(
+    ///     m.to_latex(LatexFlavor::BMatrix, Some(1)),
+    ///     "\\begin{bmatrix}\n1.0 & 2.5 \\\\\n-3.0 & 4.0\n\\end{bmatrix}\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_latex(&self, flavor: LatexFlavor, precision: Option<usize>) -> String {
+        let rows: Vec<_> = self
+            .formatted_rows(precision)
+            .into_iter()
+            .map(|row| row.join(" & "))
+            .collect();
+
+        format!(
+            "\\begin{{{env}}}\n{}\n\\end{{{env}}}\n",
+            rows.join(" \\\\\n"),
+            env = flavor.environment()
+        )
+    }
+
+    /// Formats this matrix as a Markdown table.
+    ///
+    /// Since Markdown tables require a header, the first row of the table contains the column
+    /// indices. If `precision` is set, each component is formatted with this number of digits
+    /// after the decimal point.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    ///
+    /// assert_eq!(
+    ///     m.to_markdown_table(None),
+    ///     "| 0 | 1 | 2 |\n|---|---|---|\n| 1 | 2 | 3 |\n| 4 | 5 | 6 |\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_markdown_table(&self, precision: Option<usize>) -> String {
+        let header: Vec<_> = (0..self.ncols()).map(|j| j.to_string()).collect();
+        let separator = vec!["---"; self.ncols()];
+        let mut table = format!("| {} |\n|{}|\n", header.join(" | "), separator.join("|"));
+
+        for row in self.formatted_rows(precision) {
+            table.push_str(&format!("| {} |\n", row.join(" | ")));
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn index_labels_and_scientific() {
+        let m = crate::Matrix2x3::new(1.0, 20.0, 300.0, 4.0, 5.0, 6.0);
+        let s = format!(
+            "{}",
+            m.display().precision(1).scientific().index_labels(true)
+        );
+        let expected = "
+         0     1     2
+   ┌                   ┐
+0  │ 1.0e0 2.0e1 3.0e2 │
+1  │ 4.0e0 5.0e0 6.0e0 │
+   └                   ┘
+
+";
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn left_alignment_and_formatter_precision() {
+        let m = crate::Vector3::new(1.0, -10.0, 100.0);
+        let s = format!(
+            "{:.2}",
+            m.display().alignment(super::Alignment::Left).max_rows(2)
+        );
+        let expected = "
+  ┌        ┐
+  │ 1.00   │
+  │ ⋮      │
+  │ 100.00 │
+  └        ┘
+
+";
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn latex_pmatrix_vector() {
+        let v = crate::Vector3::new(1, 2, 3);
+        let expected = "\\begin{pmatrix}\n1 \\\\\n2 \\\\\n3\n\\end{pmatrix}\n";
+        assert_eq!(v.to_latex(super::LatexFlavor::PMatrix, None), expected);
+    }
+}
@@ -2,15 +2,27 @@ use num::{One, Zero};
 use std::cmp;
 #[cfg(any(feature = "std", feature = "alloc"))]
 use std::iter::ExactSizeIterator;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use std::mem;
 use std::ptr;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
 use crate::base::allocator::{Allocator, Reallocator};
 use crate::base::constraint::{DimEq, SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::base::dimension::Dynamic;
-use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, U1};
+use crate::base::dimension::{
+    Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimMul, DimProd, DimSub, DimSum, U1,
+};
 use crate::base::storage::{RawStorage, RawStorageMut, ReshapableStorage};
-use crate::base::{DefaultAllocator, Matrix, OMatrix, RowVector, Scalar, Vector};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::{DMatrix, DMatrixSlice, DMatrixSliceMut, DVector};
+use crate::base::{
+    DefaultAllocator, Matrix, MatrixSlice, MatrixSliceMut, OMatrix, OVector, RowVector, Scalar,
+    SliceStorage, SliceStorageMut, Vector,
+};
 use crate::{Storage, UninitMatrix};
 use std::mem::MaybeUninit;
 
@@ -332,6 +344,101 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
         }
         // Otherwise do nothing.
     }
+
+    /// Reverses the order of the rows of this matrix in-place.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    /// let mut m = Matrix3x2::new(1, 2,
+    ///                            3, 4,
+    ///                            5, 6);
+    /// m.reverse_rows_mut();
+    /// assert_eq!(m, Matrix3x2::new(5, 6,
+    ///                              3, 4,
+    ///                              1, 2));
+    /// ```
+    #[inline]
+    pub fn reverse_rows_mut(&mut self) {
+        let nrows = self.nrows();
+
+        for i in 0..nrows / 2 {
+            self.swap_rows(i, nrows - i - 1);
+        }
+    }
+
+    /// Reverses the order of the columns of this matrix in-place.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let mut m = Matrix2x3::new(1, 2, 3,
+    ///                            4, 5, 6);
+    /// m.reverse_columns_mut();
+    /// assert_eq!(m, Matrix2x3::new(3, 2, 1,
+    ///                              6, 5, 4));
+    /// ```
+    #[inline]
+    pub fn reverse_columns_mut(&mut self) {
+        let ncols = self.ncols();
+
+        for j in 0..ncols / 2 {
+            self.swap_columns(j, ncols - j - 1);
+        }
+    }
+}
+
+/// # Reversed rows and columns
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Returns a copy of this matrix with its rows in reverse order.
+    ///
+    /// Matrix views cannot have negative strides, so this copies the components. Use
+    /// `.reverse_rows_mut()` to reverse the rows of a matrix in-place instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    /// let m = Matrix3x2::new(1, 2,
+    ///                        3, 4,
+    ///                        5, 6);
+    /// assert_eq!(m.rows_reversed(), Matrix3x2::new(5, 6,
+    ///                                              3, 4,
+    ///                                              1, 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rows_reversed(&self) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let last = nrows.value().saturating_sub(1);
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| self[(last - i, j)].clone())
+    }
+
+    /// Returns a copy of this matrix with its columns in reverse order.
+    ///
+    /// Matrix views cannot have negative strides, so this copies the components. Use
+    /// `.reverse_columns_mut()` to reverse the columns of a matrix in-place instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// assert_eq!(m.columns_reversed(), Matrix2x3::new(3, 2, 1,
+    ///                                                 6, 5, 4));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn columns_reversed(&self) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let last = ncols.value().saturating_sub(1);
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| self[(i, last - j)].clone())
+    }
 }
 
 /*
@@ -356,7 +463,9 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         self.remove_fixed_columns::<1>(i)
     }
 
-    /// Removes all columns in `indices`   
+    /// Removes all columns in `indices`.
+    ///
+    /// Out-of-bounds and duplicate indices are ignored.
     #[cfg(any(feature = "std", feature = "alloc"))]
     pub fn remove_columns_at(self, indices: &[usize]) -> OMatrix<T, R, Dynamic>
     where
@@ -365,10 +474,11 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     {
         let mut m = self.into_owned();
         let (nrows, ncols) = m.shape_generic();
+        let removed = removal_mask(ncols.value(), indices);
         let mut offset: usize = 0;
         let mut target: usize = 0;
         while offset + target < ncols.value() {
-            if indices.contains(&(target + offset)) {
+            if removed[target + offset] {
                 // Safety: the resulting pointer is within range.
                 let col_ptr = unsafe { m.data.ptr_mut().add((target + offset) * nrows.value()) };
                 // Drop every element in the column we are about to overwrite.
@@ -407,7 +517,9 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         }
     }
 
-    /// Removes all rows in `indices`   
+    /// Removes all rows in `indices`.
+    ///
+    /// Out-of-bounds and duplicate indices are ignored.
     #[cfg(any(feature = "std", feature = "alloc"))]
     pub fn remove_rows_at(self, indices: &[usize]) -> OMatrix<T, Dynamic, C>
     where
@@ -416,10 +528,12 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     {
         let mut m = self.into_owned();
         let (nrows, ncols) = m.shape_generic();
+        let removed = removal_mask(nrows.value(), indices);
+        let nremoved = removed.iter().filter(|r| **r).count();
         let mut offset: usize = 0;
         let mut target: usize = 0;
         while offset + target < nrows.value() * ncols.value() {
-            if indices.contains(&((target + offset) % nrows.value())) {
+            if removed[(target + offset) % nrows.value()] {
                 // Safety: the resulting pointer is within range.
                 unsafe {
                     let elt_ptr = m.data.ptr_mut().add(target + offset);
@@ -447,7 +561,7 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         //         be assumed to be initialized.
         unsafe {
             let new_data = DefaultAllocator::reallocate_copy(
-                nrows.sub(Dynamic::from_usize(offset / ncols.value())),
+                nrows.sub(Dynamic::from_usize(nremoved)),
                 ncols,
                 m.data,
             );
@@ -1015,6 +1129,483 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     }
 }
 
+/// # Reshaping views
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Reinterprets `self` as a matrix slice with dimensions `new_nrows × new_ncols`, without
+    /// copying its components.
+    ///
+    /// The arrangement of the components is the same as with `Matrix::reshape_generic`. Returns
+    /// `None` if the components of `self` are not contiguous in memory, e.g., if `self` is a
+    /// slice of some rows of a larger matrix. In that case, `Matrix::reshape_owned_generic` can
+    /// be used to reshape a copy of `self` instead.
+    ///
+    /// # Panics
+    /// Panics if the number of elements of `self` differs from `new_nrows × new_ncols`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Const, Matrix2x3, Matrix3x2};
+    /// let m = Matrix3x2::new(1, 4, 2, 5, 3, 6);
+    /// let reshaped = m.reshape_slice_generic(Const::<2>, Const::<3>).unwrap();
+    /// assert_eq!(reshaped, Matrix2x3::new(1, 3, 5, 2, 4, 6));
+    ///
+    /// // The rows of a matrix are not contiguous.
+    /// assert!(m.rows(0, 2).reshape_slice_generic(Const::<1>, Const::<4>).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reshape_slice_generic<R2: Dim, C2: Dim>(
+        &self,
+        new_nrows: R2,
+        new_ncols: C2,
+    ) -> Option<MatrixSlice<'_, T, R2, C2, U1, R2>> {
+        assert_reshape_len(self.shape(), new_nrows.value(), new_ncols.value());
+
+        if !self.data.is_contiguous() {
+            return None;
+        }
+
+        // Safety: the `len()` components of `self` are contiguous, and so are the components
+        //         of the slice, which has the same number of elements.
+        unsafe {
+            let data = SliceStorage::from_raw_parts(
+                self.as_ptr(),
+                (new_nrows, new_ncols),
+                (Const::<1>, new_nrows),
+            );
+            Some(Matrix::from_data_statically_unchecked(data))
+        }
+    }
+
+    /// Reshapes a copy of `self` such that it has dimensions `new_nrows × new_ncols`.
+    ///
+    /// Unlike `Matrix::reshape_slice_generic`, this works regardless of the layout of `self`
+    /// in memory. The arrangement of the components is the same as with
+    /// `Matrix::reshape_generic`.
+    ///
+    /// # Panics
+    /// Panics if the number of elements of `self` differs from `new_nrows × new_ncols`.
+    #[inline]
+    #[must_use]
+    pub fn reshape_owned_generic<R2: Dim, C2: Dim>(
+        &self,
+        new_nrows: R2,
+        new_ncols: C2,
+    ) -> OMatrix<T, R2, C2>
+    where
+        T: Scalar,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        assert_reshape_len(self.shape(), new_nrows.value(), new_ncols.value());
+        OMatrix::from_iterator_generic(new_nrows, new_ncols, self.iter().cloned())
+    }
+}
+
+impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// Reinterprets `self` as a mutable matrix slice with dimensions `new_nrows × new_ncols`,
+    /// without copying its components.
+    ///
+    /// Returns `None` if the components of `self` are not contiguous in memory. See
+    /// `Matrix::reshape_slice_generic` for details.
+    ///
+    /// # Panics
+    /// Panics if the number of elements of `self` differs from `new_nrows × new_ncols`.
+    #[inline]
+    #[must_use]
+    pub fn reshape_slice_mut_generic<R2: Dim, C2: Dim>(
+        &mut self,
+        new_nrows: R2,
+        new_ncols: C2,
+    ) -> Option<MatrixSliceMut<'_, T, R2, C2, U1, R2>> {
+        assert_reshape_len(self.shape(), new_nrows.value(), new_ncols.value());
+
+        if !self.data.is_contiguous() {
+            return None;
+        }
+
+        // Safety: see `reshape_slice_generic`.
+        unsafe {
+            let data = SliceStorageMut::from_raw_parts(
+                self.as_mut_ptr(),
+                (new_nrows, new_ncols),
+                (Const::<1>, new_nrows),
+            );
+            Some(Matrix::from_data_statically_unchecked(data))
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Reinterprets `self` as a dynamically-sized matrix slice with `new_nrows` rows and
+    /// `new_ncols` columns, without copying its components.
+    ///
+    /// Returns `None` if the components of `self` are not contiguous in memory. See
+    /// `Matrix::reshape_slice_generic` for details.
+    ///
+    /// # Panics
+    /// Panics if the number of elements of `self` differs from `new_nrows × new_ncols`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let m = DMatrix::from_fn(3, 4, |i, j| i + 3 * j);
+    /// let flat = m.reshape_slice(12, 1).unwrap();
+    /// assert_eq!(flat, DVector::from_fn(12, |i, _| i));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reshape_slice(&self, new_nrows: usize, new_ncols: usize) -> Option<DMatrixSlice<'_, T>> {
+        self.reshape_slice_generic(Dynamic::new(new_nrows), Dynamic::new(new_ncols))
+    }
+
+    /// Reshapes a copy of `self` such that it has `new_nrows` rows and `new_ncols` columns.
+    ///
+    /// See `Matrix::reshape_owned_generic` for details.
+    ///
+    /// # Panics
+    /// Panics if the number of elements of `self` differs from `new_nrows × new_ncols`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix3x4};
+    /// let m = Matrix3x4::from_fn(|i, j| i + 3 * j);
+    /// // The first two rows are not contiguous in memory.
+    /// let reshaped = m.rows(0, 2).reshape_owned(4, 2);
+    /// assert_eq!(reshaped, DMatrix::from_column_slice(4, 2, &[0, 1, 3, 4, 6, 7, 9, 10]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reshape_owned(&self, new_nrows: usize, new_ncols: usize) -> DMatrix<T>
+    where
+        T: Scalar,
+    {
+        self.reshape_owned_generic(Dynamic::new(new_nrows), Dynamic::new(new_ncols))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// Reinterprets `self` as a dynamically-sized mutable matrix slice with `new_nrows` rows and
+    /// `new_ncols` columns, without copying its components.
+    ///
+    /// Returns `None` if the components of `self` are not contiguous in memory. See
+    /// `Matrix::reshape_slice_generic` for details.
+    ///
+    /// # Panics
+    /// Panics if the number of elements of `self` differs from `new_nrows × new_ncols`.
+    #[inline]
+    #[must_use]
+    pub fn reshape_slice_mut(
+        &mut self,
+        new_nrows: usize,
+        new_ncols: usize,
+    ) -> Option<DMatrixSliceMut<'_, T>> {
+        self.reshape_slice_mut_generic(Dynamic::new(new_nrows), Dynamic::new(new_ncols))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar> DMatrix<T> {
+    /// Reshapes this matrix such that it has `new_nrows` rows and `new_ncols` columns, without
+    /// copying its components.
+    ///
+    /// This is a shorthand for `self.reshape_generic(Dynamic::new(new_nrows),
+    /// Dynamic::new(new_ncols))`.
+    ///
+    /// # Panics
+    /// Panics if the number of elements of `self` differs from `new_nrows × new_ncols`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    /// let reshaped = m.reshape(3, 2);
+    /// assert_eq!(reshaped, DMatrix::from_row_slice(3, 2, &[1, 5, 4, 3, 2, 6]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reshape(self, new_nrows: usize, new_ncols: usize) -> Self {
+        self.reshape_generic(Dynamic::new(new_nrows), Dynamic::new(new_ncols))
+    }
+}
+
+/// # Vectorization and block-diagonal matrices
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Stacks the columns of this matrix into a single column vector, i.e., computes `vec(self)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector6};
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// assert_eq!(m.vectorize(), Vector6::new(1, 4, 2, 5, 3, 6));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn vectorize(&self) -> OVector<T, DimProd<R, C>>
+    where
+        R: DimMul<C>,
+        DefaultAllocator: Allocator<T, DimProd<R, C>>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        self.reshape_owned_generic(nrows.mul(ncols), Const::<1>)
+    }
+
+    /// Builds the block-diagonal matrix with the blocks `self` and `rhs`, i.e., computes the
+    /// direct sum `self ⊕ rhs`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix1, Matrix2, Matrix3};
+    /// let a = Matrix2::new(1, 2,
+    ///                      3, 4);
+    /// let b = Matrix1::new(5);
+    ///
+    /// assert_eq!(a.direct_sum(&b), Matrix3::new(1, 2, 0,
+    ///                                           3, 4, 0,
+    ///                                           0, 0, 5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn direct_sum<R2, C2, S2>(
+        &self,
+        rhs: &Matrix<T, R2, C2, S2>,
+    ) -> OMatrix<T, DimSum<R, R2>, DimSum<C, C2>>
+    where
+        T: Zero,
+        R: DimAdd<R2>,
+        C: DimAdd<C2>,
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, DimSum<R, R2>, DimSum<C, C2>>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let (nrows2, ncols2) = rhs.shape_generic();
+        let mut res = Matrix::zeros_generic(nrows.add(nrows2), ncols.add(ncols2));
+
+        res.generic_slice_mut((0, 0), (nrows, ncols))
+            .copy_from(self);
+        res.generic_slice_mut((nrows.value(), ncols.value()), (nrows2, ncols2))
+            .copy_from(rhs);
+
+        res
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Stacks the components of the lower-triangular part (including the diagonal) of this square
+    /// matrix, column by column, into a vector of length `n * (n + 1) / 2`, i.e., computes the
+    /// half-vectorization `vech(self)`.
+    ///
+    /// For a symmetric matrix, this contains each distinct component exactly once.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DVector, Matrix3};
+    /// let m = Matrix3::new(1, 2, 3,
+    ///                      2, 4, 5,
+    ///                      3, 5, 6);
+    /// assert_eq!(m.vech(), DVector::from_vec(vec![1, 2, 3, 4, 5, 6]));
+    /// ```
+    #[must_use]
+    pub fn vech(&self) -> DVector<T> {
+        assert!(
+            self.is_square(),
+            "Unable to half-vectorize a non-square matrix."
+        );
+
+        let dim = self.nrows();
+        let components = (0..dim).flat_map(|j| (j..dim).map(move |i| self[(i, j)].clone()));
+        DVector::from_iterator(dim * (dim + 1) / 2, components)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar, D: Dim, S: RawStorage<T, D>> Vector<T, D, S> {
+    /// Rebuilds the matrix with `nrows` rows and `ncols` columns whose columns are stacked in
+    /// this vector, i.e., inverts `Matrix::vectorize`.
+    ///
+    /// # Panics
+    /// Panics if the length of `self` differs from `nrows × ncols`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Vector6};
+    /// let v = Vector6::new(1, 4, 2, 5, 3, 6);
+    /// let m = v.unvec(2, 3);
+    ///
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+    /// assert_eq!(m.vectorize(), v);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn unvec(&self, nrows: usize, ncols: usize) -> DMatrix<T> {
+        self.reshape_owned(nrows, ncols)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar + Zero> DMatrix<T> {
+    /// Builds the block-diagonal matrix with the given blocks on its diagonal, i.e., computes the
+    /// direct sum of all the blocks.
+    ///
+    /// The blocks do not have to be square.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let a = DMatrix::from_row_slice(2, 2, &[1, 2,
+    ///                                         3, 4]);
+    /// let b = DMatrix::from_row_slice(1, 3, &[5, 6, 7]);
+    /// let m = DMatrix::block_diag(&[a, b]);
+    ///
+    /// assert_eq!(m, DMatrix::from_row_slice(3, 5, &[1, 2, 0, 0, 0,
+    ///                                               3, 4, 0, 0, 0,
+    ///                                               0, 0, 5, 6, 7]));
+    /// ```
+    #[must_use]
+    pub fn block_diag<R: Dim, C: Dim, S: RawStorage<T, R, C>>(
+        blocks: &[Matrix<T, R, C, S>],
+    ) -> Self {
+        let nrows = blocks.iter().map(|b| b.nrows()).sum();
+        let ncols = blocks.iter().map(|b| b.ncols()).sum();
+        let mut res = Self::zeros(nrows, ncols);
+        let (mut i, mut j) = (0, 0);
+
+        for block in blocks {
+            res.generic_slice_mut((i, j), block.shape_generic())
+                .copy_from(block);
+            i += block.nrows();
+            j += block.ncols();
+        }
+
+        res
+    }
+}
+
+/// # Tiling and grids
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Builds the matrix made of `reps_rows × reps_cols` copies of this matrix, i.e., the block
+    /// matrix with `reps_rows` block rows and `reps_cols` block columns all equal to `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix1x2};
+    /// let m = Matrix1x2::new(1, 2);
+    ///
+    /// assert_eq!(m.tile(2, 3), DMatrix::from_row_slice(2, 6, &[1, 2, 1, 2, 1, 2,
+    ///                                                          1, 2, 1, 2, 1, 2]));
+    /// ```
+    #[must_use]
+    pub fn tile(&self, reps_rows: usize, reps_cols: usize) -> DMatrix<T> {
+        let (nrows, ncols) = self.shape();
+        DMatrix::from_fn(nrows * reps_rows, ncols * reps_cols, |i, j| {
+            self[(i % nrows, j % ncols)].clone()
+        })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar, D: Dim, S: RawStorage<T, D>> Vector<T, D, S> {
+    /// Builds the matrix with `n` rows all equal to the transpose of this vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Vector3};
+    /// let v = Vector3::new(1, 2, 3);
+    ///
+    /// assert_eq!(v.repeat_as_rows(2), DMatrix::from_row_slice(2, 3, &[1, 2, 3,
+    ///                                                                 1, 2, 3]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn repeat_as_rows(&self, n: usize) -> OMatrix<T, Dynamic, D>
+    where
+        DefaultAllocator: Allocator<T, Dynamic, D>,
+    {
+        Matrix::from_fn_generic(Dynamic::new(n), self.shape_generic().0, |_, j| {
+            self[j].clone()
+        })
+    }
+
+    /// Builds the matrix with `n` columns all equal to this vector.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Vector3};
+    /// let v = Vector3::new(1, 2, 3);
+    ///
+    /// assert_eq!(v.repeat_as_columns(2), DMatrix::from_row_slice(3, 2, &[1, 1,
+    ///                                                                    2, 2,
+    ///                                                                    3, 3]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn repeat_as_columns(&self, n: usize) -> OMatrix<T, D, Dynamic>
+    where
+        DefaultAllocator: Allocator<T, D, Dynamic>,
+    {
+        Matrix::from_fn_generic(self.shape_generic().0, Dynamic::new(n), |i, _| {
+            self[i].clone()
+        })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar> DMatrix<T> {
+    /// Builds the coordinate matrices of the grid with the abscissas `x` and the ordinates `y`.
+    ///
+    /// This returns the matrices `(xs, ys)` with `y.len()` rows and `x.len()` columns such that
+    /// `(xs[(i, j)], ys[(i, j)])` is the point `(x[j], y[i])`, i.e., every row of `xs` is a copy
+    /// of `x` and every column of `ys` is a copy of `y`. This is the same convention as
+    /// `meshgrid` in Matlab and NumPy, which makes it easy to evaluate a function on a grid with,
+    /// e.g., `xs.zip_map(&ys, f)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Vector2, Vector3};
+    /// let x = Vector3::new(0.0, 0.5, 1.0);
+    /// let y = Vector2::new(-1.0, 1.0);
+    /// let (xs, ys) = DMatrix::meshgrid(&x, &y);
+    ///
+    /// assert_eq!(xs, DMatrix::from_row_slice(2, 3, &[0.0, 0.5, 1.0,
+    ///                                                0.0, 0.5, 1.0]));
+    /// assert_eq!(ys, DMatrix::from_row_slice(2, 3, &[-1.0, -1.0, -1.0,
+    ///                                                1.0, 1.0, 1.0]));
+    /// assert_eq!(xs.zip_map(&ys, |x, y| x * y)[(1, 1)], 0.5);
+    /// ```
+    #[must_use]
+    pub fn meshgrid<D1: Dim, D2: Dim, S1, S2>(
+        x: &Vector<T, D1, S1>,
+        y: &Vector<T, D2, S2>,
+    ) -> (Self, Self)
+    where
+        S1: RawStorage<T, D1>,
+        S2: RawStorage<T, D2>,
+    {
+        let (nrows, ncols) = (y.len(), x.len());
+        let xs = Self::from_fn(nrows, ncols, |_, j| x[j].clone());
+        let ys = Self::from_fn(nrows, ncols, |i, _| y[i].clone());
+
+        (xs, ys)
+    }
+}
+
+fn assert_reshape_len((nrows, ncols): (usize, usize), new_nrows: usize, new_ncols: usize) {
+    assert_eq!(
+        nrows * ncols,
+        new_nrows * new_ncols,
+        "Reshape: the number of elements must remain unchanged."
+    );
+}
+
 /// # In-place resizing
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl<T: Scalar> OMatrix<T, Dynamic, Dynamic> {
@@ -1023,14 +1614,141 @@ impl<T: Scalar> OMatrix<T, Dynamic, Dynamic> {
     /// The values are copied such that `self[(i, j)] == result[(i, j)]`. If the result has more
     /// rows and/or columns than `self`, then the extra rows or columns are filled with `val`.
     ///
-    /// Defined only for owned fully-dynamic matrices, i.e., `DMatrix`.
-    pub fn resize_mut(&mut self, new_nrows: usize, new_ncols: usize, val: T)
-    where
-        DefaultAllocator: Reallocator<T, Dynamic, Dynamic, Dynamic, Dynamic>,
-    {
-        // TODO: avoid the clone.
-        *self = self.clone().resize(new_nrows, new_ncols, val);
+    /// Defined only for owned fully-dynamic matrices, i.e., `DMatrix`. The components are moved
+    /// within the existing buffer, which is reallocated only if the result has more components
+    /// than `self.capacity()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let mut m = DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    /// m.reserve(2);
+    /// let capacity = m.capacity();
+    ///
+    /// m.resize_mut(4, 2, 0);
+    /// assert_eq!(m, DMatrix::from_row_slice(4, 2, &[1, 2, 4, 5, 0, 0, 0, 0]));
+    /// assert_eq!(m.capacity(), capacity);
+    /// ```
+    pub fn resize_mut(&mut self, new_nrows: usize, new_ncols: usize, val: T) {
+        let (nrows, ncols) = self.shape();
+        let kept_ncols = cmp::min(ncols, new_ncols);
+        let mut data = self.take_vec();
+
+        data.truncate(nrows * kept_ncols);
+
+        if new_nrows < nrows {
+            let mut k = 0;
+            data.retain(|_| {
+                let keep = k % nrows < new_nrows;
+                k += 1;
+                keep
+            });
+        } else if new_nrows > nrows {
+            data.resize(new_nrows * kept_ncols, val.clone());
+
+            // Move the components to their new position, starting with the last one, so that
+            // only padding values get overwritten.
+            for j in (0..kept_ncols).rev() {
+                for i in (0..nrows).rev() {
+                    data.swap(j * new_nrows + i, j * nrows + i);
+                }
+            }
+        }
+
+        data.resize(new_nrows * new_ncols, val);
+        *self = Self::from_vec(new_nrows, new_ncols, data);
+    }
+
+    /// Removes all the rows in `indices` in-place, without reallocating.
+    ///
+    /// Out-of-bounds and duplicate indices are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let mut m = DMatrix::from_row_slice(4, 2, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    /// m.remove_rows_at_mut(&[3, 0]);
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 2, &[3, 4, 5, 6]));
+    /// ```
+    pub fn remove_rows_at_mut(&mut self, indices: &[usize]) {
+        let (nrows, ncols) = self.shape();
+        let removed = removal_mask(nrows, indices);
+        let nremoved = removed.iter().filter(|r| **r).count();
+        let mut data = self.take_vec();
+        let mut k = 0;
+
+        data.retain(|_| {
+            let keep = !removed[k % nrows];
+            k += 1;
+            keep
+        });
+
+        *self = Self::from_vec(nrows - nremoved, ncols, data);
     }
+
+    /// Removes all the columns in `indices` in-place, without reallocating.
+    ///
+    /// Out-of-bounds and duplicate indices are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let mut m = DMatrix::from_row_slice(2, 4, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    /// m.remove_columns_at_mut(&[1, 2, 1]);
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 2, &[1, 4, 5, 8]));
+    /// ```
+    pub fn remove_columns_at_mut(&mut self, indices: &[usize]) {
+        let (nrows, ncols) = self.shape();
+        let removed = removal_mask(ncols, indices);
+        let nremoved = removed.iter().filter(|r| **r).count();
+        let mut data = self.take_vec();
+        let mut k = 0;
+
+        data.retain(|_| {
+            let keep = !removed[k / nrows];
+            k += 1;
+            keep
+        });
+
+        *self = Self::from_vec(nrows, ncols - nremoved, data);
+    }
+
+    /// The number of components this matrix can hold without reallocating its buffer.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.data.as_vec().capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more components, so that growing this matrix
+    /// with `resize_mut` or `Extend` does not reallocate its buffer.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        // Safety: reserving capacity does not change the length of the vector.
+        unsafe { self.data.as_vec_mut().reserve(additional) }
+    }
+
+    /// Moves the buffer out of this matrix, leaving it empty.
+    fn take_vec(&mut self) -> Vec<T> {
+        mem::replace(self, Self::from_vec(0, 0, Vec::new()))
+            .data
+            .into()
+    }
+}
+
+/// The mask of the `len` rows or columns selected by `indices`, ignoring out-of-bounds ones.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn removal_mask(len: usize, indices: &[usize]) -> Vec<bool> {
+    let mut mask = Vec::new();
+    mask.resize(len, false);
+
+    for &i in indices {
+        if let Some(removed) = mask.get_mut(i) {
+            *removed = true;
+        }
+    }
+
+    mask
 }
 
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -5,6 +5,9 @@ use crate::base::{
     Const, Dim, DimDiff, DimName, DimSub, Dynamic, Matrix, MatrixSlice, MatrixSliceMut, Scalar, U1,
 };
 
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::fmt;
 use std::ops;
 
 // N.B.: Not a public trait!
@@ -476,6 +479,26 @@ pub trait MatrixIndexMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>>:
 ///                         4, 7,
 ///                         5, 8)));
 /// ```
+///
+/// ## Ranges and the `[]` operator
+/// The `[]` operator only accepts indices to individual elements, e.g., `matrix[(1, 2)]`.
+/// Indexing with ranges, e.g., `matrix[(0..2, 1..)]`, is not possible because
+/// `std::ops::Index` must return a reference to a value stored in the matrix, while a slice
+/// is a new value borrowing the matrix. Use the `index` and `index_mut` methods instead, which
+/// accept the same ranges as the `[]` syntax would:
+/// ```
+/// # use nalgebra::*;
+/// let mut matrix = Matrix3::new(0, 3, 6,
+///                               1, 4, 7,
+///                               2, 5, 8);
+///
+/// matrix.index_mut((0..2, 1..)).fill(0);
+///
+/// assert_eq!(matrix, Matrix3::new(0, 0, 0,
+///                                 1, 0, 0,
+///                                 2, 5, 8));
+/// assert_eq!(matrix.index((2, ..)), Matrix1x3::new(2, 5, 8));
+/// ```
 impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
     /// Produces a view of the data at the given index, or
     /// `None` if the index is out of bounds.
@@ -546,6 +569,203 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
     }
 }
 
+/// The error returned by the checked indexing methods when an index is out of bounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IndexError {
+    /// Some of the indexed rows are out of bounds.
+    RowsOutOfBounds {
+        /// The range of the indexed rows.
+        rows: ops::Range<usize>,
+        /// The number of rows of the matrix.
+        nrows: usize,
+    },
+    /// Some of the indexed columns are out of bounds.
+    ColumnsOutOfBounds {
+        /// The range of the indexed columns.
+        columns: ops::Range<usize>,
+        /// The number of columns of the matrix.
+        ncols: usize,
+    },
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexError::RowsOutOfBounds { rows, nrows } => write!(
+                f,
+                "the rows {:?} are out of bounds for a matrix with {} rows",
+                rows, nrows
+            ),
+            IndexError::ColumnsOutOfBounds { columns, ncols } => write!(
+                f,
+                "the columns {:?} are out of bounds for a matrix with {} columns",
+                columns, ncols
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for IndexError {}
+
+/// # Checked indexing
+/// These methods return an `IndexError` describing the violated bound instead of panicking
+/// when an index is out of bounds. This makes them suitable for indices coming from untrusted
+/// data. Use `get` and `get_mut` to access individual elements.
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    fn check_rows(&self, first_row: usize, nrows: usize) -> Result<(), IndexError> {
+        let end = first_row.saturating_add(nrows);
+
+        if end > self.nrows() {
+            Err(IndexError::RowsOutOfBounds {
+                rows: first_row..end,
+                nrows: self.nrows(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_columns(&self, first_col: usize, ncols: usize) -> Result<(), IndexError> {
+        let end = first_col.saturating_add(ncols);
+
+        if end > self.ncols() {
+            Err(IndexError::ColumnsOutOfBounds {
+                columns: first_col..end,
+                ncols: self.ncols(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sets the element at the given row and column to `val`, or returns an error if it is out
+    /// of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// # use nalgebra::base::indexing::IndexError;
+    /// let mut m = Matrix2x3::<f64>::zeros();
+    ///
+    /// assert_eq!(m.try_set((1, 2), 5.0), Ok(()));
+    /// assert_eq!(m[(1, 2)], 5.0);
+    /// assert_eq!(
+    ///     m.try_set((2, 0), 1.0),
+    ///     Err(IndexError::RowsOutOfBounds { rows: 2..3, nrows: 2 })
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_set(&mut self, (irow, icol): (usize, usize), val: T) -> Result<(), IndexError>
+    where
+        S: RawStorageMut<T, R, C>,
+    {
+        self.check_rows(irow, 1)?;
+        self.check_columns(icol, 1)?;
+        // Safety: the indices were checked above.
+        unsafe { *self.data.get_unchecked_mut(irow, icol) = val };
+        Ok(())
+    }
+
+    /// Slices this matrix starting at its component `(irow, icol)` and with `(nrows, ncols)`
+    /// consecutive elements, or returns an error if the slice is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// # use nalgebra::base::indexing::IndexError;
+    /// let m = Matrix3::new(1, 2, 3,
+    ///                      4, 5, 6,
+    ///                      7, 8, 9);
+    ///
+    /// assert_eq!(m.try_slice((1, 1), (2, 2)), Ok(m.slice((1, 1), (2, 2))));
+    /// assert_eq!(
+    ///     m.try_slice((1, 2), (2, 2)),
+    ///     Err(IndexError::ColumnsOutOfBounds { columns: 2..4, ncols: 3 })
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_slice(
+        &self,
+        start: (usize, usize),
+        shape: (usize, usize),
+    ) -> Result<MatrixSlice<'_, T, Dynamic, Dynamic, S::RStride, S::CStride>, IndexError> {
+        self.check_rows(start.0, shape.0)?;
+        self.check_columns(start.1, shape.1)?;
+        Ok(self.slice(start, shape))
+    }
+
+    /// Extracts from this matrix `nrows` consecutive rows, or returns an error if some of them
+    /// are out of bounds.
+    #[inline]
+    pub fn try_rows(
+        &self,
+        first_row: usize,
+        nrows: usize,
+    ) -> Result<MatrixSlice<'_, T, Dynamic, C, S::RStride, S::CStride>, IndexError> {
+        self.check_rows(first_row, nrows)?;
+        Ok(self.rows(first_row, nrows))
+    }
+
+    /// Extracts from this matrix `ncols` consecutive columns, or returns an error if some of
+    /// them are out of bounds.
+    #[inline]
+    pub fn try_columns(
+        &self,
+        first_col: usize,
+        ncols: usize,
+    ) -> Result<MatrixSlice<'_, T, R, Dynamic, S::RStride, S::CStride>, IndexError> {
+        self.check_columns(first_col, ncols)?;
+        Ok(self.columns(first_col, ncols))
+    }
+
+    /// Mutably slices this matrix starting at its component `(irow, icol)` and with
+    /// `(nrows, ncols)` consecutive elements, or returns an error if the slice is out of bounds.
+    #[inline]
+    pub fn try_slice_mut(
+        &mut self,
+        start: (usize, usize),
+        shape: (usize, usize),
+    ) -> Result<MatrixSliceMut<'_, T, Dynamic, Dynamic, S::RStride, S::CStride>, IndexError>
+    where
+        S: RawStorageMut<T, R, C>,
+    {
+        self.check_rows(start.0, shape.0)?;
+        self.check_columns(start.1, shape.1)?;
+        Ok(self.slice_mut(start, shape))
+    }
+
+    /// Mutably extracts from this matrix `nrows` consecutive rows, or returns an error if some
+    /// of them are out of bounds.
+    #[inline]
+    pub fn try_rows_mut(
+        &mut self,
+        first_row: usize,
+        nrows: usize,
+    ) -> Result<MatrixSliceMut<'_, T, Dynamic, C, S::RStride, S::CStride>, IndexError>
+    where
+        S: RawStorageMut<T, R, C>,
+    {
+        self.check_rows(first_row, nrows)?;
+        Ok(self.rows_mut(first_row, nrows))
+    }
+
+    /// Mutably extracts from this matrix `ncols` consecutive columns, or returns an error if
+    /// some of them are out of bounds.
+    #[inline]
+    pub fn try_columns_mut(
+        &mut self,
+        first_col: usize,
+        ncols: usize,
+    ) -> Result<MatrixSliceMut<'_, T, R, Dynamic, S::RStride, S::CStride>, IndexError>
+    where
+        S: RawStorageMut<T, R, C>,
+    {
+        self.check_columns(first_col, ncols)?;
+        Ok(self.columns_mut(first_col, ncols))
+    }
+}
+
 // EXTRACT A SINGLE ELEMENT BY 1D LINEAR ADDRESS
 
 impl<'a, T, R, C, S> MatrixIndex<'a, T, R, C, S> for usize
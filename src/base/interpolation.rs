@@ -62,10 +62,15 @@ impl<T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul, D: Dim, S: Stor
 impl<T: RealField, D: Dim, S: Storage<T, D>> Unit<Vector<T, D, S>> {
     /// Computes the spherical linear interpolation between two unit vectors.
     ///
+    /// If the two vectors are collinear with opposite directions, the interpolation follows an
+    /// arbitrary half great circle between them. In dimension 1, there is no such path, and
+    /// `self` is returned.
+    ///
     /// # Examples:
     ///
     /// ```
-    /// # use nalgebra::{Unit, Vector2};
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Unit, Vector2, Vector3};
     ///
     /// let v1 = Unit::new_normalize(Vector2::new(1.0, 2.0));
     /// let v2 = Unit::new_normalize(Vector2::new(2.0, -3.0));
@@ -73,6 +78,11 @@ impl<T: RealField, D: Dim, S: Storage<T, D>> Unit<Vector<T, D, S>> {
     /// let v = v1.slerp(&v2, 1.0);
     ///
     /// assert_eq!(v, v2);
+    ///
+    /// // Halfway between opposite vectors is orthogonal to both of them.
+    /// let v1 = Vector3::x_axis();
+    /// let v = v1.slerp(&-v1, 0.5);
+    /// assert_relative_eq!(v.dot(&v1), 0.0, epsilon = 1.0e-15);
     /// ```
     #[must_use]
     pub fn slerp<S2: Storage<T, D>>(
@@ -83,9 +93,71 @@ impl<T: RealField, D: Dim, S: Storage<T, D>> Unit<Vector<T, D, S>> {
     where
         DefaultAllocator: Allocator<T, D>,
     {
-        // TODO: the result is wrong when self and rhs are collinear with opposite direction.
-        self.try_slerp(rhs, t, T::default_epsilon())
-            .unwrap_or_else(|| Unit::new_unchecked(self.clone_owned()))
+        self.try_slerp(rhs, t.clone(), T::default_epsilon())
+            .unwrap_or_else(|| {
+                if self.dot(rhs) >= T::zero() {
+                    return Unit::new_unchecked(self.clone_owned());
+                }
+
+                // The vectors are opposite: rotate `self` towards any vector orthogonal to it,
+                // built from the canonical basis vector the least aligned with `self`.
+                let i = self.iamin();
+                let mut ortho = self.scale(-self[i].clone());
+                ortho[i] += T::one();
+
+                match Unit::try_new(ortho, T::default_epsilon()) {
+                    Some(ortho) => {
+                        let (sin, cos) = (t * T::pi()).sin_cos();
+                        let mut res = self.scale(cos);
+                        res.axpy(sin, &*ortho, T::one());
+                        Unit::new_unchecked(res)
+                    }
+                    None => Unit::new_unchecked(self.clone_owned()),
+                }
+            })
+    }
+
+    /// Computes the unit vector obtained by rotating `self` towards `rhs` along the shortest
+    /// great circle, by an angle of at most `max_angle`.
+    ///
+    /// Returns `rhs` if it is within `max_angle` of `self`. This is typically called at each
+    /// frame of an animation to turn smoothly towards a target direction at a bounded angular
+    /// speed. `max_angle` should be non-negative. See `.slerp` for the case where `rhs` is
+    /// opposite to `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// # use nalgebra::{Unit, Vector3};
+    /// let v1 = Vector3::x_axis();
+    /// let v2 = Vector3::y_axis();
+    ///
+    /// let v = v1.slerp_clamped_angle(&v2, 0.1);
+    /// assert_relative_eq!(v.angle(&v1), 0.1, epsilon = 1.0e-10);
+    /// assert_relative_eq!(v.angle(&v2), FRAC_PI_2 - 0.1, epsilon = 1.0e-10);
+    ///
+    /// assert_eq!(v1.slerp_clamped_angle(&v2, 2.0), v2);
+    /// ```
+    #[must_use]
+    pub fn slerp_clamped_angle<S2: Storage<T, D>>(
+        &self,
+        rhs: &Unit<Vector<T, D, S2>>,
+        max_angle: T,
+    ) -> Unit<OVector<T, D>>
+    where
+        DefaultAllocator: Allocator<T, D>,
+    {
+        // This formula is accurate for all angles, unlike the `acos` of the dot product.
+        let diff = self.as_ref() - rhs.as_ref();
+        let sum = self.as_ref() + rhs.as_ref();
+        let angle = diff.norm().atan2(sum.norm()) * crate::convert(2.0);
+
+        if angle <= max_angle {
+            Unit::new_unchecked(rhs.clone_owned())
+        } else {
+            self.slerp(rhs, max_angle / angle)
+        }
     }
 
     /// Computes the spherical linear interpolation between two unit vectors.
@@ -109,12 +181,21 @@ impl<T: RealField, D: Dim, S: Storage<T, D>> Unit<Vector<T, D, S>> {
             return Some(Unit::new_unchecked(self.clone_owned()));
         }
 
-        let hang = c_hang.clone().acos();
-        let s_hang = (T::one() - c_hang.clone() * c_hang).sqrt();
+        // The `acos` of the dot product is inaccurate, or even NaN, for almost opposite
+        // vectors, whose dot product may be rounded below -1.
+        let diff = self.as_ref() - rhs.as_ref();
+        let sum = self.as_ref() + rhs.as_ref();
+        let hang = diff.norm().atan2(sum.norm()) * crate::convert(2.0);
+        let s_hang = hang.clone().sin();
 
         // TODO: what if s_hang is 0.0 ? The result is not well-defined.
         if relative_eq!(s_hang, T::zero(), epsilon = epsilon) {
-            None
+            // The vectors are either equal up to rounding errors, or opposite.
+            if hang < T::frac_pi_2() {
+                Some(Unit::new_unchecked(self.clone_owned()))
+            } else {
+                None
+            }
         } else {
             let ta = ((T::one() - t.clone()) * hang.clone()).sin() / s_hang.clone();
             let tb = (t * hang).sin() / s_hang;
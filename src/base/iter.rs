@@ -3,10 +3,11 @@
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::mem;
+use std::ops::Range;
 
-use crate::base::dimension::{Dim, U1};
+use crate::base::dimension::{Dim, DimName, U1};
 use crate::base::storage::{RawStorage, RawStorageMut};
-use crate::base::{Matrix, MatrixSlice, MatrixSliceMut, Scalar};
+use crate::base::{Matrix, MatrixSlice, MatrixSliceMut, Scalar, SliceStorageMut};
 
 macro_rules! iterator {
     (struct $Name:ident for $Storage:ident.$ptr: ident -> $Ptr:ty, $Ref:ty, $SRef: ty) => {
@@ -296,12 +297,31 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ExactSizeIte
 /// An iterator through the columns of a matrix.
 pub struct ColumnIter<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> {
     mat: &'a Matrix<T, R, C, S>,
-    curr: usize,
+    range: Range<usize>,
 }
 
 impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> ColumnIter<'a, T, R, C, S> {
     pub(crate) fn new(mat: &'a Matrix<T, R, C, S>) -> Self {
-        ColumnIter { mat, curr: 0 }
+        ColumnIter {
+            mat,
+            range: 0..mat.ncols(),
+        }
+    }
+
+    /// Splits this iterator into two iterators, respectively through the `index` first remaining
+    /// columns, and through the other ones.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.range.start + index;
+        let left = ColumnIter {
+            mat: self.mat,
+            range: self.range.start..mid,
+        };
+        let right = ColumnIter {
+            mat: self.mat,
+            range: mid..self.range.end,
+        };
+        (left, right)
     }
 }
 
@@ -310,60 +330,99 @@ impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> Iterator for ColumnIter
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.mat.ncols() {
-            let res = self.mat.column(self.curr);
-            self.curr += 1;
-            Some(res)
-        } else {
-            None
-        }
+        let mat = self.mat;
+        self.range.next().map(|j| mat.column(j))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (
-            self.mat.ncols() - self.curr,
-            Some(self.mat.ncols() - self.curr),
-        )
+        (self.range.len(), Some(self.range.len()))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.mat.ncols() - self.curr
+        self.range.len()
     }
 }
 
-impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> ExactSizeIterator
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> DoubleEndedIterator
+    for ColumnIter<'a, T, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mat = self.mat;
+        self.range.next_back().map(|j| mat.column(j))
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> ExactSizeIterator
     for ColumnIter<'a, T, R, C, S>
 {
     #[inline]
     fn len(&self) -> usize {
-        self.mat.ncols() - self.curr
+        self.range.len()
     }
 }
 
 /// An iterator through the mutable columns of a matrix.
 #[derive(Debug)]
 pub struct ColumnIterMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> {
-    mat: *mut Matrix<T, R, C, S>,
-    curr: usize,
+    // NOTE: we don't keep a `&mut Matrix` here so that the columns can be built without creating
+    // aliasing mutable references to the whole matrix (which matters when the iterator is split
+    // and used by several threads).
+    ptr: *mut T,
+    nrows: R,
+    strides: (S::RStride, S::CStride),
+    range: Range<usize>,
     phantom: PhantomData<&'a mut Matrix<T, R, C, S>>,
 }
 
 impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ColumnIterMut<'a, T, R, C, S> {
     pub(crate) fn new(mat: &'a mut Matrix<T, R, C, S>) -> Self {
+        let (nrows, ncols) = mat.data.shape();
         ColumnIterMut {
-            mat,
-            curr: 0,
+            ptr: mat.data.ptr_mut(),
+            nrows,
+            strides: mat.data.strides(),
+            range: 0..ncols.value(),
             phantom: PhantomData,
         }
     }
 
-    fn ncols(&self) -> usize {
-        unsafe { (*self.mat).ncols() }
+    /// Splits this iterator into two iterators, respectively through the `index` first remaining
+    /// columns, and through the other ones.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.range.start + index;
+        let left = ColumnIterMut {
+            range: self.range.start..mid,
+            ..self
+        };
+        let right = ColumnIterMut {
+            range: mid..self.range.end,
+            ..self
+        };
+        (left, right)
+    }
+
+    /// The `j`-th column of the matrix.
+    ///
+    /// # Safety
+    /// `j` must be a valid column index, and the column must not be borrowed elsewhere.
+    #[inline]
+    unsafe fn column(&self, j: usize) -> MatrixSliceMut<'a, T, R, U1, S::RStride, S::CStride> {
+        let ptr = self.ptr.add(j * self.strides.1.value());
+        let data = SliceStorageMut::from_raw_parts(ptr, (self.nrows, U1::name()), self.strides);
+        Matrix::from_data_statically_unchecked(data)
     }
 }
 
+// Safety: the iterator yields disjoint mutable columns, which are `Send` if `T` is.
+unsafe impl<'a, T: Send, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Send
+    for ColumnIterMut<'a, T, R, C, S>
+{
+}
+
 impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Iterator
     for ColumnIterMut<'a, T, R, C, S>
 {
@@ -371,31 +430,36 @@ impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Iterator
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.ncols() {
-            let res = unsafe { (*self.mat).column_mut(self.curr) };
-            self.curr += 1;
-            Some(res)
-        } else {
-            None
-        }
+        // Safety: each column index is yielded only once.
+        self.range.next().map(|j| unsafe { self.column(j) })
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.ncols() - self.curr, Some(self.ncols() - self.curr))
+        (self.range.len(), Some(self.range.len()))
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.ncols() - self.curr
+        self.range.len()
     }
 }
 
-impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ExactSizeIterator
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> DoubleEndedIterator
+    for ColumnIterMut<'a, T, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Safety: each column index is yielded only once.
+        self.range.next_back().map(|j| unsafe { self.column(j) })
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ExactSizeIterator
     for ColumnIterMut<'a, T, R, C, S>
 {
     #[inline]
     fn len(&self) -> usize {
-        self.ncols() - self.curr
+        self.range.len()
     }
 }
@@ -0,0 +1,362 @@
+use num::{One, Zero};
+use num_complex::Complex;
+use std::ops::Mul;
+
+use simba::scalar::{ClosedAdd, ClosedMul};
+
+use crate::base::allocator::Allocator;
+use crate::base::constraint::{
+    AreMultipliable, DimEq, SameNumberOfColumns, SameNumberOfRows, ShapeConstraint,
+};
+use crate::base::dimension::Dim;
+use crate::base::storage::{RawStorage, RawStorageMut, Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar, SliceStorage, SliceStorageMut};
+use crate::SimdComplexField;
+
+/// A lazily transposed and scaled matrix, i.e., the expression `alpha * matrix.transpose()`.
+///
+/// This is returned by `Matrix::t`. It can be scaled and multiplied by a matrix without ever
+/// computing the transpose of `matrix`.
+#[derive(Debug)]
+pub struct MatrixTranspose<'a, T, R: Dim, C: Dim, S> {
+    matrix: &'a Matrix<T, R, C, S>,
+    alpha: T,
+}
+
+/// A lazily conjugate-transposed and scaled matrix, i.e., the expression
+/// `alpha * matrix.adjoint()`.
+///
+/// This is returned by `Matrix::adjoint_view`. It can be scaled and multiplied by a matrix without
+/// ever computing the adjoint of `matrix`.
+#[derive(Debug)]
+pub struct MatrixAdjoint<'a, T, R: Dim, C: Dim, S> {
+    matrix: &'a Matrix<T, R, C, S>,
+    alpha: T,
+}
+
+/// # Lazy transposition
+impl<T: Scalar + One, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// A lazy transpose of this matrix, which can be multiplied by other matrices without
+    /// computing the transpose.
+    ///
+    /// Products involving the result are computed with `gemm_tr` or `gemm`, e.g.,
+    /// `a.t() * &b` is equivalent to `a.tr_mul(&b)` and `alpha * a.t()` is not evaluated until it
+    /// is multiplied. Use `.eval()` to compute the transpose explicitly.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix3x2};
+    /// let a = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    ///
+    /// assert_eq!(a.t() * &a, a.transpose() * a);
+    /// assert_eq!(&a * a.t(), a * a.transpose());
+    /// assert_eq!(2.0 * a.t() * &a, Matrix2::new(70.0, 88.0,
+    ///                                           88.0, 112.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn t(&self) -> MatrixTranspose<'_, T, R, C, S> {
+        MatrixTranspose {
+            matrix: self,
+            alpha: T::one(),
+        }
+    }
+
+    /// A lazy adjoint of this matrix, which can be multiplied by other matrices without computing
+    /// the adjoint.
+    ///
+    /// This is the same as `.t()` but the components of the matrix are conjugated.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Complex, Matrix2, Vector2};
+    /// let a = Matrix2::new(Complex::new(1.0, 2.0), Complex::new(0.0, 1.0),
+    ///                      Complex::new(3.0, 0.0), Complex::new(1.0, -1.0));
+    /// let v = Vector2::new(Complex::new(1.0, 0.0), Complex::new(0.0, 1.0));
+    ///
+    /// assert_eq!(a.adjoint_view() * &v, a.adjoint() * v);
+    /// assert_eq!(&a * a.adjoint_view(), a * a.adjoint());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn adjoint_view(&self) -> MatrixAdjoint<'_, T, R, C, S>
+    where
+        T: SimdComplexField,
+    {
+        MatrixAdjoint {
+            matrix: self,
+            alpha: T::one(),
+        }
+    }
+}
+
+/// A view of the transpose of `m`, i.e., a view of its components with swapped strides.
+fn transposed_view<T, R: Dim, C: Dim, S: RawStorage<T, R, C>>(
+    m: &Matrix<T, R, C, S>,
+) -> Matrix<T, C, R, SliceStorage<'_, T, C, R, S::CStride, S::RStride>> {
+    let (nrows, ncols) = m.shape_generic();
+    let (rstride, cstride) = m.data.strides();
+    // Safety: the view addresses exactly the components of `m`.
+    unsafe {
+        let data = SliceStorage::from_raw_parts(m.data.ptr(), (ncols, nrows), (cstride, rstride));
+        Matrix::from_data_statically_unchecked(data)
+    }
+}
+
+/// A mutable view of the transpose of `m`, i.e., a view of its components with swapped strides.
+fn transposed_view_mut<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>>(
+    m: &mut Matrix<T, R, C, S>,
+) -> Matrix<T, C, R, SliceStorageMut<'_, T, C, R, S::CStride, S::RStride>> {
+    let (nrows, ncols) = m.shape_generic();
+    let (rstride, cstride) = m.data.strides();
+    // Safety: the view addresses exactly the components of `m`, which it borrows mutably.
+    unsafe {
+        let data =
+            SliceStorageMut::from_raw_parts(m.data.ptr_mut(), (ncols, nrows), (cstride, rstride));
+        Matrix::from_data_statically_unchecked(data)
+    }
+}
+
+macro_rules! lazy_transpose_impl(
+    ($Op: ident, $op: ident, $gemm_op: ident, $conjugate: expr, $mul_op_to: expr
+     $(, $Bound: ident)*) => {
+        impl<'a, T: Clone, R: Dim, C: Dim, S> Clone for $Op<'a, T, R, C, S> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    matrix: self.matrix,
+                    alpha: self.alpha.clone(),
+                }
+            }
+        }
+
+        impl<'a, T, R: Dim, C: Dim, S> $Op<'a, T, R, C, S>
+        where
+            T: Scalar + Zero + One + ClosedAdd + ClosedMul $(+ $Bound)*,
+            S: Storage<T, R, C>,
+        {
+            /// The matrix this expression is computed from, before transposition and scaling.
+            #[inline]
+            #[must_use]
+            pub fn matrix(&self) -> &'a Matrix<T, R, C, S> {
+                self.matrix
+            }
+
+            /// The factor this expression is scaled by.
+            #[inline]
+            #[must_use]
+            pub fn alpha(&self) -> &T {
+                &self.alpha
+            }
+
+            /// The number of rows and columns of this expression.
+            #[inline]
+            #[must_use]
+            pub fn shape(&self) -> (usize, usize) {
+                let (nrows, ncols) = self.matrix.shape();
+                (ncols, nrows)
+            }
+
+            /// Evaluates this expression into a new matrix.
+            #[inline]
+            #[must_use]
+            pub fn eval(&self) -> OMatrix<T, C, R>
+            where
+                DefaultAllocator: Allocator<T, C, R>,
+            {
+                self.matrix.$op() * self.alpha.clone()
+            }
+
+            /// Equivalent to `self.eval() * rhs` but stores the result into `out` and does not
+            /// compute `self.eval()`.
+            #[inline]
+            pub fn mul_to<R2: Dim, C2: Dim, SB, R3: Dim, C3: Dim, SC>(
+                &self,
+                rhs: &Matrix<T, R2, C2, SB>,
+                out: &mut Matrix<T, R3, C3, SC>,
+            ) where
+                SB: Storage<T, R2, C2>,
+                SC: StorageMut<T, R3, C3>,
+                ShapeConstraint: AreMultipliable<C, R, R2, C2>
+                    + SameNumberOfRows<R3, C>
+                    + SameNumberOfColumns<C3, C2>,
+            {
+                out.$gemm_op(self.alpha.clone(), self.matrix, rhs, T::zero());
+            }
+        }
+
+        impl<'a, 'b, T, R1: Dim, C1: Dim, R2: Dim, C2: Dim, SA, SB> Mul<&'b Matrix<T, R2, C2, SB>>
+            for $Op<'a, T, R1, C1, SA>
+        where
+            T: Scalar + Zero + One + ClosedAdd + ClosedMul $(+ $Bound)*,
+            SA: Storage<T, R1, C1>,
+            SB: Storage<T, R2, C2>,
+            DefaultAllocator: Allocator<T, C1, C2>,
+            ShapeConstraint: AreMultipliable<C1, R1, R2, C2>,
+        {
+            type Output = OMatrix<T, C1, C2>;
+
+            #[inline]
+            fn mul(self, rhs: &'b Matrix<T, R2, C2, SB>) -> Self::Output {
+                let mut res = Matrix::zeros_generic(
+                    self.matrix.shape_generic().1,
+                    rhs.shape_generic().1,
+                );
+                res.$gemm_op(self.alpha, self.matrix, rhs, T::zero());
+                res
+            }
+        }
+
+        impl<'a, T, R1: Dim, C1: Dim, R2: Dim, C2: Dim, SA, SB> Mul<Matrix<T, R2, C2, SB>>
+            for $Op<'a, T, R1, C1, SA>
+        where
+            T: Scalar + Zero + One + ClosedAdd + ClosedMul $(+ $Bound)*,
+            SA: Storage<T, R1, C1>,
+            SB: Storage<T, R2, C2>,
+            DefaultAllocator: Allocator<T, C1, C2>,
+            ShapeConstraint: AreMultipliable<C1, R1, R2, C2>,
+        {
+            type Output = OMatrix<T, C1, C2>;
+
+            #[inline]
+            fn mul(self, rhs: Matrix<T, R2, C2, SB>) -> Self::Output {
+                self * &rhs
+            }
+        }
+
+        // A * op(B) is computed with a single product involving a transposed view of B.
+        impl<'a, 'b, T, R1: Dim, C1: Dim, R2: Dim, C2: Dim, SA, SB> Mul<$Op<'a, T, R2, C2, SB>>
+            for &'b Matrix<T, R1, C1, SA>
+        where
+            T: Scalar + Zero + One + ClosedAdd + ClosedMul $(+ $Bound)*,
+            SA: Storage<T, R1, C1>,
+            SB: Storage<T, R2, C2>,
+            DefaultAllocator: Allocator<T, R1, R2>,
+            ShapeConstraint: SameNumberOfColumns<C1, C2> + DimEq<C1, C2> + DimEq<C2, C1>,
+        {
+            type Output = OMatrix<T, R1, R2>;
+
+            #[inline]
+            fn mul(self, rhs: $Op<'a, T, R2, C2, SB>) -> Self::Output {
+                assert_eq!(
+                    self.ncols(),
+                    rhs.matrix.ncols(),
+                    "Matrix multiplication dimensions mismatch."
+                );
+
+                let mut res =
+                    Matrix::zeros_generic(self.shape_generic().0, rhs.matrix.shape_generic().0);
+                $mul_op_to(&mut res, rhs.alpha, self, rhs.matrix);
+                res
+            }
+        }
+
+        impl<'a, T, R1: Dim, C1: Dim, R2: Dim, C2: Dim, SA, SB> Mul<$Op<'a, T, R2, C2, SB>>
+            for Matrix<T, R1, C1, SA>
+        where
+            T: Scalar + Zero + One + ClosedAdd + ClosedMul $(+ $Bound)*,
+            SA: Storage<T, R1, C1>,
+            SB: Storage<T, R2, C2>,
+            DefaultAllocator: Allocator<T, R1, R2>,
+            ShapeConstraint: SameNumberOfColumns<C1, C2> + DimEq<C1, C2> + DimEq<C2, C1>,
+        {
+            type Output = OMatrix<T, R1, R2>;
+
+            #[inline]
+            fn mul(self, rhs: $Op<'a, T, R2, C2, SB>) -> Self::Output {
+                &self * rhs
+            }
+        }
+
+        // op(A) * op(B) = op(B * A), computed componentwise from the rows of B and the columns
+        // of A.
+        impl<'a, 'b, T, R1: Dim, C1: Dim, R2: Dim, C2: Dim, SA, SB> Mul<$Op<'b, T, R2, C2, SB>>
+            for $Op<'a, T, R1, C1, SA>
+        where
+            T: Scalar + Zero + One + ClosedAdd + ClosedMul $(+ $Bound)*,
+            SA: Storage<T, R1, C1>,
+            SB: Storage<T, R2, C2>,
+            DefaultAllocator: Allocator<T, C1, R2>,
+            ShapeConstraint: DimEq<C2, R1>,
+        {
+            type Output = OMatrix<T, C1, R2>;
+
+            #[inline]
+            fn mul(self, rhs: $Op<'b, T, R2, C2, SB>) -> Self::Output {
+                let (a, b) = (self.matrix, rhs.matrix);
+                assert_eq!(
+                    a.nrows(),
+                    b.ncols(),
+                    "Matrix multiplication dimensions mismatch."
+                );
+
+                let alpha = self.alpha * rhs.alpha;
+                let conjugate = $conjugate;
+
+                Matrix::from_fn_generic(a.shape_generic().1, b.shape_generic().0, |i, j| {
+                    alpha.clone() * conjugate(b.row(j).tr_dot(&a.column(i)))
+                })
+            }
+        }
+
+        impl<'a, T, R: Dim, C: Dim, S> Mul<T> for $Op<'a, T, R, C, S>
+        where
+            T: Scalar + ClosedMul $(+ $Bound)*,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                Self {
+                    matrix: self.matrix,
+                    alpha: self.alpha * rhs,
+                }
+            }
+        }
+    }
+);
+
+lazy_transpose_impl!(
+    MatrixTranspose,
+    transpose,
+    gemm_tr,
+    |e: T| e,
+    |res: &mut OMatrix<T, R1, R2>, alpha, a, b| {
+        // A * B^T, with matrixmultiply for large matrices.
+        res.gemm(alpha, a, &transposed_view(b), T::zero())
+    }
+);
+lazy_transpose_impl!(
+    MatrixAdjoint,
+    adjoint,
+    gemm_ad,
+    |e: T| e.simd_conjugate(),
+    |res: &mut OMatrix<T, R1, R2>, alpha, a, b| {
+        // (A * B^H)^T = conj(B) * A^T = (B^T)^H * A^T.
+        transposed_view_mut(res).gemm_ad(alpha, &transposed_view(b), &transposed_view(a), T::zero())
+    },
+    SimdComplexField
+);
+
+macro_rules! left_scalar_mul_impl(
+    ($Op: ident; $($T: ty),* $(,)*) => {$(
+        impl<'a, R: Dim, C: Dim, S> Mul<$Op<'a, $T, R, C, S>> for $T {
+            type Output = $Op<'a, $T, R, C, S>;
+
+            #[inline]
+            fn mul(self, rhs: $Op<'a, $T, R, C, S>) -> Self::Output {
+                $Op {
+                    matrix: rhs.matrix,
+                    alpha: self * rhs.alpha,
+                }
+            }
+        }
+    )*}
+);
+
+left_scalar_mul_impl!(
+    MatrixTranspose; u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64
+);
+left_scalar_mul_impl!(MatrixAdjoint; f32, f64, Complex<f32>, Complex<f64>);
@@ -0,0 +1,73 @@
+use std::ops::{Div, Mul};
+
+use simba::scalar::{ClosedDiv, ClosedMul};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
+
+/// A scalar meant to be the left-hand side of a product or a division by a matrix.
+///
+/// Because of Rust's orphan rules, `s * m` can only be implemented by nalgebra for the
+/// primitive scalar types, and not for any scalar type `T`. Wrapping the scalar into a
+/// `LeftScalar` makes this possible for custom scalar types (dual numbers, fixed-point or
+/// arbitrary-precision numbers, etc.) and in generic code:
+///
+/// - `LeftScalar(s) * m` multiplies each component of `m` by `s` on the left, i.e., computes
+///   `s * m[(i, j)]`. This differs from `m * s` for scalars with a non-commutative product.
+/// - `LeftScalar(s) / m` divides `s` by each component of `m`, i.e., computes `s / m[(i, j)]`.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{LeftScalar, Matrix2, RealField};
+/// fn double<T: RealField>(m: &Matrix2<T>) -> Matrix2<T> {
+///     LeftScalar(T::one() + T::one()) * m
+/// }
+///
+/// let m = Matrix2::new(1.0, 2.0,
+///                      4.0, 8.0);
+/// assert_eq!(double(&m), 2.0 * m);
+/// assert_eq!(LeftScalar(8.0) / m, Matrix2::new(8.0, 4.0,
+///                                              2.0, 1.0));
+/// ```
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LeftScalar<T>(pub T);
+
+macro_rules! left_scalar_op_impl(
+    ($Op: ident, $op: ident, $Bound: ident) => {
+        impl<T, R: Dim, C: Dim, S> $Op<Matrix<T, R, C, S>> for LeftScalar<T>
+        where
+            T: Scalar + $Bound,
+            S: Storage<T, R, C>,
+            DefaultAllocator: Allocator<T, R, C>,
+        {
+            type Output = OMatrix<T, R, C>;
+
+            #[inline]
+            fn $op(self, rhs: Matrix<T, R, C, S>) -> Self::Output {
+                let mut res = rhs.into_owned();
+                res.apply(|e| *e = self.0.clone().$op(e.clone()));
+                res
+            }
+        }
+
+        impl<'b, T, R: Dim, C: Dim, S> $Op<&'b Matrix<T, R, C, S>> for LeftScalar<T>
+        where
+            T: Scalar + $Bound,
+            S: Storage<T, R, C>,
+            DefaultAllocator: Allocator<T, R, C>,
+        {
+            type Output = OMatrix<T, R, C>;
+
+            #[inline]
+            fn $op(self, rhs: &'b Matrix<T, R, C, S>) -> Self::Output {
+                rhs.map(|e| self.0.clone().$op(e))
+            }
+        }
+    }
+);
+
+left_scalar_op_impl!(Mul, mul, ClosedMul);
+left_scalar_op_impl!(Div, div, ClosedDiv);
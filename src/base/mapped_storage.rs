@@ -0,0 +1,298 @@
+#[cfg(feature = "mmap")]
+use std::fs::File;
+#[cfg(feature = "mmap")]
+use std::io;
+use std::marker::PhantomData;
+use std::mem::{align_of, size_of};
+use std::ops::Deref;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use crate::base::allocator::Allocator;
+use crate::base::default_allocator::DefaultAllocator;
+#[cfg(feature = "mmap")]
+use crate::base::dimension::Dynamic;
+use crate::base::dimension::{Const, Dim};
+use crate::base::storage::{IsContiguous, Owned, RawStorage, Storage};
+use crate::base::{Matrix, Scalar};
+
+/// A buffer of bytes that always dereferences to the same memory location, even after being
+/// moved.
+///
+/// # Safety
+/// Every call to `deref` on a given value must return the same slice, until the value is
+/// dropped. This is the case for heap-allocated buffers and memory mappings, but not for
+/// buffers storing their bytes inline.
+pub unsafe trait StableByteBuffer: Deref<Target = [u8]> {}
+
+unsafe impl StableByteBuffer for &[u8] {}
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl StableByteBuffer for Vec<u8> {}
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl StableByteBuffer for Box<[u8]> {}
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl StableByteBuffer for Arc<[u8]> {}
+#[cfg(feature = "mmap")]
+unsafe impl StableByteBuffer for memmap2::Mmap {}
+
+/*
+ *
+ * RawStorage.
+ *
+ */
+/// A read-only matrix data storage reading its components directly from a buffer of bytes,
+/// typically a memory-mapped file.
+///
+/// The components are read in column-major order, with the native endianness, starting at a
+/// given byte offset of the buffer. Nothing is copied, so matrices larger than the available
+/// memory can be streamed through operations that only read their arguments: products like
+/// `gemv` and `gemm` into another matrix, norms, sums, slicing, etc. Operations returning a
+/// new matrix allocate it on the heap.
+///
+/// With the `mmap` feature, `Matrix::from_mmap_file_generic` maps a file into such a storage.
+///
+/// Only the storages reading from a shared buffer, i.e., `&[u8]` or `Arc<[u8]>`, can be cloned:
+/// cloning an owned buffer would copy its bytes to a new address, which may not be suitably
+/// aligned for `T`.
+#[derive(Debug)]
+pub struct MappedStorage<T, R: Dim, C: Dim, B> {
+    buffer: B,
+    offset: usize,
+    nrows: R,
+    ncols: C,
+    _phantoms: PhantomData<T>,
+}
+
+impl<T: bytemuck::Pod, R: Dim, C: Dim, B: StableByteBuffer> MappedStorage<T, R, C, B> {
+    /// Creates a storage reading the `nrows × ncols` components of a matrix from `buffer`,
+    /// starting at the byte `offset`.
+    ///
+    /// Returns `None` if `buffer` is too small, or if its byte at `offset` is not suitably
+    /// aligned for `T`.
+    #[inline]
+    pub fn new(buffer: B, offset: usize, nrows: R, ncols: C) -> Option<Self> {
+        let len = nrows
+            .value()
+            .checked_mul(ncols.value())?
+            .checked_mul(size_of::<T>())?;
+        let bytes = buffer.get(offset..)?;
+
+        if bytes.len() < len || bytes.as_ptr() as usize % align_of::<T>() != 0 {
+            return None;
+        }
+
+        Some(Self {
+            buffer,
+            offset,
+            nrows,
+            ncols,
+            _phantoms: PhantomData,
+        })
+    }
+}
+
+// NOTE: a clone of these buffers dereferences to the same bytes, so they remain aligned.
+impl<T, R: Dim, C: Dim> Clone for MappedStorage<T, R, C, &[u8]> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer,
+            offset: self.offset,
+            nrows: self.nrows,
+            ncols: self.ncols,
+            _phantoms: PhantomData,
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T, R: Dim, C: Dim> Clone for MappedStorage<T, R, C, Arc<[u8]>> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            buffer: Arc::clone(&self.buffer),
+            offset: self.offset,
+            nrows: self.nrows,
+            ncols: self.ncols,
+            _phantoms: PhantomData,
+        }
+    }
+}
+
+impl<T, R: Dim, C: Dim, B> MappedStorage<T, R, C, B> {
+    /// The buffer the components are read from.
+    #[inline]
+    #[must_use]
+    pub fn buffer(&self) -> &B {
+        &self.buffer
+    }
+
+    /// The byte offset of the first component in the buffer.
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Retrieves the buffer the components are read from.
+    #[inline]
+    pub fn into_buffer(self) -> B {
+        self.buffer
+    }
+}
+
+unsafe impl<T, R: Dim, C: Dim, B: StableByteBuffer> RawStorage<T, R, C>
+    for MappedStorage<T, R, C, B>
+{
+    type RStride = Const<1>;
+    type CStride = R;
+
+    #[inline]
+    fn ptr(&self) -> *const T {
+        // Safety: `new` checked that the offset is within the buffer.
+        unsafe { self.buffer.as_ptr().add(self.offset) as *const T }
+    }
+
+    #[inline]
+    fn shape(&self) -> (R, C) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    fn strides(&self) -> (Self::RStride, Self::CStride) {
+        (Const::<1>, self.nrows)
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    unsafe fn as_slice_unchecked(&self) -> &[T] {
+        std::slice::from_raw_parts(self.ptr(), self.nrows.value() * self.ncols.value())
+    }
+}
+
+unsafe impl<T: Scalar, R: Dim, C: Dim, B: StableByteBuffer> Storage<T, R, C>
+    for MappedStorage<T, R, C, B>
+{
+    #[inline]
+    fn into_owned(self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        self.clone_owned()
+    }
+
+    #[inline]
+    fn clone_owned(&self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        // Safety: the storage is contiguous.
+        let it = unsafe { self.as_slice_unchecked() }.iter().cloned();
+        DefaultAllocator::allocate_from_iterator(self.nrows, self.ncols, it)
+    }
+}
+
+unsafe impl<T, R: Dim, C: Dim, B: StableByteBuffer> IsContiguous for MappedStorage<T, R, C, B> {}
+
+/// # Matrices read from a buffer of bytes
+impl<T, R, C, B> Matrix<T, R, C, MappedStorage<T, R, C, B>>
+where
+    T: Scalar + bytemuck::Pod,
+    R: Dim,
+    C: Dim,
+    B: StableByteBuffer,
+{
+    /// Creates a matrix reading its components from `buffer`, starting at the byte `offset`,
+    /// with dimensions specified by generic types instances.
+    ///
+    /// The components are read in column-major order with the native endianness, without any
+    /// copy. Returns `None` if `buffer` is too small, or if its byte at `offset` is not suitably
+    /// aligned for `T`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Const, Dynamic, MappedStorage, Matrix, Matrix2x3, U2};
+    /// let m = Matrix2x3::new(1.0f64, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let bytes: &[u8] = bytemuck::cast_slice(m.as_slice());
+    ///
+    /// let mapped = Matrix::from_mapped_bytes_generic(bytes, 0, Const::<2>, Dynamic::new(3));
+    /// let mapped = mapped.unwrap();
+    /// assert_eq!(mapped, m);
+    /// assert_eq!(mapped.column(1).norm_squared(), 29.0);
+    ///
+    /// // The buffer is too small for a 2×4 matrix, and misaligned at the offset 4.
+    /// type Mapped<'a> = Matrix<f64, U2, Dynamic, MappedStorage<f64, U2, Dynamic, &'a [u8]>>;
+    /// assert!(Mapped::from_mapped_bytes_generic(bytes, 0, Const::<2>, Dynamic::new(4)).is_none());
+    /// assert!(Mapped::from_mapped_bytes_generic(bytes, 4, Const::<2>, Dynamic::new(2)).is_none());
+    /// ```
+    #[inline]
+    pub fn from_mapped_bytes_generic(buffer: B, offset: usize, nrows: R, ncols: C) -> Option<Self> {
+        MappedStorage::new(buffer, offset, nrows, ncols).map(Self::from_data)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<T, R, C> Matrix<T, R, C, MappedStorage<T, R, C, memmap2::Mmap>>
+where
+    T: Scalar + bytemuck::Pod,
+    R: Dim,
+    C: Dim,
+{
+    /// Maps `file` into memory, and reads the components of a matrix from it, starting at the
+    /// byte `offset`, with dimensions specified by generic types instances.
+    ///
+    /// The components are read in column-major order with the native endianness. The pages of
+    /// the file are loaded lazily by the operating system as the components are read, so the
+    /// file may be larger than the available memory.
+    ///
+    /// Returns an error of kind `InvalidInput` if the file is too small, or if `offset` is not
+    /// suitably aligned for `T`.
+    ///
+    /// # Safety
+    /// The file must not be modified, by this process or another one, while the matrix exists.
+    /// See `memmap2::Mmap::map` for details.
+    pub unsafe fn from_mmap_file_generic(
+        file: &File,
+        offset: usize,
+        nrows: R,
+        ncols: C,
+    ) -> io::Result<Self> {
+        let mmap = memmap2::Mmap::map(file)?;
+        Self::from_mapped_bytes_generic(mmap, offset, nrows, ncols).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the file is too small or misaligned for the requested matrix",
+            )
+        })
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<T: Scalar + bytemuck::Pod>
+    Matrix<T, Dynamic, Dynamic, MappedStorage<T, Dynamic, Dynamic, memmap2::Mmap>>
+{
+    /// Maps `file` into memory, and reads the components of a `nrows × ncols` matrix from it,
+    /// starting at the byte `offset`.
+    ///
+    /// See `Matrix::from_mmap_file_generic` for details.
+    ///
+    /// # Safety
+    /// The file must not be modified, by this process or another one, while the matrix exists.
+    /// See `memmap2::Mmap::map` for details.
+    pub unsafe fn from_mmap_file(
+        file: &File,
+        offset: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> io::Result<Self> {
+        Self::from_mmap_file_generic(file, offset, Dynamic::new(nrows), Dynamic::new(ncols))
+    }
+}
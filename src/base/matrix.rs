@@ -1,3 +1,5 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{format, vec::Vec};
 use num::{One, Zero};
 #[cfg(feature = "abomonation-serialize")]
 use std::io::{Result as IOResult, Write};
@@ -27,7 +29,10 @@ use crate::base::iter::{
 };
 use crate::base::storage::{Owned, RawStorage, RawStorageMut, SameShapeStorage};
 use crate::base::{Const, DefaultAllocator, OMatrix, OVector, Scalar, Unit};
-use crate::{ArrayStorage, SMatrix, SimdComplexField, Storage, UninitMatrix};
+use crate::{
+    ArrayStorage, RealField, SMatrix, SimdComplexField, SimdRealField, Storage, UninitMatrix,
+    Vector3,
+};
 
 use crate::storage::IsContiguous;
 use crate::uninit::{Init, InitStatus, Uninit};
@@ -152,6 +157,20 @@ pub type MatrixCross<T, R1, C1, R2, C2> =
 /// Note that mixing `Dynamic` with type-level unsigned integers is allowed. Actually, a
 /// dynamically-sized column vector should be represented as a `Matrix<T, Dynamic, U1, S>` (given
 /// some concrete types for `T` and a compatible data storage type `S`).
+///
+/// # Memory layout
+/// `Matrix` is `#[repr(C)]` and its only field that is not zero-sized is its data storage `S`, so
+/// it has the same memory layout as `S`:
+/// - `ArrayStorage<T, R, C>` has the same layout as `[[T; R]; C]`, hence statically-sized
+/// matrices store their components contiguously, in column-major order.
+/// - `VecStorage` stores the components of dynamically-sized matrices in a contiguous heap
+/// buffer, in column-major order too. This buffer is accessible with `.as_ptr()` and
+/// `.as_slice()`.
+/// - `SliceStorage` and `SliceStorageMut`, used by matrix slices, point to a strided buffer where
+/// the component `(i, j)` is located `i * rstride + j * cstride` elements after the first one.
+/// Such slices can be created over memory owned by foreign code, e.g., a C library or a
+/// memory-mapped file, with `MatrixSlice::from_slice_with_strides_generic`, or with
+/// `MatrixSlice::from_raw_parts_generic` when only a raw pointer is available.
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Matrix<T, R, C, S> {
@@ -783,6 +802,48 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         unsafe { res.assume_init() }
     }
 
+    /// Returns a matrix containing the result of `f` applied to each of its entries, or the first
+    /// error returned by `f`.
+    ///
+    /// The entries are visited in column-major order, and `f` is not called anymore after it
+    /// returns an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, 4.0, 9.0);
+    /// let sqrt = |x: f64| if x >= 0.0 { Ok(x.sqrt()) } else { Err(x) };
+    ///
+    /// assert_eq!(v.try_map(sqrt), Ok(Vector3::new(1.0, 2.0, 3.0)));
+    /// assert_eq!(Vector3::new(1.0, -4.0, -9.0).try_map(sqrt), Err(-4.0));
+    /// ```
+    #[inline]
+    pub fn try_map<T2: Scalar, E, F: FnMut(T) -> Result<T2, E>>(
+        &self,
+        mut f: F,
+    ) -> Result<OMatrix<T2, R, C>, E>
+    where
+        T: Scalar,
+        DefaultAllocator: Allocator<T2, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::uninit(nrows, ncols);
+
+        for j in 0..ncols.value() {
+            for i in 0..nrows.value() {
+                // Safety: all indices are in range.
+                unsafe {
+                    let a = self.data.get_unchecked(i, j).clone();
+                    // NOTE: on error, the components already computed are leaked.
+                    *res.data.get_unchecked_mut(i, j) = MaybeUninit::new(f(a)?);
+                }
+            }
+        }
+
+        // Safety: res is now fully initialized.
+        Ok(unsafe { res.assume_init() })
+    }
+
     /// Cast the components of `self` to another type.
     ///
     /// # Example
@@ -1387,6 +1448,63 @@ impl<T: Scalar, D: Dim, S: RawStorageMut<T, D, D>> Matrix<T, D, D, S> {
     }
 }
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar> DMatrix<T> {
+    /// Transposes `self` in-place, even if it is not square.
+    ///
+    /// Unlike `self.transpose()`, this does not allocate a second matrix: the components are
+    /// permuted within the buffer of `self` by following the cycles of the transposition, which
+    /// only requires one additional bit per component.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let mut m = DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    /// let expected = m.transpose();
+    /// m.transpose_in_place();
+    ///
+    /// assert_eq!(m, expected);
+    /// ```
+    pub fn transpose_in_place(&mut self) {
+        let (nrows, ncols) = self.shape();
+
+        if nrows == ncols {
+            return self.transpose_mut();
+        }
+
+        let len = nrows * ncols;
+
+        if nrows > 1 && ncols > 1 {
+            // The component at the index `k < len - 1` of the column-major buffer moves to the
+            // index `k * ncols mod (len - 1)`. The first and last components don't move.
+            let dest = |k: usize| (k * ncols) % (len - 1);
+            let mut visited = Vec::new();
+            visited.resize((len + 63) / 64, 0u64);
+            let data = self.as_mut_slice();
+
+            for start in 1..len - 1 {
+                if visited[start / 64] & (1 << (start % 64)) != 0 {
+                    continue;
+                }
+
+                // Rotate the cycle containing `start`, using `data[start]` as a buffer.
+                let mut k = dest(start);
+                while k != start {
+                    data.swap(start, k);
+                    visited[k / 64] |= 1 << (k % 64);
+                    k = dest(k);
+                }
+
+                visited[start / 64] |= 1 << (start % 64);
+            }
+        }
+
+        let empty = Matrix::from_vec_generic(Dynamic::new(0), Dynamic::new(0), Vec::new());
+        let m = mem::replace(self, empty);
+        *self = m.reshape_generic(Dynamic::new(ncols), Dynamic::new(nrows));
+    }
+}
+
 impl<T: SimdComplexField, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
     /// Takes the adjoint (aka. conjugate-transpose) of `self` and store the result into `out`.
     #[inline]
@@ -1659,6 +1777,43 @@ impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
     }
 }
 
+impl<T: SimdComplexField, D: Dim, S: RawStorageMut<T, D, D>> SquareMatrix<T, D, S> {
+    /// Replaces `self` by its symmetric part, i.e., `0.5 * (self + self.transpose())`, without
+    /// allocating.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let mut m = Matrix2::new(1.0, 2.0,
+    ///                          4.0, 3.0);
+    /// m.symmetrize();
+    /// assert_eq!(m, Matrix2::new(1.0, 3.0,
+    ///                            3.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn symmetrize(&mut self) {
+        assert!(
+            self.is_square(),
+            "Cannot compute the symmetric part of a non-square matrix."
+        );
+
+        let half = crate::convert::<_, T>(0.5);
+        let dim = self.nrows();
+
+        for j in 0..dim {
+            for i in j + 1..dim {
+                unsafe {
+                    let avg = (self.get_unchecked((i, j)).clone()
+                        + self.get_unchecked((j, i)).clone())
+                        * half.clone();
+                    *self.get_unchecked_mut((j, i)) = avg.clone();
+                    *self.get_unchecked_mut((i, j)) = avg;
+                }
+            }
+        }
+    }
+}
+
 impl<T: Scalar + Zero + One, D: DimAdd<U1> + IsNotStaticOne, S: RawStorage<T, D, D>>
     Matrix<T, D, D, S>
 {
@@ -1919,7 +2074,7 @@ macro_rules! impl_fmt {
             S: RawStorage<T, R, C>,
         {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                #[cfg(feature = "std")]
+                #[cfg(any(feature = "std", feature = "alloc"))]
                 fn val_width<T: Scalar + $trait>(val: &T, f: &mut fmt::Formatter<'_>) -> usize {
                     match f.precision() {
                         Some(precision) => format!($fmt_str_with_precision, val, precision)
@@ -1929,7 +2084,7 @@ macro_rules! impl_fmt {
                     }
                 }
 
-                #[cfg(not(feature = "std"))]
+                #[cfg(not(any(feature = "std", feature = "alloc")))]
                 fn val_width<T: Scalar + $trait>(_: &T, _: &mut fmt::Formatter<'_>) -> usize {
                     4
                 }
@@ -2144,6 +2299,79 @@ impl<T: Scalar + Field, S: RawStorage<T, U3>> Vector<T, U3, S> {
     }
 }
 
+impl<T: RealField, S: Storage<T, U3>> Vector<T, U3, S> {
+    /// The smallest angle between two 3D vectors, in `[0, π]`.
+    ///
+    /// This computes `atan2(‖self × other‖, self · other)`, which is accurate for all angles,
+    /// whereas the `acos` formulation of `.angle()` loses most of its precision for vectors that
+    /// are nearly parallel or nearly opposite. Returns zero if any of the vectors is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let a = Vector3::new(1.0, 0.0, 0.0);
+    /// let b = Vector3::new(1.0, 1.0e-10, 0.0);
+    ///
+    /// assert_eq!(a.angle_to(&b), 1.0e-10);
+    /// assert_eq!(a.angle(&b), 0.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn angle_to<SB: Storage<T, U3>>(&self, other: &Vector<T, U3, SB>) -> T {
+        self.cross(other).norm().atan2(self.dot(other))
+    }
+}
+
+/// Computes two vectors `(b1, b2)` such that `(b1, b2, n)` is a right-handed orthonormal basis,
+/// where `n` is a unit vector, with the method of Duff et al., "Building an Orthonormal Basis,
+/// Revisited" (2017).
+pub(crate) fn duff_orthonormal_basis<T, S>(n: &Vector<T, U3, S>) -> (Vector3<T>, Vector3<T>)
+where
+    T: SimdRealField,
+    T::Element: SimdRealField,
+    S: Storage<T, U3>,
+{
+    let (x, y, z) = (n[0].clone(), n[1].clone(), n[2].clone());
+    let sign = T::one().simd_copysign(z.clone());
+    let a = -T::one() / (sign.clone() + z);
+    let b = x.clone() * y.clone() * a.clone();
+
+    let b1 = Vector3::new(
+        T::one() + sign.clone() * x.clone() * x.clone() * a.clone(),
+        sign.clone() * b.clone(),
+        -sign.clone() * x,
+    );
+    let b2 = Vector3::new(b, sign + y.clone() * y.clone() * a, -y);
+
+    (b1, b2)
+}
+
+impl<T: RealField, S: Storage<T, U3>> Unit<Vector<T, U3, S>> {
+    /// Computes two unit vectors `(b1, b2)` such that `(b1, b2, self)` is a right-handed
+    /// orthonormal basis.
+    ///
+    /// This uses the branchless method of Duff et al., "Building an Orthonormal Basis,
+    /// Revisited" (2017), which is continuous everywhere except where `self.z` changes sign, and
+    /// accurate for all directions, including when `self` is close to `-z`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Unit, Vector3};
+    /// let n = Unit::new_normalize(Vector3::new(1.0, -2.0, 3.0));
+    /// let (b1, b2) = n.any_orthonormal_basis();
+    ///
+    /// assert_relative_eq!(b1.dot(&b2), 0.0, epsilon = 1.0e-15);
+    /// assert_relative_eq!(b1.dot(&n), 0.0, epsilon = 1.0e-15);
+    /// assert_relative_eq!(b1.cross(&b2), n.into_inner(), epsilon = 1.0e-15);
+    /// ```
+    #[must_use]
+    pub fn any_orthonormal_basis(&self) -> (Unit<Vector3<T>>, Unit<Vector3<T>>) {
+        let (b1, b2) = duff_orthonormal_basis(self.as_ref());
+        (Unit::new_unchecked(b1), Unit::new_unchecked(b2))
+    }
+}
+
 impl<T: SimdComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// The smallest angle between two vectors.
     #[inline]
@@ -0,0 +1,240 @@
+/*
+ * Explicitly vectorized product and inverse of 4×4 single-precision matrices.
+ *
+ * These are the hottest operations of 3D renderers, so the generic multiplication and inversion
+ * code dispatch to these functions whenever the scalar type is `f32` and the matrices are 4×4.
+ * They rely on SSE on x86 and x86_64, and NEON on AArch64, which are enabled by default on these
+ * targets.
+ *
+ * Each 4-dimensional column is stored in a single SIMD register. Only a handful of
+ * architecture-specific operations are needed: they are implemented by the `arch` modules below.
+ */
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[macro_use]
+mod arch {
+    #[cfg(target_arch = "x86")]
+    pub use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    pub use std::arch::x86_64::*;
+
+    pub type F32x4 = __m128;
+
+    #[inline(always)]
+    pub unsafe fn load(ptr: *const f32) -> F32x4 {
+        _mm_loadu_ps(ptr)
+    }
+
+    #[inline(always)]
+    pub unsafe fn store(ptr: *mut f32, v: F32x4) {
+        _mm_storeu_ps(ptr, v)
+    }
+
+    #[inline(always)]
+    pub unsafe fn from_array(a: [f32; 4]) -> F32x4 {
+        _mm_loadu_ps(a.as_ptr())
+    }
+
+    #[inline(always)]
+    pub unsafe fn splat(x: f32) -> F32x4 {
+        _mm_set1_ps(x)
+    }
+
+    #[inline(always)]
+    pub unsafe fn sub(a: F32x4, b: F32x4) -> F32x4 {
+        _mm_sub_ps(a, b)
+    }
+
+    #[inline(always)]
+    pub unsafe fn mul(a: F32x4, b: F32x4) -> F32x4 {
+        _mm_mul_ps(a, b)
+    }
+
+    /// Computes `a * b + c`.
+    #[inline(always)]
+    pub unsafe fn mul_add(a: F32x4, b: F32x4, c: F32x4) -> F32x4 {
+        _mm_add_ps(_mm_mul_ps(a, b), c)
+    }
+
+    /// Builds the vector `[v[i0], v[i1], v[i2], v[i3]]`.
+    macro_rules! shuffle {
+        ($v: expr, [$i0: literal, $i1: literal, $i2: literal, $i3: literal]) => {{
+            let v = $v;
+            _mm_shuffle_ps(v, v, $i0 | ($i1 << 2) | ($i2 << 4) | ($i3 << 6))
+        }};
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[macro_use]
+mod arch {
+    pub use std::arch::aarch64::*;
+
+    pub type F32x4 = float32x4_t;
+
+    #[inline(always)]
+    pub unsafe fn load(ptr: *const f32) -> F32x4 {
+        vld1q_f32(ptr)
+    }
+
+    #[inline(always)]
+    pub unsafe fn store(ptr: *mut f32, v: F32x4) {
+        vst1q_f32(ptr, v)
+    }
+
+    #[inline(always)]
+    pub unsafe fn from_array(a: [f32; 4]) -> F32x4 {
+        vld1q_f32(a.as_ptr())
+    }
+
+    #[inline(always)]
+    pub unsafe fn splat(x: f32) -> F32x4 {
+        vdupq_n_f32(x)
+    }
+
+    #[inline(always)]
+    pub unsafe fn sub(a: F32x4, b: F32x4) -> F32x4 {
+        vsubq_f32(a, b)
+    }
+
+    #[inline(always)]
+    pub unsafe fn mul(a: F32x4, b: F32x4) -> F32x4 {
+        vmulq_f32(a, b)
+    }
+
+    /// Computes `a * b + c`.
+    #[inline(always)]
+    pub unsafe fn mul_add(a: F32x4, b: F32x4, c: F32x4) -> F32x4 {
+        vmlaq_f32(c, a, b)
+    }
+
+    /// Builds the vector `[v[i0], v[i1], v[i2], v[i3]]`.
+    macro_rules! shuffle {
+        ($v: expr, [$i0: literal, $i1: literal, $i2: literal, $i3: literal]) => {{
+            let mut lanes = [0.0f32; 4];
+            vst1q_f32(lanes.as_mut_ptr(), $v);
+            vld1q_f32([lanes[$i0], lanes[$i1], lanes[$i2], lanes[$i3]].as_ptr())
+        }};
+    }
+}
+
+use self::arch::*;
+
+/// Computes `y = alpha * a * b + beta * y` where `a` is a 4×4 matrix and `b` and `y` are 4×`ncols`
+/// matrices, all stored with a row stride of one and the given column strides.
+///
+/// If `beta` is zero, `y` is never read from and may be uninitialized.
+///
+/// # Safety
+/// The pointers must be valid for reading (and writing for `y`) the matrices with the given
+/// strides.
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn gemm4(
+    alpha: f32,
+    a: *const f32,
+    a_col_stride: usize,
+    b: *const f32,
+    b_col_stride: usize,
+    beta: f32,
+    y: *mut f32,
+    y_col_stride: usize,
+    ncols: usize,
+) {
+    let a0 = load(a);
+    let a1 = load(a.add(a_col_stride));
+    let a2 = load(a.add(a_col_stride * 2));
+    let a3 = load(a.add(a_col_stride * 3));
+
+    for j in 0..ncols {
+        let b = b.add(j * b_col_stride);
+        let y = y.add(j * y_col_stride);
+
+        let mut res = mul(a0, splat(*b));
+        res = mul_add(a1, splat(*b.add(1)), res);
+        res = mul_add(a2, splat(*b.add(2)), res);
+        res = mul_add(a3, splat(*b.add(3)), res);
+
+        if alpha != 1.0 {
+            res = mul(res, splat(alpha));
+        }
+
+        if beta != 0.0 {
+            res = mul_add(load(y), splat(beta), res);
+        }
+
+        store(y, res);
+    }
+}
+
+/// Computes the inverse of the 4×4 matrix with the given column-major components, or returns
+/// `None` if its determinant is zero.
+///
+/// The inverse is computed with Cramer's rule, i.e., as the adjugate matrix divided by the
+/// determinant, where all the 2×2 minors involving the first two or last two rows are shared
+/// between the cofactors.
+#[inline]
+pub fn inverse4(m: &[f32; 16]) -> Option<[f32; 16]> {
+    unsafe {
+        let rows = [
+            from_array([m[0], m[4], m[8], m[12]]),
+            from_array([m[1], m[5], m[9], m[13]]),
+            from_array([m[2], m[6], m[10], m[14]]),
+            from_array([m[3], m[7], m[11], m[15]]),
+        ];
+
+        // Computes the minors `[p_a * q_b - q_a * p_b]` for the given lanes `a` and `b` of the
+        // rows `p` and `q`.
+        macro_rules! minors {
+            ($p: expr, $q: expr, $a: tt, $b: tt) => {
+                sub(
+                    mul(shuffle!($p, $a), shuffle!($q, $b)),
+                    mul(shuffle!($q, $a), shuffle!($p, $b)),
+                )
+            };
+        }
+
+        // Computes two columns of the adjugate matrix from the rows `p` and `q`. The cofactors of
+        // a 4×4 matrix are linear combinations of the components of a row `x` of the other two
+        // rows weighted by their 2×2 minors:
+        //     [ x1 * m23 - x2 * m13 + x3 * m12,
+        //      -x0 * m23 + x2 * m03 - x3 * m02,
+        //       x0 * m13 - x1 * m03 + x3 * m01,
+        //      -x0 * m12 + x1 * m02 - x2 * m01 ]
+        // where `mab` is the minor of the columns `a` and `b`.
+        let adjugate_columns = |p: F32x4, q: F32x4, x: F32x4, y: F32x4| {
+            let signs = from_array([1.0, -1.0, 1.0, -1.0]);
+            let k1 = mul(minors!(p, q, [2, 2, 1, 1], [3, 3, 3, 2]), signs);
+            let k2 = mul(minors!(p, q, [1, 0, 0, 0], [3, 3, 3, 2]), signs);
+            let k3 = mul(minors!(p, q, [1, 0, 0, 0], [2, 2, 1, 1]), signs);
+
+            let cofactors = |x: F32x4| {
+                let res = mul(shuffle!(x, [1, 0, 0, 0]), k1);
+                let res = sub(res, mul(shuffle!(x, [2, 2, 1, 1]), k2));
+                mul_add(shuffle!(x, [3, 3, 3, 2]), k3, res)
+            };
+
+            (cofactors(x), sub(splat(0.0), cofactors(y)))
+        };
+
+        let (col0, col1) = adjugate_columns(rows[2], rows[3], rows[1], rows[0]);
+        let (col2, col3) = adjugate_columns(rows[0], rows[1], rows[3], rows[2]);
+
+        let mut det = [0.0f32; 4];
+        store(det.as_mut_ptr(), mul(rows[0], col0));
+        let det = (det[0] + det[1]) + (det[2] + det[3]);
+
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv_det = splat(1.0 / det);
+        let mut res = [0.0f32; 16];
+        store(res.as_mut_ptr(), mul(col0, inv_det));
+        store(res.as_mut_ptr().add(4), mul(col1, inv_det));
+        store(res.as_mut_ptr().add(8), mul(col2, inv_det));
+        store(res.as_mut_ptr().add(12), mul(col3, inv_det));
+
+        Some(res)
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+use std::cell::{Cell, RefCell};
+
+use num::Zero;
+
+use crate::base::dimension::{Const, Dim, Dynamic};
+use crate::base::storage::RawStorage;
+use crate::base::{DMatrixSliceMut, DVectorSliceMut, Matrix, MatrixSliceMutMN, Scalar};
+
+/// An arena allocating the components of temporary matrices from large chunks of memory, which
+/// are all freed or reused at once.
+///
+/// The matrices allocated by an arena are mutable slices borrowing the arena. They can be used
+/// as the output of all the methods writing their result into an existing matrix, e.g., `gemm`,
+/// `gemv`, `mul_to`, `tr_mul_to`, `copy_from`, or `solve_lower_triangular_mut`. Once they are
+/// no longer used, `.reset()` makes their memory available for the next allocations, without
+/// calling the global allocator. This is useful to avoid allocating and freeing memory for the
+/// temporary matrices of every iteration of a loop, like a per-frame computation.
+///
+/// Operations returning a new dynamically-sized matrix, e.g., `a * b`, still allocate it with
+/// the global allocator.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{DMatrix, MatrixArena};
+/// let a = DMatrix::from_fn(4, 3, |i, j| (i + j) as f64);
+/// let mut arena = MatrixArena::with_capacity(64);
+///
+/// for _ in 0..10 {
+///     let mut ata = arena.alloc_zeros(3, 3);
+///     a.tr_mul_to(&a, &mut ata);
+///     let mut trace = arena.alloc_copy(&ata.diagonal());
+///     trace.add_scalar_mut(1.0);
+///
+///     assert_eq!(ata, a.tr_mul(&a));
+///     assert_eq!(arena.allocated_len(), 12);
+///
+///     // All the matrices allocated during this iteration must be dropped before resetting.
+///     arena.reset();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MatrixArena<T> {
+    // Chunks are never resized, so that their components never move while they are borrowed.
+    chunks: RefCell<Vec<Vec<T>>>,
+    // The number of components allocated from the last chunk.
+    last_chunk_len: Cell<usize>,
+    // The number of components allocated from all the chunks.
+    allocated_len: Cell<usize>,
+}
+
+impl<T: Scalar + Zero> Default for MatrixArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Scalar + Zero> MatrixArena<T> {
+    /// The capacity of the first chunk of an arena created by `MatrixArena::new`.
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Creates an arena that allocates a chunk of a default capacity when the first matrix is
+    /// allocated.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            chunks: RefCell::new(Vec::new()),
+            last_chunk_len: Cell::new(0),
+            allocated_len: Cell::new(0),
+        }
+    }
+
+    /// Creates an arena that can allocate matrices with a total of `capacity` components
+    /// before allocating more memory.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let arena = Self::new();
+        arena.add_chunk(capacity);
+        arena
+    }
+
+    /// The total number of components of the matrices allocated since this arena was created
+    /// or reset.
+    #[inline]
+    #[must_use]
+    pub fn allocated_len(&self) -> usize {
+        self.allocated_len.get()
+    }
+
+    /// The total number of components this arena can allocate before allocating more memory.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.chunks.borrow().iter().map(|c| c.len()).sum()
+    }
+
+    /// Makes the memory of all the matrices allocated so far available for new matrices.
+    ///
+    /// If several chunks of memory were needed since the last reset, they are merged into a
+    /// single chunk, so that the same allocations do not need more memory after this reset.
+    pub fn reset(&mut self) {
+        let chunks = self.chunks.get_mut();
+
+        if chunks.len() > 1 {
+            let capacity = chunks.iter().map(|c| c.len()).sum();
+            chunks.clear();
+            chunks.push(vec![T::zero(); capacity]);
+        }
+
+        self.last_chunk_len.set(0);
+        self.allocated_len.set(0);
+    }
+
+    /// Allocates a matrix with all its components set to zero, with dimensions specified by
+    /// generic types instances.
+    ///
+    /// # Panics
+    /// Panics if the number of components `nrows × ncols` overflows `usize`.
+    #[inline]
+    pub fn alloc_zeros_generic<R: Dim, C: Dim>(
+        &self,
+        nrows: R,
+        ncols: C,
+    ) -> MatrixSliceMutMN<'_, T, R, C> {
+        let len = nrows
+            .value()
+            .checked_mul(ncols.value())
+            .expect("Matrix arena: the number of components overflows `usize`.");
+        let data = self.alloc_slice(len);
+        MatrixSliceMutMN::from_slice_generic(data, nrows, ncols)
+    }
+
+    /// Allocates a `nrows × ncols` matrix with all its components set to zero.
+    #[inline]
+    pub fn alloc_zeros(&self, nrows: usize, ncols: usize) -> DMatrixSliceMut<'_, T> {
+        self.alloc_zeros_generic(Dynamic::new(nrows), Dynamic::new(ncols))
+    }
+
+    /// Allocates a vector of dimension `dim` with all its components set to zero.
+    #[inline]
+    pub fn alloc_zeros_vector(&self, dim: usize) -> DVectorSliceMut<'_, T> {
+        self.alloc_zeros_generic(Dynamic::new(dim), Const::<1>)
+    }
+
+    /// Allocates a copy of `m`, with the same dimensions.
+    #[inline]
+    pub fn alloc_copy<R: Dim, C: Dim, S: RawStorage<T, R, C>>(
+        &self,
+        m: &Matrix<T, R, C, S>,
+    ) -> MatrixSliceMutMN<'_, T, R, C> {
+        let (nrows, ncols) = m.shape_generic();
+        let mut res = self.alloc_zeros_generic(nrows, ncols);
+        res.copy_from(m);
+        res
+    }
+
+    fn add_chunk(&self, len: usize) {
+        self.chunks.borrow_mut().push(vec![T::zero(); len]);
+        self.last_chunk_len.set(0);
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    fn alloc_slice(&self, len: usize) -> &mut [T] {
+        let last_chunk_capacity = self.chunks.borrow().last().map_or(0, Vec::len);
+
+        if last_chunk_capacity - self.last_chunk_len.get() < len {
+            let capacity = self.capacity();
+            self.add_chunk(len.max(capacity).max(Self::DEFAULT_CAPACITY));
+        }
+
+        let start = self.last_chunk_len.get();
+        self.last_chunk_len.set(start + len);
+        self.allocated_len.set(self.allocated_len.get() + len);
+
+        // NOTE: we must not create a reference to the whole chunk since some of its components
+        // may be borrowed by previously allocated matrices.
+        let ptr = self.chunks.borrow_mut().last_mut().unwrap().as_mut_ptr();
+
+        // Safety: the components `start..start + len` of the last chunk are not borrowed by any
+        // other matrix allocated since the last reset, and the reset requires a mutable borrow of
+        // the arena. The chunk is never resized nor dropped before the next reset, so its
+        // components don't move after the `RefCell` borrow ends.
+        let res = unsafe { std::slice::from_raw_parts_mut(ptr.add(start), len) };
+        res.fill(T::zero());
+        res
+    }
+}
@@ -6,14 +6,24 @@ pub mod constraint;
 pub mod coordinates;
 pub mod default_allocator;
 pub mod dimension;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod display;
 pub mod iter;
 mod ops;
+#[cfg(feature = "parallel")]
+pub mod par_iter;
 pub mod storage;
 
 mod alias;
 mod alias_slice;
+mod any_matrix;
 mod array_storage;
+mod array_vec_storage;
+mod band;
 mod cg;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod comparison;
+mod complex_embedding;
 mod componentwise;
 #[macro_use]
 mod construction;
@@ -21,19 +31,35 @@ mod construction_slice;
 mod conversion;
 mod edition;
 pub mod indexing;
+mod lazy_transpose;
+mod left_scalar;
+#[cfg(feature = "bytemuck")]
+mod mapped_storage;
 mod matrix;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod matrix_arena;
 mod matrix_simba;
 mod matrix_slice;
 mod norm;
 mod properties;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod row_major_storage;
+mod running_covariance;
 mod scalar;
-mod statistics;
+pub(crate) mod statistics;
 mod swizzle;
+#[cfg(feature = "full-swizzle")]
+mod swizzle_full;
 mod unit;
 #[cfg(any(feature = "std", feature = "alloc"))]
 mod vec_storage;
 
 mod blas_uninit;
+#[cfg(any(
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+pub(crate) mod matrix4_simd;
 #[doc(hidden)]
 pub mod helper;
 mod interpolation;
@@ -51,8 +77,20 @@ pub use self::dimension::*;
 
 pub use self::alias::*;
 pub use self::alias_slice::*;
+pub use self::any_matrix::*;
 pub use self::array_storage::*;
+pub use self::array_vec_storage::*;
+pub use self::band::*;
+pub use self::lazy_transpose::*;
+pub use self::left_scalar::*;
+#[cfg(feature = "bytemuck")]
+pub use self::mapped_storage::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::matrix_arena::*;
 pub use self::matrix_slice::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::row_major_storage::*;
+pub use self::running_covariance::*;
 pub use self::storage::*;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use self::vec_storage::*;
@@ -5,10 +5,10 @@ use num::Zero;
 use std::ops::Neg;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Dim, DimName, Matrix, Normed, OMatrix, OVector};
+use crate::base::{Const, DefaultAllocator, Dim, DimName, Matrix, Normed, OMatrix, OVector};
 use crate::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
 use crate::storage::{Storage, StorageMut};
-use crate::{ComplexField, Scalar, SimdComplexField, Unit};
+use crate::{ComplexField, RealField, Scalar, SimdComplexField, Unit};
 use simba::scalar::ClosedNeg;
 use simba::simd::{SimdOption, SimdPartialOrd, SimdValue};
 
@@ -462,6 +462,126 @@ impl<T: Scalar, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
     }
 }
 
+/// # Operator norms
+impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The operator norm induced by the 1-norm, i.e., the largest 1-norm of the columns of this
+    /// matrix.
+    ///
+    /// Unlike `.lp_norm(1)`, which is the sum of the moduli of all the components, this is the
+    /// smallest value `n` such that `(self * x).lp_norm(1) <= n * x.lp_norm(1)` for any vector
+    /// `x`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1.0, -2.0, 3.0,
+    ///                        4.0, 5.0, -6.0);
+    /// assert_eq!(m.norm_one(), 9.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn norm_one(&self) -> T::RealField {
+        (0..self.ncols()).fold(T::RealField::zero(), |max, j| {
+            let norm = self
+                .column(j)
+                .iter()
+                .fold(T::RealField::zero(), |a, e| a + e.clone().modulus());
+            RealField::max(max, norm)
+        })
+    }
+
+    /// The operator norm induced by the uniform norm, i.e., the largest 1-norm of the rows of this
+    /// matrix.
+    ///
+    /// Unlike `.amax()`, which is the largest modulus of all the components, this is the
+    /// smallest value `n` such that `(self * x).amax() <= n * x.amax()` for any vector `x`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1.0, -2.0, 3.0,
+    ///                        4.0, 5.0, -6.0);
+    /// assert_eq!(m.norm_inf(), 15.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn norm_inf(&self) -> T::RealField {
+        (0..self.nrows()).fold(T::RealField::zero(), |max, i| {
+            let norm = self
+                .row(i)
+                .iter()
+                .fold(T::RealField::zero(), |a, e| a + e.clone().modulus());
+            RealField::max(max, norm)
+        })
+    }
+
+    /// The spectral norm of this matrix, i.e., the operator norm induced by the euclidean norm,
+    /// which is equal to its largest singular value.
+    ///
+    /// This is computed with a power iteration on `self.adjoint() * self`, without forming this
+    /// product, until two successive estimates differ by less than `eps` times their value. If
+    /// `max_niter` iterations are performed before convergence, the last estimate is returned
+    /// anyway. If `max_niter == 0`, the iteration continues indefinitely until convergence, or
+    /// until a non-finite estimate is reached, e.g., if `self` contains NaNs.
+    ///
+    /// This is much cheaper than computing the singular values of large matrices, but the
+    /// convergence is slow if the two largest singular values are close.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(3.0, 0.0, 0.0,
+    ///                        0.0, 0.0, -4.0);
+    /// assert_relative_eq!(m.spectral_norm(1.0e-12, 100), 4.0, epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn spectral_norm(&self, eps: T::RealField, max_niter: usize) -> T::RealField
+    where
+        DefaultAllocator: Allocator<T, R> + Allocator<T, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let amax = self.camax();
+
+        if amax.is_zero() {
+            return amax;
+        }
+
+        // Start from a generic vector, so that it is not orthogonal to the dominant right singular
+        // vector in practice: the sum of the rows of `self`, perturbed by a vector with irregularly
+        // spaced components.
+        let golden_ratio: T::RealField = crate::convert(0.618_033_988_749_894_9);
+        let mut v = OVector::from_fn_generic(ncols, Const::<1>, |j, _| {
+            let perturbation = (golden_ratio.clone() * crate::convert((j + 1) as f64)).fract();
+            T::from_real(amax.clone() * perturbation)
+        });
+        let ones = OVector::repeat_generic(nrows, Const::<1>, T::one());
+        v.gemv_ad(T::one(), self, &ones, T::one());
+
+        let mut w = OVector::zeros_generic(nrows, Const::<1>);
+        let mut sigma = T::RealField::zero();
+        let mut niter = 0;
+
+        loop {
+            let _ = v.normalize_mut();
+            w.gemv(T::one(), self, &v, T::zero());
+            let new_sigma = w.norm();
+            niter += 1;
+
+            // Stop on non-finite values, which never converge.
+            if !new_sigma.is_finite()
+                || (new_sigma.clone() - sigma).abs() <= eps.clone() * new_sigma.clone()
+                || niter == max_niter
+            {
+                return new_sigma;
+            }
+
+            sigma = new_sigma;
+            v.gemv_ad(T::one(), self, &w, T::zero());
+        }
+    }
+}
+
 impl<T: SimdComplexField, R: Dim, C: Dim> Normed for OMatrix<T, R, C>
 where
     DefaultAllocator: Allocator<T, R, C>,
@@ -0,0 +1,188 @@
+//! Parallel iterators for matrices, using `rayon`.
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+use crate::base::dimension::{Dim, U1};
+use crate::base::iter::{ColumnIter, ColumnIterMut};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{Matrix, MatrixSlice, MatrixSliceMut};
+
+/// A parallel iterator through the columns of a matrix.
+///
+/// This is created by `Matrix::par_column_iter`.
+#[derive(Debug)]
+pub struct ParColumnIter<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> {
+    mat: &'a Matrix<T, R, C, S>,
+}
+
+impl<'a, T, R, C, S> ParallelIterator for ParColumnIter<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    R: Dim,
+    C: Dim,
+    S: Sync + RawStorage<T, R, C>,
+{
+    type Item = MatrixSlice<'a, T, R, U1, S::RStride, S::CStride>;
+
+    fn drive_unindexed<Cons>(self, consumer: Cons) -> Cons::Result
+    where
+        Cons: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.mat.ncols())
+    }
+}
+
+impl<'a, T, R, C, S> IndexedParallelIterator for ParColumnIter<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    R: Dim,
+    C: Dim,
+    S: Sync + RawStorage<T, R, C>,
+{
+    fn len(&self) -> usize {
+        self.mat.ncols()
+    }
+
+    fn drive<Cons: Consumer<Self::Item>>(self, consumer: Cons) -> Cons::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ColumnIter::new(self.mat))
+    }
+}
+
+impl<'a, T, R, C, S> Producer for ColumnIter<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    R: Dim,
+    C: Dim,
+    S: Sync + RawStorage<T, R, C>,
+{
+    type Item = MatrixSlice<'a, T, R, U1, S::RStride, S::CStride>;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        ColumnIter::split_at(self, index)
+    }
+}
+
+/// A parallel iterator through the mutable columns of a matrix.
+///
+/// This is created by `Matrix::par_column_iter_mut`.
+#[derive(Debug)]
+pub struct ParColumnIterMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> {
+    mat: &'a mut Matrix<T, R, C, S>,
+}
+
+impl<'a, T, R, C, S> ParallelIterator for ParColumnIterMut<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    R: Dim,
+    C: Dim,
+    S: Send + Sync + RawStorageMut<T, R, C>,
+{
+    type Item = MatrixSliceMut<'a, T, R, U1, S::RStride, S::CStride>;
+
+    fn drive_unindexed<Cons>(self, consumer: Cons) -> Cons::Result
+    where
+        Cons: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.mat.ncols())
+    }
+}
+
+impl<'a, T, R, C, S> IndexedParallelIterator for ParColumnIterMut<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    R: Dim,
+    C: Dim,
+    S: Send + Sync + RawStorageMut<T, R, C>,
+{
+    fn len(&self) -> usize {
+        self.mat.ncols()
+    }
+
+    fn drive<Cons: Consumer<Self::Item>>(self, consumer: Cons) -> Cons::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ColumnIterMut::new(self.mat))
+    }
+}
+
+impl<'a, T, R, C, S> Producer for ColumnIterMut<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    R: Dim,
+    C: Dim,
+    S: Send + Sync + RawStorageMut<T, R, C>,
+{
+    type Item = MatrixSliceMut<'a, T, R, U1, S::RStride, S::CStride>;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        ColumnIterMut::split_at(self, index)
+    }
+}
+
+/// # Parallel iterators using `rayon`
+/// *Only available if compiled with the feature `parallel`.*
+impl<T: Send + Sync, R: Dim, C: Dim, S: Sync + RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Iterates in parallel through the columns of this matrix, using `rayon`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let m = DMatrix::from_fn(100, 50, |i, j| (i * j) as f64);
+    /// let norms: Vec<f64> = m.par_column_iter().map(|col| col.norm()).collect();
+    ///
+    /// assert_eq!(norms.len(), 50);
+    /// assert_eq!(norms[7], m.column(7).norm());
+    /// ```
+    #[inline]
+    pub fn par_column_iter(&self) -> ParColumnIter<'_, T, R, C, S> {
+        ParColumnIter { mat: self }
+    }
+}
+
+impl<T: Send + Sync, R: Dim, C: Dim, S: Send + Sync + RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// Iterates in parallel through the mutable columns of this matrix, using `rayon`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut m = DMatrix::from_fn(100, 50, |i, j| (i + j) as f64);
+    /// m.par_column_iter_mut().for_each(|mut col| {
+    ///     let _ = col.normalize_mut();
+    /// });
+    ///
+    /// assert!(m.column_iter().all(|col| (col.norm() - 1.0).abs() < 1.0e-10));
+    /// ```
+    #[inline]
+    pub fn par_column_iter_mut(&mut self) -> ParColumnIterMut<'_, T, R, C, S> {
+        ParColumnIterMut { mat: self }
+    }
+}
@@ -109,6 +109,100 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
 
         true
     }
+
+    /// Indicates if this is a symmetric matrix, i.e., a square matrix equal to its transpose
+    /// within a relative error of `eps`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3};
+    /// let mat = Matrix2::new(1.0, 2.0,
+    ///                        2.0 + 1.0e-10, 3.0);
+    /// assert!(mat.is_symmetric(1.0e-8));
+    /// assert!(!mat.is_symmetric(0.0));
+    /// assert!(!Matrix2x3::<f64>::zeros().is_symmetric(1.0e-8));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_symmetric(&self, eps: T::Epsilon) -> bool
+    where
+        T: RelativeEq,
+        T::Epsilon: Clone,
+    {
+        if !self.is_square() {
+            return false;
+        }
+
+        for j in 1..self.ncols() {
+            for i in 0..j {
+                if !relative_eq!(self[(i, j)], self[(j, i)], epsilon = eps.clone()) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Indicates if all the components below the diagonal of this matrix are zero within a
+    /// relative error of `eps`.
+    ///
+    /// The matrix does not have to be square.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    /// let mat = Matrix3x2::new(1.0, 2.0,
+    ///                          0.0, 3.0,
+    ///                          1.0e-10, 0.0);
+    /// assert!(mat.is_upper_triangular(1.0e-8));
+    /// assert!(!mat.is_lower_triangular(1.0e-8));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_upper_triangular(&self, eps: T::Epsilon) -> bool
+    where
+        T: Zero + RelativeEq,
+        T::Epsilon: Clone,
+    {
+        let (nrows, ncols) = self.shape();
+
+        for j in 0..ncols.min(nrows) {
+            for i in j + 1..nrows {
+                if !relative_eq!(self[(i, j)], T::zero(), epsilon = eps.clone()) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Indicates if all the components above the diagonal of this matrix are zero within a
+    /// relative error of `eps`.
+    ///
+    /// The matrix does not have to be square.
+    #[inline]
+    #[must_use]
+    pub fn is_lower_triangular(&self, eps: T::Epsilon) -> bool
+    where
+        T: Zero + RelativeEq,
+        T::Epsilon: Clone,
+    {
+        let (nrows, ncols) = self.shape();
+
+        for j in 1..ncols {
+            for i in 0..j.min(nrows) {
+                if !relative_eq!(self[(i, j)], T::zero(), epsilon = eps.clone()) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
 }
 
 impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
@@ -129,6 +223,129 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     }
 }
 
+impl<T: ComplexField, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Indicates if this is an hermitian matrix, i.e., a square matrix equal to its adjoint
+    /// within a relative error of `eps`.
+    ///
+    /// This implies that the imaginary parts of the diagonal components are zero within `eps`.
+    /// For real matrices, this is the same as `self.is_symmetric(eps)`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Complex, Matrix2};
+    /// let mat = Matrix2::new(Complex::new(1.0, 0.0), Complex::new(2.0, 3.0),
+    ///                        Complex::new(2.0, -3.0), Complex::new(4.0, 0.0));
+    /// assert!(mat.is_hermitian(1.0e-8));
+    /// assert!(!mat.is_symmetric(1.0e-8));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_hermitian(&self, eps: T::Epsilon) -> bool
+    where
+        T: RelativeEq,
+        T::Epsilon: Clone,
+    {
+        if !self.is_square() {
+            return false;
+        }
+
+        for j in 0..self.ncols() {
+            for i in 0..=j {
+                let conj = self[(j, i)].clone().conjugate();
+
+                if !relative_eq!(self[(i, j)], conj, epsilon = eps.clone()) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Indicates if this is a square matrix whose diagonal components are, in modulus, greater
+    /// than or equal to the sum of the moduli of the other components of their row.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let mat = Matrix3::new(4.0, -1.0, 2.0,
+    ///                        1.0, -2.0, 1.0,
+    ///                        0.0, 5.0, 6.0);
+    /// assert!(mat.is_diagonal_dominant());
+    /// assert!(!mat.transpose().is_diagonal_dominant());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_diagonal_dominant(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        for i in 0..self.nrows() {
+            let mut off_diagonal = T::RealField::zero();
+
+            for j in 0..self.ncols() {
+                if i != j {
+                    off_diagonal += self[(i, j)].clone().modulus();
+                }
+            }
+
+            if self[(i, i)].clone().modulus() < off_diagonal {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    /// Indicates if this is an hermitian positive-definite matrix.
+    ///
+    /// The matrix must be hermitian within a relative error of `eps` (see `is_hermitian`), and
+    /// its Cholesky decomposition must succeed with a real positive diagonal. Only the
+    /// lower-triangular part of the matrix is used by the Cholesky decomposition.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let mat = Matrix2::new(2.0, -1.0,
+    ///                        -1.0, 2.0);
+    /// assert!(mat.is_positive_definite(1.0e-8));
+    /// assert!(!(-mat).is_positive_definite(1.0e-8));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_positive_definite(&self, eps: T::Epsilon) -> bool
+    where
+        T: RelativeEq,
+        T::Epsilon: Clone,
+    {
+        self.is_hermitian(eps) && self.has_definite_cholesky()
+    }
+
+    /// Indicates if the Cholesky decomposition of this matrix succeeds with a real positive
+    /// diagonal.
+    ///
+    /// The square root of a negative real number does not fail for complex numbers, so the
+    /// decomposition of an indefinite complex matrix may succeed with an imaginary diagonal.
+    pub(crate) fn has_definite_cholesky(&self) -> bool {
+        match self.clone_owned().cholesky() {
+            Some(chol) => (0..self.nrows()).all(|i| {
+                let d = chol.l_dirty()[(i, i)].clone();
+                d.clone().real() > d.imaginary().abs()
+            }),
+            None => false,
+        }
+    }
+}
+
 impl<T: RealField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
 where
     DefaultAllocator: Allocator<T, D, D>,
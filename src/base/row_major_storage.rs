@@ -0,0 +1,270 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::base::allocator::Allocator;
+use crate::base::default_allocator::DefaultAllocator;
+use crate::base::dimension::{Const, Dim, Dynamic, U1};
+use crate::base::iter::MatrixIter;
+use crate::base::storage::{Owned, RawStorage, RawStorageMut, Storage};
+use crate::base::{DMatrix, DMatrixRowMajor, Matrix, Scalar, VecStorage};
+
+/*
+ *
+ * RawStorage.
+ *
+ */
+/// A Vec-based matrix data storage, with the components stored in row-major order.
+///
+/// Because matrices with this storage have the row stride `ncols` and the column stride `1`, they
+/// support the same operations and decompositions as matrix slices. Operations returning a new
+/// matrix return a column-major one, i.e., an `OMatrix`.
+#[repr(C)]
+#[derive(Eq, Debug, Clone, PartialEq)]
+pub struct RowMajorVecStorage<T, R: Dim, C: Dim> {
+    data: Vec<T>,
+    nrows: R,
+    ncols: C,
+}
+
+impl<T, R: Dim, C: Dim> RowMajorVecStorage<T, R, C> {
+    /// Creates a new row-major matrix data storage from the given vector and shape.
+    #[inline]
+    pub fn new(nrows: R, ncols: C, data: Vec<T>) -> Self {
+        assert!(
+            nrows.value() * ncols.value() == data.len(),
+            "Data storage buffer dimension mismatch."
+        );
+        Self { data, nrows, ncols }
+    }
+
+    /// The underlying data storage.
+    #[inline]
+    #[must_use]
+    pub fn as_vec(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// The number of elements on the underlying vector.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the underlying vector contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A slice containing all the components stored in this storage in row-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data[..]
+    }
+
+    /// A mutable slice containing all the components stored in this storage in row-major order.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data[..]
+    }
+}
+
+impl<T, R: Dim, C: Dim> From<RowMajorVecStorage<T, R, C>> for Vec<T> {
+    fn from(vec: RowMajorVecStorage<T, R, C>) -> Self {
+        vec.data
+    }
+}
+
+unsafe impl<T, R: Dim, C: Dim> RawStorage<T, R, C> for RowMajorVecStorage<T, R, C> {
+    type RStride = C;
+    type CStride = U1;
+
+    #[inline]
+    fn ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    #[inline]
+    fn shape(&self) -> (R, C) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    fn strides(&self) -> (Self::RStride, Self::CStride) {
+        (self.ncols, Const::<1>)
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        // Row-major and column-major orders only match for row and column vectors.
+        self.nrows.value() <= 1 || self.ncols.value() <= 1
+    }
+
+    #[inline]
+    unsafe fn as_slice_unchecked(&self) -> &[T] {
+        &self.data
+    }
+}
+
+unsafe impl<T: Scalar, R: Dim, C: Dim> Storage<T, R, C> for RowMajorVecStorage<T, R, C> {
+    #[inline]
+    fn into_owned(self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        self.clone_owned()
+    }
+
+    #[inline]
+    fn clone_owned(&self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let (nrows, ncols) = self.shape();
+        let it = MatrixIter::new(self).cloned();
+        DefaultAllocator::allocate_from_iterator(nrows, ncols, it)
+    }
+}
+
+unsafe impl<T, R: Dim, C: Dim> RawStorageMut<T, R, C> for RowMajorVecStorage<T, R, C> {
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut T {
+        self.data.as_mut_ptr()
+    }
+
+    #[inline]
+    unsafe fn as_mut_slice_unchecked(&mut self) -> &mut [T] {
+        &mut self.data[..]
+    }
+}
+
+/// # Row-major matrices
+impl<T, R: Dim, C: Dim> Matrix<T, R, C, RowMajorVecStorage<T, R, C>> {
+    /// Creates a row-major matrix from a vector containing its components in row-major order,
+    /// with dimensions specified by generic types instances.
+    ///
+    /// # Panics
+    /// Panics if the length of `data` differs from `nrows × ncols`.
+    #[inline]
+    pub fn from_row_major_vec_generic(nrows: R, ncols: C, data: Vec<T>) -> Self {
+        Self::from_data(RowMajorVecStorage::new(nrows, ncols, data))
+    }
+
+    /// The components of this matrix, in row-major order.
+    #[inline]
+    #[must_use]
+    pub fn as_row_major_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// The mutable components of this matrix, in row-major order.
+    #[inline]
+    #[must_use]
+    pub fn as_row_major_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_mut_slice()
+    }
+
+    /// Moves the components of this matrix, in row-major order, into a vector.
+    #[inline]
+    pub fn into_row_major_vec(self) -> Vec<T> {
+        self.data.into()
+    }
+}
+
+impl<T> DMatrixRowMajor<T> {
+    /// Creates a row-major matrix from a vector containing its components in row-major order.
+    ///
+    /// # Panics
+    /// Panics if the length of `data` differs from `nrows × ncols`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrixRowMajor, Matrix2x3};
+    /// let m = DMatrixRowMajor::from_row_major_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(m, Matrix2x3::new(1, 2, 3, 4, 5, 6));
+    /// assert_eq!(m.as_row_major_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn from_row_major_vec(nrows: usize, ncols: usize, data: Vec<T>) -> Self {
+        Self::from_row_major_vec_generic(Dynamic::new(nrows), Dynamic::new(ncols), data)
+    }
+
+    /// Reinterprets the buffer of this row-major matrix as a column-major matrix, which is the
+    /// transpose of `self`.
+    ///
+    /// This does not copy nor move any component.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrixRowMajor, Matrix3x2};
+    /// let m = DMatrixRowMajor::from_row_major_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let t = m.transpose_into_column_major();
+    ///
+    /// assert_eq!(t, Matrix3x2::new(1, 4, 2, 5, 3, 6));
+    /// assert_eq!(t.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn transpose_into_column_major(self) -> DMatrix<T> {
+        let (nrows, ncols) = self.shape_generic();
+        Matrix::from_data(VecStorage::new(ncols, nrows, self.data.into()))
+    }
+}
+
+impl<T> DMatrix<T> {
+    /// Reinterprets the buffer of this column-major matrix as a row-major matrix, which is the
+    /// transpose of `self`.
+    ///
+    /// This does not copy nor move any component.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix3x2};
+    /// let m = DMatrix::from_column_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    /// let t = m.transpose_into_row_major();
+    ///
+    /// assert_eq!(t, Matrix3x2::new(1, 2, 3, 4, 5, 6));
+    /// assert_eq!(t.as_row_major_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn transpose_into_row_major(self) -> DMatrixRowMajor<T> {
+        let (nrows, ncols) = self.shape_generic();
+        Matrix::from_row_major_vec_generic(ncols, nrows, self.data.into())
+    }
+}
+
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Copies this matrix into a new matrix with its components stored in row-major order.
+    ///
+    /// Use `.into_owned()` or `.clone_owned()` to convert a row-major matrix back to a
+    /// column-major matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// let row_major = m.to_row_major();
+    ///
+    /// assert_eq!(row_major.as_row_major_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(row_major.clone_owned(), m);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_row_major(&self) -> Matrix<T, R, C, RowMajorVecStorage<T, R, C>> {
+        let (nrows, ncols) = self.shape_generic();
+        let mut data = Vec::with_capacity(nrows.value() * ncols.value());
+
+        for i in 0..nrows.value() {
+            for j in 0..ncols.value() {
+                // Safety: the indices are within range.
+                data.push(unsafe { self.get_unchecked((i, j)).clone() });
+            }
+        }
+
+        Matrix::from_row_major_vec_generic(nrows, ncols, data)
+    }
+}
@@ -0,0 +1,217 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use crate::allocator::Allocator;
+use crate::base::constraint::{DimEq, ShapeConstraint};
+use crate::base::dimension::{Const, Dim, DimName};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Vector};
+use crate::RealField;
+
+/// The mean and covariance matrix of a stream of samples, updated one sample at a time.
+///
+/// This uses Welford's algorithm: the mean and the sum of the outer products of the deviations
+/// from the mean are updated with each new sample. This is numerically stable, unlike
+/// accumulating the sums of the samples and of their outer products, and does not require
+/// storing all the samples into a matrix.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Matrix2, RunningCovariance, Vector2};
+/// let mut stats = RunningCovariance::new();
+///
+/// for sample in &[Vector2::new(1.0, 2.0), Vector2::new(3.0, 0.0), Vector2::new(5.0, 4.0)] {
+///     stats.push(sample);
+/// }
+///
+/// assert_eq!(stats.count(), 3);
+/// assert_eq!(*stats.mean(), Vector2::new(3.0, 2.0));
+/// assert_relative_eq!(stats.covariance(), Matrix2::new(4.0, 2.0,
+///                                                      2.0, 4.0));
+/// ```
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D> +
+                           Allocator<T, D, D>,
+         OVector<T, D>: Serialize,
+         OMatrix<T, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D> +
+                           Allocator<T, D, D>,
+         OVector<T, D>: Deserialize<'de>,
+         OMatrix<T, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct RunningCovariance<T: RealField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+    count: usize,
+    mean: OVector<T, D>,
+    // Only the lower triangle is up to date.
+    scatter: OMatrix<T, D, D>,
+}
+
+impl<T: RealField, D: Dim> Copy for RunningCovariance<T, D>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+    OVector<T, D>: Copy,
+    OMatrix<T, D, D>: Copy,
+{
+}
+
+impl<T: RealField, D: DimName> RunningCovariance<T, D>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+    /// Creates statistics of an empty stream of statically-sized samples.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::new_generic(D::name())
+    }
+}
+
+impl<T: RealField, D: DimName> Default for RunningCovariance<T, D>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: RealField, D: Dim> RunningCovariance<T, D>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+    /// Creates statistics of an empty stream of samples of dimension `dim`.
+    ///
+    /// Use `Dynamic::new(dim)` for dynamically-sized samples.
+    #[inline]
+    #[must_use]
+    pub fn new_generic(dim: D) -> Self {
+        Self {
+            count: 0,
+            mean: Matrix::zeros_generic(dim, Const::<1>),
+            scatter: Matrix::zeros_generic(dim, dim),
+        }
+    }
+
+    /// The number of samples pushed so far.
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The mean of the samples pushed so far, or zero if there are none.
+    #[inline]
+    #[must_use]
+    pub fn mean(&self) -> &OVector<T, D> {
+        &self.mean
+    }
+
+    /// Updates these statistics with a new sample.
+    ///
+    /// # Panics
+    /// Panics if the dimension of `sample` differs from the dimension of the previous samples.
+    pub fn push<D2: Dim, S2>(&mut self, sample: &Vector<T, D2, S2>)
+    where
+        S2: Storage<T, D2>,
+        ShapeConstraint: DimEq<D, D2>,
+    {
+        assert_eq!(
+            self.mean.len(),
+            sample.len(),
+            "RunningCovariance: mismatched sample dimension."
+        );
+
+        let dim = self.mean.shape_generic().0;
+        let delta = Matrix::from_fn_generic(dim, Const::<1>, |i, _| {
+            sample[i].clone() - self.mean[i].clone()
+        });
+        self.push_deviation(delta);
+    }
+
+    /// Updates these statistics with a new sample, given by its deviation `delta` from the
+    /// current mean.
+    fn push_deviation(&mut self, delta: OVector<T, D>) {
+        self.count += 1;
+        let count: T = crate::convert(self.count as f64);
+        self.mean.axpy(count.clone().recip(), &delta, T::one());
+
+        // The deviation of the sample from the new mean is `delta * (count - 1) / count`.
+        let weight = (count.clone() - T::one()) / count;
+        self.scatter.syger(weight, &delta, &delta, T::one());
+    }
+
+    /// Merges the statistics of another stream of samples into these statistics.
+    ///
+    /// The result is the same, up to rounding errors, as if all the samples of `other` had been
+    /// pushed into `self`. This allows, e.g., to accumulate statistics in parallel.
+    ///
+    /// # Panics
+    /// Panics if the samples of `other` do not have the same dimension as the samples of `self`.
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            self.mean.len(),
+            other.mean.len(),
+            "RunningCovariance: mismatched sample dimension."
+        );
+
+        if other.count == 0 {
+            return;
+        }
+
+        let (n1, n2): (T, T) = (
+            crate::convert(self.count as f64),
+            crate::convert(other.count as f64),
+        );
+        self.count += other.count;
+        let count: T = crate::convert(self.count as f64);
+
+        let mut delta = other.mean.clone();
+        delta -= &self.mean;
+        self.mean.axpy(n2.clone() / count.clone(), &delta, T::one());
+
+        self.scatter += &other.scatter;
+        self.scatter
+            .syger(n1 * n2 / count, &delta, &delta, T::one());
+    }
+
+    /// The sample covariance matrix of the samples pushed so far, i.e., the sum of the outer
+    /// products of their deviations from the mean divided by `count - 1`.
+    ///
+    /// Returns zero if less than two samples have been pushed.
+    #[must_use]
+    pub fn covariance(&self) -> OMatrix<T, D, D> {
+        self.scaled_scatter(self.count.saturating_sub(1))
+    }
+
+    /// The population covariance matrix of the samples pushed so far, i.e., the sum of the
+    /// outer products of their deviations from the mean divided by `count`.
+    ///
+    /// Returns zero if no sample has been pushed.
+    #[must_use]
+    pub fn population_covariance(&self) -> OMatrix<T, D, D> {
+        self.scaled_scatter(self.count)
+    }
+
+    fn scaled_scatter(&self, denom: usize) -> OMatrix<T, D, D> {
+        let dim = self.scatter.shape_generic().0;
+
+        if denom == 0 {
+            return Matrix::zeros_generic(dim, dim);
+        }
+
+        let factor = crate::convert::<f64, T>(denom as f64).recip();
+        let mut res = self.scatter.clone() * factor;
+        res.fill_upper_triangle_with_lower_triangle();
+        res
+    }
+}
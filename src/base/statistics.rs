@@ -1,8 +1,13 @@
 use crate::allocator::Allocator;
-use crate::storage::RawStorage;
-use crate::{Const, DefaultAllocator, Dim, Matrix, OVector, RowOVector, Scalar, VectorSlice, U1};
+use crate::constraint::{DimEq, ShapeConstraint};
+use crate::storage::{RawStorage, Storage};
+use crate::{
+    Const, DefaultAllocator, Dim, Matrix, OVector, RealField, RowOVector, Scalar, Vector,
+    VectorSlice, U1,
+};
 use num::Zero;
 use simba::scalar::{ClosedAdd, Field, SupersetOf};
+use std::cmp::Ordering;
 use std::mem::MaybeUninit;
 
 /// # Folding on columns and rows
@@ -400,3 +405,503 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         })
     }
 }
+
+/// Adds `x` to the running sum `sum`, accumulating the rounding error of this addition into
+/// `compensation`, as in Neumaier's variant of Kahan's compensated summation algorithm.
+pub(crate) fn compensated_add<T: RealField>(sum: &mut T, compensation: &mut T, x: T) {
+    let t = sum.clone() + x.clone();
+
+    // Accumulate the low-order bits lost when computing `t`.
+    if sum.clone().abs() >= x.clone().abs() {
+        *compensation += (sum.clone() - t.clone()) + x;
+    } else {
+        *compensation += (x - t.clone()) + sum.clone();
+    }
+
+    *sum = t;
+}
+
+/// A running sum using Neumaier's variant of Kahan's compensated summation algorithm.
+pub(crate) struct CompensatedSum<T> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T: RealField> CompensatedSum<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            sum: T::zero(),
+            compensation: T::zero(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, x: T) {
+        compensated_add(&mut self.sum, &mut self.compensation, x)
+    }
+
+    pub(crate) fn value(self) -> T {
+        self.sum + self.compensation
+    }
+}
+
+/// # Compensated summation
+impl<T: RealField, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// The sum of all the elements of this matrix, computed with a compensated summation.
+    ///
+    /// This uses Neumaier's variant of the Kahan summation algorithm: the rounding error of each
+    /// addition is accumulated separately and added back to the result. The error of the result
+    /// is thus independent of the number of elements, whereas it grows linearly with it for
+    /// `.sum()`, at the cost of a slower computation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::DVector;
+    /// let v = DVector::from_fn(1_000_001, |i, _| if i == 0 { 1.0 } else { 1.0e-16 });
+    ///
+    /// assert_eq!(v.sum(), 1.0);
+    /// assert_eq!(v.sum_compensated(), 1.0000000001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sum_compensated(&self) -> T {
+        let mut sum = CompensatedSum::new();
+
+        for x in self.iter() {
+            sum.add(x.clone());
+        }
+
+        sum.value()
+    }
+
+    /// The mean of all the elements of this matrix, computed with a compensated summation.
+    ///
+    /// See `.sum_compensated()` for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.mean_compensated(), 3.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mean_compensated(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.sum_compensated() / crate::convert(self.len() as f64)
+        }
+    }
+
+    /// The dot product between two vectors or matrices (seen as vectors), computed with a
+    /// compensated summation.
+    ///
+    /// The rounding error of each product is recovered exactly with a fused multiply-add, and
+    /// accumulated with the rounding errors of the summation, as in `.sum_compensated()`. The
+    /// result is thus as accurate as if it was computed with twice the working precision, then
+    /// rounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let a = Vector3::new(1.0e16, 1.0, -1.0e16);
+    /// let b = Vector3::new(1.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(a.dot(&b), 0.0);
+    /// assert_eq!(a.dot_compensated(&b), 1.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn dot_compensated<R2: Dim, C2: Dim, SB>(&self, rhs: &Matrix<T, R2, C2, SB>) -> T
+    where
+        SB: RawStorage<T, R2, C2>,
+        ShapeConstraint: DimEq<R, R2> + DimEq<C, C2>,
+    {
+        assert!(
+            self.shape() == rhs.shape(),
+            "Dot product dimensions mismatch for shapes {:?} and {:?}.",
+            self.shape(),
+            rhs.shape(),
+        );
+
+        let mut sum = CompensatedSum::new();
+
+        for (a, b) in self.iter().zip(rhs.iter()) {
+            let product = a.clone() * b.clone();
+            let error = a.clone().mul_add(b.clone(), -product.clone());
+            sum.add(product);
+            sum.compensation += error;
+        }
+
+        sum.value()
+    }
+}
+
+/// # Weighted statistics
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// The weighted mean of all the elements of this matrix, where `weights[(i, j)]` is the
+    /// weight of `self[(i, j)]`.
+    ///
+    /// The weights must have a non-zero sum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let weights = Matrix2x3::new(1.0, 0.0, 0.0,
+    ///                              0.0, 0.0, 3.0);
+    /// assert_eq!(m.weighted_mean(&weights), 4.75);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn weighted_mean<R2: Dim, C2: Dim, SW>(&self, weights: &Matrix<T, R2, C2, SW>) -> T
+    where
+        T: Field,
+        SW: RawStorage<T, R2, C2>,
+        ShapeConstraint: DimEq<R, R2> + DimEq<C, C2>,
+    {
+        assert_eq!(
+            self.shape(),
+            weights.shape(),
+            "Weighted mean: mismatched weights dimensions."
+        );
+
+        if self.is_empty() {
+            T::zero()
+        } else {
+            let (sum, total) = self
+                .iter()
+                .zip(weights.iter())
+                .fold((T::zero(), T::zero()), |(sum, total), (x, w)| {
+                    (sum + w.clone() * x.clone(), total + w.clone())
+                });
+            sum / total
+        }
+    }
+
+    /// The weighted variance of all the elements of this matrix, where `weights[(i, j)]` is the
+    /// weight of `self[(i, j)]`.
+    ///
+    /// This is the weighted mean of the squared deviations from `self.weighted_mean(weights)`,
+    /// so it equals `.variance()` if all the weights are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    ///
+    /// let v = Vector3::new(1.0, 2.0, 5.0);
+    /// let weights = Vector3::new(1.0, 2.0, 1.0);
+    /// assert_eq!(v.weighted_variance(&weights), 2.25);
+    /// assert_eq!(v.weighted_variance(&Vector3::repeat(2.0)), v.variance());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn weighted_variance<R2: Dim, C2: Dim, SW>(&self, weights: &Matrix<T, R2, C2, SW>) -> T
+    where
+        T: Field,
+        SW: RawStorage<T, R2, C2>,
+        ShapeConstraint: DimEq<R, R2> + DimEq<C, C2>,
+    {
+        let mean = self.weighted_mean(weights);
+
+        if self.is_empty() {
+            T::zero()
+        } else {
+            let (sum, total) = self.iter().zip(weights.iter()).fold(
+                (T::zero(), T::zero()),
+                |(sum, total), (x, w)| {
+                    let dev = x.clone() - mean.clone();
+                    (sum + w.clone() * dev.clone() * dev, total + w.clone())
+                },
+            );
+            sum / total
+        }
+    }
+
+    /// The weighted mean of all the rows of this matrix, where `weights[i]` is the weight of the
+    /// `i`-th row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2, Vector3};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    /// let weights = Vector3::new(2.0, 1.0, 1.0);
+    /// assert_eq!(m.row_weighted_mean(&weights), RowVector2::new(2.5, 3.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_weighted_mean<R2: Dim, SW>(&self, weights: &Vector<T, R2, SW>) -> RowOVector<T, C>
+    where
+        T: Field,
+        SW: RawStorage<T, R2>,
+        DefaultAllocator: Allocator<T, U1, C>,
+        ShapeConstraint: DimEq<R, R2>,
+    {
+        self.compress_rows(|col| col.weighted_mean(weights))
+    }
+
+    /// The weighted variance of all the rows of this matrix, where `weights[i]` is the weight of
+    /// the `i`-th row.
+    #[inline]
+    #[must_use]
+    pub fn row_weighted_variance<R2: Dim, SW>(
+        &self,
+        weights: &Vector<T, R2, SW>,
+    ) -> RowOVector<T, C>
+    where
+        T: Field,
+        SW: RawStorage<T, R2>,
+        DefaultAllocator: Allocator<T, U1, C>,
+        ShapeConstraint: DimEq<R, R2>,
+    {
+        self.compress_rows(|col| col.weighted_variance(weights))
+    }
+
+    /// The weighted mean of all the columns of this matrix, where `weights[j]` is the weight of
+    /// the `j`-th column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2, Vector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let weights = Vector3::new(1.0, 0.0, 3.0);
+    /// assert_eq!(m.column_weighted_mean(&weights), Vector2::new(2.5, 5.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_weighted_mean<C2: Dim, SW>(&self, weights: &Vector<T, C2, SW>) -> OVector<T, R>
+    where
+        T: Field,
+        SW: RawStorage<T, C2>,
+        DefaultAllocator: Allocator<T, R>,
+        ShapeConstraint: DimEq<C, C2>,
+    {
+        assert_eq!(
+            self.ncols(),
+            weights.len(),
+            "Weighted mean: mismatched weights dimensions."
+        );
+
+        let nrows = self.shape_generic().0;
+        let mut res = OVector::zeros_generic(nrows, Const::<1>);
+        let mut total = T::zero();
+
+        for (col, w) in self.column_iter().zip(weights.iter()) {
+            res.axpy(w.clone(), &col, T::one());
+            total += w.clone();
+        }
+
+        if !self.is_empty() {
+            res.apply(|e| *e = e.clone() / total.clone());
+        }
+
+        res
+    }
+
+    /// The weighted variance of all the columns of this matrix, where `weights[j]` is the weight
+    /// of the `j`-th column.
+    #[inline]
+    #[must_use]
+    pub fn column_weighted_variance<C2: Dim, SW>(
+        &self,
+        weights: &Vector<T, C2, SW>,
+    ) -> OVector<T, R>
+    where
+        T: Field,
+        SW: RawStorage<T, C2>,
+        DefaultAllocator: Allocator<T, R>,
+        ShapeConstraint: DimEq<C, C2>,
+    {
+        let mean = self.column_weighted_mean(weights);
+        let nrows = self.shape_generic().0;
+        let mut res = OVector::zeros_generic(nrows, Const::<1>);
+        let mut total = T::zero();
+
+        for (col, w) in self.column_iter().zip(weights.iter()) {
+            for i in 0..nrows.value() {
+                let dev = col[i].clone() - mean[i].clone();
+                res[i] += w.clone() * dev.clone() * dev;
+            }
+
+            total += w.clone();
+        }
+
+        if !self.is_empty() {
+            res.apply(|e| *e = e.clone() / total.clone());
+        }
+
+        res
+    }
+}
+
+/// # Quantiles
+impl<T: RealField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The `q`-th quantile of all the elements of this matrix, where `0 ≤ q ≤ 1`.
+    ///
+    /// This interpolates linearly between the two elements closest to the rank `q * (n - 1)`
+    /// among the `n` sorted elements, which is the default definition used by NumPy and R. The
+    /// elements are not fully sorted: the quantile is found with a selection algorithm, in linear
+    /// time on average, on a copy of this matrix. NaNs are ordered after all the other numbers.
+    ///
+    /// # Panics
+    /// Panics if this matrix is empty or if `q` is not between 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(6.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 1.0);
+    /// assert_eq!(m.quantile(0.0), 1.0);
+    /// assert_eq!(m.quantile(0.1), 1.5);
+    /// assert_eq!(m.quantile(1.0), 6.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn quantile(&self, q: T) -> T
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut values = self.clone_owned();
+        select_quantile(values.as_mut_slice(), q)
+    }
+
+    /// The median of all the elements of this matrix, i.e., `self.quantile(0.5)`.
+    ///
+    /// # Panics
+    /// Panics if this matrix is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Vector3, Vector4};
+    ///
+    /// assert_eq!(Vector3::new(3.0, -1.0, 10.0).median(), 3.0);
+    /// assert_eq!(Vector4::new(3.0, -1.0, 10.0, 4.0).median(), 3.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn median(&self) -> T
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        self.quantile(crate::convert(0.5))
+    }
+
+    /// The `q`-th quantile of all the rows of this matrix, i.e., of each of its columns.
+    ///
+    /// See `.quantile(q)` for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 20.0,
+    ///                        3.0, 40.0,
+    ///                        2.0, 30.0);
+    /// assert_eq!(m.row_quantile(0.25), RowVector2::new(1.5, 25.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_quantile(&self, q: T) -> RowOVector<T, C>
+    where
+        DefaultAllocator: Allocator<T, U1, C> + Allocator<T, R>,
+    {
+        self.compress_rows(|col| col.quantile(q.clone()))
+    }
+
+    /// The median of all the rows of this matrix, i.e., of each of its columns.
+    #[inline]
+    #[must_use]
+    pub fn row_median(&self) -> RowOVector<T, C>
+    where
+        DefaultAllocator: Allocator<T, U1, C> + Allocator<T, R>,
+    {
+        self.row_quantile(crate::convert(0.5))
+    }
+
+    /// The `q`-th quantile of all the columns of this matrix, i.e., of each of its rows.
+    ///
+    /// See `.quantile(q)` for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 3.0, 2.0,
+    ///                        20.0, 40.0, 30.0);
+    /// assert_eq!(m.column_quantile(0.25), Vector2::new(1.5, 25.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_quantile(&self, q: T) -> OVector<T, R>
+    where
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        let nrows = self.shape_generic().0;
+        OVector::from_fn_generic(nrows, Const::<1>, |i, _| self.row(i).quantile(q.clone()))
+    }
+
+    /// The median of all the columns of this matrix, i.e., of each of its rows.
+    #[inline]
+    #[must_use]
+    pub fn column_median(&self) -> OVector<T, R>
+    where
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        self.column_quantile(crate::convert(0.5))
+    }
+}
+
+/// Compares `a` and `b`, ordering NaNs after all the other numbers.
+fn cmp_nan_last<T: RealField>(a: &T, b: &T) -> Ordering {
+    let is_nan = |x: &T| x.partial_cmp(x).is_none();
+    a.partial_cmp(b)
+        .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+}
+
+/// Computes the `q`-th quantile of `values`, which are reordered in the process.
+fn select_quantile<T: RealField>(values: &mut [T], q: T) -> T {
+    assert!(
+        !values.is_empty(),
+        "Quantile: the matrix must not be empty."
+    );
+    assert!(
+        q >= T::zero() && q <= T::one(),
+        "Quantile: the quantile must be between 0 and 1."
+    );
+
+    let rank = q * crate::convert::<f64, T>((values.len() - 1) as f64);
+    let lower_rank = rank.clone().floor();
+    let fract = rank - lower_rank.clone();
+    let (_, lower, greater) =
+        values.select_nth_unstable_by(lower_rank.to_subset_unchecked() as usize, cmp_nan_last);
+    let lower = lower.clone();
+
+    if fract.is_zero() {
+        lower
+    } else {
+        // The next element in sorted order is the smallest of the greater ones.
+        let upper = greater.iter().min_by(|a, b| cmp_nan_last(*a, *b)).unwrap();
+        lower.clone() + fract * (upper.clone() - lower)
+    }
+}
@@ -0,0 +1,465 @@
+use crate::base::{Const, DimName, Scalar, ToTypenum, Vector, Vector2, Vector3, Vector4};
+use crate::geometry::{Point, Point2, Point3, Point4};
+use crate::storage::{RawStorage, RawStorageMut};
+use typenum::{self, Cmp, Greater};
+
+// NOTE: the swizzles involving only the `x`, `y`, and `z` components of two- and
+// three-component results are always available (see `swizzle.rs`). The ones listed here
+// are only enabled by the `full-swizzle` feature because of their impact on compile times.
+// They are implemented for both vectors and points from the same lists below.
+
+macro_rules! impl_swizzle_full {
+    ($doc: expr, $Typenum: ty; $( where $BaseDim: ident: $( $name: ident() -> $Result: ident[$($i: expr),+] ),+ ;)* ) => {
+        $(
+            $(
+                #[doc = $doc]
+                #[inline]
+                #[must_use]
+                pub fn $name(&self) -> $Result<T>
+                where $Typenum: Cmp<typenum::$BaseDim, Output=Greater> {
+                    $Result::new($(self[$i].clone()),*)
+                }
+            )*
+        )*
+    }
+}
+
+macro_rules! impl_swizzle_set {
+    ($Typenum: ty; $( where $BaseDim: ident: $( $name: ident($($arg: ident),+) -> [$($i: expr),+] ),+ ;)* ) => {
+        $(
+            $(
+                /// Sets the given components of `self`, leaving the others unchanged.
+                #[inline]
+                pub fn $name(&mut self, $($arg: T),+)
+                where $Typenum: Cmp<typenum::$BaseDim, Output=Greater> {
+                    $(self[$i] = $arg;)+
+                }
+            )*
+        )*
+    }
+}
+
+// The full swizzles, returning a new `$R2`, `$R3`, or `$R4`.
+macro_rules! swizzle_full_getters {
+    ($doc: expr, $Typenum: ty, $R2: ident, $R3: ident, $R4: ident) => {
+        impl_swizzle_full!($doc, $Typenum;
+            where U0: xxxx() -> $R4[0, 0, 0, 0];
+
+            where U1: xxxy() -> $R4[0, 0, 0, 1],
+                      xxyx() -> $R4[0, 0, 1, 0],
+                      xxyy() -> $R4[0, 0, 1, 1],
+                      xyxx() -> $R4[0, 1, 0, 0],
+                      xyxy() -> $R4[0, 1, 0, 1],
+                      xyyx() -> $R4[0, 1, 1, 0],
+                      xyyy() -> $R4[0, 1, 1, 1],
+                      yxxx() -> $R4[1, 0, 0, 0],
+                      yxxy() -> $R4[1, 0, 0, 1],
+                      yxyx() -> $R4[1, 0, 1, 0],
+                      yxyy() -> $R4[1, 0, 1, 1],
+                      yyxx() -> $R4[1, 1, 0, 0],
+                      yyxy() -> $R4[1, 1, 0, 1],
+                      yyyx() -> $R4[1, 1, 1, 0],
+                      yyyy() -> $R4[1, 1, 1, 1];
+
+            where U2: xxxz() -> $R4[0, 0, 0, 2],
+                      xxyz() -> $R4[0, 0, 1, 2],
+                      xxzx() -> $R4[0, 0, 2, 0],
+                      xxzy() -> $R4[0, 0, 2, 1],
+                      xxzz() -> $R4[0, 0, 2, 2],
+                      xyxz() -> $R4[0, 1, 0, 2],
+                      xyyz() -> $R4[0, 1, 1, 2],
+                      xyzx() -> $R4[0, 1, 2, 0],
+                      xyzy() -> $R4[0, 1, 2, 1],
+                      xyzz() -> $R4[0, 1, 2, 2],
+                      xzxx() -> $R4[0, 2, 0, 0],
+                      xzxy() -> $R4[0, 2, 0, 1],
+                      xzxz() -> $R4[0, 2, 0, 2],
+                      xzyx() -> $R4[0, 2, 1, 0],
+                      xzyy() -> $R4[0, 2, 1, 1],
+                      xzyz() -> $R4[0, 2, 1, 2],
+                      xzzx() -> $R4[0, 2, 2, 0],
+                      xzzy() -> $R4[0, 2, 2, 1],
+                      xzzz() -> $R4[0, 2, 2, 2],
+                      yxxz() -> $R4[1, 0, 0, 2],
+                      yxyz() -> $R4[1, 0, 1, 2],
+                      yxzx() -> $R4[1, 0, 2, 0],
+                      yxzy() -> $R4[1, 0, 2, 1],
+                      yxzz() -> $R4[1, 0, 2, 2],
+                      yyxz() -> $R4[1, 1, 0, 2],
+                      yyyz() -> $R4[1, 1, 1, 2],
+                      yyzx() -> $R4[1, 1, 2, 0],
+                      yyzy() -> $R4[1, 1, 2, 1],
+                      yyzz() -> $R4[1, 1, 2, 2],
+                      yzxx() -> $R4[1, 2, 0, 0],
+                      yzxy() -> $R4[1, 2, 0, 1],
+                      yzxz() -> $R4[1, 2, 0, 2],
+                      yzyx() -> $R4[1, 2, 1, 0],
+                      yzyy() -> $R4[1, 2, 1, 1],
+                      yzyz() -> $R4[1, 2, 1, 2],
+                      yzzx() -> $R4[1, 2, 2, 0],
+                      yzzy() -> $R4[1, 2, 2, 1],
+                      yzzz() -> $R4[1, 2, 2, 2],
+                      zxxx() -> $R4[2, 0, 0, 0],
+                      zxxy() -> $R4[2, 0, 0, 1],
+                      zxxz() -> $R4[2, 0, 0, 2],
+                      zxyx() -> $R4[2, 0, 1, 0],
+                      zxyy() -> $R4[2, 0, 1, 1],
+                      zxyz() -> $R4[2, 0, 1, 2],
+                      zxzx() -> $R4[2, 0, 2, 0],
+                      zxzy() -> $R4[2, 0, 2, 1],
+                      zxzz() -> $R4[2, 0, 2, 2],
+                      zyxx() -> $R4[2, 1, 0, 0],
+                      zyxy() -> $R4[2, 1, 0, 1],
+                      zyxz() -> $R4[2, 1, 0, 2],
+                      zyyx() -> $R4[2, 1, 1, 0],
+                      zyyy() -> $R4[2, 1, 1, 1],
+                      zyyz() -> $R4[2, 1, 1, 2],
+                      zyzx() -> $R4[2, 1, 2, 0],
+                      zyzy() -> $R4[2, 1, 2, 1],
+                      zyzz() -> $R4[2, 1, 2, 2],
+                      zzxx() -> $R4[2, 2, 0, 0],
+                      zzxy() -> $R4[2, 2, 0, 1],
+                      zzxz() -> $R4[2, 2, 0, 2],
+                      zzyx() -> $R4[2, 2, 1, 0],
+                      zzyy() -> $R4[2, 2, 1, 1],
+                      zzyz() -> $R4[2, 2, 1, 2],
+                      zzzx() -> $R4[2, 2, 2, 0],
+                      zzzy() -> $R4[2, 2, 2, 1],
+                      zzzz() -> $R4[2, 2, 2, 2];
+
+            where U3: xw()   -> $R2[0, 3],
+                      yw()   -> $R2[1, 3],
+                      zw()   -> $R2[2, 3],
+                      wx()   -> $R2[3, 0],
+                      wy()   -> $R2[3, 1],
+                      wz()   -> $R2[3, 2],
+                      ww()   -> $R2[3, 3],
+                      xxw()  -> $R3[0, 0, 3],
+                      xyw()  -> $R3[0, 1, 3],
+                      xzw()  -> $R3[0, 2, 3],
+                      xwx()  -> $R3[0, 3, 0],
+                      xwy()  -> $R3[0, 3, 1],
+                      xwz()  -> $R3[0, 3, 2],
+                      xww()  -> $R3[0, 3, 3],
+                      yxw()  -> $R3[1, 0, 3],
+                      yyw()  -> $R3[1, 1, 3],
+                      yzw()  -> $R3[1, 2, 3],
+                      ywx()  -> $R3[1, 3, 0],
+                      ywy()  -> $R3[1, 3, 1],
+                      ywz()  -> $R3[1, 3, 2],
+                      yww()  -> $R3[1, 3, 3],
+                      zxw()  -> $R3[2, 0, 3],
+                      zyw()  -> $R3[2, 1, 3],
+                      zzw()  -> $R3[2, 2, 3],
+                      zwx()  -> $R3[2, 3, 0],
+                      zwy()  -> $R3[2, 3, 1],
+                      zwz()  -> $R3[2, 3, 2],
+                      zww()  -> $R3[2, 3, 3],
+                      wxx()  -> $R3[3, 0, 0],
+                      wxy()  -> $R3[3, 0, 1],
+                      wxz()  -> $R3[3, 0, 2],
+                      wxw()  -> $R3[3, 0, 3],
+                      wyx()  -> $R3[3, 1, 0],
+                      wyy()  -> $R3[3, 1, 1],
+                      wyz()  -> $R3[3, 1, 2],
+                      wyw()  -> $R3[3, 1, 3],
+                      wzx()  -> $R3[3, 2, 0],
+                      wzy()  -> $R3[3, 2, 1],
+                      wzz()  -> $R3[3, 2, 2],
+                      wzw()  -> $R3[3, 2, 3],
+                      wwx()  -> $R3[3, 3, 0],
+                      wwy()  -> $R3[3, 3, 1],
+                      wwz()  -> $R3[3, 3, 2],
+                      www()  -> $R3[3, 3, 3],
+                      xxxw() -> $R4[0, 0, 0, 3],
+                      xxyw() -> $R4[0, 0, 1, 3],
+                      xxzw() -> $R4[0, 0, 2, 3],
+                      xxwx() -> $R4[0, 0, 3, 0],
+                      xxwy() -> $R4[0, 0, 3, 1],
+                      xxwz() -> $R4[0, 0, 3, 2],
+                      xxww() -> $R4[0, 0, 3, 3],
+                      xyxw() -> $R4[0, 1, 0, 3],
+                      xyyw() -> $R4[0, 1, 1, 3],
+                      xyzw() -> $R4[0, 1, 2, 3],
+                      xywx() -> $R4[0, 1, 3, 0],
+                      xywy() -> $R4[0, 1, 3, 1],
+                      xywz() -> $R4[0, 1, 3, 2],
+                      xyww() -> $R4[0, 1, 3, 3],
+                      xzxw() -> $R4[0, 2, 0, 3],
+                      xzyw() -> $R4[0, 2, 1, 3],
+                      xzzw() -> $R4[0, 2, 2, 3],
+                      xzwx() -> $R4[0, 2, 3, 0],
+                      xzwy() -> $R4[0, 2, 3, 1],
+                      xzwz() -> $R4[0, 2, 3, 2],
+                      xzww() -> $R4[0, 2, 3, 3],
+                      xwxx() -> $R4[0, 3, 0, 0],
+                      xwxy() -> $R4[0, 3, 0, 1],
+                      xwxz() -> $R4[0, 3, 0, 2],
+                      xwxw() -> $R4[0, 3, 0, 3],
+                      xwyx() -> $R4[0, 3, 1, 0],
+                      xwyy() -> $R4[0, 3, 1, 1],
+                      xwyz() -> $R4[0, 3, 1, 2],
+                      xwyw() -> $R4[0, 3, 1, 3],
+                      xwzx() -> $R4[0, 3, 2, 0],
+                      xwzy() -> $R4[0, 3, 2, 1],
+                      xwzz() -> $R4[0, 3, 2, 2],
+                      xwzw() -> $R4[0, 3, 2, 3],
+                      xwwx() -> $R4[0, 3, 3, 0],
+                      xwwy() -> $R4[0, 3, 3, 1],
+                      xwwz() -> $R4[0, 3, 3, 2],
+                      xwww() -> $R4[0, 3, 3, 3],
+                      yxxw() -> $R4[1, 0, 0, 3],
+                      yxyw() -> $R4[1, 0, 1, 3],
+                      yxzw() -> $R4[1, 0, 2, 3],
+                      yxwx() -> $R4[1, 0, 3, 0],
+                      yxwy() -> $R4[1, 0, 3, 1],
+                      yxwz() -> $R4[1, 0, 3, 2],
+                      yxww() -> $R4[1, 0, 3, 3],
+                      yyxw() -> $R4[1, 1, 0, 3],
+                      yyyw() -> $R4[1, 1, 1, 3],
+                      yyzw() -> $R4[1, 1, 2, 3],
+                      yywx() -> $R4[1, 1, 3, 0],
+                      yywy() -> $R4[1, 1, 3, 1],
+                      yywz() -> $R4[1, 1, 3, 2],
+                      yyww() -> $R4[1, 1, 3, 3],
+                      yzxw() -> $R4[1, 2, 0, 3],
+                      yzyw() -> $R4[1, 2, 1, 3],
+                      yzzw() -> $R4[1, 2, 2, 3],
+                      yzwx() -> $R4[1, 2, 3, 0],
+                      yzwy() -> $R4[1, 2, 3, 1],
+                      yzwz() -> $R4[1, 2, 3, 2],
+                      yzww() -> $R4[1, 2, 3, 3],
+                      ywxx() -> $R4[1, 3, 0, 0],
+                      ywxy() -> $R4[1, 3, 0, 1],
+                      ywxz() -> $R4[1, 3, 0, 2],
+                      ywxw() -> $R4[1, 3, 0, 3],
+                      ywyx() -> $R4[1, 3, 1, 0],
+                      ywyy() -> $R4[1, 3, 1, 1],
+                      ywyz() -> $R4[1, 3, 1, 2],
+                      ywyw() -> $R4[1, 3, 1, 3],
+                      ywzx() -> $R4[1, 3, 2, 0],
+                      ywzy() -> $R4[1, 3, 2, 1],
+                      ywzz() -> $R4[1, 3, 2, 2],
+                      ywzw() -> $R4[1, 3, 2, 3],
+                      ywwx() -> $R4[1, 3, 3, 0],
+                      ywwy() -> $R4[1, 3, 3, 1],
+                      ywwz() -> $R4[1, 3, 3, 2],
+                      ywww() -> $R4[1, 3, 3, 3],
+                      zxxw() -> $R4[2, 0, 0, 3],
+                      zxyw() -> $R4[2, 0, 1, 3],
+                      zxzw() -> $R4[2, 0, 2, 3],
+                      zxwx() -> $R4[2, 0, 3, 0],
+                      zxwy() -> $R4[2, 0, 3, 1],
+                      zxwz() -> $R4[2, 0, 3, 2],
+                      zxww() -> $R4[2, 0, 3, 3],
+                      zyxw() -> $R4[2, 1, 0, 3],
+                      zyyw() -> $R4[2, 1, 1, 3],
+                      zyzw() -> $R4[2, 1, 2, 3],
+                      zywx() -> $R4[2, 1, 3, 0],
+                      zywy() -> $R4[2, 1, 3, 1],
+                      zywz() -> $R4[2, 1, 3, 2],
+                      zyww() -> $R4[2, 1, 3, 3],
+                      zzxw() -> $R4[2, 2, 0, 3],
+                      zzyw() -> $R4[2, 2, 1, 3],
+                      zzzw() -> $R4[2, 2, 2, 3],
+                      zzwx() -> $R4[2, 2, 3, 0],
+                      zzwy() -> $R4[2, 2, 3, 1],
+                      zzwz() -> $R4[2, 2, 3, 2],
+                      zzww() -> $R4[2, 2, 3, 3],
+                      zwxx() -> $R4[2, 3, 0, 0],
+                      zwxy() -> $R4[2, 3, 0, 1],
+                      zwxz() -> $R4[2, 3, 0, 2],
+                      zwxw() -> $R4[2, 3, 0, 3],
+                      zwyx() -> $R4[2, 3, 1, 0],
+                      zwyy() -> $R4[2, 3, 1, 1],
+                      zwyz() -> $R4[2, 3, 1, 2],
+                      zwyw() -> $R4[2, 3, 1, 3],
+                      zwzx() -> $R4[2, 3, 2, 0],
+                      zwzy() -> $R4[2, 3, 2, 1],
+                      zwzz() -> $R4[2, 3, 2, 2],
+                      zwzw() -> $R4[2, 3, 2, 3],
+                      zwwx() -> $R4[2, 3, 3, 0],
+                      zwwy() -> $R4[2, 3, 3, 1],
+                      zwwz() -> $R4[2, 3, 3, 2],
+                      zwww() -> $R4[2, 3, 3, 3],
+                      wxxx() -> $R4[3, 0, 0, 0],
+                      wxxy() -> $R4[3, 0, 0, 1],
+                      wxxz() -> $R4[3, 0, 0, 2],
+                      wxxw() -> $R4[3, 0, 0, 3],
+                      wxyx() -> $R4[3, 0, 1, 0],
+                      wxyy() -> $R4[3, 0, 1, 1],
+                      wxyz() -> $R4[3, 0, 1, 2],
+                      wxyw() -> $R4[3, 0, 1, 3],
+                      wxzx() -> $R4[3, 0, 2, 0],
+                      wxzy() -> $R4[3, 0, 2, 1],
+                      wxzz() -> $R4[3, 0, 2, 2],
+                      wxzw() -> $R4[3, 0, 2, 3],
+                      wxwx() -> $R4[3, 0, 3, 0],
+                      wxwy() -> $R4[3, 0, 3, 1],
+                      wxwz() -> $R4[3, 0, 3, 2],
+                      wxww() -> $R4[3, 0, 3, 3],
+                      wyxx() -> $R4[3, 1, 0, 0],
+                      wyxy() -> $R4[3, 1, 0, 1],
+                      wyxz() -> $R4[3, 1, 0, 2],
+                      wyxw() -> $R4[3, 1, 0, 3],
+                      wyyx() -> $R4[3, 1, 1, 0],
+                      wyyy() -> $R4[3, 1, 1, 1],
+                      wyyz() -> $R4[3, 1, 1, 2],
+                      wyyw() -> $R4[3, 1, 1, 3],
+                      wyzx() -> $R4[3, 1, 2, 0],
+                      wyzy() -> $R4[3, 1, 2, 1],
+                      wyzz() -> $R4[3, 1, 2, 2],
+                      wyzw() -> $R4[3, 1, 2, 3],
+                      wywx() -> $R4[3, 1, 3, 0],
+                      wywy() -> $R4[3, 1, 3, 1],
+                      wywz() -> $R4[3, 1, 3, 2],
+                      wyww() -> $R4[3, 1, 3, 3],
+                      wzxx() -> $R4[3, 2, 0, 0],
+                      wzxy() -> $R4[3, 2, 0, 1],
+                      wzxz() -> $R4[3, 2, 0, 2],
+                      wzxw() -> $R4[3, 2, 0, 3],
+                      wzyx() -> $R4[3, 2, 1, 0],
+                      wzyy() -> $R4[3, 2, 1, 1],
+                      wzyz() -> $R4[3, 2, 1, 2],
+                      wzyw() -> $R4[3, 2, 1, 3],
+                      wzzx() -> $R4[3, 2, 2, 0],
+                      wzzy() -> $R4[3, 2, 2, 1],
+                      wzzz() -> $R4[3, 2, 2, 2],
+                      wzzw() -> $R4[3, 2, 2, 3],
+                      wzwx() -> $R4[3, 2, 3, 0],
+                      wzwy() -> $R4[3, 2, 3, 1],
+                      wzwz() -> $R4[3, 2, 3, 2],
+                      wzww() -> $R4[3, 2, 3, 3],
+                      wwxx() -> $R4[3, 3, 0, 0],
+                      wwxy() -> $R4[3, 3, 0, 1],
+                      wwxz() -> $R4[3, 3, 0, 2],
+                      wwxw() -> $R4[3, 3, 0, 3],
+                      wwyx() -> $R4[3, 3, 1, 0],
+                      wwyy() -> $R4[3, 3, 1, 1],
+                      wwyz() -> $R4[3, 3, 1, 2],
+                      wwyw() -> $R4[3, 3, 1, 3],
+                      wwzx() -> $R4[3, 3, 2, 0],
+                      wwzy() -> $R4[3, 3, 2, 1],
+                      wwzz() -> $R4[3, 3, 2, 2],
+                      wwzw() -> $R4[3, 3, 2, 3],
+                      wwwx() -> $R4[3, 3, 3, 0],
+                      wwwy() -> $R4[3, 3, 3, 1],
+                      wwwz() -> $R4[3, 3, 3, 2],
+                      wwww() -> $R4[3, 3, 3, 3];
+        );
+    }
+}
+
+// The setters of two, three, or four distinct components.
+macro_rules! swizzle_full_setters {
+    ($Typenum: ty) => {
+        impl_swizzle_set!($Typenum;
+            where U1: set_xy(x, y) -> [0, 1],
+                      set_yx(y, x) -> [1, 0];
+
+            where U2: set_xz(x, z) -> [0, 2],
+                      set_yz(y, z) -> [1, 2],
+                      set_zx(z, x) -> [2, 0],
+                      set_zy(z, y) -> [2, 1],
+                      set_xyz(x, y, z) -> [0, 1, 2],
+                      set_xzy(x, z, y) -> [0, 2, 1],
+                      set_yxz(y, x, z) -> [1, 0, 2],
+                      set_yzx(y, z, x) -> [1, 2, 0],
+                      set_zxy(z, x, y) -> [2, 0, 1],
+                      set_zyx(z, y, x) -> [2, 1, 0];
+
+            where U3: set_xw(x, w) -> [0, 3],
+                      set_yw(y, w) -> [1, 3],
+                      set_zw(z, w) -> [2, 3],
+                      set_wx(w, x) -> [3, 0],
+                      set_wy(w, y) -> [3, 1],
+                      set_wz(w, z) -> [3, 2],
+                      set_xyw(x, y, w) -> [0, 1, 3],
+                      set_xzw(x, z, w) -> [0, 2, 3],
+                      set_xwy(x, w, y) -> [0, 3, 1],
+                      set_xwz(x, w, z) -> [0, 3, 2],
+                      set_yxw(y, x, w) -> [1, 0, 3],
+                      set_yzw(y, z, w) -> [1, 2, 3],
+                      set_ywx(y, w, x) -> [1, 3, 0],
+                      set_ywz(y, w, z) -> [1, 3, 2],
+                      set_zxw(z, x, w) -> [2, 0, 3],
+                      set_zyw(z, y, w) -> [2, 1, 3],
+                      set_zwx(z, w, x) -> [2, 3, 0],
+                      set_zwy(z, w, y) -> [2, 3, 1],
+                      set_wxy(w, x, y) -> [3, 0, 1],
+                      set_wxz(w, x, z) -> [3, 0, 2],
+                      set_wyx(w, y, x) -> [3, 1, 0],
+                      set_wyz(w, y, z) -> [3, 1, 2],
+                      set_wzx(w, z, x) -> [3, 2, 0],
+                      set_wzy(w, z, y) -> [3, 2, 1],
+                      set_xyzw(x, y, z, w) -> [0, 1, 2, 3],
+                      set_xywz(x, y, w, z) -> [0, 1, 3, 2],
+                      set_xzyw(x, z, y, w) -> [0, 2, 1, 3],
+                      set_xzwy(x, z, w, y) -> [0, 2, 3, 1],
+                      set_xwyz(x, w, y, z) -> [0, 3, 1, 2],
+                      set_xwzy(x, w, z, y) -> [0, 3, 2, 1],
+                      set_yxzw(y, x, z, w) -> [1, 0, 2, 3],
+                      set_yxwz(y, x, w, z) -> [1, 0, 3, 2],
+                      set_yzxw(y, z, x, w) -> [1, 2, 0, 3],
+                      set_yzwx(y, z, w, x) -> [1, 2, 3, 0],
+                      set_ywxz(y, w, x, z) -> [1, 3, 0, 2],
+                      set_ywzx(y, w, z, x) -> [1, 3, 2, 0],
+                      set_zxyw(z, x, y, w) -> [2, 0, 1, 3],
+                      set_zxwy(z, x, w, y) -> [2, 0, 3, 1],
+                      set_zyxw(z, y, x, w) -> [2, 1, 0, 3],
+                      set_zywx(z, y, w, x) -> [2, 1, 3, 0],
+                      set_zwxy(z, w, x, y) -> [2, 3, 0, 1],
+                      set_zwyx(z, w, y, x) -> [2, 3, 1, 0],
+                      set_wxyz(w, x, y, z) -> [3, 0, 1, 2],
+                      set_wxzy(w, x, z, y) -> [3, 0, 2, 1],
+                      set_wyxz(w, y, x, z) -> [3, 1, 0, 2],
+                      set_wyzx(w, y, z, x) -> [3, 1, 2, 0],
+                      set_wzxy(w, z, x, y) -> [3, 2, 0, 1],
+                      set_wzyx(w, z, y, x) -> [3, 2, 1, 0];
+        );
+    }
+}
+
+/// # Full swizzling
+impl<T: Scalar, D, S: RawStorage<T, D>> Vector<T, D, S>
+where
+    D: DimName + ToTypenum,
+{
+    swizzle_full_getters!(
+        "Builds a new vector from components of `self`.",
+        D::Typenum,
+        Vector2,
+        Vector3,
+        Vector4
+    );
+}
+
+/// # Swizzling setters
+impl<T: Scalar, D, S: RawStorageMut<T, D>> Vector<T, D, S>
+where
+    D: DimName + ToTypenum,
+{
+    swizzle_full_setters!(D::Typenum);
+}
+
+/// # Full swizzling
+impl<T: Scalar, const D: usize> Point<T, D>
+where
+    Const<D>: ToTypenum,
+{
+    swizzle_full_getters!(
+        "Builds a new point from components of `self`.",
+        <Const<D> as ToTypenum>::Typenum,
+        Point2,
+        Point3,
+        Point4
+    );
+}
+
+/// # Swizzling setters
+impl<T: Scalar, const D: usize> Point<T, D>
+where
+    Const<D>: ToTypenum,
+{
+    swizzle_full_setters!(<Const<D> as ToTypenum>::Typenum);
+}
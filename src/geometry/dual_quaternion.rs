@@ -504,6 +504,43 @@ where
         quat.dual = -quat.real.clone() * quat.dual.clone() * quat.real.clone();
     }
 
+    /// Makes this dual quaternion represent a rigid motion again, after rounding errors of
+    /// repeated computations made it drift away from it.
+    ///
+    /// A unit dual quaternion represents a rigid motion if its real part has a unit norm, and if
+    /// its dual part is orthogonal to its real part. `Unit::renormalize` only restores the first
+    /// condition. This restores both, by normalizing the real part and then removing from the
+    /// dual part its component along the real part. The rotation and the translation of `self`
+    /// are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DualQuaternion, Quaternion, UnitDualQuaternion};
+    /// let qr = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let qd = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    /// let mut dq = UnitDualQuaternion::new_unchecked(DualQuaternion::from_real_and_dual(qr, qd));
+    /// dq.renormalize_rigid();
+    ///
+    /// assert_relative_eq!(dq.real.norm(), 1.0, epsilon = 1.0e-12);
+    /// assert_relative_eq!(dq.real.coords.dot(&dq.dual.coords), 0.0, epsilon = 1.0e-12);
+    /// assert_relative_eq!(
+    ///     UnitDualQuaternion::from_isometry(&dq.to_isometry()),
+    ///     dq,
+    ///     epsilon = 1.0e-12
+    /// );
+    /// ```
+    #[inline]
+    pub fn renormalize_rigid(&mut self) {
+        let quat = self.as_mut_unchecked();
+        let norm = quat.real.norm();
+        quat.real.unscale_mut(norm.clone());
+        quat.dual.unscale_mut(norm);
+
+        let dot = quat.real.coords.dot(&quat.dual.coords);
+        quat.dual -= quat.real.clone() * dot;
+    }
+
     /// The unit dual quaternion needed to make `self` and `other` coincide.
     ///
     /// The result is such that: `self.isometry_to(other) * self == other`.
@@ -593,8 +630,9 @@ where
     /// Screw linear interpolation between two unit quaternions. This creates a
     /// smooth arc from one dual-quaternion to another.
     ///
-    /// Panics if the angle between both quaternion is 180 degrees (in which case the interpolation
-    /// is not well-defined). Use `.try_sclerp` instead to avoid the panic.
+    /// The interpolation always follows the shortest screw motion, i.e., `other` is negated if
+    /// it lies on the opposite hemisphere as `self`. If both dual quaternions have the same
+    /// rotation part, this reduces to a linear interpolation of the translation.
     ///
     /// # Example
     /// ```
@@ -627,16 +665,18 @@ where
             .expect("DualQuaternion sclerp: ambiguous configuration.")
     }
 
-    /// Computes the screw-linear interpolation between two unit quaternions or returns `None`
-    /// if both quaternions are approximately 180 degrees apart (in which case the interpolation is
-    /// not well-defined).
+    /// Computes the screw-linear interpolation between two unit quaternions.
+    ///
+    /// This currently always returns `Some`: if the rotation parts of both dual quaternions
+    /// coincide, the screw motion degenerates to a pure translation which is interpolated
+    /// linearly.
     ///
     /// # Arguments
     /// * `self`: the first quaternion to interpolate from.
     /// * `other`: the second quaternion to interpolate toward.
     /// * `t`: the interpolation parameter. Should be between 0 and 1.
-    /// * `epsilon`: the value below which the sinus of the angle separating both quaternion
-    /// must be to return `None`.
+    /// * `epsilon`: the value below which the sinus of the half-angle separating both rotations
+    /// must be for the relative motion to be considered a pure translation.
     #[inline]
     #[must_use]
     pub fn try_sclerp(&self, other: &Self, t: T, epsilon: T) -> Option<Self>
@@ -660,7 +700,14 @@ where
         let difference = self.as_ref().conjugate() * other.as_ref();
         let norm_squared = difference.real.vector().norm_squared();
         if relative_eq!(norm_squared, T::zero(), epsilon = epsilon) {
-            return None;
+            // Pure translation: the screw axis is at infinity.
+            let dual = Quaternion::from_imag(difference.dual.vector() * t);
+            return Some(
+                self * UnitDualQuaternion::new_unchecked(DualQuaternion::from_real_and_dual(
+                    Quaternion::identity(),
+                    dual,
+                )),
+            );
         }
 
         let inverse_norm_squared = T::one() / norm_squared;
@@ -691,6 +738,67 @@ where
         )
     }
 
+    /// Blends several unit dual quaternions using dual-quaternion linear blending (DLB).
+    ///
+    /// This computes the normalized weighted sum of `dual_quaternions`, after negating the ones
+    /// lying on the opposite hemisphere as the first one so that all of them take the shortest
+    /// path. This is the usual blending operation for dual-quaternion skinning.
+    ///
+    /// Returns `None` if `dual_quaternions` is empty or if the weighted sum has a zero real
+    /// part. Panics if `dual_quaternions` and `weights` do not have the same length.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitDualQuaternion, UnitQuaternion, Vector3};
+    /// let dq1 = UnitDualQuaternion::from_parts(
+    ///     Vector3::new(0.0, 0.0, 0.0).into(),
+    ///     UnitQuaternion::from_euler_angles(0.0, 0.0, 0.0),
+    /// );
+    /// let dq2 = UnitDualQuaternion::from_parts(
+    ///     Vector3::new(0.0, 2.0, 0.0).into(),
+    ///     UnitQuaternion::from_euler_angles(0.0, 0.0, 1.0),
+    /// );
+    ///
+    /// let blend = UnitDualQuaternion::blend(&[dq1, dq2], &[0.5, 0.5]).unwrap();
+    /// assert_relative_eq!(blend.rotation().angle(), 0.5, epsilon = 1.0e-6);
+    /// ```
+    #[must_use]
+    pub fn blend(dual_quaternions: &[Self], weights: &[T]) -> Option<Self>
+    where
+        T: RealField,
+    {
+        assert_eq!(
+            dual_quaternions.len(),
+            weights.len(),
+            "Dual quaternion blending: the number of weights must match the number of dual quaternions."
+        );
+
+        let first = dual_quaternions.first()?;
+        let mut res = DualQuaternion::from_real_and_dual(Quaternion::zero(), Quaternion::zero());
+
+        for (dq, w) in dual_quaternions.iter().zip(weights.iter()) {
+            if first.as_ref().real.coords.dot(&dq.as_ref().real.coords) < T::zero() {
+                res -= dq.as_ref().clone() * w.clone();
+            } else {
+                res += dq.as_ref().clone() * w.clone();
+            }
+        }
+
+        let norm = res.real.norm();
+
+        if norm.is_zero() {
+            return None;
+        }
+
+        res /= norm;
+        // Enforce the orthogonality of the real and dual parts.
+        let proj = res.real.coords.dot(&res.dual.coords);
+        res.dual.coords -= &res.real.coords * proj;
+
+        Some(Self::new_unchecked(res))
+    }
+
     /// Return the rotation part of this unit dual quaternion.
     ///
     /// ```
@@ -0,0 +1,95 @@
+use std::cmp::Ordering;
+
+use simba::scalar::RealField;
+
+use crate::base::{Matrix3, Vector3};
+use crate::geometry::{Isometry3, Rotation3};
+
+/// # Inertia tensors
+impl<T: RealField> Matrix3<T> {
+    /// Expresses this inertia tensor in another frame.
+    ///
+    /// `self` must be the inertia tensor of a rigid body of mass `mass` with respect to its
+    /// center of mass, expressed in a local frame centered at the center of mass. `iso` maps
+    /// this local frame to the target frame. The result is the inertia tensor of the body with
+    /// respect to the origin of the target frame: the rotation part of `iso` conjugates the
+    /// tensor, and its translation part adds the term of the parallel axis theorem.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// # use nalgebra::{Isometry3, Matrix3, Vector3};
+    /// // A thin rod of mass 2 and length 1 along the local `x` axis.
+    /// let rod = Matrix3::from_diagonal(&Vector3::new(0.0, 1.0, 1.0)) / 6.0;
+    ///
+    /// // Rotate the rod along the `y` axis, and move its center to `y = 0.5`.
+    /// let iso = Isometry3::new(Vector3::y() * 0.5, Vector3::z() * FRAC_PI_2);
+    /// let inertia = rod.inertia_transform_by(2.0, &iso);
+    ///
+    /// // The moment of inertia of the rod about its end is `m * l² / 3`.
+    /// let expected = Matrix3::from_diagonal(&Vector3::new(2.0 / 3.0, 0.0, 2.0 / 3.0));
+    /// assert_relative_eq!(inertia, expected, epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn inertia_transform_by(&self, mass: T, iso: &Isometry3<T>) -> Self {
+        let rot = iso.rotation.clone().to_rotation_matrix();
+        let t = &iso.translation.vector;
+        let rotated = rot.matrix() * self * rot.matrix().transpose();
+        let shift = Matrix3::from_diagonal_element(t.norm_squared()) - t * t.transpose();
+
+        rotated + shift * mass
+    }
+
+    /// Computes the principal moments and principal axes of this inertia tensor.
+    ///
+    /// Returns the principal moments sorted in increasing order, and a rotation whose columns
+    /// are the corresponding principal axes, such that `self = R * diag(moments) * Rᵀ`. The
+    /// rotation maps the principal frame of the body to the frame this tensor is expressed in.
+    ///
+    /// Only the lower-triangular part of `self`, including its diagonal, is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let inertia = Matrix3::new(2.0, 1.0, 0.0,
+    ///                            1.0, 2.0, 0.0,
+    ///                            0.0, 0.0, 5.0);
+    /// let (moments, axes) = inertia.inertia_principal_axes();
+    ///
+    /// assert_relative_eq!(moments, Vector3::new(1.0, 3.0, 5.0), epsilon = 1.0e-12);
+    /// assert_relative_eq!(
+    ///     axes * Matrix3::from_diagonal(&moments) * axes.inverse(),
+    ///     inertia,
+    ///     epsilon = 1.0e-12
+    /// );
+    /// ```
+    #[must_use]
+    pub fn inertia_principal_axes(&self) -> (Vector3<T>, Rotation3<T>) {
+        let eigen = self.clone().symmetric_eigen();
+        let vals = &eigen.eigenvalues;
+        let vecs = &eigen.eigenvectors;
+
+        let mut perm = [0, 1, 2];
+        perm.sort_unstable_by(|&i, &j| vals[i].partial_cmp(&vals[j]).unwrap_or(Ordering::Equal));
+
+        let moments = Vector3::new(
+            vals[perm[0]].clone(),
+            vals[perm[1]].clone(),
+            vals[perm[2]].clone(),
+        );
+        let mut axes = Matrix3::from_columns(&[
+            vecs.column(perm[0]),
+            vecs.column(perm[1]),
+            vecs.column(perm[2]),
+        ]);
+
+        // The eigenvectors may form a reflection.
+        if axes.determinant() < T::zero() {
+            axes.column_mut(2).neg_mut();
+        }
+
+        (moments, Rotation3::from_matrix_unchecked(axes))
+    }
+}
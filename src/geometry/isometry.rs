@@ -16,8 +16,10 @@ use simba::simd::SimdRealField;
 use crate::base::allocator::Allocator;
 use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
 use crate::base::storage::Owned;
-use crate::base::{Const, DefaultAllocator, OMatrix, SVector, Scalar, Unit};
+use crate::base::{Const, DefaultAllocator, OMatrix, SMatrix, SVector, Scalar, Unit};
 use crate::geometry::{AbstractRotation, Point, Translation};
+#[cfg(feature = "bytemuck")]
+use crate::geometry::{Rotation, UnitComplex, UnitQuaternion};
 
 /// A direct isometry, i.e., a rotation followed by a translation (aka. a rigid-body motion).
 ///
@@ -175,6 +177,45 @@ impl<T: Scalar + Copy, R: Copy, const D: usize> Copy for Isometry<T, R, D> where
 {
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, R, const D: usize> bytemuck::Zeroable for Isometry<T, R, D>
+where
+    T: Scalar + bytemuck::Zeroable,
+    R: bytemuck::Zeroable,
+    SVector<T, D>: bytemuck::Zeroable,
+{
+}
+
+// NOTE: `Pod` is only implemented for rotations with the same scalar type as the translation,
+// since a generic rotation type could introduce padding between both fields.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const D: usize> bytemuck::Pod for Isometry<T, Rotation<T, D>, D>
+where
+    T: Scalar + bytemuck::Pod,
+    Rotation<T, D>: bytemuck::Pod,
+    SVector<T, D>: bytemuck::Pod,
+    Owned<T, Const<D>>: Copy,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Isometry<T, UnitQuaternion<T>, 3>
+where
+    T: Scalar + bytemuck::Pod,
+    UnitQuaternion<T>: bytemuck::Pod,
+    SVector<T, 3>: bytemuck::Pod,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Isometry<T, UnitComplex<T>, 2>
+where
+    T: Scalar + bytemuck::Pod,
+    UnitComplex<T>: bytemuck::Pod,
+    SVector<T, 2>: bytemuck::Pod,
+{
+}
+
 impl<T: Scalar, R: Clone, const D: usize> Clone for Isometry<T, R, D> {
     #[inline]
     fn clone(&self) -> Self {
@@ -489,6 +530,97 @@ where
     }
 }
 
+/// # Batch transformation of points and vectors
+impl<T: SimdRealField, R: AbstractRotation<T, D>, const D: usize> Isometry<T, R, D>
+where
+    T::Element: SimdRealField,
+{
+    /// The matrix of the rotational part of this isometry.
+    ///
+    /// The columns are obtained by rotating each canonical basis vector, so this works for
+    /// any kind of rotation, including unit quaternions and unit complex numbers.
+    #[inline]
+    pub(crate) fn rotation_matrix(&self) -> SMatrix<T, D, D> {
+        let mut res = SMatrix::<T, D, D>::identity();
+
+        for mut col in res.column_iter_mut() {
+            let rotated = self.rotation.transform_vector(&col.clone_owned());
+            col.copy_from(&rotated);
+        }
+
+        res
+    }
+
+    /// Transforms in-place each point of the given slice by this isometry.
+    ///
+    /// The rotation is converted only once to its matrix form so that each point is then
+    /// transformed by a single small matrix-vector product followed by a translation. This is
+    /// much cheaper than calling `transform_point` on each point when the rotation is a unit
+    /// quaternion, and is amenable to auto-vectorization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Isometry3, Translation3, UnitQuaternion, Vector3, Point3};
+    /// let tra = Translation3::new(0.0, 0.0, 3.0);
+    /// let rot = UnitQuaternion::from_scaled_axis(Vector3::y() * f32::consts::FRAC_PI_2);
+    /// let iso = Isometry3::from_parts(tra, rot);
+    ///
+    /// let mut points = [Point3::new(1.0, 2.0, 3.0), Point3::new(0.0, 0.0, 1.0)];
+    /// iso.transform_points(&mut points);
+    /// assert_relative_eq!(points[0], Point3::new(3.0, 2.0, 2.0), epsilon = 1.0e-6);
+    /// assert_relative_eq!(points[1], Point3::new(1.0, 0.0, 3.0), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn transform_points(&self, points: &mut [Point<T, D>]) {
+        let rotation = self.rotation_matrix();
+
+        for pt in points {
+            pt.coords = &rotation * &pt.coords + &self.translation.vector;
+        }
+    }
+
+    /// Transforms in-place each vector of the given slice by this isometry, ignoring the
+    /// translation component of the isometry.
+    ///
+    /// See `transform_points` for details regarding the performance of this method.
+    #[inline]
+    pub fn transform_vectors(&self, vectors: &mut [SVector<T, D>]) {
+        let rotation = self.rotation_matrix();
+
+        for v in vectors {
+            *v = &rotation * &*v;
+        }
+    }
+
+    /// Transforms in-place each point of the given slice by the inverse of this isometry.
+    ///
+    /// See `transform_points` for details regarding the performance of this method.
+    #[inline]
+    pub fn inverse_transform_points(&self, points: &mut [Point<T, D>]) {
+        let rotation = self.rotation_matrix();
+
+        for pt in points {
+            pt.coords = rotation.tr_mul(&(&pt.coords - &self.translation.vector));
+        }
+    }
+
+    /// Transforms in-place each vector of the given slice by the inverse of this isometry,
+    /// ignoring the translation component of the isometry.
+    ///
+    /// See `transform_points` for details regarding the performance of this method.
+    #[inline]
+    pub fn inverse_transform_vectors(&self, vectors: &mut [SVector<T, D>]) {
+        let rotation = self.rotation_matrix();
+
+        for v in vectors {
+            *v = rotation.tr_mul(v);
+        }
+    }
+}
+
 // NOTE: we don't require `R: Rotation<...>` here because this is not useful for the implementation
 // and makes it hard to use it, e.g., for Transform × Isometry implementation.
 // This is OK since all constructors of the isometry enforce the Rotation bound already (and
@@ -275,8 +275,8 @@ macro_rules! look_at_isometry_construction_impl(
         /// # Arguments
         ///   * eye - The observer position.
         ///   * target - The target position.
-        ///   * up - Vertical direction. The only requirement of this parameter is to not be collinear
-        ///   to `eye - at`. Non-collinearity is not checked.
+        ///   * up - Vertical direction. If it is collinear to `target - eye`, a horizontal axis is
+        ///   chosen deterministically, see `Rotation3::face_towards`.
         ///
         /// # Example
         ///
@@ -326,8 +326,9 @@ macro_rules! look_at_isometry_construction_impl(
         /// # Arguments
         ///   * eye - The eye position.
         ///   * target - The target position.
-        ///   * up - A vector approximately aligned with required the vertical axis. The only
-        ///   requirement of this parameter is to not be collinear to `target - eye`.
+        ///   * up - A vector approximately aligned with required the vertical axis. If it is
+        ///   collinear to `target - eye`, a horizontal axis is chosen deterministically, see
+        ///   `Rotation3::face_towards`.
         ///
         /// # Example
         ///
@@ -354,7 +355,48 @@ macro_rules! look_at_isometry_construction_impl(
                           target: &Point3<T>,
                           up:     &Vector3<T>)
                           -> Self {
-            let rotation = $RotId::look_at_rh(&(target - eye), up);
+            Self::look_to_rh(eye, &(target - eye), up)
+        }
+
+        /// Builds a right-handed view matrix from the position of the eye and its view direction.
+        ///
+        /// It maps the view direction `dir` to the **negative** `z` axis and the `eye` to the
+        /// origin. This is the same as `look_at_rh(eye, eye + dir, up)`, for a camera that is
+        /// given a direction instead of a target.
+        ///
+        /// # Arguments
+        ///   * eye - The eye position.
+        ///   * dir - The view direction. It does not need to be normalized.
+        ///   * up - A vector approximately aligned with required the vertical axis. If it is
+        ///   collinear to `dir`, a horizontal axis is chosen deterministically, see
+        ///   `Rotation3::face_towards`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # #[macro_use] extern crate approx;
+        /// # use nalgebra::{Isometry3, IsometryMatrix3, Point3, Vector3};
+        /// let eye = Point3::new(1.0, 2.0, 3.0);
+        /// let dir = Vector3::new(2.0, 0.0, 0.0);
+        /// let up = Vector3::y();
+        ///
+        /// let iso = Isometry3::look_to_rh(&eye, &dir, &up);
+        /// assert_eq!(iso * eye, Point3::origin());
+        /// assert_relative_eq!(iso * Vector3::x(), -Vector3::z());
+        ///
+        /// let iso = IsometryMatrix3::look_to_rh(&eye, &dir, &up);
+        /// assert_relative_eq!(iso * Vector3::x(), -Vector3::z());
+        ///
+        /// // Looking straight down doesn't produce NaNs.
+        /// let iso = Isometry3::look_to_rh(&eye, &-up, &up);
+        /// assert_relative_eq!(iso * -up, -Vector3::z(), epsilon = 1.0e-7);
+        /// ```
+        #[inline]
+        pub fn look_to_rh(eye: &Point3<T>,
+                          dir: &Vector3<T>,
+                          up:  &Vector3<T>)
+                          -> Self {
+            let rotation = $RotId::look_at_rh(dir, up);
             let trans    = &rotation * (-eye);
 
             Self::from_parts(Translation::from(trans.coords), rotation)
@@ -369,8 +411,9 @@ macro_rules! look_at_isometry_construction_impl(
         /// # Arguments
         ///   * eye - The eye position.
         ///   * target - The target position.
-        ///   * up - A vector approximately aligned with required the vertical axis. The only
-        ///   requirement of this parameter is to not be collinear to `target - eye`.
+        ///   * up - A vector approximately aligned with required the vertical axis. If it is
+        ///   collinear to `target - eye`, a horizontal axis is chosen deterministically, see
+        ///   `Rotation3::face_towards`.
         ///
         /// # Example
         ///
@@ -397,7 +440,44 @@ macro_rules! look_at_isometry_construction_impl(
                           target: &Point3<T>,
                           up:     &Vector3<T>)
                           -> Self {
-            let rotation = $RotId::look_at_lh(&(target - eye), up);
+            Self::look_to_lh(eye, &(target - eye), up)
+        }
+
+        /// Builds a left-handed view matrix from the position of the eye and its view direction.
+        ///
+        /// It maps the view direction `dir` to the **positive** `z` axis and the `eye` to the
+        /// origin. This is the same as `look_at_lh(eye, eye + dir, up)`, for a camera that is
+        /// given a direction instead of a target.
+        ///
+        /// # Arguments
+        ///   * eye - The eye position.
+        ///   * dir - The view direction. It does not need to be normalized.
+        ///   * up - A vector approximately aligned with required the vertical axis. If it is
+        ///   collinear to `dir`, a horizontal axis is chosen deterministically, see
+        ///   `Rotation3::face_towards`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # #[macro_use] extern crate approx;
+        /// # use nalgebra::{Isometry3, IsometryMatrix3, Point3, Vector3};
+        /// let eye = Point3::new(1.0, 2.0, 3.0);
+        /// let dir = Vector3::new(2.0, 0.0, 0.0);
+        /// let up = Vector3::y();
+        ///
+        /// let iso = Isometry3::look_to_lh(&eye, &dir, &up);
+        /// assert_eq!(iso * eye, Point3::origin());
+        /// assert_relative_eq!(iso * Vector3::x(), Vector3::z());
+        ///
+        /// let iso = IsometryMatrix3::look_to_lh(&eye, &dir, &up);
+        /// assert_relative_eq!(iso * Vector3::x(), Vector3::z());
+        /// ```
+        #[inline]
+        pub fn look_to_lh(eye: &Point3<T>,
+                          dir: &Vector3<T>,
+                          up:  &Vector3<T>)
+                          -> Self {
+            let rotation = $RotId::look_at_lh(dir, up);
             let trans    = &rotation * (-eye);
 
             Self::from_parts(Translation::from(trans.coords), rotation)
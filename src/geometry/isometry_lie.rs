@@ -0,0 +1,296 @@
+use simba::scalar::RealField;
+
+use crate::base::{Matrix3, Matrix4, Matrix6, Unit, Vector3, Vector6};
+use crate::geometry::{Isometry3, Point3, Rotation3, Translation3, UnitQuaternion};
+
+use super::rotation_lie::{alternating_inverse_factorial, even_series};
+
+/// The off-diagonal block `Q` of the left Jacobian of the SE(3) exponential map.
+fn se3_left_jacobian_q<T: RealField>(rho: &Vector3<T>, phi: &Vector3<T>) -> Matrix3<T> {
+    let theta2 = phi.norm_squared();
+    let rho_hat = rho.cross_matrix();
+    let phi_hat = phi.cross_matrix();
+    let phi_rho = &phi_hat * &rho_hat;
+    let rho_phi = &rho_hat * &phi_hat;
+    let phi_rho_phi = &phi_rho * &phi_hat;
+    let phi2_rho = &phi_hat * &phi_rho;
+    let rho_phi2 = &rho_phi * &phi_hat;
+
+    let (a, b, c) = if theta2 < T::one() {
+        // The coefficients of the series of `c` are `(-1)ᵏ (k + 1) / (2k + 5)!`.
+        (
+            even_series(theta2.clone(), |k| alternating_inverse_factorial(k, 3)),
+            even_series(theta2.clone(), |k| alternating_inverse_factorial(k, 4)),
+            even_series(theta2, |k| {
+                alternating_inverse_factorial(k, 5) * (k + 1) as f64
+            }),
+        )
+    } else {
+        let theta = theta2.clone().sqrt();
+        let two: T = crate::convert(2.0);
+        let three: T = crate::convert(3.0);
+        let (sin, cos) = theta.clone().sin_cos();
+        let theta3 = theta2.clone() * theta.clone();
+        let theta4 = theta2.clone() * theta2.clone();
+        (
+            (theta.clone() - sin.clone()) / theta3,
+            (theta2.clone() + two.clone() * cos.clone() - two.clone()) / (two.clone() * theta4),
+            (two.clone() * theta.clone() - three * sin + theta.clone() * cos)
+                / (two * theta2.clone() * theta2 * theta),
+        )
+    };
+
+    let half: T = crate::convert(0.5);
+    let three: T = crate::convert(3.0);
+
+    rho_hat * half
+        + (&phi_rho + &rho_phi + &phi_rho_phi) * a
+        + (&phi2_rho + &rho_phi2 - &phi_rho_phi * three) * b
+        + (phi_rho_phi * &phi_hat + phi2_rho * &phi_hat) * c
+}
+
+/// Assembles the 6x6 block-triangular matrix `[[diag, off_diag], [0, diag]]`.
+fn se3_block_triangular<T: RealField>(diag: &Matrix3<T>, off_diag: &Matrix3<T>) -> Matrix6<T> {
+    let mut res = Matrix6::zeros();
+    res.fixed_slice_mut::<3, 3>(0, 0).copy_from(diag);
+    res.fixed_slice_mut::<3, 3>(0, 3).copy_from(off_diag);
+    res.fixed_slice_mut::<3, 3>(3, 3).copy_from(diag);
+    res
+}
+
+/// # Lie group SE(3)
+///
+/// Twists (elements of the Lie algebra se(3)) are represented as 6D vectors `[ρ, φ]` where
+/// the first three components `ρ` are the translational part and the last three components `φ`
+/// are the rotation vector.
+impl<T: RealField> Isometry3<T> {
+    /// The exponential map of SE(3), converting a twist to the corresponding isometry.
+    ///
+    /// Note that the translational part of the twist is not the translation of the resulting
+    /// isometry unless the rotation vector is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Vector6};
+    /// let twist = Vector6::new(1.0, 2.0, 3.0, 0.1, 0.2, 0.3);
+    /// let iso = Isometry3::exp(&twist);
+    /// assert_relative_eq!(iso.log(), twist, epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn exp(twist: &Vector6<T>) -> Self {
+        let rho = twist.fixed_rows::<3>(0).into_owned();
+        let phi = twist.fixed_rows::<3>(3).into_owned();
+        let translation = Rotation3::left_jacobian(&phi) * rho;
+
+        Self::from_parts(
+            Translation3::from(translation),
+            UnitQuaternion::from_scaled_axis(phi),
+        )
+    }
+
+    /// The logarithm map of SE(3), converting this isometry to the corresponding twist.
+    ///
+    /// The rotation vector of the returned twist has a norm in `[0, pi]`.
+    #[must_use]
+    pub fn log(&self) -> Vector6<T> {
+        let phi = self.rotation.scaled_axis();
+        let rho = Rotation3::left_jacobian_inverse(&phi) * &self.translation.vector;
+
+        Vector6::new(
+            rho.x.clone(),
+            rho.y.clone(),
+            rho.z.clone(),
+            phi.x.clone(),
+            phi.y.clone(),
+            phi.z.clone(),
+        )
+    }
+
+    /// The hat operator, mapping a twist to the corresponding 4x4 matrix of se(3).
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Isometry3, Vector6};
+    /// let twist = Vector6::new(1.0, 2.0, 3.0, 0.1, 0.2, 0.3);
+    /// assert_eq!(Isometry3::vee(&Isometry3::hat(&twist)), twist);
+    /// ```
+    #[must_use]
+    pub fn hat(twist: &Vector6<T>) -> Matrix4<T> {
+        let mut res = Matrix4::zeros();
+        res.fixed_slice_mut::<3, 3>(0, 0)
+            .copy_from(&Rotation3::hat(&twist.fixed_rows::<3>(3).into_owned()));
+        res.fixed_slice_mut::<3, 1>(0, 3)
+            .copy_from(&twist.fixed_rows::<3>(0));
+        res
+    }
+
+    /// The vee operator, inverse of the hat operator.
+    ///
+    /// This extracts the twist from the 4x4 matrix `m` of se(3). Only the skew-symmetric part of
+    /// the upper-left 3x3 block of `m` is taken into account.
+    #[must_use]
+    pub fn vee(m: &Matrix4<T>) -> Vector6<T> {
+        let phi = Rotation3::vee(&m.fixed_slice::<3, 3>(0, 0).into_owned());
+        Vector6::new(
+            m[(0, 3)].clone(),
+            m[(1, 3)].clone(),
+            m[(2, 3)].clone(),
+            phi.x.clone(),
+            phi.y.clone(),
+            phi.z.clone(),
+        )
+    }
+
+    /// The left Jacobian of the SE(3) exponential map at `twist`.
+    ///
+    /// For a small perturbation `delta`, this satisfies `Isometry3::exp(&(twist + delta)) ≈
+    /// Isometry3::exp(&(left_jacobian(twist) * delta)) * Isometry3::exp(&twist)`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Vector6};
+    /// let twist = Vector6::new(1.0, 2.0, 3.0, 0.1, 0.2, 0.3);
+    /// let delta = Vector6::new(1.0e-6, -2.0e-6, 1.0e-6, 2.0e-6, 1.0e-6, -1.0e-6);
+    /// let jl = Isometry3::left_jacobian(&twist);
+    /// assert_relative_eq!(
+    ///     Isometry3::exp(&(twist + delta)),
+    ///     Isometry3::exp(&(jl * delta)) * Isometry3::exp(&twist),
+    ///     epsilon = 1.0e-10
+    /// );
+    /// ```
+    #[must_use]
+    pub fn left_jacobian(twist: &Vector6<T>) -> Matrix6<T> {
+        let rho = twist.fixed_rows::<3>(0).into_owned();
+        let phi = twist.fixed_rows::<3>(3).into_owned();
+        let j = Rotation3::left_jacobian(&phi);
+        let q = se3_left_jacobian_q(&rho, &phi);
+
+        se3_block_triangular(&j, &q)
+    }
+
+    /// The inverse of the left Jacobian of the SE(3) exponential map at `twist`.
+    #[must_use]
+    pub fn left_jacobian_inverse(twist: &Vector6<T>) -> Matrix6<T> {
+        let rho = twist.fixed_rows::<3>(0).into_owned();
+        let phi = twist.fixed_rows::<3>(3).into_owned();
+        let j_inv = Rotation3::left_jacobian_inverse(&phi);
+        let q = se3_left_jacobian_q(&rho, &phi);
+
+        se3_block_triangular(&j_inv, &-(&j_inv * q * &j_inv))
+    }
+
+    /// The right Jacobian of the SE(3) exponential map at `twist`.
+    ///
+    /// For a small perturbation `delta`, this satisfies `Isometry3::exp(&(twist + delta)) ≈
+    /// Isometry3::exp(&twist) * Isometry3::exp(&(right_jacobian(twist) * delta))`.
+    #[inline]
+    #[must_use]
+    pub fn right_jacobian(twist: &Vector6<T>) -> Matrix6<T> {
+        Self::left_jacobian(&-twist)
+    }
+
+    /// The inverse of the right Jacobian of the SE(3) exponential map at `twist`.
+    #[inline]
+    #[must_use]
+    pub fn right_jacobian_inverse(twist: &Vector6<T>) -> Matrix6<T> {
+        Self::left_jacobian_inverse(&-twist)
+    }
+
+    /// The 6x6 adjoint matrix of this isometry.
+    ///
+    /// Given a twist `[linear, angular]` expressed in the local frame of this isometry, multiplying
+    /// it by this matrix yields the same twist expressed in the parent frame. The transpose of
+    /// the inverse of this matrix acts on wrenches the same way.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Vector3, Vector6};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    /// let twist = Vector6::new(0.1, 0.2, 0.3, 0.4, 0.5, 0.6);
+    /// assert_relative_eq!(
+    ///     Isometry3::exp(&(iso.adjoint() * twist)),
+    ///     iso * Isometry3::exp(&twist) * iso.inverse(),
+    ///     epsilon = 1.0e-7
+    /// );
+    /// ```
+    #[must_use]
+    pub fn adjoint(&self) -> Matrix6<T> {
+        let rot = self.rotation.clone().to_rotation_matrix().into_inner();
+        let t_rot = self.translation.vector.cross_matrix() * &rot;
+        let mut res = Matrix6::zeros();
+        res.fixed_slice_mut::<3, 3>(0, 0).copy_from(&rot);
+        res.fixed_slice_mut::<3, 3>(0, 3).copy_from(&t_rot);
+        res.fixed_slice_mut::<3, 3>(3, 3).copy_from(&rot);
+        res
+    }
+}
+
+/// # Screw decomposition
+impl<T: RealField> Isometry3<T> {
+    /// The screw axis of this isometry, following Chasles' theorem.
+    ///
+    /// Any rigid motion is a rotation about an axis followed by a translation along the same
+    /// axis. This returns `(point, axis, angle, translation)` where `point` is the point of the
+    /// screw axis closest to the origin, `axis` is its unit direction, `angle` is the rotation
+    /// angle about it, in `[0, π]`, and `translation` is the signed distance travelled along it.
+    ///
+    /// For pure translations, the axis goes through the origin in the direction of the
+    /// translation, and `angle` is zero. Returns `None` for the identity.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// # use nalgebra::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
+    /// // A rotation of π/2 about the axis going through (1, 0, 0) with direction z, followed by
+    /// // a translation of 2 along z.
+    /// let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2);
+    /// let iso = Translation3::new(1.0, 0.0, 2.0) * rot * Translation3::new(-1.0, 0.0, 0.0);
+    ///
+    /// let (point, axis, angle, translation) = iso.screw_axis().unwrap();
+    /// assert_relative_eq!(point, Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-12);
+    /// assert_relative_eq!(axis, Vector3::z_axis(), epsilon = 1.0e-12);
+    /// assert_relative_eq!(angle, FRAC_PI_2, epsilon = 1.0e-12);
+    /// assert_relative_eq!(translation, 2.0, epsilon = 1.0e-12);
+    ///
+    /// // A pure translation.
+    /// let (point, axis, angle, translation) = Isometry3::translation(0.0, 3.0, 0.0)
+    ///     .screw_axis()
+    ///     .unwrap();
+    /// assert_eq!((point, axis, angle), (Point3::origin(), Vector3::y_axis(), 0.0));
+    /// assert_eq!(translation, 3.0);
+    /// assert!(Isometry3::<f64>::identity().screw_axis().is_none());
+    /// ```
+    #[must_use]
+    pub fn screw_axis(&self) -> Option<(Point3<T>, Unit<Vector3<T>>, T, T)> {
+        let t = &self.translation.vector;
+
+        match self.rotation.axis() {
+            Some(axis) => {
+                let translation = axis.dot(t);
+                let t_perp = t - axis.scale(translation.clone());
+
+                // The point `p` of the axis orthogonal to it satisfies `(I - R) * p = t_perp`,
+                // which gives `p = (t_perp + cot(θ / 2) * (axis × t_perp)) / 2`.
+                let q = self.rotation.quaternion();
+                let cot_half_angle = q.scalar().abs() / q.vector().norm();
+                let point =
+                    (axis.cross(&t_perp) * cot_half_angle + t_perp) * crate::convert::<_, T>(0.5);
+
+                Some((
+                    Point3::from(point),
+                    axis,
+                    self.rotation.angle(),
+                    translation,
+                ))
+            }
+            None => {
+                let (axis, translation) = Unit::try_new_and_get(t.clone(), T::zero())?;
+                Some((Point3::origin(), axis, T::zero(), translation))
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::base::{Vector1, Vector2, Vector3, Vector6};
+use crate::geometry::{Isometry2, Isometry3, Rotation3, Translation2, UnitComplex, UnitQuaternion};
+use crate::RealField;
+
+/// Trait implemented by transformations that can be perturbed and differenced in their tangent
+/// space.
+///
+/// This provides the `⊞` (boxplus) and `⊟` (boxminus) operators commonly used by optimization
+/// and filtering algorithms working on manifolds. Perturbations are applied on the right-hand
+/// side, i.e., `x.oplus(delta)` is `x * exp(delta)`. For any `x` and `y`, we have
+/// `x.oplus(&y.ominus(&x)) == y` (up to rounding errors).
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Manifold, UnitQuaternion, Vector3};
+/// let x = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+/// let y = x.oplus(&Vector3::new(0.01, -0.02, 0.03));
+/// assert_relative_eq!(y.ominus(&x), Vector3::new(0.01, -0.02, 0.03), epsilon = 1.0e-7);
+/// assert_relative_eq!(x.oplus(&y.ominus(&x)), y, epsilon = 1.0e-7);
+/// ```
+pub trait Manifold: Sized {
+    /// The type of the vectors of the tangent space of this manifold.
+    type Tangent;
+
+    /// Perturbs `self` by the tangent vector `delta`: `self ⊞ delta`.
+    fn oplus(&self, delta: &Self::Tangent) -> Self;
+
+    /// The tangent vector `delta` such that `other ⊞ delta == self`: `self ⊟ other`.
+    fn ominus(&self, other: &Self) -> Self::Tangent;
+}
+
+impl<T: RealField> Manifold for UnitComplex<T> {
+    type Tangent = Vector1<T>;
+
+    #[inline]
+    fn oplus(&self, delta: &Vector1<T>) -> Self {
+        self * UnitComplex::new(delta.x.clone())
+    }
+
+    #[inline]
+    fn ominus(&self, other: &Self) -> Vector1<T> {
+        Vector1::new((other.inverse() * self).angle())
+    }
+}
+
+impl<T: RealField> Manifold for UnitQuaternion<T> {
+    type Tangent = Vector3<T>;
+
+    #[inline]
+    fn oplus(&self, delta: &Vector3<T>) -> Self {
+        self * UnitQuaternion::new(delta.clone())
+    }
+
+    #[inline]
+    fn ominus(&self, other: &Self) -> Vector3<T> {
+        (other.inverse() * self).scaled_axis()
+    }
+}
+
+impl<T: RealField> Manifold for Rotation3<T> {
+    type Tangent = Vector3<T>;
+
+    #[inline]
+    fn oplus(&self, delta: &Vector3<T>) -> Self {
+        self * Rotation3::new(delta.clone())
+    }
+
+    #[inline]
+    fn ominus(&self, other: &Self) -> Vector3<T> {
+        (other.inverse() * self).scaled_axis()
+    }
+}
+
+/// The tangent vectors of `Isometry2` are `[translation_x, translation_y, angle]`, using the
+/// exponential map of SE(2).
+impl<T: RealField> Manifold for Isometry2<T> {
+    type Tangent = Vector3<T>;
+
+    #[inline]
+    fn oplus(&self, delta: &Vector3<T>) -> Self {
+        let angle = delta.z.clone();
+        let (a, b) = se2_v_coefficients(angle.clone());
+        let rho = Vector2::new(delta.x.clone(), delta.y.clone());
+        let t = Vector2::new(
+            a.clone() * rho.x.clone() - b.clone() * rho.y.clone(),
+            b * rho.x.clone() + a * rho.y.clone(),
+        );
+
+        self * Isometry2::from_parts(Translation2::from(t), UnitComplex::new(angle))
+    }
+
+    #[inline]
+    fn ominus(&self, other: &Self) -> Vector3<T> {
+        let delta = other.inv_mul(self);
+        let angle = delta.rotation.angle();
+        let (a, b) = se2_v_coefficients(angle.clone());
+        let t = &delta.translation.vector;
+        let denom = a.clone() * a.clone() + b.clone() * b.clone();
+
+        Vector3::new(
+            (a.clone() * t.x.clone() + b.clone() * t.y.clone()) / denom.clone(),
+            (a * t.y.clone() - b * t.x.clone()) / denom,
+            angle,
+        )
+    }
+}
+
+/// The tangent vectors of `Isometry3` are `[translation, rotation]` twists, using the
+/// exponential map of SE(3) (see `Isometry3::exp`).
+impl<T: RealField> Manifold for Isometry3<T> {
+    type Tangent = Vector6<T>;
+
+    #[inline]
+    fn oplus(&self, delta: &Vector6<T>) -> Self {
+        self * Isometry3::exp(delta)
+    }
+
+    #[inline]
+    fn ominus(&self, other: &Self) -> Vector6<T> {
+        other.inv_mul(self).log()
+    }
+}
+
+/// The coefficients `(sin(angle) / angle, (1 - cos(angle)) / angle)` of the matrix `V` of the
+/// SE(2) exponential map.
+fn se2_v_coefficients<T: RealField>(angle: T) -> (T, T) {
+    let angle2 = angle.clone() * angle.clone();
+
+    if angle2 < T::default_epsilon().sqrt() {
+        let a = T::one() - angle2.clone() / crate::convert(6.0);
+        let b = angle.clone() / crate::convert(2.0) - angle * angle2 / crate::convert(24.0);
+        (a, b)
+    } else {
+        let (sin, cos) = angle.clone().sin_cos();
+        (sin / angle.clone(), (T::one() - cos) / angle)
+    }
+}
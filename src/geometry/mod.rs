@@ -4,9 +4,11 @@
 mod op_macros;
 
 mod abstract_rotation;
+mod manifold;
 
 mod point;
 mod point_alias;
+mod point_barycentric;
 mod point_construction;
 mod point_conversion;
 mod point_coordinates;
@@ -18,6 +20,7 @@ mod rotation_alias;
 mod rotation_construction;
 mod rotation_conversion;
 mod rotation_interpolation;
+mod rotation_lie;
 mod rotation_ops;
 mod rotation_simba; // TODO: implement Rotation methods.
 mod rotation_specialization;
@@ -26,6 +29,7 @@ mod quaternion;
 mod quaternion_construction;
 mod quaternion_conversion;
 mod quaternion_coordinates;
+mod quaternion_interpolation;
 mod quaternion_ops;
 mod quaternion_simba;
 
@@ -53,6 +57,7 @@ mod isometry_alias;
 mod isometry_construction;
 mod isometry_conversion;
 mod isometry_interpolation;
+mod isometry_lie;
 mod isometry_ops;
 mod isometry_simba;
 
@@ -74,11 +79,25 @@ mod transform_simba;
 
 mod reflection;
 mod reflection_alias;
+mod reflection_ops;
+
+mod shear;
+mod shear_alias;
+mod shear_ops;
+
+mod twist;
+
+mod inertia;
+mod planar;
 
 mod orthographic;
 mod perspective;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod spline;
+
 pub use self::abstract_rotation::AbstractRotation;
+pub use self::manifold::Manifold;
 
 pub use self::point::*;
 pub use self::point_alias::*;
@@ -107,5 +126,13 @@ pub use self::transform_alias::*;
 pub use self::reflection::*;
 pub use self::reflection_alias::*;
 
+pub use self::shear::*;
+pub use self::shear_alias::*;
+
+pub use self::twist::{Twist, Wrench};
+
 pub use self::orthographic::Orthographic3;
 pub use self::perspective::Perspective3;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::spline::{ArcLengthParameterization, HermiteSpline, IsometrySpline3};
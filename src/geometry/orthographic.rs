@@ -13,7 +13,7 @@ use simba::scalar::RealField;
 
 use crate::base::dimension::U3;
 use crate::base::storage::Storage;
-use crate::base::{Matrix4, Vector, Vector3};
+use crate::base::{Matrix4, Unit, Vector, Vector2, Vector3};
 
 use crate::geometry::{Point3, Projective3};
 
@@ -496,6 +496,48 @@ impl<T: RealField> Orthographic3<T> {
         )
     }
 
+    /// Computes the ray, in camera space, going through the pixel at `(px, py)` of a viewport of
+    /// size `viewport = (width, height)` pixels.
+    ///
+    /// The pixel coordinates have their origin at the top-left corner of the viewport and their
+    /// `y` axis pointing downward, so the center of the top-left pixel is at `(0.5, 0.5)`. The
+    /// returned ray starts on the near plane, and its direction is normalized. Multiply both by
+    /// the pose of the camera to get a ray in world space.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Orthographic3, Point3, Vector2, Vector3};
+    /// let proj = Orthographic3::new(1.0, 10.0, 2.0, 20.0, 0.1, 1000.0);
+    /// let viewport = Vector2::new(900.0, 1800.0);
+    ///
+    /// // All the rays follow the `-z` axis.
+    /// let (origin, dir) = proj.ray_for_pixel(&viewport, 0.0, 0.0);
+    /// assert_relative_eq!(origin, Point3::new(1.0, 20.0, -0.1), epsilon = 1.0e-10);
+    /// assert_relative_eq!(dir.into_inner(), -Vector3::z(), epsilon = 1.0e-10);
+    ///
+    /// let (origin, dir) = proj.ray_for_pixel(&viewport, 450.0, 1800.0);
+    /// assert_relative_eq!(origin, Point3::new(5.5, 2.0, -0.1), epsilon = 1.0e-10);
+    /// assert_relative_eq!(dir.into_inner(), -Vector3::z(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn ray_for_pixel(
+        &self,
+        viewport: &Vector2<T>,
+        px: T,
+        py: T,
+    ) -> (Point3<T>, Unit<Vector3<T>>) {
+        let two: T = crate::convert(2.0);
+        let ndc_x = two.clone() * px / viewport.x.clone() - T::one();
+        let ndc_y = T::one() - two * py / viewport.y.clone();
+
+        let origin = self.unproject_point(&Point3::new(ndc_x.clone(), ndc_y.clone(), -T::one()));
+        let far = self.unproject_point(&Point3::new(ndc_x, ndc_y, T::one()));
+        let dir = Unit::new_normalize(far - origin.clone());
+
+        (origin, dir)
+    }
+
     // TODO: when we get specialization, specialize the Mul impl instead.
     /// Projects a vector. Faster than matrix multiplication.
     ///
@@ -716,6 +758,82 @@ impl<T: RealField> Orthographic3<T> {
         self.matrix[(2, 2)] = -crate::convert::<_, T>(2.0) / (zfar.clone() - znear.clone());
         self.matrix[(2, 3)] = -(zfar.clone() + znear.clone()) / (zfar - znear);
     }
+
+    /// Returns this projection followed by a translation of `offset` in normalized device
+    /// coordinates.
+    ///
+    /// This is typically used for temporal anti-aliasing, where the projection is jittered by a
+    /// different sub-pixel offset at each frame. An offset of one pixel of a `width × height`
+    /// viewport is `2 / width` along `x`, and `2 / height` along `y`. This is equivalent to
+    /// shifting the view cuboid by `-offset` times its half extents. The offset is added to the
+    /// current jitter of this projection. See `.set_jitter` to replace it instead.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Orthographic3, Point3, Vector2};
+    /// let proj = Orthographic3::new(1.0, 10.0, 2.0, 20.0, 0.1, 1000.0);
+    /// let offset = Vector2::new(0.5 * 2.0 / 1920.0, -0.5 * 2.0 / 1080.0);
+    /// let mut jittered = proj.jittered(&offset);
+    ///
+    /// let pt = Point3::new(1.0, 2.0, -3.0);
+    /// let expected = proj.project_point(&pt) + offset.push(0.0);
+    /// assert_relative_eq!(jittered.project_point(&pt), expected, epsilon = 1.0e-10);
+    /// assert_relative_eq!(jittered.jitter(&proj), offset);
+    ///
+    /// jittered.clear_jitter(&proj);
+    /// assert_relative_eq!(jittered.as_matrix(), proj.as_matrix(), epsilon = 1.0e-10);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn jittered(&self, offset: &Vector2<T>) -> Self {
+        let mut res = self.clone();
+        res.jitter_mut(offset);
+        res
+    }
+
+    /// Translates the result of this projection by `offset` in normalized device coordinates.
+    ///
+    /// See `.jittered` for details.
+    #[inline]
+    pub fn jitter_mut(&mut self, offset: &Vector2<T>) {
+        self.matrix[(0, 3)] += offset.x.clone();
+        self.matrix[(1, 3)] += offset.y.clone();
+    }
+
+    /// Gets the jitter of this projection, i.e., its translation in normalized device coordinates
+    /// relative to `base`, the same projection without jitter.
+    ///
+    /// The jitter cannot be told apart from the position of the view cuboid, so the unjittered
+    /// projection must be provided.
+    #[inline]
+    #[must_use]
+    pub fn jitter(&self, base: &Self) -> Vector2<T> {
+        Vector2::new(
+            self.matrix[(0, 3)].clone() - base.matrix[(0, 3)].clone(),
+            self.matrix[(1, 3)].clone() - base.matrix[(1, 3)].clone(),
+        )
+    }
+
+    /// Sets the jitter of this projection, i.e., its translation in normalized device coordinates
+    /// relative to `base`, the same projection without jitter.
+    ///
+    /// Only the position of the view cuboid along `x` and `y` is read from `base`, so the other
+    /// parameters of this projection, e.g., its near and far planes, are kept.
+    #[inline]
+    pub fn set_jitter(&mut self, base: &Self, offset: &Vector2<T>) {
+        self.matrix[(0, 3)] = base.matrix[(0, 3)].clone() + offset.x.clone();
+        self.matrix[(1, 3)] = base.matrix[(1, 3)].clone() + offset.y.clone();
+    }
+
+    /// Removes the jitter of this projection, restoring the position of the view cuboid of
+    /// `base`, the same projection without jitter.
+    ///
+    /// See `.set_jitter` for details.
+    #[inline]
+    pub fn clear_jitter(&mut self, base: &Self) {
+        self.set_jitter(base, &Vector2::zeros())
+    }
 }
 
 #[cfg(feature = "rand-no-std")]
@@ -14,11 +14,15 @@ use simba::scalar::RealField;
 
 use crate::base::dimension::U3;
 use crate::base::storage::Storage;
-use crate::base::{Matrix4, Vector, Vector3};
+use crate::base::{Matrix4, Unit, Vector, Vector2, Vector3};
 
 use crate::geometry::{Point3, Projective3};
 
 /// A 3D perspective projection stored as a homogeneous 4x4 matrix.
+///
+/// The view frustum is symmetric when created with `Perspective3::new` or
+/// `Perspective3::from_fov_x`. It may be asymmetric (off-center) when created from the
+/// intrinsic parameters of a camera with `Perspective3::from_intrinsics`.
 #[repr(C)]
 pub struct Perspective3<T> {
     matrix: Matrix4<T>,
@@ -120,6 +124,106 @@ impl<T: RealField> Perspective3<T> {
         res
     }
 
+    /// Creates a new perspective matrix from the aspect ratio, x field of view, and near/far planes.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// # use nalgebra::Perspective3;
+    /// let proj = Perspective3::from_fov_x(2.0, FRAC_PI_2, 0.1, 100.0);
+    /// assert_relative_eq!(proj.fovx(), FRAC_PI_2);
+    /// assert_relative_eq!(proj.fovy(), 2.0 * 0.5f64.atan());
+    /// ```
+    pub fn from_fov_x(aspect: T, fovx: T, znear: T, zfar: T) -> Self {
+        assert!(
+            !relative_eq!(aspect, T::zero()),
+            "The aspect ratio must not be zero."
+        );
+
+        let two: T = crate::convert(2.0);
+        let fovy = ((fovx / two.clone()).tan() / aspect.clone()).atan() * two;
+        Self::new(aspect, fovy, znear, zfar)
+    }
+
+    /// Creates a new perspective matrix from the intrinsic parameters of a pinhole camera, and
+    /// near/far planes.
+    ///
+    /// The focal lengths `fx, fy` and the principal point `cx, cy` are expressed in pixels, in an
+    /// image of size `width × height` pixels with its origin at its top-left corner and its `y`
+    /// axis pointing downward, as is common in computer vision. The camera looks toward its local
+    /// `-z` axis, with its `y` axis pointing upward, as is common in computer graphics. The
+    /// resulting frustum is off-center if the principal point is not at the center of the image.
+    ///
+    /// The point `(0, 0)` is the corner of the image. With OpenCV's convention, where it is the
+    /// center of the top-left pixel, add `0.5` to `cx` and `cy`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Perspective3, Point3};
+    /// let (width, height) = (640.0, 480.0);
+    /// let (fx, fy, cx, cy) = (500.0, 500.0, 300.0, 250.0);
+    /// let proj = Perspective3::from_intrinsics(fx, fy, cx, cy, width, height, 0.1, 100.0);
+    ///
+    /// // A point 2 units in front of the camera, projected to normalized device coordinates,
+    /// // then to pixel coordinates.
+    /// let ndc = proj.project_point(&Point3::new(0.4, -0.2, -2.0));
+    /// let u = (ndc.x + 1.0) * width / 2.0;
+    /// let v = (1.0 - ndc.y) * height / 2.0;
+    /// assert_relative_eq!(u, fx * 0.4 / 2.0 + cx, epsilon = 1.0e-10);
+    /// assert_relative_eq!(v, fy * 0.2 / 2.0 + cy, epsilon = 1.0e-10);
+    ///
+    /// let intrinsics = proj.intrinsics(width, height);
+    /// assert_relative_eq!(intrinsics.0, fx, epsilon = 1.0e-10);
+    /// assert_relative_eq!(intrinsics.3, cy, epsilon = 1.0e-10);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_intrinsics(
+        fx: T,
+        fy: T,
+        cx: T,
+        cy: T,
+        width: T,
+        height: T,
+        znear: T,
+        zfar: T,
+    ) -> Self {
+        assert!(
+            !relative_eq!(width.clone(), T::zero()) && !relative_eq!(height.clone(), T::zero()),
+            "The image size must not be zero."
+        );
+
+        let two: T = crate::convert(2.0);
+        let mut res = Self::new(T::one(), T::one(), znear, zfar);
+
+        res.matrix[(0, 0)] = two.clone() * fx / width.clone();
+        res.matrix[(1, 1)] = two.clone() * fy / height.clone();
+        res.matrix[(0, 2)] = T::one() - two.clone() * cx / width;
+        res.matrix[(1, 2)] = two * cy / height - T::one();
+
+        res
+    }
+
+    /// Retrieves the intrinsic parameters `(fx, fy, cx, cy)` of the pinhole camera matching this
+    /// projection, for an image of size `width × height` pixels.
+    ///
+    /// This is the inverse of `Perspective3::from_intrinsics`, see its documentation for the
+    /// conventions used.
+    #[inline]
+    #[must_use]
+    pub fn intrinsics(&self, width: T, height: T) -> (T, T, T, T) {
+        let half_width = width / crate::convert(2.0);
+        let half_height = height / crate::convert(2.0);
+
+        (
+            self.matrix[(0, 0)].clone() * half_width.clone(),
+            self.matrix[(1, 1)].clone() * half_height.clone(),
+            (T::one() - self.matrix[(0, 2)].clone()) * half_width,
+            (T::one() + self.matrix[(1, 2)].clone()) * half_height,
+        )
+    }
+
     /// Retrieves the inverse of the underlying homogeneous matrix.
     #[inline]
     #[must_use]
@@ -129,10 +233,16 @@ impl<T: RealField> Perspective3<T> {
         res[(0, 0)] = T::one() / self.matrix[(0, 0)].clone();
         res[(1, 1)] = T::one() / self.matrix[(1, 1)].clone();
         res[(2, 2)] = T::zero();
+        res[(0, 2)] = T::zero();
+        res[(1, 2)] = T::zero();
 
         let m23 = self.matrix[(2, 3)].clone();
         let m32 = self.matrix[(3, 2)].clone();
 
+        // Offsets of an off-center frustum.
+        res[(0, 3)] = -self.matrix[(0, 2)].clone() / (self.matrix[(0, 0)].clone() * m32.clone());
+        res[(1, 3)] = -self.matrix[(1, 2)].clone() / (self.matrix[(1, 1)].clone() * m32.clone());
+
         res[(2, 3)] = T::one() / m32.clone();
         res[(3, 2)] = T::one() / m23.clone();
         res[(3, 3)] = -self.matrix[(2, 2)].clone() / (m23 * m32);
@@ -189,6 +299,13 @@ impl<T: RealField> Perspective3<T> {
         self.matrix[(1, 1)].clone() / self.matrix[(0, 0)].clone()
     }
 
+    /// Gets the x field of view of the view frustum.
+    #[inline]
+    #[must_use]
+    pub fn fovx(&self) -> T {
+        (T::one() / self.matrix[(0, 0)].clone()).atan() * crate::convert(2.0)
+    }
+
     /// Gets the y field of view of the view frustum.
     #[inline]
     #[must_use]
@@ -226,8 +343,12 @@ impl<T: RealField> Perspective3<T> {
     pub fn project_point(&self, p: &Point3<T>) -> Point3<T> {
         let inverse_denom = -T::one() / p[2].clone();
         Point3::new(
-            self.matrix[(0, 0)].clone() * p[0].clone() * inverse_denom.clone(),
-            self.matrix[(1, 1)].clone() * p[1].clone() * inverse_denom.clone(),
+            (self.matrix[(0, 0)].clone() * p[0].clone()
+                + self.matrix[(0, 2)].clone() * p[2].clone())
+                * inverse_denom.clone(),
+            (self.matrix[(1, 1)].clone() * p[1].clone()
+                + self.matrix[(1, 2)].clone() * p[2].clone())
+                * inverse_denom.clone(),
             (self.matrix[(2, 2)].clone() * p[2].clone() + self.matrix[(2, 3)].clone())
                 * inverse_denom,
         )
@@ -241,12 +362,67 @@ impl<T: RealField> Perspective3<T> {
             self.matrix[(2, 3)].clone() / (p[2].clone() + self.matrix[(2, 2)].clone());
 
         Point3::new(
-            p[0].clone() * inverse_denom.clone() / self.matrix[(0, 0)].clone(),
-            p[1].clone() * inverse_denom.clone() / self.matrix[(1, 1)].clone(),
+            (p[0].clone() + self.matrix[(0, 2)].clone()) * inverse_denom.clone()
+                / self.matrix[(0, 0)].clone(),
+            (p[1].clone() + self.matrix[(1, 2)].clone()) * inverse_denom.clone()
+                / self.matrix[(1, 1)].clone(),
             -inverse_denom,
         )
     }
 
+    /// Computes the ray, in camera space, going through the pixel at `(px, py)` of a viewport of
+    /// size `viewport = (width, height)` pixels.
+    ///
+    /// The pixel coordinates have their origin at the top-left corner of the viewport and their
+    /// `y` axis pointing downward, so the center of the top-left pixel is at `(0.5, 0.5)`. The
+    /// returned ray starts on the near plane, and its direction is normalized. Multiply both by
+    /// the pose of the camera to get a ray in world space.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Perspective3, Point3, Vector2, Vector3};
+    /// let proj = Perspective3::new(16.0 / 9.0, 1.0, 0.1, 100.0);
+    /// let viewport = Vector2::new(1920.0, 1080.0);
+    ///
+    /// // The ray through the center of the viewport follows the `-z` axis.
+    /// let (origin, dir) = proj.ray_for_pixel(&viewport, 960.0, 540.0);
+    /// assert_relative_eq!(origin, Point3::new(0.0, 0.0, -0.1), epsilon = 1.0e-10);
+    /// assert_relative_eq!(dir.into_inner(), -Vector3::z(), epsilon = 1.0e-10);
+    ///
+    /// // In world space, for a camera at `eye` looking at the origin, this ray goes from the eye
+    /// // toward the origin.
+    /// let eye = Point3::new(1.0, 2.0, 3.0);
+    /// let pose = Isometry3::look_at_rh(&eye, &Point3::origin(), &Vector3::y()).inverse();
+    /// let (world_origin, world_dir) = (pose * origin, pose * dir);
+    /// assert_relative_eq!(world_dir.into_inner(), -eye.coords.normalize(), epsilon = 1.0e-10);
+    /// assert_relative_eq!(world_origin, eye + world_dir.into_inner() * 0.1, epsilon = 1.0e-10);
+    ///
+    /// // The points along the ray are projected on the pixel.
+    /// let (origin, dir) = proj.ray_for_pixel(&viewport, 100.5, 200.5);
+    /// let ndc = proj.project_point(&(origin + dir.into_inner() * 10.0));
+    /// assert_relative_eq!((ndc.x + 1.0) * 960.0, 100.5, epsilon = 1.0e-7);
+    /// assert_relative_eq!((1.0 - ndc.y) * 540.0, 200.5, epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn ray_for_pixel(
+        &self,
+        viewport: &Vector2<T>,
+        px: T,
+        py: T,
+    ) -> (Point3<T>, Unit<Vector3<T>>) {
+        let two: T = crate::convert(2.0);
+        let ndc = Point3::new(
+            two.clone() * px / viewport.x.clone() - T::one(),
+            T::one() - two * py / viewport.y.clone(),
+            -T::one(),
+        );
+
+        // The camera is at the origin, so the ray is aligned with the point on the near plane.
+        let origin = self.unproject_point(&ndc);
+        (origin.clone(), Unit::new_normalize(origin.coords))
+    }
+
     // TODO: when we get specialization, specialize the Mul impl instead.
     /// Projects a vector. Faster than matrix multiplication.
     #[inline]
@@ -257,8 +433,12 @@ impl<T: RealField> Perspective3<T> {
     {
         let inverse_denom = -T::one() / p[2].clone();
         Vector3::new(
-            self.matrix[(0, 0)].clone() * p[0].clone() * inverse_denom.clone(),
-            self.matrix[(1, 1)].clone() * p[1].clone() * inverse_denom,
+            (self.matrix[(0, 0)].clone() * p[0].clone()
+                + self.matrix[(0, 2)].clone() * p[2].clone())
+                * inverse_denom.clone(),
+            (self.matrix[(1, 1)].clone() * p[1].clone()
+                + self.matrix[(1, 2)].clone() * p[2].clone())
+                * inverse_denom,
             self.matrix[(2, 2)].clone(),
         )
     }
@@ -303,6 +483,81 @@ impl<T: RealField> Perspective3<T> {
         self.matrix[(2, 2)] = (zfar.clone() + znear.clone()) / (znear.clone() - zfar.clone());
         self.matrix[(2, 3)] = zfar.clone() * znear.clone() * crate::convert(2.0) / (znear - zfar);
     }
+
+    /// Returns this projection followed by a translation of `offset` in normalized device
+    /// coordinates.
+    ///
+    /// This is typically used for temporal anti-aliasing, where the projection is jittered by a
+    /// different sub-pixel offset at each frame. An offset of one pixel of a `width × height`
+    /// viewport is `2 / width` along `x`, and `2 / height` along `y`. The offset is added to the
+    /// current jitter of this projection. See `.set_jitter` to replace it instead.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Perspective3, Point3, Vector2};
+    /// let proj = Perspective3::new(16.0 / 9.0, 1.0, 0.1, 100.0);
+    /// let offset = Vector2::new(0.5 * 2.0 / 1920.0, -0.5 * 2.0 / 1080.0);
+    /// let jittered = proj.jittered(&offset);
+    ///
+    /// let pt = Point3::new(1.0, 2.0, -3.0);
+    /// let expected = proj.project_point(&pt) + offset.push(0.0);
+    /// assert_relative_eq!(jittered.project_point(&pt), expected, epsilon = 1.0e-10);
+    /// assert_relative_eq!(jittered.jitter(&proj), offset);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn jittered(&self, offset: &Vector2<T>) -> Self {
+        let mut res = self.clone();
+        res.jitter_mut(offset);
+        res
+    }
+
+    /// Translates the result of this projection by `offset` in normalized device coordinates.
+    ///
+    /// See `.jittered` for details.
+    #[inline]
+    pub fn jitter_mut(&mut self, offset: &Vector2<T>) {
+        // The projected points are divided by `-z`, so the translation is applied to the
+        // coefficients of `z`.
+        self.matrix[(0, 2)] -= offset.x.clone();
+        self.matrix[(1, 2)] -= offset.y.clone();
+    }
+
+    /// Gets the jitter of this projection, i.e., its translation in normalized device coordinates
+    /// relative to `base`, the same projection without jitter.
+    ///
+    /// The jitter cannot be told apart from the off-center offset of the view frustum, e.g., of a
+    /// projection created with `Perspective3::from_intrinsics`, so the unjittered projection must
+    /// be provided.
+    #[inline]
+    #[must_use]
+    pub fn jitter(&self, base: &Self) -> Vector2<T> {
+        Vector2::new(
+            base.matrix[(0, 2)].clone() - self.matrix[(0, 2)].clone(),
+            base.matrix[(1, 2)].clone() - self.matrix[(1, 2)].clone(),
+        )
+    }
+
+    /// Sets the jitter of this projection, i.e., its translation in normalized device coordinates
+    /// relative to `base`, the same projection without jitter.
+    ///
+    /// Only the off-center offset of the view frustum is read from `base`, so the other
+    /// parameters of this projection, e.g., its near and far planes, are kept.
+    #[inline]
+    pub fn set_jitter(&mut self, base: &Self, offset: &Vector2<T>) {
+        self.matrix[(0, 2)] = base.matrix[(0, 2)].clone() - offset.x.clone();
+        self.matrix[(1, 2)] = base.matrix[(1, 2)].clone() - offset.y.clone();
+    }
+
+    /// Removes the jitter of this projection, restoring the off-center offset of the view
+    /// frustum of `base`, the same projection without jitter.
+    ///
+    /// See `.set_jitter` for details.
+    #[inline]
+    pub fn clear_jitter(&mut self, base: &Self) {
+        self.set_jitter(base, &Vector2::zeros())
+    }
 }
 
 #[cfg(feature = "rand-no-std")]
@@ -0,0 +1,144 @@
+//! Conversions between 2D geometric entities and 3D entities in the `xy` plane.
+
+use simba::scalar::RealField;
+use simba::simd::SimdRealField;
+
+use crate::base::{Scalar, Vector3};
+use crate::geometry::{
+    Isometry2, Isometry3, Point2, Point3, Translation2, Translation3, UnitComplex, UnitQuaternion,
+};
+
+/// # Conversion to 3D
+impl<T: Scalar> Point2<T> {
+    /// The 3D point with the same `x` and `y` coordinates as `self`, and the given `z`
+    /// coordinate.
+    ///
+    /// Use `Point3::xy` for the converse projection.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Point2, Point3};
+    /// let p = Point2::new(1.0, 2.0);
+    /// assert_eq!(p.to_3d(3.0), Point3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(p.to_3d(3.0).xy(), p);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_3d(&self, z: T) -> Point3<T> {
+        Point3::new(self.x.clone(), self.y.clone(), z)
+    }
+}
+
+/// # Conversion to 3D
+impl<T: SimdRealField> UnitComplex<T>
+where
+    T::Element: SimdRealField,
+{
+    /// The 3D rotation about the `z` axis with the same angle as this 2D rotation.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitComplex, UnitQuaternion, Vector2, Vector3};
+    /// let rot = UnitComplex::new(1.2);
+    /// let rot3 = rot.to_unit_quaternion_about_z();
+    ///
+    /// assert_relative_eq!(rot3, UnitQuaternion::from_euler_angles(0.0, 0.0, 1.2));
+    /// assert_relative_eq!((rot3 * Vector3::x()).xy(), rot * Vector2::x());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_unit_quaternion_about_z(&self) -> UnitQuaternion<T> {
+        UnitQuaternion::from_axis_angle(&Vector3::z_axis(), self.angle())
+    }
+}
+
+/// # Conversion to 3D
+impl<T: SimdRealField> Isometry2<T>
+where
+    T::Element: SimdRealField,
+{
+    /// The 3D isometry acting on the `xy` plane like this 2D isometry, and leaving the `z`
+    /// coordinates unchanged.
+    ///
+    /// Its rotation is about the `z` axis, and its translation has a zero `z` component.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry2, Point2, Point3, Vector2};
+    /// let iso = Isometry2::new(Vector2::new(1.0, 2.0), 0.7);
+    /// let iso3 = iso.to_isometry3_xy();
+    ///
+    /// let p = Point2::new(3.0, -1.0);
+    /// assert_relative_eq!(iso3 * p.to_3d(5.0), (iso * p).to_3d(5.0), epsilon = 1.0e-12);
+    /// assert_relative_eq!(iso3.project_to_xy_plane(), iso, epsilon = 1.0e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_isometry3_xy(&self) -> Isometry3<T> {
+        let t = &self.translation.vector;
+        Isometry3::from_parts(
+            Translation3::new(t.x.clone(), t.y.clone(), T::zero()),
+            self.rotation.to_unit_quaternion_about_z(),
+        )
+    }
+}
+
+/// # Projection to 2D
+impl<T: RealField> UnitQuaternion<T> {
+    /// The 2D rotation whose angle is the angle of the rotation of this quaternion about the `z`
+    /// axis, a.k.a., its yaw.
+    ///
+    /// This is the twist about `z` of the swing-twist decomposition of this rotation. If this
+    /// rotation is a rotation of `π` about an axis in the `xy` plane, the twist is not defined
+    /// and the identity is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitComplex, UnitQuaternion, Vector3};
+    /// let yaw = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.5);
+    /// let tilt = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.3);
+    ///
+    /// assert_relative_eq!(
+    ///     (yaw * tilt).project_to_z_rotation(),
+    ///     UnitComplex::new(0.5),
+    ///     epsilon = 1.0e-12
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn project_to_z_rotation(&self) -> UnitComplex<T> {
+        let q = self.quaternion();
+        UnitComplex::new(q.k.clone().atan2(q.w.clone()) * crate::convert(2.0))
+    }
+}
+
+/// # Projection to 2D
+impl<T: RealField> Isometry3<T> {
+    /// The 2D isometry acting on the `xy` plane like the projection of this isometry.
+    ///
+    /// Its translation is the `xy` part of the translation of `self`, and its rotation is the
+    /// rotation of `self` about the `z` axis (see `UnitQuaternion::project_to_z_rotation`).
+    /// This is the converse of `Isometry2::to_isometry3_xy`, e.g., to get the planar pose of a
+    /// ground vehicle from its 3D pose.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry2, Isometry3, Vector2, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::z() * 0.4);
+    /// let expected = Isometry2::new(Vector2::new(1.0, 2.0), 0.4);
+    /// assert_relative_eq!(iso.project_to_xy_plane(), expected, epsilon = 1.0e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn project_to_xy_plane(&self) -> Isometry2<T> {
+        let t = &self.translation.vector;
+        Isometry2::from_parts(
+            Translation2::new(t.x.clone(), t.y.clone()),
+            self.rotation.project_to_z_rotation(),
+        )
+    }
+}
@@ -1,5 +1,6 @@
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num::One;
+use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash;
@@ -17,7 +18,9 @@ use simba::simd::SimdPartialOrd;
 use crate::base::allocator::Allocator;
 use crate::base::dimension::{DimName, DimNameAdd, DimNameSum, U1};
 use crate::base::iter::{MatrixIter, MatrixIterMut};
+use crate::base::statistics::{compensated_add, CompensatedSum};
 use crate::base::{Const, DefaultAllocator, OVector, Scalar};
+use crate::RealField;
 use std::mem::MaybeUninit;
 
 /// A point in an euclidean space.
@@ -449,6 +452,148 @@ where
     }
 }
 
+/*
+ * Centroid
+ */
+impl<T: RealField, D: DimName> OPoint<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// The centroid (aka. barycenter or mean) of a set of points.
+    ///
+    /// Returns `None` if `points` is empty. The coordinates are accumulated with a compensated
+    /// summation (see `Matrix::sum_compensated`), so the result stays accurate for large sets of
+    /// points, and for points far from the origin.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Point2;
+    /// let points = [
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(4.0, 0.0),
+    ///     Point2::new(4.0, 2.0),
+    ///     Point2::new(0.0, 2.0),
+    /// ];
+    ///
+    /// assert_eq!(Point2::centroid(&points), Some(Point2::new(2.0, 1.0)));
+    /// assert_eq!(Point2::<f64>::centroid(&[]), None);
+    /// ```
+    ///
+    /// # See also:
+    ///
+    /// * [`centroid_iter`](#method.centroid_iter)
+    /// * [`weighted_centroid`](#method.weighted_centroid)
+    /// * [center](../fn.center.html)
+    #[inline]
+    #[must_use]
+    pub fn centroid(points: &[Self]) -> Option<Self> {
+        Self::centroid_iter(points)
+    }
+
+    /// The centroid of the points yielded by an iterator, either by value or by reference.
+    ///
+    /// Returns `None` if `points` is empty. See `Self::centroid` for details.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Point3;
+    /// let points = vec![Point3::new(1.0, 2.0, 3.0), Point3::new(3.0, 2.0, 1.0)];
+    /// let expected = Some(Point3::new(2.0, 2.0, 2.0));
+    ///
+    /// assert_eq!(Point3::centroid_iter(&points), expected);
+    /// assert_eq!(Point3::centroid_iter(points.into_iter()), expected);
+    /// ```
+    #[must_use]
+    pub fn centroid_iter<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        let mut sum = OVector::<T, D>::zeros();
+        let mut compensation = OVector::<T, D>::zeros();
+        let mut count = 0usize;
+
+        for point in points {
+            let coords = &point.borrow().coords;
+
+            for i in 0..coords.len() {
+                compensated_add(&mut sum[i], &mut compensation[i], coords[i].clone());
+            }
+
+            count += 1;
+        }
+
+        if count == 0 {
+            None
+        } else {
+            let count: T = crate::convert(count as f64);
+            Some(Self::from((sum + compensation) / count))
+        }
+    }
+
+    /// The centroid of a set of points, each with the given weight.
+    ///
+    /// Returns `None` if the sum of the weights is zero, including if `points` is empty. The
+    /// weights may be negative. See `Self::centroid` for details.
+    ///
+    /// # Panics
+    /// Panics if `points` and `weights` don't have the same length.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Point2;
+    /// let points = [Point2::new(0.0, 0.0), Point2::new(4.0, 8.0)];
+    ///
+    /// assert_eq!(Point2::weighted_centroid(&points, &[3.0, 1.0]), Some(Point2::new(1.0, 2.0)));
+    /// assert_eq!(Point2::weighted_centroid(&points, &[1.0, -1.0]), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn weighted_centroid(points: &[Self], weights: &[T]) -> Option<Self> {
+        assert_eq!(
+            points.len(),
+            weights.len(),
+            "Weighted centroid: the numbers of points and weights must match."
+        );
+        Self::weighted_centroid_iter(points.iter().zip(weights.iter().cloned()))
+    }
+
+    /// The centroid of the `(point, weight)` pairs yielded by an iterator, where the points are
+    /// given either by value or by reference.
+    ///
+    /// Returns `None` if the sum of the weights is zero, including if `points` is empty. See
+    /// `Self::weighted_centroid` for details.
+    #[must_use]
+    pub fn weighted_centroid_iter<I, P>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (P, T)>,
+        P: Borrow<Self>,
+    {
+        let mut sum = OVector::<T, D>::zeros();
+        let mut compensation = OVector::<T, D>::zeros();
+        let mut total_weight = CompensatedSum::new();
+
+        for (point, weight) in points {
+            let coords = &point.borrow().coords;
+
+            for i in 0..coords.len() {
+                let x = coords[i].clone() * weight.clone();
+                compensated_add(&mut sum[i], &mut compensation[i], x);
+            }
+
+            total_weight.add(weight);
+        }
+
+        let total_weight = total_weight.value();
+
+        if total_weight.is_zero() {
+            None
+        } else {
+            Some(Self::from((sum + compensation) / total_weight))
+        }
+    }
+}
+
 /*
  *
  * Display
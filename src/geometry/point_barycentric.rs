@@ -0,0 +1,110 @@
+//! Barycentric coordinates of points with respect to triangles.
+
+use simba::scalar::RealField;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::DimName;
+use crate::base::{DefaultAllocator, Vector3};
+use crate::geometry::OPoint;
+
+/// # Barycentric coordinates
+impl<T: RealField, D: DimName> OPoint<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// The barycentric coordinates `(u, v, w)` of this point with respect to the triangle
+    /// `(a, b, c)`, such that `self == u * a + v * b + w * c` and `u + v + w == 1`.
+    ///
+    /// In dimension 3 and higher, these are the barycentric coordinates of the orthogonal
+    /// projection of `self` onto the plane of the triangle. Returns `None` if the triangle is
+    /// degenerate, i.e., if its vertices are collinear.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Point2, Point3, Vector3};
+    /// let (a, b, c) = (Point2::new(0.0, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 2.0));
+    /// let bary = Point2::new(1.0, 1.0).barycentric_coordinates(&a, &b, &c);
+    /// assert_relative_eq!(bary.unwrap(), Vector3::new(0.25, 0.25, 0.5));
+    ///
+    /// // The point is projected onto the plane of the triangle.
+    /// let (a, b, c) = (a.to_3d(0.0), b.to_3d(0.0), c.to_3d(0.0));
+    /// let bary = Point3::new(1.0, 1.0, 5.0).barycentric_coordinates(&a, &b, &c);
+    /// assert_relative_eq!(bary.unwrap(), Vector3::new(0.25, 0.25, 0.5));
+    ///
+    /// // Degenerate triangle.
+    /// assert!(a.barycentric_coordinates(&a, &b, &b).is_none());
+    /// ```
+    #[must_use]
+    pub fn barycentric_coordinates(&self, a: &Self, b: &Self, c: &Self) -> Option<Vector3<T>> {
+        // See "Real-Time Collision Detection" by Christer Ericson, section 3.4.
+        let ab = b - a;
+        let ac = c - a;
+        let ap = self - a;
+
+        let d00 = ab.norm_squared();
+        let d01 = ab.dot(&ac);
+        let d11 = ac.norm_squared();
+        let d20 = ap.dot(&ab);
+        let d21 = ap.dot(&ac);
+        let denom = d00.clone() * d11.clone() - d01.clone() * d01.clone();
+
+        // The denominator is the squared area of the parallelogram spanned by `ab` and `ac`.
+        if denom <= T::default_epsilon() * d00.clone() * d11.clone() {
+            return None;
+        }
+
+        let v = (d11 * d20.clone() - d01.clone() * d21.clone()) / denom.clone();
+        let w = (d00 * d21 - d01 * d20) / denom;
+        let u = T::one() - v.clone() - w.clone();
+
+        Some(Vector3::new(u, v, w))
+    }
+
+    /// Tests if this point lies inside of the triangle `(a, b, c)`, or on its boundary.
+    ///
+    /// In dimension 3 and higher, this tests the orthogonal projection of `self` onto the plane
+    /// of the triangle. Returns `false` if the triangle is degenerate. Points very close to the
+    /// boundary of the triangle may be classified either way because of rounding errors.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Point2;
+    /// let (a, b, c) = (Point2::new(0.0, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 2.0));
+    ///
+    /// assert!(Point2::new(1.0, 1.0).is_in_triangle(&a, &b, &c));
+    /// assert!(Point2::new(2.0, 0.0).is_in_triangle(&a, &b, &c));
+    /// assert!(!Point2::new(3.0, 1.0).is_in_triangle(&a, &b, &c));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_in_triangle(&self, a: &Self, b: &Self, c: &Self) -> bool {
+        match self.barycentric_coordinates(a, b, c) {
+            Some(bary) => bary.iter().all(|e| *e >= T::zero()),
+            None => false,
+        }
+    }
+
+    /// The point with the barycentric coordinates `bary = (u, v, w)` with respect to the
+    /// triangle `(a, b, c)`, i.e., `u * a + v * b + w * c`.
+    ///
+    /// The barycentric coordinates are expected to sum to one.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Point2, Vector3};
+    /// let (a, b, c) = (Point2::new(0.0, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 2.0));
+    /// let p = Point2::new(1.0, 1.0);
+    /// let bary = p.barycentric_coordinates(&a, &b, &c).unwrap();
+    ///
+    /// assert_relative_eq!(Point2::from_barycentric_coordinates(&a, &b, &c, &bary), p);
+    /// ```
+    #[must_use]
+    pub fn from_barycentric_coordinates(a: &Self, b: &Self, c: &Self, bary: &Vector3<T>) -> Self {
+        let mut coords = a.coords.scale(bary.x.clone());
+        coords.axpy(bary.y.clone(), &b.coords, T::one());
+        coords.axpy(bary.z.clone(), &c.coords, T::one());
+        Self::from(coords)
+    }
+}
@@ -193,7 +193,7 @@ impl<T: Scalar> Point1<T> {
     /// assert_eq!(p.x, 1.0);
     /// ```
     #[inline]
-    pub fn new(x: T) -> Self {
+    pub const fn new(x: T) -> Self {
         Point {
             coords: Vector1::new(x),
         }
@@ -207,7 +207,7 @@ macro_rules! componentwise_constructors_impl(
             #[doc = $doc]
             #[doc = "```"]
             #[inline]
-            pub fn new($($args: T),*) -> Self {
+            pub const fn new($($args: T),*) -> Self {
                 Point { coords: $Vector::new($($args),*) }
             }
         }
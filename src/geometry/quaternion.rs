@@ -1295,6 +1295,42 @@ where
         coords.map(|q| Unit::new_unchecked(Quaternion::from(q.into_inner())))
     }
 
+    /// Rotates `self` towards `other` along the shortest path, by an angle of at most
+    /// `max_angle`.
+    ///
+    /// Returns `other` if it is within `max_angle` of `self`. This is typically called at each
+    /// frame of an animation to turn smoothly towards a target orientation at a bounded angular
+    /// speed. `max_angle` should be non-negative.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let q1 = UnitQuaternion::identity();
+    /// let q2 = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 1.0);
+    ///
+    /// let q = q1.rotate_towards(&q2, 0.25);
+    /// assert_relative_eq!(q, UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.25));
+    ///
+    /// assert_eq!(q1.rotate_towards(&q2, 2.0), q2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rotate_towards(&self, other: &Self, max_angle: T) -> Self
+    where
+        T: RealField,
+    {
+        let angle = self.angle_to(other);
+
+        if angle <= max_angle {
+            other.clone()
+        } else {
+            // `try_slerp` only fails if both rotations are almost equal.
+            self.try_slerp(other, max_angle / angle, T::default_epsilon())
+                .unwrap_or_else(|| other.clone())
+        }
+    }
+
     /// Compute the conjugate of this unit quaternion in-place.
     #[inline]
     pub fn conjugate_mut(&mut self) {
@@ -1460,6 +1496,38 @@ where
         }
     }
 
+    /// Decomposes this rotation into a swing and a twist such that `self == swing * twist`.
+    ///
+    /// The twist is a rotation about `axis`, and the swing is a rotation about an axis
+    /// perpendicular to `axis`. If `self` is a rotation of 180 degrees about an axis perpendicular
+    /// to `axis`, the twist is not uniquely defined: the identity is returned as the twist and
+    /// `self` as the swing.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let swing = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.5);
+    /// let twist = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 1.2);
+    /// let (s, t) = (swing * twist).swing_twist(&Vector3::z_axis());
+    /// assert_relative_eq!(s, swing, epsilon = 1.0e-6);
+    /// assert_relative_eq!(t, twist, epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn swing_twist(&self, axis: &Unit<Vector3<T>>) -> (Self, Self)
+    where
+        T: RealField,
+    {
+        let q = self.quaternion();
+        let proj = axis.as_ref() * q.imag().dot(axis);
+        let twist = Quaternion::from_parts(q.scalar(), proj);
+        let twist = Unit::try_new(twist, T::default_epsilon()).unwrap_or_else(Self::identity);
+        let swing = self * twist.inverse();
+
+        (swing, twist)
+    }
+
     /// Builds a rotation matrix from this unit quaternion.
     ///
     /// # Example
@@ -1683,6 +1751,56 @@ where
         let q2 = Quaternion::from_imag(axisangle * half);
         Unit::new_normalize(&q1 + q2 * &q1)
     }
+
+    /// The orientation reached after rotating during `dt` with the constant angular velocity
+    /// `angular_velocity`, starting from this orientation.
+    ///
+    /// The angular velocity is expressed in the local frame of the rotating body, like the
+    /// measurements of a gyroscope attached to it. The integration is exact: this computes
+    /// `self * UnitQuaternion::new(angular_velocity * dt)`. For an angular velocity expressed
+    /// in the global frame, use `UnitQuaternion::new(angular_velocity * dt) * self` instead.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64::consts::PI;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let q = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), PI / 2.0);
+    /// // Rotating about the local `x` axis is rotating about the global `y` axis.
+    /// let q1 = q.integrate(&Vector3::new(PI, 0.0, 0.0), 0.5);
+    ///
+    /// assert_relative_eq!(q1 * Vector3::x(), Vector3::y(), epsilon = 1.0e-12);
+    /// assert_relative_eq!(q1 * Vector3::y(), Vector3::z(), epsilon = 1.0e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn integrate(&self, angular_velocity: &Vector3<T>, dt: T) -> Self {
+        self * Self::new(angular_velocity * dt)
+    }
+
+    /// The time derivative `q̇ = q ⊗ (0, ω) / 2` of this unit quaternion rotating with the
+    /// angular velocity `ω`, expressed in the local frame of the rotating body.
+    ///
+    /// For an angular velocity `ω` expressed in the global frame, the derivative is
+    /// `(0, ω) ⊗ q / 2`, i.e., `self.derivative(&self.inverse_transform_vector(&ω))`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let q = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+    /// let omega = Vector3::new(0.5, -1.0, 2.0);
+    /// let dt = 1.0e-6;
+    ///
+    /// let finite_difference = (q.integrate(&omega, dt).into_inner() - q.into_inner()) / dt;
+    /// assert_relative_eq!(q.derivative(&omega), finite_difference, epsilon = 1.0e-5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn derivative(&self, angular_velocity: &Vector3<T>) -> Quaternion<T> {
+        let half: T = crate::convert(0.5);
+        self.as_ref() * Quaternion::from_imag(angular_velocity * half)
+    }
 }
 
 impl<T: RealField> Default for UnitQuaternion<T> {
@@ -582,8 +582,8 @@ where
     /// # Arguments
     ///   * dir - The look direction. It does not need to be normalized.
     ///   * up - The vertical direction. It does not need to be normalized.
-    ///   The only requirement of this parameter is to not be collinear to `dir`. Non-collinearity
-    ///   is not checked.
+    ///   If it is collinear to `dir`, a horizontal axis is chosen deterministically, see
+    ///   `Rotation3::face_towards`.
     ///
     /// # Example
     /// ```
@@ -624,7 +624,8 @@ where
     /// # Arguments
     ///   * dir − The view direction. It does not need to be normalized.
     ///   * up - A vector approximately aligned with required the vertical axis. It does not need
-    ///   to be normalized. The only requirement of this parameter is to not be collinear to `dir`.
+    ///   to be normalized. If it is collinear to `dir`, a horizontal axis is chosen
+    ///   deterministically, see `Rotation3::face_towards`.
     ///
     /// # Example
     /// ```
@@ -654,8 +655,9 @@ where
     ///
     /// # Arguments
     ///   * dir − The view direction. It does not need to be normalized.
-    ///   * up - A vector approximately aligned with required the vertical axis. The only
-    ///   requirement of this parameter is to not be collinear to `dir`.
+    ///   * up - A vector approximately aligned with required the vertical axis. If it is
+    ///   collinear to `dir`, a horizontal axis is chosen deterministically, see
+    ///   `Rotation3::face_towards`.
     ///
     /// # Example
     /// ```
@@ -833,25 +835,67 @@ where
             .map(|q| q.as_vector() * q.as_vector().transpose())
             .sum();
 
+        Self::from_outer_products_sum(quaternions_matrix)
+    }
+
+    /// Computes the weighted mean of the given unit quaternions.
+    ///
+    /// The mean is the eigenvector associated to the largest eigenvalue of the weighted sum of
+    /// the outer products `w * q * qᵀ` of each quaternion (seen as a 4D vector) with itself. This
+    /// is insensitive to the sign of the quaternions, unlike a naive average of their components.
+    ///
+    /// Panics if `unit_quaternions` is empty, if it has a different length than `weights`, or if
+    /// all the weights are zero.
+    ///
+    /// Algorithm from: Markley, F. Landis, et al. "Averaging quaternions." Journal of Guidance,
+    /// Control, and Dynamics 30.4 (2007): 1193-1197.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::UnitQuaternion;
+    /// let q1 = UnitQuaternion::from_euler_angles(0.0, 0.0, 0.0);
+    /// let q2 = UnitQuaternion::from_euler_angles(0.2, 0.0, 0.0);
+    /// // The same rotation as `q2`, on the other hemisphere.
+    /// let q3 = UnitQuaternion::new_unchecked(-q2.into_inner());
+    ///
+    /// let q_mean = UnitQuaternion::weighted_mean_of(&[q1, q2, q3], &[2.0, 1.0, 1.0]);
+    /// assert_relative_eq!(q_mean.euler_angles().0, 0.1, epsilon = 1.0e-7);
+    /// ```
+    pub fn weighted_mean_of(unit_quaternions: &[Self], weights: &[T]) -> Self
+    where
+        T: RealField,
+    {
+        assert_eq!(
+            unit_quaternions.len(),
+            weights.len(),
+            "The number of quaternions and weights must match."
+        );
+
+        let quaternions_matrix: Matrix4<T> = unit_quaternions
+            .iter()
+            .zip(weights.iter())
+            .map(|(q, w)| q.as_vector() * q.as_vector().transpose() * w.clone())
+            .sum();
+
+        Self::from_outer_products_sum(quaternions_matrix)
+    }
+
+    /// The unit quaternion corresponding to the dominant eigenvector of a (weighted) sum of
+    /// quaternion outer products.
+    fn from_outer_products_sum(quaternions_matrix: Matrix4<T>) -> Self
+    where
+        T: RealField,
+    {
         assert!(!quaternions_matrix.is_zero());
 
         let eigen_matrix = quaternions_matrix
-            .try_symmetric_eigen(T::RealField::default_epsilon(), 10)
+            .try_symmetric_eigen(T::RealField::default_epsilon(), 0)
             .expect("Quaternions matrix could not be diagonalized. This behavior should not be possible.");
 
-        let max_eigenvalue_index = eigen_matrix
-            .eigenvalues
-            .iter()
-            .position(|v| *v == eigen_matrix.eigenvalues.max())
-            .unwrap();
-
+        let max_eigenvalue_index = eigen_matrix.eigenvalues.imax();
         let max_eigenvector = eigen_matrix.eigenvectors.column(max_eigenvalue_index);
-        UnitQuaternion::from_quaternion(Quaternion::new(
-            max_eigenvector[0].clone(),
-            max_eigenvector[1].clone(),
-            max_eigenvector[2].clone(),
-            max_eigenvector[3].clone(),
-        ))
+        UnitQuaternion::from_quaternion(Quaternion::from(max_eigenvector.into_owned()))
     }
 }
 
@@ -0,0 +1,100 @@
+use crate::{RealField, UnitQuaternion};
+
+/// # Spline interpolation
+impl<T: RealField> UnitQuaternion<T> {
+    /// Computes the intermediate control point of `current` used by the SQUAD interpolation.
+    ///
+    /// The returned quaternion is meant to be given to `UnitQuaternion::squad` as the control
+    /// point associated to the keyframe `current`, where `prev` and `next` are the keyframes
+    /// immediately before and after it.
+    #[inline]
+    #[must_use]
+    pub fn squad_control_point(prev: &Self, current: &Self, next: &Self) -> Self {
+        let inv = current.inverse();
+        let log_prev = (&inv * prev).scaled_axis();
+        let log_next = (&inv * next).scaled_axis();
+        let quarter: T = crate::convert(0.25);
+
+        current * Self::new(-(log_prev + log_next) * quarter)
+    }
+
+    /// Spherical quadrangle interpolation (SQUAD) between two unit quaternions.
+    ///
+    /// The rotations `a` and `b` are the control points associated to `self` and `other`
+    /// respectively, as computed by `UnitQuaternion::squad_control_point`. Evaluating
+    /// successive segments of a keyframe sequence this way yields a C¹-continuous curve passing
+    /// through all the keyframes.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::UnitQuaternion;
+    /// let q0 = UnitQuaternion::from_euler_angles(0.0, 0.0, 0.0);
+    /// let q1 = UnitQuaternion::from_euler_angles(0.3, 0.1, 0.0);
+    /// let q2 = UnitQuaternion::from_euler_angles(0.5, 0.4, 0.2);
+    /// let q3 = UnitQuaternion::from_euler_angles(0.6, 0.8, 0.9);
+    /// let a = UnitQuaternion::squad_control_point(&q0, &q1, &q2);
+    /// let b = UnitQuaternion::squad_control_point(&q1, &q2, &q3);
+    ///
+    /// assert_relative_eq!(q1.squad(&q2, &a, &b, 0.0), q1, epsilon = 1.0e-7);
+    /// assert_relative_eq!(q1.squad(&q2, &a, &b, 1.0), q2, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn squad(&self, other: &Self, a: &Self, b: &Self, t: T) -> Self {
+        let two: T = crate::convert(2.0);
+        let s = two * t.clone() * (T::one() - t.clone());
+        let q1 = self.slerp(other, t.clone());
+        let q2 = a.slerp(b, t);
+
+        q1.slerp(&q2, s)
+    }
+
+    /// Evaluates a uniform cumulative cubic B-spline on SO(3).
+    ///
+    /// The four control points `[r0, r1, r2, r3]` define the segment of the spline parametrized
+    /// by `u` in `[0, 1]`, which lies between the influence of `r1` and `r2`. Sliding a window of
+    /// four consecutive control points over a sequence of rotations yields a C²-continuous
+    /// curve. Unlike SQUAD, the curve does not pass through the control points.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let r = [
+    ///     UnitQuaternion::new(Vector3::new(0.1, 0.0, 0.0)),
+    ///     UnitQuaternion::new(Vector3::new(0.2, 0.3, 0.0)),
+    ///     UnitQuaternion::new(Vector3::new(0.3, 0.4, 0.5)),
+    ///     UnitQuaternion::new(Vector3::new(0.4, 0.5, 0.9)),
+    ///     UnitQuaternion::new(Vector3::new(0.1, 0.2, 1.2)),
+    /// ];
+    /// let end_of_first = UnitQuaternion::cubic_bspline(&[r[0], r[1], r[2], r[3]], 1.0);
+    /// let start_of_second = UnitQuaternion::cubic_bspline(&[r[1], r[2], r[3], r[4]], 0.0);
+    /// assert_relative_eq!(end_of_first, start_of_second, epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn cubic_bspline(control_points: &[Self; 4], u: T) -> Self {
+        let six: T = crate::convert(6.0);
+        let two: T = crate::convert(2.0);
+        let three: T = crate::convert(3.0);
+        let five: T = crate::convert(5.0);
+        let u2 = u.clone() * u.clone();
+        let u3 = u2.clone() * u.clone();
+
+        let weights = [
+            (five + three.clone() * u.clone() - three.clone() * u2.clone() + u3.clone())
+                / six.clone(),
+            (T::one() + three.clone() * u + three * u2 - two * u3.clone()) / six.clone(),
+            u3 / six,
+        ];
+
+        let mut res = control_points[0].clone();
+
+        for (i, w) in weights.iter().enumerate() {
+            let delta = control_points[i].inverse() * &control_points[i + 1];
+            res *= Self::new(delta.scaled_axis() * w.clone());
+        }
+
+        res
+    }
+}
@@ -1,17 +1,38 @@
+use crate::base::allocator::Allocator;
 use crate::base::constraint::{AreMultipliable, DimEq, SameNumberOfRows, ShapeConstraint};
-use crate::base::{Const, Matrix, Unit, Vector};
-use crate::dimension::{Dim, U1};
+use crate::base::{Const, DefaultAllocator, Matrix, Matrix3, Matrix4, OMatrix, SMatrix, SVector};
+use crate::base::{Unit, Vector};
+use crate::dimension::{Dim, DimNameAdd, DimNameSum, U1};
 use crate::storage::{Storage, StorageMut};
-use simba::scalar::ComplexField;
+use simba::scalar::{ComplexField, RealField};
+use std::fmt;
 
-use crate::geometry::Point;
+use crate::geometry::{Affine2, Affine3, Point, Reflection2, Reflection3};
 
 /// A reflection wrt. a plane.
+///
+/// In 2D, this is a reflection wrt. a line, and in 3D a reflection wrt. a plane. The reflection
+/// plane is described by its unit normal `axis` and its signed distance `bias` to the origin.
+///
+/// Geometric reflections can be applied to points and vectors by multiplication, and composed
+/// with isometries and general transformations, in which case the result is an affine
+/// transformation. This type is also used internally by matrix decompositions as a Householder
+/// reflection.
+#[derive(Copy, Clone)]
 pub struct Reflection<T, D, S> {
     axis: Vector<T, D, S>,
     bias: T,
 }
 
+impl<T: fmt::Debug, D: Dim, S: fmt::Debug> fmt::Debug for Reflection<T, D, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reflection")
+            .field("axis", &self.axis)
+            .field("bias", &self.bias)
+            .finish()
+    }
+}
+
 impl<T: ComplexField, S: Storage<T, Const<D>>, const D: usize> Reflection<T, Const<D>, S> {
     /// Creates a new reflection wrt. the plane orthogonal to the given axis and that contains the
     /// point `pt`.
@@ -123,3 +144,108 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D>> Reflection<T, D, S> {
         lhs.gerc(m_two, work, &self.axis, sign);
     }
 }
+
+/// # Geometric reflection of points and vectors
+impl<T: RealField, S: Storage<T, Const<D>>, const D: usize> Reflection<T, Const<D>, S> {
+    /// Reflects the given point wrt. the reflection plane.
+    ///
+    /// This is the same as the multiplication `self * pt`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Point2, Reflection2, Vector2};
+    /// // Reflection wrt. the line `x = 1`.
+    /// let refl = Reflection2::new_containing_point(Vector2::x_axis(), &Point2::new(1.0, 0.0));
+    /// assert_relative_eq!(refl.transform_point(&Point2::new(3.0, 2.0)), Point2::new(-1.0, 2.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn transform_point(&self, pt: &Point<T, D>) -> Point<T, D> {
+        let two: T = crate::convert(2.0);
+        let factor = (self.axis.dot(&pt.coords) - self.bias.clone()) * two;
+        pt - &self.axis * factor
+    }
+
+    /// Reflects the given vector wrt. the reflection plane.
+    ///
+    /// The bias of the reflection is ignored since vectors are invariant by translation. This
+    /// is the same as the multiplication `self * v`.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector(&self, v: &SVector<T, D>) -> SVector<T, D> {
+        let two: T = crate::convert(2.0);
+        let factor = self.axis.dot(v) * two;
+        v - &self.axis * factor
+    }
+
+    /// The linear part of this reflection, i.e., the `D×D` Householder matrix `I - 2 * axis * axisᵀ`.
+    #[inline]
+    #[must_use]
+    pub fn to_linear_matrix(&self) -> SMatrix<T, D, D> {
+        let two: T = crate::convert(2.0);
+        SMatrix::identity() - &self.axis * self.axis.transpose() * two
+    }
+
+    /// Converts this reflection into its equivalent homogeneous transformation matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3, Point2, Reflection2, Vector2};
+    /// // Reflection wrt. the line `y = 2`.
+    /// let refl = Reflection2::new_containing_point(Vector2::y_axis(), &Point2::new(0.0, 2.0));
+    /// let expected = Matrix3::new(1.0,  0.0, 0.0,
+    ///                             0.0, -1.0, 4.0,
+    ///                             0.0,  0.0, 1.0);
+    /// assert_eq!(refl.to_homogeneous(), expected);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_homogeneous(&self) -> OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>
+    where
+        Const<D>: DimNameAdd<U1>,
+        DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+    {
+        let two: T = crate::convert(2.0);
+        let mut res = OMatrix::<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>::identity();
+        res.fixed_slice_mut::<D, D>(0, 0)
+            .copy_from(&self.to_linear_matrix());
+        res.fixed_slice_mut::<D, 1>(0, D)
+            .copy_from(&(&self.axis * (self.bias.clone() * two)));
+
+        res
+    }
+}
+
+/*
+ *
+ * Conversions.
+ *
+ */
+impl<T: RealField> From<Reflection2<T>> for Affine2<T> {
+    #[inline]
+    fn from(refl: Reflection2<T>) -> Self {
+        Affine2::from_matrix_unchecked(refl.to_homogeneous())
+    }
+}
+
+impl<T: RealField> From<Reflection3<T>> for Affine3<T> {
+    #[inline]
+    fn from(refl: Reflection3<T>) -> Self {
+        Affine3::from_matrix_unchecked(refl.to_homogeneous())
+    }
+}
+
+impl<T: RealField> From<Reflection2<T>> for Matrix3<T> {
+    #[inline]
+    fn from(refl: Reflection2<T>) -> Self {
+        refl.to_homogeneous()
+    }
+}
+
+impl<T: RealField> From<Reflection3<T>> for Matrix4<T> {
+    #[inline]
+    fn from(refl: Reflection3<T>) -> Self {
+        refl.to_homogeneous()
+    }
+}
@@ -0,0 +1,130 @@
+// The macros break if the references are taken out, for some reason.
+#![allow(clippy::op_ref)]
+
+/*
+ *
+ * This provides the following operator overladings:
+ *
+ * Reflection × Point
+ * Reflection × Vector
+ * Reflection × Isometry
+ * Isometry   × Reflection
+ * Reflection × Transform
+ * Transform  × Reflection
+ *
+ * Transform ×= Reflection
+ */
+
+use num::{One, Zero};
+use std::ops::{Mul, MulAssign};
+
+use simba::scalar::{ClosedAdd, ClosedMul, RealField, SubsetOf};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
+use crate::base::{ArrayStorage, Const, DefaultAllocator, OMatrix, SVector, Scalar};
+
+use crate::geometry::{
+    Isometry, Point, Reflection, TAffine, TCategory, TCategoryMul, Transform,
+};
+
+// Reflection × Point
+md_impl_all!(
+    Mul, mul where T: RealField;
+    (Const<D>, U1), (Const<D>, U1)
+    const D;
+    for;
+    where Const<D>: DimNameAdd<U1>;
+    self: Reflection<T, Const<D>, ArrayStorage<T, D, 1>>, right: Point<T, D>, Output = Point<T, D>;
+    [val val] => self.transform_point(&right);
+    [ref val] => self.transform_point(&right);
+    [val ref] => self.transform_point(right);
+    [ref ref] => self.transform_point(right);
+);
+
+// Reflection × Vector
+md_impl_all!(
+    Mul, mul where T: RealField;
+    (Const<D>, U1), (Const<D>, U1)
+    const D;
+    for;
+    where Const<D>: DimNameAdd<U1>;
+    self: Reflection<T, Const<D>, ArrayStorage<T, D, 1>>, right: SVector<T, D>, Output = SVector<T, D>;
+    [val val] => self.transform_vector(&right);
+    [ref val] => self.transform_vector(&right);
+    [val ref] => self.transform_vector(right);
+    [ref ref] => self.transform_vector(right);
+);
+
+// Reflection × Isometry
+md_impl_all!(
+    Mul, mul where T: RealField;
+    (Const<D>, U1), (Const<D>, U1)
+    const D;
+    for R;
+    where Const<D>: DimNameAdd<U1>, R: SubsetOf<OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>> >,
+          DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>;
+    self: Reflection<T, Const<D>, ArrayStorage<T, D, 1>>, rhs: Isometry<T, R, D>, Output = Transform<T, TAffine, D>;
+    [val val] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous());
+    [ref val] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous());
+    [val ref] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous());
+    [ref ref] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous());
+);
+
+// Isometry × Reflection
+md_impl_all!(
+    Mul, mul where T: RealField;
+    (Const<D>, U1), (Const<D>, U1)
+    const D;
+    for R;
+    where Const<D>: DimNameAdd<U1>, R: SubsetOf<OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>> >,
+          DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>;
+    self: Isometry<T, R, D>, rhs: Reflection<T, Const<D>, ArrayStorage<T, D, 1>>, Output = Transform<T, TAffine, D>;
+    [val val] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous());
+    [ref val] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous());
+    [val ref] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous());
+    [ref ref] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous());
+);
+
+// Reflection × Transform
+md_impl_all!(
+    Mul, mul where T: RealField;
+    (Const<D>, U1), (DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>)
+    const D;
+    for C;
+    where Const<D>: DimNameAdd<U1>, C: TCategoryMul<TAffine>,
+          DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>;
+    self: Reflection<T, Const<D>, ArrayStorage<T, D, 1>>, rhs: Transform<T, C, D>, Output = Transform<T, C::Representative, D>;
+    [val val] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.into_inner());
+    [ref val] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.into_inner());
+    [val ref] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.matrix());
+    [ref ref] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.matrix());
+);
+
+// Transform × Reflection
+md_impl_all!(
+    Mul, mul where T: RealField;
+    (DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>), (Const<D>, U1)
+    const D;
+    for C;
+    where Const<D>: DimNameAdd<U1>, C: TCategoryMul<TAffine>,
+          DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>;
+    self: Transform<T, C, D>, rhs: Reflection<T, Const<D>, ArrayStorage<T, D, 1>>, Output = Transform<T, C::Representative, D>;
+    [val val] => Self::Output::from_matrix_unchecked(self.into_inner() * rhs.to_homogeneous());
+    [ref val] => Self::Output::from_matrix_unchecked(self.matrix() * rhs.to_homogeneous());
+    [val ref] => Self::Output::from_matrix_unchecked(self.into_inner() * rhs.to_homogeneous());
+    [ref ref] => Self::Output::from_matrix_unchecked(self.matrix() * rhs.to_homogeneous());
+);
+
+// Transform ×= Reflection
+md_assign_impl_all!(
+    MulAssign, mul_assign where T: RealField;
+    (DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>), (Const<D>, U1)
+    const D;
+    for C;
+    where Const<D>: DimNameAdd<U1>, C: TCategory,
+          DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>;
+    self: Transform<T, C, D>, rhs: Reflection<T, Const<D>, ArrayStorage<T, D, 1>>;
+    [val] => *self.matrix_mut_unchecked() *= rhs.to_homogeneous();
+    [ref] => *self.matrix_mut_unchecked() *= rhs.to_homogeneous();
+);
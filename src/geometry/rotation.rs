@@ -500,6 +500,51 @@ where
     pub fn inverse_transform_unit_vector(&self, v: &Unit<SVector<T, D>>) -> Unit<SVector<T, D>> {
         Unit::new_unchecked(self.inverse_transform_vector(&**v))
     }
+
+    /// Rotates in-place each point of the given slice.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Point3, Rotation3, Vector3};
+    /// let rot = Rotation3::new(Vector3::y() * f32::consts::FRAC_PI_2);
+    /// let mut points = [Point3::new(1.0, 2.0, 3.0), Point3::new(0.0, 0.0, 1.0)];
+    /// rot.transform_points(&mut points);
+    ///
+    /// assert_relative_eq!(points[0], Point3::new(3.0, 2.0, -1.0), epsilon = 1.0e-6);
+    /// assert_relative_eq!(points[1], Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-6);
+    /// ```
+    #[inline]
+    pub fn transform_points(&self, points: &mut [Point<T, D>]) {
+        for pt in points {
+            pt.coords = self.matrix() * &pt.coords;
+        }
+    }
+
+    /// Rotates in-place each vector of the given slice.
+    #[inline]
+    pub fn transform_vectors(&self, vectors: &mut [SVector<T, D>]) {
+        for v in vectors {
+            *v = self.matrix() * &*v;
+        }
+    }
+
+    /// Rotates in-place each point of the given slice by the inverse of this rotation.
+    #[inline]
+    pub fn inverse_transform_points(&self, points: &mut [Point<T, D>]) {
+        for pt in points {
+            pt.coords = self.matrix().tr_mul(&pt.coords);
+        }
+    }
+
+    /// Rotates in-place each vector of the given slice by the inverse of this rotation.
+    #[inline]
+    pub fn inverse_transform_vectors(&self, vectors: &mut [SVector<T, D>]) {
+        for v in vectors {
+            *v = self.matrix().tr_mul(v);
+        }
+    }
 }
 
 impl<T: Scalar + Eq, const D: usize> Eq for Rotation<T, D> {}
@@ -0,0 +1,162 @@
+use simba::scalar::RealField;
+
+use crate::base::{Matrix3, Vector3};
+use crate::geometry::Rotation3;
+
+/// The number of terms of the power series of the Jacobians, enough for the accuracy of `f64`
+/// when `θ² < 1`.
+const SERIES_TERMS: usize = 10;
+
+/// The absolute values of the Bernoulli numbers `B₂, B₄, …, B₂₀`.
+const BERNOULLI: [f64; SERIES_TERMS] = [
+    1.0 / 6.0,
+    1.0 / 30.0,
+    1.0 / 42.0,
+    1.0 / 30.0,
+    5.0 / 66.0,
+    691.0 / 2730.0,
+    7.0 / 6.0,
+    3617.0 / 510.0,
+    43867.0 / 798.0,
+    174611.0 / 330.0,
+];
+
+/// Evaluates the power series `Σₖ coeff(k) θ²ᵏ`, truncated to its first `SERIES_TERMS` terms.
+///
+/// The closed forms of the coefficients of the Jacobians suffer from catastrophic cancellation
+/// for small angles, so these series are used instead whenever `θ² < 1`.
+pub(super) fn even_series<T: RealField>(theta2: T, coeff: impl Fn(usize) -> f64) -> T {
+    (0..SERIES_TERMS).rev().fold(T::zero(), |acc, k| {
+        acc * theta2.clone() + crate::convert(coeff(k))
+    })
+}
+
+/// `n!` as a float.
+fn factorial(n: usize) -> f64 {
+    (2..=n).fold(1.0, |acc, i| acc * i as f64)
+}
+
+/// `(-1)ᵏ / (2k + m)!`, the `k`-th coefficient of the series of `(1 - cos θ) / θ²` for `m = 2`,
+/// and of `(θ - sin θ) / θ³` for `m = 3`.
+pub(super) fn alternating_inverse_factorial(k: usize, m: usize) -> f64 {
+    let sign = [1.0, -1.0][k % 2];
+    sign / factorial(2 * k + m)
+}
+
+/// # Lie algebra so(3)
+impl<T: RealField> Rotation3<T> {
+    /// The hat operator, mapping a rotation vector to the corresponding element of so(3).
+    ///
+    /// This is the skew-symmetric matrix `[v]×` such that `[v]× * w == v.cross(&w)`. It is the
+    /// same as `v.cross_matrix()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// let w = Vector3::new(4.0, 5.0, 6.0);
+    /// assert_eq!(Rotation3::hat(&v) * w, v.cross(&w));
+    /// assert_eq!(Rotation3::vee(&Rotation3::hat(&v)), v);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hat(v: &Vector3<T>) -> Matrix3<T> {
+        v.cross_matrix()
+    }
+
+    /// The vee operator, inverse of the hat operator.
+    ///
+    /// This extracts the rotation vector from the skew-symmetric matrix `m`. Only the
+    /// skew-symmetric part of `m` is taken into account.
+    #[inline]
+    #[must_use]
+    pub fn vee(m: &Matrix3<T>) -> Vector3<T> {
+        let half: T = crate::convert(0.5);
+        Vector3::new(
+            m[(2, 1)].clone() - m[(1, 2)].clone(),
+            m[(0, 2)].clone() - m[(2, 0)].clone(),
+            m[(1, 0)].clone() - m[(0, 1)].clone(),
+        ) * half
+    }
+
+    /// The left Jacobian of the SO(3) exponential map at the rotation vector `phi`.
+    ///
+    /// For a small perturbation `delta`, this satisfies `Rotation3::new(phi + delta) ≈
+    /// Rotation3::new(left_jacobian(phi) * delta) * Rotation3::new(phi)`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let phi = Vector3::new(0.1, 0.2, 0.3);
+    /// let delta = Vector3::new(1.0e-6, -2.0e-6, 1.0e-6);
+    /// let jl = Rotation3::left_jacobian(&phi);
+    /// assert_relative_eq!(
+    ///     Rotation3::new(phi + delta),
+    ///     Rotation3::new(jl * delta) * Rotation3::new(phi),
+    ///     epsilon = 1.0e-10
+    /// );
+    /// ```
+    #[must_use]
+    pub fn left_jacobian(phi: &Vector3<T>) -> Matrix3<T> {
+        let theta2 = phi.norm_squared();
+        let phi_hat = phi.cross_matrix();
+        let phi_hat2 = &phi_hat * &phi_hat;
+
+        let (a, b) = if theta2 < T::one() {
+            (
+                even_series(theta2.clone(), |k| alternating_inverse_factorial(k, 2)),
+                even_series(theta2, |k| alternating_inverse_factorial(k, 3)),
+            )
+        } else {
+            let theta = theta2.clone().sqrt();
+            (
+                (T::one() - theta.clone().cos()) / theta2.clone(),
+                (theta.clone() - theta.clone().sin()) / (theta2 * theta),
+            )
+        };
+
+        Matrix3::identity() + phi_hat * a + phi_hat2 * b
+    }
+
+    /// The inverse of the left Jacobian of the SO(3) exponential map at the rotation vector `phi`.
+    ///
+    /// This is singular when the norm of `phi` is a non-zero multiple of `2 * pi`.
+    #[must_use]
+    pub fn left_jacobian_inverse(phi: &Vector3<T>) -> Matrix3<T> {
+        let theta2 = phi.norm_squared();
+        let phi_hat = phi.cross_matrix();
+        let phi_hat2 = &phi_hat * &phi_hat;
+        let half: T = crate::convert(0.5);
+
+        // The series of `(1 - θ/2 * cot(θ/2)) / θ²` is `Σₖ |B₂ₖ₊₂| θ²ᵏ / (2k + 2)!`.
+        let c = if theta2 < T::one() {
+            even_series(theta2, |k| BERNOULLI[k] / factorial(2 * k + 2))
+        } else {
+            let theta = theta2.clone().sqrt();
+            (T::one()
+                - theta.clone() * theta.clone().sin()
+                    / (crate::convert::<f64, T>(2.0) * (T::one() - theta.cos())))
+                / theta2
+        };
+
+        Matrix3::identity() - phi_hat * half + phi_hat2 * c
+    }
+
+    /// The right Jacobian of the SO(3) exponential map at the rotation vector `phi`.
+    ///
+    /// For a small perturbation `delta`, this satisfies `Rotation3::new(phi + delta) ≈
+    /// Rotation3::new(phi) * Rotation3::new(right_jacobian(phi) * delta)`.
+    #[inline]
+    #[must_use]
+    pub fn right_jacobian(phi: &Vector3<T>) -> Matrix3<T> {
+        Self::left_jacobian(&-phi)
+    }
+
+    /// The inverse of the right Jacobian of the SO(3) exponential map at the rotation vector `phi`.
+    #[inline]
+    #[must_use]
+    pub fn right_jacobian_inverse(phi: &Vector3<T>) -> Matrix3<T> {
+        Self::left_jacobian_inverse(&-phi)
+    }
+}
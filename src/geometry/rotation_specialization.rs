@@ -12,14 +12,17 @@ use rand::{
 };
 
 use simba::scalar::RealField;
-use simba::simd::{SimdBool, SimdRealField};
+use simba::simd::{SimdBool, SimdRealField, SimdValue};
 use std::ops::Neg;
 
 use crate::base::dimension::{U1, U2, U3};
 use crate::base::storage::Storage;
-use crate::base::{Matrix2, Matrix3, SMatrix, SVector, Unit, Vector, Vector1, Vector2, Vector3};
+use crate::base::{
+    duff_orthonormal_basis, Matrix2, Matrix3, SMatrix, SVector, Unit, Vector, Vector1, Vector2,
+    Vector3,
+};
 
-use crate::geometry::{Rotation2, Rotation3, UnitComplex, UnitQuaternion};
+use crate::geometry::{Rotation2, Rotation3, UnitComplex};
 
 /*
  *
@@ -456,8 +459,9 @@ where
     ///
     /// # Arguments
     ///   * dir - The look direction, that is, direction the matrix `z` axis will be aligned with.
-    ///   * up - The vertical direction. The only requirement of this parameter is to not be
-    ///   collinear to `dir`. Non-collinearity is not checked.
+    ///   * up - The vertical direction. If it is zero or (nearly) collinear to `dir`, the
+    ///   rotated `x` axis is chosen deterministically among the directions orthogonal to `dir`,
+    ///   instead of being undefined.
     ///
     /// # Example
     /// ```
@@ -469,6 +473,11 @@ where
     ///
     /// let rot = Rotation3::face_towards(&dir, &up);
     /// assert_relative_eq!(rot * Vector3::z(), dir.normalize());
+    ///
+    /// // Looking straight up still yields a valid rotation.
+    /// let rot = Rotation3::face_towards(&up, &up);
+    /// assert_relative_eq!(rot * Vector3::z(), up);
+    /// assert_relative_eq!(rot.matrix().determinant(), 1.0);
     /// ```
     #[inline]
     pub fn face_towards<SB, SC>(dir: &Vector<T, U3, SB>, up: &Vector<T, U3, SC>) -> Self
@@ -477,7 +486,17 @@ where
         SC: Storage<T, U3>,
     {
         let zaxis = dir.normalize();
-        let xaxis = up.cross(&zaxis).normalize();
+        let xaxis = up.cross(&zaxis);
+        let xaxis_norm_squared = xaxis.norm_squared();
+        let degenerate = xaxis_norm_squared
+            .clone()
+            .simd_le(up.norm_squared() * T::simd_default_epsilon());
+
+        // If `up` is collinear to `dir`, complete `zaxis` into an orthonormal basis with the
+        // method of Duff et al., "Building an Orthonormal Basis, Revisited" (2017).
+        let (fallback, _) = duff_orthonormal_basis(&zaxis);
+
+        let xaxis = fallback.select(degenerate, xaxis / xaxis_norm_squared.simd_sqrt());
         let yaxis = zaxis.cross(&xaxis).normalize();
 
         Self::from_matrix_unchecked(SMatrix::<T, 3, 3>::new(
@@ -511,8 +530,9 @@ where
     ///
     /// # Arguments
     ///   * dir - The direction toward which the camera looks.
-    ///   * up - A vector approximately aligned with required the vertical axis. The only
-    ///   requirement of this parameter is to not be collinear to `dir`.
+    ///   * up - A vector approximately aligned with required the vertical axis. If it is
+    ///   collinear to `dir`, a horizontal axis is chosen deterministically, see
+    ///   `Rotation3::face_towards`.
     ///
     /// # Example
     /// ```
@@ -542,8 +562,9 @@ where
     ///
     /// # Arguments
     ///   * dir - The direction toward which the camera looks.
-    ///   * up - A vector approximately aligned with required the vertical axis. The only
-    ///   requirement of this parameter is to not be collinear to `dir`.
+    ///   * up - A vector approximately aligned with required the vertical axis. If it is
+    ///   collinear to `dir`, a horizontal axis is chosen deterministically, see
+    ///   `Rotation3::face_towards`.
     ///
     /// # Example
     /// ```
@@ -704,7 +725,8 @@ where
     /// Builds a rotation matrix by extracting the rotation part of the given transformation `m`.
     ///
     /// This is an iterative method. See `.from_matrix_eps` to provide mover
-    /// convergence parameters and starting solution.
+    /// convergence parameters and starting solution, or `.from_matrix_nearest` for a
+    /// non-iterative projection based on the SVD.
     /// This implements "A Robust Method to Extract the Rotational Part of Deformations" by Müller et al.
     pub fn from_matrix(m: &Matrix3<T>) -> Self
     where
@@ -755,17 +777,126 @@ where
         Self::from_matrix_unchecked(rot)
     }
 
+    /// Builds the rotation matrix nearest to `m` in the Frobenius norm.
+    ///
+    /// This is the orthogonal factor of the polar decomposition of `m`, computed with its SVD
+    /// `m = U * Σ * Vᵀ` as `U * Vᵀ`, where the sign of the column of `U` associated to the
+    /// smallest singular value is flipped if necessary so that the result is a proper rotation,
+    /// i.e., with a determinant equal to `1` instead of `-1`.
+    ///
+    /// Unlike `from_matrix`, this is not iterative and any matrix, e.g., a rotation matrix that
+    /// drifted after accumulating rounding errors, or a reflection, yields a valid rotation.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Rotation3, Vector3};
+    /// let rot = Rotation3::from_axis_angle(&Vector3::y_axis(), 0.3);
+    /// let drifted = rot.matrix() + Matrix3::repeat(1.0e-3);
+    /// let nearest = Rotation3::from_matrix_nearest(&drifted);
+    ///
+    /// assert_relative_eq!(nearest.matrix().determinant(), 1.0, epsilon = 1.0e-10);
+    /// assert!((nearest.matrix() - drifted).norm() <= (rot.matrix() - drifted).norm());
+    /// ```
+    pub fn from_matrix_nearest(m: &Matrix3<T>) -> Self
+    where
+        T: RealField,
+    {
+        let svd = m.clone_owned().svd(true, true);
+        let mut u = svd.u.unwrap();
+        let v_t = svd.v_t.unwrap();
+
+        if (&u * &v_t).determinant() < T::zero() {
+            let imin = svd.singular_values.imin();
+            u.column_mut(imin).neg_mut();
+        }
+
+        Self::from_matrix_unchecked(u * v_t)
+    }
+
     /// Ensure this rotation is an orthonormal rotation matrix. This is useful when repeated
     /// computations might cause the matrix from progressively not being orthonormal anymore.
+    ///
+    /// This applies a few steps of the iteration `R ← R * (3 * I - Rᵀ * R) / 2`, which converges
+    /// quadratically towards the nearest rotation matrix when `R` is close to being orthonormal.
+    /// Rotations that drifted too far are projected with `Rotation3::from_matrix_nearest`.
     #[inline]
     pub fn renormalize(&mut self)
     where
         T: RealField,
     {
-        let mut c = UnitQuaternion::from(self.clone());
-        let _ = c.renormalize();
+        let eps = T::default_epsilon() * crate::convert(8.0);
+        let half: T = crate::convert(0.5);
+        let mut m = self.clone().into_inner();
 
-        *self = Self::from_matrix_eps(self.matrix(), T::default_epsilon(), 0, c.into())
+        for _ in 0..8 {
+            let err = Matrix3::identity() - m.tr_mul(&m);
+            let err_norm = err.norm();
+
+            if err_norm <= eps {
+                *self = Self::from_matrix_unchecked(m);
+                return;
+            }
+
+            if err_norm >= T::one() {
+                break;
+            }
+
+            m += &m * err * half.clone();
+        }
+
+        *self = Self::from_matrix_nearest(&m);
+    }
+
+    /// Computes the weighted chordal L2 mean of the given rotations.
+    ///
+    /// This is the rotation minimizing the weighted sum of the squared Frobenius distances to
+    /// each rotation of `rotations`. It is obtained by projecting the weighted arithmetic mean of
+    /// the rotation matrices back onto the set of rotations.
+    ///
+    /// Panics if `rotations` is empty or if `rotations` and `weights` have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Rotation3;
+    /// let r1 = Rotation3::from_euler_angles(0.0, 0.0, 0.0);
+    /// let r2 = Rotation3::from_euler_angles(0.0, 0.0, 0.2);
+    /// let r3 = Rotation3::from_euler_angles(0.0, 0.0, 0.3);
+    ///
+    /// let mean = Rotation3::chordal_mean_of(&[r1, r2, r3], &[1.0, 2.0, 0.0]);
+    /// assert_relative_eq!(mean.euler_angles().2, 0.1335, epsilon = 1.0e-4);
+    /// ```
+    pub fn chordal_mean_of(rotations: &[Self], weights: &[T]) -> Self
+    where
+        T: RealField,
+    {
+        assert!(
+            !rotations.is_empty(),
+            "Cannot compute the mean of an empty set of rotations."
+        );
+        assert_eq!(
+            rotations.len(),
+            weights.len(),
+            "The number of rotations and weights must match."
+        );
+
+        let mut mean = Matrix3::zeros();
+
+        for (r, w) in rotations.iter().zip(weights.iter()) {
+            mean += r.matrix() * w.clone();
+        }
+
+        let svd = mean.svd(true, true);
+        let mut u = svd.u.unwrap();
+        let v_t = svd.v_t.unwrap();
+
+        if (&u * &v_t).determinant() < T::zero() {
+            let imin = svd.singular_values.imin();
+            u.column_mut(imin).neg_mut();
+        }
+
+        Self::from_matrix_unchecked(u * v_t)
     }
 }
 
@@ -948,6 +1079,49 @@ impl<T: SimdRealField> Rotation3<T> {
     }
 }
 
+/// # Kinematics
+impl<T: SimdRealField> Rotation3<T>
+where
+    T::Element: SimdRealField,
+{
+    /// The orientation reached after rotating during `dt` with the constant angular velocity
+    /// `angular_velocity`, starting from this orientation.
+    ///
+    /// The angular velocity is expressed in the local frame of the rotating body, like the
+    /// measurements of a gyroscope attached to it. The integration is exact: this computes
+    /// `self * Rotation3::new(angular_velocity * dt)`. For an angular velocity expressed in the
+    /// global frame, use `Rotation3::new(angular_velocity * dt) * self` instead.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64::consts::PI;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let rot = Rotation3::from_axis_angle(&Vector3::z_axis(), PI / 2.0);
+    /// // Rotating about the local `x` axis is rotating about the global `y` axis.
+    /// let rot1 = rot.integrate(&Vector3::new(PI, 0.0, 0.0), 0.5);
+    ///
+    /// assert_relative_eq!(rot1 * Vector3::x(), Vector3::y(), epsilon = 1.0e-12);
+    /// assert_relative_eq!(rot1 * Vector3::y(), Vector3::z(), epsilon = 1.0e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn integrate(&self, angular_velocity: &Vector3<T>, dt: T) -> Self {
+        self * Self::new(angular_velocity * dt)
+    }
+
+    /// The time derivative `Ṙ = R * [ω]×` of this rotation matrix rotating with the angular
+    /// velocity `ω`, expressed in the local frame of the rotating body.
+    ///
+    /// `[ω]×` is the cross-product matrix of `ω`. For an angular velocity `ω` expressed in the
+    /// global frame, the derivative is `[ω]× * R`.
+    #[inline]
+    #[must_use]
+    pub fn derivative(&self, angular_velocity: &Vector3<T>) -> Matrix3<T> {
+        self.matrix() * angular_velocity.cross_matrix()
+    }
+}
+
 #[cfg(feature = "rand-no-std")]
 impl<T: SimdRealField> Distribution<Rotation3<T>> for Standard
 where
@@ -0,0 +1,418 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{One, Zero};
+use std::fmt;
+use std::hash;
+
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::{ClosedAdd, ClosedMul, RealField};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
+use crate::base::Scalar;
+use crate::base::{Const, DefaultAllocator, Matrix2, Matrix3, Matrix4, OMatrix, SMatrix, SVector};
+use crate::geometry::{Affine2, Affine3, Point, Transform2, Transform3};
+
+/// A shear transformation.
+///
+/// A shear is a linear transformation represented by a matrix with ones on its diagonal. Each
+/// off-diagonal entry `(i, j)` is the shear factor by which the `j`-th coordinate of a point is
+/// scaled before being added to its `i`-th coordinate. Note that the composition of two shears
+/// along different axes is generally not a shear. Since a shear may not be invertible, e.g., if
+/// `xy * yx == 1` in 2D, shears compose with other transformations into a general
+/// transformation, e.g., `Transform3`.
+///
+/// Note that instead of using the [`Shear`](crate::Shear) type in your code directly, you should
+/// use one of its aliases: [`Shear2`](crate::Shear2), or [`Shear3`](crate::Shear3).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: Scalar + Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: Scalar + Deserialize<'de>"))
+)]
+pub struct Shear<T, const D: usize> {
+    matrix: SMatrix<T, D, D>,
+}
+
+impl<T: Scalar + hash::Hash, const D: usize> hash::Hash for Shear<T, D>
+where
+    <DefaultAllocator as Allocator<T, Const<D>, Const<D>>>::Buffer: hash::Hash,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.matrix.hash(state)
+    }
+}
+
+impl<T: Scalar + Copy, const D: usize> Copy for Shear<T, D> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const D: usize> bytemuck::Zeroable for Shear<T, D>
+where
+    T: Scalar + bytemuck::Zeroable,
+    SMatrix<T, D, D>: bytemuck::Zeroable,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const D: usize> bytemuck::Pod for Shear<T, D>
+where
+    T: Scalar + bytemuck::Pod,
+    SMatrix<T, D, D>: bytemuck::Pod,
+{
+}
+
+impl<T: Scalar, const D: usize> Clone for Shear<T, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            matrix: self.matrix.clone(),
+        }
+    }
+}
+
+impl<T: Scalar + Eq, const D: usize> Eq for Shear<T, D> {}
+
+impl<T: Scalar + PartialEq, const D: usize> PartialEq for Shear<T, D> {
+    #[inline]
+    fn eq(&self, right: &Self) -> bool {
+        self.matrix == right.matrix
+    }
+}
+
+/// # Construction
+impl<T: Scalar + Zero + One, const D: usize> Shear<T, D> {
+    /// Creates the identity shear, i.e., a shear with all its factors set to zero.
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            matrix: SMatrix::identity(),
+        }
+    }
+
+    /// Creates a shear from the given matrix, ignoring its diagonal.
+    ///
+    /// The diagonal of the resulting shear matrix is set to one, and its off-diagonal elements
+    /// are copied from `matrix`.
+    #[inline]
+    pub fn from_matrix(matrix: &SMatrix<T, D, D>) -> Self {
+        let mut matrix = matrix.clone();
+        matrix.fill_diagonal(T::one());
+        Self { matrix }
+    }
+
+    /// The shear factor by which the `j`-th coordinate is scaled before being added to the
+    /// `i`-th coordinate.
+    ///
+    /// Panics if `i == j` or if any of the indices is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn factor(&self, i: usize, j: usize) -> T {
+        assert!(
+            i != j,
+            "The diagonal of a shear matrix is not a shear factor."
+        );
+        self.matrix[(i, j)].clone()
+    }
+
+    /// Sets the shear factor by which the `j`-th coordinate is scaled before being added to the
+    /// `i`-th coordinate.
+    ///
+    /// Panics if `i == j` or if any of the indices is out of bounds.
+    #[inline]
+    pub fn set_factor(&mut self, i: usize, j: usize, factor: T) {
+        assert!(
+            i != j,
+            "The diagonal of a shear matrix is not a shear factor."
+        );
+        self.matrix[(i, j)] = factor;
+    }
+}
+
+impl<T: Scalar + Zero + One> Shear<T, 2> {
+    /// Creates a 2D shear from its two shear factors.
+    ///
+    /// A point `(x, y)` is mapped to `(x + xy * y, y + yx * x)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Point2, Shear2};
+    /// let shear = Shear2::new(2.0, 0.0);
+    /// assert_eq!(shear * Point2::new(1.0, 3.0), Point2::new(7.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn new(xy: T, yx: T) -> Self {
+        Self {
+            matrix: Matrix2::new(T::one(), xy, yx, T::one()),
+        }
+    }
+}
+
+impl<T: Scalar + Zero + One> Shear<T, 3> {
+    /// Creates a 3D shear from its six shear factors.
+    ///
+    /// A point `(x, y, z)` is mapped to `(x + xy * y + xz * z, y + yx * x + yz * z, z + zx * x +
+    /// zy * y)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Point3, Shear3};
+    /// let shear = Shear3::new(0.0, 1.0, 0.0, 0.0, 0.0, 2.0);
+    /// assert_eq!(shear * Point3::new(1.0, 2.0, 3.0), Point3::new(4.0, 2.0, 7.0));
+    /// ```
+    #[inline]
+    pub fn new(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        Self {
+            matrix: Matrix3::new(T::one(), xy, xz, yx, T::one(), yz, zx, zy, T::one()),
+        }
+    }
+}
+
+/// # Conversion to a matrix
+impl<T: Scalar, const D: usize> Shear<T, D> {
+    /// A reference to the underlying matrix representation of this shear.
+    #[inline]
+    #[must_use]
+    pub fn matrix(&self) -> &SMatrix<T, D, D> {
+        &self.matrix
+    }
+
+    /// Unwraps the underlying matrix.
+    #[inline]
+    pub fn into_inner(self) -> SMatrix<T, D, D> {
+        self.matrix
+    }
+
+    /// Converts this shear into its equivalent homogeneous transformation matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3, Shear2};
+    /// let shear = Shear2::new(2.0, 3.0);
+    /// let expected = Matrix3::new(1.0, 2.0, 0.0,
+    ///                             3.0, 1.0, 0.0,
+    ///                             0.0, 0.0, 1.0);
+    /// assert_eq!(shear.to_homogeneous(), expected);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_homogeneous(&self) -> OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>
+    where
+        T: Zero + One,
+        Const<D>: DimNameAdd<U1>,
+        DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+    {
+        let mut res = OMatrix::<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>::identity();
+        res.fixed_slice_mut::<D, D>(0, 0).copy_from(&self.matrix);
+
+        res
+    }
+}
+
+/// # Transformation of a vector or a point
+impl<T: Scalar + Zero + One + ClosedAdd + ClosedMul, const D: usize> Shear<T, D> {
+    /// Shears the given point.
+    ///
+    /// This is the same as the multiplication `self * pt`.
+    #[inline]
+    #[must_use]
+    pub fn transform_point(&self, pt: &Point<T, D>) -> Point<T, D> {
+        self * pt
+    }
+
+    /// Shears the given vector.
+    ///
+    /// This is the same as the multiplication `self * v`.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector(&self, v: &SVector<T, D>) -> SVector<T, D> {
+        self * v
+    }
+}
+
+/// # Inversion
+impl<T: RealField> Shear<T, 2> {
+    /// Attempts to invert this shear.
+    ///
+    /// The inverse of a shear is generally not a shear, so the result is an affine
+    /// transformation. Returns `None` if this shear is not invertible, i.e., if `xy * yx == 1`.
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Affine2<T>> {
+        Affine2::from_matrix_unchecked(self.to_homogeneous()).try_inverse()
+    }
+
+    /// Converts this shear to an affine transformation, if it is invertible.
+    ///
+    /// Returns `None` if this shear is not invertible, i.e., if `xy * yx == 1`. Use
+    /// `Transform2::from` to convert any shear to a general transformation.
+    #[inline]
+    #[must_use]
+    pub fn try_to_affine(&self) -> Option<Affine2<T>> {
+        if self.matrix.determinant().is_zero() {
+            None
+        } else {
+            Some(Affine2::from_matrix_unchecked(self.to_homogeneous()))
+        }
+    }
+}
+
+/// # Inversion
+impl<T: RealField> Shear<T, 3> {
+    /// Attempts to invert this shear.
+    ///
+    /// The inverse of a shear is generally not a shear, so the result is an affine
+    /// transformation. Returns `None` if this shear is not invertible.
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Affine3<T>> {
+        Affine3::from_matrix_unchecked(self.to_homogeneous()).try_inverse()
+    }
+
+    /// Converts this shear to an affine transformation, if it is invertible.
+    ///
+    /// Returns `None` if this shear is not invertible. Use `Transform3::from` to convert any
+    /// shear to a general transformation.
+    #[inline]
+    #[must_use]
+    pub fn try_to_affine(&self) -> Option<Affine3<T>> {
+        if self.matrix.determinant().is_zero() {
+            None
+        } else {
+            Some(Affine3::from_matrix_unchecked(self.to_homogeneous()))
+        }
+    }
+}
+
+/*
+ *
+ * Conversions.
+ *
+ */
+// NOTE: a shear may not be invertible, so it converts to a general transformation. See
+// `Shear::try_to_affine` for a conversion to an affine transformation.
+impl<T: RealField> From<Shear<T, 2>> for Transform2<T> {
+    #[inline]
+    fn from(shear: Shear<T, 2>) -> Self {
+        Transform2::from_matrix_unchecked(shear.to_homogeneous())
+    }
+}
+
+impl<T: RealField> From<Shear<T, 3>> for Transform3<T> {
+    #[inline]
+    fn from(shear: Shear<T, 3>) -> Self {
+        Transform3::from_matrix_unchecked(shear.to_homogeneous())
+    }
+}
+
+impl<T: RealField> From<Shear<T, 2>> for Matrix3<T> {
+    #[inline]
+    fn from(shear: Shear<T, 2>) -> Self {
+        shear.to_homogeneous()
+    }
+}
+
+impl<T: RealField> From<Shear<T, 3>> for Matrix4<T> {
+    #[inline]
+    fn from(shear: Shear<T, 3>) -> Self {
+        shear.to_homogeneous()
+    }
+}
+
+impl<T: Scalar> From<Shear<T, 2>> for Matrix2<T> {
+    #[inline]
+    fn from(shear: Shear<T, 2>) -> Self {
+        shear.into_inner()
+    }
+}
+
+impl<T: Scalar> From<Shear<T, 3>> for Matrix3<T> {
+    #[inline]
+    fn from(shear: Shear<T, 3>) -> Self {
+        shear.into_inner()
+    }
+}
+
+/*
+ *
+ * Approximate equality
+ *
+ */
+impl<T, const D: usize> AbsDiffEq for Shear<T, D>
+where
+    T: Scalar + AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.matrix.abs_diff_eq(&other.matrix, epsilon)
+    }
+}
+
+impl<T, const D: usize> RelativeEq for Shear<T, D>
+where
+    T: Scalar + RelativeEq,
+    T::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.matrix
+            .relative_eq(&other.matrix, epsilon, max_relative)
+    }
+}
+
+impl<T, const D: usize> UlpsEq for Shear<T, D>
+where
+    T: Scalar + UlpsEq,
+    T::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.matrix.ulps_eq(&other.matrix, epsilon, max_ulps)
+    }
+}
+
+/*
+ *
+ * Display
+ *
+ */
+impl<T, const D: usize> fmt::Display for Shear<T, D>
+where
+    T: RealField + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+
+        writeln!(f, "Shear matrix {{")?;
+        write!(f, "{:.*}", precision, self.matrix)?;
+        writeln!(f, "}}")
+    }
+}
@@ -0,0 +1,7 @@
+use crate::geometry::Shear;
+
+/// A 2-dimensional shear.
+pub type Shear2<T> = Shear<T, 2>;
+
+/// A 3-dimensional shear.
+pub type Shear3<T> = Shear<T, 3>;
@@ -0,0 +1,108 @@
+/*
+ *
+ * This provides the following operator overladings:
+ *
+ * Index<(usize, usize)>
+ *
+ * Shear     × Point
+ * Shear     × Vector
+ * Shear     × Transform
+ * Transform × Shear
+ *
+ * Transform ×= Shear
+ */
+
+use num::{One, Zero};
+use std::ops::{Index, Mul, MulAssign};
+
+use simba::scalar::{ClosedAdd, ClosedMul, RealField};
+
+use crate::base::allocator::Allocator;
+use crate::base::constraint::{AreMultipliable, ShapeConstraint};
+use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
+use crate::base::{Const, DefaultAllocator, SVector, Scalar};
+
+use crate::geometry::{Point, Shear, TCategory, TGeneral, Transform};
+
+impl<T: Scalar, const D: usize> Index<(usize, usize)> for Shear<T, D> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, row_col: (usize, usize)) -> &T {
+        self.matrix().index(row_col)
+    }
+}
+
+// Shear × Point
+md_impl_all!(
+    Mul, mul;
+    (Const<D>, Const<D>), (Const<D>, U1)
+    const D;
+    for;
+    where ShapeConstraint: AreMultipliable<Const<D>, Const<D>, Const<D>, U1>;
+    self: Shear<T, D>, right: Point<T, D>, Output = Point<T, D>;
+    [val val] => self.into_inner() * right;
+    [ref val] => self.matrix() * right;
+    [val ref] => self.into_inner() * right;
+    [ref ref] => self.matrix() * right;
+);
+
+// Shear × Vector
+md_impl_all!(
+    Mul, mul;
+    (Const<D>, Const<D>), (Const<D>, U1)
+    const D;
+    for;
+    where ShapeConstraint: AreMultipliable<Const<D>, Const<D>, Const<D>, U1>;
+    self: Shear<T, D>, right: SVector<T, D>, Output = SVector<T, D>;
+    [val val] => self.into_inner() * right;
+    [ref val] => self.matrix() * right;
+    [val ref] => self.into_inner() * right;
+    [ref ref] => self.matrix() * right;
+);
+
+// NOTE: a shear may not be invertible, e.g., if `xy * yx == 1` in 2D, so its products with a
+// `Transform` are general transformations, whatever the category of the `Transform`.
+
+// Transform × Shear
+md_impl_all!(
+    Mul, mul where T: RealField;
+    (DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>), (Const<D>, Const<D>)
+    const D;
+    for C;
+    where Const<D>: DimNameAdd<U1>, C: TCategory,
+          DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>;
+    self: Transform<T, C, D>, rhs: Shear<T, D>, Output = Transform<T, TGeneral, D>;
+    [val val] => Self::Output::from_matrix_unchecked(self.into_inner() * rhs.to_homogeneous());
+    [ref val] => Self::Output::from_matrix_unchecked(self.matrix() * rhs.to_homogeneous());
+    [val ref] => Self::Output::from_matrix_unchecked(self.into_inner() * rhs.to_homogeneous());
+    [ref ref] => Self::Output::from_matrix_unchecked(self.matrix() * rhs.to_homogeneous());
+);
+
+// Shear × Transform
+md_impl_all!(
+    Mul, mul where T: RealField;
+    (Const<D>, Const<D>), (DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>)
+    const D;
+    for C;
+    where Const<D>: DimNameAdd<U1>, C: TCategory,
+          DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>;
+    self: Shear<T, D>, rhs: Transform<T, C, D>, Output = Transform<T, TGeneral, D>;
+    [val val] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.into_inner());
+    [ref val] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.into_inner());
+    [val ref] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.matrix());
+    [ref ref] => Self::Output::from_matrix_unchecked(self.to_homogeneous() * rhs.matrix());
+);
+
+// Transform ×= Shear
+md_assign_impl_all!(
+    MulAssign, mul_assign where T: RealField;
+    (DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>), (Const<D>, Const<D>)
+    const D;
+    for;
+    where Const<D>: DimNameAdd<U1>,
+          DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>;
+    self: Transform<T, TGeneral, D>, rhs: Shear<T, D>;
+    [val] => *self.matrix_mut_unchecked() *= rhs.to_homogeneous();
+    [ref] => *self.matrix_mut_unchecked() *= rhs.to_homogeneous();
+);
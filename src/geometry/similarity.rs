@@ -20,6 +20,8 @@ use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
 use crate::base::storage::Owned;
 use crate::base::{Const, DefaultAllocator, OMatrix, SVector, Scalar};
 use crate::geometry::{AbstractRotation, Isometry, Point, Translation};
+#[cfg(feature = "bytemuck")]
+use crate::geometry::{Rotation, UnitComplex, UnitQuaternion};
 
 /// A similarity, i.e., an uniform scaling, followed by a rotation, followed by a translation.
 #[repr(C)]
@@ -80,6 +82,44 @@ where
 {
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, R, const D: usize> bytemuck::Zeroable for Similarity<T, R, D>
+where
+    T: Scalar + bytemuck::Zeroable,
+    Isometry<T, R, D>: bytemuck::Zeroable,
+{
+}
+
+// NOTE: `Pod` is only implemented for the isometries that implement it, i.e., those with a
+// rotation with the same scalar type as the scaling factor, which rules out any padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const D: usize> bytemuck::Pod for Similarity<T, Rotation<T, D>, D>
+where
+    T: Scalar + Zero + bytemuck::Pod,
+    Rotation<T, D>: AbstractRotation<T, D>,
+    Isometry<T, Rotation<T, D>, D>: bytemuck::Pod,
+    Owned<T, Const<D>>: Copy,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Similarity<T, UnitQuaternion<T>, 3>
+where
+    T: Scalar + Zero + bytemuck::Pod,
+    UnitQuaternion<T>: AbstractRotation<T, 3>,
+    Isometry<T, UnitQuaternion<T>, 3>: bytemuck::Pod,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Similarity<T, UnitComplex<T>, 2>
+where
+    T: Scalar + Zero + bytemuck::Pod,
+    UnitComplex<T>: AbstractRotation<T, 2>,
+    Isometry<T, UnitComplex<T>, 2>: bytemuck::Pod,
+{
+}
+
 impl<T: Scalar + Zero, R: AbstractRotation<T, D> + Clone, const D: usize> Clone
     for Similarity<T, R, D>
 {
@@ -319,6 +359,77 @@ where
     }
 }
 
+/// # Batch transformation of points and vectors
+impl<T: SimdRealField, R, const D: usize> Similarity<T, R, D>
+where
+    T::Element: SimdRealField,
+    R: AbstractRotation<T, D>,
+{
+    /// Transforms in-place each point of the given slice by this similarity.
+    ///
+    /// The rotation and scaling are combined only once into a single matrix so that each
+    /// point is then transformed by a small matrix-vector product followed by a translation.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f32;
+    /// # use nalgebra::{Point3, Similarity3, Vector3};
+    /// let axisangle = Vector3::y() * f32::consts::FRAC_PI_2;
+    /// let translation = Vector3::new(1.0, 2.0, 3.0);
+    /// let sim = Similarity3::new(translation, axisangle, 3.0);
+    /// let mut points = [Point3::new(4.0, 5.0, 6.0)];
+    /// sim.transform_points(&mut points);
+    /// assert_relative_eq!(points[0], Point3::new(19.0, 17.0, -9.0), epsilon = 1.0e-5);
+    /// ```
+    #[inline]
+    pub fn transform_points(&self, points: &mut [Point<T, D>]) {
+        let linear = self.isometry.rotation_matrix() * self.scaling();
+
+        for pt in points {
+            pt.coords = &linear * &pt.coords + &self.isometry.translation.vector;
+        }
+    }
+
+    /// Transforms in-place each vector of the given slice by this similarity, ignoring the
+    /// translational component.
+    ///
+    /// See `transform_points` for details regarding the performance of this method.
+    #[inline]
+    pub fn transform_vectors(&self, vectors: &mut [SVector<T, D>]) {
+        let linear = self.isometry.rotation_matrix() * self.scaling();
+
+        for v in vectors {
+            *v = &linear * &*v;
+        }
+    }
+
+    /// Transforms in-place each point of the given slice by the inverse of this similarity.
+    ///
+    /// See `transform_points` for details regarding the performance of this method.
+    #[inline]
+    pub fn inverse_transform_points(&self, points: &mut [Point<T, D>]) {
+        let linear = self.isometry.rotation_matrix() / self.scaling();
+
+        for pt in points {
+            pt.coords = linear.tr_mul(&(&pt.coords - &self.isometry.translation.vector));
+        }
+    }
+
+    /// Transforms in-place each vector of the given slice by the inverse of this similarity,
+    /// ignoring the translational component.
+    ///
+    /// See `transform_points` for details regarding the performance of this method.
+    #[inline]
+    pub fn inverse_transform_vectors(&self, vectors: &mut [SVector<T, D>]) {
+        let linear = self.isometry.rotation_matrix() / self.scaling();
+
+        for v in vectors {
+            *v = linear.tr_mul(v);
+        }
+    }
+}
+
 // NOTE: we don't require `R: Rotation<...>` here because this is not useful for the implementation
 // and makes it harder to use it, e.g., for Transform × Isometry implementation.
 // This is OK since all constructors of the isometry enforce the Rotation bound already (and
@@ -274,8 +274,8 @@ macro_rules! similarity_construction_impl(
             /// # Arguments
             ///   * eye - The observer position.
             ///   * target - The target position.
-            ///   * up - Vertical direction. The only requirement of this parameter is to not be collinear
-            ///   to `eye - at`. Non-collinearity is not checked.
+            ///   * up - Vertical direction. If it is collinear to `target - eye`, a horizontal axis is
+            ///   chosen deterministically, see `Rotation3::face_towards`.
             ///
             /// # Example
             ///
@@ -324,8 +324,9 @@ macro_rules! similarity_construction_impl(
             /// # Arguments
             ///   * eye - The eye position.
             ///   * target - The target position.
-            ///   * up - A vector approximately aligned with required the vertical axis. The only
-            ///   requirement of this parameter is to not be collinear to `target - eye`.
+            ///   * up - A vector approximately aligned with required the vertical axis. If it is
+            ///   collinear to `target - eye`, a horizontal axis is chosen deterministically, see
+            ///   `Rotation3::face_towards`.
             ///
             /// # Example
             ///
@@ -362,8 +363,9 @@ macro_rules! similarity_construction_impl(
             /// # Arguments
             ///   * eye - The eye position.
             ///   * target - The target position.
-            ///   * up - A vector approximately aligned with required the vertical axis. The only
-            ///   requirement of this parameter is to not be collinear to `target - eye`.
+            ///   * up - A vector approximately aligned with required the vertical axis. If it is
+            ///   collinear to `target - eye`, a horizontal axis is chosen deterministically, see
+            ///   `Rotation3::face_towards`.
             ///
             /// # Example
             ///
@@ -0,0 +1,433 @@
+//! Cubic splines interpolating sequences of points and of rigid-body poses.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use simba::scalar::RealField;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimName, U3};
+use crate::base::{DefaultAllocator, OVector, Scalar};
+use crate::geometry::{Isometry3, OPoint, Translation3, UnitQuaternion};
+
+/// # Hermite interpolation
+impl<T: RealField, D: DimName> OPoint<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// The point at the parameter `t ∈ [0, 1]` of the cubic Hermite curve going from `p0` with
+    /// the tangent `m0`, to `p1` with the tangent `m1`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Point2, Vector2};
+    /// let (p0, p1) = (Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+    /// let (m0, m1) = (Vector2::new(0.0, 1.0), Vector2::new(0.0, -1.0));
+    ///
+    /// assert_eq!(Point2::hermite(&p0, &m0, &p1, &m1, 0.0), p0);
+    /// assert_eq!(Point2::hermite(&p0, &m0, &p1, &m1, 1.0), p1);
+    /// assert_relative_eq!(Point2::hermite(&p0, &m0, &p1, &m1, 0.5), Point2::new(0.5, 0.25));
+    /// ```
+    #[must_use]
+    pub fn hermite(p0: &Self, m0: &OVector<T, D>, p1: &Self, m1: &OVector<T, D>, t: T) -> Self {
+        let t2 = t.clone() * t.clone();
+        let t3 = t2.clone() * t.clone();
+        let two: T = crate::convert(2.0);
+        let three: T = crate::convert(3.0);
+
+        // The basis functions of the points sum to one, so only the one of `p1` is needed.
+        let h01 = three * t2.clone() - two.clone() * t3.clone();
+        let h10 = t3.clone() - two * t2.clone() + t;
+        let h11 = t3 - t2;
+
+        let mut coords = p0.coords.clone();
+        coords.axpy(h01.clone(), &p1.coords, T::one());
+        coords.axpy(-h01, &p0.coords, T::one());
+        coords.axpy(h10, m0, T::one());
+        coords.axpy(h11, m1, T::one());
+        Self::from(coords)
+    }
+}
+
+/// Splits the parameter `t` of a spline with `n` control points into the index of a segment and
+/// the parameter in `[0, 1]` along this segment.
+fn segment<T: RealField>(t: T, n: usize) -> (usize, T) {
+    if n < 2 || t <= T::zero() {
+        return (0, T::zero());
+    }
+
+    let last: T = crate::convert((n - 1) as f64);
+    if t >= last {
+        return (n - 2, T::one());
+    }
+
+    let i: f64 = t.clone().floor().to_subset_unchecked();
+    let i = (i as usize).min(n - 2);
+    let u = t - crate::convert::<_, T>(i as f64);
+    (i, u)
+}
+
+/// A cubic Hermite spline interpolating a sequence of points with given tangents.
+///
+/// The spline is parameterized by `t ∈ [0, n - 1]`, where `n` is the number of control points:
+/// the `i`-th control point is reached at `t = i`. Use `.arc_length_parameterization` to move
+/// along the spline at a constant speed instead.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{HermiteSpline, Point3};
+/// let points = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(2.0, 0.0, 0.0),
+///     Point3::new(3.0, 1.0, 1.0),
+/// ];
+/// let spline = HermiteSpline::catmull_rom(points.clone());
+///
+/// assert_eq!(spline.max_parameter(), 3.0);
+/// assert_relative_eq!(spline.eval(1.0), points[1]);
+/// assert_relative_eq!(spline.eval(2.0), points[2]);
+///
+/// // Move along the spline at a constant speed.
+/// let arc_length = spline.arc_length_parameterization(64);
+/// let midpoint = spline.eval(arc_length.parameter_at(arc_length.length() / 2.0));
+/// # let _ = midpoint;
+/// ```
+#[derive(Clone, Debug)]
+pub struct HermiteSpline<T: Scalar, D: DimName>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    points: Vec<OPoint<T, D>>,
+    tangents: Vec<OVector<T, D>>,
+}
+
+impl<T: RealField, D: DimName> HermiteSpline<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Creates a spline interpolating `points` with the given `tangents` at each point.
+    ///
+    /// # Panics
+    /// Panics if there is no point, or if the numbers of points and tangents don't match.
+    #[inline]
+    pub fn new(points: Vec<OPoint<T, D>>, tangents: Vec<OVector<T, D>>) -> Self {
+        assert!(!points.is_empty(), "A spline needs at least one point.");
+        assert_eq!(
+            points.len(),
+            tangents.len(),
+            "Hermite spline: the numbers of points and tangents must match."
+        );
+
+        Self { points, tangents }
+    }
+
+    /// Creates a uniform Catmull–Rom spline interpolating `points`.
+    ///
+    /// The tangent at each point is half the difference between the next and previous points.
+    /// At the first and last points, one-sided differences are used instead.
+    ///
+    /// # Panics
+    /// Panics if there is no point.
+    pub fn catmull_rom(points: Vec<OPoint<T, D>>) -> Self {
+        assert!(!points.is_empty(), "A spline needs at least one point.");
+
+        let n = points.len();
+        let half: T = crate::convert(0.5);
+        let tangents = (0..n)
+            .map(|i| {
+                if n == 1 {
+                    OVector::zeros()
+                } else if i == 0 {
+                    &points[1] - &points[0]
+                } else if i == n - 1 {
+                    &points[n - 1] - &points[n - 2]
+                } else {
+                    (&points[i + 1] - &points[i - 1]) * half.clone()
+                }
+            })
+            .collect();
+
+        Self { points, tangents }
+    }
+
+    /// The points interpolated by this spline.
+    #[inline]
+    #[must_use]
+    pub fn points(&self) -> &[OPoint<T, D>] {
+        &self.points
+    }
+
+    /// The tangents of this spline at each of its points.
+    #[inline]
+    #[must_use]
+    pub fn tangents(&self) -> &[OVector<T, D>] {
+        &self.tangents
+    }
+
+    /// The parameter of the last point of this spline, i.e., the number of points minus one.
+    #[inline]
+    #[must_use]
+    pub fn max_parameter(&self) -> T {
+        crate::convert((self.points.len() - 1) as f64)
+    }
+
+    /// The point of this spline at the parameter `t`, clamped to `[0, self.max_parameter()]`.
+    #[must_use]
+    pub fn eval(&self, t: T) -> OPoint<T, D> {
+        if self.points.len() == 1 {
+            return self.points[0].clone();
+        }
+
+        let (i, u) = segment(t, self.points.len());
+        OPoint::hermite(
+            &self.points[i],
+            &self.tangents[i],
+            &self.points[i + 1],
+            &self.tangents[i + 1],
+            u,
+        )
+    }
+
+    /// The derivative of this spline with respect to its parameter, at the parameter `t`
+    /// clamped to `[0, self.max_parameter()]`.
+    #[must_use]
+    pub fn derivative(&self, t: T) -> OVector<T, D> {
+        if self.points.len() == 1 {
+            return OVector::zeros();
+        }
+
+        let (i, u) = segment(t, self.points.len());
+        let u2 = u.clone() * u.clone();
+        let two: T = crate::convert(2.0);
+        let three: T = crate::convert(3.0);
+        let six: T = crate::convert(6.0);
+
+        let dh01 = six * (u.clone() - u2.clone());
+        let dh10 = three.clone() * u2.clone() - two.clone() * two.clone() * u.clone() + T::one();
+        let dh11 = three * u2 - two * u;
+
+        let mut res = &self.points[i + 1] - &self.points[i];
+        res *= dh01;
+        res.axpy(dh10, &self.tangents[i], T::one());
+        res.axpy(dh11, &self.tangents[i + 1], T::one());
+        res
+    }
+
+    /// Approximates the arc length of this spline, to evaluate it at a given distance from its
+    /// first point.
+    ///
+    /// The spline is approximated by a polyline with `samples_per_segment` segments between two
+    /// consecutive points.
+    ///
+    /// # Panics
+    /// Panics if `samples_per_segment` is zero.
+    pub fn arc_length_parameterization(
+        &self,
+        samples_per_segment: usize,
+    ) -> ArcLengthParameterization<T> {
+        assert!(
+            samples_per_segment > 0,
+            "Arc length parameterization: at least one sample per segment is needed."
+        );
+
+        let num_samples = (self.points.len() - 1) * samples_per_segment;
+        let step = T::one() / crate::convert(samples_per_segment as f64);
+        let mut lengths = Vec::with_capacity(num_samples + 1);
+        let mut prev = self.points[0].clone();
+        let mut length = T::zero();
+        lengths.push(length.clone());
+
+        for j in 1..=num_samples {
+            let curr = self.eval(step.clone() * crate::convert(j as f64));
+            length += (&curr - &prev).norm();
+            lengths.push(length.clone());
+            prev = curr;
+        }
+
+        ArcLengthParameterization {
+            lengths,
+            samples_per_segment,
+        }
+    }
+}
+
+/// A table converting distances along a spline into values of its parameter.
+///
+/// This is created by `HermiteSpline::arc_length_parameterization`.
+#[derive(Clone, Debug)]
+pub struct ArcLengthParameterization<T> {
+    // The arc lengths at uniformly spaced values of the parameter of the spline.
+    lengths: Vec<T>,
+    samples_per_segment: usize,
+}
+
+impl<T: RealField> ArcLengthParameterization<T> {
+    /// The approximate total length of the spline.
+    #[inline]
+    #[must_use]
+    pub fn length(&self) -> T {
+        self.lengths.last().cloned().unwrap_or_else(T::zero)
+    }
+
+    /// The parameter of the spline at the distance `s` along the spline from its first point.
+    ///
+    /// The distance `s` is clamped to `[0, self.length()]`.
+    #[must_use]
+    pub fn parameter_at(&self, s: T) -> T {
+        let samples_per_segment: T = crate::convert(self.samples_per_segment as f64);
+
+        if s <= T::zero() || self.lengths.len() < 2 {
+            return T::zero();
+        }
+
+        if s >= self.length() {
+            return crate::convert::<_, T>((self.lengths.len() - 1) as f64) / samples_per_segment;
+        }
+
+        // Index of the first sample at a distance greater than or equal to `s`.
+        let j = match self
+            .lengths
+            .binary_search_by(|l| l.partial_cmp(&s).unwrap_or(Ordering::Less))
+        {
+            Ok(j) | Err(j) => j.max(1),
+        };
+
+        let l0 = self.lengths[j - 1].clone();
+        let l1 = self.lengths[j].clone();
+        let frac = if l1 > l0.clone() {
+            (s - l0.clone()) / (l1 - l0)
+        } else {
+            T::zero()
+        };
+
+        (crate::convert::<_, T>((j - 1) as f64) + frac) / samples_per_segment
+    }
+}
+
+/// A spline interpolating a sequence of rigid-body poses.
+///
+/// The translations are interpolated by a Catmull–Rom spline, and the rotations by spherical
+/// cubic interpolation (squad), which is the rotational equivalent of a Catmull–Rom spline. The
+/// spline is parameterized by `t ∈ [0, n - 1]`, where `n` is the number of keyframes.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Isometry3, IsometrySpline3, Vector3};
+/// let keyframes = [
+///     Isometry3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.0)),
+///     Isometry3::new(Vector3::new(1.0, 0.0, 1.0), Vector3::new(0.0, 0.5, 0.0)),
+///     Isometry3::new(Vector3::new(2.0, 1.0, 1.0), Vector3::new(0.2, 1.0, 0.0)),
+/// ];
+/// let spline = IsometrySpline3::catmull_rom(&keyframes);
+///
+/// assert_relative_eq!(spline.eval(1.0), keyframes[1], epsilon = 1.0e-10);
+/// assert_relative_eq!(spline.eval(2.0), keyframes[2], epsilon = 1.0e-10);
+///
+/// // Move the camera at a constant speed.
+/// let arc_length = spline.translation_spline().arc_length_parameterization(64);
+/// let pose = spline.eval(arc_length.parameter_at(1.0));
+/// # let _ = pose;
+/// ```
+#[derive(Clone, Debug)]
+pub struct IsometrySpline3<T: Scalar> {
+    translations: HermiteSpline<T, U3>,
+    rotations: Vec<UnitQuaternion<T>>,
+    // The inner control rotations of squad.
+    controls: Vec<UnitQuaternion<T>>,
+}
+
+impl<T: RealField> IsometrySpline3<T> {
+    /// Creates a spline interpolating the given keyframes.
+    ///
+    /// # Panics
+    /// Panics if there is no keyframe.
+    pub fn catmull_rom(keyframes: &[Isometry3<T>]) -> Self {
+        assert!(
+            !keyframes.is_empty(),
+            "A spline needs at least one keyframe."
+        );
+
+        let translations = HermiteSpline::catmull_rom(
+            keyframes
+                .iter()
+                .map(|k| k.translation.vector.clone().into())
+                .collect(),
+        );
+
+        // Make consecutive quaternions lie in the same hemisphere, so that the interpolation
+        // follows the shortest paths.
+        let mut rotations: Vec<UnitQuaternion<T>> = Vec::with_capacity(keyframes.len());
+        for k in keyframes {
+            let mut q = k.rotation.clone();
+            if let Some(prev) = rotations.last() {
+                if prev.coords.dot(&q.coords) < T::zero() {
+                    q = UnitQuaternion::new_unchecked(-q.into_inner());
+                }
+            }
+            rotations.push(q);
+        }
+
+        let n = rotations.len();
+        let quarter: T = crate::convert(0.25);
+        let controls = (0..n)
+            .map(|i| {
+                if i == 0 || i == n - 1 {
+                    rotations[i].clone()
+                } else {
+                    let inv = rotations[i].inverse();
+                    let next = (&inv * &rotations[i + 1]).scaled_axis();
+                    let prev = (&inv * &rotations[i - 1]).scaled_axis();
+                    &rotations[i]
+                        * UnitQuaternion::from_scaled_axis((next + prev) * -quarter.clone())
+                }
+            })
+            .collect();
+
+        Self {
+            translations,
+            rotations,
+            controls,
+        }
+    }
+
+    /// The spline followed by the translation part of the keyframes.
+    ///
+    /// Its arc length parameterization can be used to move along this spline at a constant
+    /// speed.
+    #[inline]
+    #[must_use]
+    pub fn translation_spline(&self) -> &HermiteSpline<T, U3> {
+        &self.translations
+    }
+
+    /// The parameter of the last keyframe of this spline, i.e., the number of keyframes minus
+    /// one.
+    #[inline]
+    #[must_use]
+    pub fn max_parameter(&self) -> T {
+        self.translations.max_parameter()
+    }
+
+    /// The pose of this spline at the parameter `t`, clamped to `[0, self.max_parameter()]`.
+    #[must_use]
+    pub fn eval(&self, t: T) -> Isometry3<T> {
+        let translation = Translation3::from(self.translations.eval(t.clone()).coords);
+
+        if self.rotations.len() == 1 {
+            return Isometry3::from_parts(translation, self.rotations[0].clone());
+        }
+
+        let (i, u) = segment(t, self.rotations.len());
+        let two: T = crate::convert(2.0);
+        let q = self.rotations[i].slerp(&self.rotations[i + 1], u.clone());
+        let s = self.controls[i].slerp(&self.controls[i + 1], u.clone());
+        let rotation = q.slerp(&s, two * u.clone() * (T::one() - u));
+
+        Isometry3::from_parts(translation, rotation)
+    }
+}
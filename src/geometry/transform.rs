@@ -12,9 +12,9 @@ use simba::scalar::RealField;
 use crate::base::allocator::Allocator;
 use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
 use crate::base::storage::Owned;
-use crate::base::{Const, DefaultAllocator, DimName, OMatrix, SVector};
+use crate::base::{Const, DefaultAllocator, Dim, DimName, OMatrix, SMatrix, SVector, Vector3};
 
-use crate::geometry::Point;
+use crate::geometry::{Point, Shear3, Translation3, UnitQuaternion};
 
 /// Trait implemented by phantom types identifying the projective transformation type.
 ///
@@ -70,6 +70,21 @@ pub enum TProjective {}
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum TAffine {}
 
+/// The most specific kind of transformation represented by the homogeneous matrix of a
+/// `Transform`, as determined at runtime by `Transform::kind`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum TransformKind {
+    /// A transformation that is not invertible (up to the given tolerance).
+    Singular,
+    /// An invertible transformation with a bottom-row different from `(0, 0 ... 0, 1)`.
+    Projective,
+    /// An invertible transformation with a bottom-row equal to `(0, 0 ... 0, 1)`.
+    Affine,
+    /// An affine transformation with an orthogonal linear part, i.e., a rotation (possibly
+    /// combined with a reflection) followed by a translation.
+    Isometry,
+}
+
 impl TCategory for TGeneral {
     #[inline]
     fn check_homogeneous_invariants<T: RealField, D: DimName>(_: &OMatrix<T, D, D>) -> bool
@@ -423,6 +438,12 @@ where
     #[inline]
     #[must_use = "Did you mean to use try_inverse_mut()?"]
     pub fn try_inverse(self) -> Option<Transform<T, C, D>> {
+        if self.is_affine(&T::zero()) {
+            return self
+                .affine_inverse(false)
+                .map(Transform::from_matrix_unchecked);
+        }
+
         self.matrix
             .try_inverse()
             .map(Transform::from_matrix_unchecked)
@@ -450,8 +471,7 @@ where
     where
         C: SubTCategoryOf<TProjective>,
     {
-        // TODO: specialize for TAffine?
-        Transform::from_matrix_unchecked(self.matrix.try_inverse().unwrap())
+        self.try_inverse().unwrap()
     }
 
     /// Attempts to invert this transformation in-place. You may use `.inverse_mut` instead of this
@@ -509,6 +529,218 @@ where
     }
 }
 
+/// # Runtime category checks and robust inversion
+impl<T: RealField, C: TCategory, const D: usize> Transform<T, C, D>
+where
+    Const<D>: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+{
+    /// Determines at runtime the most specific kind of transformation represented by this
+    /// transform, independently from its category `C`.
+    ///
+    /// The tolerance `eps` is used to compare the bottom-row of this transform to
+    /// `(0, 0 ... 0, 1)`, to check the orthogonality of its linear part, and to detect
+    /// singular matrices: a matrix `m` is considered singular if `m.amax() * inv.amax()`, where
+    /// `inv` is its inverse, is greater than `1 / eps`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use nalgebra::{Isometry3, Matrix4, Transform3, TransformKind, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    /// let t = Transform3::from_matrix_unchecked(iso.to_homogeneous());
+    /// assert_eq!(t.kind(1.0e-7), TransformKind::Isometry);
+    ///
+    /// let t = Transform3::from_matrix_unchecked(Matrix4::new_scaling(2.0));
+    /// assert_eq!(t.kind(1.0e-7), TransformKind::Affine);
+    ///
+    /// let t = Transform3::from_matrix_unchecked(Matrix4::new_perspective(1.0, 1.0, 0.1, 100.0));
+    /// assert_eq!(t.kind(1.0e-7), TransformKind::Projective);
+    ///
+    /// let scaling = Vector3::new(2.0, 1.0, 1.0e-10);
+    /// let t = Transform3::from_matrix_unchecked(Matrix4::new_nonuniform_scaling(&scaling));
+    /// assert_eq!(t.kind(1.0e-7), TransformKind::Singular);
+    /// ```
+    #[must_use]
+    pub fn kind(&self, eps: T) -> TransformKind {
+        if self.is_affine(&eps) {
+            let linear = self.matrix.fixed_slice::<D, D>(0, 0);
+
+            if (linear.transpose() * &linear).is_identity(eps.clone()) {
+                TransformKind::Isometry
+            } else if self.affine_inverse_eps(&eps).is_some() {
+                TransformKind::Affine
+            } else {
+                TransformKind::Singular
+            }
+        } else if Self::well_conditioned_inverse(self.matrix.clone(), &eps).is_some() {
+            TransformKind::Projective
+        } else {
+            TransformKind::Singular
+        }
+    }
+
+    /// Attempts to invert this transformation, considering it singular if it is ill-conditioned
+    /// up to the tolerance `eps`.
+    ///
+    /// See `.kind(eps)` for the meaning of `eps`. Transformations that are isometries or affine
+    /// transformations up to `eps` are inverted by specialized methods that are faster and more
+    /// accurate than a general matrix inversion. In these cases, the bottom-row of the result
+    /// is set exactly to `(0, 0 ... 0, 1)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Transform2};
+    /// let m = Matrix3::new(2.0, 2.0, -0.3,
+    ///                      3.0, 4.0, 0.1,
+    ///                      0.0, 0.0, 1.0);
+    /// let t = Transform2::from_matrix_unchecked(m);
+    /// let inv_t = t.try_inverse_eps(1.0e-7).unwrap();
+    /// assert_relative_eq!(t * inv_t, Transform2::identity());
+    ///
+    /// // Numerically singular case.
+    /// let m = Matrix3::new(1.0, 2.0, 0.0,
+    ///                      2.0, 4.0 + 1.0e-12, 0.0,
+    ///                      0.0, 0.0, 1.0);
+    /// let t = Transform2::from_matrix_unchecked(m);
+    /// assert!(t.try_inverse().is_some());
+    /// assert!(t.try_inverse_eps(1.0e-7).is_none());
+    /// ```
+    #[must_use]
+    pub fn try_inverse_eps(self, eps: T) -> Option<Transform<T, C, D>> {
+        let inv = match self.kind(eps.clone()) {
+            TransformKind::Singular => None,
+            TransformKind::Isometry => self.affine_inverse(true),
+            TransformKind::Affine => self.affine_inverse_eps(&eps),
+            TransformKind::Projective => Self::well_conditioned_inverse(self.matrix, &eps),
+        };
+
+        inv.map(Transform::from_matrix_unchecked)
+    }
+
+    /// Attempts to convert this transform into a projective transformation, checking at
+    /// runtime that it is invertible up to the tolerance `eps`.
+    ///
+    /// See `.kind(eps)` for the meaning of `eps`.
+    #[must_use]
+    pub fn try_into_projective(self, eps: T) -> Option<Transform<T, TProjective, D>> {
+        if self.kind(eps) == TransformKind::Singular {
+            None
+        } else {
+            Some(Transform::from_matrix_unchecked(self.matrix))
+        }
+    }
+
+    /// Attempts to convert this transform into an affine transformation, checking at runtime
+    /// that it is affine and invertible up to the tolerance `eps`.
+    ///
+    /// The matrix is first normalized by dividing it by its bottom-right element, so
+    /// homogeneous matrices that differ only by a scalar factor are treated alike. The
+    /// bottom-row of the result is then set exactly to `(0, 0 ... 0, 1)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use nalgebra::{Matrix3, Transform2};
+    /// let m = Matrix3::new(4.0, 2.0, 2.0,
+    ///                      0.0, 2.0, 4.0,
+    ///                      0.0, 1.0e-12, 2.0);
+    /// let t = Transform2::from_matrix_unchecked(m);
+    /// let affine = t.try_into_affine(1.0e-7).unwrap();
+    /// let expected = Matrix3::new(2.0, 1.0, 1.0,
+    ///                             0.0, 1.0, 2.0,
+    ///                             0.0, 0.0, 1.0);
+    /// assert_eq!(affine.into_inner(), expected);
+    /// ```
+    #[must_use]
+    pub fn try_into_affine(self, eps: T) -> Option<Transform<T, TAffine, D>> {
+        let mut matrix = self.matrix;
+        let w = matrix[(D, D)].clone();
+
+        if w.clone().abs() <= eps {
+            return None;
+        }
+
+        matrix /= w;
+
+        if (0..D).any(|i| matrix[(D, i)].clone().abs() > eps) {
+            return None;
+        }
+
+        matrix.fixed_slice_mut::<1, D>(D, 0).fill(T::zero());
+        matrix[(D, D)] = T::one();
+
+        let res = Transform::from_matrix_unchecked(matrix);
+        res.affine_inverse_eps(&eps).map(|_| res)
+    }
+
+    /// Checks if the bottom-row of this transform is equal to `(0, 0 ... 0, 1)`, up to `eps`.
+    fn is_affine(&self, eps: &T) -> bool {
+        (0..D).all(|i| self.matrix[(D, i)].clone().abs() <= *eps)
+            && (self.matrix[(D, D)].clone() - T::one()).abs() <= *eps
+    }
+
+    /// The inverse of `matrix`, if it is invertible and its condition number estimate is
+    /// smaller than `1 / eps`.
+    fn well_conditioned_inverse<R: Dim>(
+        matrix: OMatrix<T, R, R>,
+        eps: &T,
+    ) -> Option<OMatrix<T, R, R>>
+    where
+        DefaultAllocator: Allocator<T, R, R>,
+    {
+        let norm = matrix.amax();
+        let inv = matrix.try_inverse()?;
+
+        if norm * inv.amax() * eps.clone() < T::one() {
+            Some(inv)
+        } else {
+            None
+        }
+    }
+
+    /// The inverse of this transform, assuming its bottom-row is `(0, 0 ... 0, 1)` and that
+    /// its linear part is well-conditioned up to `eps`.
+    fn affine_inverse_eps(
+        &self,
+        eps: &T,
+    ) -> Option<OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>> {
+        let linear = self.matrix.fixed_slice::<D, D>(0, 0).into_owned();
+        let linear_inv = Self::well_conditioned_inverse::<Const<D>>(linear, eps)?;
+        Some(self.affine_inverse_with_linear_inverse(linear_inv))
+    }
+
+    /// The inverse of this transform, assuming its bottom-row is `(0, 0 ... 0, 1)`.
+    ///
+    /// If `orthogonal` is `true`, the linear part of this transform is assumed to be orthogonal
+    /// and is inverted by transposition.
+    fn affine_inverse(
+        &self,
+        orthogonal: bool,
+    ) -> Option<OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>> {
+        let linear = self.matrix.fixed_slice::<D, D>(0, 0);
+        let linear_inv = if orthogonal {
+            linear.transpose()
+        } else {
+            linear.into_owned().try_inverse()?
+        };
+
+        Some(self.affine_inverse_with_linear_inverse(linear_inv))
+    }
+
+    fn affine_inverse_with_linear_inverse(
+        &self,
+        linear_inv: SMatrix<T, D, D>,
+    ) -> OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>> {
+        let translation = self.matrix.fixed_slice::<D, 1>(0, D);
+        let translation_inv = -(&linear_inv * translation);
+        let mut res = OMatrix::<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>::identity();
+        res.fixed_slice_mut::<D, D>(0, 0).copy_from(&linear_inv);
+        res.fixed_slice_mut::<D, 1>(0, D)
+            .copy_from(&translation_inv);
+        res
+    }
+}
+
 impl<T, C, const D: usize> Transform<T, C, D>
 where
     T: RealField,
@@ -536,6 +768,76 @@ where
     pub fn transform_vector(&self, v: &SVector<T, D>) -> SVector<T, D> {
         self * v
     }
+
+    /// Transforms in-place each point of the given slice by this transformation.
+    ///
+    /// The linear part, translation and (for projective transformations) normalizer are
+    /// extracted only once so that each point is then transformed by a small matrix-vector
+    /// product. The result is the same as calling `transform_point` on each point.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix4, Point3, Projective3};
+    /// let m = Matrix4::new_perspective(1.0, 0.8, 0.1, 100.0);
+    /// let proj = Projective3::from_matrix_unchecked(m);
+    /// let mut points = [Point3::new(1.0, 2.0, -3.0), Point3::new(0.5, -1.0, -10.0)];
+    /// let expected = [proj.transform_point(&points[0]), proj.transform_point(&points[1])];
+    /// proj.transform_points(&mut points);
+    ///
+    /// assert_relative_eq!(points[0], expected[0], epsilon = 1.0e-7);
+    /// assert_relative_eq!(points[1], expected[1], epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn transform_points(&self, points: &mut [Point<T, D>]) {
+        let transform: SMatrix<T, D, D> = self.matrix().fixed_slice::<D, D>(0, 0).into_owned();
+        let translation: SVector<T, D> = self.matrix().fixed_slice::<D, 1>(0, D).into_owned();
+
+        if C::has_normalizer() {
+            let normalizer: SVector<T, D> = self.matrix().fixed_slice::<1, D>(D, 0).transpose();
+            let w = self.matrix()[(D, D)].clone();
+
+            for pt in points {
+                let n = normalizer.dot(&pt.coords) + w.clone();
+                pt.coords = &transform * &pt.coords + &translation;
+
+                if !n.is_zero() {
+                    pt.coords /= n;
+                }
+            }
+        } else {
+            for pt in points {
+                pt.coords = &transform * &pt.coords + &translation;
+            }
+        }
+    }
+
+    /// Transforms in-place each vector of the given slice by this transformation, ignoring the
+    /// translational component of the transformation.
+    ///
+    /// The result is the same as calling `transform_vector` on each vector.
+    #[inline]
+    pub fn transform_vectors(&self, vectors: &mut [SVector<T, D>]) {
+        let transform: SMatrix<T, D, D> = self.matrix().fixed_slice::<D, D>(0, 0).into_owned();
+
+        if C::has_normalizer() {
+            let normalizer: SVector<T, D> = self.matrix().fixed_slice::<1, D>(D, 0).transpose();
+
+            for v in vectors {
+                let n = normalizer.dot(v);
+
+                if !n.is_zero() {
+                    *v /= n;
+                }
+
+                *v = &transform * &*v;
+            }
+        } else {
+            for v in vectors {
+                *v = &transform * &*v;
+            }
+        }
+    }
 }
 
 impl<T: RealField, C: TCategory, const D: usize> Transform<T, C, D>
@@ -565,6 +867,26 @@ where
     }
 }
 
+impl<T: RealField, C: TCategory> Transform<T, C, 3> {
+    /// Decomposes this transformation into a translation, a rotation, a non-uniform scale, and
+    /// an optional shear.
+    ///
+    /// See [`Matrix4::decompose_trs`](crate::Matrix4::decompose_trs) for details. Returns `None`
+    /// if this transformation is projective or if its linear part is not invertible.
+    #[inline]
+    #[must_use]
+    pub fn decompose(
+        &self,
+    ) -> Option<(
+        Translation3<T>,
+        UnitQuaternion<T>,
+        Vector3<T>,
+        Option<Shear3<T>>,
+    )> {
+        self.matrix().decompose_trs()
+    }
+}
+
 impl<T: RealField, const D: usize> Transform<T, TGeneral, D>
 where
     Const<D>: DimNameAdd<U1>,
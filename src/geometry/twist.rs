@@ -0,0 +1,279 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::RealField;
+
+use crate::base::{Scalar, Vector3, Vector6};
+use crate::geometry::Isometry3;
+
+/// A spatial velocity, composed of a linear velocity and an angular velocity.
+///
+/// A twist is an element of the Lie algebra se(3). When converted to a 6D vector, its linear part
+/// comes first, followed by its angular part. This is the same convention as the one used by
+/// `Isometry3::exp` and `Isometry3::adjoint`.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: RealField + Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: RealField + Deserialize<'de>"))
+)]
+pub struct Twist<T> {
+    /// The linear velocity part of this twist.
+    pub linear: Vector3<T>,
+    /// The angular velocity part of this twist.
+    pub angular: Vector3<T>,
+}
+
+/// A spatial force, composed of a force and a torque.
+///
+/// A wrench is the dual of a twist: the dot product of a wrench with a twist expressed in the
+/// same frame is a power. When converted to a 6D vector, its force part comes first, followed by
+/// its torque part.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "T: RealField + Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "T: RealField + Deserialize<'de>"))
+)]
+pub struct Wrench<T> {
+    /// The force part of this wrench.
+    pub force: Vector3<T>,
+    /// The torque part of this wrench.
+    pub torque: Vector3<T>,
+}
+
+impl<T: Scalar + Copy> Copy for Twist<T> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Twist<T>
+where
+    T: Scalar + bytemuck::Zeroable,
+    Vector3<T>: bytemuck::Zeroable,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Twist<T>
+where
+    T: Scalar + bytemuck::Pod,
+    Vector3<T>: bytemuck::Pod,
+{
+}
+
+impl<T: Scalar> Clone for Twist<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            linear: self.linear.clone(),
+            angular: self.angular.clone(),
+        }
+    }
+}
+
+impl<T: Scalar + Eq> Eq for Twist<T> {}
+
+impl<T: Scalar> PartialEq for Twist<T> {
+    #[inline]
+    fn eq(&self, right: &Self) -> bool {
+        self.linear == right.linear && self.angular == right.angular
+    }
+}
+
+impl<T: Scalar + Copy> Copy for Wrench<T> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Wrench<T>
+where
+    T: Scalar + bytemuck::Zeroable,
+    Vector3<T>: bytemuck::Zeroable,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Wrench<T>
+where
+    T: Scalar + bytemuck::Pod,
+    Vector3<T>: bytemuck::Pod,
+{
+}
+
+impl<T: Scalar> Clone for Wrench<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            force: self.force.clone(),
+            torque: self.torque.clone(),
+        }
+    }
+}
+
+impl<T: Scalar + Eq> Eq for Wrench<T> {}
+
+impl<T: Scalar> PartialEq for Wrench<T> {
+    #[inline]
+    fn eq(&self, right: &Self) -> bool {
+        self.force == right.force && self.torque == right.torque
+    }
+}
+
+impl<T: RealField> Twist<T> {
+    /// Creates a new twist from its linear and angular parts.
+    #[inline]
+    pub fn new(linear: Vector3<T>, angular: Vector3<T>) -> Self {
+        Self { linear, angular }
+    }
+
+    /// The twist with zero linear and angular velocities.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(Vector3::zeros(), Vector3::zeros())
+    }
+
+    /// Creates a new twist from a 6D vector `[linear, angular]`.
+    #[inline]
+    pub fn from_vector(v: &Vector6<T>) -> Self {
+        Self::new(
+            v.fixed_rows::<3>(0).into_owned(),
+            v.fixed_rows::<3>(3).into_owned(),
+        )
+    }
+
+    /// This twist as a 6D vector `[linear, angular]`.
+    #[inline]
+    #[must_use]
+    pub fn to_vector(&self) -> Vector6<T> {
+        let mut res = Vector6::zeros();
+        res.fixed_rows_mut::<3>(0).copy_from(&self.linear);
+        res.fixed_rows_mut::<3>(3).copy_from(&self.angular);
+        res
+    }
+
+    /// Expresses in the frame `A` this twist given in the frame `B`, where `iso` maps the frame
+    /// `B` to the frame `A`.
+    ///
+    /// This is the same as multiplying `self.to_vector()` by `iso.adjoint()`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Twist, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    /// let twist = Twist::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    /// let transformed = twist.transform_by(&iso);
+    /// let expected = iso.adjoint() * twist.to_vector();
+    /// assert_relative_eq!(transformed.to_vector(), expected, epsilon = 1.0e-7);
+    /// let back = transformed.inverse_transform_by(&iso);
+    /// assert_relative_eq!(back.to_vector(), twist.to_vector(), epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn transform_by(&self, iso: &Isometry3<T>) -> Self {
+        let angular = &iso.rotation * &self.angular;
+        let linear = &iso.rotation * &self.linear + iso.translation.vector.cross(&angular);
+        Self::new(linear, angular)
+    }
+
+    /// Expresses in the frame `B` this twist given in the frame `A`, where `iso` maps the frame
+    /// `B` to the frame `A`.
+    #[must_use]
+    pub fn inverse_transform_by(&self, iso: &Isometry3<T>) -> Self {
+        let linear = self.linear.clone() - iso.translation.vector.cross(&self.angular);
+        Self::new(
+            iso.rotation.inverse_transform_vector(&linear),
+            iso.rotation.inverse_transform_vector(&self.angular),
+        )
+    }
+
+    /// The isometry obtained by following this twist during a unit of time.
+    ///
+    /// This is the same as `Isometry3::exp(&self.to_vector())`.
+    #[inline]
+    #[must_use]
+    pub fn exp(&self) -> Isometry3<T> {
+        Isometry3::exp(&self.to_vector())
+    }
+
+    /// The spatial cross product of this twist with `other`.
+    ///
+    /// This is the derivative of `other` when it is moving with the velocity `self`.
+    #[must_use]
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.angular.cross(&other.linear) + self.linear.cross(&other.angular),
+            self.angular.cross(&other.angular),
+        )
+    }
+
+    /// The power developed by `wrench` on a rigid body moving with the velocity `self`.
+    ///
+    /// Both `self` and `wrench` must be expressed in the same frame.
+    #[inline]
+    #[must_use]
+    pub fn power(&self, wrench: &Wrench<T>) -> T {
+        self.linear.dot(&wrench.force) + self.angular.dot(&wrench.torque)
+    }
+}
+
+impl<T: RealField> Wrench<T> {
+    /// Creates a new wrench from its force and torque parts.
+    #[inline]
+    pub fn new(force: Vector3<T>, torque: Vector3<T>) -> Self {
+        Self { force, torque }
+    }
+
+    /// The wrench with zero force and torque.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(Vector3::zeros(), Vector3::zeros())
+    }
+
+    /// Creates a new wrench from a 6D vector `[force, torque]`.
+    #[inline]
+    pub fn from_vector(v: &Vector6<T>) -> Self {
+        Self::new(
+            v.fixed_rows::<3>(0).into_owned(),
+            v.fixed_rows::<3>(3).into_owned(),
+        )
+    }
+
+    /// This wrench as a 6D vector `[force, torque]`.
+    #[inline]
+    #[must_use]
+    pub fn to_vector(&self) -> Vector6<T> {
+        let mut res = Vector6::zeros();
+        res.fixed_rows_mut::<3>(0).copy_from(&self.force);
+        res.fixed_rows_mut::<3>(3).copy_from(&self.torque);
+        res
+    }
+
+    /// Expresses in the frame `A` this wrench given in the frame `B`, where `iso` maps the frame
+    /// `B` to the frame `A`.
+    ///
+    /// This preserves the power developed on twists transformed by `Twist::transform_by`.
+    #[must_use]
+    pub fn transform_by(&self, iso: &Isometry3<T>) -> Self {
+        let force = &iso.rotation * &self.force;
+        let torque = &iso.rotation * &self.torque + iso.translation.vector.cross(&force);
+        Self::new(force, torque)
+    }
+
+    /// Expresses in the frame `B` this wrench given in the frame `A`, where `iso` maps the frame
+    /// `B` to the frame `A`.
+    #[must_use]
+    pub fn inverse_transform_by(&self, iso: &Isometry3<T>) -> Self {
+        let torque = self.torque.clone() - iso.translation.vector.cross(&self.force);
+        Self::new(
+            iso.rotation.inverse_transform_vector(&self.force),
+            iso.rotation.inverse_transform_vector(&torque),
+        )
+    }
+}
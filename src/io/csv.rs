@@ -0,0 +1,342 @@
+//! Reading and writing of dense matrices as CSV (comma-separated values) data.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+use crate::base::dimension::{Dim, Dynamic};
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, Matrix, Scalar};
+
+/// How missing values, i.e., empty fields, are handled when reading CSV data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MissingValues {
+    /// Missing values result in an error.
+    Error,
+    /// Missing values are replaced by NaN. This requires the scalar type to parse `"NaN"`
+    /// successfully, which is the case of `f32` and `f64`.
+    Nan,
+}
+
+/// Options for reading and writing CSV data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CsvOptions {
+    delimiter: char,
+    has_header: bool,
+    missing_values: MissingValues,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            has_header: false,
+            missing_values: MissingValues::Error,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// The default options: values separated by commas, no header, and missing values are errors.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the character separating the values of a row.
+    #[inline]
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the first line of the data is a header containing the name of each column.
+    #[inline]
+    #[must_use]
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Sets how missing values are handled.
+    #[inline]
+    #[must_use]
+    pub fn missing_values(mut self, missing_values: MissingValues) -> Self {
+        self.missing_values = missing_values;
+        self
+    }
+}
+
+/// An error that occurred while reading or writing CSV data.
+///
+/// Line and column numbers are 1-based.
+#[derive(Debug)]
+pub enum CsvError {
+    /// An error of the underlying reader or writer.
+    Io(io::Error),
+    /// A field could not be parsed as a value of the requested scalar type.
+    Parse {
+        /// The line of the field.
+        line: usize,
+        /// The column of the field.
+        column: usize,
+        /// The content of the field.
+        field: String,
+    },
+    /// A field is empty and missing values are not allowed.
+    MissingValue {
+        /// The line of the field.
+        line: usize,
+        /// The column of the field.
+        column: usize,
+    },
+    /// A row does not have the same number of fields as the previous ones.
+    RaggedRow {
+        /// The line of the row.
+        line: usize,
+        /// The number of fields of the previous rows.
+        expected: usize,
+        /// The number of fields of this row.
+        found: usize,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(e) => write!(f, "I/O error: {}", e),
+            CsvError::Parse {
+                line,
+                column,
+                field,
+            } => write!(
+                f,
+                "line {}, column {}: invalid value \"{}\"",
+                line, column, field
+            ),
+            CsvError::MissingValue { line, column } => {
+                write!(f, "line {}, column {}: missing value", line, column)
+            }
+            CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} fields, found {}",
+                line, expected, found
+            ),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> Self {
+        CsvError::Io(e)
+    }
+}
+
+/// Splits a line into its fields, removing surrounding whitespaces and quotes.
+fn split_fields(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut in_quotes = false;
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    // Escaped quote.
+                    field.push('"');
+                    let _ = chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' && field.trim().is_empty() {
+            field.clear();
+            in_quotes = true;
+        } else if c == delimiter {
+            fields.push(field.trim().to_string());
+            field.clear();
+        } else {
+            field.push(c);
+        }
+    }
+
+    fields.push(field.trim().to_string());
+    fields
+}
+
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl<T: Scalar + FromStr> DMatrix<T> {
+    /// Reads a matrix from CSV data, where each line is a row of the matrix.
+    ///
+    /// If the data has a header, it is ignored. Use `DMatrix::from_csv_with_header` to retrieve
+    /// the column names. Empty lines are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra::io::{CsvOptions, MissingValues};
+    /// let data = "x;y;z\n1.0;2.0;3.0\n4.0;;6.0\n";
+    /// let options = CsvOptions::new()
+    ///     .delimiter(';')
+    ///     .has_header(true)
+    ///     .missing_values(MissingValues::Nan);
+    /// let m = DMatrix::<f64>::from_csv(data.as_bytes(), &options).unwrap();
+    ///
+    /// assert_eq!(m.shape(), (2, 3));
+    /// assert_eq!(m[(1, 2)], 6.0);
+    /// assert!(m[(1, 1)].is_nan());
+    /// ```
+    pub fn from_csv<R: Read>(reader: R, options: &CsvOptions) -> Result<Self, CsvError> {
+        Self::from_csv_with_header(reader, options).map(|(_, m)| m)
+    }
+
+    /// Reads a matrix from CSV data, and returns it with the column names read from the header.
+    ///
+    /// If `options` indicates that the data has no header, the returned column names are empty.
+    pub fn from_csv_with_header<R: Read>(
+        reader: R,
+        options: &CsvOptions,
+    ) -> Result<(Vec<String>, Self), CsvError> {
+        let mut header = Vec::new();
+        let mut data = Vec::new();
+        let mut ncols = None;
+        let mut nrows = 0;
+
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line_number = i + 1;
+
+            if options.has_header && i == 0 {
+                header = split_fields(&line, options.delimiter);
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields = split_fields(&line, options.delimiter);
+
+            match ncols {
+                Some(n) if n != fields.len() => {
+                    return Err(CsvError::RaggedRow {
+                        line: line_number,
+                        expected: n,
+                        found: fields.len(),
+                    })
+                }
+                _ => ncols = Some(fields.len()),
+            }
+
+            for (j, field) in fields.iter().enumerate() {
+                let value = if field.is_empty() {
+                    match options.missing_values {
+                        MissingValues::Error => None,
+                        MissingValues::Nan => T::from_str("NaN").ok(),
+                    }
+                    .ok_or(CsvError::MissingValue {
+                        line: line_number,
+                        column: j + 1,
+                    })?
+                } else {
+                    T::from_str(field).map_err(|_| CsvError::Parse {
+                        line: line_number,
+                        column: j + 1,
+                        field: field.clone(),
+                    })?
+                };
+
+                data.push(value);
+            }
+
+            nrows += 1;
+        }
+
+        let ncols = ncols.unwrap_or_else(|| header.len());
+        let matrix = DMatrix::from_vec_generic(Dynamic::new(ncols), Dynamic::new(nrows), data);
+
+        // The data was read in row-major order.
+        Ok((header, matrix.transpose()))
+    }
+}
+
+impl<T: Scalar + fmt::Display, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// Writes this matrix as CSV data, with values separated by commas and without header.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// let mut csv = Vec::new();
+    /// m.write_csv(&mut csv).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "1,2,3\n4,5,6\n");
+    /// ```
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<(), CsvError> {
+        self.write_csv_with_header(writer, &CsvOptions::default(), &[])
+    }
+
+    /// Writes this matrix as CSV data, with the given options and column names.
+    ///
+    /// The header is written only if `options` indicates that the data has a header, in which
+    /// case `header` must contain the name of each column.
+    ///
+    /// # Panics
+    /// Panics if the data has a header and the number of column names differs from the number
+    /// of columns of this matrix.
+    pub fn write_csv_with_header<W: Write>(
+        &self,
+        writer: W,
+        options: &CsvOptions,
+        header: &[&str],
+    ) -> Result<(), CsvError> {
+        let mut writer = io::BufWriter::new(writer);
+        let delimiter = options.delimiter.to_string();
+
+        if options.has_header {
+            assert_eq!(
+                header.len(),
+                self.ncols(),
+                "CSV header: the number of column names must match the number of columns."
+            );
+            let names: Vec<_> = header
+                .iter()
+                .map(|name| quote_field(name, options.delimiter))
+                .collect();
+            writeln!(writer, "{}", names.join(&delimiter))?;
+        }
+
+        for row in self.row_iter() {
+            let values: Vec<_> = row.iter().map(|value| value.to_string()).collect();
+            writeln!(writer, "{}", values.join(&delimiter))?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+}
@@ -1,5 +1,12 @@
 //! Parsers for various matrix formats.
 
+pub use self::csv::{CsvError, CsvOptions, MissingValues};
 pub use self::matrix_market::{cs_matrix_from_matrix_market, cs_matrix_from_matrix_market_str};
+pub use self::npy::{
+    read_npy, read_npy_vector, read_npz, write_npy, write_npy_vector, write_npz, NpyElement,
+    NpyError,
+};
 
+mod csv;
 mod matrix_market;
+mod npy;
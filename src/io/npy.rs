@@ -0,0 +1,668 @@
+//! Reading and writing of NumPy `.npy` and `.npz` files.
+//!
+//! Arrays with up to two dimensions are supported: one-dimensional arrays are read as column
+//! vectors, and two-dimensional arrays in either C or Fortran order are read as matrices. Data
+//! may be stored in either endianness, but its dtype must match exactly the scalar type
+//! requested, e.g., `<f8` for `f64` or `<i4` for `i32`.
+//!
+//! Only uncompressed `.npz` archives, as written by `numpy.savez`, are supported. Archives
+//! written by `numpy.savez_compressed` result in an error.
+
+use std::convert::{TryFrom, TryInto};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use num_complex::Complex;
+
+use crate::base::dimension::{Const, Dim, Dynamic, U1};
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Matrix, Scalar};
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+/// The maximum length of the headers read. The headers of the supported arrays are much shorter,
+/// but the versions 2.0 and 3.0 of the format store this length on 4 bytes.
+const NPY_MAX_HEADER_LEN: usize = u16::MAX as usize;
+
+/// An error that occurred while reading or writing a `.npy` or `.npz` file.
+#[derive(Debug)]
+pub enum NpyError {
+    /// An error of the underlying reader or writer.
+    Io(io::Error),
+    /// The data does not follow the `.npy` or `.npz` format, or uses features of these formats
+    /// that are not supported (e.g. arrays with more than two dimensions).
+    InvalidFormat(String),
+    /// The dtype of the stored array does not match the requested scalar type.
+    DTypeMismatch {
+        /// The dtype expected for the requested scalar type, e.g., `<f8`.
+        expected: String,
+        /// The dtype of the stored array.
+        found: String,
+    },
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NpyError::Io(e) => write!(f, "I/O error: {}", e),
+            NpyError::InvalidFormat(msg) => write!(f, "invalid npy/npz data: {}", msg),
+            NpyError::DTypeMismatch { expected, found } => {
+                write!(f, "dtype mismatch: expected {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for NpyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NpyError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(e: io::Error) -> Self {
+        NpyError::Io(e)
+    }
+}
+
+fn invalid<T>(msg: impl Into<String>) -> Result<T, NpyError> {
+    Err(NpyError::InvalidFormat(msg.into()))
+}
+
+/// A scalar type that can be stored in a `.npy` file.
+pub trait NpyElement: Scalar + Copy {
+    /// The kind of the NumPy dtype: `f` for floats, `i` for signed integers, `u` for unsigned
+    /// integers, and `c` for complex numbers.
+    const KIND: char;
+    /// The size in bytes of one element.
+    const SIZE: usize;
+
+    /// Reads an element from exactly `Self::SIZE` bytes.
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self;
+
+    /// Appends the little-endian representation of this element to `out`.
+    fn write_le_bytes(&self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element(
+    ($($T: ty, $kind: expr);* $(;)*) => {$(
+        impl NpyElement for $T {
+            const KIND: char = $kind;
+            const SIZE: usize = std::mem::size_of::<$T>();
+
+            #[inline]
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let bytes = bytes.try_into().unwrap();
+
+                if big_endian {
+                    <$T>::from_be_bytes(bytes)
+                } else {
+                    <$T>::from_le_bytes(bytes)
+                }
+            }
+
+            #[inline]
+            fn write_le_bytes(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes())
+            }
+        }
+    )*}
+);
+
+impl_npy_element!(
+    f32, 'f'; f64, 'f';
+    i8, 'i'; i16, 'i'; i32, 'i'; i64, 'i';
+    u8, 'u'; u16, 'u'; u32, 'u'; u64, 'u';
+);
+
+// NOTE: only complex floats have a NumPy dtype, i.e., `<c8` and `<c16`.
+macro_rules! impl_npy_complex(
+    ($($T: ty);* $(;)*) => {$(
+        impl NpyElement for Complex<$T> {
+            const KIND: char = 'c';
+            const SIZE: usize = 2 * <$T>::SIZE;
+
+            #[inline]
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let (re, im) = bytes.split_at(<$T>::SIZE);
+                Complex::new(<$T>::from_bytes(re, big_endian), <$T>::from_bytes(im, big_endian))
+            }
+
+            #[inline]
+            fn write_le_bytes(&self, out: &mut Vec<u8>) {
+                self.re.write_le_bytes(out);
+                self.im.write_le_bytes(out);
+            }
+        }
+    )*}
+);
+
+impl_npy_complex!(f32; f64);
+
+fn descr<T: NpyElement>() -> String {
+    let order = if T::SIZE == 1 { '|' } else { '<' };
+    format!("{}{}{}", order, T::KIND, T::SIZE)
+}
+
+/*
+ *
+ * .npy format.
+ *
+ */
+struct NpyHeader {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+/// Extracts the textual value associated to `key` in the Python dict literal of the header.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let quoted = [format!("'{}'", key), format!("\"{}\"", key)];
+    let start = quoted
+        .iter()
+        .find_map(|k| header.find(k.as_str()).map(|i| i + k.len()))
+        .ok_or_else(|| NpyError::InvalidFormat(format!("missing key '{}' in header", key)))?;
+    let value = header[start..].trim_start();
+
+    match value.strip_prefix(':') {
+        Some(value) => Ok(value.trim_start()),
+        None => invalid(format!("malformed entry '{}' in header", key)),
+    }
+}
+
+fn parse_header(header: &str) -> Result<NpyHeader, NpyError> {
+    let descr = header_value(header, "descr")?;
+    let quote = descr.chars().next().filter(|c| *c == '\'' || *c == '"');
+    let descr = match quote.and_then(|q| descr[1..].find(q).map(|end| &descr[1..end + 1])) {
+        Some(descr) => descr.to_string(),
+        None => return invalid("the dtype must be a simple type string"),
+    };
+
+    let fortran_order = header_value(header, "fortran_order")?;
+    let fortran_order = if fortran_order.starts_with("True") {
+        true
+    } else if fortran_order.starts_with("False") {
+        false
+    } else {
+        return invalid("invalid value for 'fortran_order'");
+    };
+
+    let shape = header_value(header, "shape")?;
+    let shape = match shape
+        .strip_prefix('(')
+        .and_then(|s| s.find(')').map(|e| &s[..e]))
+    {
+        Some(shape) => shape,
+        None => return invalid("invalid value for 'shape'"),
+    };
+    let shape = shape
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.trim_end_matches('L').parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .or_else(|_| invalid("invalid value for 'shape'"))?;
+
+    Ok(NpyHeader {
+        descr,
+        fortran_order,
+        shape,
+    })
+}
+
+fn read_header<R: Read>(reader: &mut R) -> Result<NpyHeader, NpyError> {
+    let mut preamble = [0; 8];
+    reader.read_exact(&mut preamble)?;
+
+    if &preamble[..6] != NPY_MAGIC {
+        return invalid("missing npy magic string");
+    }
+
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        v => return invalid(format!("unsupported npy format version {}", v)),
+    };
+
+    if header_len > NPY_MAX_HEADER_LEN {
+        return invalid("the header is too large");
+    }
+
+    let mut header = vec![0; header_len];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8(header).or_else(|_| invalid("the header is not valid UTF-8"))?;
+
+    parse_header(&header)
+}
+
+/// Reads the dtype and data of an array, and returns it as a matrix.
+fn read_array<T: NpyElement, R: Read>(reader: &mut R) -> Result<(DMatrix<T>, usize), NpyError> {
+    let header = read_header(reader)?;
+    let expected = descr::<T>();
+    let mut chars = header.descr.chars();
+    let big_endian = match chars.next() {
+        Some('>') => true,
+        Some('<') | Some('|') => false,
+        Some('=') => cfg!(target_endian = "big"),
+        _ => return invalid(format!("invalid dtype {}", header.descr)),
+    };
+
+    if chars.as_str() != &expected[1..] {
+        return Err(NpyError::DTypeMismatch {
+            expected,
+            found: header.descr,
+        });
+    }
+
+    let (nrows, ncols) = match header.shape[..] {
+        [] => (1, 1),
+        [n] => (n, 1),
+        [r, c] => (r, c),
+        _ => {
+            return invalid(format!(
+                "arrays with {} dimensions are not supported",
+                header.shape.len()
+            ))
+        }
+    };
+
+    let len = nrows
+        .checked_mul(ncols)
+        .and_then(|len| len.checked_mul(T::SIZE))
+        .ok_or_else(|| NpyError::InvalidFormat("the array is too large".to_string()))?;
+    // The shape comes from untrusted data: only allocate the bytes actually provided by the
+    // reader, instead of trusting `len`.
+    let mut bytes = Vec::new();
+    let _ = reader.by_ref().take(len as u64).read_to_end(&mut bytes)?;
+
+    if bytes.len() != len {
+        return invalid("truncated array data");
+    }
+
+    let data: Vec<T> = bytes
+        .chunks_exact(T::SIZE)
+        .map(|elt| T::from_bytes(elt, big_endian))
+        .collect();
+
+    let matrix = if header.fortran_order {
+        DMatrix::from_vec(nrows, ncols, data)
+    } else {
+        DMatrix::from_row_slice(nrows, ncols, &data)
+    };
+
+    Ok((matrix, header.shape.len()))
+}
+
+fn write_array<T, R, C, S, W>(
+    matrix: &Matrix<T, R, C, S>,
+    shape: &str,
+    writer: &mut W,
+) -> Result<(), NpyError>
+where
+    T: NpyElement,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C>,
+    W: Write,
+{
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': True, 'shape': {}, }}",
+        descr::<T>(),
+        shape
+    );
+    // The total length of the preamble and header must be a multiple of 64, and the header must
+    // end with a newline.
+    let preamble_len = NPY_MAGIC.len() + 4;
+    let padding = 63 - (preamble_len + header.len()) % 64;
+    header.extend(std::iter::repeat(' ').take(padding));
+    header.push('\n');
+
+    if header.len() > u16::MAX as usize {
+        return invalid("the array header is too large");
+    }
+
+    let mut bytes = Vec::with_capacity(preamble_len + header.len() + matrix.len() * T::SIZE);
+    bytes.extend_from_slice(NPY_MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+
+    // Matrix iterators yield the elements in column-major order, i.e., Fortran order.
+    for elt in matrix.iter() {
+        elt.write_le_bytes(&mut bytes);
+    }
+
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Reads a matrix from the given `.npy` data.
+///
+/// One-dimensional arrays are read as column vectors. Fails if the array has more than two
+/// dimensions or if its dtype does not match `T`.
+///
+/// # Example
+/// ```
+/// # use nalgebra::DMatrix;
+/// # use nalgebra::io::{read_npy, write_npy};
+/// let m = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// let mut bytes = Vec::new();
+/// write_npy(&m, &mut bytes).unwrap();
+///
+/// let m2: DMatrix<f64> = read_npy(&bytes[..]).unwrap();
+/// assert_eq!(m, m2);
+/// ```
+pub fn read_npy<T: NpyElement, R: Read>(mut reader: R) -> Result<DMatrix<T>, NpyError> {
+    read_array(&mut reader).map(|(matrix, _)| matrix)
+}
+
+/// Reads a vector from the given `.npy` data.
+///
+/// Fails unless the array is one-dimensional, or two-dimensional with a single row or column.
+pub fn read_npy_vector<T: NpyElement, R: Read>(reader: R) -> Result<DVector<T>, NpyError> {
+    let matrix = read_npy(reader)?;
+
+    if matrix.ncols() == 1 || matrix.nrows() == 1 {
+        let len = matrix.len();
+        Ok(matrix.reshape_generic(Dynamic::new(len), Const::<1>))
+    } else {
+        invalid("the array is not a vector")
+    }
+}
+
+/// Writes the given matrix as a two-dimensional array in `.npy` format.
+///
+/// The data is written in Fortran order, which matches the column-major storage of matrices.
+pub fn write_npy<T, R, C, S, W>(matrix: &Matrix<T, R, C, S>, mut writer: W) -> Result<(), NpyError>
+where
+    T: NpyElement,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C>,
+    W: Write,
+{
+    let shape = format!("({}, {})", matrix.nrows(), matrix.ncols());
+    write_array(matrix, &shape, &mut writer)
+}
+
+/// Writes the given vector as a one-dimensional array in `.npy` format.
+pub fn write_npy_vector<T, R, S, W>(
+    vector: &Matrix<T, R, U1, S>,
+    mut writer: W,
+) -> Result<(), NpyError>
+where
+    T: NpyElement,
+    R: Dim,
+    S: Storage<T, R>,
+    W: Write,
+{
+    let shape = format!("({},)", vector.nrows());
+    write_array(vector, &shape, &mut writer)
+}
+
+/*
+ *
+ * .npz format (uncompressed zip archives).
+ *
+ */
+const ZIP_LOCAL_HEADER: u32 = 0x0403_4b50;
+const ZIP_CENTRAL_HEADER: u32 = 0x0201_4b50;
+const ZIP_END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+// Date of the archive entries: 1980-01-01, the earliest date representable in a zip file.
+const ZIP_DATE: u16 = (1 << 5) | 1;
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+/// The `len` bytes of `data` starting at `offset`, where both come from untrusted data.
+fn zip_bytes(data: &[u8], offset: usize, len: usize) -> Result<&[u8], NpyError> {
+    match offset.checked_add(len) {
+        Some(end) if end <= data.len() => Ok(&data[offset..end]),
+        _ => invalid("truncated zip archive"),
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, NpyError> {
+    let bytes = zip_bytes(data, offset, 2)?;
+    Ok(u16::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, NpyError> {
+    let bytes = zip_bytes(data, offset, 4)?;
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, NpyError> {
+    let bytes = zip_bytes(data, offset, 8)?;
+    Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// Lists the name and stored data of all the entries of a zip archive.
+fn zip_entries(archive: &[u8]) -> Result<Vec<(String, &[u8])>, NpyError> {
+    // The end of central directory record is at least 22 bytes long and may be followed by a
+    // comment of up to 65535 bytes.
+    let eocd = (0..=archive.len().saturating_sub(22))
+        .rev()
+        .take(22 + u16::MAX as usize)
+        .find(|i| read_u32(archive, *i).ok() == Some(ZIP_END_OF_CENTRAL_DIRECTORY))
+        .ok_or_else(|| NpyError::InvalidFormat("not a zip archive".to_string()))?;
+
+    let num_entries = read_u16(archive, eocd + 10)? as usize;
+    let mut offset = read_u32(archive, eocd + 16)? as usize;
+    let mut entries = Vec::with_capacity(num_entries);
+
+    for _ in 0..num_entries {
+        if read_u32(archive, offset)? != ZIP_CENTRAL_HEADER {
+            return invalid("corrupted zip central directory");
+        }
+
+        let method = read_u16(archive, offset + 10)?;
+        let crc = read_u32(archive, offset + 16)?;
+        let mut compressed_size = read_u32(archive, offset + 20)? as u64;
+        let mut uncompressed_size = read_u32(archive, offset + 24)? as u64;
+        let name_len = read_u16(archive, offset + 28)? as usize;
+        let extra_len = read_u16(archive, offset + 30)? as usize;
+        let comment_len = read_u16(archive, offset + 32)? as usize;
+        let mut local_offset = read_u32(archive, offset + 42)? as u64;
+        let name = zip_bytes(archive, offset + 46, name_len)?;
+        let name = String::from_utf8_lossy(name).into_owned();
+
+        // Entries written by `numpy.savez` use the zip64 extension, in which case the sizes and
+        // offset are stored in an extra field.
+        let extra_start = offset + 46 + name_len;
+        let mut extra = extra_start;
+
+        while extra + 4 <= extra_start + extra_len {
+            let id = read_u16(archive, extra)?;
+            let len = read_u16(archive, extra + 2)? as usize;
+
+            if id == 0x0001 {
+                let mut field = extra + 4;
+
+                for value in [
+                    &mut uncompressed_size,
+                    &mut compressed_size,
+                    &mut local_offset,
+                ] {
+                    if *value == u32::MAX as u64 {
+                        *value = read_u64(archive, field)?;
+                        field += 8;
+                    }
+                }
+            }
+
+            extra += 4 + len;
+        }
+
+        if method != 0 {
+            return invalid(format!(
+                "entry '{}' is compressed, which is not supported",
+                name
+            ));
+        }
+
+        let local_offset =
+            usize::try_from(local_offset).or_else(|_| invalid("truncated zip archive"))?;
+
+        if read_u32(archive, local_offset)? != ZIP_LOCAL_HEADER {
+            return invalid("corrupted zip local header");
+        }
+
+        let data_start = local_offset
+            + 30
+            + read_u16(archive, local_offset + 26)? as usize
+            + read_u16(archive, local_offset + 28)? as usize;
+        let compressed_size =
+            usize::try_from(compressed_size).or_else(|_| invalid("truncated zip archive"))?;
+        let data = zip_bytes(archive, data_start, compressed_size)?;
+
+        if crc32(data) != crc {
+            return invalid(format!("checksum mismatch for entry '{}'", name));
+        }
+
+        entries.push((name, data));
+        offset = extra_start + extra_len + comment_len;
+    }
+
+    Ok(entries)
+}
+
+/// Reads all the arrays of the given uncompressed `.npz` data.
+///
+/// Returns the name of each array, without the `.npy` extension, along with its value. All the
+/// arrays must have the same dtype.
+///
+/// # Example
+/// ```
+/// # use nalgebra::DMatrix;
+/// # use nalgebra::io::{read_npz, write_npz};
+/// let a = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+/// let b = DMatrix::from_row_slice(1, 3, &[5.0, 6.0, 7.0]);
+/// let mut bytes = Vec::new();
+/// write_npz(&[("a", &a), ("b", &b)], &mut bytes).unwrap();
+///
+/// let arrays = read_npz::<f64, _>(&bytes[..]).unwrap();
+/// assert_eq!(arrays, vec![("a".to_string(), a), ("b".to_string(), b)]);
+/// ```
+pub fn read_npz<T: NpyElement, R: Read>(
+    mut reader: R,
+) -> Result<Vec<(String, DMatrix<T>)>, NpyError> {
+    let mut archive = Vec::new();
+    let _ = reader.read_to_end(&mut archive)?;
+
+    zip_entries(&archive)?
+        .into_iter()
+        .map(|(name, mut data)| {
+            let name = name.strip_suffix(".npy").unwrap_or(&name).to_string();
+            read_array(&mut data).map(|(matrix, _)| (name, matrix))
+        })
+        .collect()
+}
+
+/// Writes the given named matrices as an uncompressed `.npz` archive.
+///
+/// The resulting data can be read with `numpy.load`. Each matrix is stored as a two-dimensional
+/// array.
+pub fn write_npz<T: NpyElement, W: Write>(
+    arrays: &[(&str, &DMatrix<T>)],
+    mut writer: W,
+) -> Result<(), NpyError> {
+    let mut archive = Vec::new();
+    let mut central_directory = Vec::new();
+
+    for (name, matrix) in arrays {
+        let mut data = Vec::new();
+        write_npy(*matrix, &mut data)?;
+
+        let name = format!("{}.npy", name);
+        let name_len: u16 = name
+            .len()
+            .try_into()
+            .or_else(|_| invalid("the array name is too long for a zip archive"))?;
+        let crc = crc32(&data);
+        let size: u32 = data
+            .len()
+            .try_into()
+            .or_else(|_| invalid("the array is too large for a zip archive"))?;
+        let offset: u32 = archive
+            .len()
+            .try_into()
+            .or_else(|_| invalid("the archive is too large"))?;
+
+        // Fields common to the local and central headers: version needed, flags, compression
+        // method, time, date, checksum, sizes, name length, and extra field length.
+        let mut common = Vec::with_capacity(26);
+        common.extend_from_slice(&20u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&ZIP_DATE.to_le_bytes());
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&name_len.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+
+        archive.extend_from_slice(&ZIP_LOCAL_HEADER.to_le_bytes());
+        archive.extend_from_slice(&common);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(&data);
+
+        central_directory.extend_from_slice(&ZIP_CENTRAL_HEADER.to_le_bytes());
+        // Version made by.
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        central_directory.extend_from_slice(&common);
+        // Comment length, disk number, internal and external attributes.
+        central_directory.extend_from_slice(&[0; 10]);
+        central_directory.extend_from_slice(&offset.to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+    }
+
+    let num_entries: u16 = arrays
+        .len()
+        .try_into()
+        .or_else(|_| invalid("too many arrays for a zip archive"))?;
+    let directory_offset: u32 = archive
+        .len()
+        .try_into()
+        .or_else(|_| invalid("the archive is too large"))?;
+    let directory_len: u32 = central_directory
+        .len()
+        .try_into()
+        .or_else(|_| invalid("the archive is too large"))?;
+
+    archive.extend_from_slice(&central_directory);
+    archive.extend_from_slice(&ZIP_END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+    archive.extend_from_slice(&[0; 4]);
+    archive.extend_from_slice(&num_entries.to_le_bytes());
+    archive.extend_from_slice(&num_entries.to_le_bytes());
+    archive.extend_from_slice(&directory_len.to_le_bytes());
+    archive.extend_from_slice(&directory_offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes());
+
+    writer.write_all(&archive)?;
+    Ok(())
+}
@@ -142,6 +142,7 @@ pub use nalgebra_macros::{dmatrix, dvector, matrix, point, vector};
 
 use simba::scalar::SupersetOf;
 use std::cmp::{self, Ordering, PartialOrd};
+use std::ops::{Add, Mul};
 
 use num::{One, Signed, Zero};
 
@@ -421,6 +422,43 @@ pub fn distance_squared<T: SimdComplexField, const D: usize>(
     (&p2.coords - &p1.coords).norm_squared()
 }
 
+/// Interpolates the values `a`, `b`, `c` attached to the vertices of a triangle, at the point
+/// with barycentric coordinates `bary = (u, v, w)`, i.e., computes `a * u + b * v + c * w`.
+///
+/// The values can be any per-vertex attribute that can be scaled and summed, e.g., scalars,
+/// vectors, colors, or texture coordinates. The barycentric coordinates are typically computed
+/// with `Point::barycentric_coordinates`.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Point2, Vector3};
+/// let (a, b, c) = (Point2::new(0.0, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 2.0));
+/// let bary = Point2::new(1.0, 1.0).barycentric_coordinates(&a, &b, &c).unwrap();
+///
+/// let red = Vector3::new(1.0, 0.0, 0.0);
+/// let green = Vector3::new(0.0, 1.0, 0.0);
+/// let blue = Vector3::new(0.0, 0.0, 1.0);
+/// let color = nalgebra::barycentric_interpolation(&bary, red, green, blue);
+/// assert_relative_eq!(color, Vector3::new(0.25, 0.25, 0.5));
+///
+/// let depth = nalgebra::barycentric_interpolation(&bary, 1.0, 2.0, 3.0);
+/// assert_relative_eq!(depth, 2.25);
+/// ```
+///
+/// # See also:
+///
+/// * [`Point::barycentric_coordinates`](struct.OPoint.html#method.barycentric_coordinates)
+/// * [`Point::from_barycentric_coordinates`](struct.OPoint.html#method.from_barycentric_coordinates)
+#[inline]
+pub fn barycentric_interpolation<T, V>(bary: &Vector3<T>, a: V, b: V, c: V) -> V
+where
+    T: RealField,
+    V: Mul<T, Output = V> + Add<Output = V>,
+{
+    a * bary.x.clone() + b * bary.y.clone() + c * bary.z.clone()
+}
+
 /*
  * Cast
  */
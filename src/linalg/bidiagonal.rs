@@ -1,9 +1,12 @@
+use num::Zero;
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::Allocator;
 use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Const, Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
+use crate::storage::Storage;
 use simba::scalar::ComplexField;
 
 use crate::geometry::Reflection;
@@ -302,6 +305,107 @@ where
     pub fn uv_internal(&self) -> &OMatrix<T, R, C> {
         &self.uv
     }
+
+    /// Computes the least-squares solution of `self * x = b`, i.e., the vector `x` minimizing the
+    /// norm of `self * x - b`, directly from this decomposition.
+    ///
+    /// The stored reflections of `U` are applied to `b`, the bidiagonal system is solved by
+    /// backward substitution, and the reflections of `V` are applied to the result. This is
+    /// cheaper than computing the SVD for well-conditioned systems, but does not handle
+    /// rank-deficient ones. Returns `None` if an element of the diagonal is zero.
+    ///
+    /// # Panics
+    /// Panics if the decomposed matrix has more columns than rows.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix4x2, Vector2, Vector4};
+    /// // Fit of the line `y = a * t + b` to four points.
+    /// let m = Matrix4x2::new(0.0, 1.0,
+    ///                        1.0, 1.0,
+    ///                        2.0, 1.0,
+    ///                        3.0, 1.0);
+    /// let y = Vector4::new(1.0, 2.9, 5.1, 7.0);
+    ///
+    /// let x = m.bidiagonalize().solve_least_squares(&y).unwrap();
+    /// assert_relative_eq!(x, Vector2::new(2.02, 0.97), epsilon = 1.0e-10);
+    /// assert_relative_eq!(m.transpose() * (m * x - y), Vector2::zeros(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, C, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
+    {
+        assert!(
+            self.upper_diagonal,
+            "Bidiagonal least squares: the matrix must not have more columns than rows."
+        );
+        assert_eq!(
+            self.uv.nrows(),
+            b.nrows(),
+            "Bidiagonal least squares: matrix dimension mismatch."
+        );
+
+        let dim = self.diagonal.len();
+
+        // Apply `U^*`.
+        let mut ub = b.clone_owned();
+
+        for i in 0..dim {
+            let axis = self.uv.slice_range(i.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
+            let sign = self.diagonal[i].clone().signum().conjugate();
+
+            refl.reflect_with_sign(&mut ub.rows_range_mut(i..), sign);
+        }
+
+        let mut x = Matrix::zeros_generic(self.uv.shape_generic().1, b.shape_generic().1);
+        x.rows_mut(0, dim).copy_from(&ub.rows(0, dim));
+
+        // Solve the upper-bidiagonal system.
+        for k in 0..x.ncols() {
+            let mut x = x.column_mut(k);
+
+            for i in (0..dim).rev() {
+                let diag = self.diagonal[i].clone().modulus();
+
+                if diag.is_zero() {
+                    return None;
+                }
+
+                let mut coeff = x[i].clone();
+
+                if i + 1 < dim {
+                    coeff -= x[i + 1]
+                        .clone()
+                        .scale(self.off_diagonal[i].clone().modulus());
+                }
+
+                x[i] = coeff.unscale(diag);
+            }
+        }
+
+        // Apply `V`.
+        let mut axis_packed = Matrix::zeros_generic(self.uv.shape_generic().1, Const::<1>);
+
+        for i in (0..dim - 1).rev() {
+            let axis = self.uv.slice_range(i, i + 1..);
+            let mut axis_packed = axis_packed.rows_range_mut(i + 1..);
+            axis_packed.tr_copy_from(&axis);
+            let refl = Reflection::new(Unit::new_unchecked(axis_packed), T::zero());
+            let sign = self.off_diagonal[i].clone().signum().conjugate();
+
+            refl.reflect_with_sign(&mut x.rows_range_mut(i + 1..), sign);
+        }
+
+        Some(x)
+    }
 }
 
 // impl<T: ComplexField, D: DimMin<D, Output = D> + DimSub<Dynamic>> Bidiagonal<T, D, D>
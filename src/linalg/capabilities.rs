@@ -0,0 +1,144 @@
+//! Allocator bounds required by the matrix decompositions, grouped into single traits.
+//!
+//! Each decomposition needs to allocate several matrices and vectors with dimensions derived
+//! from the dimensions of the decomposed matrix. Instead of repeating all the corresponding
+//! `DefaultAllocator: Allocator<...>` bounds, generic code can require the single trait of
+//! this module matching the decomposition it uses. All these traits are implemented by
+//! `DefaultAllocator` whenever the underlying allocators exist, so they never have to be
+//! implemented manually.
+//!
+//! Because Rust does not propagate where-clauses other than supertraits, the constraints on
+//! the dimensions themselves (e.g. `R: DimMin<C>` or `D: DimSub<U1>`) still have to be stated
+//! alongside these traits.
+//!
+//! # Example
+//! ```
+//! # use nalgebra::{ComplexField, DefaultAllocator, Dim, DimMin, DimMinimum, DimSub, OMatrix,
+//! #     OVector, SVDCapable, U1};
+//! fn condition_number<T, R, C>(m: OMatrix<T, R, C>) -> T::RealField
+//! where
+//!     T: ComplexField,
+//!     R: DimMin<C>,
+//!     C: Dim,
+//!     DimMinimum<R, C>: DimSub<U1>,
+//!     DefaultAllocator: SVDCapable<T, R, C>,
+//! {
+//!     let singular_values = m.svd(false, false).singular_values;
+//!     singular_values.max() / singular_values.min()
+//! }
+//!
+//! let m = nalgebra::Matrix2::new(4.0, 0.0, 0.0, 0.5);
+//! assert_eq!(condition_number(m), 8.0);
+//! ```
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
+use crate::base::DefaultAllocator;
+use simba::scalar::ComplexField;
+
+macro_rules! capability_trait(
+    (
+        $(#[$doc: meta])*
+        $Trait: ident<$T: ident $(: $TBound: ident)*, $($Dims: ident: $DimBound: path),*>
+        where [$($where: tt)*]
+        : $($Allocators: tt)*
+    ) => {
+        $(#[$doc])*
+        pub trait $Trait<$T $(: $TBound)*, $($Dims: $DimBound),*>: $($Allocators)*
+        where
+            $($where)*
+        {
+        }
+
+        impl<$T $(: $TBound)*, $($Dims: $DimBound),*> $Trait<$T, $($Dims),*>
+            for DefaultAllocator
+        where
+            DefaultAllocator: $($Allocators)*,
+            $($where)*
+        {
+        }
+    }
+);
+
+capability_trait!(
+    /// The allocators needed by the LU decompositions `.lu()` and `.full_piv_lu()` of a matrix
+    /// with `R` rows and `C` columns.
+    LUCapable<T, R: DimMin<C>, C: Dim>
+    where []
+    : Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>
+);
+
+capability_trait!(
+    /// The allocators needed by the QR decomposition `.qr()` of a matrix with `R` rows and `C`
+    /// columns.
+    QRCapable<T, R: DimMin<C>, C: Dim>
+    where []
+    : Allocator<T, R, C> + Allocator<T, R> + Allocator<T, DimMinimum<R, C>>
+);
+
+capability_trait!(
+    /// The allocators needed by the QR decomposition with column pivoting `.col_piv_qr()` of a
+    /// matrix with `R` rows and `C` columns.
+    ColPivQRCapable<T, R: DimMin<C>, C: Dim>
+    where []
+    : QRCapable<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>
+);
+
+capability_trait!(
+    /// The allocators needed by the bidiagonalization `.bidiagonalize()` of a matrix with `R`
+    /// rows and `C` columns.
+    BidiagonalCapable<T, R: DimMin<C>, C: Dim>
+    where [DimMinimum<R, C>: DimSub<U1>]
+    : Allocator<T, R, C>
+        + Allocator<T, C>
+        + Allocator<T, R>
+        + Allocator<T, DimMinimum<R, C>>
+        + Allocator<T, DimDiff<DimMinimum<R, C>, U1>>
+);
+
+capability_trait!(
+    /// The allocators needed by the singular value decomposition `.svd()` of a matrix with `R`
+    /// rows and `C` columns, and by the methods based on it like `.singular_values()` or
+    /// `.pseudo_inverse()`.
+    SVDCapable<T: ComplexField, R: DimMin<C>, C: Dim>
+    where [DimMinimum<R, C>: DimSub<U1>]
+    : BidiagonalCapable<T, R, C>
+        + Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, R, DimMinimum<R, C>>
+        + Allocator<T::RealField, DimMinimum<R, C>>
+        + Allocator<T::RealField, DimDiff<DimMinimum<R, C>, U1>>
+);
+
+capability_trait!(
+    /// The allocators needed by the Hessenberg decomposition `.hessenberg()` of a square matrix
+    /// of dimension `D`.
+    HessenbergCapable<T, D: DimSub<U1>>
+    where []
+    : Allocator<T, D, D> + Allocator<T, D> + Allocator<T, DimDiff<D, U1>>
+);
+
+capability_trait!(
+    /// The allocators needed by the Schur decomposition `.schur()` of a square matrix of
+    /// dimension `D`, and by the methods based on it like `.eigenvalues()`.
+    SchurCapable<T, D: DimSub<U1>>
+    where []
+    : HessenbergCapable<T, D> + Allocator<T, D, DimDiff<D, U1>>
+);
+
+capability_trait!(
+    /// The allocators needed by the tridiagonalization `.symmetric_tridiagonalize()` of a
+    /// symmetric matrix of dimension `D`.
+    SymmetricTridiagonalCapable<T, D: DimSub<U1>>
+    where []
+    : Allocator<T, D, D> + Allocator<T, DimDiff<D, U1>>
+);
+
+capability_trait!(
+    /// The allocators needed by the eigendecomposition `.symmetric_eigen()` of a symmetric
+    /// matrix of dimension `D`, and by the methods based on it like `.symmetric_eigenvalues()`.
+    SymmetricEigenCapable<T: ComplexField, D: DimSub<U1>>
+    where []
+    : SymmetricTridiagonalCapable<T, D>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, DimDiff<D, U1>>
+);
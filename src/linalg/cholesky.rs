@@ -1,7 +1,7 @@
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
-use num::One;
+use num::{One, Zero};
 use simba::scalar::ComplexField;
 use simba::simd::SimdComplexField;
 
@@ -90,6 +90,40 @@ where
         self.chol
     }
 
+    /// Consumes this decomposition and returns its lower-triangular factor, without zeroing-out
+    /// its strict upper-triangular part.
+    ///
+    /// This is the same as `self.unpack_dirty()`. The decomposition can be rebuilt from this
+    /// factor with `Cholesky::from_parts`, e.g., after it has been stored or sent to another
+    /// process.
+    #[inline]
+    pub fn into_parts(self) -> OMatrix<T, D, D> {
+        self.chol
+    }
+
+    /// Rebuilds a Cholesky decomposition from its lower-triangular factor, as returned by
+    /// `Cholesky::into_parts`.
+    ///
+    /// The strict upper-triangular part of `l` is ignored. No check is performed: the diagonal
+    /// elements of `l` must be real and positive.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Cholesky, Matrix3, Vector3};
+    /// let m = Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0);
+    /// let l = m.cholesky().unwrap().into_parts();
+    /// let decomp = Cholesky::from_parts(l);
+    /// let b = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_relative_eq!(m * decomp.solve(&b), b, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn from_parts(l: OMatrix<T, D, D>) -> Self {
+        assert!(l.is_square(), "The Cholesky factor must be square.");
+        Cholesky { chol: l }
+    }
+
     /// Retrieves the lower-triangular factor of the Cholesky decomposition with its strictly
     /// uppen-triangular part filled with zeros.
     #[must_use]
@@ -109,7 +143,21 @@ where
 
     /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
     ///
-    /// The result is stored on `b`.
+    /// The result is stored on `b`, which may have several columns, i.e., right-hand sides. No
+    /// allocation is performed.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Matrix3x2};
+    /// let m = Matrix3::new(4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0);
+    /// let chol = m.cholesky().unwrap();
+    /// let b = Matrix3x2::new(1.0, 0.0, 2.0, 1.0, 3.0, -1.0);
+    ///
+    /// let mut x = b;
+    /// chol.solve_mut(&mut x);
+    /// assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    /// ```
     pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
     where
         S2: StorageMut<T, R2, C2>,
@@ -133,6 +181,36 @@ where
         res
     }
 
+    /// Solves the system `self.transpose() * x = b` where `self` is the decomposed matrix and `x`
+    /// the unknown.
+    ///
+    /// The result is stored on `b`. Because the decomposed matrix is hermitian, this is the
+    /// same as solving `conj(self) * x = b`, and solving `self.adjoint() * x = b` is the same as
+    /// calling `self.solve_mut(b)`.
+    pub fn tr_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        b.conjugate_mut();
+        self.solve_mut(b);
+        b.conjugate_mut();
+    }
+
+    /// Returns the solution of the system `self.transpose() * x = b` where `self` is the
+    /// decomposed matrix and `x` the unknown.
+    #[must_use = "Did you mean to use tr_solve_mut()?"]
+    pub fn tr_solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> OMatrix<T, R2, C2>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        self.tr_solve_mut(&mut res);
+        res
+    }
+
     /// Computes the inverse of the decomposed matrix.
     #[must_use]
     pub fn inverse(&self) -> OMatrix<T, D, D> {
@@ -143,6 +221,31 @@ where
         res
     }
 
+    /// Computes the inverse of the decomposed matrix and outputs the result to `out`.
+    ///
+    /// This is an allocation-less version of `self.inverse()`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0);
+    /// let chol = m.cholesky().unwrap();
+    ///
+    /// let mut inv = Matrix3::zeros();
+    /// chol.inverse_to(&mut inv);
+    /// assert_relative_eq!(m * inv, Matrix3::identity(), epsilon = 1.0e-10);
+    /// ```
+    pub fn inverse_to<S2: StorageMut<T, D, D>>(&self, out: &mut Matrix<T, D, D, S2>) {
+        assert!(
+            self.chol.shape() == out.shape(),
+            "Cholesky inverse: mismatched output shape."
+        );
+
+        out.fill_with_identity();
+        self.solve_mut(out);
+    }
+
     /// Computes the determinant of the decomposed matrix.
     #[must_use]
     pub fn determinant(&self) -> T::SimdRealField {
@@ -153,6 +256,38 @@ where
         }
         prod_diag.simd_modulus_squared()
     }
+
+    /// Computes the natural logarithm of the determinant of the decomposed matrix.
+    ///
+    /// This is computed from the logarithms of the diagonal elements of the Cholesky factor, and
+    /// thus does not overflow nor underflow for large matrices, e.g., when evaluating the
+    /// log-likelihood of a multivariate normal distribution.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::<f64>::identity(500, 500) * 1.0e-3;
+    /// let chol = m.cholesky().unwrap();
+    ///
+    /// assert_eq!(chol.determinant(), 0.0);
+    /// assert_relative_eq!(chol.ln_determinant(), 500.0 * 1.0e-3f64.ln(), epsilon = 1.0e-9);
+    /// ```
+    #[must_use]
+    pub fn ln_determinant(&self) -> T::SimdRealField {
+        let dim = self.chol.nrows();
+        let mut sum_diag = T::SimdRealField::zero();
+        for i in 0..dim {
+            sum_diag += unsafe {
+                self.chol
+                    .get_unchecked((i, i))
+                    .clone()
+                    .simd_modulus_squared()
+                    .simd_ln()
+            };
+        }
+        sum_diag
+    }
 }
 
 impl<T: ComplexField, D: Dim> Cholesky<T, D>
@@ -162,39 +297,66 @@ where
     /// Attempts to compute the Cholesky decomposition of `matrix`.
     ///
     /// Returns `None` if the input matrix is not definite-positive. The input matrix is assumed
-    /// to be symmetric and only the lower-triangular part is read.
+    /// to be symmetric and only the lower-triangular part is read. Use `matrix.is_hermitian(eps)`
+    /// to check beforehand that the input matrix actually is symmetric.
+    ///
+    /// Large matrices are decomposed with a blocked right-looking algorithm: after the
+    /// decomposition of each diagonal block of 64 columns, the rest of the matrix is updated with
+    /// matrix products.
     pub fn new(mut matrix: OMatrix<T, D, D>) -> Option<Self> {
         assert!(matrix.is_square(), "The input matrix must be square.");
 
         let n = matrix.nrows();
 
-        for j in 0..n {
-            for k in 0..j {
-                let factor = unsafe { -matrix.get_unchecked((j, k)).clone() };
+        if n <= CHOLESKY_BLOCK_SIZE {
+            return if cholesky_unblocked(&mut matrix) {
+                Some(Cholesky { chol: matrix })
+            } else {
+                None
+            };
+        }
 
-                let (mut col_j, col_k) = matrix.columns_range_pair_mut(j, k);
-                let mut col_j = col_j.rows_range_mut(j..);
-                let col_k = col_k.rows_range(j..);
+        for k in (0..n).step_by(CHOLESKY_BLOCK_SIZE) {
+            let end = n.min(k + CHOLESKY_BLOCK_SIZE);
 
-                col_j.axpy(factor.conjugate(), &col_k, T::one());
+            if !cholesky_unblocked(&mut matrix.slice_range_mut(k..end, k..end)) {
+                return None;
             }
 
-            let diag = unsafe { matrix.get_unchecked((j, j)).clone() };
-            if !diag.is_zero() {
-                if let Some(denom) = diag.try_sqrt() {
-                    unsafe {
-                        *matrix.get_unchecked_mut((j, j)) = denom.clone();
-                    }
-
-                    let mut col = matrix.slice_range_mut(j + 1.., j);
-                    col /= denom;
-                    continue;
+            if end == n {
+                break;
+            }
+
+            // Solve `L21 * L11ᴴ = A21`, and store `L21ᴴ` in the unused upper-triangular part.
+            let (mut panel, mut rest) = matrix.columns_range_pair_mut(k..end, end..);
+            let (l11, mut l21) = panel.rows_range_pair_mut(k..end, end..);
+            let (mut l21_ad, mut a22) = rest.rows_range_pair_mut(k..end, end..);
+
+            for j in 0..end - k {
+                for p in 0..j {
+                    let factor = -l11[(j, p)].clone().conjugate();
+                    let (mut col_j, col_p) = l21.columns_range_pair_mut(j, p);
+                    col_j.axpy(factor, &col_p, T::one());
+                }
+
+                let mut col_j = l21.column_mut(j);
+                col_j /= l11[(j, j)].clone();
+
+                for (i, e) in col_j.iter().enumerate() {
+                    l21_ad[(j, i)] = e.clone().conjugate();
                 }
             }
 
-            // The diagonal element is either zero or its square root could not
-            // be taken (e.g. for negative real numbers).
-            return None;
+            // A22 -= L21 * L21ᴴ, only on the lower-triangular part and the diagonal blocks.
+            for j in (0..n - end).step_by(CHOLESKY_BLOCK_SIZE) {
+                let len = CHOLESKY_BLOCK_SIZE.min(n - end - j);
+                a22.slice_range_mut(j.., j..j + len).gemm(
+                    -T::one(),
+                    &l21.rows_range(j..),
+                    &l21_ad.columns_range(j..j + len),
+                    T::one(),
+                );
+            }
         }
 
         Some(Cholesky { chol: matrix })
@@ -377,3 +539,50 @@ where
         }
     }
 }
+
+/// The number of columns of the diagonal blocks of the blocked Cholesky decomposition. Matrices
+/// with at most this many rows are decomposed without blocking.
+const CHOLESKY_BLOCK_SIZE: usize = 64;
+
+/// Overwrites the lower-triangular part of the square `matrix` with its Cholesky factor, column
+/// by column. Returns `false` if `matrix` is not definite-positive.
+fn cholesky_unblocked<T, R, C, S>(matrix: &mut Matrix<T, R, C, S>) -> bool
+where
+    T: ComplexField,
+    R: Dim,
+    C: Dim,
+    S: StorageMut<T, R, C>,
+{
+    let n = matrix.nrows();
+
+    for j in 0..n {
+        for k in 0..j {
+            let factor = unsafe { -matrix.get_unchecked((j, k)).clone() };
+
+            let (mut col_j, col_k) = matrix.columns_range_pair_mut(j, k);
+            let mut col_j = col_j.rows_range_mut(j..);
+            let col_k = col_k.rows_range(j..);
+
+            col_j.axpy(factor.conjugate(), &col_k, T::one());
+        }
+
+        let diag = unsafe { matrix.get_unchecked((j, j)).clone() };
+        if !diag.is_zero() {
+            if let Some(denom) = diag.try_sqrt() {
+                unsafe {
+                    *matrix.get_unchecked_mut((j, j)) = denom.clone();
+                }
+
+                let mut col = matrix.slice_range_mut(j + 1.., j);
+                col /= denom;
+                continue;
+            }
+        }
+
+        // The diagonal element is either zero or its square root could not
+        // be taken (e.g. for negative real numbers).
+        return false;
+    }
+
+    true
+}
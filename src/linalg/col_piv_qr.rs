@@ -6,7 +6,7 @@ use crate::allocator::{Allocator, Reallocator};
 use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector, Unit};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
-use crate::storage::StorageMut;
+use crate::storage::{Storage, StorageMut};
 use crate::ComplexField;
 
 use crate::geometry::Reflection;
@@ -198,6 +198,96 @@ where
             refl.reflect_with_sign(&mut rhs_rows, self.diag[i].clone().signum().conjugate());
         }
     }
+
+    /// Computes the numerical rank of the decomposed matrix, i.e., the number of leading diagonal
+    /// elements of `R` with a modulus greater than `eps`.
+    ///
+    /// Because each column is pivoted on the largest remaining component, the block of `R`
+    /// following the first diagonal element smaller than `eps` is negligible too.
+    #[must_use]
+    pub fn rank(&self, eps: T::RealField) -> usize {
+        assert!(
+            eps >= T::RealField::zero(),
+            "ColPivQR rank: the epsilon must be non-negative."
+        );
+        self.diag
+            .iter()
+            .take_while(|e| (*e).clone().modulus() > eps)
+            .count()
+    }
+
+    /// Computes a basic least-squares solution of `self * x = b`, truncated at the numerical
+    /// rank `self.rank(eps)`, and the norm of its residual `self * x - b`.
+    ///
+    /// If `r` is the numerical rank, the components of `x` matching all but the first `r` pivot
+    /// columns are set to zero, and the remaining ones minimize the norm of the residual. Unlike
+    /// `solve`, this works with rectangular and rank-deficient matrices, though the result is
+    /// not the minimum-norm solution computed by `SVD::solve` if the matrix is rank-deficient.
+    /// If `b` has several columns, the returned norm is the Frobenius norm of the residuals.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix4x3, Vector3, Vector4};
+    /// // The third column is the sum of the first two.
+    /// let m = Matrix4x3::new(1.0, 2.0, 3.0,
+    ///                        2.0, 4.0, 6.0,
+    ///                        1.0, 0.0, 1.0,
+    ///                        0.0, 1.0, 1.0);
+    /// let qr = m.col_piv_qr();
+    /// assert_eq!(qr.rank(1.0e-10), 2);
+    ///
+    /// let b = m * Vector3::new(1.0, 1.0, 1.0);
+    /// let (x, residual) = qr.solve_least_squares(&b, 1.0e-10);
+    /// assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    /// assert!(residual < 1.0e-10);
+    ///
+    /// let b = Vector4::new(1.0, 0.0, 0.0, 0.0);
+    /// let (x, residual) = qr.solve_least_squares(&b, 1.0e-10);
+    /// assert_relative_eq!((m * x - b).norm(), residual, epsilon = 1.0e-10);
+    /// assert_relative_eq!(m.transpose() * (m * x - b), Vector3::zeros(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        eps: T::RealField,
+    ) -> (OMatrix<T, C, C2>, T::RealField)
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
+    {
+        assert_eq!(
+            self.col_piv_qr.nrows(),
+            b.nrows(),
+            "ColPivQR least squares: matrix dimension mismatch."
+        );
+
+        let rank = self.rank(eps);
+        let mut qtb = b.clone_owned();
+        self.q_tr_mul(&mut qtb);
+
+        // The residual is orthogonal to the range of the first `rank` columns of `Q`.
+        let residual_norm = qtb.rows_range(rank..).norm();
+
+        let mut x = Matrix::zeros_generic(self.col_piv_qr.shape_generic().1, b.shape_generic().1);
+        x.rows_mut(0, rank).copy_from(&qtb.rows(0, rank));
+
+        for k in 0..x.ncols() {
+            let mut x = x.column_mut(k);
+
+            for i in (0..rank).rev() {
+                let coeff = x[i].clone().unscale(self.diag[i].clone().modulus());
+                x[i] = coeff.clone();
+                x.rows_range_mut(..i)
+                    .axpy(-coeff, &self.col_piv_qr.slice_range(..i, i), T::one());
+            }
+        }
+
+        self.p.inv_permute_rows(&mut x);
+        (x, residual_norm)
+    }
 }
 
 impl<T: ComplexField, D: DimMin<D, Output = D>> ColPivQR<T, D, D>
@@ -207,7 +297,8 @@ where
 {
     /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
     ///
-    /// Returns `None` if `self` is not invertible.
+    /// Returns `None` if `self` is not invertible. Nearly singular matrices are not detected,
+    /// use `solve_least_squares` with a tolerance to solve rank-deficient systems.
     #[must_use = "Did you mean to use solve_mut()?"]
     pub fn solve<R2: Dim, C2: Dim, S2>(
         &self,
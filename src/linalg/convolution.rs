@@ -5,6 +5,65 @@ use crate::base::default_allocator::DefaultAllocator;
 use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimSub, DimSum};
 use crate::storage::Storage;
 use crate::{zero, OVector, RealField, Vector, U1};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{DMatrix, DVector, Matrix};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use num_complex::Complex;
+
+/// The part of the full convolution (or cross-correlation) returned by the FFT-based
+/// convolutions, e.g., `Vector::convolve_fft`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConvolutionMode {
+    /// The full convolution, of length `n + m - 1` for a signal of length `n` and a kernel of
+    /// length `m`.
+    Full,
+    /// The part of the full convolution of the same size as the signal, starting at the offset
+    /// `(m - 1) / 2`, as with SciPy's `"same"` mode.
+    ///
+    /// Note that `Vector::convolve_same` starts at the offset `m - 2` instead, so both only agree
+    /// for kernels of length 2 or 3.
+    Same,
+    /// The part of the full convolution which does not depend on the boundary, of length
+    /// `n - m + 1`.
+    Valid,
+}
+
+/// The extension of the signal beyond its boundaries used by the FFT-based convolutions, e.g.,
+/// `Vector::convolve_fft`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConvolutionBoundary {
+    /// The signal is padded with zeros.
+    Zero,
+    /// The signal is extended periodically: `[a, b, c]` is extended to
+    /// `[.., b, c, a, b, c, a, b, ..]`.
+    Periodic,
+    /// The signal is extended by reflection on its edges, which are repeated: `[a, b, c]` is
+    /// extended to `[.., b, a, a, b, c, c, b, ..]`.
+    Symmetric,
+}
+
+impl ConvolutionBoundary {
+    /// The index within `0..len` of the component at the index `i` of the extended signal.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn source_index(self, i: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+
+        match self {
+            ConvolutionBoundary::Zero => {
+                if i >= 0 && i < len {
+                    Some(i as usize)
+                } else {
+                    None
+                }
+            }
+            ConvolutionBoundary::Periodic => Some(i.rem_euclid(len) as usize),
+            ConvolutionBoundary::Symmetric => {
+                let i = i.rem_euclid(2 * len);
+                Some(if i < len { i } else { 2 * len - 1 - i } as usize)
+            }
+        }
+    }
+}
 
 impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
     /// Returns the convolution of the target vector and a kernel.
@@ -142,3 +201,335 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
         conv
     }
 }
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
+    /// Returns the convolution of this vector and a kernel, computed with the fast Fourier
+    /// transform.
+    ///
+    /// This takes `O((n + m) log(n + m))` operations for a vector of length `n` and a kernel of
+    /// length `m`, instead of the `O(n m)` operations of `convolve_full`, `convolve_same`, and
+    /// `convolve_valid`, which are faster for small kernels. The components outside of this
+    /// vector are given by `boundary`, and the part of the full convolution which is returned is
+    /// given by `mode`.
+    ///
+    /// With `ConvolutionMode::Same` and `ConvolutionBoundary::Zero`, the result only matches
+    /// `convolve_same` for kernels of length 2 or 3: for other lengths, the part returned by
+    /// `convolve_same` is not centered with respect to the full convolution. See
+    /// `ConvolutionMode::Same`.
+    ///
+    /// # Panics
+    /// Panics if this vector or the kernel is empty, or if `mode` is `ConvolutionMode::Valid` and
+    /// the kernel is longer than this vector.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{ConvolutionBoundary, ConvolutionMode, Vector3, Vector4};
+    /// let signal = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let kernel = Vector3::new(1.0, 0.0, -1.0);
+    ///
+    /// let full = signal.convolve_fft(kernel, ConvolutionMode::Full, ConvolutionBoundary::Zero);
+    /// let expected = signal.convolve_full(kernel);
+    /// assert_relative_eq!(full.as_slice(), expected.as_slice(), epsilon = 1.0e-10);
+    ///
+    /// let periodic = ConvolutionBoundary::Periodic;
+    /// let same = signal.convolve_fft(kernel, ConvolutionMode::Same, periodic);
+    /// assert_relative_eq!(same.as_slice(), &[-2.0, 2.0, 2.0, -2.0][..], epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn convolve_fft<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DVector<T>
+    where
+        D2: Dim,
+        S2: Storage<T, D2>,
+    {
+        fft_convolve(self, &kernel, mode, boundary, false)
+            .column(0)
+            .into_owned()
+    }
+
+    /// Returns the cross-correlation of this vector and a kernel, computed with the fast Fourier
+    /// transform.
+    ///
+    /// This is the convolution of this vector and the reversed kernel. See `Vector::convolve_fft`
+    /// for details about the arguments.
+    ///
+    /// # Panics
+    /// Panics if this vector or the kernel is empty, or if `mode` is `ConvolutionMode::Valid` and
+    /// the kernel is longer than this vector.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{ConvolutionBoundary, ConvolutionMode, Vector2, Vector5};
+    /// let signal = Vector5::new(0.0, 1.0, 2.0, 1.0, 0.0);
+    /// let pattern = Vector2::new(2.0, 1.0);
+    ///
+    /// let corr = signal.correlate_fft(pattern, ConvolutionMode::Valid, ConvolutionBoundary::Zero);
+    /// assert_relative_eq!(corr.as_slice(), &[1.0, 4.0, 5.0, 2.0][..], epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn correlate_fft<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DVector<T>
+    where
+        D2: Dim,
+        S2: Storage<T, D2>,
+    {
+        fft_convolve(self, &kernel, mode, boundary, true)
+            .column(0)
+            .into_owned()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: RealField, R1: Dim, C1: Dim, S1: Storage<T, R1, C1>> Matrix<T, R1, C1, S1> {
+    /// Returns the 2D convolution of this matrix and a kernel, computed with the fast Fourier
+    /// transform.
+    ///
+    /// The components outside of this matrix are given by `boundary`, which is applied to the
+    /// rows and to the columns independently, and the part of the full convolution which is
+    /// returned is given by `mode`, along each dimension.
+    ///
+    /// # Panics
+    /// Panics if this matrix or the kernel is empty, or if `mode` is `ConvolutionMode::Valid` and
+    /// the kernel has more rows or more columns than this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{ConvolutionBoundary, ConvolutionMode, DMatrix, Matrix3};
+    /// let image = DMatrix::from_fn(5, 6, |i, j| (i * j) as f64);
+    /// // A box blur.
+    /// let kernel = Matrix3::repeat(1.0 / 9.0);
+    ///
+    /// let blurred =
+    ///     image.convolve_2d_fft(kernel, ConvolutionMode::Same, ConvolutionBoundary::Symmetric);
+    /// assert_eq!(blurred.shape(), (5, 6));
+    /// // The blur preserves bilinear functions away from the boundary.
+    /// assert_relative_eq!(blurred[(2, 3)], 6.0, epsilon = 1.0e-10);
+    ///
+    /// let zero = ConvolutionBoundary::Zero;
+    /// let valid = image.convolve_2d_fft(kernel, ConvolutionMode::Valid, zero);
+    /// assert_relative_eq!(valid, blurred.slice((1, 1), (3, 4)).into_owned(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn convolve_2d_fft<R2, C2, S2>(
+        &self,
+        kernel: Matrix<T, R2, C2, S2>,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        fft_convolve(self, &kernel, mode, boundary, false)
+    }
+
+    /// Returns the 2D cross-correlation of this matrix and a kernel, computed with the fast
+    /// Fourier transform.
+    ///
+    /// This is the 2D convolution of this matrix and the kernel flipped along both dimensions.
+    /// See `Matrix::convolve_2d_fft` for details about the arguments.
+    ///
+    /// # Panics
+    /// Panics if this matrix or the kernel is empty, or if `mode` is `ConvolutionMode::Valid` and
+    /// the kernel has more rows or more columns than this matrix.
+    #[must_use]
+    pub fn correlate_2d_fft<R2, C2, S2>(
+        &self,
+        kernel: Matrix<T, R2, C2, S2>,
+        mode: ConvolutionMode,
+        boundary: ConvolutionBoundary,
+    ) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        fft_convolve(self, &kernel, mode, boundary, true)
+    }
+}
+
+/// Computes the 2D convolution, or cross-correlation if `correlate` is `true`, of `signal` and
+/// `kernel`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn fft_convolve<T, R1, C1, S1, R2, C2, S2>(
+    signal: &Matrix<T, R1, C1, S1>,
+    kernel: &Matrix<T, R2, C2, S2>,
+    mode: ConvolutionMode,
+    boundary: ConvolutionBoundary,
+    correlate: bool,
+) -> DMatrix<T>
+where
+    T: RealField,
+    R1: Dim,
+    C1: Dim,
+    S1: Storage<T, R1, C1>,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+{
+    let (nrows, ncols) = signal.shape();
+    let (knrows, kncols) = kernel.shape();
+
+    assert!(
+        nrows * ncols != 0 && knrows * kncols != 0,
+        "FFT convolution: the signal and the kernel must not be empty."
+    );
+    assert!(
+        mode != ConvolutionMode::Valid || (knrows <= nrows && kncols <= ncols),
+        "FFT convolution: the kernel must not be larger than the signal in the valid mode."
+    );
+
+    // Extend the signal by `kernel size - 1` on each side, except for zero-padding which is
+    // implicit in the linear convolution computed below.
+    let (rows_ext, cols_ext) = if boundary == ConvolutionBoundary::Zero {
+        (0, 0)
+    } else {
+        (knrows - 1, kncols - 1)
+    };
+    let ext_nrows = nrows + 2 * rows_ext;
+    let ext_ncols = ncols + 2 * cols_ext;
+
+    let fft_nrows = (ext_nrows + knrows - 1).next_power_of_two();
+    let fft_ncols = (ext_ncols + kncols - 1).next_power_of_two();
+    let zero = Complex::new(T::zero(), T::zero());
+    let mut fsignal = DMatrix::from_element(fft_nrows, fft_ncols, zero.clone());
+    let mut fkernel = fsignal.clone();
+
+    for j in 0..ext_ncols {
+        let src_j = boundary.source_index(j as isize - cols_ext as isize, ncols);
+
+        for i in 0..ext_nrows {
+            let src_i = boundary.source_index(i as isize - rows_ext as isize, nrows);
+
+            if let (Some(src_i), Some(src_j)) = (src_i, src_j) {
+                fsignal[(i, j)].re = signal[(src_i, src_j)].clone();
+            }
+        }
+    }
+
+    for j in 0..kncols {
+        for i in 0..knrows {
+            fkernel[(i, j)].re = if correlate {
+                kernel[(knrows - 1 - i, kncols - 1 - j)].clone()
+            } else {
+                kernel[(i, j)].clone()
+            };
+        }
+    }
+
+    fft_2d(&mut fsignal, false);
+    fft_2d(&mut fkernel, false);
+    fsignal.zip_apply(&fkernel, |a, b| *a = a.clone() * b);
+    fft_2d(&mut fsignal, true);
+
+    // The full convolution starts at the offset `ext` of the linear convolution of the extended
+    // signal.
+    let (start_i, len_i) = mode_range(mode, nrows, knrows);
+    let (start_j, len_j) = mode_range(mode, ncols, kncols);
+
+    DMatrix::from_fn(len_i, len_j, |i, j| {
+        fsignal[(rows_ext + start_i + i, cols_ext + start_j + j)]
+            .re
+            .clone()
+    })
+}
+
+/// The start and the length of the part of a full convolution returned with the given mode.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn mode_range(mode: ConvolutionMode, len: usize, kernel_len: usize) -> (usize, usize) {
+    match mode {
+        ConvolutionMode::Full => (0, len + kernel_len - 1),
+        ConvolutionMode::Same => ((kernel_len - 1) / 2, len),
+        ConvolutionMode::Valid => (kernel_len - 1, len - kernel_len + 1),
+    }
+}
+
+/// Computes in-place the 2D discrete Fourier transform, or its inverse, of `data`, whose
+/// dimensions must be powers of two.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn fft_2d<T: RealField>(data: &mut DMatrix<Complex<T>>, inverse: bool) {
+    let (nrows, ncols) = data.shape();
+
+    for column in data.as_mut_slice().chunks_mut(nrows) {
+        fft(column, inverse);
+    }
+
+    if ncols > 1 {
+        let mut row = DVector::from_element(ncols, Complex::new(T::zero(), T::zero()));
+
+        for i in 0..nrows {
+            row.tr_copy_from(&data.row(i));
+            fft(row.as_mut_slice(), inverse);
+            data.row_mut(i).tr_copy_from(&row);
+        }
+    }
+}
+
+/// Computes in-place the discrete Fourier transform, or its inverse, of `data`, whose length
+/// must be a power of two, with the iterative radix-2 Cooley-Tukey algorithm.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn fft<T: RealField>(data: &mut [Complex<T>], inverse: bool) {
+    let n = data.len();
+    debug_assert!(n.is_power_of_two());
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+
+    for i in 1..n {
+        let mut bit = n >> 1;
+
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+
+        j |= bit;
+
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+
+    while len <= n {
+        let half = len / 2;
+
+        for k in 0..half {
+            let angle = T::two_pi() * crate::convert::<f64, T>(k as f64)
+                / crate::convert::<f64, T>(len as f64);
+            let (sin, cos) = angle.sin_cos();
+            let twiddle = Complex::new(cos, if inverse { sin } else { -sin });
+
+            for start in (0..n).step_by(len) {
+                let a = data[start + k].clone();
+                let b = data[start + k + half].clone() * twiddle.clone();
+                data[start + k] = a.clone() + b.clone();
+                data[start + k + half] = a - b;
+            }
+        }
+
+        len *= 2;
+    }
+
+    if inverse {
+        let scale = crate::convert::<f64, T>(n as f64);
+
+        for e in data.iter_mut() {
+            *e = e.clone().unscale(scale.clone());
+        }
+    }
+}
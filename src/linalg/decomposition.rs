@@ -1,9 +1,11 @@
 use crate::storage::Storage;
 use crate::{
-    Allocator, Bidiagonal, Cholesky, ColPivQR, ComplexField, DefaultAllocator, Dim, DimDiff,
-    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, RealField, Schur, SymmetricEigen,
-    SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
+    Allocator, Bidiagonal, BidiagonalCapable, Cholesky, ColPivQR, ColPivQRCapable, ComplexField,
+    DefaultAllocator, Dim, DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, HessenbergCapable,
+    LUCapable, Matrix, QRCapable, SVDCapable, Schur, SchurCapable, SchurOptions, SymmetricEigen,
+    SymmetricEigenCapable, SymmetricTridiagonal, SymmetricTridiagonalCapable, LU, QR, SVD, U1, UDU,
 };
+use std::ops::ControlFlow;
 
 /// # Rectangular matrix decomposition
 ///
@@ -23,11 +25,7 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     where
         R: DimMin<C>,
         DimMinimum<R, C>: DimSub<U1>,
-        DefaultAllocator: Allocator<T, R, C>
-            + Allocator<T, C>
-            + Allocator<T, R>
-            + Allocator<T, DimMinimum<R, C>>
-            + Allocator<T, DimDiff<DimMinimum<R, C>, U1>>,
+        DefaultAllocator: BidiagonalCapable<T, R, C>,
     {
         Bidiagonal::new(self.into_owned())
     }
@@ -38,7 +36,7 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     pub fn full_piv_lu(self) -> FullPivLU<T, R, C>
     where
         R: DimMin<C>,
-        DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
+        DefaultAllocator: LUCapable<T, R, C>,
     {
         FullPivLU::new(self.into_owned())
     }
@@ -47,7 +45,7 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     pub fn lu(self) -> LU<T, R, C>
     where
         R: DimMin<C>,
-        DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
+        DefaultAllocator: LUCapable<T, R, C>,
     {
         LU::new(self.into_owned())
     }
@@ -56,7 +54,7 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     pub fn qr(self) -> QR<T, R, C>
     where
         R: DimMin<C>,
-        DefaultAllocator: Allocator<T, R, C> + Allocator<T, R> + Allocator<T, DimMinimum<R, C>>,
+        DefaultAllocator: QRCapable<T, R, C>,
     {
         QR::new(self.into_owned())
     }
@@ -65,10 +63,7 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     pub fn col_piv_qr(self) -> ColPivQR<T, R, C>
     where
         R: DimMin<C>,
-        DefaultAllocator: Allocator<T, R, C>
-            + Allocator<T, R>
-            + Allocator<T, DimMinimum<R, C>>
-            + Allocator<(usize, usize), DimMinimum<R, C>>,
+        DefaultAllocator: ColPivQRCapable<T, R, C>,
     {
         ColPivQR::new(self.into_owned())
     }
@@ -78,15 +73,7 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     where
         R: DimMin<C>,
         DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
-        DefaultAllocator: Allocator<T, R, C>
-            + Allocator<T, C>
-            + Allocator<T, R>
-            + Allocator<T, DimDiff<DimMinimum<R, C>, U1>>
-            + Allocator<T, DimMinimum<R, C>, C>
-            + Allocator<T, R, DimMinimum<R, C>>
-            + Allocator<T, DimMinimum<R, C>>
-            + Allocator<T::RealField, DimMinimum<R, C>>
-            + Allocator<T::RealField, DimDiff<DimMinimum<R, C>, U1>>,
+        DefaultAllocator: SVDCapable<T, R, C>,
     {
         SVD::new(self.into_owned(), compute_u, compute_v)
     }
@@ -111,18 +98,38 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     where
         R: DimMin<C>,
         DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
-        DefaultAllocator: Allocator<T, R, C>
-            + Allocator<T, C>
-            + Allocator<T, R>
-            + Allocator<T, DimDiff<DimMinimum<R, C>, U1>>
-            + Allocator<T, DimMinimum<R, C>, C>
-            + Allocator<T, R, DimMinimum<R, C>>
-            + Allocator<T, DimMinimum<R, C>>
-            + Allocator<T::RealField, DimMinimum<R, C>>
-            + Allocator<T::RealField, DimDiff<DimMinimum<R, C>, U1>>,
+        DefaultAllocator: SVDCapable<T, R, C>,
     {
         SVD::try_new(self.into_owned(), compute_u, compute_v, eps, max_niter)
     }
+
+    /// Attempts to compute the Singular Value Decomposition of `matrix` using implicit shift,
+    /// reporting the progress of the iterations to `progress`.
+    ///
+    /// See `SVD::try_new_with`.
+    pub fn try_svd_with<F>(
+        self,
+        compute_u: bool,
+        compute_v: bool,
+        eps: T::RealField,
+        max_niter: usize,
+        progress: F,
+    ) -> Option<SVD<T, R, C>>
+    where
+        R: DimMin<C>,
+        DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
+        DefaultAllocator: SVDCapable<T, R, C>,
+        F: FnMut(usize, T::RealField) -> ControlFlow<()>,
+    {
+        SVD::try_new_with(
+            self.into_owned(),
+            compute_u,
+            compute_v,
+            eps,
+            max_niter,
+            progress,
+        )
+    }
 }
 
 /// # Square matrix decomposition
@@ -134,7 +141,7 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 /// | -------------------------|---------------------------|--------------|
 /// | Hessenberg               | `Q * H * Qᵀ`             | `Q` is a unitary matrix and `H` an upper-Hessenberg matrix. |
 /// | Cholesky                 | `L * Lᵀ`                 | `L` is a lower-triangular matrix. |
-/// | UDU                      | `U * D * Uᵀ`             | `U` is a upper-triangular matrix, and `D` a diagonal matrix. |
+/// | UDU                      | `U * D * Uᴴ`             | `U` is a upper-triangular matrix, and `D` a diagonal matrix. |
 /// | Schur decomposition      | `Q * T * Qᵀ`             | `Q` is an unitary matrix and `T` a quasi-upper-triangular matrix. |
 /// | Symmetric eigendecomposition | `Q ~ Λ ~ Qᵀ`   | `Q` is an unitary matrix, and `Λ` is a real diagonal matrix. |
 /// | Symmetric tridiagonalization | `Q ~ T ~ Qᵀ`   | `Q` is an unitary matrix, and `T` is a tridiagonal matrix. |
@@ -152,12 +159,11 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
 
     /// Attempts to compute the UDU decomposition of this matrix.
     ///
-    /// The input matrix `self` is assumed to be symmetric and this decomposition will only read
+    /// The input matrix `self` is assumed to be hermitian and this decomposition will only read
     /// the upper-triangular part of `self`.
     pub fn udu(self) -> Option<UDU<T, D>>
     where
-        T: RealField,
-        DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+        DefaultAllocator: Allocator<T::RealField, D> + Allocator<T, D, D>,
     {
         UDU::new(self.into_owned())
     }
@@ -166,7 +172,7 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
     pub fn hessenberg(self) -> Hessenberg<T, D>
     where
         D: DimSub<U1>,
-        DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<T, DimDiff<D, U1>>,
+        DefaultAllocator: HessenbergCapable<T, D>,
     {
         Hessenberg::new(self.into_owned())
     }
@@ -175,10 +181,7 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
     pub fn schur(self) -> Schur<T, D>
     where
         D: DimSub<U1>, // For Hessenberg.
-        DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
-            + Allocator<T, DimDiff<D, U1>>
-            + Allocator<T, D, D>
-            + Allocator<T, D>,
+        DefaultAllocator: SchurCapable<T, D>,
     {
         Schur::new(self.into_owned())
     }
@@ -197,24 +200,48 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
     pub fn try_schur(self, eps: T::RealField, max_niter: usize) -> Option<Schur<T, D>>
     where
         D: DimSub<U1>, // For Hessenberg.
-        DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
-            + Allocator<T, DimDiff<D, U1>>
-            + Allocator<T, D, D>
-            + Allocator<T, D>,
+        DefaultAllocator: SchurCapable<T, D>,
     {
         Schur::try_new(self.into_owned(), eps, max_niter)
     }
 
+    /// Attempts to compute the Schur decomposition of a square matrix, reporting the progress of
+    /// the iterations to `progress`.
+    ///
+    /// See `Schur::try_new_with`.
+    pub fn try_schur_with<F>(
+        self,
+        eps: T::RealField,
+        max_niter: usize,
+        progress: F,
+    ) -> Option<Schur<T, D>>
+    where
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: SchurCapable<T, D>,
+        F: FnMut(usize, T::RealField) -> ControlFlow<()>,
+    {
+        Schur::try_new_with(self.into_owned(), eps, max_niter, progress)
+    }
+
+    /// Attempts to compute the Schur decomposition of a square matrix with the given options of
+    /// the implicit QR iterations.
+    ///
+    /// See `Schur::try_new_with_options`.
+    pub fn try_schur_with_options(self, options: &SchurOptions<T::RealField>) -> Option<Schur<T, D>>
+    where
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: SchurCapable<T, D>,
+    {
+        Schur::try_new_with_options(self.into_owned(), options)
+    }
+
     /// Computes the eigendecomposition of this symmetric matrix.
     ///
     /// Only the lower-triangular part (including the diagonal) of `m` is read.
     pub fn symmetric_eigen(self) -> SymmetricEigen<T, D>
     where
         D: DimSub<U1>,
-        DefaultAllocator: Allocator<T, D, D>
-            + Allocator<T, DimDiff<D, U1>>
-            + Allocator<T::RealField, D>
-            + Allocator<T::RealField, DimDiff<D, U1>>,
+        DefaultAllocator: SymmetricEigenCapable<T, D>,
     {
         SymmetricEigen::new(self.into_owned())
     }
@@ -237,21 +264,38 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
     ) -> Option<SymmetricEigen<T, D>>
     where
         D: DimSub<U1>,
-        DefaultAllocator: Allocator<T, D, D>
-            + Allocator<T, DimDiff<D, U1>>
-            + Allocator<T::RealField, D>
-            + Allocator<T::RealField, DimDiff<D, U1>>,
+        DefaultAllocator: SymmetricEigenCapable<T, D>,
     {
         SymmetricEigen::try_new(self.into_owned(), eps, max_niter)
     }
 
+    /// Computes the eigendecomposition of the given symmetric matrix with user-specified
+    /// convergence parameters, reporting the progress of the iterations to `progress`.
+    ///
+    /// Only the lower-triangular part (including the diagonal) of `m` is read.
+    ///
+    /// See `SymmetricEigen::try_new_with`.
+    pub fn try_symmetric_eigen_with<F>(
+        self,
+        eps: T::RealField,
+        max_niter: usize,
+        progress: F,
+    ) -> Option<SymmetricEigen<T, D>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: SymmetricEigenCapable<T, D>,
+        F: FnMut(usize, T::RealField) -> ControlFlow<()>,
+    {
+        SymmetricEigen::try_new_with(self.into_owned(), eps, max_niter, progress)
+    }
+
     /// Computes the tridiagonalization of this symmetric matrix.
     ///
     /// Only the lower-triangular part (including the diagonal) of `m` is read.
     pub fn symmetric_tridiagonalize(self) -> SymmetricTridiagonal<T, D>
     where
         D: DimSub<U1>,
-        DefaultAllocator: Allocator<T, D, D> + Allocator<T, DimDiff<D, U1>>,
+        DefaultAllocator: SymmetricTridiagonalCapable<T, D>,
     {
         SymmetricTridiagonal::new(self.into_owned())
     }
@@ -0,0 +1,225 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use simba::scalar::ComplexField;
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, SquareMatrix};
+use crate::linalg::LinearOperator;
+
+/// The eigenvalues of largest modulus, and the associated eigenvectors, of a hermitian matrix or
+/// operator.
+///
+/// They are computed by subspace iteration, a.k.a. block power iteration or orthogonal
+/// iteration: the operator is repeatedly applied to a block of `k` orthonormal vectors, which is
+/// then orthonormalized again, until the Rayleigh-Ritz approximations of the eigenpairs converge.
+/// This only requires products of the operator with `n × k` matrices, and is much cheaper than
+/// a full `SymmetricEigen` if `k` is small compared to the dimension `n` of the operator. The
+/// convergence is slow if the modulus of the `k`-th largest eigenvalue is close to the modulus
+/// of the next one.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(
+        serialize = "T: Serialize, T::RealField: Serialize",
+        deserialize = "T: Deserialize<'de>, T::RealField: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct DominantEigen<T: ComplexField> {
+    /// The orthonormal eigenvectors, stored as the columns of a `n × k` matrix.
+    pub eigenvectors: DMatrix<T>,
+    /// The `k` eigenvalues of largest modulus, sorted by decreasing modulus.
+    pub eigenvalues: DVector<T::RealField>,
+}
+
+impl<T: ComplexField> DominantEigen<T> {
+    /// Computes the `k` eigenvalues of largest modulus, and the associated eigenvectors, of the
+    /// given hermitian matrix.
+    ///
+    /// See `DominantEigen::try_new_with_operator` for details about the arguments.
+    ///
+    /// # Panics
+    /// Panics if `m` is not square, or if `k` is zero or greater than the dimension of `m`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, DominantEigen};
+    /// // The 1D discrete laplacian, with eigenvalues `2 - 2 * cos(i * π / (n + 1))`.
+    /// let n = 20;
+    /// let m = DMatrix::from_fn(n, n, |i, j| match (i as isize - j as isize).abs() {
+    ///     0 => 2.0,
+    ///     1 => -1.0,
+    ///     _ => 0.0,
+    /// });
+    ///
+    /// let eigen = DominantEigen::try_new(&m, 3, 1.0e-10, 10_000).unwrap();
+    /// let largest = 2.0 - 2.0 * (n as f64 * std::f64::consts::PI / (n as f64 + 1.0)).cos();
+    ///
+    /// assert_eq!(eigen.eigenvectors.shape(), (20, 3));
+    /// assert_relative_eq!(eigen.eigenvalues[0], largest, epsilon = 1.0e-9);
+    /// assert_relative_eq!(
+    ///     &m * &eigen.eigenvectors,
+    ///     &eigen.eigenvectors * DMatrix::from_diagonal(&eigen.eigenvalues),
+    ///     epsilon = 1.0e-8
+    /// );
+    /// ```
+    pub fn try_new<D: Dim, S: Storage<T, D, D>>(
+        m: &SquareMatrix<T, D, S>,
+        k: usize,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigenvectors of a non-square matrix."
+        );
+
+        Self::try_new_with_operator(m, k, eps, max_niter)
+    }
+
+    /// Computes the `k` eigenvalues of largest modulus, and the associated eigenvectors, of a
+    /// hermitian linear operator.
+    ///
+    /// The operator is only accessed through its products with `n × k` matrices, where `n` is its
+    /// dimension. This allows, e.g., the use of sparse matrices, compositions of operators, or
+    /// implicit matrices defined by closures with `FnOperator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine the convergence. The iteration stops when, for
+    ///   each eigenpair `(λ, v)`, the norm of the residual `A * v - λ * v` is smaller than `eps`
+    ///   times the largest eigenvalue modulus.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    ///   number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    ///   continues indefinitely until convergence.
+    ///
+    /// # Panics
+    /// Panics if `operator` is not square, or if `k` is zero or greater than its dimension.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, DominantEigen, FnOperator};
+    /// // The 1D discrete laplacian, applied without forming its matrix.
+    /// let n = 20;
+    /// let laplacian = FnOperator::new_hermitian(n, |x: &DMatrix<f64>| {
+    ///     DMatrix::from_fn(n, x.ncols(), |i, j| {
+    ///         let prev = if i > 0 { x[(i - 1, j)] } else { 0.0 };
+    ///         let next = if i + 1 < n { x[(i + 1, j)] } else { 0.0 };
+    ///         2.0 * x[(i, j)] - prev - next
+    ///     })
+    /// });
+    ///
+    /// let eigen = DominantEigen::try_new_with_operator(&laplacian, 3, 1.0e-10, 10_000).unwrap();
+    /// let largest = 2.0 - 2.0 * (n as f64 * std::f64::consts::PI / (n as f64 + 1.0)).cos();
+    /// assert_relative_eq!(eigen.eigenvalues[0], largest, epsilon = 1.0e-9);
+    /// ```
+    pub fn try_new_with_operator<O: LinearOperator<T> + ?Sized>(
+        operator: &O,
+        k: usize,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        let (dim, ncols) = operator.shape();
+        assert_eq!(
+            dim, ncols,
+            "Unable to compute the eigenvectors of a non-square operator."
+        );
+        assert!(
+            k > 0 && k <= dim,
+            "Dominant eigenvectors: the number of eigenvectors must be in [1, dim]."
+        );
+
+        // A deterministic starting block, unlikely to be orthogonal to any eigenvector.
+        let start = DMatrix::from_fn(dim, k, |i, j| {
+            let hash = (i.wrapping_mul(2_654_435_761) ^ j.wrapping_mul(40_503)) % 65_536;
+            crate::convert::<f64, T>(hash as f64 / 65_536.0 - 0.5)
+        });
+        let mut basis = start.qr().q();
+        let mut niter = 0;
+
+        loop {
+            let image = operator.apply(&basis);
+            assert_eq!(
+                image.shape(),
+                (dim, k),
+                "Dominant eigenvectors: the operator returned a matrix with the wrong shape."
+            );
+
+            // Rayleigh-Ritz projection on the current subspace.
+            let projected = basis.ad_mul(&image).hermitian_part();
+            let eigen = projected.symmetric_eigen();
+
+            let mut indices: Vec<usize> = (0..k).collect();
+            indices.sort_by(|a, b| {
+                eigen.eigenvalues[*b]
+                    .clone()
+                    .abs()
+                    .partial_cmp(&eigen.eigenvalues[*a].clone().abs())
+                    .unwrap_or(Ordering::Equal)
+            });
+
+            let rotation = eigen.eigenvectors.select_columns(&indices);
+            let eigenvalues = eigen.eigenvalues.select_rows(&indices);
+            let eigenvectors = &basis * &rotation;
+            let image = image * rotation;
+
+            let tolerance = eps.clone() * eigenvalues[0].clone().abs();
+            let converged = (0..k).all(|j| {
+                let residual =
+                    image.column(j) - eigenvectors.column(j) * T::from_real(eigenvalues[j].clone());
+                residual.norm() <= tolerance
+            });
+
+            if converged {
+                return Some(Self {
+                    eigenvectors,
+                    eigenvalues,
+                });
+            }
+
+            niter += 1;
+
+            if niter == max_niter {
+                return None;
+            }
+
+            basis = image.qr().q();
+        }
+    }
+
+    /// Rebuilds the rank-`k` approximation `V * Λ * Vᴴ` of the decomposed operator.
+    #[must_use]
+    pub fn recompose(&self) -> DMatrix<T> {
+        let mut scaled = self.eigenvectors.clone();
+
+        for (mut column, eigenvalue) in scaled.column_iter_mut().zip(self.eigenvalues.iter()) {
+            column.scale_mut(eigenvalue.clone());
+        }
+
+        scaled * self.eigenvectors.adjoint()
+    }
+}
+
+impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
+    /// Computes the `k` eigenvalues of largest modulus, and the associated eigenvectors, of this
+    /// hermitian matrix using subspace iteration.
+    ///
+    /// See `DominantEigen::try_new`.
+    #[must_use]
+    pub fn try_dominant_eigen(
+        &self,
+        k: usize,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<DominantEigen<T>> {
+        DominantEigen::try_new(self, k, eps, max_niter)
+    }
+}
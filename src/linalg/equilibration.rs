@@ -0,0 +1,242 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::{Const, Dim};
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, OVector};
+
+/// Row and column scaling factors which equilibrate a matrix.
+///
+/// Multiplying the `i`-th row of the matrix by `row_scales[i]` and its `j`-th column by
+/// `col_scales[j]`, i.e., computing `diag(row_scales) * m * diag(col_scales)`, yields a matrix
+/// whose rows and columns all have a largest entry with a modulus between `1/√2` and `√2`, which
+/// often has a much smaller condition number than `m`. All the factors are powers of two, so
+/// scaling does not introduce any rounding error.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T::RealField, R> +
+                           Allocator<T::RealField, C>,
+         OVector<T::RealField, R>: Serialize,
+         OVector<T::RealField, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T::RealField, R> +
+                           Allocator<T::RealField, C>,
+         OVector<T::RealField, R>: Deserialize<'de>,
+         OVector<T::RealField, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct Equilibration<T: ComplexField, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<T::RealField, R> + Allocator<T::RealField, C>,
+{
+    row_scales: OVector<T::RealField, R>,
+    col_scales: OVector<T::RealField, C>,
+}
+
+impl<T: ComplexField, R: Dim, C: Dim> Copy for Equilibration<T, R, C>
+where
+    DefaultAllocator: Allocator<T::RealField, R> + Allocator<T::RealField, C>,
+    OVector<T::RealField, R>: Copy,
+    OVector<T::RealField, C>: Copy,
+{
+}
+
+impl<T: ComplexField, R: Dim, C: Dim> Equilibration<T, R, C>
+where
+    DefaultAllocator: Allocator<T::RealField, R> + Allocator<T::RealField, C>,
+{
+    /// Computes the equilibration factors of `matrix`.
+    ///
+    /// The row factors are the powers of two closest to the inverses of the largest moduli of
+    /// the rows of `matrix`. The column factors are then computed the same way from the
+    /// row-scaled matrix. Rows and columns of zeros get a factor of one.
+    pub fn new<S: Storage<T, R, C>>(matrix: &Matrix<T, R, C, S>) -> Self {
+        let (nrows, ncols) = matrix.shape_generic();
+        let mut row_scales = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut col_scales = Matrix::zeros_generic(ncols, Const::<1>);
+
+        for i in 0..nrows.value() {
+            let amax = matrix
+                .row(i)
+                .iter()
+                .fold(T::RealField::zero(), |a, e| a.max(e.clone().modulus()));
+            row_scales[i] = inverse_power_of_two(amax);
+        }
+
+        for j in 0..ncols.value() {
+            let amax = matrix
+                .column(j)
+                .iter()
+                .zip(row_scales.iter())
+                .fold(T::RealField::zero(), |a, (e, r)| {
+                    a.max(e.clone().modulus() * r.clone())
+                });
+            col_scales[j] = inverse_power_of_two(amax);
+        }
+
+        Self {
+            row_scales,
+            col_scales,
+        }
+    }
+
+    /// The factors by which the rows of the matrix are multiplied.
+    #[inline]
+    #[must_use]
+    pub fn row_scales(&self) -> &OVector<T::RealField, R> {
+        &self.row_scales
+    }
+
+    /// The factors by which the columns of the matrix are multiplied.
+    #[inline]
+    #[must_use]
+    pub fn col_scales(&self) -> &OVector<T::RealField, C> {
+        &self.col_scales
+    }
+
+    /// Computes the ratio between the smallest and the largest row scaling factors.
+    ///
+    /// As suggested by LAPACK, scaling the rows is not worth it if this is larger than `0.1` and
+    /// the entries of the matrix are neither too large nor too small.
+    #[must_use]
+    pub fn row_condition(&self) -> T::RealField {
+        scales_condition(self.row_scales.as_slice())
+    }
+
+    /// Computes the ratio between the smallest and the largest column scaling factors.
+    #[must_use]
+    pub fn col_condition(&self) -> T::RealField {
+        scales_condition(self.col_scales.as_slice())
+    }
+
+    /// Equilibrates `matrix` in-place, i.e., overwrites it with
+    /// `diag(row_scales) * matrix * diag(col_scales)`.
+    ///
+    /// # Panics
+    /// Panics if `matrix` does not have the shape of the matrix these factors were computed from.
+    pub fn scale_matrix<S2: StorageMut<T, R, C>>(&self, matrix: &mut Matrix<T, R, C, S2>) {
+        assert_eq!(
+            matrix.shape(),
+            (self.row_scales.len(), self.col_scales.len()),
+            "Equilibration: matrix dimension mismatch."
+        );
+
+        for (j, c) in self.col_scales.iter().enumerate() {
+            for (i, r) in self.row_scales.iter().enumerate() {
+                // The product of both factors may overflow, so they are applied one at a time.
+                matrix[(i, j)] = matrix[(i, j)].clone().scale(r.clone()).scale(c.clone());
+            }
+        }
+    }
+
+    /// Scales the right-hand side `b` of the system `matrix * x = b` in-place, i.e., overwrites
+    /// it with `diag(row_scales) * b`.
+    ///
+    /// The solution `y` of the equilibrated system with this right-hand side is related to the
+    /// solution of the original system by `x = diag(col_scales) * y`, see `unscale_solution`.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many rows as the equilibrated matrix.
+    pub fn scale_rhs<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+    {
+        assert_eq!(
+            b.nrows(),
+            self.row_scales.len(),
+            "Equilibration: right-hand side dimension mismatch."
+        );
+
+        for (i, r) in self.row_scales.iter().enumerate() {
+            b.row_mut(i).apply(|e| *e = e.clone().scale(r.clone()));
+        }
+    }
+
+    /// Converts in-place a solution of the equilibrated system into a solution of the original
+    /// system, i.e., overwrites `x` with `diag(col_scales) * x`.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have as many rows as the equilibrated matrix has columns.
+    pub fn unscale_solution<R2: Dim, C2: Dim, S2>(&self, x: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, C>,
+    {
+        assert_eq!(
+            x.nrows(),
+            self.col_scales.len(),
+            "Equilibration: solution dimension mismatch."
+        );
+
+        for (i, c) in self.col_scales.iter().enumerate() {
+            x.row_mut(i).apply(|e| *e = e.clone().scale(c.clone()));
+        }
+    }
+}
+
+impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
+where
+    DefaultAllocator: Allocator<T::RealField, R> + Allocator<T::RealField, C>,
+{
+    /// Computes row and column scaling factors, all powers of two, which reduce the condition
+    /// number of this matrix before factorizing it.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0e10, 2.0e10,
+    ///                      3.0e-8, 1.0e-8);
+    /// let equilibration = m.equilibrate();
+    ///
+    /// let mut scaled = m;
+    /// equilibration.scale_matrix(&mut scaled);
+    /// assert!(scaled.amin() >= 0.125 && scaled.amax() <= 2.0);
+    /// ```
+    #[must_use]
+    pub fn equilibrate(&self) -> Equilibration<T, R, C> {
+        Equilibration::new(self)
+    }
+}
+
+/// The power of two closest to `1 / x` in logarithmic scale, or one if `x` is zero.
+///
+/// As with the `SMLNUM` and `BIGNUM` bounds of LAPACK's `xGEEQU`, the result is clamped to a
+/// finite range, so that it does not overflow if `x` is subnormal.
+fn inverse_power_of_two<T: RealField>(x: T) -> T {
+    if x.is_zero() || !x.is_finite() {
+        T::one()
+    } else {
+        // The largest exponent whose power of two is finite, even if `log2` rounds up.
+        let max_exponent = (T::max_value().log2() - T::one()).ceil();
+        let exponent = (-x.log2().round())
+            .min(max_exponent.clone())
+            .max(-max_exponent);
+        exponent.exp2()
+    }
+}
+
+fn scales_condition<T: RealField>(scales: &[T]) -> T {
+    let mut min = T::one();
+    let mut max = T::one();
+
+    if let Some(first) = scales.first() {
+        min = first.clone();
+        max = first.clone();
+    }
+
+    for s in scales {
+        min = min.min(s.clone());
+        max = max.max(s.clone());
+    }
+
+    min / max
+}
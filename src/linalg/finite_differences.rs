@@ -0,0 +1,159 @@
+//! Numerical derivatives computed with central finite differences.
+
+use simba::scalar::RealField;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::{DefaultAllocator, OMatrix, OVector};
+
+/// The step used to differentiate along the `i`-th component of `x`, scaled by the magnitude of
+/// this component so that the step stays meaningful relative to `x`.
+fn scaled_step<T: RealField, D: Dim>(x: &OVector<T, D>, i: usize, eps: &T) -> T
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    eps.clone() * x[i].clone().abs().max(T::one())
+}
+
+/// Approximates the Jacobian matrix of the vector function `f` at `x`, using central finite
+/// differences.
+///
+/// The `j`-th column of the result is `(f(x + hⱼ eⱼ) - f(x - hⱼ eⱼ)) / 2hⱼ`, where the step
+/// `hⱼ = eps * max(1, |xⱼ|)` is scaled by the magnitude of the `j`-th component of `x`. This
+/// needs `2n` evaluations of `f` for an input of dimension `n` (or one if `n` is zero). The
+/// approximation error is minimized by an `eps` close to the cube root of the machine epsilon,
+/// e.g., `1.0e-5` for `f64`.
+///
+/// This is typically used to test the analytic derivatives of a function.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Matrix2x3, Vector2, Vector3};
+/// let f = |x: &Vector3<f64>| Vector2::new(x.x * x.y, x.z.sin());
+/// let x = Vector3::new(1.0, 2.0, 3.0);
+///
+/// let jacobian = nalgebra::jacobian(f, &x, 1.0e-5);
+/// let expected = Matrix2x3::new(x.y, x.x, 0.0,
+///                               0.0, 0.0, x.z.cos());
+/// assert_relative_eq!(jacobian, expected, epsilon = 1.0e-9);
+/// ```
+#[must_use]
+pub fn jacobian<T, R, C, F>(f: F, x: &OVector<T, C>, eps: T) -> OMatrix<T, R, C>
+where
+    T: RealField,
+    R: Dim,
+    C: Dim,
+    F: Fn(&OVector<T, C>) -> OVector<T, R>,
+    DefaultAllocator: Allocator<T, R> + Allocator<T, C> + Allocator<T, R, C>,
+{
+    let ncols = x.shape_generic().0;
+    let mut res: Option<OMatrix<T, R, C>> = None;
+    let mut xh = x.clone();
+
+    for j in 0..ncols.value() {
+        let h = scaled_step(x, j, &eps);
+        xh[j] = x[j].clone() + h.clone();
+        let xp = xh[j].clone();
+        let fp = f(&xh);
+        xh[j] = x[j].clone() - h;
+        // Use the actual difference between the evaluation points to compensate rounding errors.
+        let dx = xp - xh[j].clone();
+        let fm = f(&xh);
+        xh[j] = x[j].clone();
+
+        let res = res.get_or_insert_with(|| OMatrix::zeros_generic(fp.shape_generic().0, ncols));
+        let mut col = res.column_mut(j);
+        col.copy_from(&fp);
+        col -= fm;
+        col /= dx;
+    }
+
+    // `f` is only evaluated to know the number of rows of the result if `x` is empty.
+    res.unwrap_or_else(|| OMatrix::zeros_generic(f(x).shape_generic().0, ncols))
+}
+
+/// Approximates the Hessian matrix of the scalar function `f` at `x`, using central finite
+/// differences.
+///
+/// The second derivative along the components `i` and `j` is approximated from the values of
+/// `f` at `x ± hᵢ eᵢ ± hⱼ eⱼ`, where the step `hᵢ = eps * max(1, |xᵢ|)` is scaled by the
+/// magnitude of the `i`-th component of `x`. The result is exactly symmetric. This needs
+/// `2n² + 1` evaluations of `f` for an input of dimension `n`. The approximation error is
+/// minimized by an `eps` close to the fourth root of the machine epsilon, e.g., `1.0e-4` for
+/// `f64`.
+///
+/// This is typically used to test the analytic derivatives of a function.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Matrix2, Vector2};
+/// let f = |x: &Vector2<f64>| x.x * x.x * x.y + x.y.exp();
+/// let x = Vector2::new(1.0, 2.0);
+///
+/// let hessian = nalgebra::hessian(f, &x, 1.0e-4);
+/// let expected = Matrix2::new(2.0 * x.y, 2.0 * x.x,
+///                             2.0 * x.x, x.y.exp());
+/// assert_relative_eq!(hessian, expected, epsilon = 1.0e-6);
+/// ```
+#[must_use]
+pub fn hessian<T, D, F>(f: F, x: &OVector<T, D>, eps: T) -> OMatrix<T, D, D>
+where
+    T: RealField,
+    D: Dim,
+    F: Fn(&OVector<T, D>) -> T,
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+    let dim = x.shape_generic().0;
+    let mut res = OMatrix::zeros_generic(dim, dim);
+    let mut xh = x.clone();
+    let fx = f(x);
+    let two: T = crate::convert(2.0);
+
+    for i in 0..dim.value() {
+        let hi = scaled_step(x, i, &eps);
+        let xip = x[i].clone() + hi.clone();
+        let xim = x[i].clone() - hi;
+        // Use the actual steps to compensate rounding errors.
+        let hip = xip.clone() - x[i].clone();
+        let him = x[i].clone() - xim.clone();
+
+        xh[i] = xip.clone();
+        let fp = f(&xh);
+        xh[i] = xim.clone();
+        let fm = f(&xh);
+
+        // Second-order central difference with possibly uneven steps.
+        res[(i, i)] = two.clone()
+            * (fp / hip.clone() + fm / him.clone()
+                - fx.clone() * (hip.clone() + him.clone()) / (hip.clone() * him.clone()))
+            / (hip + him);
+
+        for j in 0..i {
+            let hj = scaled_step(x, j, &eps);
+            let xjp = x[j].clone() + hj.clone();
+            let xjm = x[j].clone() - hj;
+
+            xh[i] = xip.clone();
+            xh[j] = xjp.clone();
+            let fpp = f(&xh);
+            xh[j] = xjm.clone();
+            let fpm = f(&xh);
+            xh[i] = xim.clone();
+            let fmm = f(&xh);
+            xh[j] = xjp.clone();
+            let fmp = f(&xh);
+            xh[j] = x[j].clone();
+
+            let hessian_ij = (fpp - fpm - fmp + fmm)
+                / ((xip.clone() - xim.clone()) * (xjp.clone() - xjm.clone()));
+            res[(i, j)] = hessian_ij.clone();
+            res[(j, i)] = hessian_ij;
+        }
+
+        xh[i] = x[i].clone();
+    }
+
+    res
+}
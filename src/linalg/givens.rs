@@ -1,14 +1,54 @@
-//! Construction of givens rotations.
+//! Construction and application of Givens rotations.
+//!
+//! A Givens rotation is a rotation in the plane spanned by two coordinate axes. Applying it to a
+//! matrix only modifies two of its rows or columns, which makes it the building block of many
+//! algorithms introducing zeros one at a time: QR decompositions and their updates, Jacobi
+//! sweeps, or the QR iterations on bidiagonal and tridiagonal matrices.
 
 use num::{One, Zero};
 use simba::scalar::ComplexField;
 
+use crate::base::allocator::Allocator;
 use crate::base::constraint::{DimEq, ShapeConstraint};
 use crate::base::dimension::{Dim, U2};
 use crate::base::storage::{Storage, StorageMut};
-use crate::base::{Matrix, Vector};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Vector};
 
 /// A Givens rotation.
+///
+/// The rotation is described by its cosine `c`, which is real, and its sine `s`, which may be
+/// complex. It acts on two-dimensional vectors as the unitary matrix:
+///
+/// ```text
+/// | c  -s* |
+/// | s   c  |
+/// ```
+///
+/// Use `apply_left` and `apply_right` to apply it to any pair of rows or columns of a matrix,
+/// and `to_matrix_generic` to embed it into a square matrix. The orthogonal (or unitary) factor
+/// of a sequence of rotations is accumulated by applying each of them to the right of an
+/// identity matrix.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{DMatrix, GivensRotation};
+/// // Cancel the subdiagonal components of the first column with rotations of the rows.
+/// let mut r = DMatrix::from_row_slice(3, 2, &[3.0, 1.0,
+///                                             4.0, 2.0,
+///                                             0.0, 5.0]);
+/// let a = r.clone();
+/// let mut q = DMatrix::identity(3, 3);
+///
+/// if let Some((rot, _)) = GivensRotation::cancel_y(&r.fixed_slice::<2, 1>(0, 0)) {
+///     rot.apply_left(&mut r, 0, 1);
+///     rot.inverse().apply_right(&mut q, 0, 1);
+/// }
+///
+/// assert_relative_eq!(r[(1, 0)], 0.0, epsilon = 1.0e-12);
+/// assert_relative_eq!(&q * &r, a, epsilon = 1.0e-12);
+/// assert!(q.is_orthogonal(1.0e-12));
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub struct GivensRotation<T: ComplexField> {
     c: T::RealField,
@@ -17,7 +57,7 @@ pub struct GivensRotation<T: ComplexField> {
 
 // Matrix = UnitComplex * Matrix
 impl<T: ComplexField> GivensRotation<T> {
-    /// The Givents rotation that does nothing.
+    /// The Givens rotation that does nothing.
     pub fn identity() -> Self {
         Self {
             c: T::RealField::one(),
@@ -88,13 +128,13 @@ impl<T: ComplexField> GivensRotation<T> {
         }
     }
 
-    /// The cos part of this roration.
+    /// The cos part of this rotation.
     #[must_use]
     pub fn c(&self) -> T::RealField {
         self.c.clone()
     }
 
-    /// The sin part of this roration.
+    /// The sin part of this rotation.
     #[must_use]
     pub fn s(&self) -> T {
         self.s.clone()
@@ -162,4 +202,92 @@ impl<T: ComplexField> GivensRotation<T> {
             }
         }
     }
+
+    /// Performs the multiplication `m = R * m` in-place, where `R` is this rotation acting on
+    /// the rows `i` and `j` of `m`.
+    ///
+    /// Only the rows `i` and `j` are modified, and the columns where both of them are zero are
+    /// skipped, so this is efficient on sparse matrices.
+    ///
+    /// # Panics
+    /// Panics if `i == j`, or if `i` or `j` is not a row of `m`.
+    pub fn apply_left<R2: Dim, C2: Dim, S2: StorageMut<T, R2, C2>>(
+        &self,
+        m: &mut Matrix<T, R2, C2, S2>,
+        i: usize,
+        j: usize,
+    ) {
+        assert!(
+            i != j && i < m.nrows() && j < m.nrows(),
+            "Givens rotation: invalid pair of rows."
+        );
+        let s = self.s.clone();
+        let c = self.c.clone();
+
+        for k in 0..m.ncols() {
+            unsafe {
+                let a = m.get_unchecked((i, k)).clone();
+                let b = m.get_unchecked((j, k)).clone();
+
+                if a.is_zero() && b.is_zero() {
+                    continue;
+                }
+
+                *m.get_unchecked_mut((i, k)) =
+                    a.clone().scale(c.clone()) - s.clone().conjugate() * b.clone();
+                *m.get_unchecked_mut((j, k)) = s.clone() * a + b.scale(c.clone());
+            }
+        }
+    }
+
+    /// Performs the multiplication `m = m * R` in-place, where `R` is this rotation acting on
+    /// the columns `i` and `j` of `m`.
+    ///
+    /// Only the columns `i` and `j` are modified, and the rows where both of them are zero are
+    /// skipped, so this is efficient on sparse matrices.
+    ///
+    /// # Panics
+    /// Panics if `i == j`, or if `i` or `j` is not a column of `m`.
+    pub fn apply_right<R2: Dim, C2: Dim, S2: StorageMut<T, R2, C2>>(
+        &self,
+        m: &mut Matrix<T, R2, C2, S2>,
+        i: usize,
+        j: usize,
+    ) {
+        assert!(
+            i != j && i < m.ncols() && j < m.ncols(),
+            "Givens rotation: invalid pair of columns."
+        );
+        let s = self.s.clone();
+        let c = self.c.clone();
+
+        for k in 0..m.nrows() {
+            unsafe {
+                let a = m.get_unchecked((k, i)).clone();
+                let b = m.get_unchecked((k, j)).clone();
+
+                if a.is_zero() && b.is_zero() {
+                    continue;
+                }
+
+                *m.get_unchecked_mut((k, i)) = a.clone().scale(c.clone()) + s.clone() * b.clone();
+                *m.get_unchecked_mut((k, j)) = -s.clone().conjugate() * a + b.scale(c.clone());
+            }
+        }
+    }
+
+    /// The `dim × dim` matrix of this rotation acting on the coordinates `i` and `j`, with
+    /// dimensions specified by generic types instances.
+    ///
+    /// # Panics
+    /// Panics if `i == j`, or if `i` or `j` is not smaller than `dim`.
+    #[must_use]
+    pub fn to_matrix_generic<D: Dim>(&self, dim: D, i: usize, j: usize) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        let mut res = OMatrix::identity_generic(dim, dim);
+        self.apply_left(&mut res, i, j);
+        res
+    }
 }
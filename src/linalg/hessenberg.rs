@@ -2,10 +2,14 @@
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, OMatrix, OVector};
-use crate::dimension::{Const, DimDiff, DimSub, U1};
+use crate::base::{DefaultAllocator, OMatrix, OVector, Unit, Vector2};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::dimension::{Const, Dim, DimDiff, DimSub, U1};
+use crate::storage::{Storage, StorageMut};
 use simba::scalar::ComplexField;
 
+use crate::geometry::Reflection;
+use crate::linalg::givens::GivensRotation;
 use crate::linalg::householder;
 use crate::Matrix;
 use std::mem::MaybeUninit;
@@ -147,6 +151,146 @@ where
         householder::assemble_q(&self.hess, self.subdiag.as_slice())
     }
 
+    /// Solves the linear system `self * x = b`, where `self` is the decomposed matrix and `x`
+    /// the unknown.
+    ///
+    /// Returns `None` if the decomposed matrix is not invertible. See `Hessenberg::solve_shifted`.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        self.solve_shifted(b, T::zero())
+    }
+
+    /// Solves the linear system `self * x = b`, where `self` is the decomposed matrix and `x`
+    /// the unknown.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        self.solve_shifted_mut(b, T::zero())
+    }
+
+    /// Solves the shifted linear system `(self - sigma * I) * x = b`, where `self` is the
+    /// decomposed matrix and `x` the unknown.
+    ///
+    /// The Hessenberg matrix `H - sigma * I` is reduced to upper-triangular form with Givens
+    /// rotations, so each solve only takes `O(n²)` operations instead of the `O(n³)` operations
+    /// of a new factorization. This makes it possible to solve many shifted systems with the same
+    /// matrix, e.g., in shift-and-invert or rational Krylov methods. Returns `None` if
+    /// `self - sigma * I` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix4, Vector4};
+    /// let m = Matrix4::new(4.0, 1.0, -2.0, 2.0,
+    ///                      1.0, 2.0, 0.0, 1.0,
+    ///                      -3.0, 0.5, 3.0, -2.0,
+    ///                      2.0, 1.0, -2.0, -1.0);
+    /// let hess = m.hessenberg();
+    /// let b = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    ///
+    /// for sigma in [0.5, 1.0, 2.0] {
+    ///     let x = hess.solve_shifted(&b, sigma).unwrap();
+    ///     assert_relative_eq!(m * x - x * sigma, b, epsilon = 1.0e-10);
+    /// }
+    /// ```
+    #[must_use = "Did you mean to use solve_shifted_mut()?"]
+    pub fn solve_shifted<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        sigma: T,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+
+        if self.solve_shifted_mut(&mut res, sigma) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the shifted linear system `(self - sigma * I) * x = b`, where `self` is the
+    /// decomposed matrix and `x` the unknown.
+    ///
+    /// If `self - sigma * I` is not invertible, this returns `false` and its input `b` may be
+    /// overwritten with garbage.
+    pub fn solve_shifted_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<T, R2, C2, S2>,
+        sigma: T,
+    ) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.hess.nrows(),
+            b.nrows(),
+            "Hessenberg solve matrix dimension mismatch."
+        );
+
+        let dim = self.hess.nrows();
+
+        // Apply `Q^*`.
+        for i in 0..dim - 1 {
+            let axis = self.hess.slice_range(i + 1.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
+            let sign = self.subdiag[i].clone().signum().conjugate();
+
+            refl.reflect_with_sign(&mut b.rows_range_mut(i + 1..), sign);
+        }
+
+        // Reduce `H - sigma * I` to upper-triangular form.
+        let mut h = self.h();
+
+        for i in 0..dim {
+            h[(i, i)] -= sigma.clone();
+        }
+
+        for i in 0..dim - 1 {
+            let v = Vector2::new(h[(i, i)].clone(), h[(i + 1, i)].clone());
+
+            if let Some((rot, r)) = GivensRotation::cancel_y(&v) {
+                h[(i, i)] = r;
+                h[(i + 1, i)] = T::zero();
+                rot.rotate(&mut h.fixed_rows_mut::<2>(i).columns_range_mut(i + 1..));
+                rot.rotate(&mut b.fixed_rows_mut::<2>(i));
+            }
+        }
+
+        if !h.solve_upper_triangular_mut(b) {
+            return false;
+        }
+
+        // Apply `Q`.
+        for i in (0..dim - 1).rev() {
+            let axis = self.hess.slice_range(i + 1.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
+            let sign = self.subdiag[i].clone().signum();
+
+            refl.reflect_with_sign(&mut b.rows_range_mut(i + 1..), sign);
+        }
+
+        true
+    }
+
     #[doc(hidden)]
     pub fn hess_internal(&self) -> &OMatrix<T, D, D> {
         &self.hess
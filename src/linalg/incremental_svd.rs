@@ -0,0 +1,204 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use simba::scalar::ComplexField;
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, DVector, Matrix, Vector};
+
+/// A thin singular value decomposition updated incrementally as new columns are appended to the
+/// decomposed matrix.
+///
+/// This implements Brand's algorithm: appending a column only requires the SVD of a small
+/// `(r + 1) × (r + 1)` matrix, where `r` is the current rank, instead of a full recomputation. The
+/// rank is bounded by `max_rank`, the decomposition being truncated to the largest singular values
+/// after each update, which makes it suitable for online subspace tracking.
+///
+/// Because of the truncation and the accumulation of rounding errors, the decomposition is an
+/// approximation of the SVD of the matrix formed by all the appended columns.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{DMatrix, IncrementalSVD};
+/// let m = DMatrix::from_fn(6, 10, |i, j| ((i + 1) * (j % 3 + 1)) as f64 + (j / 3) as f64);
+/// let mut svd = IncrementalSVD::new(6, 6, 1.0e-10);
+///
+/// for column in m.column_iter() {
+///     svd.push_column(&column);
+/// }
+///
+/// assert_eq!(svd.ncols(), 10);
+/// assert_eq!(svd.rank(), 2);
+/// assert_relative_eq!(svd.recompose(), m, epsilon = 1.0e-10);
+/// ```
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(
+        serialize = "T: Serialize, T::RealField: Serialize",
+        deserialize = "T: Deserialize<'de>, T::RealField: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct IncrementalSVD<T: ComplexField> {
+    u: DMatrix<T>,
+    singular_values: DVector<T::RealField>,
+    v_t: DMatrix<T>,
+    max_rank: usize,
+    eps: T::RealField,
+}
+
+impl<T: ComplexField> IncrementalSVD<T> {
+    /// Initializes the decomposition of a matrix with `nrows` rows and no column.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_rank` − the maximum number of singular values and vectors kept.
+    /// * `eps`       − the relative tolerance under which the component of a new column that is
+    ///   orthogonal to the current left-singular vectors is considered zero.
+    ///
+    /// # Panics
+    /// Panics if `max_rank` is zero.
+    pub fn new(nrows: usize, max_rank: usize, eps: T::RealField) -> Self {
+        assert!(
+            max_rank > 0,
+            "Incremental SVD: the maximum rank must be positive."
+        );
+
+        Self {
+            u: DMatrix::zeros(nrows, 0),
+            singular_values: DVector::zeros(0),
+            v_t: DMatrix::zeros(0, 0),
+            max_rank,
+            eps,
+        }
+    }
+
+    /// The number of rows of the decomposed matrix.
+    #[inline]
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.u.nrows()
+    }
+
+    /// The number of columns of the decomposed matrix, i.e., the number of appended columns.
+    #[inline]
+    #[must_use]
+    pub fn ncols(&self) -> usize {
+        self.v_t.ncols()
+    }
+
+    /// The current rank `r` of the decomposition, i.e., its number of singular values.
+    #[inline]
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.singular_values.len()
+    }
+
+    /// The `nrows × r` matrix of the left-singular vectors.
+    #[inline]
+    #[must_use]
+    pub fn u(&self) -> &DMatrix<T> {
+        &self.u
+    }
+
+    /// The singular values, sorted in decreasing order.
+    #[inline]
+    #[must_use]
+    pub fn singular_values(&self) -> &DVector<T::RealField> {
+        &self.singular_values
+    }
+
+    /// The `r × ncols` matrix of the right-singular vectors `V^t`.
+    #[inline]
+    #[must_use]
+    pub fn v_t(&self) -> &DMatrix<T> {
+        &self.v_t
+    }
+
+    /// Rebuilds the (approximation of the) decomposed matrix.
+    #[must_use]
+    pub fn recompose(&self) -> DMatrix<T> {
+        let mut u = self.u.clone();
+
+        for i in 0..self.rank() {
+            u.column_mut(i).scale_mut(self.singular_values[i].clone());
+        }
+
+        u * &self.v_t
+    }
+
+    /// Updates the decomposition after the columns of `columns` are appended, in order, to the
+    /// decomposed matrix.
+    ///
+    /// # Panics
+    /// Panics if the number of rows of `columns` differs from the number of rows of the decomposed
+    /// matrix.
+    pub fn push_columns<R: Dim, C: Dim, S: Storage<T, R, C>>(
+        &mut self,
+        columns: &Matrix<T, R, C, S>,
+    ) {
+        for column in columns.column_iter() {
+            self.push_column(&column);
+        }
+    }
+
+    /// Updates the decomposition after the column `column` is appended to the decomposed matrix.
+    ///
+    /// # Panics
+    /// Panics if the length of `column` differs from the number of rows of the decomposed matrix.
+    pub fn push_column<D: Dim, S: Storage<T, D>>(&mut self, column: &Vector<T, D, S>) {
+        assert_eq!(
+            column.len(),
+            self.nrows(),
+            "Incremental SVD: the column length must match the number of rows."
+        );
+
+        let column = DVector::from_iterator(column.len(), column.iter().cloned());
+        let rank = self.rank();
+        let ncols = self.ncols();
+
+        // Split the column into its components inside and orthogonal to span(U).
+        let proj = self.u.ad_mul(&column);
+        let residual = &column - &self.u * &proj;
+        let residual_norm = residual.norm();
+        let grows = residual_norm > self.eps.clone() * column.norm();
+
+        if rank == 0 && !grows {
+            self.v_t = DMatrix::zeros(0, ncols + 1);
+            return;
+        }
+
+        // The decomposed matrix is `[U j] * K * [[V, 0], [0, 1]]^t`, where `j` is the normalized
+        // residual if it is not zero.
+        let k_nrows = if grows { rank + 1 } else { rank };
+        let mut k = DMatrix::zeros(k_nrows, rank + 1);
+
+        for i in 0..rank {
+            k[(i, i)] = T::from_real(self.singular_values[i].clone());
+            k[(i, rank)] = proj[i].clone();
+        }
+
+        let mut basis = self.u.clone();
+
+        if grows {
+            k[(rank, rank)] = T::from_real(residual_norm.clone());
+            basis = basis.insert_column(rank, T::zero());
+            basis
+                .column_mut(rank)
+                .copy_from(&residual.unscale(residual_norm));
+        }
+
+        let new_rank = k_nrows.min(self.max_rank);
+        let (k_u, singular_values, k_v_t) = k.svd(true, true).truncated(new_rank).unwrap();
+
+        let mut v_t = self.v_t.clone().resize(rank + 1, ncols + 1, T::zero());
+        v_t[(rank, ncols)] = T::one();
+
+        self.u = basis * k_u;
+        self.singular_values = singular_values;
+        self.v_t = k_v_t * v_t;
+    }
+}
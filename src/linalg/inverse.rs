@@ -1,7 +1,23 @@
 use simba::scalar::ComplexField;
+#[cfg(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+use std::any::TypeId;
 
 use crate::base::allocator::Allocator;
 use crate::base::dimension::Dim;
+#[cfg(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+use crate::base::matrix4_simd;
 use crate::base::storage::{Storage, StorageMut};
 use crate::base::{DefaultAllocator, OMatrix, SquareMatrix};
 
@@ -115,6 +131,19 @@ impl<T: ComplexField, D: Dim, S: StorageMut<T, D, D>> SquareMatrix<T, D, S> {
                     }
                 }
                 4 => {
+                    #[cfg(any(
+                        all(
+                            any(target_arch = "x86", target_arch = "x86_64"),
+                            target_feature = "sse"
+                        ),
+                        all(target_arch = "aarch64", target_feature = "neon")
+                    ))]
+                    {
+                        if TypeId::of::<T>() == TypeId::of::<f32>() {
+                            return try_inverse4_f32(self);
+                        }
+                    }
+
                     let oself = self.clone_owned();
                     do_inverse4(&oself, self)
                 }
@@ -127,6 +156,39 @@ impl<T: ComplexField, D: Dim, S: StorageMut<T, D, D>> SquareMatrix<T, D, S> {
     }
 }
 
+// Inverts a 4×4 single-precision matrix with the explicitly vectorized `matrix4_simd::inverse4`.
+//
+// # Safety
+// `T` must be `f32`.
+#[cfg(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+unsafe fn try_inverse4_f32<T: ComplexField, D: Dim, S: StorageMut<T, D, D>>(
+    m: &mut SquareMatrix<T, D, S>,
+) -> bool {
+    let mut components = [0.0f32; 16];
+    for j in 0..4 {
+        for i in 0..4 {
+            components[i + j * 4] = *(m.get_unchecked((i, j)) as *const T as *const f32);
+        }
+    }
+
+    if let Some(inverse) = matrix4_simd::inverse4(&components) {
+        for j in 0..4 {
+            for i in 0..4 {
+                *(m.get_unchecked_mut((i, j)) as *mut T as *mut f32) = inverse[i + j * 4];
+            }
+        }
+        true
+    } else {
+        false
+    }
+}
+
 // NOTE: this is an extremely efficient, loop-unrolled matrix inverse from MESA (MIT licensed).
 fn do_inverse4<T: ComplexField, D: Dim, S: StorageMut<T, D, D>>(
     m: &OMatrix<T, D, D>,
@@ -0,0 +1,353 @@
+//! Weighted, equality-constrained, and regularized linear least squares.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num::Zero;
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::{Dim, DimMin, DimMinimum};
+use crate::base::storage::Storage;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Vector};
+use crate::linalg::QR;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::linalg::SVD;
+
+impl<T: ComplexField, R: DimMin<C>, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
+where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, R> + Allocator<T, DimMinimum<R, C>>,
+{
+    /// Computes the weighted least-squares solution of `self * x = b`, i.e., the `x` minimizing
+    /// `Σ w_i |(self * x - b)_i|²`, where `w_i` is the non-negative weight of the `i`-th row.
+    ///
+    /// The rows of `self` and `b` are scaled by the square roots of their weights before being
+    /// solved with a QR decomposition. Returns `None` if the scaled matrix does not have full
+    /// column rank, e.g., if too many weights are zero. If `b` has several columns, each of them
+    /// is solved with the same weights.
+    ///
+    /// # Panics
+    /// Panics if `self` has fewer rows than columns, if `b` or `weights` does not have as many
+    /// rows as `self`, or if a weight is negative.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, Vector2, Vector3};
+    /// let m = Matrix3x2::new(1.0, 0.0,
+    ///                        1.0, 1.0,
+    ///                        1.0, 2.0);
+    /// let y = Vector3::new(1.0, 2.0, 4.0);
+    ///
+    /// // A zero weight ignores the last row, so the line passes through the first two points.
+    /// let x = m.solve_weighted_least_squares(&y, &Vector3::new(1.0, 1.0, 0.0)).unwrap();
+    /// assert_relative_eq!(x, Vector2::new(1.0, 1.0), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn solve_weighted_least_squares<R2, C2, S2, R3, S3>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        weights: &Vector<T::RealField, R3, S3>,
+    ) -> Option<OMatrix<T, C, C2>>
+    where
+        R2: Dim,
+        C2: Dim,
+        R3: Dim,
+        S2: Storage<T, R2, C2>,
+        S3: Storage<T::RealField, R3>,
+        ShapeConstraint: SameNumberOfRows<R2, R> + SameNumberOfRows<R3, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
+    {
+        assert_eq!(
+            self.nrows(),
+            b.nrows(),
+            "Weighted least squares: matrix dimension mismatch."
+        );
+        assert_eq!(
+            self.nrows(),
+            weights.len(),
+            "Weighted least squares: there must be one weight per row."
+        );
+        assert!(
+            weights.iter().all(|w| *w >= T::RealField::zero()),
+            "Weighted least squares: the weights must not be negative."
+        );
+
+        let mut m = self.clone_owned();
+        let mut b = b.clone_owned();
+
+        for (i, w) in weights.iter().enumerate() {
+            let scale = w.clone().sqrt();
+            m.row_mut(i).scale_mut(scale.clone());
+            b.row_mut(i).scale_mut(scale);
+        }
+
+        QR::new(m).solve_least_squares(&b)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// Computes the least-squares solution of `self * x = b` subject to the linear equality
+    /// constraints `c * x = d`, i.e., the `x` minimizing the norm of `self * x - b` among the
+    /// solutions of `c * x = d`.
+    ///
+    /// This uses the null-space method: with the QR decomposition `cᴴ = Q * R`, the constraints
+    /// fix the first components of `Qᴴ * x`, and the remaining ones are the solution of an
+    /// unconstrained least-squares problem. Returns `None` if the solution is not unique, i.e.,
+    /// if `c` does not have full row rank, or if the matrix stacking `self` on top of `c` does not
+    /// have full column rank. Nearly rank-deficient matrices are not detected.
+    ///
+    /// # Panics
+    /// Panics if the dimensions of `self`, `b`, `c`, and `d` are inconsistent, or if `c` has more
+    /// rows than columns.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix1x2, Matrix3x2, Vector1, Vector3};
+    /// let m = Matrix3x2::new(1.0, 0.0,
+    ///                        1.0, 1.0,
+    ///                        1.0, 2.0);
+    /// let y = Vector3::new(1.0, 2.0, 4.0);
+    ///
+    /// // Fit a line through the three points, constrained to pass through the first one.
+    /// let c = Matrix1x2::new(1.0, 0.0);
+    /// let d = Vector1::new(1.0);
+    /// let x = m.solve_constrained_least_squares(&y, &c, &d).unwrap();
+    ///
+    /// assert_relative_eq!(c * x, d, epsilon = 1.0e-10);
+    /// assert_relative_eq!(x[1], 1.4, epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn solve_constrained_least_squares<R2, C2, S2, R3, C3, S3, R4, C4, S4>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        c: &Matrix<T, R3, C3, S3>,
+        d: &Matrix<T, R4, C4, S4>,
+    ) -> Option<OMatrix<T, C, C2>>
+    where
+        R2: Dim,
+        C2: Dim,
+        R3: Dim,
+        C3: Dim,
+        R4: Dim,
+        C4: Dim,
+        S2: Storage<T, R2, C2>,
+        S3: Storage<T, R3, C3>,
+        S4: Storage<T, R4, C4>,
+        DefaultAllocator: Allocator<T, C, C2>,
+    {
+        let (nrows, ncols) = self.shape();
+        let nconstraints = c.nrows();
+        let nrhs = b.ncols();
+
+        assert!(
+            b.nrows() == nrows && c.ncols() == ncols && d.shape() == (nconstraints, nrhs),
+            "Constrained least squares: matrix dimension mismatch."
+        );
+        assert!(
+            nconstraints <= ncols,
+            "Constrained least squares: there must not be more constraints than unknowns."
+        );
+
+        let a_ad = DMatrix::from_fn(ncols, nrows, |i, j| self[(j, i)].clone().conjugate());
+        let b_dyn = DMatrix::from_fn(nrows, nrhs, |i, j| b[(i, j)].clone());
+        let c_ad = DMatrix::from_fn(ncols, nconstraints, |i, j| c[(j, i)].clone().conjugate());
+        let d_dyn = DMatrix::from_fn(nconstraints, nrhs, |i, j| d[(i, j)].clone());
+        let x = constrained_least_squares(a_ad, b_dyn, c_ad, d_dyn)?;
+
+        Some(OMatrix::from_iterator_generic(
+            self.shape_generic().1,
+            b.shape_generic().1,
+            x.iter().cloned(),
+        ))
+    }
+
+    /// Computes the ridge (or Tikhonov) regularized least-squares solution of `self * x = b`,
+    /// i.e., the `x` minimizing `‖self * x - b‖² + lambda * ‖x‖²`.
+    ///
+    /// This is the solution of `(selfᴴ * self + lambda * I) * x = selfᴴ * b`, computed from the
+    /// SVD of `self` with the filter factors `σ / (σ² + lambda)` instead of forming the normal
+    /// equations, which would square the condition number of `self`. If `lambda` is zero, this is
+    /// the minimum-norm least-squares solution. Use `solve_ridge_path` to compute the solutions
+    /// for several values of `lambda` with a single decomposition.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many rows as `self`, or if `lambda` is negative.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix3x2, Vector3};
+    /// let m = Matrix3x2::new(1.0, 0.0,
+    ///                        1.0, 1.0,
+    ///                        1.0, 2.0);
+    /// let y = Vector3::new(1.0, 2.0, 4.0);
+    /// let x = m.solve_ridge(&y, 0.5);
+    ///
+    /// let lhs = m.transpose() * m + Matrix2::identity() * 0.5;
+    /// assert_relative_eq!(lhs * x, m.transpose() * y, epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn solve_ridge<R2, C2, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        lambda: T::RealField,
+    ) -> OMatrix<T, C, C2>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, C, C2>,
+    {
+        self.solve_ridge_path(b, &[lambda])
+            .pop()
+            .expect("Ridge regression: a solution must have been computed.")
+    }
+
+    /// Computes the ridge regularized least-squares solutions of `self * x = b` for each of the
+    /// given regularization parameters, i.e., the regularization path.
+    ///
+    /// The `i`-th returned matrix is `self.solve_ridge(b, lambdas[i])`, but the SVD of `self` is
+    /// only computed once, so that each additional solution only costs a few matrix products.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many rows as `self`, or if one of the `lambdas` is
+    /// negative.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, Vector3};
+    /// let m = Matrix3x2::new(1.0, 0.0,
+    ///                        1.0, 1.0,
+    ///                        1.0, 2.0);
+    /// let y = Vector3::new(1.0, 2.0, 4.0);
+    /// let path = m.solve_ridge_path(&y, &[0.0, 0.1, 1.0, 10.0]);
+    ///
+    /// // The norm of the solution decreases as the regularization increases.
+    /// assert!(path.windows(2).all(|w| w[1].norm() < w[0].norm()));
+    /// assert_relative_eq!(path[2], m.solve_ridge(&y, 1.0));
+    /// ```
+    #[must_use]
+    pub fn solve_ridge_path<R2, C2, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+        lambdas: &[T::RealField],
+    ) -> Vec<OMatrix<T, C, C2>>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, C, C2>,
+    {
+        let (nrows, ncols) = self.shape();
+        assert_eq!(
+            nrows,
+            b.nrows(),
+            "Ridge regression: matrix dimension mismatch."
+        );
+        assert!(
+            lambdas.iter().all(|l| *l >= T::RealField::zero()),
+            "Ridge regression: the regularization parameters must not be negative."
+        );
+
+        let a = DMatrix::from_fn(nrows, ncols, |i, j| self[(i, j)].clone());
+        let b_dyn = DMatrix::from_fn(nrows, b.ncols(), |i, j| b[(i, j)].clone());
+
+        ridge_path(a, b_dyn, lambdas)
+            .into_iter()
+            .map(|x| {
+                OMatrix::from_iterator_generic(
+                    self.shape_generic().1,
+                    b.shape_generic().1,
+                    x.iter().cloned(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Solves `min ‖a * x - b‖` subject to `c * x = d`, where `a_ad` and `c_ad` are the adjoints of
+/// `a` and `c`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn constrained_least_squares<T: ComplexField>(
+    mut a_ad: DMatrix<T>,
+    b: DMatrix<T>,
+    c_ad: DMatrix<T>,
+    d: DMatrix<T>,
+) -> Option<DMatrix<T>> {
+    let (ncols, nrows) = a_ad.shape();
+    let nconstraints = c_ad.ncols();
+    let free = ncols - nconstraints;
+
+    if nrows < free {
+        return None;
+    }
+
+    // With `cᴴ = Q * R` and `y = Qᴴ * x`, the constraints read `Rᴴ * y1 = d`, and the
+    // objective is the norm of `a * Q1 * y1 + a * Q2 * y2 - b`.
+    let qr = c_ad.qr();
+    let mut y = DMatrix::zeros(ncols, b.ncols());
+
+    if nconstraints != 0 {
+        let y1 = qr.r().ad_solve_upper_triangular(&d)?;
+        y.rows_mut(0, nconstraints).copy_from(&y1);
+    }
+
+    if free != 0 {
+        // `a * Q`, computed as `(Qᴴ * aᴴ)ᴴ`.
+        qr.q_tr_mul(&mut a_ad);
+        let aq = a_ad.adjoint();
+        let rhs = b - aq.columns(0, nconstraints) * y.rows(0, nconstraints);
+        let y2 = aq
+            .columns(nconstraints, free)
+            .into_owned()
+            .qr()
+            .solve_least_squares(&rhs)?;
+        y.rows_mut(nconstraints, free).copy_from(&y2);
+    }
+
+    qr.q_mul(&mut y);
+    Some(y)
+}
+
+/// Computes `V * diag(σ / (σ² + λ)) * Uᴴ * b` for each `λ`, where `a = U * diag(σ) * Vᴴ`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn ridge_path<T: ComplexField>(
+    a: DMatrix<T>,
+    b: DMatrix<T>,
+    lambdas: &[T::RealField],
+) -> Vec<DMatrix<T>> {
+    let svd = SVD::new(a, true, true);
+    let u = svd.u.expect("Ridge regression: U has not been computed.");
+    let v_t = svd
+        .v_t
+        .expect("Ridge regression: V^T has not been computed.");
+    let singular_values = svd.singular_values;
+    let ut_b = u.ad_mul(&b);
+
+    lambdas
+        .iter()
+        .map(|lambda| {
+            let mut filtered = ut_b.clone();
+
+            for (i, sigma) in singular_values.iter().enumerate() {
+                let denom = sigma.clone() * sigma.clone() + lambda.clone();
+                let factor = if denom.is_zero() {
+                    T::RealField::zero()
+                } else {
+                    sigma.clone() / denom
+                };
+
+                filtered.row_mut(i).scale_mut(factor);
+            }
+
+            v_t.ad_mul(&filtered)
+        })
+        .collect()
+}
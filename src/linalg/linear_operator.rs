@@ -0,0 +1,310 @@
+//! Matrix-free linear operators.
+
+use simba::scalar::ComplexField;
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, Matrix, Scalar};
+
+/// A linear map from `T^ncols` to `T^nrows` which is only accessed through its products with
+/// dense matrices.
+///
+/// Algorithms which only need such products, e.g., `DominantEigen::try_new_with_operator`, are
+/// generic over this trait, so they also work on operators which are too large or too structured
+/// to be stored as dense matrices. This trait is implemented by:
+///
+/// * dense matrices, and references to any linear operator,
+/// * the sparse matrices of the `nalgebra-sparse` crate,
+/// * user closures, with `FnOperator`,
+/// * compositions of linear operators, with `ScaledOperator`, `SumOperator`, and
+///   `ProductOperator`.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{DMatrix, FnOperator, LinearOperator, ProductOperator, SumOperator};
+/// let a = DMatrix::from_fn(4, 3, |i, j| (i * 3 + j) as f64);
+/// let b = DMatrix::from_fn(3, 4, |i, j| i as f64 - j as f64);
+///
+/// // The operator `A * B + I`, which is never materialized.
+/// let identity = FnOperator::new_hermitian(4, |x: &DMatrix<f64>| x.clone());
+/// let op = SumOperator::new(ProductOperator::new(&a, &b), identity);
+/// let x = DMatrix::from_fn(4, 2, |i, j| (i + j) as f64);
+///
+/// let expected = &a * &b + DMatrix::identity(4, 4);
+/// assert_eq!(op.shape(), (4, 4));
+/// assert_relative_eq!(op.apply(&x), &expected * &x);
+/// assert_relative_eq!(op.apply_adjoint(&x), expected.transpose() * &x);
+/// ```
+pub trait LinearOperator<T: Scalar> {
+    /// The dimension of the codomain of this operator, i.e., its number of rows.
+    fn nrows(&self) -> usize;
+
+    /// The dimension of the domain of this operator, i.e., its number of columns.
+    fn ncols(&self) -> usize;
+
+    /// The shape `(nrows, ncols)` of this operator.
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    /// Computes the product of this operator with the `ncols × k` matrix `x`.
+    ///
+    /// # Panics
+    /// May panic if `x` does not have `ncols` rows.
+    fn apply(&self, x: &DMatrix<T>) -> DMatrix<T>;
+
+    /// Computes the product of the adjoint of this operator with the `nrows × k` matrix `x`.
+    ///
+    /// # Panics
+    /// May panic if `x` does not have `nrows` rows.
+    fn apply_adjoint(&self, x: &DMatrix<T>) -> DMatrix<T>;
+}
+
+impl<T: Scalar, O: LinearOperator<T> + ?Sized> LinearOperator<T> for &O {
+    #[inline]
+    fn nrows(&self) -> usize {
+        (**self).nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        (**self).ncols()
+    }
+
+    #[inline]
+    fn apply(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        (**self).apply(x)
+    }
+
+    #[inline]
+    fn apply_adjoint(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        (**self).apply_adjoint(x)
+    }
+}
+
+impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> LinearOperator<T>
+    for Matrix<T, R, C, S>
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        Matrix::nrows(self)
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        Matrix::ncols(self)
+    }
+
+    #[inline]
+    fn apply(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        let m = self.slice((0, 0), Matrix::shape(self));
+        &m * x
+    }
+
+    #[inline]
+    fn apply_adjoint(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        let m = self.slice((0, 0), Matrix::shape(self));
+        m.ad_mul(x)
+    }
+}
+
+/// The linear operator `alpha * op`, where `alpha` is a scalar.
+#[derive(Clone, Debug)]
+pub struct ScaledOperator<T, O> {
+    alpha: T,
+    op: O,
+}
+
+impl<T, O> ScaledOperator<T, O> {
+    /// Creates the linear operator `alpha * op`.
+    #[inline]
+    pub fn new(alpha: T, op: O) -> Self {
+        Self { alpha, op }
+    }
+}
+
+impl<T: ComplexField, O: LinearOperator<T>> LinearOperator<T> for ScaledOperator<T, O> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.op.nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.op.ncols()
+    }
+
+    #[inline]
+    fn apply(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        self.op.apply(x) * self.alpha.clone()
+    }
+
+    #[inline]
+    fn apply_adjoint(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        self.op.apply_adjoint(x) * self.alpha.clone().conjugate()
+    }
+}
+
+/// The linear operator `a + b`, where `a` and `b` are linear operators with the same shape.
+#[derive(Clone, Debug)]
+pub struct SumOperator<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> SumOperator<A, B> {
+    /// Creates the linear operator `a + b`.
+    ///
+    /// # Panics
+    /// Panics if `a` and `b` do not have the same shape.
+    #[inline]
+    pub fn new<T: Scalar>(a: A, b: B) -> Self
+    where
+        A: LinearOperator<T>,
+        B: LinearOperator<T>,
+    {
+        assert_eq!(
+            a.shape(),
+            b.shape(),
+            "Sum operator: the operands must have the same shape."
+        );
+        Self { a, b }
+    }
+}
+
+impl<T: ComplexField, A: LinearOperator<T>, B: LinearOperator<T>> LinearOperator<T>
+    for SumOperator<A, B>
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.a.nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.a.ncols()
+    }
+
+    #[inline]
+    fn apply(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        self.a.apply(x) + self.b.apply(x)
+    }
+
+    #[inline]
+    fn apply_adjoint(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        self.a.apply_adjoint(x) + self.b.apply_adjoint(x)
+    }
+}
+
+/// The linear operator `a * b`, where the number of columns of `a` is the number of rows of `b`.
+#[derive(Clone, Debug)]
+pub struct ProductOperator<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> ProductOperator<A, B> {
+    /// Creates the linear operator `a * b`.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `a` differs from the number of rows of `b`.
+    #[inline]
+    pub fn new<T: Scalar>(a: A, b: B) -> Self
+    where
+        A: LinearOperator<T>,
+        B: LinearOperator<T>,
+    {
+        assert_eq!(
+            a.ncols(),
+            b.nrows(),
+            "Product operator: the operands have incompatible shapes."
+        );
+        Self { a, b }
+    }
+}
+
+impl<T: Scalar, A: LinearOperator<T>, B: LinearOperator<T>> LinearOperator<T>
+    for ProductOperator<A, B>
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.a.nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.b.ncols()
+    }
+
+    #[inline]
+    fn apply(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        self.a.apply(&self.b.apply(x))
+    }
+
+    #[inline]
+    fn apply_adjoint(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        self.b.apply_adjoint(&self.a.apply_adjoint(x))
+    }
+}
+
+/// A linear operator defined by closures computing its products, and the products of its
+/// adjoint, with dense matrices.
+#[derive(Clone, Debug)]
+pub struct FnOperator<F, G> {
+    nrows: usize,
+    ncols: usize,
+    apply: F,
+    apply_adjoint: G,
+}
+
+impl<F, G> FnOperator<F, G> {
+    /// Creates the `nrows × ncols` linear operator whose products with a matrix `x` are computed
+    /// by `apply(x)`, and whose adjoint's products are computed by `apply_adjoint(x)`.
+    #[inline]
+    pub fn new(nrows: usize, ncols: usize, apply: F, apply_adjoint: G) -> Self {
+        Self {
+            nrows,
+            ncols,
+            apply,
+            apply_adjoint,
+        }
+    }
+}
+
+impl<F: Clone> FnOperator<F, F> {
+    /// Creates the hermitian `dim × dim` linear operator whose products with a matrix `x` are
+    /// computed by `apply(x)`.
+    #[inline]
+    pub fn new_hermitian(dim: usize, apply: F) -> Self {
+        Self::new(dim, dim, apply.clone(), apply)
+    }
+}
+
+impl<T, F, G> LinearOperator<T> for FnOperator<F, G>
+where
+    T: Scalar,
+    F: Fn(&DMatrix<T>) -> DMatrix<T>,
+    G: Fn(&DMatrix<T>) -> DMatrix<T>,
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    fn apply(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        (self.apply)(x)
+    }
+
+    #[inline]
+    fn apply_adjoint(&self, x: &DMatrix<T>) -> DMatrix<T> {
+        (self.apply_adjoint)(x)
+    }
+}
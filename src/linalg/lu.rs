@@ -9,7 +9,11 @@ use crate::storage::{Storage, StorageMut};
 use simba::scalar::{ComplexField, Field};
 use std::mem;
 
-use crate::linalg::PermutationSequence;
+use crate::linalg::{Equilibration, PermutationSequence};
+
+/// The number of columns of the panels of the blocked LU decomposition. Matrices with at most this
+/// many rows or columns are decomposed without blocking.
+const LU_BLOCK_SIZE: usize = 64;
 
 /// LU decomposition with partial (row) pivoting.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
@@ -89,31 +93,65 @@ where
     DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
 {
     /// Computes the LU decomposition with partial (row) pivoting of `matrix`.
+    ///
+    /// Large matrices are decomposed with a blocked right-looking algorithm: each panel of 64
+    /// columns is decomposed with gaussian elimination, and the rest of the matrix is updated
+    /// with a single matrix product per panel.
     pub fn new(mut matrix: OMatrix<T, R, C>) -> Self {
         let (nrows, ncols) = matrix.shape_generic();
         let min_nrows_ncols = nrows.min(ncols);
+        let min_dim = min_nrows_ncols.value();
+        let ncols = ncols.value();
 
         let mut p = PermutationSequence::identity_generic(min_nrows_ncols);
 
-        if min_nrows_ncols.value() == 0 {
+        if min_dim == 0 {
             return LU { lu: matrix, p };
         }
 
-        for i in 0..min_nrows_ncols.value() {
-            let piv = matrix.slice_range(i.., i).icamax() + i;
-            let diag = matrix[(piv, i)].clone();
+        // Small matrices are decomposed as a single panel spanning all the columns.
+        let block_size = if min_dim > LU_BLOCK_SIZE {
+            LU_BLOCK_SIZE
+        } else {
+            min_dim
+        };
 
-            if diag.is_zero() {
-                // No non-zero entries on this column.
-                continue;
+        for k in (0..min_dim).step_by(block_size) {
+            let panel_len = block_size.min(min_dim - k);
+            let panel_end = if block_size == min_dim {
+                ncols
+            } else {
+                k + panel_len
+            };
+
+            for i in k..k + panel_len {
+                let piv = matrix.slice_range(i.., i).icamax() + i;
+                let diag = matrix[(piv, i)].clone();
+
+                if diag.is_zero() {
+                    // No non-zero entries on this column.
+                    continue;
+                }
+
+                if piv != i {
+                    p.append_permutation(i, piv);
+                    matrix.columns_range_mut(..i).swap_rows(i, piv);
+                    matrix.columns_range_mut(panel_end..).swap_rows(i, piv);
+                    gauss_step_swap(&mut matrix.columns_range_mut(..panel_end), diag, i, piv);
+                } else {
+                    gauss_step(&mut matrix.columns_range_mut(..panel_end), diag, i);
+                }
             }
 
-            if piv != i {
-                p.append_permutation(i, piv);
-                matrix.columns_range_mut(..i).swap_rows(i, piv);
-                gauss_step_swap(&mut matrix, diag, i, piv);
-            } else {
-                gauss_step(&mut matrix, diag, i);
+            if panel_end < ncols {
+                // U12 = L11⁻¹ * A12 and A22 -= L21 * U12
+                let (panel, mut rest) = matrix.columns_range_pair_mut(k..panel_end, panel_end..);
+                let (mut u12, mut a22) = rest.rows_range_pair_mut(k..panel_end, panel_end..);
+                let l11 = panel.rows_range(k..panel_end);
+                let l21 = panel.rows_range(panel_end..);
+
+                let _ = l11.solve_lower_triangular_with_diag_mut(&mut u12, T::one());
+                a22.gemm(-T::one(), &l21, &u12, T::one());
             }
         }
 
@@ -207,6 +245,38 @@ where
 
         (p, l, u)
     }
+
+    /// Consumes this decomposition and returns its packed factors and row permutations.
+    ///
+    /// The strictly lower-triangular part of the returned matrix contains `L` (whose diagonal
+    /// elements are all equal to one) while its upper-triangular part contains `U`. The
+    /// decomposition can be rebuilt from these parts with `LU::from_parts`, e.g., after they
+    /// have been stored or sent to another process.
+    #[inline]
+    pub fn into_parts(self) -> (OMatrix<T, R, C>, PermutationSequence<DimMinimum<R, C>>) {
+        (self.lu, self.p)
+    }
+
+    /// Rebuilds a LU decomposition from the packed factors and row permutations returned by
+    /// `LU::into_parts`.
+    ///
+    /// No check is performed: the given parts must come from a previous LU decomposition.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3, LU};
+    /// let m = Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0);
+    /// let (lu, p) = m.lu().into_parts();
+    /// let decomp = LU::from_parts(lu, p);
+    /// let b = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_relative_eq!(m * decomp.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn from_parts(lu: OMatrix<T, R, C>, p: PermutationSequence<DimMinimum<R, C>>) -> Self {
+        LU { lu, p }
+    }
 }
 
 impl<T: ComplexField, D: DimMin<D, Output = D>> LU<T, D, D>
@@ -258,6 +328,143 @@ where
         self.lu.solve_upper_triangular_mut(b)
     }
 
+    /// Solves the linear system `self.transpose() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// This reuses the factors of `self` instead of decomposing its transpose. Returns `None` if
+    /// `self` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Matrix3::new(2.0, 1.0, 1.0,
+    ///                      4.0, -6.0, 0.0,
+    ///                      -2.0, 7.0, 2.0);
+    /// let b = Vector3::new(1.0, 2.0, 3.0);
+    /// let x = m.lu().tr_solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(m.transpose() * x, b, epsilon = 1.0e-10);
+    /// ```
+    #[must_use = "Did you mean to use tr_solve_mut()?"]
+    pub fn tr_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.tr_solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self.adjoint() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// This reuses the factors of `self` instead of decomposing its adjoint. Returns `None` if
+    /// `self` is not invertible.
+    #[must_use = "Did you mean to use ad_solve_mut()?"]
+    pub fn ad_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.ad_solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self.transpose() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn tr_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        self.xx_solve_mut(b, false)
+    }
+
+    /// Solves the linear system `self.adjoint() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
+    /// be overwritten with garbage.
+    pub fn ad_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        self.xx_solve_mut(b, true)
+    }
+
+    // Solves `(P⁻¹ * L * U)ᵀ * x = b`, or `(P⁻¹ * L * U)ᴴ * x = b` if `adjoint` is `true`.
+    fn xx_solve_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<T, R2, C2, S2>,
+        adjoint: bool,
+    ) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.lu.nrows(),
+            b.nrows(),
+            "LU solve matrix dimension mismatch."
+        );
+        assert!(
+            self.lu.is_square(),
+            "LU solve: unable to solve a non-square system."
+        );
+
+        let solved = if adjoint {
+            self.lu.ad_solve_upper_triangular_mut(b)
+        } else {
+            self.lu.tr_solve_upper_triangular_mut(b)
+        };
+
+        if !solved {
+            return false;
+        }
+
+        // Backward substitution with the transpose of the unit lower-triangular factor.
+        let dim = self.lu.nrows();
+
+        for k in 0..b.ncols() {
+            let mut b = b.column_mut(k);
+
+            for i in (0..dim).rev() {
+                let l = self.lu.slice_range(i + 1.., i);
+                let dot = if adjoint {
+                    l.dotc(&b.rows_range(i + 1..))
+                } else {
+                    l.dot(&b.rows_range(i + 1..))
+                };
+
+                b[i] -= dot;
+            }
+        }
+
+        self.p.inv_permute_rows(b);
+        true
+    }
+
     /// Computes the inverse of the decomposed matrix.
     ///
     /// Returns `None` if the matrix is not invertible.
@@ -330,6 +537,66 @@ where
     }
 }
 
+impl<T: ComplexField, D: DimMin<D, Output = D>> LU<T, D, D>
+where
+    DefaultAllocator:
+        Allocator<T, D, D> + Allocator<(usize, usize), D> + Allocator<T::RealField, D>,
+{
+    /// Computes the LU decomposition of `matrix` after equilibrating it.
+    ///
+    /// Returns the decomposition of `diag(r) * matrix * diag(c)` together with the scaling
+    /// factors `r` and `c`, which are needed to solve systems involving `matrix` with
+    /// `solve_equilibrated`. On badly scaled matrices, this is often much more accurate than
+    /// decomposing `matrix` directly.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3, LU};
+    /// let m = Matrix3::new(1.0e12, 2.0e12, 0.0,
+    ///                      1.0e-6, 3.0e-6, 1.0e-6,
+    ///                      0.0, 1.0, 4.0);
+    /// let (lu, equilibration) = LU::new_equilibrated(m);
+    ///
+    /// let x = Vector3::new(1.0, -2.0, 3.0);
+    /// let b = m * x;
+    /// let solution = lu.solve_equilibrated(&equilibration, &b).unwrap();
+    /// assert_relative_eq!(solution, x, epsilon = 1.0e-10);
+    /// ```
+    pub fn new_equilibrated(mut matrix: OMatrix<T, D, D>) -> (Self, Equilibration<T, D, D>) {
+        let equilibration = matrix.equilibrate();
+        equilibration.scale_matrix(&mut matrix);
+        (Self::new(matrix), equilibration)
+    }
+
+    /// Solves the linear system `matrix * x = b`, where `self` is the decomposition of the
+    /// equilibrated `matrix` computed by `new_equilibrated`.
+    ///
+    /// The right-hand side is scaled before solving the equilibrated system, and its solution is
+    /// unscaled to obtain `x`. Returns `None` if the decomposed matrix is not invertible.
+    #[must_use]
+    pub fn solve_equilibrated<R2: Dim, C2: Dim, S2>(
+        &self,
+        equilibration: &Equilibration<T, D, D>,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        equilibration.scale_rhs(&mut res);
+
+        if self.solve_mut(&mut res) {
+            equilibration.unscale_solution(&mut res);
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
 #[doc(hidden)]
 /// Executes one step of gaussian elimination on the i-th row and column of `matrix`. The diagonal
 /// element `matrix[(i, i)]` is provided as argument.
@@ -2,29 +2,43 @@
 
 pub mod balancing;
 mod bidiagonal;
+mod capabilities;
 mod cholesky;
 mod convolution;
 mod determinant;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod dominant_eigen;
 // TODO: this should not be needed. However, the exp uses
 // explicit float operations on `f32` and `f64`. We need to
 // get rid of these to allow exp to be used on a no-std context.
 mod col_piv_qr;
 mod decomposition;
+mod equilibration;
 #[cfg(feature = "std")]
 mod exp;
+mod finite_differences;
 mod full_piv_lu;
 pub mod givens;
 mod hessenberg;
 pub mod householder;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod incremental_svd;
 mod inverse;
+mod least_squares;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod linear_operator;
 mod lu;
+mod nearest_spd;
 mod permutation_sequence;
+mod polynomial;
 mod pow;
 mod qr;
 mod schur;
 mod solve;
 mod svd;
 mod symmetric_eigen;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod symmetric_eigen_dc;
 mod symmetric_tridiagonal;
 mod udu;
 
@@ -33,15 +47,26 @@ mod udu;
 // mod eigen;
 
 pub use self::bidiagonal::*;
+pub use self::capabilities::*;
 pub use self::cholesky::*;
 pub use self::col_piv_qr::*;
 pub use self::convolution::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::dominant_eigen::*;
+pub use self::equilibration::*;
 #[cfg(feature = "std")]
 pub use self::exp::*;
+pub use self::finite_differences::*;
 pub use self::full_piv_lu::*;
+pub use self::givens::GivensRotation;
 pub use self::hessenberg::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::incremental_svd::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::linear_operator::*;
 pub use self::lu::*;
 pub use self::permutation_sequence::*;
+pub use self::polynomial::*;
 pub use self::pow::*;
 pub use self::qr::*;
 pub use self::schur::*;
@@ -0,0 +1,85 @@
+//! Computation of the nearest symmetric positive-definite matrix.
+
+use approx::AbsDiffEq;
+use num::{One, Zero};
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{DimDiff, DimSub, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, OMatrix, SquareMatrix};
+
+impl<T: ComplexField, D: DimSub<U1>, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the symmetric (or hermitian) positive-definite matrix nearest to `self`, using
+    /// Higham's algorithm.
+    ///
+    /// The nearest positive-semidefinite matrix in the Frobenius norm is computed from the
+    /// eigendecomposition of the hermitian part of `self` by replacing its negative eigenvalues
+    /// with zeros. Its diagonal is then shifted by increasing multiples of the machine epsilon
+    /// until it passes `is_positive_definite`.
+    ///
+    /// This is typically used to repair covariance matrices which lost their positive
+    /// definiteness because of rounding errors. Returns `None` if `self` has non-finite
+    /// components.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// // A correlation matrix with a negative eigenvalue.
+    /// let m = Matrix3::new(1.0, 0.9, 0.7,
+    ///                      0.9, 1.0, 0.3,
+    ///                      0.7, 0.3, 1.0);
+    /// assert!(m.cholesky().is_none());
+    ///
+    /// let spd = m.nearest_spd().unwrap();
+    /// assert!(spd.is_symmetric(0.0));
+    /// assert!(spd.cholesky().is_some());
+    /// assert!((spd - m).norm() < 0.1);
+    /// ```
+    #[must_use]
+    pub fn nearest_spd(&self) -> Option<OMatrix<T, D, D>> {
+        if !self.iter().all(|e| e.is_finite()) {
+            return None;
+        }
+
+        if self.is_empty() {
+            return Some(self.clone_owned());
+        }
+
+        // Clamping the eigenvalues of the hermitian part `B` yields `(B + H) / 2`, where `H` is the
+        // hermitian factor of the polar decomposition of `B`.
+        let eigen = self.hermitian_part().symmetric_eigen();
+        let scale = eigen.eigenvalues.amax();
+        let mut res = eigen
+            .recompose_with(|e| RealField::max(e, T::RealField::zero()))
+            .hermitian_part();
+
+        let eps = if scale.is_zero() {
+            T::RealField::default_epsilon()
+        } else {
+            T::RealField::default_epsilon() * scale
+        };
+        let mut k = T::RealField::one();
+
+        while !res.has_definite_cholesky() {
+            let min_eigenvalue = res.symmetric_eigenvalues().min();
+            let shift = (RealField::max(-min_eigenvalue, T::RealField::zero()) + eps.clone())
+                * k.clone()
+                * k.clone();
+
+            for i in 0..res.nrows() {
+                res[(i, i)] += T::from_real(shift.clone());
+            }
+
+            k += T::RealField::one();
+        }
+
+        Some(res)
+    }
+}
@@ -0,0 +1,179 @@
+//! Characteristic polynomials, companion matrices, and polynomial roots.
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use num_complex::Complex as NumComplex;
+use simba::scalar::ComplexField;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use simba::scalar::RealField;
+
+use crate::allocator::Allocator;
+use crate::base::dimension::{Const, DimAdd, DimDiff, DimSub, DimSum, U1};
+use crate::base::storage::Storage;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::{DMatrix, DVector};
+use crate::base::{DefaultAllocator, Matrix, OVector, SquareMatrix};
+
+use crate::linalg::Hessenberg;
+
+impl<T: ComplexField, D: DimSub<U1> + DimAdd<U1>, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T, DimSum<D, U1>, DimSum<D, U1>>
+        + Allocator<T, DimSum<D, U1>>,
+{
+    /// Computes the coefficients of the characteristic polynomial `det(λI - self)` of this
+    /// matrix, by increasing degree.
+    ///
+    /// The polynomial is monic, so the last of the `n + 1` coefficients is always `1`. It is
+    /// obtained from the Hessenberg form of `self` with Hyman's recurrence, in `O(n³)` operations.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector4};
+    /// let m = Matrix3::new(2.0, 0.0, 0.0,
+    ///                      1.0, 3.0, 0.0,
+    ///                      4.0, 5.0, 4.0);
+    /// // (λ - 2) * (λ - 3) * (λ - 4) = λ³ - 9λ² + 26λ - 24
+    /// let p = m.characteristic_polynomial();
+    /// assert_relative_eq!(p, Vector4::new(-24.0, 26.0, -9.0, 1.0), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn characteristic_polynomial(&self) -> OVector<T, DimSum<D, U1>> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the characteristic polynomial of a non-square matrix."
+        );
+
+        let (nrows, ncols) = self.shape_generic();
+        let dim = nrows.value();
+        let ext_dim = nrows.add(Const::<1>);
+
+        if dim == 0 {
+            return Matrix::from_element_generic(ext_dim, Const::<1>, T::one());
+        }
+
+        let h = Hessenberg::new(self.clone_owned()).unpack_h();
+
+        // The column `k` contains the characteristic polynomial `p_k` of the leading `k × k`
+        // block of `h`, which satisfies:
+        // p_k = (λ - h_{k-1,k-1}) p_{k-1} - Σ_{i<k-1} h_{i,k-1} (Π_{i<j<k} h_{j,j-1}) p_i
+        let mut polys = Matrix::zeros_generic(ext_dim, ncols.add(Const::<1>));
+        polys[(0, 0)] = T::one();
+
+        for k in 1..=dim {
+            let diag = h[(k - 1, k - 1)].clone();
+
+            for d in 0..k {
+                let prev = polys[(d, k - 1)].clone();
+                polys[(d, k)] -= diag.clone() * prev.clone();
+                polys[(d + 1, k)] += prev;
+            }
+
+            let mut prod = T::one();
+
+            for i in (0..k - 1).rev() {
+                prod *= h[(i + 1, i)].clone();
+                let coeff = h[(i, k - 1)].clone() * prod.clone();
+
+                for d in 0..=i {
+                    let term = coeff.clone() * polys[(d, i)].clone();
+                    polys[(d, k)] -= term;
+                }
+            }
+        }
+
+        polys.column(dim).into_owned()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField> DMatrix<T> {
+    /// Builds the companion matrix of the polynomial with the given coefficients, sorted by
+    /// increasing degree.
+    ///
+    /// For a polynomial `p` of degree `n`, this is the `n × n` matrix with ones on its
+    /// subdiagonal and the coefficients of `-p / c_n` on its last column, where `c_n` is the
+    /// leading coefficient of `p`. Its characteristic polynomial is `p / c_n`, so its eigenvalues
+    /// are the roots of `p`.
+    ///
+    /// # Panics
+    /// Panics if `coeffs` is empty or if its last element is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix3};
+    /// // 2x³ - 4x² + 6x + 8
+    /// let c = DMatrix::companion_matrix(&[8.0, 6.0, -4.0, 2.0]);
+    ///
+    /// assert_eq!(c, Matrix3::new(0.0, 0.0, -4.0,
+    ///                            1.0, 0.0, -3.0,
+    ///                            0.0, 1.0, 2.0));
+    /// ```
+    #[must_use]
+    pub fn companion_matrix(coeffs: &[T]) -> Self {
+        let lead = coeffs
+            .last()
+            .expect("Companion matrix: the polynomial must have at least one coefficient.");
+        assert!(
+            !lead.is_zero(),
+            "Companion matrix: the leading coefficient must not be zero."
+        );
+
+        let dim = coeffs.len() - 1;
+        let mut res = Self::zeros(dim, dim);
+
+        for i in 0..dim {
+            if i + 1 < dim {
+                res[(i + 1, i)] = T::one();
+            }
+
+            res[(i, dim - 1)] = -coeffs[i].clone() / lead.clone();
+        }
+
+        res
+    }
+}
+
+/// Computes the complex roots of the real polynomial with the given coefficients, sorted by
+/// increasing degree.
+///
+/// The roots are the eigenvalues of the companion matrix of the polynomial, which is balanced
+/// first to improve their accuracy. Leading zero coefficients are ignored, so the number of
+/// returned roots is the degree of the polynomial, each root being repeated according to its
+/// multiplicity.
+///
+/// # Panics
+/// Panics if all the coefficients are zero.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{polynomial_roots, Complex};
+/// // x³ - x² + x - 1 = (x - 1) * (x² + 1)
+/// let mut roots = polynomial_roots(&[-1.0, 1.0, -1.0, 1.0, 0.0]);
+/// roots.as_mut_slice().sort_by(|a, b| a.im.partial_cmp(&b.im).unwrap());
+///
+/// assert_eq!(roots.len(), 3);
+/// assert_relative_eq!(roots[0], Complex::new(0.0, -1.0), epsilon = 1.0e-10);
+/// assert_relative_eq!(roots[1], Complex::new(1.0, 0.0), epsilon = 1.0e-10);
+/// assert_relative_eq!(roots[2], Complex::new(0.0, 1.0), epsilon = 1.0e-10);
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[must_use]
+pub fn polynomial_roots<T: RealField>(coeffs: &[T]) -> DVector<NumComplex<T>> {
+    let degree = coeffs
+        .iter()
+        .rposition(|c| !c.is_zero())
+        .expect("Polynomial roots: the zero polynomial has infinitely many roots.");
+
+    if degree == 0 {
+        return DVector::zeros(0);
+    }
+
+    let mut companion = DMatrix::companion_matrix(&coeffs[..=degree]);
+    let _ = crate::linalg::balancing::balance_parlett_reinsch(&mut companion);
+    companion.complex_eigenvalues()
+}
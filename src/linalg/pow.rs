@@ -1,13 +1,14 @@
 //! This module provides the matrix exponential (pow) function to square matrices.
 
+use std::mem;
 use std::ops::DivAssign;
 
 use crate::{
     allocator::Allocator,
     storage::{Storage, StorageMut},
-    DefaultAllocator, DimMin, Matrix, OMatrix,
+    DefaultAllocator, Dim, DimMin, Matrix, OMatrix, Scalar,
 };
-use num::PrimInt;
+use num::{CheckedAdd, CheckedMul, One, PrimInt, Zero};
 use simba::scalar::ComplexField;
 
 impl<T: ComplexField, D, S> Matrix<T, D, D, S>
@@ -35,28 +36,30 @@ where
             return false;
         }
 
-        let one = I::one();
         let two = I::from(2u8).unwrap();
 
-        // We use the buffer to hold the result of multiplier ^ 2, thus avoiding
+        // We use the buffer to hold the result of the products, thus avoiding
         // extra allocations.
         let mut multiplier = self.clone_owned();
         let mut buf = self.clone_owned();
+        self.fill_with_identity();
 
-        // Exponentiation by squares.
+        // Exponentiation by squares. Note that `e % two` is negative for odd
+        // negative exponents, and `e /= two` rounds towards zero.
         loop {
-            if e % two == one {
+            if e % two != zero {
                 self.mul_to(&multiplier, &mut buf);
                 self.copy_from(&buf);
             }
 
             e /= two;
-            multiplier.mul_to(&multiplier, &mut buf);
-            multiplier.copy_from(&buf);
 
             if e == zero {
                 return true;
             }
+
+            multiplier.mul_to(&multiplier, &mut buf);
+            multiplier.copy_from(&buf);
         }
     }
 }
@@ -81,3 +84,83 @@ where
         }
     }
 }
+
+impl<T, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S>
+where
+    T: Scalar + Zero + One + CheckedAdd + CheckedMul,
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    /// Raises this matrix of integers to the power `exp`, or returns `None` if an overflow
+    /// occurred while computing any of the intermediate products.
+    ///
+    /// Uses exponentiation by squares. With an adjacency matrix, the component `(i, j)` of the
+    /// result is the number of paths of length `exp` from the vertex `i` to the vertex `j`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2;
+    /// let fibonacci = Matrix2::new(1u64, 1,
+    ///                              1, 0);
+    ///
+    /// assert_eq!(fibonacci.checked_pow(10).unwrap()[(0, 1)], 55);
+    /// assert_eq!(fibonacci.checked_pow(92).unwrap()[(0, 0)], 12200160415121876738);
+    /// assert_eq!(fibonacci.checked_pow(93), None);
+    /// ```
+    #[must_use]
+    pub fn checked_pow(&self, mut exp: u32) -> Option<OMatrix<T, D, D>> {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = OMatrix::identity_generic(nrows, ncols);
+
+        if exp == 0 {
+            return Some(res);
+        }
+
+        // The buffer holds the result of the products, and is then swapped with the factor it
+        // replaces, thus avoiding extra allocations.
+        let mut multiplier = self.clone_owned();
+        let mut buf = self.clone_owned();
+
+        loop {
+            if exp % 2 == 1 {
+                checked_mul_to(&res, &multiplier, &mut buf)?;
+                mem::swap(&mut res, &mut buf);
+            }
+
+            exp /= 2;
+
+            if exp == 0 {
+                return Some(res);
+            }
+
+            checked_mul_to(&multiplier, &multiplier, &mut buf)?;
+            mem::swap(&mut multiplier, &mut buf);
+        }
+    }
+}
+
+/// Computes `out = a * b` for square matrices, or returns `None` on overflow.
+fn checked_mul_to<T, D: Dim>(
+    a: &OMatrix<T, D, D>,
+    b: &OMatrix<T, D, D>,
+    out: &mut OMatrix<T, D, D>,
+) -> Option<()>
+where
+    T: Scalar + Zero + CheckedAdd + CheckedMul,
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    let dim = a.nrows();
+
+    for j in 0..dim {
+        for i in 0..dim {
+            let mut acc = T::zero();
+
+            for k in 0..dim {
+                acc = acc.checked_add(&a[(i, k)].checked_mul(&b[(k, j)])?)?;
+            }
+
+            out[(i, j)] = acc;
+        }
+    }
+
+    Some(())
+}
@@ -142,6 +142,37 @@ where
         (self.q(), self.unpack_r())
     }
 
+    /// Consumes this decomposition and returns its packed representation.
+    ///
+    /// The first returned matrix contains the Householder reflection axes in its lower-triangular
+    /// part and the strictly upper-triangular part of `R`. The returned vector contains the
+    /// diagonal of `R`. The decomposition can be rebuilt from these parts with `QR::from_parts`,
+    /// e.g., after they have been stored or sent to another process.
+    #[inline]
+    pub fn into_parts(self) -> (OMatrix<T, R, C>, OVector<T, DimMinimum<R, C>>) {
+        (self.qr, self.diag)
+    }
+
+    /// Rebuilds a QR decomposition from the packed representation returned by `QR::into_parts`.
+    ///
+    /// No check is performed: the given parts must come from a previous QR decomposition.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3, QR};
+    /// let m = Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0);
+    /// let (qr, diag) = m.qr().into_parts();
+    /// let decomp = QR::from_parts(qr, diag);
+    /// let b = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_relative_eq!(m * decomp.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn from_parts(qr: OMatrix<T, R, C>, diag: OVector<T, DimMinimum<R, C>>) -> Self {
+        QR { qr, diag }
+    }
+
     #[doc(hidden)]
     pub fn qr_internal(&self) -> &OMatrix<T, R, C> {
         &self.qr
@@ -163,6 +194,93 @@ where
             refl.reflect_with_sign(&mut rhs_rows, self.diag[i].clone().signum().conjugate());
         }
     }
+
+    /// Multiplies the provided matrix by the `Q` matrix of this decomposition.
+    pub fn q_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let dim = self.diag.len();
+
+        for i in (0..dim).rev() {
+            let axis = self.qr.slice_range(i.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
+
+            let mut rhs_rows = rhs.rows_range_mut(i..);
+            refl.reflect_with_sign(&mut rhs_rows, self.diag[i].clone().signum());
+        }
+    }
+
+    /// Computes the least-squares solution of `self * x = b`, i.e., the `x` minimizing the norm
+    /// of the residual `self * x - b`.
+    ///
+    /// Returns `None` if the decomposed matrix does not have full column rank. Nearly
+    /// rank-deficient matrices are not detected, use `ColPivQR::solve_least_squares` with a
+    /// tolerance to solve rank-deficient systems.
+    ///
+    /// # Panics
+    /// Panics if the decomposed matrix has fewer rows than columns.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, Vector2, Vector3};
+    /// // Fit a line through three points.
+    /// let m = Matrix3x2::new(1.0, 0.0,
+    ///                        1.0, 1.0,
+    ///                        1.0, 2.0);
+    /// let y = Vector3::new(1.0, 2.0, 4.0);
+    ///
+    /// let x = m.qr().solve_least_squares(&y).unwrap();
+    /// assert_relative_eq!(m.transpose() * (m * x - y), Vector2::zeros(), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn solve_least_squares<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, C, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, R>,
+        DefaultAllocator: Allocator<T, R2, C2> + Allocator<T, C, C2>,
+    {
+        let (nrows, ncols) = self.qr.shape_generic();
+        assert!(
+            nrows.value() >= ncols.value(),
+            "QR least squares: the matrix must not have more columns than rows."
+        );
+        assert_eq!(
+            nrows.value(),
+            b.nrows(),
+            "QR least squares: matrix dimension mismatch."
+        );
+
+        let dim = ncols.value();
+        let mut qtb = b.clone_owned();
+        self.q_tr_mul(&mut qtb);
+
+        let mut x = Matrix::zeros_generic(ncols, b.shape_generic().1);
+        x.copy_from(&qtb.rows_generic(0, ncols));
+
+        for k in 0..x.ncols() {
+            let mut x = x.column_mut(k);
+
+            for i in (0..dim).rev() {
+                let diag = self.diag[i].clone().modulus();
+
+                if diag.is_zero() {
+                    return None;
+                }
+
+                let coeff = x[i].clone().unscale(diag);
+                x[i] = coeff.clone();
+                x.rows_range_mut(..i)
+                    .axpy(-coeff, &self.qr.slice_range(..i, i), T::one());
+            }
+        }
+
+        Some(x)
+    }
 }
 
 impl<T: ComplexField, D: DimMin<D, Output = D>> QR<T, D, D>
@@ -214,6 +332,142 @@ where
         self.solve_upper_triangular_mut(b)
     }
 
+    /// Solves the linear system `self.transpose() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// This reuses the factors of `self` instead of decomposing its transpose. Returns `None` if
+    /// `self` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Matrix3::new(2.0, 1.0, 1.0,
+    ///                      4.0, -6.0, 0.0,
+    ///                      -2.0, 7.0, 2.0);
+    /// let b = Vector3::new(1.0, 2.0, 3.0);
+    /// let x = m.qr().tr_solve(&b).unwrap();
+    ///
+    /// assert_relative_eq!(m.transpose() * x, b, epsilon = 1.0e-10);
+    /// ```
+    #[must_use = "Did you mean to use tr_solve_mut()?"]
+    pub fn tr_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+
+        if self.tr_solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self.adjoint() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// This reuses the factors of `self` instead of decomposing its adjoint. Returns `None` if
+    /// `self` is not invertible.
+    #[must_use = "Did you mean to use ad_solve_mut()?"]
+    pub fn ad_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+
+        if self.ad_solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves the linear system `self.transpose() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
+    /// overwritten with garbage.
+    pub fn tr_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        // `Aᵀ * x = b` is equivalent to `Aᴴ * conj(x) = conj(b)`.
+        b.conjugate_mut();
+        let res = self.ad_solve_mut(b);
+        b.conjugate_mut();
+        res
+    }
+
+    /// Solves the linear system `self.adjoint() * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
+    /// overwritten with garbage.
+    pub fn ad_solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.qr.nrows(),
+            b.nrows(),
+            "QR solve matrix dimension mismatch."
+        );
+        assert!(
+            self.qr.is_square(),
+            "QR solve: unable to solve a non-square system."
+        );
+
+        if !self.ad_solve_upper_triangular_mut(b) {
+            return false;
+        }
+
+        self.q_mul(b);
+        true
+    }
+
+    // Solves `Rᴴ * x = b` by forward substitution.
+    fn ad_solve_upper_triangular_mut<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &mut Matrix<T, R2, C2, S2>,
+    ) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let dim = self.qr.nrows();
+
+        for k in 0..b.ncols() {
+            let mut b = b.column_mut(k);
+
+            for i in 0..dim {
+                let diag = self.diag[i].clone().modulus();
+
+                if diag.is_zero() {
+                    return false;
+                }
+
+                let dot = self.qr.slice_range(..i, i).dotc(&b.rows_range(..i));
+                let coeff = (b[i].clone() - dot).unscale(diag);
+                b[i] = coeff;
+            }
+        }
+
+        true
+    }
+
     // TODO: duplicate code from the `solve` module.
     fn solve_upper_triangular_mut<R2: Dim, C2: Dim, S2>(
         &self,
@@ -3,19 +3,23 @@
 use serde::{Deserialize, Serialize};
 
 use approx::AbsDiffEq;
+use num::Zero;
 use num_complex::Complex as NumComplex;
 use simba::scalar::{ComplexField, RealField};
 use std::cmp;
+use std::ops::ControlFlow;
 
 use crate::allocator::Allocator;
 use crate::base::dimension::{Const, Dim, DimDiff, DimSub, Dynamic, U1, U2};
 use crate::base::storage::Storage;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::storage::StorageMut;
 use crate::base::{DefaultAllocator, OMatrix, OVector, SquareMatrix, Unit, Vector2, Vector3};
 
 use crate::geometry::Reflection;
 use crate::linalg::givens::GivensRotation;
 use crate::linalg::householder;
-use crate::linalg::Hessenberg;
+use crate::linalg::{Hessenberg, SchurCapable};
 use crate::{Matrix, UninitVector};
 use std::mem::MaybeUninit;
 
@@ -49,6 +53,109 @@ where
 {
 }
 
+/// The shifts used by the implicit QR iterations computing a Schur decomposition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SchurShift {
+    /// The Francis double shift, i.e., both eigenvalues of the trailing 2×2 block of the part of
+    /// the Hessenberg matrix that has not converged yet.
+    Francis,
+    /// The Wilkinson shift, i.e., the eigenvalue of the trailing 2×2 block which is the closest to
+    /// its last diagonal element, used twice.
+    ///
+    /// The Francis double shift is used instead when this block has complex eigenvalues.
+    Wilkinson,
+}
+
+/// Options of the implicit QR iterations computing a Schur decomposition.
+///
+/// The default options are the ones of `Schur::new`: Francis double shifts, no exceptional
+/// shifts, no aggressive early deflation, a tolerance of `T::default_epsilon()`, and no limit on
+/// the number of iterations. Exceptional shifts and aggressive early deflation may help matrices
+/// on which the iterations fail to converge.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SchurOptions<T> {
+    eps: T,
+    max_niter: usize,
+    shift: SchurShift,
+    exceptional_shift_period: usize,
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    aggressive_early_deflation: bool,
+}
+
+impl<T: RealField> Default for SchurOptions<T> {
+    fn default() -> Self {
+        Self {
+            eps: T::default_epsilon(),
+            max_niter: 0,
+            shift: SchurShift::Francis,
+            exceptional_shift_period: 0,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            aggressive_early_deflation: false,
+        }
+    }
+}
+
+impl<T: RealField> SchurOptions<T> {
+    /// The default options, see `SchurOptions`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the tolerance used to determine when a subdiagonal element converged to 0, relatively
+    /// to the moduli of its neighboring diagonal elements.
+    #[inline]
+    #[must_use]
+    pub fn eps(mut self, eps: T) -> Self {
+        self.eps = eps;
+        self
+    }
+
+    /// Sets the maximum total number of iterations, after which the decomposition fails. If
+    /// `max_niter == 0`, then the algorithm continues indefinitely until convergence.
+    #[inline]
+    #[must_use]
+    pub fn max_niter(mut self, max_niter: usize) -> Self {
+        self.max_niter = max_niter;
+        self
+    }
+
+    /// Sets the shifts of the implicit QR iterations.
+    #[inline]
+    #[must_use]
+    pub fn shift(mut self, shift: SchurShift) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    /// Sets the number of consecutive iterations without any deflation after which an exceptional
+    /// ad hoc shift is used instead of the regular ones, to break cycles in which the regular
+    /// shifts do not make progress. If `period == 0`, exceptional shifts are never used.
+    ///
+    /// LAPACK uses exceptional shifts every 10 iterations.
+    #[inline]
+    #[must_use]
+    pub fn exceptional_shift_period(mut self, period: usize) -> Self {
+        self.exceptional_shift_period = period;
+        self
+    }
+
+    /// Sets whether the aggressive early deflation is performed before each iteration on large
+    /// enough matrices.
+    ///
+    /// The Schur decomposition of a trailing window of the part of the Hessenberg matrix that has
+    /// not converged yet is computed, and its eigenvalues that are only weakly coupled with the
+    /// rest of the matrix are deflated, often well before the corresponding subdiagonal elements
+    /// become negligible.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline]
+    #[must_use]
+    pub fn aggressive_early_deflation(mut self, enabled: bool) -> Self {
+        self.aggressive_early_deflation = enabled;
+        self
+    }
+}
+
 impl<T: ComplexField, D: Dim> Schur<T, D>
 where
     D: DimSub<U1>, // For Hessenberg.
@@ -76,16 +183,71 @@ where
     pub fn try_new(m: OMatrix<T, D, D>, eps: T::RealField, max_niter: usize) -> Option<Self> {
         let mut work = Matrix::zeros_generic(m.shape_generic().0, Const::<1>);
 
-        Self::do_decompose(m, &mut work, eps, max_niter, true)
+        let options = SchurOptions::new().eps(eps).max_niter(max_niter);
+
+        Self::do_decompose(m, &mut work, &options, true, None)
+            .map(|(q, t)| Schur { q: q.unwrap(), t })
+    }
+
+    /// Attempts to compute the Schur decomposition of a square matrix with the given options of
+    /// the implicit QR iterations.
+    ///
+    /// Returns `None` if the maximum number of iterations of `options` is exceeded.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, Schur, SchurOptions};
+    /// // A cyclic permutation, on which the Francis double shifts do not make any progress.
+    /// let m = DMatrix::from_fn(6, 6, |i, j| if i == (j + 1) % 6 { 1.0 } else { 0.0 });
+    /// assert!(Schur::try_new(m.clone(), 1.0e-15, 100).is_none());
+    ///
+    /// let options = SchurOptions::new().max_niter(100).exceptional_shift_period(10);
+    /// let (q, t) = Schur::try_new_with_options(m.clone(), &options).unwrap().unpack();
+    /// assert_relative_eq!(&q * t * q.transpose(), m, epsilon = 1.0e-12);
+    /// ```
+    pub fn try_new_with_options(
+        m: OMatrix<T, D, D>,
+        options: &SchurOptions<T::RealField>,
+    ) -> Option<Self> {
+        let mut work = Matrix::zeros_generic(m.shape_generic().0, Const::<1>);
+
+        Self::do_decompose(m, &mut work, options, true, None)
+            .map(|(q, t)| Schur { q: q.unwrap(), t })
+    }
+
+    /// Attempts to compute the Schur decomposition of a square matrix, reporting the progress of
+    /// the iterations.
+    ///
+    /// After each iteration, `progress` is called with the number of iterations performed so far
+    /// and the norm of the subdiagonal of the part of the Hessenberg matrix that has not converged
+    /// yet. The computation is cancelled, and `None` is returned, as soon as `progress` returns
+    /// `ControlFlow::Break(())`.
+    ///
+    /// See `Schur::try_new` for the other arguments.
+    pub fn try_new_with<F>(
+        m: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+        mut progress: F,
+    ) -> Option<Self>
+    where
+        F: FnMut(usize, T::RealField) -> ControlFlow<()>,
+    {
+        let mut work = Matrix::zeros_generic(m.shape_generic().0, Const::<1>);
+
+        let options = SchurOptions::new().eps(eps).max_niter(max_niter);
+
+        Self::do_decompose(m, &mut work, &options, true, Some(&mut progress))
             .map(|(q, t)| Schur { q: q.unwrap(), t })
     }
 
     fn do_decompose(
         mut m: OMatrix<T, D, D>,
         work: &mut OVector<T, D>,
-        eps: T::RealField,
-        max_niter: usize,
+        options: &SchurOptions<T::RealField>,
         compute_q: bool,
+        mut progress: Option<&mut dyn FnMut(usize, T::RealField) -> ControlFlow<()>>,
     ) -> Option<(Option<OMatrix<T, D, D>>, OMatrix<T, D, D>)> {
         assert!(
             m.is_square(),
@@ -129,13 +291,25 @@ where
         }
 
         // Implicit double-shift QR method.
+        let eps = options.eps.clone();
         let mut niter = 0;
+        // Number of iterations since the last deflation.
+        let mut nstalled = 0;
         let (mut start, mut end) = Self::delimit_subproblem(&mut t, eps.clone(), dim.value() - 1);
 
         while end != start {
             let subdim = end - start + 1;
 
-            if subdim > 2 {
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            let deflated = options.aggressive_early_deflation
+                && subdim > 2 * AED_WINDOW
+                && aggressive_early_deflation(&mut t, q.as_mut(), end, eps.clone());
+            #[cfg(not(any(feature = "std", feature = "alloc")))]
+            let deflated = false;
+
+            if deflated {
+                // The deflated eigenvalues are detected by `delimit_subproblem` below.
+            } else if subdim > 2 {
                 let m = end - 1;
                 let n = end;
 
@@ -150,8 +324,36 @@ where
                 let hnm = t[(n, m)].clone();
                 let hmn = t[(m, n)].clone();
 
-                let tra = hnn.clone() + hmm.clone();
-                let det = hnn * hmm - hnm * hmn;
+                let period = options.exceptional_shift_period;
+                let (tra, det) = if period != 0 && nstalled != 0 && nstalled % period == 0 {
+                    // Same ad hoc shifts as LAPACK.
+                    let s = t[(n, m)].clone().norm1() + t[(m, m - 1)].clone().norm1();
+                    let h = T::from_real(s.clone() * crate::convert(0.75)) + hnn;
+                    let tra = h.clone() + h.clone();
+                    (
+                        tra,
+                        h.clone() * h + T::from_real(s.clone() * s * crate::convert(0.4375)),
+                    )
+                } else {
+                    let wilkinson = match options.shift {
+                        SchurShift::Francis => None,
+                        SchurShift::Wilkinson => compute_2x2_eigvals(&t.fixed_slice::<2, 2>(m, m))
+                            .map(|(a, b)| {
+                                if (a.clone() - hnn.clone()).norm1()
+                                    <= (b.clone() - hnn.clone()).norm1()
+                                {
+                                    a
+                                } else {
+                                    b
+                                }
+                            }),
+                    };
+
+                    match wilkinson {
+                        Some(shift) => (shift.clone() + shift.clone(), shift.clone() * shift),
+                        None => (hnn.clone() + hmm.clone(), hnn * hmm - hnm * hmn),
+                    }
+                };
 
                 let mut axis = Vector3::new(
                     h11.clone() * h11.clone() + h12 * h21.clone() - tra.clone() * h11.clone() + det,
@@ -256,13 +458,31 @@ where
 
             let sub = Self::delimit_subproblem(&mut t, eps.clone(), end);
 
+            if sub.1 == end {
+                nstalled += 1;
+            } else {
+                nstalled = 0;
+            }
+
             start = sub.0;
             end = sub.1;
 
             niter += 1;
-            if niter == max_niter {
+            if niter == options.max_niter {
                 return None;
             }
+
+            if let Some(progress) = progress.as_mut() {
+                let subdiagonal_norm = (start..end)
+                    .map(|i| t[(i + 1, i)].clone().modulus_squared())
+                    .fold(T::RealField::zero(), |acc, x| acc + x)
+                    .sqrt()
+                    * amax_m.clone();
+
+                if let ControlFlow::Break(()) = progress(niter, subdiagonal_norm) {
+                    return None;
+                }
+            }
         }
 
         t.scale_mut(amax_m);
@@ -418,6 +638,127 @@ where
     }
 }
 
+/// Size of the trailing window inspected by the aggressive early deflation.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const AED_WINDOW: usize = 8;
+
+/// Performs an aggressive early deflation on the trailing window of the unreduced Hessenberg
+/// submatrix of `t` ending at row `end`, and accumulates the transformations onto `q`.
+///
+/// The window is replaced by its Schur form, and the eigenvalues at the bottom of this Schur
+/// form are deflated as long as their coupling with the rest of the matrix, i.e., the
+/// corresponding components of the transformed subdiagonal element above the window, is
+/// negligible. The undeflated part of the window is then reduced to Hessenberg form again.
+///
+/// Returns `false`, without modifying `t` and `q`, if no eigenvalue could be deflated.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn aggressive_early_deflation<T, D, S1, S2>(
+    t: &mut Matrix<T, D, D, S1>,
+    mut q: Option<&mut Matrix<T, D, D, S2>>,
+    end: usize,
+    eps: T::RealField,
+) -> bool
+where
+    T: ComplexField,
+    D: Dim,
+    S1: StorageMut<T, D, D>,
+    S2: StorageMut<T, D, D>,
+{
+    let dim = t.nrows();
+    let top = end + 1 - AED_WINDOW;
+    let spike = t[(top, top - 1)].clone();
+
+    let window = t.slice((top, top), (AED_WINDOW, AED_WINDOW)).clone_owned();
+    let (v, tw) = match Schur::try_new(window, eps.clone(), 30 * AED_WINDOW) {
+        Some(schur) => schur.unpack(),
+        None => return false,
+    };
+
+    // Deflate the trailing 1×1 and 2×2 blocks of the Schur form with a negligible spike.
+    let mut kept = AED_WINDOW;
+
+    while kept > 0 {
+        let first = if kept > 1 && !tw[(kept - 1, kept - 2)].is_zero() {
+            kept - 2
+        } else {
+            kept - 1
+        };
+
+        let mut scale = tw[(first, first)].clone().norm1();
+        if first + 2 == kept {
+            scale += tw[(first, kept - 1)].clone().norm1().sqrt()
+                * tw[(kept - 1, first)].clone().norm1().sqrt();
+        }
+        if scale.is_zero() {
+            scale = spike.clone().norm1();
+        }
+
+        let negligible = (first..kept)
+            .all(|i| (spike.clone() * v[(0, i)].clone()).norm1() <= eps.clone() * scale.clone());
+
+        if negligible {
+            kept = first;
+        } else {
+            break;
+        }
+    }
+
+    if kept == AED_WINDOW {
+        return false;
+    }
+
+    t.slice_mut((top, top), (AED_WINDOW, AED_WINDOW))
+        .copy_from(&tw);
+
+    for i in 0..AED_WINDOW {
+        t[(top + i, top - 1)] = if i < kept {
+            spike.clone() * v[(0, i)].clone().conjugate()
+        } else {
+            T::zero()
+        };
+    }
+
+    if end + 1 < dim {
+        let right = v.ad_mul(&t.slice_range(top..=end, end + 1..));
+        t.slice_range_mut(top..=end, end + 1..).copy_from(&right);
+    }
+
+    let above = t.slice_range(..top, top..=end) * &v;
+    t.slice_range_mut(..top, top..=end).copy_from(&above);
+
+    if let Some(q) = q.as_mut() {
+        let cols = q.slice((0, top), (dim, AED_WINDOW)) * &v;
+        q.slice_mut((0, top), (dim, AED_WINDOW)).copy_from(&cols);
+    }
+
+    // Reduce the spike and the undeflated part of the window to Hessenberg form.
+    let last = top + kept;
+
+    for c in top - 1..last.saturating_sub(2) {
+        for r in (c + 2..last).rev() {
+            let x = Vector2::new(t[(r - 1, c)].clone(), t[(r, c)].clone());
+
+            if let Some((rot, _)) = GivensRotation::cancel_y(&x) {
+                rot.rotate(
+                    &mut t.generic_slice_mut((r - 1, c), (Const::<2>, Dynamic::new(dim - c))),
+                );
+                rot.inverse().rotate_rows(
+                    &mut t.generic_slice_mut((0, r - 1), (Dynamic::new(end + 1), Const::<2>)),
+                );
+                t[(r, c)] = T::zero();
+
+                if let Some(q) = q.as_mut() {
+                    rot.inverse().rotate_rows(
+                        &mut q.generic_slice_mut((0, r - 1), (Dynamic::new(dim), Const::<2>)),
+                    );
+                }
+            }
+        }
+    }
+
+    true
+}
+
 fn decompose_2x2<T: ComplexField, D: Dim>(
     mut m: OMatrix<T, D, D>,
     compute_q: bool,
@@ -511,10 +852,7 @@ fn compute_2x2_basis<T: ComplexField, S: Storage<T, U2, U2>>(
 impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
 where
     D: DimSub<U1>, // For Hessenberg.
-    DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
-        + Allocator<T, DimDiff<D, U1>>
-        + Allocator<T, D, D>
-        + Allocator<T, D>,
+    DefaultAllocator: SchurCapable<T, D>,
 {
     /// Computes the eigenvalues of this matrix.
     #[must_use]
@@ -545,9 +883,9 @@ where
         let schur = Schur::do_decompose(
             self.clone_owned(),
             &mut work,
-            T::RealField::default_epsilon(),
-            0,
+            &SchurOptions::default(),
             false,
+            None,
         )
         .unwrap();
 
@@ -572,9 +910,9 @@ where
         let schur = Schur::do_decompose(
             self.clone_owned(),
             &mut work,
-            T::default_epsilon(),
-            0,
+            &SchurOptions::default(),
             false,
+            None,
         )
         .unwrap();
         let mut eig = Matrix::uninit(dim, Const::<1>);
@@ -1,19 +1,27 @@
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 use approx::AbsDiffEq;
 use num::{One, Zero};
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix, Matrix2x3, OMatrix, OVector, Vector2};
+use crate::base::{
+    DefaultAllocator, Matrix, Matrix2, Matrix2x3, Matrix3, Matrix3x2, OMatrix, OVector, Vector2,
+};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
-use crate::dimension::{Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::dimension::Dynamic;
+use crate::dimension::{Const, Dim, DimDiff, DimMin, DimMinimum, DimSub, U1};
 use crate::storage::Storage;
 use simba::scalar::{ComplexField, RealField};
 
 use crate::linalg::givens::GivensRotation;
 use crate::linalg::symmetric_eigen;
-use crate::linalg::Bidiagonal;
+use crate::linalg::{Bidiagonal, SVDCapable};
 
 /// Singular Value Decomposition of a general matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
@@ -65,6 +73,120 @@ where
 {
 }
 
+impl<T: ComplexField, R: DimMin<C>, C: Dim> SVD<T, R, C>
+where
+    DefaultAllocator: Allocator<T, DimMinimum<R, C>, C>
+        + Allocator<T, R, DimMinimum<R, C>>
+        + Allocator<T::RealField, DimMinimum<R, C>>,
+{
+    /// Consumes this decomposition and returns its parts `(U, Σ, V^t)`.
+    ///
+    /// The decomposition can be rebuilt from these parts with `SVD::from_parts`, e.g., after
+    /// they have been stored or sent to another process.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (
+        Option<OMatrix<T, R, DimMinimum<R, C>>>,
+        OVector<T::RealField, DimMinimum<R, C>>,
+        Option<OMatrix<T, DimMinimum<R, C>, C>>,
+    ) {
+        (self.u, self.singular_values, self.v_t)
+    }
+
+    /// Rebuilds a singular value decomposition from its parts `(U, Σ, V^t)`, as returned by
+    /// `SVD::into_parts`.
+    ///
+    /// No check is performed: `U` and `V^t` must have orthonormal columns and rows respectively,
+    /// and the singular values must be non-negative.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3, SVD};
+    /// let m = Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0);
+    /// let (u, singular_values, v_t) = m.svd(true, true).into_parts();
+    /// let decomp = SVD::from_parts(u, singular_values, v_t);
+    ///
+    /// assert_relative_eq!(decomp.recompose().unwrap(), m, epsilon = 1.0e-7);
+    /// ```
+    #[inline]
+    pub fn from_parts(
+        u: Option<OMatrix<T, R, DimMinimum<R, C>>>,
+        singular_values: OVector<T::RealField, DimMinimum<R, C>>,
+        v_t: Option<OMatrix<T, DimMinimum<R, C>, C>>,
+    ) -> Self {
+        SVD {
+            u,
+            v_t,
+            singular_values,
+        }
+    }
+
+    /// Returns the factors `(U_k, Σ_k, V_k^t)` of the best rank-`k` approximation
+    /// `U_k * Σ_k * V_k^t` of the decomposed matrix, i.e., the singular vectors associated to its
+    /// `k` largest singular values.
+    ///
+    /// The singular values of `Σ_k` are sorted in decreasing order. If `k` exceeds the number of
+    /// singular values, all of them are kept. Returns `Err` if the right- and left- singular
+    /// vectors have not been computed at construction-time.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, DVector};
+    /// let u = DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// let v = DVector::from_vec(vec![1.0, -1.0, 0.5]);
+    /// let noise = DMatrix::from_fn(4, 3, |i, j| ((i * 3 + j) % 5) as f64 * 1.0e-3);
+    /// let m = &u * v.transpose() + noise;
+    ///
+    /// let (u_k, sigma_k, v_t_k) = m.clone().svd(true, true).truncated(1).unwrap();
+    /// assert_eq!(u_k.shape(), (4, 1));
+    /// assert_eq!(v_t_k.shape(), (1, 3));
+    ///
+    /// let rank_one = u_k * DMatrix::from_diagonal(&sigma_k) * v_t_k;
+    /// assert_relative_eq!(rank_one, u * v.transpose(), epsilon = 1.0e-2);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn truncated(
+        &self,
+        k: usize,
+    ) -> Result<
+        (
+            OMatrix<T, R, Dynamic>,
+            OVector<T::RealField, Dynamic>,
+            OMatrix<T, Dynamic, C>,
+        ),
+        &'static str,
+    >
+    where
+        DefaultAllocator: Allocator<T, R, Dynamic> + Allocator<T, Dynamic, C>,
+    {
+        match (&self.u, &self.v_t) {
+            (Some(u), Some(v_t)) => {
+                let mut indices: Vec<usize> = (0..self.singular_values.len()).collect();
+                // Sort by decreasing singular value.
+                indices.sort_by(|a, b| {
+                    self.singular_values[*b]
+                        .partial_cmp(&self.singular_values[*a])
+                        .unwrap_or(Ordering::Equal)
+                });
+                indices.truncate(k);
+
+                Ok((
+                    u.select_columns(&indices),
+                    self.singular_values.select_rows(&indices),
+                    v_t.select_rows(&indices),
+                ))
+            }
+            (None, None) => Err("SVD truncation: U and V^t have not been computed."),
+            (None, _) => Err("SVD truncation: U has not been computed."),
+            (_, None) => Err("SVD truncation: V^t has not been computed."),
+        }
+    }
+}
+
 impl<T: ComplexField, R: DimMin<C>, C: Dim> SVD<T, R, C>
 where
     DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
@@ -79,7 +201,14 @@ where
         + Allocator<T::RealField, DimDiff<DimMinimum<R, C>, U1>>,
 {
     /// Computes the Singular Value Decomposition of `matrix` using implicit shift.
+    ///
+    /// The SVDs of 2×2 and 3×2 matrices are computed with closed-form formulas instead of
+    /// iterations.
     pub fn new(matrix: OMatrix<T, R, C>, compute_u: bool, compute_v: bool) -> Self {
+        if let Some(svd) = Self::new_closed_form(&matrix, compute_u, compute_v) {
+            return svd;
+        }
+
         Self::try_new(
             matrix,
             compute_u,
@@ -101,11 +230,122 @@ where
     /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
     /// continues indefinitely until convergence.
     pub fn try_new(
+        matrix: OMatrix<T, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        Self::do_decompose(matrix, compute_u, compute_v, eps, max_niter, None)
+    }
+
+    /// Attempts to compute the Singular Value Decomposition of `matrix` using implicit shift,
+    /// reporting the progress of the iterations.
+    ///
+    /// After each iteration, `progress` is called with the number of iterations performed so far
+    /// and the norm of the off-diagonal part of the bidiagonal matrix that has not converged yet.
+    /// The computation is cancelled, and `None` is returned, as soon as `progress` returns
+    /// `ControlFlow::Break(())`. This allows long-running decompositions to be monitored and
+    /// interrupted.
+    ///
+    /// See `SVD::try_new` for the other arguments.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, SVD};
+    /// use std::ops::ControlFlow;
+    ///
+    /// let m = DMatrix::from_fn(30, 20, |i, j| ((i * 7 + j * 3) % 11) as f64);
+    ///
+    /// let mut niter = 0;
+    /// let svd = SVD::try_new_with(m.clone(), true, true, 1.0e-15, 0, |i, _off_diag_norm| {
+    ///     niter = i;
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert!(svd.is_some());
+    /// assert!(niter > 0);
+    ///
+    /// // Cancel the computation after five iterations.
+    /// let svd = SVD::try_new_with(m, true, true, 1.0e-15, 0, |i, _| {
+    ///     if i < 5 {
+    ///         ControlFlow::Continue(())
+    ///     } else {
+    ///         ControlFlow::Break(())
+    ///     }
+    /// });
+    /// assert!(svd.is_none());
+    /// ```
+    pub fn try_new_with<F>(
+        matrix: OMatrix<T, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+        eps: T::RealField,
+        max_niter: usize,
+        mut progress: F,
+    ) -> Option<Self>
+    where
+        F: FnMut(usize, T::RealField) -> ControlFlow<()>,
+    {
+        Self::do_decompose(
+            matrix,
+            compute_u,
+            compute_v,
+            eps,
+            max_niter,
+            Some(&mut progress),
+        )
+    }
+
+    /// Computes the SVD of a 2×2 or 3×2 matrix with closed-form formulas.
+    ///
+    /// Returns `None` if `matrix` has any other dimension, or is zero.
+    fn new_closed_form(
+        matrix: &OMatrix<T, R, C>,
+        compute_u: bool,
+        compute_v: bool,
+    ) -> Option<Self> {
+        let (nrows, ncols) = matrix.shape_generic();
+        if ncols.value() != 2 || (nrows.value() != 2 && nrows.value() != 3) {
+            return None;
+        }
+
+        let m_amax = matrix.camax();
+        if m_amax.is_zero() {
+            return None;
+        }
+
+        let m = Matrix3x2::from_fn(|i, j| {
+            if i < nrows.value() {
+                matrix[(i, j)].clone().unscale(m_amax.clone())
+            } else {
+                T::zero()
+            }
+        });
+
+        let (u, mut singular_values, v_t) = svd_3x2(m, compute_u, compute_v);
+        singular_values.scale_mut(m_amax);
+
+        let min_nrows_ncols = nrows.min(ncols);
+        Some(Self {
+            u: u.map(|u| {
+                OMatrix::from_fn_generic(nrows, min_nrows_ncols, |i, j| u[(i, j)].clone())
+            }),
+            v_t: v_t.map(|v_t| {
+                OMatrix::from_fn_generic(min_nrows_ncols, ncols, |i, j| v_t[(i, j)].clone())
+            }),
+            singular_values: OVector::from_fn_generic(min_nrows_ncols, Const::<1>, |i, _| {
+                singular_values[i].clone()
+            }),
+        })
+    }
+
+    fn do_decompose(
         mut matrix: OMatrix<T, R, C>,
         compute_u: bool,
         compute_v: bool,
         eps: T::RealField,
         max_niter: usize,
+        mut progress: Option<&mut dyn FnMut(usize, T::RealField) -> ControlFlow<()>>,
     ) -> Option<Self> {
         assert!(
             !matrix.is_empty(),
@@ -292,6 +532,14 @@ where
             if niter == max_niter {
                 return None;
             }
+
+            if let Some(progress) = progress.as_mut() {
+                let off_diagonal_norm = off_diagonal.rows_range(start..end).norm() * m_amax.clone();
+
+                if let ControlFlow::Break(()) = progress(niter, off_diagonal_norm) {
+                    return None;
+                }
+            }
         }
 
         diagonal *= m_amax;
@@ -615,20 +863,87 @@ where
 impl<T: ComplexField, R: DimMin<C>, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
 where
     DimMinimum<R, C>: DimSub<U1>, // for Bidiagonal.
-    DefaultAllocator: Allocator<T, R, C>
-        + Allocator<T, C>
-        + Allocator<T, R>
-        + Allocator<T, DimDiff<DimMinimum<R, C>, U1>>
-        + Allocator<T, DimMinimum<R, C>, C>
-        + Allocator<T, R, DimMinimum<R, C>>
-        + Allocator<T, DimMinimum<R, C>>
-        + Allocator<T::RealField, DimMinimum<R, C>>
-        + Allocator<T::RealField, DimDiff<DimMinimum<R, C>, U1>>,
+    DefaultAllocator: SVDCapable<T, R, C>,
 {
-    /// Computes the singular values of this matrix.
+    /// Computes the singular values of this matrix, sorted in decreasing order.
+    ///
+    /// Because no singular vector is needed, the singular values of the bidiagonal form of this
+    /// matrix are computed with the dqds algorithm instead of the implicit-shift QR iterations of
+    /// `SVD::new`. This is faster, only needs a workspace of two vectors beyond the
+    /// bidiagonalization, and computes even the smallest singular values with a high relative
+    /// accuracy.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(1.0, 1.0e-4, 0.0,
+    ///                      0.0, 1.0e-4, 1.0e-8,
+    ///                      0.0, 0.0,    1.0e-8);
+    /// let singular_values = m.singular_values();
+    ///
+    /// assert!(singular_values[0] >= singular_values[1]);
+    /// assert!(singular_values[1] >= singular_values[2]);
+    /// // The product of the singular values is the absolute value of the determinant.
+    /// assert_relative_eq!(singular_values.iter().product::<f64>(), 1.0e-12, max_relative = 1.0e-12);
+    /// ```
     #[must_use]
     pub fn singular_values(&self) -> OVector<T::RealField, DimMinimum<R, C>> {
-        SVD::new(self.clone_owned(), false, false).singular_values
+        assert!(
+            !self.is_empty(),
+            "Cannot compute the singular values of an empty matrix."
+        );
+
+        if let Some(svd) = SVD::new_closed_form(&self.clone_owned(), false, false) {
+            let mut singular_values = svd.singular_values;
+            singular_values
+                .as_mut_slice()
+                .sort_unstable_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+            return singular_values;
+        }
+
+        let mut matrix = self.clone_owned();
+        let m_amax = matrix.camax();
+
+        if !m_amax.is_zero() {
+            matrix.unscale_mut(m_amax.clone());
+        }
+
+        let bi_matrix = Bidiagonal::new(matrix);
+        let mut singular_values = bi_matrix.diagonal();
+        let mut off_diagonal = bi_matrix.off_diagonal();
+
+        if !dqds(
+            &mut singular_values,
+            &mut off_diagonal,
+            T::RealField::default_epsilon(),
+        ) {
+            // Fall back to the implicit-shift QR iterations in the unlikely case where dqds
+            // failed to converge.
+            singular_values = SVD::new(self.clone_owned(), false, false).singular_values;
+        } else if !m_amax.is_zero() {
+            singular_values.scale_mut(m_amax);
+        }
+
+        singular_values
+            .as_mut_slice()
+            .sort_unstable_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        singular_values
+    }
+
+    /// Computes the nuclear norm of this matrix, i.e., the sum of its singular values.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(3.0, 0.0, 0.0,
+    ///                        0.0, 0.0, -4.0);
+    /// assert_relative_eq!(m.nuclear_norm(), 7.0, epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn nuclear_norm(&self) -> T::RealField {
+        self.singular_values().sum()
     }
 
     /// Computes the rank of this matrix.
@@ -636,8 +951,11 @@ where
     /// All singular values below `eps` are considered equal to 0.
     #[must_use]
     pub fn rank(&self, eps: T::RealField) -> usize {
-        let svd = SVD::new(self.clone_owned(), false, false);
-        svd.rank(eps)
+        assert!(
+            eps >= T::RealField::zero(),
+            "SVD rank: the epsilon must be non-negative."
+        );
+        self.singular_values().iter().filter(|e| **e > eps).count()
     }
 
     /// Computes the pseudo-inverse of this matrix.
@@ -706,3 +1024,266 @@ fn compute_2x2_uptrig_svd<T: RealField>(
 
     (u, Vector2::new(v1, v2), v_t)
 }
+
+// Computes the SVD of a non-zero 3×2 matrix, or of a 2×2 matrix padded with a row of zeros.
+//
+// The matrix is first reduced to an upper-triangular 2×2 matrix `R` by Givens rotations, and
+// the phases of the components of `R` are then moved to unitary diagonal matrices so that the
+// explicit formulae of `compute_2x2_uptrig_svd` can be applied to the real matrix
+// `[|r11| |r12|; 0 |r22|]`.
+fn svd_3x2<T: ComplexField>(
+    mut m: Matrix3x2<T>,
+    compute_u: bool,
+    compute_v: bool,
+) -> (
+    Option<Matrix3x2<T>>,
+    Vector2<T::RealField>,
+    Option<Matrix2<T>>,
+) {
+    // Pivot the column with the largest norm first so that `R` has a zero diagonal element only
+    // if its second row is zero.
+    let pivot = m.column(1).norm_squared() > m.column(0).norm_squared();
+    if pivot {
+        m.swap_columns(0, 1);
+    }
+
+    // Accumulates the adjoint of the orthogonal factor of the QR decomposition.
+    let mut q_t = Matrix3::identity();
+
+    for (i, j) in [(1, 0), (0, 0), (1, 1)] {
+        let elts = Vector2::new(m[(i, j)].clone(), m[(i + 1, j)].clone());
+        if let Some((rot, _)) = GivensRotation::cancel_y(&elts) {
+            rot.rotate(&mut m.fixed_rows_mut::<2>(i));
+            rot.rotate(&mut q_t.fixed_rows_mut::<2>(i));
+            m[(i + 1, j)] = T::zero();
+        }
+    }
+
+    let phase = |e: &T| {
+        let modulus = e.clone().modulus();
+        if modulus.is_zero() {
+            T::one()
+        } else {
+            e.clone().unscale(modulus)
+        }
+    };
+
+    // `diag(1, d1) * R * diag(c0, c1)` is real with non-negative components.
+    let c0 = phase(&m[(0, 0)]).conjugate();
+    let c1 = phase(&m[(0, 1)]).conjugate();
+    let d1 = phase(&(m[(1, 1)].clone() * c1.clone())).conjugate();
+    let (r11, r12, r22) = (
+        m[(0, 0)].clone().modulus(),
+        m[(0, 1)].clone().modulus(),
+        m[(1, 1)].clone().modulus(),
+    );
+
+    let (u2, mut singular_values, v2) = if r22.is_zero() {
+        // The matrix has rank one, and its only non-zero row is its first right-singular vector.
+        let norm = r11.clone().hypot(r12.clone());
+        let v2 = GivensRotation::new_unchecked(r11 / norm.clone(), r12 / norm.clone());
+        (None, Vector2::new(norm, T::RealField::zero()), Some(v2))
+    } else {
+        // NOTE: the left-singular vectors are computed whenever the right ones are because the
+        // signs of the singular values are only fixed up once both rotations are known.
+        compute_2x2_uptrig_svd(r11, r12, r22, compute_u || compute_v, compute_v)
+    };
+
+    let mut u = None;
+    if compute_u {
+        let mut u_mat = q_t.fixed_rows::<2>(0).adjoint();
+        u_mat.column_mut(1).apply(|e| *e *= d1.clone().conjugate());
+
+        if let Some(u2) = u2 {
+            let rot = GivensRotation::new_unchecked(u2.c(), T::from_real(u2.s()));
+            rot.rotate_rows(&mut u_mat);
+        }
+
+        u = Some(u_mat);
+    }
+
+    let mut v_t = None;
+    if compute_v {
+        let mut v_t_mat = Matrix2::from_diagonal(&Vector2::new(c0.conjugate(), c1.conjugate()));
+
+        if let Some(v2) = v2 {
+            let rot = GivensRotation::new_unchecked(v2.c(), T::from_real(v2.s()));
+            rot.inverse().rotate(&mut v_t_mat);
+        }
+
+        if pivot {
+            v_t_mat.swap_columns(0, 1);
+        }
+
+        v_t = Some(v_t_mat);
+    }
+
+    // Make the singular values non-negative by flipping the corresponding left-singular vectors.
+    for i in 0..2 {
+        if singular_values[i] < T::RealField::zero() {
+            singular_values[i] = -singular_values[i].clone();
+
+            if let Some(u) = &mut u {
+                u.column_mut(i).neg_mut();
+            }
+        }
+    }
+
+    (u, singular_values, v_t)
+}
+
+// Computes in-place the singular values of the bidiagonal matrix with the non-negative diagonal
+// `diagonal` and the non-negative off-diagonal `off_diagonal`, using the dqds algorithm of
+// Fernando and Parlett with a simplified shift strategy. The singular values are not sorted.
+//
+// Returns `false` if the algorithm did not converge.
+fn dqds<T: RealField, D: Dim, E: Dim>(
+    diagonal: &mut OVector<T, D>,
+    off_diagonal: &mut OVector<T, E>,
+    eps: T,
+) -> bool
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, E>,
+{
+    let dim = diagonal.len();
+    let two: T = crate::convert(2.0f64);
+    let four: T = crate::convert(4.0f64);
+    let half: T = crate::convert(0.5f64);
+    let quarter: T = crate::convert(0.25f64);
+
+    // The algorithm works on the qd array `(q, e)` of the squared entries, which represents the
+    // tridiagonal matrix `BᵀB`. Each dqds transform computes the qd array of `BBᵀ - τ I`.
+    diagonal.apply(|x| *x = x.clone() * x.clone());
+    off_diagonal.apply(|x| *x = x.clone() * x.clone());
+
+    let (q, e) = (diagonal, off_diagonal);
+    let mut new_q = q.clone();
+    let mut new_e = e.clone();
+
+    let tol2 = eps.clone() * eps.clone();
+    let max_niter = 30 * dim;
+    let mut niter = 0;
+    let mut sigma = T::zero();
+    let mut dmin = None;
+    let mut n = dim;
+
+    while n > 0 {
+        if n == 1 {
+            q[0] = (q[0].clone() + sigma).sqrt();
+            break;
+        }
+
+        // Deflate the last eigenvalue of `BᵀB` if it is decoupled from the rest.
+        if e[n - 2] <= tol2.clone() * (sigma.clone() + q[n - 1].clone()) {
+            q[n - 1] = (q[n - 1].clone() + sigma.clone()).sqrt();
+            dmin = None;
+            n -= 1;
+            continue;
+        }
+
+        if n == 2 {
+            // Explicit eigenvalues of the remaining `2 × 2` block.
+            let (q0, q1, e0) = (q[0].clone(), q[1].clone(), e[0].clone());
+            let c = q1.clone() + e0.clone();
+            let disc = (q0.clone() - c.clone()) * (q0.clone() - c.clone()) + four * q0.clone() * e0;
+            let largest = (q0.clone() + c + disc.sqrt()) / two;
+            let smallest = q0 * q1 / largest.clone();
+            q[0] = (largest + sigma.clone()).sqrt();
+            q[1] = (smallest + sigma).sqrt();
+            break;
+        }
+
+        niter += 1;
+
+        if niter > max_niter {
+            return false;
+        }
+
+        // The smallest eigenvalue of the trailing `2 × 2` block of `BᵀB`, written without
+        // cancellation, and the minimum of the `d` of the previous transform both approximate
+        // the smallest eigenvalue from above. The shift is slightly reduced according to how
+        // much the last row is still coupled to the others, so that it is rarely rejected.
+        let a = q[n - 2].clone() + e[n - 3].clone();
+        let c = q[n - 1].clone() + e[n - 2].clone();
+        let b2 = q[n - 2].clone() * e[n - 2].clone();
+        let disc = (a.clone() - c.clone()) * (a.clone() - c.clone()) + four.clone() * b2;
+        let largest = (a + c.clone() + disc.sqrt()) / two.clone();
+        let estimate =
+            (q[n - 2].clone() * q[n - 1].clone() + e[n - 3].clone() * c.clone()) / largest;
+        let coupling = RealField::min(e[n - 2].clone() / c, half.clone());
+
+        let mut tau = match dmin {
+            Some(dmin) => RealField::min(estimate, dmin),
+            None => estimate,
+        } * (T::one() - coupling);
+
+        // The shift is rejected if it breaks the positive definiteness. If only the last
+        // auxiliary quantity `d` is negative, the shift overshot the smallest eigenvalue by
+        // about `-d`. Otherwise, it is reduced, and eventually zeroed, in which case the
+        // transform never fails.
+        let mut attempt = 0;
+
+        let new_dmin = loop {
+            match dqds_transform(q, e, &mut new_q, &mut new_e, n, tau.clone()) {
+                Ok(new_dmin) => break new_dmin,
+                Err(last_d) => {
+                    attempt += 1;
+                    tau = match last_d {
+                        _ if attempt > 2 => T::zero(),
+                        Some(last_d) if last_d.clone() + tau.clone() > T::zero() => {
+                            (tau + last_d) * (T::one() - two.clone() * eps.clone())
+                        }
+                        _ => tau * quarter.clone(),
+                    };
+                }
+            }
+        };
+
+        q.rows_mut(0, n).copy_from(&new_q.rows(0, n));
+        e.rows_mut(0, n - 1).copy_from(&new_e.rows(0, n - 1));
+        sigma += tau;
+        dmin = Some(new_dmin);
+    }
+
+    true
+}
+
+// Applies one dqds transform with the shift `tau` to the first `n` entries of the qd array
+// `(q, e)`, and returns the minimum of the auxiliary quantities `d`.
+//
+// If the shift is too large and not zero, returns an error containing the last `d` if it is the
+// only negative one.
+fn dqds_transform<T: RealField, D: Dim, E: Dim>(
+    q: &OVector<T, D>,
+    e: &OVector<T, E>,
+    new_q: &mut OVector<T, D>,
+    new_e: &mut OVector<T, E>,
+    n: usize,
+    tau: T,
+) -> Result<T, Option<T>>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, E>,
+{
+    let unshifted = tau.is_zero();
+    let mut d = q[0].clone() - tau.clone();
+    let mut dmin = d.clone();
+
+    for i in 0..n - 1 {
+        if !unshifted && d <= T::zero() {
+            return Err(None);
+        }
+
+        new_q[i] = d.clone() + e[i].clone();
+        let t = q[i + 1].clone() / new_q[i].clone();
+        new_e[i] = e[i].clone() * t.clone();
+        d = d * t - tau.clone();
+        dmin = RealField::min(dmin, d.clone());
+    }
+
+    if !unshifted && d < T::zero() {
+        return Err(Some(d));
+    }
+
+    new_q[n - 1] = d;
+    Ok(dmin)
+}
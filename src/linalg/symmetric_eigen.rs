@@ -2,16 +2,19 @@
 use serde::{Deserialize, Serialize};
 
 use approx::AbsDiffEq;
-use num::Zero;
+use num::{One, Zero};
+use std::ops::ControlFlow;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix2, OMatrix, OVector, SquareMatrix, Vector2};
-use crate::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::base::{
+    DefaultAllocator, Matrix2, Matrix3, OMatrix, OVector, SquareMatrix, Vector2, Vector3,
+};
+use crate::dimension::{Const, Dim, DimDiff, DimSub, U1};
 use crate::storage::Storage;
-use simba::scalar::ComplexField;
+use simba::scalar::{ComplexField, RealField};
 
 use crate::linalg::givens::GivensRotation;
-use crate::linalg::SymmetricTridiagonal;
+use crate::linalg::{SymmetricEigenCapable, SymmetricTridiagonal};
 
 /// Eigendecomposition of a symmetric matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
@@ -55,12 +58,20 @@ where
 {
     /// Computes the eigendecomposition of the given symmetric matrix.
     ///
-    /// Only the lower-triangular parts (including its diagonal) of `m` is read.
+    /// Only the lower-triangular parts (including its diagonal) of `m` is read. Use
+    /// `m.is_hermitian(eps)` to check beforehand that `m` actually is symmetric.
+    ///
+    /// The eigendecompositions of 2×2 and 3×3 matrices are computed with closed-form formulas
+    /// instead of iterations.
     pub fn new(m: OMatrix<T, D, D>) -> Self
     where
         D: DimSub<U1>,
         DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
     {
+        if let Some(eigen) = Self::new_closed_form(&m) {
+            return eigen;
+        }
+
         Self::try_new(m, T::RealField::default_epsilon(), 0).unwrap()
     }
 
@@ -80,17 +91,254 @@ where
         D: DimSub<U1>,
         DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
     {
-        Self::do_decompose(m, true, eps, max_niter).map(|(vals, vecs)| SymmetricEigen {
+        Self::do_decompose(m, true, eps, max_niter, None).map(|(vals, vecs)| SymmetricEigen {
             eigenvectors: vecs.unwrap(),
             eigenvalues: vals,
         })
     }
 
+    /// Computes the eigendecomposition of the given symmetric matrix with user-specified
+    /// convergence parameters, reporting the progress of the iterations.
+    ///
+    /// After each iteration, `progress` is called with the number of iterations performed so far
+    /// and the norm of the off-diagonal part of the tridiagonal matrix that has not converged yet.
+    /// The computation is cancelled, and `None` is returned, as soon as `progress` returns
+    /// `ControlFlow::Break(())`.
+    ///
+    /// See `SymmetricEigen::try_new` for the other arguments.
+    pub fn try_new_with<F>(
+        m: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+        mut progress: F,
+    ) -> Option<Self>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+        F: FnMut(usize, T::RealField) -> ControlFlow<()>,
+    {
+        Self::do_decompose(m, true, eps, max_niter, Some(&mut progress)).map(|(vals, vecs)| {
+            SymmetricEigen {
+                eigenvectors: vecs.unwrap(),
+                eigenvalues: vals,
+            }
+        })
+    }
+
+    /// Computes the eigendecomposition of the given symmetric matrix using the cyclic Jacobi
+    /// eigenvalue algorithm.
+    ///
+    /// This is significantly slower than `SymmetricEigen::new` for large matrices, but the
+    /// eigenvalues of positive-definite matrices are computed with a high relative accuracy.
+    /// This includes the smallest eigenvalues of graded matrices, i.e., of the form `D * A * D`
+    /// where `D` is diagonal with components of widely different magnitudes and `A` is well
+    /// conditioned, which `SymmetricEigen::new` may only compute with an absolute accuracy.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, SymmetricEigen};
+    /// // A graded positive-definite matrix with eigenvalues spanning 40 orders of magnitude.
+    /// let m = Matrix3::new(1.0e40, 1.0e29, 1.0e19,
+    ///                      1.0e29, 1.0e20, 1.0e9,
+    ///                      1.0e19, 1.0e9,  1.0);
+    /// let eigen = SymmetricEigen::new_jacobi(m);
+    /// let smallest = eigen.eigenvalues.min();
+    ///
+    /// // The smallest eigenvalue is the inverse of the largest eigenvalue of the inverse.
+    /// let inv = m.try_inverse().unwrap();
+    /// let largest_of_inv = SymmetricEigen::new_jacobi(inv).eigenvalues.max();
+    /// assert_relative_eq!(smallest, 1.0 / largest_of_inv, max_relative = 1.0e-12);
+    /// ```
+    pub fn new_jacobi(m: OMatrix<T, D, D>) -> Self {
+        Self::try_new_jacobi(m, T::RealField::default_epsilon(), 0).unwrap()
+    }
+
+    /// Computes the eigendecomposition of the given symmetric matrix using the cyclic Jacobi
+    /// eigenvalue algorithm, with user-specified convergence parameters.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when an off-diagonal component `m_ij` converged
+    ///   to 0, i.e., when `|m_ij| <= eps * sqrt(|m_ii * m_jj|)`.
+    /// * `max_niter` − maximum number of sweeps through all the off-diagonal components performed
+    ///   by the algorithm. If this number of sweeps is exceeded, `None` is returned. If
+    ///   `max_niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_new_jacobi(
+        mut m: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self> {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigendecomposition of a non-square matrix."
+        );
+
+        let dim = m.nrows();
+
+        // Rebuild the whole hermitian matrix from its lower-triangular part.
+        for j in 0..dim {
+            m[(j, j)] = T::from_real(m[(j, j)].clone().real());
+
+            for i in 0..j {
+                m[(i, j)] = m[(j, i)].clone().conjugate();
+            }
+        }
+
+        let (nrows, ncols) = m.shape_generic();
+        let mut eigenvectors = OMatrix::identity_generic(nrows, ncols);
+        let mut niter = 0;
+
+        loop {
+            let mut converged = true;
+
+            for q in 1..dim {
+                for p in 0..q {
+                    let apq = m[(p, q)].clone();
+                    let norm = apq.clone().modulus();
+                    let app = m[(p, p)].clone().real();
+                    let aqq = m[(q, q)].clone().real();
+
+                    // This threshold is what guarantees the high relative accuracy.
+                    if norm <= eps.clone() * (app.clone() * aqq.clone()).abs().sqrt() {
+                        continue;
+                    }
+
+                    converged = false;
+
+                    // Make `m_pq` real by multiplying the column `q` by the conjugate of its
+                    // phase, and the row `q` by its phase.
+                    let phase = apq.unscale(norm.clone());
+
+                    for r in 0..dim {
+                        m[(r, q)] *= phase.clone().conjugate();
+                        eigenvectors[(r, q)] *= phase.clone().conjugate();
+                    }
+
+                    for r in 0..dim {
+                        m[(q, r)] *= phase.clone();
+                    }
+
+                    // Then cancel it with a real rotation.
+                    let theta = (aqq.clone() - app.clone()) / (norm.clone() + norm.clone());
+                    let t = if theta >= T::RealField::zero() {
+                        T::RealField::one()
+                    } else {
+                        -T::RealField::one()
+                    } / (theta.clone().abs() + theta.hypot(T::RealField::one()));
+                    let c = T::RealField::one() / t.clone().hypot(T::RealField::one());
+                    let s = t.clone() * c.clone();
+
+                    for r in 0..dim {
+                        let (x, y) = (m[(r, p)].clone(), m[(r, q)].clone());
+                        m[(r, p)] = x.clone().scale(c.clone()) - y.clone().scale(s.clone());
+                        m[(r, q)] = x.scale(s.clone()) + y.scale(c.clone());
+
+                        let (x, y) = (eigenvectors[(r, p)].clone(), eigenvectors[(r, q)].clone());
+                        eigenvectors[(r, p)] =
+                            x.clone().scale(c.clone()) - y.clone().scale(s.clone());
+                        eigenvectors[(r, q)] = x.scale(s.clone()) + y.scale(c.clone());
+                    }
+
+                    for r in 0..dim {
+                        let (x, y) = (m[(p, r)].clone(), m[(q, r)].clone());
+                        m[(p, r)] = x.clone().scale(c.clone()) - y.clone().scale(s.clone());
+                        m[(q, r)] = x.scale(s.clone()) + y.scale(c.clone());
+                    }
+
+                    m[(p, p)] = T::from_real(app - t.clone() * norm.clone());
+                    m[(q, q)] = T::from_real(aqq + t * norm);
+                    m[(p, q)] = T::zero();
+                    m[(q, p)] = T::zero();
+                }
+            }
+
+            if converged {
+                break;
+            }
+
+            niter += 1;
+
+            if niter == max_niter {
+                return None;
+            }
+        }
+
+        let eigenvalues =
+            OVector::from_fn_generic(nrows, Const::<1>, |i, _| m[(i, i)].clone().real());
+
+        Some(SymmetricEigen {
+            eigenvectors,
+            eigenvalues,
+        })
+    }
+
+    /// Computes the eigendecomposition of a 2×2 or 3×3 matrix with closed-form formulas.
+    ///
+    /// Returns `None` if `m` has any other dimension.
+    fn new_closed_form(m: &OMatrix<T, D, D>) -> Option<Self> {
+        let dim = m.shape_generic().0;
+        let n = dim.value();
+        if n != 2 && n != 3 {
+            return None;
+        }
+
+        // Rebuild the full hermitian matrix from the lower-triangular part of `m`.
+        let mut m3 = Matrix3::from_fn(|i, j| {
+            if i >= n || j >= n {
+                T::zero()
+            } else if i == j {
+                T::from_real(m[(i, i)].clone().real())
+            } else if i > j {
+                m[(i, j)].clone()
+            } else {
+                m[(j, i)].clone().conjugate()
+            }
+        });
+
+        let m_amax = m3.camax();
+        if !m_amax.is_zero() {
+            m3.unscale_mut(m_amax.clone());
+        }
+
+        let (mut vals, vecs) = if n == 2 {
+            let (vals, vecs) = hermitian_eigen_2x2(
+                m3[(0, 0)].clone().real(),
+                m3[(1, 0)].clone(),
+                m3[(1, 1)].clone().real(),
+            );
+            let vecs = Matrix3::from_fn(|i, j| {
+                if i < 2 && j < 2 {
+                    vecs[(i, j)].clone()
+                } else {
+                    T::zero()
+                }
+            });
+            (vals.push(T::RealField::zero()), vecs)
+        } else {
+            hermitian_eigen_3x3(&m3)
+        };
+
+        if !m_amax.is_zero() {
+            vals.scale_mut(m_amax);
+        }
+
+        Some(SymmetricEigen {
+            eigenvectors: OMatrix::from_fn_generic(dim, dim, |i, j| vecs[(i, j)].clone()),
+            eigenvalues: OVector::from_fn_generic(dim, Const::<1>, |i, _| vals[i].clone()),
+        })
+    }
+
     fn do_decompose(
         mut matrix: OMatrix<T, D, D>,
         eigenvectors: bool,
         eps: T::RealField,
         max_niter: usize,
+        mut progress: Option<&mut dyn FnMut(usize, T::RealField) -> ControlFlow<()>>,
     ) -> Option<(OVector<T::RealField, D>, Option<OMatrix<T, D, D>>)>
     where
         D: DimSub<U1>,
@@ -100,14 +348,13 @@ where
             matrix.is_square(),
             "Unable to compute the eigendecomposition of a non-square matrix."
         );
-        let dim = matrix.nrows();
         let m_amax = matrix.camax();
 
         if !m_amax.is_zero() {
             matrix.unscale_mut(m_amax.clone());
         }
 
-        let (mut q_mat, mut diag, mut off_diag);
+        let (q_mat, diag, off_diag);
 
         if eigenvectors {
             let res = SymmetricTridiagonal::new(matrix).unpack();
@@ -121,6 +368,64 @@ where
             off_diag = res.1;
         }
 
+        Self::do_decompose_scaled_tridiagonal(
+            diag, off_diag, q_mat, m_amax, eps, max_niter, progress,
+        )
+    }
+
+    /// Computes the eigenvalues, and the eigenvectors if `q_mat` is not `None`, of the symmetric
+    /// tridiagonal matrix with the given diagonal and off-diagonal elements.
+    ///
+    /// The eigenvectors are accumulated onto `q_mat`.
+    pub(crate) fn do_decompose_tridiagonal(
+        mut diag: OVector<T::RealField, D>,
+        mut off_diag: OVector<T::RealField, DimDiff<D, U1>>,
+        q_mat: Option<OMatrix<T, D, D>>,
+        eps: T::RealField,
+        max_niter: usize,
+        progress: Option<&mut dyn FnMut(usize, T::RealField) -> ControlFlow<()>>,
+    ) -> Option<(OVector<T::RealField, D>, Option<OMatrix<T, D, D>>)>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        assert!(
+            !diag.is_empty(),
+            "Unable to compute the eigendecomposition of an empty tridiagonal matrix."
+        );
+        assert_eq!(
+            diag.len(),
+            off_diag.len() + 1,
+            "Tridiagonal eigendecomposition: the off-diagonal must be one element shorter."
+        );
+
+        let amax = RealField::max(diag.amax(), off_diag.amax());
+
+        if !amax.is_zero() {
+            diag.unscale_mut(amax.clone());
+            off_diag.unscale_mut(amax.clone());
+        }
+
+        Self::do_decompose_scaled_tridiagonal(diag, off_diag, q_mat, amax, eps, max_niter, progress)
+    }
+
+    // Same as `do_decompose_tridiagonal`, where the tridiagonal matrix is already divided by
+    // `scale`.
+    fn do_decompose_scaled_tridiagonal(
+        mut diag: OVector<T::RealField, D>,
+        mut off_diag: OVector<T::RealField, DimDiff<D, U1>>,
+        mut q_mat: Option<OMatrix<T, D, D>>,
+        m_amax: T::RealField,
+        eps: T::RealField,
+        max_niter: usize,
+        mut progress: Option<&mut dyn FnMut(usize, T::RealField) -> ControlFlow<()>>,
+    ) -> Option<(OVector<T::RealField, D>, Option<OMatrix<T, D, D>>)>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        let dim = diag.len();
+
         if dim == 1 {
             diag.scale_mut(m_amax);
             return Some((diag, q_mat));
@@ -199,23 +504,46 @@ where
                     diag[start + 1].clone(),
                 );
                 let eigvals = m.eigenvalues().unwrap();
-                let basis = Vector2::new(
-                    eigvals.x.clone() - diag[start + 1].clone(),
-                    off_diag[start].clone(),
-                );
-
-                diag[start] = eigvals[0].clone();
-                diag[start + 1] = eigvals[1].clone();
 
                 if let Some(ref mut q) = q_mat {
-                    if let Some((rot, _)) =
-                        GivensRotation::try_new(basis.x.clone(), basis.y.clone(), eps.clone())
-                    {
+                    // Both `(λ - d2, o)` and `(o, λ - d1)` are eigenvectors of the first
+                    // eigenvalue `λ`. One of them may vanish because of cancellations, so use the
+                    // one with the largest norm.
+                    let basis1 = Vector2::new(
+                        eigvals.x.clone() - diag[start + 1].clone(),
+                        off_diag[start].clone(),
+                    );
+                    let basis2 = Vector2::new(
+                        off_diag[start].clone(),
+                        eigvals.x.clone() - diag[start].clone(),
+                    );
+                    let basis = if basis1.norm_squared() >= basis2.norm_squared() {
+                        basis1
+                    } else {
+                        basis2
+                    };
+
+                    // If both vectors vanish, the block is a multiple of the identity and is
+                    // already diagonal.
+                    let scale = diag[start]
+                        .clone()
+                        .abs()
+                        .max(diag[start + 1].clone().abs())
+                        .max(off_diag[start].clone().abs());
+
+                    if let Some((rot, _)) = GivensRotation::try_new(
+                        basis.x.clone(),
+                        basis.y.clone(),
+                        eps.clone() * scale,
+                    ) {
                         let rot = GivensRotation::new_unchecked(rot.c(), T::from_real(rot.s()));
                         rot.rotate_rows(&mut q.fixed_columns_mut::<2>(start));
                     }
                 }
 
+                diag[start] = eigvals[0].clone();
+                diag[start + 1] = eigvals[1].clone();
+
                 end -= 1;
             }
 
@@ -229,6 +557,14 @@ where
             if niter == max_niter {
                 return None;
             }
+
+            if let Some(progress) = progress.as_mut() {
+                let off_diag_norm = off_diag.rows_range(start..end).norm() * m_amax.clone();
+
+                if let ControlFlow::Break(()) = progress(niter, off_diag_norm) {
+                    return None;
+                }
+            }
         }
 
         diag.scale_mut(m_amax);
@@ -287,9 +623,39 @@ where
     /// This is useful if some of the eigenvalues have been manually modified.
     #[must_use]
     pub fn recompose(&self) -> OMatrix<T, D, D> {
+        self.recompose_with(|val| val)
+    }
+
+    /// Rebuild a matrix from this decomposition, after applying `f` to each eigenvalue.
+    ///
+    /// This computes `U * f(Λ) * Uᴴ`, where `U` are the eigenvectors, and `Λ` the eigenvalues.
+    /// With `f = sqrt`, `exp`, or `ln`, this yields the square root, exponential, or logarithm
+    /// of the decomposed matrix. Clamping the negative eigenvalues to zero yields the nearest
+    /// positive-semidefinite matrix in Frobenius norm.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let cov = Matrix2::new(4.0, 1.0,
+    ///                        1.0, 3.0);
+    /// let eigen = cov.symmetric_eigen();
+    ///
+    /// // The inverse square root used to whiten data with covariance `cov`.
+    /// let whitening = eigen.recompose_with(|val: f64| 1.0 / val.sqrt());
+    /// assert_relative_eq!(whitening * cov * whitening, Matrix2::identity(), epsilon = 1.0e-12);
+    ///
+    /// let exp = eigen.recompose_with(f64::exp);
+    /// assert_relative_eq!(exp, cov.exp(), epsilon = 1.0e-9);
+    /// ```
+    #[must_use]
+    pub fn recompose_with<F>(&self, mut f: F) -> OMatrix<T, D, D>
+    where
+        F: FnMut(T::RealField) -> T::RealField,
+    {
         let mut u_t = self.eigenvectors.clone();
         for i in 0..self.eigenvalues.len() {
-            let val = self.eigenvalues[i].clone();
+            let val = f(self.eigenvalues[i].clone());
             u_t.column_mut(i).scale_mut(val);
         }
         u_t.adjoint_mut();
@@ -297,6 +663,72 @@ where
     }
 }
 
+impl<T: RealField, D: DimSub<U1>> SymmetricEigen<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<T, DimDiff<D, U1>>,
+{
+    /// Computes the eigendecomposition of the symmetric tridiagonal matrix with the given
+    /// diagonal and off-diagonal elements.
+    ///
+    /// The implicit QR iterations are performed directly on the tridiagonal form, e.g., computed
+    /// by the Lanczos algorithm, which does not have to be embedded into a dense matrix first.
+    ///
+    /// # Panics
+    /// Panics if `diagonal` is empty or if `off_diagonal` does not have one element less than
+    /// `diagonal`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DVector, SymmetricEigen};
+    /// // The 1D discrete laplacian, with eigenvalues `2 - 2 * cos(i * π / (n + 1))`.
+    /// let n = 50;
+    /// let diagonal = DVector::repeat(n, 2.0);
+    /// let off_diagonal = DVector::repeat(n - 1, -1.0);
+    ///
+    /// let eigen = SymmetricEigen::from_tridiagonal(diagonal, off_diagonal);
+    /// let largest = 2.0 - 2.0 * (n as f64 * std::f64::consts::PI / (n as f64 + 1.0)).cos();
+    ///
+    /// assert_relative_eq!(eigen.eigenvalues.max(), largest, epsilon = 1.0e-12);
+    /// assert!(eigen.eigenvectors.is_orthogonal(1.0e-12));
+    /// ```
+    pub fn from_tridiagonal(
+        diagonal: OVector<T, D>,
+        off_diagonal: OVector<T, DimDiff<D, U1>>,
+    ) -> Self {
+        let dim = diagonal.shape_generic().0;
+        let (eigenvalues, eigenvectors) = Self::do_decompose_tridiagonal(
+            diagonal,
+            off_diagonal,
+            Some(OMatrix::identity_generic(dim, dim)),
+            T::default_epsilon(),
+            0,
+            None,
+        )
+        .unwrap();
+
+        SymmetricEigen {
+            eigenvectors: eigenvectors.unwrap(),
+            eigenvalues,
+        }
+    }
+
+    /// Computes the eigenvalues of the symmetric tridiagonal matrix with the given diagonal and
+    /// off-diagonal elements.
+    ///
+    /// This is the same as `SymmetricEigen::from_tridiagonal`, without computing the
+    /// eigenvectors.
+    #[must_use]
+    pub fn tridiagonal_eigenvalues(
+        diagonal: OVector<T, D>,
+        off_diagonal: OVector<T, DimDiff<D, U1>>,
+    ) -> OVector<T, D> {
+        Self::do_decompose_tridiagonal(diagonal, off_diagonal, None, T::default_epsilon(), 0, None)
+            .unwrap()
+            .0
+    }
+}
+
 /// Computes the wilkinson shift, i.e., the 2x2 symmetric matrix eigenvalue to its tailing
 /// component `tnn`.
 ///
@@ -314,6 +746,116 @@ pub fn wilkinson_shift<T: ComplexField>(tmm: T, tnn: T, tmn: T) -> T {
     }
 }
 
+/// Computes the eigendecomposition of the 2×2 hermitian matrix with diagonal `(a, d)` and lower
+/// off-diagonal element `b`.
+///
+/// The eigenvalues are returned in decreasing order.
+fn hermitian_eigen_2x2<T: ComplexField>(
+    a: T::RealField,
+    b: T,
+    d: T::RealField,
+) -> (Vector2<T::RealField>, Matrix2<T>) {
+    let half: T::RealField = crate::convert(0.5);
+    let mean = a.clone() * half.clone() + d.clone() * half.clone();
+    let diff = a.clone() * half.clone() - d.clone() * half;
+    let radius = diff.clone().hypot(b.clone().modulus());
+
+    // Compute the eigenvector of the largest eigenvalue from the row of `A - λ1 * I` with the
+    // largest diagonal element, which prevents any catastrophic cancellation.
+    let mut x = if a >= d {
+        Vector2::new(T::from_real(diff + radius.clone()), b)
+    } else {
+        Vector2::new(b.conjugate(), T::from_real(radius.clone() - diff))
+    };
+
+    if x.try_normalize_mut(T::RealField::zero()).is_none() {
+        // The matrix is a multiple of the identity.
+        x = Vector2::x();
+    }
+
+    let y = Vector2::new(-x[1].clone().conjugate(), x[0].clone().conjugate());
+
+    (
+        Vector2::new(mean.clone() + radius.clone(), mean - radius),
+        Matrix2::from_columns(&[x, y]),
+    )
+}
+
+/// Computes the eigendecomposition of the 3×3 hermitian matrix `m`, which should have been
+/// scaled so that its entries have moduli at most one.
+///
+/// One eigenvalue is computed with the trigonometric formula for the roots of the characteristic
+/// polynomial, and its eigenvector from the cross products of the rows of `m - λ * I`. The two
+/// other eigenpairs are computed from the projection of `m` on the orthogonal complement of that
+/// eigenvector.
+fn hermitian_eigen_3x3<T: ComplexField>(m: &Matrix3<T>) -> (Vector3<T::RealField>, Matrix3<T>) {
+    let three: T::RealField = crate::convert(3.0);
+    let q = m.trace().real() / three.clone();
+    let shifted = m - Matrix3::from_diagonal_element(T::from_real(q.clone()));
+    let p = (shifted.norm_squared() / crate::convert(6.0)).sqrt();
+
+    if p.is_zero() {
+        return (Vector3::repeat(q), Matrix3::identity());
+    }
+
+    let half_det = (shifted.unscale(p.clone()).determinant().real() * crate::convert(0.5))
+        .max(-T::RealField::one())
+        .min(T::RealField::one());
+
+    // Pick the eigenvalue the furthest from the two others, which is the best separated one.
+    let angle = half_det.clone().acos() / three;
+    let angle = if half_det >= T::RealField::zero() {
+        angle
+    } else {
+        angle + T::RealField::two_pi() / crate::convert(3.0)
+    };
+    let lambda = q.clone() + (p.clone() + p) * angle.cos();
+
+    let shifted = m - Matrix3::from_diagonal_element(T::from_real(lambda));
+    let (r0, r1, r2) = (
+        shifted.row(0).transpose(),
+        shifted.row(1).transpose(),
+        shifted.row(2).transpose(),
+    );
+    let w = [r0.cross(&r1), r0.cross(&r2), r1.cross(&r2)]
+        .iter()
+        .max_by(|a, b| {
+            a.norm_squared()
+                .partial_cmp(&b.norm_squared())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap()
+        .try_normalize(T::RealField::zero())
+        .unwrap_or_else(Vector3::x);
+
+    // Orthonormal basis of the orthogonal complement of `w`.
+    let u = if w[0].clone().modulus() > w[1].clone().modulus() {
+        Vector3::new(
+            -w[2].clone().conjugate(),
+            T::zero(),
+            w[0].clone().conjugate(),
+        )
+    } else {
+        Vector3::new(
+            T::zero(),
+            w[2].clone().conjugate(),
+            -w[1].clone().conjugate(),
+        )
+    }
+    .normalize();
+    let v = w.cross(&u).conjugate().normalize();
+
+    let (mu, mv) = (m * &u, m * &v);
+    let (vals, vecs) = hermitian_eigen_2x2(u.dotc(&mu).real(), v.dotc(&mu), v.dotc(&mv).real());
+    let x = &u * vecs[(0, 0)].clone() + &v * vecs[(1, 0)].clone();
+    let y = &u * vecs[(0, 1)].clone() + &v * vecs[(1, 1)].clone();
+
+    (
+        Vector3::new(w.dotc(&(m * &w)).real(), vals[0].clone(), vals[1].clone()),
+        Matrix3::from_columns(&[w, x, y]),
+    )
+}
+
 /*
  *
  * Computations of eigenvalues for symmetric matrices.
@@ -321,24 +863,22 @@ pub fn wilkinson_shift<T: ComplexField>(tmm: T, tnn: T, tmn: T) -> T {
  */
 impl<T: ComplexField, D: DimSub<U1>, S: Storage<T, D, D>> SquareMatrix<T, D, S>
 where
-    DefaultAllocator: Allocator<T, D, D>
-        + Allocator<T, DimDiff<D, U1>>
-        + Allocator<T::RealField, D>
-        + Allocator<T::RealField, DimDiff<D, U1>>,
+    DefaultAllocator: SymmetricEigenCapable<T, D>,
 {
     /// Computes the eigenvalues of this symmetric matrix.
     ///
     /// Only the lower-triangular part of the matrix is read.
     #[must_use]
     pub fn symmetric_eigenvalues(&self) -> OVector<T::RealField, D> {
-        SymmetricEigen::do_decompose(
-            self.clone_owned(),
-            false,
-            T::RealField::default_epsilon(),
-            0,
-        )
-        .unwrap()
-        .0
+        let m = self.clone_owned();
+
+        if let Some(eigen) = SymmetricEigen::new_closed_form(&m) {
+            return eigen.eigenvalues;
+        }
+
+        SymmetricEigen::do_decompose(m, false, T::RealField::default_epsilon(), 0, None)
+            .unwrap()
+            .0
     }
 }
 
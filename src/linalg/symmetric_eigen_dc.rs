@@ -0,0 +1,358 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use num::Zero;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::base::DMatrix;
+use crate::dimension::Dynamic;
+use crate::linalg::{SymmetricEigen, SymmetricTridiagonal};
+
+impl<T: ComplexField> SymmetricEigen<T, Dynamic> {
+    /// Computes the eigendecomposition of the given symmetric matrix with the divide-and-conquer
+    /// algorithm.
+    ///
+    /// Like LAPACK's `syevd`, the tridiagonal form of the matrix is recursively split in two
+    /// halves whose eigendecompositions are merged by solving the eigenproblem of a rank-one
+    /// modification of a diagonal matrix. The eigenvectors are thus mostly computed with matrix
+    /// products, which is much faster than the implicit QR iterations of `SymmetricEigen::new`
+    /// on large matrices.
+    ///
+    /// The eigenvalues are sorted in increasing order. Only the lower-triangular part (including
+    /// its diagonal) of `m` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{DMatrix, SymmetricEigen};
+    /// let m = DMatrix::from_fn(100, 100, |i, j| 1.0 / (1.0 + i as f64 + j as f64));
+    /// let eigen = SymmetricEigen::new_divide_and_conquer(m.clone());
+    ///
+    /// assert!(eigen.eigenvectors.is_orthogonal(1.0e-12));
+    /// assert_relative_eq!(eigen.recompose(), m, epsilon = 1.0e-12);
+    /// assert!(eigen.eigenvalues.as_slice().windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    pub fn new_divide_and_conquer(mut m: DMatrix<T>) -> Self {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigendecomposition of a non-square matrix."
+        );
+        let m_amax = m.camax();
+
+        if !m_amax.is_zero() {
+            m.unscale_mut(m_amax.clone());
+        }
+
+        let (q, diag, off_diag) = SymmetricTridiagonal::new(m).unpack();
+        let mut eigenvalues = diag;
+        let z = tridiagonal_divide_and_conquer(eigenvalues.as_mut_slice(), off_diag.as_slice());
+        eigenvalues.scale_mut(m_amax);
+
+        SymmetricEigen {
+            eigenvectors: q * z.map(T::from_real),
+            eigenvalues,
+        }
+    }
+}
+
+/// Overwrites `d` with the eigenvalues, in increasing order, of the symmetric tridiagonal
+/// matrix with the diagonal `d` and the off-diagonal `e`, and returns its eigenvectors.
+fn tridiagonal_divide_and_conquer<T: RealField>(d: &mut [T], e: &[T]) -> DMatrix<T> {
+    let n = d.len();
+
+    if n == 1 {
+        return DMatrix::identity(1, 1);
+    }
+
+    // T = diag(T1, T2) + |β| * v * vᵀ with v = [0, …, 0, 1, sign(β), 0, …, 0], where β is the
+    // off-diagonal element coupling both halves.
+    let m = n / 2;
+    let beta = e[m - 1].clone();
+    d[m - 1] -= beta.clone().abs();
+    d[m] -= beta.clone().abs();
+
+    let (d1, d2) = d.split_at_mut(m);
+    let q1 = tridiagonal_divide_and_conquer(d1, &e[..m - 1]);
+    let q2 = tridiagonal_divide_and_conquer(d2, &e[m..]);
+
+    let mut q = DMatrix::zeros(n, n);
+    q.slice_mut((0, 0), (m, m)).copy_from(&q1);
+    q.slice_mut((m, m), (n - m, n - m)).copy_from(&q2);
+
+    // z = diag(Q1, Q2)ᵀ * v / √2, so that T = Q * (D + ρ * z * zᵀ) * Qᵀ with ρ = 2|β|.
+    let frac_1_sqrt_2 = T::one() / crate::convert::<f64, T>(2.0).sqrt();
+    let sign = if beta < T::zero() {
+        -frac_1_sqrt_2.clone()
+    } else {
+        frac_1_sqrt_2.clone()
+    };
+    let mut z = Vec::with_capacity(n);
+    z.extend(
+        q1.row(m - 1)
+            .iter()
+            .map(|e| e.clone() * frac_1_sqrt_2.clone()),
+    );
+    z.extend(q2.row(0).iter().map(|e| e.clone() * sign.clone()));
+    let rho = beta.abs() * crate::convert(2.0);
+
+    merge_rank_one(d, &q, z, rho)
+}
+
+/// Overwrites `d` with the eigenvalues of `D + ρ * z * zᵀ`, where `D = diag(d)` and `ρ >= 0`,
+/// in increasing order, and returns the eigenvectors of `Q * (D + ρ * z * zᵀ) * Qᵀ`.
+fn merge_rank_one<T: RealField>(d: &mut [T], q: &DMatrix<T>, z: Vec<T>, rho: T) -> DMatrix<T> {
+    let n = d.len();
+    let mut perm: Vec<usize> = (0..n).collect();
+    perm.sort_by(|a, b| d[*a].partial_cmp(&d[*b]).unwrap_or(Ordering::Equal));
+
+    let mut ds: Vec<T> = perm.iter().map(|i| d[*i].clone()).collect();
+    let mut zs: Vec<T> = perm.iter().map(|i| z[*i].clone()).collect();
+    let mut qs = q.select_columns(perm.iter());
+
+    // Deflation: the components with a negligible `z_i` are already eigenpairs, and so are the
+    // ones with a (nearly) repeated `d_i`, after a rotation that cancels one of their `z_i`.
+    let dmax = ds.iter().fold(T::zero(), |a, e| a.max(e.clone().abs()));
+    let zmax = zs.iter().fold(T::zero(), |a, e| a.max(e.clone().abs()));
+    let tol = T::default_epsilon() * crate::convert(8.0) * dmax.max(zmax.clone());
+
+    let mut kept = Vec::new();
+
+    if rho.clone() * zmax > tol {
+        let mut prev: Option<usize> = None;
+
+        for j in 0..n {
+            if rho.clone() * zs[j].clone().abs() <= tol {
+                continue;
+            }
+
+            if let Some(p) = prev {
+                let norm = zs[j].clone().hypot(zs[p].clone());
+                let c = zs[j].clone() / norm.clone();
+                let s = -zs[p].clone() / norm.clone();
+                let t = ds[j].clone() - ds[p].clone();
+
+                if (t * c.clone() * s.clone()).abs() <= tol {
+                    zs[j] = norm;
+                    zs[p] = T::zero();
+
+                    for r in 0..n {
+                        let (x, y) = (qs[(r, p)].clone(), qs[(r, j)].clone());
+                        qs[(r, p)] = c.clone() * x.clone() + s.clone() * y.clone();
+                        qs[(r, j)] = c.clone() * y - s.clone() * x;
+                    }
+
+                    let (cc, ss) = (c.clone() * c, s.clone() * s);
+                    let dp = ds[p].clone() * cc.clone() + ds[j].clone() * ss.clone();
+                    ds[j] = ds[p].clone() * ss + ds[j].clone() * cc;
+                    ds[p] = dp;
+                } else {
+                    kept.push(p);
+                }
+            }
+
+            prev = Some(j);
+        }
+
+        if let Some(p) = prev {
+            kept.push(p);
+        }
+    }
+
+    let mut vals = ds.clone();
+    let mut vecs = qs.clone();
+
+    if !kept.is_empty() {
+        let dk: Vec<T> = kept.iter().map(|i| ds[*i].clone()).collect();
+        let zk: Vec<T> = kept.iter().map(|i| zs[*i].clone()).collect();
+        let k = dk.len();
+        let roots: Vec<(usize, T)> = (0..k)
+            .map(|j| secular_root(&dk, &zk, rho.clone(), j))
+            .collect();
+
+        // `d_i - λ_j`, computed accurately from the origin of `λ_j`.
+        let delta = |j: usize, i: usize| {
+            let (o, tau) = &roots[j];
+            (dk[i].clone() - dk[*o].clone()) - tau.clone()
+        };
+
+        // Recompute `z` so that the computed eigenvalues are the exact eigenvalues of a nearby
+        // rank-one modification, which makes the eigenvectors orthogonal (Gu & Eisenstat).
+        let zhat: Vec<T> = (0..k)
+            .map(|i| {
+                let mut prod = -delta(i, i) / rho.clone();
+
+                for j in (0..k).filter(|j| *j != i) {
+                    prod *= -delta(j, i) / (dk[j].clone() - dk[i].clone());
+                }
+
+                let zi = prod.sqrt();
+                if zk[i] < T::zero() {
+                    -zi
+                } else {
+                    zi
+                }
+            })
+            .collect();
+
+        let mut u = DMatrix::from_fn(k, k, |i, j| zhat[i].clone() / delta(j, i));
+        for mut col in u.column_iter_mut() {
+            let _ = col.normalize_mut();
+        }
+
+        let qk = qs.select_columns(kept.iter());
+        let zk = qk * u;
+
+        for (j, i) in kept.iter().enumerate() {
+            let (o, tau) = &roots[j];
+            vals[*i] = dk[*o].clone() + tau.clone();
+            vecs.column_mut(*i).copy_from(&zk.column(j));
+        }
+    }
+
+    sort_eigenpairs(d, &vals, &vecs)
+}
+
+/// Computes the `j`-th root `λ_j` of the secular equation `1 + ρ * Σ z_i² / (d_i - λ) = 0`,
+/// where `d` is strictly increasing, `ρ > 0`, and the `z_i` are nonzero.
+///
+/// The root is returned as the pair `(o, τ)` where `λ_j = d_o + τ` and `d_o` is the pole closest
+/// to `λ_j`, so that the differences `d_i - λ_j` can be computed accurately.
+fn secular_root<T: RealField>(d: &[T], z: &[T], rho: T, j: usize) -> (usize, T) {
+    let k = d.len();
+    let eps = T::default_epsilon();
+
+    // Evaluates the secular function, and the derivatives of its parts with poles at or before
+    // `d_j` (ψ) and after `d_j` (φ).
+    let eval = |o: usize, tau: &T| {
+        let (mut psi, mut dpsi, mut phi, mut dphi) = (T::zero(), T::zero(), T::zero(), T::zero());
+
+        for i in 0..k {
+            let delta = (d[i].clone() - d[o].clone()) - tau.clone();
+            let w = rho.clone() * z[i].clone() * z[i].clone() / delta.clone();
+
+            if i <= j {
+                dpsi += w.clone() / delta;
+                psi += w;
+            } else {
+                dphi += w.clone() / delta;
+                phi += w;
+            }
+        }
+
+        (T::one() + psi.clone() + phi.clone(), psi, dpsi, phi, dphi)
+    };
+
+    let (o, mut lo, mut hi);
+
+    if j + 1 < k {
+        let half_gap = (d[j + 1].clone() - d[j].clone()) * crate::convert(0.5);
+
+        if eval(j, &half_gap).0 >= T::zero() {
+            o = j;
+            lo = T::zero();
+            hi = half_gap;
+        } else {
+            o = j + 1;
+            lo = -half_gap;
+            hi = T::zero();
+        }
+    } else {
+        o = j;
+        lo = T::zero();
+        hi = z.iter().fold(T::zero(), |a, e| a + e.clone() * e.clone()) * rho.clone();
+    }
+
+    let mut tau = (lo.clone() + hi.clone()) * crate::convert(0.5);
+
+    for _ in 0..100 {
+        let (f, psi, dpsi, phi, dphi) = eval(o, &tau);
+
+        if f.clone().abs() <= eps.clone() * crate::convert(8.0) * (T::one() + psi.abs() + phi.abs())
+        {
+            break;
+        }
+
+        if f < T::zero() {
+            lo = tau.clone();
+        } else {
+            hi = tau.clone();
+        }
+
+        // Interpolate ψ and φ by rational functions with poles at `d_j` and `d_{j+1}` matching
+        // their values and derivatives, and move to the root of this interpolant.
+        let a1 = (d[j].clone() - d[o].clone()) - tau.clone();
+        let wa = a1.clone() * a1.clone() * dpsi;
+        let eta = if j + 1 < k {
+            let a2 = (d[j + 1].clone() - d[o].clone()) - tau.clone();
+            let wb = a2.clone() * a2.clone() * dphi;
+            let c = f - wa.clone() / a1.clone() - wb.clone() / a2.clone();
+            let qb = c.clone() * (a1.clone() + a2.clone()) + wa.clone() + wb.clone();
+            let qc = c.clone() * a1.clone() * a2.clone() + wa * a2 + wb * a1;
+
+            if c.is_zero() {
+                qc / qb
+            } else {
+                let sqrt_disc = (qb.clone() * qb.clone()
+                    - c.clone() * qc.clone() * crate::convert(4.0))
+                .max(T::zero())
+                .sqrt();
+
+                // Pick the root lying inside the current bracket.
+                let (r1, r2) = if qb >= T::zero() {
+                    let s = qb + sqrt_disc;
+                    (
+                        s.clone() / (c * crate::convert(2.0)),
+                        qc * crate::convert(2.0) / s,
+                    )
+                } else {
+                    let s = qb - sqrt_disc;
+                    (
+                        qc * crate::convert(2.0) / s.clone(),
+                        s / (c * crate::convert(2.0)),
+                    )
+                };
+
+                let t1 = tau.clone() + r1.clone();
+                if t1 > lo && t1 < hi {
+                    r1
+                } else {
+                    r2
+                }
+            }
+        } else {
+            let c = f - wa.clone() / a1.clone();
+            a1 + wa / c
+        };
+
+        let next = tau.clone() + eta;
+        let next = if next > lo && next < hi && next.is_finite() {
+            next
+        } else {
+            (lo.clone() + hi.clone()) * crate::convert(0.5)
+        };
+
+        if next == tau
+            || hi.clone() - lo.clone() <= eps.clone() * lo.clone().abs().max(hi.clone().abs())
+        {
+            break;
+        }
+
+        tau = next;
+    }
+
+    (o, tau)
+}
+
+/// Writes the eigenvalues `vals` into `d` in increasing order, and returns the eigenvectors
+/// `vecs` sorted accordingly.
+fn sort_eigenpairs<T: RealField>(d: &mut [T], vals: &[T], vecs: &DMatrix<T>) -> DMatrix<T> {
+    let mut perm: Vec<usize> = (0..vals.len()).collect();
+    perm.sort_by(|a, b| vals[*a].partial_cmp(&vals[*b]).unwrap_or(Ordering::Equal));
+
+    for (di, i) in d.iter_mut().zip(perm.iter()) {
+        *di = vals[*i].clone();
+    }
+
+    vecs.select_columns(perm.iter())
+}
@@ -1,12 +1,14 @@
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
+use approx::AbsDiffEq;
+
 use crate::allocator::Allocator;
 use crate::base::{DefaultAllocator, OMatrix, OVector};
 use crate::dimension::{Const, DimDiff, DimSub, U1};
 use simba::scalar::ComplexField;
 
-use crate::linalg::householder;
+use crate::linalg::{householder, SymmetricEigen};
 use crate::Matrix;
 use std::mem::MaybeUninit;
 
@@ -147,6 +149,71 @@ where
         self.off_diagonal.map(T::modulus)
     }
 
+    /// Computes the eigenvalues of the decomposed matrix by performing implicit QR iterations
+    /// on this tridiagonal form.
+    ///
+    /// This is the same as `SquareMatrix::symmetric_eigenvalues` on the decomposed matrix,
+    /// without recomputing its tridiagonalization.
+    #[must_use]
+    pub fn eigenvalues(&self) -> OVector<T::RealField, D>
+    where
+        DefaultAllocator: Allocator<T::RealField, D> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        SymmetricEigen::<T, D>::do_decompose_tridiagonal(
+            self.diagonal(),
+            self.off_diagonal(),
+            None,
+            T::RealField::default_epsilon(),
+            0,
+            None,
+        )
+        .unwrap()
+        .0
+    }
+
+    /// Computes the eigendecomposition of the decomposed matrix by performing implicit QR
+    /// iterations on this tridiagonal form.
+    ///
+    /// The eigenvectors are accumulated onto the orthogonal matrix `Q` of this decomposition,
+    /// so they are the eigenvectors of the decomposed matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix4;
+    /// let m = Matrix4::new(4.0, 1.0, -2.0, 2.0,
+    ///                      1.0, 2.0, 0.0, 1.0,
+    ///                      -2.0, 0.0, 3.0, -2.0,
+    ///                      2.0, 1.0, -2.0, -1.0);
+    /// let tridiagonal = m.symmetric_tridiagonalize();
+    /// let eigenvalues = tridiagonal.eigenvalues();
+    /// let eigen = tridiagonal.eigen();
+    ///
+    /// assert_eq!(eigen.eigenvalues, eigenvalues);
+    /// assert_relative_eq!(eigen.recompose(), m, epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn eigen(self) -> SymmetricEigen<T, D>
+    where
+        DefaultAllocator: Allocator<T::RealField, D> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        let (q, diagonal, off_diagonal) = self.unpack();
+        let (eigenvalues, eigenvectors) = SymmetricEigen::do_decompose_tridiagonal(
+            diagonal,
+            off_diagonal,
+            Some(q),
+            T::RealField::default_epsilon(),
+            0,
+            None,
+        )
+        .unwrap();
+
+        SymmetricEigen {
+            eigenvectors: eigenvectors.unwrap(),
+            eigenvalues,
+        }
+    }
+
     /// Computes the orthogonal matrix `Q` of this decomposition.
     #[must_use]
     pub fn q(&self) -> OMatrix<T, D, D> {
@@ -2,48 +2,51 @@
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::Allocator;
-use crate::base::{Const, DefaultAllocator, OMatrix, OVector};
+use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, OVector};
+use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::Dim;
-use simba::scalar::RealField;
+use crate::storage::{Storage, StorageMut};
+use num::{One, Zero};
+use simba::scalar::ComplexField;
 
-/// UDU factorization.
+/// UDU factorization of a hermitian matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
-    serde(bound(serialize = "OVector<T, D>: Serialize, OMatrix<T, D, D>: Serialize"))
+    serde(bound(serialize = "OVector<T::RealField, D>: Serialize, OMatrix<T, D, D>: Serialize"))
 )]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
     serde(bound(
-        deserialize = "OVector<T, D>: Deserialize<'de>, OMatrix<T, D, D>: Deserialize<'de>"
+        deserialize = "OVector<T::RealField, D>: Deserialize<'de>, OMatrix<T, D, D>: Deserialize<'de>"
     ))
 )]
 #[derive(Clone, Debug)]
-pub struct UDU<T: RealField, D: Dim>
+pub struct UDU<T: ComplexField, D: Dim>
 where
-    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+    DefaultAllocator: Allocator<T::RealField, D> + Allocator<T, D, D>,
 {
     /// The upper triangular matrix resulting from the factorization
     pub u: OMatrix<T, D, D>,
     /// The diagonal matrix resulting from the factorization
-    pub d: OVector<T, D>,
+    pub d: OVector<T::RealField, D>,
 }
 
-impl<T: RealField, D: Dim> Copy for UDU<T, D>
+impl<T: ComplexField, D: Dim> Copy for UDU<T, D>
 where
-    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
-    OVector<T, D>: Copy,
+    DefaultAllocator: Allocator<T::RealField, D> + Allocator<T, D, D>,
+    OVector<T::RealField, D>: Copy,
     OMatrix<T, D, D>: Copy,
 {
 }
 
-impl<T: RealField, D: Dim> UDU<T, D>
+impl<T: ComplexField, D: Dim> UDU<T, D>
 where
-    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+    DefaultAllocator: Allocator<T::RealField, D> + Allocator<T, D, D>,
 {
-    /// Computes the UDU^T factorization.
+    /// Computes the UDU^H factorization.
     ///
-    /// The input matrix `p` is assumed to be symmetric and this decomposition will only read
+    /// The input matrix `p` is assumed to be hermitian and this decomposition will only read
     /// the upper-triangular part of `p`.
     ///
     /// Ref.: "Optimal control and estimation-Dover Publications", Robert F. Stengel, (1994) page 360
@@ -54,34 +57,37 @@ where
         let mut d = OVector::zeros_generic(n_dim, Const::<1>);
         let mut u = OMatrix::zeros_generic(n_dim, n_dim);
 
-        d[n - 1] = p[(n - 1, n - 1)].clone();
+        d[n - 1] = p[(n - 1, n - 1)].clone().real();
 
         if d[n - 1].is_zero() {
             return None;
         }
 
-        u.column_mut(n - 1)
-            .axpy(T::one() / d[n - 1].clone(), &p.column(n - 1), T::zero());
+        u.column_mut(n - 1).axpy(
+            T::from_real(T::RealField::one() / d[n - 1].clone()),
+            &p.column(n - 1),
+            T::zero(),
+        );
 
         for j in (0..n - 1).rev() {
-            let mut d_j = d[j].clone();
+            let mut d_j = T::RealField::zero();
             for k in j + 1..n {
-                d_j += d[k].clone() * u[(j, k)].clone().powi(2);
+                d_j += d[k].clone() * u[(j, k)].clone().modulus_squared();
             }
 
-            d[j] = p[(j, j)].clone() - d_j;
+            d[j] = p[(j, j)].clone().real() - d_j;
 
             if d[j].is_zero() {
                 return None;
             }
 
             for i in (0..=j).rev() {
-                let mut u_ij = u[(i, j)].clone();
+                let mut u_ij = T::zero();
                 for k in j + 1..n {
-                    u_ij += d[k].clone() * u[(j, k)].clone() * u[(i, k)].clone();
+                    u_ij += u[(i, k)].clone() * u[(j, k)].clone().conjugate().scale(d[k].clone());
                 }
 
-                u[(i, j)] = (p[(i, j)].clone() - u_ij) / d[j].clone();
+                u[(i, j)] = (p[(i, j)].clone() - u_ij).unscale(d[j].clone());
             }
 
             u[(j, j)] = T::one();
@@ -93,6 +99,73 @@ where
     /// Returns the diagonal elements as a matrix
     #[must_use]
     pub fn d_matrix(&self) -> OMatrix<T, D, D> {
-        OMatrix::from_diagonal(&self.d)
+        let shape = self.u.shape_generic();
+        let mut res = OMatrix::zeros_generic(shape.0, shape.1);
+        res.set_partial_diagonal(self.d.iter().map(|e| T::from_real(e.clone())));
+        res
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    ///
+    /// The result is stored on `b`, which may have several columns, i.e., right-hand sides. No
+    /// allocation is performed.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Matrix3x2};
+    /// let m = Matrix3::new(4.0, 1.0, 0.0, 1.0, -3.0, 1.0, 0.0, 1.0, 2.0);
+    /// let udu = m.udu().unwrap();
+    /// let b = Matrix3x2::new(1.0, 0.0, 2.0, 1.0, 3.0, -1.0);
+    ///
+    /// let mut x = b;
+    /// udu.solve_mut(&mut x);
+    /// assert_relative_eq!(m * x, b, epsilon = 1.0e-10);
+    /// ```
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        self.u.solve_upper_triangular_unchecked_mut(b);
+
+        for i in 0..self.d.len() {
+            b.row_mut(i).unscale_mut(self.d[i].clone());
+        }
+
+        self.u.ad_solve_upper_triangular_unchecked_mut(b);
+    }
+
+    /// Returns the solution of the system `self * x = b` where `self` is the decomposed matrix and
+    /// `x` the unknown.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> OMatrix<T, R2, C2>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_mut(&mut res);
+        res
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    #[must_use]
+    pub fn inverse(&self) -> OMatrix<T, D, D> {
+        let shape = self.u.shape_generic();
+        let mut res = OMatrix::identity_generic(shape.0, shape.1);
+
+        self.solve_mut(&mut res);
+        res
+    }
+
+    /// Computes the determinant of the decomposed matrix, i.e., the product of the elements of
+    /// `D`.
+    #[must_use]
+    pub fn determinant(&self) -> T::RealField {
+        self.d
+            .iter()
+            .fold(T::RealField::one(), |acc, e| acc * e.clone())
     }
 }
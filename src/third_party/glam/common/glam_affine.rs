@@ -0,0 +1,141 @@
+use super::glam::{Affine2, Affine3A, DAffine2, DAffine3, DMat3, DMat4, Mat3, Mat4, Vec3};
+use crate::{Affine2 as NaAffine2, Affine3, Isometry2, Isometry3, Matrix3, Matrix4, Similarity3};
+use std::convert::TryFrom;
+
+impl From<Affine2> for Matrix3<f32> {
+    fn from(affine: Affine2) -> Matrix3<f32> {
+        Mat3::from(affine).into()
+    }
+}
+
+impl From<Affine3A> for Matrix4<f32> {
+    fn from(affine: Affine3A) -> Matrix4<f32> {
+        Mat4::from(affine).into()
+    }
+}
+
+impl From<DAffine2> for Matrix3<f64> {
+    fn from(affine: DAffine2) -> Matrix3<f64> {
+        DMat3::from(affine).into()
+    }
+}
+
+impl From<DAffine3> for Matrix4<f64> {
+    fn from(affine: DAffine3) -> Matrix4<f64> {
+        DMat4::from(affine).into()
+    }
+}
+
+impl From<Affine2> for NaAffine2<f32> {
+    fn from(affine: Affine2) -> NaAffine2<f32> {
+        NaAffine2::from_matrix_unchecked(affine.into())
+    }
+}
+
+impl From<Affine3A> for Affine3<f32> {
+    fn from(affine: Affine3A) -> Affine3<f32> {
+        Affine3::from_matrix_unchecked(affine.into())
+    }
+}
+
+impl From<DAffine2> for NaAffine2<f64> {
+    fn from(affine: DAffine2) -> NaAffine2<f64> {
+        NaAffine2::from_matrix_unchecked(affine.into())
+    }
+}
+
+impl From<DAffine3> for Affine3<f64> {
+    fn from(affine: DAffine3) -> Affine3<f64> {
+        Affine3::from_matrix_unchecked(affine.into())
+    }
+}
+
+impl From<NaAffine2<f32>> for Affine2 {
+    fn from(affine: NaAffine2<f32>) -> Affine2 {
+        Affine2::from_mat3(affine.into_inner().into())
+    }
+}
+
+impl From<Affine3<f32>> for Affine3A {
+    fn from(affine: Affine3<f32>) -> Affine3A {
+        Affine3A::from_mat4(affine.into_inner().into())
+    }
+}
+
+impl From<NaAffine2<f64>> for DAffine2 {
+    fn from(affine: NaAffine2<f64>) -> DAffine2 {
+        DAffine2::from_mat3(affine.into_inner().into())
+    }
+}
+
+impl From<Affine3<f64>> for DAffine3 {
+    fn from(affine: Affine3<f64>) -> DAffine3 {
+        DAffine3::from_mat4(affine.into_inner().into())
+    }
+}
+
+impl From<Isometry2<f32>> for Affine2 {
+    fn from(iso: Isometry2<f32>) -> Affine2 {
+        Affine2::from_mat3(iso.to_homogeneous().into())
+    }
+}
+
+impl From<Isometry3<f32>> for Affine3A {
+    fn from(iso: Isometry3<f32>) -> Affine3A {
+        Affine3A::from_rotation_translation(iso.rotation.into(), iso.translation.into())
+    }
+}
+
+impl From<Isometry2<f64>> for DAffine2 {
+    fn from(iso: Isometry2<f64>) -> DAffine2 {
+        DAffine2::from_mat3(iso.to_homogeneous().into())
+    }
+}
+
+impl From<Isometry3<f64>> for DAffine3 {
+    fn from(iso: Isometry3<f64>) -> DAffine3 {
+        DAffine3::from_rotation_translation(iso.rotation.into(), iso.translation.into())
+    }
+}
+
+impl From<Similarity3<f32>> for Affine3A {
+    fn from(sim: Similarity3<f32>) -> Affine3A {
+        Affine3A::from_scale_rotation_translation(
+            Vec3::splat(sim.scaling()),
+            sim.isometry.rotation.into(),
+            sim.isometry.translation.into(),
+        )
+    }
+}
+
+impl TryFrom<Affine2> for Isometry2<f32> {
+    type Error = ();
+
+    fn try_from(affine: Affine2) -> Result<Isometry2<f32>, Self::Error> {
+        crate::try_convert(Matrix3::from(affine)).ok_or(())
+    }
+}
+
+impl TryFrom<Affine3A> for Isometry3<f32> {
+    type Error = ();
+
+    fn try_from(affine: Affine3A) -> Result<Isometry3<f32>, Self::Error> {
+        crate::try_convert(Matrix4::from(affine)).ok_or(())
+    }
+}
+
+impl TryFrom<DAffine2> for Isometry2<f64> {
+    type Error = ();
+
+    fn try_from(affine: DAffine2) -> Result<Isometry2<f64>, Self::Error> {
+        crate::try_convert(Matrix3::from(affine)).ok_or(())
+    }
+}
+
+impl TryFrom<DAffine3> for Isometry3<f64> {
+    type Error = ();
+
+    fn try_from(affine: DAffine3) -> Result<Isometry3<f64>, Self::Error> {
+        crate::try_convert(Matrix4::from(affine)).ok_or(())
+    }
+}
@@ -8,3 +8,5 @@ mod v015;
 mod v016;
 #[cfg(feature = "glam017")]
 mod v017;
+#[cfg(feature = "glam018")]
+mod v018;
@@ -3,3 +3,5 @@ mod alga;
 mod glam;
 #[cfg(feature = "mint")]
 mod mint;
+#[cfg(feature = "ndarray")]
+mod ndarray;
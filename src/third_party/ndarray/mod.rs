@@ -0,0 +1 @@
+mod ndarray_matrix;
@@ -0,0 +1,212 @@
+use std::convert::TryFrom;
+
+use ndarray::{
+    Array1, Array2, ArrayBase, ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2, Data, Ix1,
+    Ix2, ShapeBuilder,
+};
+
+use crate::base::dimension::{Const, Dim, Dynamic, U1};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{
+    DMatrix, DMatrixSlice, DMatrixSliceMut, DVector, DVectorSlice, DVectorSliceMut, Matrix, Scalar,
+    SliceStorage, SliceStorageMut,
+};
+
+/*
+ *
+ * Owned conversions.
+ *
+ */
+impl<T: Scalar> From<DMatrix<T>> for Array2<T> {
+    #[inline]
+    fn from(m: DMatrix<T>) -> Self {
+        let shape = (m.nrows(), m.ncols()).f();
+        // The column-major buffer of the matrix is exactly an array in Fortran order.
+        Array2::from_shape_vec(shape, m.data.into()).expect("Matrix data has the right length.")
+    }
+}
+
+impl<T: Scalar> From<DVector<T>> for Array1<T> {
+    #[inline]
+    fn from(v: DVector<T>) -> Self {
+        Array1::from(Vec::from(v.data))
+    }
+}
+
+impl<T: Scalar, S: Data<Elem = T>> From<ArrayBase<S, Ix2>> for DMatrix<T> {
+    #[inline]
+    fn from(array: ArrayBase<S, Ix2>) -> Self {
+        let (nrows, ncols) = array.dim();
+
+        if let Some(data) = array.t().as_slice() {
+            // Fortran order.
+            DMatrix::from_column_slice(nrows, ncols, data)
+        } else if let Some(data) = array.as_slice() {
+            // C order.
+            DMatrix::from_row_slice(nrows, ncols, data)
+        } else {
+            DMatrix::from_fn(nrows, ncols, |i, j| array[(i, j)].clone())
+        }
+    }
+}
+
+impl<T: Scalar, S: Data<Elem = T>> From<ArrayBase<S, Ix1>> for DVector<T> {
+    #[inline]
+    fn from(array: ArrayBase<S, Ix1>) -> Self {
+        match array.as_slice() {
+            Some(data) => DVector::from_column_slice(data),
+            None => DVector::from_iterator(array.len(), array.iter().cloned()),
+        }
+    }
+}
+
+/*
+ *
+ * Zero-copy views from nalgebra to ndarray.
+ *
+ */
+impl<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> From<&'a Matrix<T, R, C, S>>
+    for ArrayView2<'a, T>
+{
+    #[inline]
+    fn from(m: &'a Matrix<T, R, C, S>) -> Self {
+        let shape = (m.nrows(), m.ncols()).strides(m.strides());
+        // Safety: the shape and strides describe exactly the elements of the matrix, which
+        // are borrowed for 'a.
+        unsafe { ArrayView2::from_shape_ptr(shape, m.as_ptr()) }
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> From<&'a mut Matrix<T, R, C, S>>
+    for ArrayViewMut2<'a, T>
+{
+    #[inline]
+    fn from(m: &'a mut Matrix<T, R, C, S>) -> Self {
+        let shape = (m.nrows(), m.ncols()).strides(m.strides());
+        // Safety: the shape and strides describe exactly the elements of the matrix, which
+        // are mutably borrowed for 'a.
+        unsafe { ArrayViewMut2::from_shape_ptr(shape, m.as_mut_ptr()) }
+    }
+}
+
+impl<'a, T, R: Dim, S: RawStorage<T, R>> From<&'a Matrix<T, R, U1, S>> for ArrayView1<'a, T> {
+    #[inline]
+    fn from(v: &'a Matrix<T, R, U1, S>) -> Self {
+        let shape = v.nrows().strides(v.strides().0);
+        // Safety: see the matrix case.
+        unsafe { ArrayView1::from_shape_ptr(shape, v.as_ptr()) }
+    }
+}
+
+impl<'a, T, R: Dim, S: RawStorageMut<T, R>> From<&'a mut Matrix<T, R, U1, S>>
+    for ArrayViewMut1<'a, T>
+{
+    #[inline]
+    fn from(v: &'a mut Matrix<T, R, U1, S>) -> Self {
+        let shape = v.nrows().strides(v.strides().0);
+        // Safety: see the matrix case.
+        unsafe { ArrayViewMut1::from_shape_ptr(shape, v.as_mut_ptr()) }
+    }
+}
+
+/*
+ *
+ * Zero-copy views from ndarray to nalgebra.
+ * These fail if the array has negative strides, which can't be represented by matrix slices.
+ *
+ */
+#[inline]
+fn matrix_strides(strides: &[isize]) -> Result<(Dynamic, Dynamic), ()> {
+    if strides[0] < 0 || strides[1] < 0 {
+        Err(())
+    } else {
+        Ok((
+            Dynamic::new(strides[0] as usize),
+            Dynamic::new(strides[1] as usize),
+        ))
+    }
+}
+
+#[inline]
+fn vector_strides(strides: &[isize]) -> Result<(Dynamic, Dynamic), ()> {
+    if strides[0] < 0 {
+        Err(())
+    } else {
+        // The column stride of a vector is irrelevant.
+        Ok((Dynamic::new(strides[0] as usize), Dynamic::new(0)))
+    }
+}
+
+impl<'a, T> TryFrom<ArrayView2<'a, T>> for DMatrixSlice<'a, T, Dynamic, Dynamic> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(view: ArrayView2<'a, T>) -> Result<Self, ()> {
+        let (nrows, ncols) = view.dim();
+        let strides = matrix_strides(view.strides())?;
+
+        unsafe {
+            let data = SliceStorage::from_raw_parts(
+                view.as_ptr(),
+                (Dynamic::new(nrows), Dynamic::new(ncols)),
+                strides,
+            );
+            Ok(Matrix::from_data_statically_unchecked(data))
+        }
+    }
+}
+
+impl<'a, T> TryFrom<ArrayViewMut2<'a, T>> for DMatrixSliceMut<'a, T, Dynamic, Dynamic> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(mut view: ArrayViewMut2<'a, T>) -> Result<Self, ()> {
+        let (nrows, ncols) = view.dim();
+        let strides = matrix_strides(view.strides())?;
+
+        unsafe {
+            let data = SliceStorageMut::from_raw_parts(
+                view.as_mut_ptr(),
+                (Dynamic::new(nrows), Dynamic::new(ncols)),
+                strides,
+            );
+            Ok(Matrix::from_data_statically_unchecked(data))
+        }
+    }
+}
+
+impl<'a, T> TryFrom<ArrayView1<'a, T>> for DVectorSlice<'a, T, Dynamic, Dynamic> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(view: ArrayView1<'a, T>) -> Result<Self, ()> {
+        let strides = vector_strides(view.strides())?;
+
+        unsafe {
+            let data = SliceStorage::from_raw_parts(
+                view.as_ptr(),
+                (Dynamic::new(view.len()), Const::<1>),
+                strides,
+            );
+            Ok(Matrix::from_data_statically_unchecked(data))
+        }
+    }
+}
+
+impl<'a, T> TryFrom<ArrayViewMut1<'a, T>> for DVectorSliceMut<'a, T, Dynamic, Dynamic> {
+    type Error = ();
+
+    #[inline]
+    fn try_from(mut view: ArrayViewMut1<'a, T>) -> Result<Self, ()> {
+        let strides = vector_strides(view.strides())?;
+
+        unsafe {
+            let data = SliceStorageMut::from_raw_parts(
+                view.as_mut_ptr(),
+                (Dynamic::new(view.len()), Const::<1>),
+                strides,
+            );
+            Ok(Matrix::from_data_statically_unchecked(data))
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use na::{
+    DMatrix, DMatrixArrayVec, DVector, DVectorArrayVec, Dynamic, Matrix2x3, Matrix3, Vector3,
+};
+
+#[test]
+fn array_vec_layout() {
+    let mut m = Matrix2x3::new(1, 2, 3, 4, 5, 6).to_array_vec::<8>();
+
+    assert_eq!(m, Matrix2x3::new(1, 2, 3, 4, 5, 6));
+    assert_eq!(m.data.capacity(), 8);
+    assert_eq!(m.data.len(), 6);
+    assert_eq!(m.as_slice(), &[1, 4, 2, 5, 3, 6]);
+
+    m[(0, 1)] = 20;
+    m.column_mut(2).fill(0);
+    assert_eq!(m.as_slice(), &[1, 4, 20, 5, 0, 0]);
+
+    // Only the components within the current shape are compared.
+    let mut a = DMatrixArrayVec::<i32, 8>::from_element_array_vec(2, 2, 1);
+    let mut b = DMatrixArrayVec::<i32, 8>::from_element_array_vec(2, 3, 2);
+    b.fill(1);
+    b.data.set_shape(Dynamic::new(2), Dynamic::new(2));
+    assert_eq!(a, b);
+    assert_eq!(a.data, b.data);
+    a.data.set_shape(Dynamic::new(4), Dynamic::new(2));
+    assert_eq!(a.shape(), (4, 2));
+}
+
+#[test]
+fn array_vec_ops() {
+    let a = Matrix3::new(4.0, 1.0, 2.0, 1.0, 5.0, 3.0, 2.0, 3.0, 6.0);
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    let da: DMatrixArrayVec<f64, 32> =
+        DMatrix::from_column_slice(3, 3, a.as_slice()).to_array_vec();
+    let db: DVectorArrayVec<f64, 8> = DVector::from_column_slice(b.as_slice()).to_array_vec();
+
+    let mut y = DVectorArrayVec::<f64, 8>::zeros_array_vec(3);
+    y.gemv(1.0, &da, &db, 0.0);
+    assert_eq!(y, a * b);
+
+    let mut c = DMatrixArrayVec::<f64, 16>::zeros_array_vec(3, 3);
+    c.gemm(1.0, &da, &da, 0.0);
+    assert_eq!(c, a * a);
+
+    let mut x = db.clone();
+    assert!(da.lower_triangle().solve_lower_triangular_mut(&mut x));
+    assert_relative_eq!(a.lower_triangle() * x, b, epsilon = 1.0e-12);
+
+    assert_eq!(da.norm(), a.norm());
+    assert_eq!(da.slice((1, 1), (2, 2)), a.slice((1, 1), (2, 2)));
+
+    // Operations returning a new matrix return a heap-allocated one.
+    let sum: DMatrix<f64> = &da + &da;
+    assert_eq!(sum, a * 2.0);
+    assert_eq!(
+        da.clone_owned(),
+        DMatrix::from_column_slice(3, 3, a.as_slice())
+    );
+    assert_relative_eq!(
+        da.lu().solve(&db).unwrap().as_slice(),
+        a.lu().solve(&b).unwrap().as_slice()
+    );
+}
+
+#[test]
+#[should_panic]
+fn array_vec_capacity_exceeded() {
+    let _ = DMatrixArrayVec::<f64, 8>::zeros_array_vec(3, 3);
+}
+
+#[test]
+#[should_panic]
+fn array_vec_capacity_overflow() {
+    // `nrows × ncols` overflows `usize`, and would wrap around to zero in release builds.
+    let _ = DMatrixArrayVec::<u64, 4>::from_element_array_vec(usize::MAX / 2 + 1, 2, 7);
+}
@@ -1,4 +1,4 @@
-use na::{geometry::Quaternion, Matrix2, Vector3};
+use na::{geometry::Quaternion, Complex, DMatrix, DVector, Matrix2, Vector3};
 use num_traits::{One, Zero};
 
 #[test]
@@ -21,6 +21,195 @@ fn gemm_noncommutative() {
     assert_eq!(res, Matrix2::zero());
 }
 
+#[test]
+fn axpy_strided() {
+    let m = DMatrix::from_fn(5, 2, |i, j| (i + 10 * j) as f64);
+    let x = m.rows_with_step(0, 3, 1);
+    let x = x.column(1);
+
+    let mut y = DVector::from_element(3, 1.0);
+    y.axpy(2.0, &x, 1.0);
+    assert_eq!(y, DVector::from_column_slice(&[21.0, 25.0, 29.0]));
+
+    let mut y = DVector::from_element(3, 1.0);
+    y.axpy(2.0, &x, 0.0);
+    assert_eq!(y, DVector::from_column_slice(&[20.0, 24.0, 28.0]));
+}
+
+#[test]
+fn gemm_mixed() {
+    let a = DMatrix::from_fn(3, 50, |i, j| ((i * 7 + j * 13) % 17) as f32 * 0.1 - 0.8);
+    let b = DMatrix::from_fn(50, 4, |i, j| ((i * 5 + j * 11) % 19) as f32 * 0.3 - 2.7);
+
+    // The result matches the product computed in f64.
+    let expected: DMatrix<f64> = a.clone().cast::<f64>() * b.clone().cast::<f64>() * 2.0;
+    let mut res = DMatrix::from_element(3, 4, f64::NAN);
+    res.gemm_mixed(2.0, &a, &b, 0.0);
+    assert_relative_eq!(res, expected, epsilon = 1.0e-12);
+
+    let mut res = DMatrix::from_element(3, 4, 1.0);
+    res.gemm_mixed(2.0, &a, &b, -1.0);
+    assert_relative_eq!(res, expected.add_scalar(-1.0), epsilon = 1.0e-12);
+
+    // Integer products that would overflow `i8`.
+    let a = DMatrix::from_element(2, 10, 127i8);
+    let b = DMatrix::from_element(10, 3, -128i8);
+    let mut res = DMatrix::<i32>::zeros(2, 3);
+    res.gemm_mixed(1, &a, &b, 0);
+    assert_eq!(res, DMatrix::from_element(2, 3, -162_560));
+}
+
+#[test]
+fn trace_of_products() {
+    let a = DMatrix::from_fn(4, 6, |i, j| (i * 6 + j) as f64 * 0.5 - 3.0);
+    let b = DMatrix::from_fn(6, 4, |i, j| ((i * 3 + j * 5) % 7) as f64 - 2.0);
+    let c = DMatrix::from_fn(4, 6, |i, j| ((i + 2 * j) % 5) as f64 + 0.25);
+
+    assert_relative_eq!(a.mul_trace(&b), (&a * &b).trace(), epsilon = 1.0e-10);
+    assert_relative_eq!(a.tr_mul_trace(&c), a.tr_mul(&c).trace(), epsilon = 1.0e-10);
+    assert_relative_eq!(a.ad_mul_trace(&c), a.ad_mul(&c).trace(), epsilon = 1.0e-10);
+
+    // Slices with non-trivial strides.
+    let a = a.fixed_slice::<3, 3>(1, 2);
+    let b = b.fixed_slice::<3, 3>(2, 1);
+    assert_relative_eq!(a.mul_trace(&b), (a * b).trace(), epsilon = 1.0e-10);
+    assert_relative_eq!(a.tr_mul_trace(&b), a.tr_mul(&b).trace(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn gram_matrices() {
+    let m = DMatrix::from_fn(5, 3, |i, j| {
+        Complex::new((i * 3 + j) as f64 * 0.3 - 1.0, (i as f64 - j as f64) * 0.7)
+    });
+
+    let gram = m.gram();
+    assert_relative_eq!(gram, m.adjoint() * &m, epsilon = 1.0e-12);
+    assert_eq!(gram, gram.adjoint());
+    assert!(gram.diagonal().iter().all(|e| e.im == 0.0));
+
+    let outer = m.outer_gram();
+    assert_relative_eq!(outer, &m * m.adjoint(), epsilon = 1.0e-12);
+    assert_eq!(outer, outer.adjoint());
+    assert!(outer.diagonal().iter().all(|e| e.im == 0.0));
+
+    let b = DMatrix::from_fn(5, 2, |i, j| Complex::new(i as f64, j as f64));
+    assert_eq!(m.cross_covariance(&b), m.ad_mul(&b));
+}
+
+#[test]
+fn gram_cholesky() {
+    // The Gram matrix of a full-rank matrix is symmetric positive-definite.
+    let m = DMatrix::from_fn(40, 12, |i, j| ((i * 17 + j * 31) % 23) as f64 / 7.0 - 1.5);
+    let gram = m.gram();
+    let l = gram.clone().cholesky().unwrap().unpack();
+
+    assert_eq!(gram, gram.transpose());
+    assert_relative_eq!(&l * l.transpose(), gram, epsilon = 1.0e-10);
+    let outer = m.transpose().outer_gram();
+    assert_eq!(outer, outer.transpose());
+    assert_relative_eq!(outer, gram, epsilon = 1.0e-10);
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(empty.gram(), DMatrix::zeros(3, 3));
+}
+
+#[test]
+fn outer_into_and_rank1_update() {
+    let m = DMatrix::from_fn(6, 3, |i, j| ((i * 5 + j * 7) % 11) as f64 - 4.0);
+    let mut scatter = DMatrix::zeros(3, 3);
+    let mut outer = DMatrix::repeat(3, 3, f64::NAN);
+
+    for row in m.row_iter() {
+        let x = row.transpose();
+        x.outer_into(&x, &mut outer);
+        assert_eq!(outer, &x * x.transpose());
+        scatter.rank1_update(2.0, &x, &x);
+    }
+
+    assert_eq!(scatter, m.gram() * 2.0);
+
+    // Outer products of vectors of different dimensions into a slice.
+    let u = DVector::from_vec(vec![1.0, -2.0]);
+    let v = Vector3::new(0.5, 1.0, 3.0);
+    let mut big = DMatrix::zeros(4, 4);
+    u.outer_into(&v, &mut big.slice_mut((1, 1), (2, 3)));
+    assert_eq!(big.slice((1, 1), (2, 3)), &u * v.transpose());
+    assert_eq!(big.row(0).sum() + big.column(0).sum(), 0.0);
+}
+
+#[test]
+fn lazy_transpose_products() {
+    let a = DMatrix::from_fn(5, 3, |i, j| (i * 3 + j) as f64 * 0.5 - 2.0);
+    let b = DMatrix::from_fn(5, 4, |i, j| ((i + 2 * j) % 7) as f64 - 3.0);
+    let c = DMatrix::from_fn(4, 4, |i, j| (i * 4 + j * j) as f64 * 0.25 - 1.0);
+
+    assert_eq!(a.t() * &b, a.tr_mul(&b));
+    assert_eq!(a.t().eval(), a.transpose());
+    assert_eq!(a.t().shape(), (3, 5));
+    assert_relative_eq!(&b * c.t(), &b * c.transpose(), epsilon = 1.0e-12);
+    assert_relative_eq!(c.t() * b.t(), (&b * &c).transpose(), epsilon = 1.0e-12);
+    assert_relative_eq!(
+        2.0 * a.t() * &b * 3.0,
+        a.transpose() * &b * 6.0,
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        &b * (c.t() * -1.0),
+        -(&b * c.transpose()),
+        epsilon = 1.0e-12
+    );
+
+    // Slices can be used as operands and as output.
+    let mut out = DMatrix::repeat(4, 5, f64::NAN);
+    let mut sub = out.slice_mut((1, 1), (3, 4));
+    a.rows(1, 3).t().mul_to(&b.rows(0, 3), &mut sub);
+    assert_eq!(sub, a.rows(1, 3).tr_mul(&b.rows(0, 3)));
+    assert!(out.row(0).iter().all(|e| e.is_nan()));
+
+    // Large and strided operands of `A * op(B)`.
+    let d = DMatrix::from_fn(40, 30, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+    let e = DMatrix::from_fn(35, 30, |i, j| ((i * 5 + j * 2) % 13) as f64 * 0.5);
+    assert_eq!(&d * e.t(), &d * e.transpose());
+    assert_eq!(
+        d.slice((1, 2), (20, 25)) * e.slice((3, 1), (15, 25)).t(),
+        d.slice((1, 2), (20, 25)) * e.slice((3, 1), (15, 25)).transpose()
+    );
+
+    let m = DMatrix::from_fn(3, 2, |i, j| {
+        Complex::new((i * 2 + j) as f64 - 1.0, (i as f64 - j as f64) * 0.5)
+    });
+    let v = DVector::from_fn(3, |i, _| Complex::new(1.0, i as f64));
+    let w = DMatrix::from_fn(4, 3, |i, j| Complex::new(j as f64, i as f64 - 1.0));
+    let k = Complex::new(0.5, -2.0);
+
+    assert_eq!(m.adjoint_view() * &v, m.ad_mul(&v));
+    assert_eq!(m.adjoint_view().eval(), m.adjoint());
+    assert_relative_eq!(&m * m.adjoint_view(), &m * m.adjoint(), epsilon = 1.0e-12);
+    assert_relative_eq!(
+        m.adjoint_view() * w.adjoint_view() * k,
+        (&w * &m).adjoint() * k,
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        w.columns(1, 2) * (k * m.rows(0, 2).adjoint_view()),
+        w.columns(1, 2) * m.rows(0, 2).adjoint() * k,
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        (m.t() * k) * v.clone(),
+        m.transpose() * &v * k,
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+#[should_panic]
+fn lazy_transpose_dimension_mismatch() {
+    let a = DMatrix::<f64>::zeros(3, 2);
+    let b = DMatrix::<f64>::zeros(3, 4);
+    let _ = &a * b.t();
+}
+
 #[cfg(feature = "proptest-support")]
 mod blas_proptest {
     use crate::proptest::{PROPTEST_F64, PROPTEST_MATRIX_DIM};
@@ -118,3 +307,33 @@ mod blas_proptest {
         }
     }
 }
+
+#[test]
+fn matrix4_f32_products() {
+    use na::{Matrix4, Matrix4x3, Matrix5, Vector4};
+
+    let a = Matrix4::<f32>::from_fn(|i, j| ((i * 7 + j * 3) % 5) as f32 - 1.5);
+    let b = Matrix4::<f32>::from_fn(|i, j| (i as f32 - j as f32) * 0.25 + 1.0);
+    let b3 = Matrix4x3::<f32>::from_fn(|i, j| (i * j) as f32 - 2.0);
+    let v = Vector4::new(1.0f32, -2.0, 0.5, 3.0);
+
+    assert_eq!(a * b, (a.cast::<f64>() * b.cast::<f64>()).cast::<f32>());
+    assert_eq!(a * b3, (a.cast::<f64>() * b3.cast::<f64>()).cast::<f32>());
+    assert_eq!(a * v, (a.cast::<f64>() * v.cast::<f64>()).cast::<f32>());
+    assert_eq!(a.transpose() * v, a.tr_mul(&v));
+
+    let mut c = b;
+    c.gemm(2.0, &a, &b, 0.5);
+    let expected = a.cast::<f64>() * b.cast::<f64>() * 2.0 + b.cast::<f64>() * 0.5;
+    assert_relative_eq!(c, expected.cast::<f32>(), epsilon = 1.0e-5);
+
+    // Strided views and dynamically-sized matrices.
+    let m5 = Matrix5::<f32>::from_fn(|i, j| (i * 5 + j) as f32);
+    let a_view = m5.fixed_slice::<4, 4>(1, 1);
+    assert_eq!(a_view * v, a_view.into_owned() * v);
+    assert_eq!(a_view * b, a_view.into_owned() * b);
+
+    let da = na::DMatrix::from_column_slice(4, 4, a.as_slice());
+    let db = na::DMatrix::from_column_slice(4, 4, b.as_slice());
+    assert_eq!((da * db).as_slice(), (a * b).as_slice());
+}
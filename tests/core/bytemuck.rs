@@ -0,0 +1,53 @@
+use na::{
+    Isometry3, IsometryMatrix3, Matrix4, Point3, Projective3, Quaternion, Rotation3, Shear3,
+    Similarity3, Translation3, Twist, UnitQuaternion, Vector3,
+};
+
+#[test]
+fn bytemuck_cast_matrices() {
+    let matrices = [
+        Matrix4::<f32>::identity(),
+        Matrix4::from_fn(|i, j| (i * 4 + j) as f32),
+    ];
+    let floats: &[f32] = bytemuck::cast_slice(&matrices);
+
+    assert_eq!(floats.len(), 32);
+    assert_eq!(floats[0], 1.0);
+    // Matrices are stored in column-major order.
+    assert_eq!(floats[16 + 1], 4.0);
+
+    let back: &[Matrix4<f32>] = bytemuck::cast_slice(floats);
+    assert_eq!(back, &matrices[..]);
+}
+
+#[test]
+fn bytemuck_cast_geometric_types() {
+    let iso = Isometry3::new(Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let floats: &[f32; 7] = bytemuck::cast_ref(&iso);
+    assert_eq!(&floats[..4], iso.rotation.coords.as_slice());
+    assert_eq!(&floats[4..], iso.translation.vector.as_slice());
+
+    let sim = Similarity3::from_isometry(iso, 2.0);
+    let floats: &[f32; 8] = bytemuck::cast_ref(&sim);
+    assert_eq!(floats[7], 2.0);
+
+    let iso = IsometryMatrix3::new(Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let floats: &[f32; 12] = bytemuck::cast_ref(&iso);
+    assert_eq!(&floats[..9], iso.rotation.matrix().as_slice());
+    assert_eq!(&floats[9..], iso.translation.vector.as_slice());
+
+    let points = [Point3::new(1.0f32, 2.0, 3.0), Point3::new(4.0, 5.0, 6.0)];
+    let floats: &[f32] = bytemuck::cast_slice(&points);
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let twist = Twist::new(Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0));
+    let floats: &[f32; 6] = bytemuck::cast_ref(&twist);
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let _: Quaternion<f32> = bytemuck::Zeroable::zeroed();
+    let _: UnitQuaternion<f32> = bytemuck::Zeroable::zeroed();
+    let _: Rotation3<f32> = bytemuck::Zeroable::zeroed();
+    let _: Translation3<f32> = bytemuck::Zeroable::zeroed();
+    let _: Shear3<f32> = bytemuck::Zeroable::zeroed();
+    let _: Projective3<f32> = bytemuck::Zeroable::zeroed();
+}
@@ -1,4 +1,7 @@
-use na::{Matrix3, Matrix4, Point2, Point3, Vector2, Vector3};
+use na::{
+    Matrix3, Matrix4, Point2, Point3, Shear3, Transform3, Translation3, UnitQuaternion, Vector2,
+    Vector3,
+};
 
 /// See Example 3.4 of "Graphics and Visualization: Principles & Algorithms"
 /// by Theoharis, Papaioannou, Platis, Patrikalakis.
@@ -57,3 +60,42 @@ fn test_scaling_wrt_point_3() {
 
     assert!(result == expected);
 }
+
+#[test]
+fn test_decompose_trs_with_shear_and_reflection() {
+    let t = Translation3::new(1.0, -2.0, 3.0);
+    let r = UnitQuaternion::from_euler_angles(0.4, -0.3, 1.2);
+    let h = Shear3::new(0.5, -0.25, 0.0, 0.75, 0.0, 0.0);
+    let s = Vector3::new(-2.0, 0.5, 3.0);
+    let m = t.to_homogeneous()
+        * r.to_homogeneous()
+        * h.to_homogeneous()
+        * Matrix4::new_nonuniform_scaling(&s);
+
+    let (t2, r2, s2, h2) = m.decompose_trs().unwrap();
+    let h2 = h2.unwrap();
+
+    assert_relative_eq!(t2, t, epsilon = 1.0e-7);
+    assert_relative_eq!(r2, r, epsilon = 1.0e-7);
+    assert_relative_eq!(s2, s, epsilon = 1.0e-7);
+    assert_relative_eq!(h2, h, epsilon = 1.0e-7);
+
+    let recomposed = t2.to_homogeneous()
+        * r2.to_homogeneous()
+        * h2.to_homogeneous()
+        * Matrix4::new_nonuniform_scaling(&s2);
+    assert_relative_eq!(recomposed, m, epsilon = 1.0e-7);
+    assert_eq!(
+        Transform3::from_matrix_unchecked(m).decompose(),
+        m.decompose_trs()
+    );
+}
+
+#[test]
+fn test_decompose_trs_degenerate() {
+    let singular = Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 0.0, 2.0));
+    assert!(singular.decompose_trs().is_none());
+
+    let projective = Matrix4::new_perspective(1.0, 1.0, 0.1, 100.0);
+    assert!(projective.decompose_trs().is_none());
+}
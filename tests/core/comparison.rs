@@ -0,0 +1,91 @@
+use na::base::comparison::{compare_matrices, MatrixComparisonError, Tolerance};
+use na::{assert_matrix_approx_eq, Complex, DMatrix, Matrix2, Matrix2x3, Vector3};
+
+#[test]
+fn compare_matrices_reports_worst_mismatches_first() {
+    let a = DMatrix::<f64>::zeros(20, 20);
+    let mut b = a.clone();
+    for i in 0..20 {
+        b[(i, i)] = i as f64;
+    }
+    b[(3, 7)] = f64::NAN;
+
+    match compare_matrices(&a, &b, Tolerance::Absolute { epsilon: 0.5 }) {
+        Err(MatrixComparisonError::Mismatches { count, worst, .. }) => {
+            assert_eq!(count, 20);
+            assert_eq!(worst.len(), 10);
+            assert_eq!((worst[0].row, worst[0].col), (3, 7));
+            assert_eq!((worst[1].row, worst[1].col), (19, 19));
+            assert_eq!((worst[9].row, worst[9].col), (11, 11));
+        }
+        other => panic!("unexpected comparison result: {:?}", other),
+    }
+}
+
+#[test]
+fn compare_matrices_shape_mismatch() {
+    let a = Matrix2x3::<f64>::zeros();
+    let b = DMatrix::<f64>::zeros(3, 2);
+
+    assert_eq!(
+        compare_matrices(&a, &b, Tolerance::Exact),
+        Err(MatrixComparisonError::ShapeMismatch {
+            left: (2, 3),
+            right: (3, 2)
+        })
+    );
+}
+
+#[test]
+fn compare_matrices_tolerances() {
+    let a = Vector3::new(1.0, 1.0e6, -2.0);
+    let b = Vector3::new(1.0, 1.0e6 + 1.0e-4, -2.0 + f64::EPSILON * 2.0);
+
+    assert!(compare_matrices(&a, &b, Tolerance::Exact).is_err());
+    assert!(compare_matrices(&a, &b, Tolerance::Absolute { epsilon: 1.0e-6 }).is_err());
+    assert!(compare_matrices(&a, &b, Tolerance::Absolute { epsilon: 1.0e-3 }).is_ok());
+
+    let relative = Tolerance::Relative {
+        epsilon: 1.0e-12,
+        max_relative: 1.0e-9,
+    };
+    assert!(compare_matrices(&a, &b, relative).is_ok());
+
+    let ulps = Tolerance::Ulps {
+        epsilon: 0.0,
+        max_ulps: 4,
+    };
+    assert!(compare_matrices(&a.rows(0, 1), &b.rows(0, 1), ulps).is_ok());
+    assert!(compare_matrices(&a, &b, ulps).is_err());
+}
+
+#[test]
+fn compare_complex_matrices() {
+    let a = Matrix2::from_element(Complex::new(1.0, 2.0));
+    let mut b = a;
+    b[(1, 0)] = Complex::new(1.0, 2.1);
+
+    assert!(compare_matrices(&a, &b, Tolerance::Absolute { epsilon: 0.2 }).is_ok());
+    assert!(compare_matrices(&a, &b, Tolerance::Absolute { epsilon: 0.05 }).is_err());
+}
+
+#[test]
+fn assert_matrix_approx_eq_passes() {
+    let a = DMatrix::<f64>::identity(5, 5);
+    let b = &a * 1.000_000_001;
+
+    assert_matrix_approx_eq!(a, a);
+    assert_matrix_approx_eq!(a, b, epsilon = 1.0e-8);
+    assert_matrix_approx_eq!(&a, &b, epsilon = 0.0, max_relative = 1.0e-8);
+    assert_matrix_approx_eq!(a, a.clone(), epsilon = 0.0, max_ulps = 1);
+}
+
+#[test]
+#[should_panic(expected = "(2, 1): left = 0, right = 0.5, |left - right| = 0.5")]
+fn assert_matrix_approx_eq_fails() {
+    let a = DMatrix::<f64>::zeros(100, 100);
+    let mut b = a.clone();
+    b[(2, 1)] = 0.5;
+
+    assert_matrix_approx_eq!(a, b, epsilon = 1.0e-6);
+}
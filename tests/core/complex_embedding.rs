@@ -0,0 +1,75 @@
+use na::{Complex, DMatrix, DVector, Matrix3, Matrix3x2, Vector3};
+
+fn complex_matrix(nrows: usize, ncols: usize, seed: usize) -> DMatrix<Complex<f64>> {
+    DMatrix::from_fn(nrows, ncols, |i, j| {
+        let k = (i * 7 + j * 3 + seed) as f64;
+        Complex::new((k * 0.37).sin(), (k * 0.91).cos())
+    })
+}
+
+#[test]
+fn real_embedding_is_a_homomorphism() {
+    let a = complex_matrix(3, 4, 0);
+    let b = complex_matrix(4, 2, 5);
+    let c = complex_matrix(3, 4, 9);
+
+    assert_relative_eq!(
+        (&a * &b).to_real_embedding(),
+        a.to_real_embedding() * b.to_real_embedding(),
+        epsilon = 1.0e-12
+    );
+    assert_eq!(
+        (&a + &c).to_real_embedding(),
+        a.to_real_embedding() + c.to_real_embedding()
+    );
+    assert_eq!(
+        a.adjoint().to_real_embedding(),
+        a.to_real_embedding().transpose()
+    );
+
+    let v = complex_matrix(4, 1, 2);
+    assert_relative_eq!(
+        (&a * &v).to_real_stacked(),
+        a.to_real_embedding() * v.to_real_stacked(),
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn real_embedding_round_trip() {
+    let m = Matrix3x2::from_fn(|i, j| Complex::new(i as f64, j as f64 - 1.0));
+    assert_eq!(Matrix3x2::from_real_embedding(&m.to_real_embedding()), m);
+    assert_eq!(Matrix3x2::from_real_stacked(&m.to_real_stacked()), m);
+
+    let d = complex_matrix(2, 5, 1);
+    assert_eq!(DMatrix::from_real_embedding(&d.to_real_embedding()), d);
+    assert_eq!(DMatrix::from_real_stacked(&d.to_real_stacked()), d);
+}
+
+#[test]
+fn real_embedding_solve() {
+    // Solve a complex system with the real LU decomposition.
+    let m = Matrix3::from_fn(|i, j| {
+        Complex::new((i == j) as u8 as f64 * 4.0 + j as f64, i as f64 - j as f64)
+    });
+    let b = Vector3::new(
+        Complex::new(1.0, 2.0),
+        Complex::new(-1.0, 0.5),
+        Complex::new(0.0, 3.0),
+    );
+
+    let real_x = m
+        .to_real_embedding()
+        .lu()
+        .solve(&b.to_real_stacked())
+        .unwrap();
+    let x = Vector3::from_real_stacked(&real_x);
+
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-12);
+}
+
+#[test]
+#[should_panic]
+fn real_stacked_odd_rows() {
+    let _ = DVector::<Complex<f64>>::from_real_stacked(&DVector::<f64>::zeros(3));
+}
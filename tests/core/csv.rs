@@ -0,0 +1,61 @@
+use na::io::{CsvError, CsvOptions, MissingValues};
+use na::{DMatrix, Matrix2x3};
+
+#[test]
+fn csv_roundtrip() {
+    let m = Matrix2x3::new(1.5, -2.0, 3.25, 4.0, 5.0, 1.0e-3);
+    let mut csv = Vec::new();
+    m.write_csv(&mut csv).unwrap();
+
+    let m2 = DMatrix::<f64>::from_csv(&csv[..], &CsvOptions::new()).unwrap();
+    assert_eq!(m2, m);
+
+    let options = CsvOptions::new().delimiter(';').has_header(true);
+    let mut csv = Vec::new();
+    m.write_csv_with_header(&mut csv, &options, &["a", "b;c", "d"])
+        .unwrap();
+    assert!(String::from_utf8_lossy(&csv).starts_with("a;\"b;c\";d\n"));
+
+    let (header, m2) = DMatrix::<f64>::from_csv_with_header(&csv[..], &options).unwrap();
+    assert_eq!(header, vec!["a", "b;c", "d"]);
+    assert_eq!(m2, m);
+}
+
+#[test]
+fn csv_parsing() {
+    let data = " 1, \"2\" ,3\n\n4,5,6\n";
+    let m = DMatrix::<i32>::from_csv(data.as_bytes(), &CsvOptions::new()).unwrap();
+    assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+
+    let m = DMatrix::<f32>::from_csv(&b""[..], &CsvOptions::new()).unwrap();
+    assert_eq!(m.shape(), (0, 0));
+
+    match DMatrix::<f64>::from_csv(&b"1,2\n3,x\n"[..], &CsvOptions::new()) {
+        Err(CsvError::Parse {
+            line: 2,
+            column: 2,
+            field,
+        }) => assert_eq!(field, "x"),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    match DMatrix::<f64>::from_csv(&b"1,2\n3,4,5\n"[..], &CsvOptions::new()) {
+        Err(CsvError::RaggedRow {
+            line: 2,
+            expected: 2,
+            found: 3,
+        }) => {}
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let missing = &b"1,,3\n"[..];
+    match DMatrix::<f64>::from_csv(missing, &CsvOptions::new()) {
+        Err(CsvError::MissingValue { line: 1, column: 2 }) => {}
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let nan = CsvOptions::new().missing_values(MissingValues::Nan);
+    let m = DMatrix::<f64>::from_csv(missing, &nan).unwrap();
+    assert!(m[(0, 1)].is_nan());
+    assert!(DMatrix::<i32>::from_csv(missing, &nan).is_err());
+}
@@ -1,8 +1,8 @@
+use na::{Const, Dynamic, U3, U5};
 use na::{
-    DMatrix, Matrix, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3, Matrix4x5, Matrix5,
-    Matrix5x3, Matrix5x4,
+    DMatrix, DVector, Matrix, Matrix2x6, Matrix3, Matrix3x4, Matrix3x5, Matrix4, Matrix4x3,
+    Matrix4x5, Matrix5, Matrix5x3, Matrix5x4,
 };
-use na::{Dynamic, U3, U5};
 
 #[test]
 #[rustfmt::skip]
@@ -210,6 +210,54 @@ fn swap_columns() {
     assert_eq!(m, expected);
 }
 
+#[test]
+#[rustfmt::skip]
+fn reverse_rows_and_columns() {
+    let m = Matrix5x3::new(
+        11, 12, 13,
+        21, 22, 23,
+        31, 32, 33,
+        41, 42, 43,
+        51, 52, 53);
+
+    let expected_rows = Matrix5x3::new(
+        51, 52, 53,
+        41, 42, 43,
+        31, 32, 33,
+        21, 22, 23,
+        11, 12, 13);
+
+    let expected_columns = Matrix5x3::new(
+        13, 12, 11,
+        23, 22, 21,
+        33, 32, 31,
+        43, 42, 41,
+        53, 52, 51);
+
+    assert_eq!(m.rows_reversed(), expected_rows);
+    assert_eq!(m.columns_reversed(), expected_columns);
+    assert_eq!(m.rows_reversed().rows_reversed(), m);
+
+    let mut m2 = m;
+    m2.reverse_rows_mut();
+    assert_eq!(m2, expected_rows);
+    m2.reverse_rows_mut();
+    m2.reverse_columns_mut();
+    assert_eq!(m2, expected_columns);
+
+    // Views of strided storages and empty matrices.
+    let mut d = DMatrix::from_fn(4, 6, |i, j| i * 10 + j);
+    let expected = d.columns_with_step(0, 3, 1).rows_reversed();
+    d.columns_with_step_mut(0, 3, 1).reverse_rows_mut();
+    assert_eq!(d.columns_with_step(0, 3, 1), expected);
+    assert_eq!(d.column(1), DVector::from_vec(vec![1, 11, 21, 31]));
+    assert_eq!(d.column(2), DVector::from_vec(vec![32, 22, 12, 2]));
+
+    let empty = DMatrix::<i32>::zeros(0, 3);
+    assert_eq!(empty.rows_reversed(), empty);
+    assert_eq!(empty.columns_reversed(), empty);
+}
+
 #[test]
 #[rustfmt::skip]
 fn remove_columns() {
@@ -700,3 +748,191 @@ fn resize_empty_matrix() {
     assert_eq!(m1, m6.resize(0, 0, 42));
     assert_eq!(m1, m7.resize(0, 0, 42));
 }
+
+#[test]
+fn resize_mut() {
+    let m = DMatrix::from_fn(3, 4, |i, j| i * 10 + j);
+
+    for new_nrows in 0..6 {
+        for new_ncols in 0..6 {
+            let mut resized = m.clone();
+            resized.resize_mut(new_nrows, new_ncols, 42);
+            assert_eq!(resized, m.clone().resize(new_nrows, new_ncols, 42));
+        }
+    }
+
+    let mut empty = DMatrix::repeat(0, 3, 0);
+    empty.resize_mut(2, 2, 42);
+    assert_eq!(empty, DMatrix::repeat(2, 2, 42));
+}
+
+#[test]
+fn resize_mut_reuses_allocation() {
+    let mut m = DMatrix::from_fn(3, 4, |i, j| i * 10 + j);
+    m.reserve(8);
+    let capacity = m.capacity();
+    let ptr = m.as_ptr();
+    assert!(capacity >= 20);
+
+    m.resize_mut(2, 2, 0);
+    m.resize_mut(4, 5, 0);
+    assert_eq!(m.capacity(), capacity);
+    assert_eq!(m.as_ptr(), ptr);
+}
+
+#[test]
+fn remove_rows_columns_at_mut() {
+    let m = DMatrix::from_fn(5, 4, |i, j| i * 10 + j);
+
+    let mut rows = m.clone();
+    let ptr = rows.as_ptr();
+    rows.remove_rows_at_mut(&[4, 1, 1, 7]);
+    assert_eq!(rows, m.clone().remove_rows_at(&[4, 1, 1, 7]));
+    assert_eq!(rows.shape(), (3, 4));
+    assert_eq!(rows.as_ptr(), ptr);
+
+    let mut cols = m.clone();
+    let ptr = cols.as_ptr();
+    cols.remove_columns_at_mut(&[0, 3, 3, 9]);
+    assert_eq!(cols, m.clone().remove_columns_at(&[0, 3, 3, 9]));
+    assert_eq!(cols.shape(), (5, 2));
+    assert_eq!(cols.as_ptr(), ptr);
+
+    let mut all = m.clone();
+    all.remove_rows_at_mut(&[0, 1, 2, 3, 4]);
+    assert_eq!(all.shape(), (0, 4));
+    all.remove_columns_at_mut(&[2]);
+    assert_eq!(all.shape(), (0, 3));
+
+    let no_cols = DMatrix::<usize>::zeros(3, 0);
+    assert_eq!(no_cols.clone().remove_rows_at(&[1]).shape(), (2, 0));
+}
+
+#[test]
+fn reshape_slices() {
+    let mut m = DMatrix::from_fn(3, 4, |i, j| (i + 3 * j) as f64);
+
+    // Contiguous matrix or columns: no copy.
+    let flat = m
+        .reshape_slice_generic(Dynamic::new(12), Const::<1>)
+        .unwrap();
+    assert_eq!(flat, DVector::from_fn(12, |i, _| i as f64));
+    assert_eq!(flat.as_ptr(), m.as_ptr());
+
+    let cols = m.columns(1, 2);
+    let cols = cols.reshape_slice(2, 3).unwrap();
+    assert_eq!(
+        cols,
+        DMatrix::from_column_slice(2, 3, &[3.0, 4.0, 5.0, 6.0, 7.0, 8.0])
+    );
+
+    let all_cols = m.columns(0, 4);
+    let static_cols = all_cols
+        .reshape_slice_generic(Const::<2>, Const::<6>)
+        .unwrap();
+    assert_eq!(static_cols, Matrix2x6::from_fn(|i, j| (i + 2 * j) as f64));
+
+    // Non-contiguous rows: fallback to a copy.
+    let rows = m.rows(1, 2);
+    assert!(rows.reshape_slice(4, 2).is_none());
+    assert_eq!(
+        rows.reshape_owned(4, 2),
+        DMatrix::from_column_slice(4, 2, &[1.0, 2.0, 4.0, 5.0, 7.0, 8.0, 10.0, 11.0])
+    );
+
+    // Mutable slices write through to the original matrix.
+    m.reshape_slice_mut(6, 2).unwrap()[(5, 1)] = -1.0;
+    assert_eq!(m[(2, 3)], -1.0);
+    assert!(m.rows_mut(0, 2).reshape_slice_mut(2, 4).is_none());
+
+    let reshaped = m.clone().reshape(2, 6);
+    assert_eq!(reshaped, m.reshape_owned(2, 6));
+}
+
+#[test]
+#[should_panic]
+fn reshape_slice_wrong_len() {
+    let m = DMatrix::<f64>::zeros(3, 4);
+    let _ = m.reshape_slice(5, 2);
+}
+
+#[test]
+fn vectorize_unvec_vech() {
+    let m = Matrix3x4::from_fn(|i, j| i + 3 * j);
+    let v = m.vectorize();
+    assert_eq!(v, na::SVector::<usize, 12>::from_fn(|i, _| i));
+    assert_eq!(v.unvec(3, 4), m);
+    assert_eq!(
+        m.rows(1, 2).vectorize(),
+        DVector::from_vec(vec![1, 2, 4, 5, 7, 8, 10, 11])
+    );
+
+    let s = Matrix3::new(1, 2, 4, 2, 3, 5, 4, 5, 6);
+    assert_eq!(s.vech(), DVector::from_vec(vec![1, 2, 4, 3, 5, 6]));
+    assert_eq!(DMatrix::<f64>::zeros(0, 0).vech().len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn vech_non_square() {
+    let _ = Matrix3x4::<f64>::zeros().vech();
+}
+
+#[test]
+fn direct_sum_block_diag() {
+    let a = Matrix3x4::from_fn(|i, j| 1 + i + 3 * j);
+    let b = Matrix5x3::from_fn(|i, j| 20 + i + 5 * j);
+    let sum = a.direct_sum(&b);
+    assert_eq!(sum.shape(), (8, 7));
+    assert_eq!(sum.fixed_slice::<3, 4>(0, 0), a);
+    assert_eq!(sum.fixed_slice::<5, 3>(3, 4), b);
+    assert_eq!(sum.iter().filter(|e| **e != 0).count(), 12 + 15);
+
+    let blocks = [
+        DMatrix::from_fn(3, 4, |i, j| 1 + i + 3 * j),
+        DMatrix::zeros(0, 2),
+        DMatrix::from_fn(5, 3, |i, j| 20 + i + 5 * j),
+    ];
+    let m = DMatrix::block_diag(&blocks);
+    assert_eq!(m.shape(), (8, 9));
+    assert_eq!(m.columns(0, 4), sum.columns(0, 4));
+    assert!(m.columns(4, 2).iter().all(|e| *e == 0));
+    assert_eq!(m.columns(6, 3), sum.columns(4, 3));
+
+    let no_blocks: [DMatrix<f64>; 0] = [];
+    assert_eq!(DMatrix::block_diag(&no_blocks).shape(), (0, 0));
+}
+
+#[test]
+fn tile_repeat_meshgrid() {
+    let a = Matrix3x4::from_fn(|i, j| 1 + i + 3 * j);
+    let tiled = a.tile(2, 3);
+    assert_eq!(tiled.shape(), (6, 12));
+
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!(tiled.fixed_slice::<3, 4>(3 * i, 4 * j), a);
+        }
+    }
+
+    assert_eq!(a.tile(0, 5).shape(), (0, 20));
+    assert_eq!(DMatrix::<u8>::zeros(0, 2).tile(3, 3).shape(), (0, 6));
+    assert_eq!(a.columns(1, 2).tile(1, 1), a.columns(1, 2));
+
+    let v = DVector::from_fn(4, |i, _| i as f64 * 0.5);
+    let rows = v.repeat_as_rows(3);
+    let columns = v.repeat_as_columns(3);
+    assert_eq!(rows, columns.transpose());
+    assert!(columns.column_iter().all(|c| c == v));
+    assert_eq!(rows, v.transpose().tile(3, 1));
+    let fixed: Matrix<_, Dynamic, U3, _> = na::Vector3::new(1, 2, 3).repeat_as_rows(2);
+    assert_eq!(fixed.row(1), na::RowVector3::new(1, 2, 3));
+
+    let x = DVector::from_fn(5, |i, _| i as f64);
+    let y = na::Vector2::new(-1.0, 1.0);
+    let (xs, ys) = DMatrix::meshgrid(&x, &y);
+    assert_eq!(xs.shape(), (2, 5));
+    assert_eq!(xs, x.repeat_as_rows(2));
+    assert_eq!(ys, y.repeat_as_columns(5));
+    assert_eq!(xs.zip_map(&ys, |x, y| x + 10.0 * y)[(0, 3)], -7.0);
+}
@@ -0,0 +1,63 @@
+use glam018 as glam;
+use na::{
+    Affine2, Affine3, Isometry2, Isometry3, Matrix3, Matrix4, Similarity3, UnitQuaternion, Vector2,
+    Vector3,
+};
+use std::convert::TryFrom;
+
+macro_rules! glam_affine_conversion(
+    ($($glam_affine_conversion_i: ident, $Glam: ident, $Affine: ident, $Matrix: ident, $T: ty,
+       $Isometry: ident, $iso: expr);* $(;)*) => {$(
+        #[test]
+        fn $glam_affine_conversion_i() {
+            let dim = $Matrix::<$T>::identity().nrows();
+            let m = $Matrix::<$T>::from_fn(|i, j| {
+                if i + 1 < dim {
+                    (i * 10 + j) as $T / 4.0 + 1.0
+                } else if j + 1 < dim {
+                    0.0
+                } else {
+                    1.0
+                }
+            });
+            let affine = $Affine::from_matrix_unchecked(m);
+
+            let ga: glam::$Glam = affine.into();
+            assert_eq!($Matrix::from(ga), m);
+            assert_eq!($Affine::from(ga), affine);
+
+            // Only rigid transformations convert back to isometries.
+            assert!($Isometry::try_from(ga).is_err());
+
+            let iso: $Isometry<$T> = $iso;
+            let gi: glam::$Glam = iso.into();
+            assert_relative_eq!($Matrix::from(gi), iso.to_homogeneous(), epsilon = 1.0e-6);
+            assert_relative_eq!($Isometry::try_from(gi).unwrap(), iso, epsilon = 1.0e-6);
+        }
+    )*}
+);
+
+glam_affine_conversion!(
+    glam_affine2_conversion,  Affine2,  Affine2, Matrix3, f32,
+        Isometry2, Isometry2::new(Vector2::new(1.0, -2.0), 0.7);
+    glam_daffine2_conversion, DAffine2, Affine2, Matrix3, f64,
+        Isometry2, Isometry2::new(Vector2::new(1.0, -2.0), 0.7);
+    glam_affine3a_conversion, Affine3A, Affine3, Matrix4, f32,
+        Isometry3, Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    glam_daffine3_conversion, DAffine3, Affine3, Matrix4, f64,
+        Isometry3, Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+);
+
+#[test]
+fn glam_similarity3_affine3a_conversion() {
+    let rotation = UnitQuaternion::from_euler_angles(0.1f32, 0.2, 0.3);
+    let sim = Similarity3::from_parts(Vector3::new(1.0, -2.0, 3.0).into(), rotation, 2.5);
+    let ga: glam::Affine3A = sim.into();
+
+    assert_relative_eq!(Matrix4::from(ga), sim.to_homogeneous(), epsilon = 1.0e-5);
+    assert_relative_eq!(
+        Affine3::from(ga).into_inner(),
+        sim.to_homogeneous(),
+        epsilon = 1.0e-5
+    );
+}
@@ -0,0 +1,108 @@
+use na::{Const, DMatrix, DVector, Dynamic, MappedStorage, Matrix, Matrix3x4, Vector4};
+
+type MappedMatrix<'a> =
+    Matrix<f64, Dynamic, Dynamic, MappedStorage<f64, Dynamic, Dynamic, &'a [u8]>>;
+
+#[test]
+fn mapped_storage_read_only_ops() {
+    // A header of two `f64` followed by a 3×4 matrix.
+    let m = Matrix3x4::from_fn(|i, j| (i * 4 + j) as f64 - 5.0);
+    let mut data = vec![0.0f64; 2];
+    data.extend_from_slice(m.as_slice());
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+
+    let mapped =
+        MappedMatrix::from_mapped_bytes_generic(bytes, 16, Dynamic::new(3), Dynamic::new(4))
+            .unwrap();
+    assert_eq!(mapped, m);
+    assert_eq!(mapped.data.offset(), 16);
+    assert_eq!(mapped.as_ptr() as *const u8, bytes[16..].as_ptr());
+
+    let x = Vector4::new(1.0, -2.0, 0.5, 3.0);
+    let mut y = DVector::zeros(3);
+    y.gemv(2.0, &mapped, &x, 0.0);
+    assert_eq!(y, m * x * 2.0);
+    assert_eq!(&mapped * x, m * x);
+    assert_eq!(mapped.tr_mul(&mapped), m.tr_mul(&m));
+
+    assert_eq!(mapped.norm(), m.norm());
+    assert_eq!(mapped.sum(), m.sum());
+    assert_eq!(mapped.slice((1, 1), (2, 3)), m.slice((1, 1), (2, 3)));
+    assert_eq!(mapped.column(2).amax(), m.column(2).amax());
+    assert_eq!(
+        mapped.clone_owned(),
+        DMatrix::from_column_slice(3, 4, m.as_slice())
+    );
+
+    let static_mapped =
+        Matrix::<f64, _, _, _>::from_mapped_bytes_generic(bytes, 16, Const::<3>, Const::<4>);
+    assert_eq!(static_mapped.unwrap().into_owned(), m);
+
+    // Clones of storages reading from a shared buffer read the same bytes.
+    let shared: std::sync::Arc<[u8]> = bytes.into();
+    let mapped =
+        Matrix::<f64, _, _, _>::from_mapped_bytes_generic(shared, 16, Const::<3>, Const::<4>)
+            .unwrap();
+    let cloned = mapped.clone();
+    assert_eq!(cloned.as_ptr(), mapped.as_ptr());
+    assert_eq!(cloned, m);
+}
+
+#[test]
+fn mapped_storage_invalid_buffers() {
+    let data = [0.0f64; 4];
+    let bytes: &[u8] = bytemuck::cast_slice(&data);
+
+    assert!(
+        MappedMatrix::from_mapped_bytes_generic(bytes, 0, Dynamic::new(2), Dynamic::new(2))
+            .is_some()
+    );
+    // Too small.
+    assert!(
+        MappedMatrix::from_mapped_bytes_generic(bytes, 8, Dynamic::new(2), Dynamic::new(2))
+            .is_none()
+    );
+    assert!(
+        MappedMatrix::from_mapped_bytes_generic(bytes, 40, Dynamic::new(0), Dynamic::new(0))
+            .is_none()
+    );
+    assert!(MappedMatrix::from_mapped_bytes_generic(
+        bytes,
+        0,
+        Dynamic::new(usize::MAX),
+        Dynamic::new(2)
+    )
+    .is_none());
+    // Misaligned.
+    assert!(
+        MappedMatrix::from_mapped_bytes_generic(bytes, 4, Dynamic::new(1), Dynamic::new(2))
+            .is_none()
+    );
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn mapped_storage_from_file() {
+    use std::io::Write;
+
+    let m = DMatrix::from_fn(50, 20, |i, j| (i as f64).sin() + j as f64);
+    let path = std::env::temp_dir().join("nalgebra_mapped_storage_from_file.bin");
+    std::fs::File::create(&path)
+        .unwrap()
+        .write_all(bytemuck::cast_slice(m.as_slice()))
+        .unwrap();
+
+    let file = std::fs::File::open(&path).unwrap();
+    let mapped = unsafe { Matrix::<f64, _, _, _>::from_mmap_file(&file, 0, 50, 20) }.unwrap();
+    assert_eq!(mapped, m);
+    assert_eq!(mapped.column_sum(), m.column_sum());
+
+    let too_large = unsafe { Matrix::<f64, _, _, _>::from_mmap_file(&file, 0, 50, 21) };
+    assert_eq!(
+        too_large.unwrap_err().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+
+    drop(mapped);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -4,8 +4,8 @@ use std::cmp::Ordering;
 use na::dimension::{U15, U8};
 use na::{
     self, Const, DMatrix, DVector, Matrix2, Matrix2x3, Matrix2x4, Matrix3, Matrix3x2, Matrix3x4,
-    Matrix4, Matrix4x3, Matrix4x5, Matrix5, Matrix6, OMatrix, RowVector3, RowVector4, RowVector5,
-    Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
+    Matrix4, Matrix4x3, Matrix4x5, Matrix5, Matrix6, OMatrix, RowVector2, RowVector3, RowVector4,
+    RowVector5, Unit, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
 };
 
 #[test]
@@ -359,6 +359,174 @@ fn simple_sum() {
     assert_eq!(a + b + c, vec![a, b, c].into_iter().sum());
 }
 
+#[test]
+fn compensated_sum_and_dot() {
+    // 0.1 is not representable, so a naive summation accumulates its rounding error.
+    let v = DVector::from_element(10_000, 0.1f32);
+    assert_ne!(v.sum(), 1000.0);
+    assert_eq!(v.sum_compensated(), 1000.0);
+    assert_eq!(v.mean_compensated(), 0.1);
+    assert_eq!(
+        v.dot_compensated(&DVector::from_element(10_000, 10.0)),
+        10_000.0
+    );
+
+    // Strided slices and empty matrices.
+    let m = DMatrix::from_fn(4, 3, |i, j| if i % 2 == 0 { 1.0e20 } else { j as f64 });
+    let rows = m.rows_with_step(1, 2, 1);
+    assert_eq!(rows.sum_compensated(), 6.0);
+    assert_eq!(m.sum_compensated(), 6.0e20);
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).sum_compensated(), 0.0);
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).mean_compensated(), 0.0);
+}
+
+#[test]
+fn weighted_statistics() {
+    let m = DMatrix::from_fn(4, 3, |i, j| ((i * 7 + j * 3) % 5) as f64 + 0.5 * j as f64);
+    let row_weights = DVector::from_vec(vec![1.0, 2.0, 0.0, 3.0]);
+    let col_weights = Vector3::new(0.5, 1.5, 2.0);
+
+    // Integer weights are equivalent to repeated rows or columns.
+    let repeated_rows = m.select_rows(&[0, 1, 1, 3, 3, 3]);
+    assert_relative_eq!(m.row_weighted_mean(&row_weights), repeated_rows.row_mean());
+    assert_relative_eq!(
+        m.row_weighted_variance(&row_weights),
+        repeated_rows.row_variance(),
+        epsilon = 1.0e-12
+    );
+    let repeated_cols = m.select_columns(&[0, 1, 1, 1, 2, 2, 2, 2]);
+    assert_relative_eq!(
+        m.column_weighted_mean(&(col_weights * 2.0)),
+        repeated_cols.column_mean()
+    );
+    assert_relative_eq!(
+        m.column_weighted_variance(&col_weights),
+        repeated_cols.column_variance(),
+        epsilon = 1.0e-12
+    );
+
+    let weights = DMatrix::from_fn(4, 3, |i, j| (i + j) as f64);
+    let repeated: Vec<f64> = m
+        .iter()
+        .zip(weights.iter())
+        .flat_map(|(x, w)| std::iter::repeat(*x).take(*w as usize))
+        .collect();
+    let repeated = DVector::from_vec(repeated);
+    assert_relative_eq!(
+        m.weighted_mean(&weights),
+        repeated.mean(),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        m.weighted_variance(&weights),
+        repeated.variance(),
+        epsilon = 1.0e-12
+    );
+
+    let empty = DMatrix::<f64>::zeros(3, 0);
+    assert_eq!(empty.weighted_mean(&empty), 0.0);
+    assert_eq!(
+        empty.column_weighted_mean(&DVector::zeros(0)),
+        DVector::zeros(3)
+    );
+    assert_eq!(
+        empty.column_weighted_variance(&DVector::zeros(0)),
+        DVector::zeros(3)
+    );
+}
+
+#[test]
+fn quantiles() {
+    let v = DVector::from_fn(101, |i, _| ((i * 37) % 101) as f64);
+    assert_eq!(v.median(), 50.0);
+    assert_eq!(v.quantile(0.0), 0.0);
+    assert_eq!(v.quantile(1.0), 100.0);
+    assert_eq!(v.quantile(0.255), 25.5);
+
+    let m = Matrix3x4::new(
+        4.0, 1.0, 9.0, -1.0, //
+        2.0, 8.0, 7.0, -2.0, //
+        6.0, 3.0, 5.0, -3.0,
+    );
+    assert_eq!(m.median(), 3.5);
+    assert_eq!(m.row_median(), RowVector4::new(4.0, 3.0, 7.0, -2.0));
+    assert_eq!(m.row_quantile(0.75), RowVector4::new(5.0, 5.5, 8.0, -1.5));
+    assert_eq!(m.column_median(), Vector3::new(2.5, 4.5, 4.0));
+    assert_eq!(m.column_quantile(1.0), Vector3::new(9.0, 8.0, 6.0));
+    assert_eq!(m.transpose().row_median().transpose(), m.column_median());
+
+    // NaNs are ordered last and the input is left untouched.
+    let with_nan = Vector4::new(f64::NAN, 2.0, 1.0, f64::NAN);
+    assert_eq!(with_nan.quantile(0.0), 1.0);
+    assert!(with_nan.median().is_nan());
+    assert!(with_nan[0].is_nan() && with_nan[1] == 2.0);
+}
+
+#[test]
+#[should_panic]
+fn quantile_out_of_range() {
+    let _ = Vector3::new(1.0, 2.0, 3.0).quantile(1.5);
+}
+
+#[test]
+#[should_panic]
+fn median_of_empty_matrix() {
+    let _ = DMatrix::<f64>::zeros(0, 2).median();
+}
+
+#[test]
+fn running_covariance() {
+    // Samples with a large offset, which breaks the naive sum-of-squares formula.
+    let samples = DMatrix::from_fn(50, 3, |i, j| {
+        1.0e8 + ((i * 13 + j * 7) % 17) as f64 * 0.25 + (i * j) as f64 * 0.01
+    });
+    let centered = samples.sub_broadcast_rows(&samples.row_mean());
+    let expected = centered.gram() / 49.0;
+
+    let mut stats = na::RunningCovariance::new_generic(na::Dynamic::new(3));
+    let mut halves = [
+        na::RunningCovariance::<f64, na::U3>::new(),
+        na::RunningCovariance::new(),
+    ];
+
+    for (i, row) in samples.row_iter().enumerate() {
+        let sample = row.transpose();
+        stats.push(&sample);
+        halves[i % 2].push(&Vector3::new(sample[0], sample[1], sample[2]));
+    }
+
+    assert_eq!(stats.count(), 50);
+    assert_relative_eq!(
+        *stats.mean(),
+        samples.row_mean().transpose(),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(stats.covariance(), expected, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        stats.population_covariance(),
+        &expected * (49.0 / 50.0),
+        epsilon = 1.0e-6
+    );
+    assert_eq!(stats.covariance(), stats.covariance().transpose());
+
+    let [mut merged, other] = halves;
+    merged.merge(&other);
+    merged.merge(&na::RunningCovariance::new());
+    assert_eq!(merged.count(), 50);
+    assert_relative_eq!(
+        merged.covariance(),
+        Matrix3::from_iterator(expected.iter().cloned()),
+        epsilon = 1.0e-6
+    );
+
+    let mut empty = na::RunningCovariance::<f64, na::U2>::default();
+    assert_eq!(empty.covariance(), Matrix2::zeros());
+    empty.push(&Vector2::new(1.0, 2.0));
+    assert_eq!(empty.covariance(), Matrix2::zeros());
+    assert_eq!(empty.population_covariance(), Matrix2::zeros());
+    assert_eq!(*empty.mean(), Vector2::new(1.0, 2.0));
+}
+
 #[test]
 fn simple_scalar_mul() {
     let a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
@@ -371,6 +539,31 @@ fn simple_scalar_mul() {
     assert_eq!(expected, 10.0 * &a);
 }
 
+#[test]
+fn left_scalar_mul_div() {
+    use na::{LeftScalar, Quaternion};
+
+    fn halve<T: na::RealField>(m: &DMatrix<T>) -> DMatrix<T> {
+        LeftScalar(T::one() / (T::one() + T::one())) * m
+    }
+
+    let a = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 4.0, 8.0]);
+    assert_eq!(halve(&a), a.clone() * 0.5);
+    assert_eq!(LeftScalar(0.5) * a.clone(), a.clone() * 0.5);
+    assert_eq!(
+        LeftScalar(8.0) / &a,
+        DMatrix::from_row_slice(2, 2, &[8.0, 4.0, 2.0, 1.0])
+    );
+
+    // The scalar is applied on the left of non-commutative components.
+    let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+    let q = Vector2::new(j, i);
+    assert_eq!(LeftScalar(i) * q, Vector2::new(i * j, i * i));
+    assert_eq!(q * i, Vector2::new(j * i, i * i));
+    assert_ne!(LeftScalar(i) * q, q * i);
+}
+
 #[test]
 fn simple_mul() {
     let a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
@@ -476,6 +669,27 @@ fn map_with_location() {
     assert_eq!(computed, expected);
 }
 
+#[test]
+fn try_map() {
+    let a = DMatrix::from_row_slice(2, 3, &["1", "2", "3", "4", "x", "6"]);
+    let b = DMatrix::from_row_slice(2, 2, &["1", "2", "3", "4"]);
+
+    assert_eq!(
+        b.try_map(|e| e.parse::<i32>()),
+        Ok(DMatrix::from_row_slice(2, 2, &[1, 2, 3, 4]))
+    );
+
+    // Entries are visited in column-major order, and the first error stops the mapping.
+    let mut visited = Vec::new();
+    let computed = a.try_map(|e| {
+        visited.push(e);
+        e.parse::<i32>()
+    });
+
+    assert!(computed.is_err());
+    assert_eq!(visited, ["1", "4", "2", "x"]);
+}
+
 #[test]
 fn zip_map() {
     let a = Matrix3::new(11i32, 12, 13, 21, 22, 23, 31, 32, 33);
@@ -489,6 +703,75 @@ fn zip_map() {
     assert_eq!(computed, expected);
 }
 
+#[test]
+fn broadcast_rows_and_columns() {
+    let m = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 6.0, 8.0);
+    let row = RowVector3::new(1.0, -2.0, 4.0);
+    let col = Vector2::new(2.0, -1.0);
+
+    assert_eq!(
+        m.add_broadcast_rows(&row),
+        Matrix2x3::new(2.0, 0.0, 7.0, 5.0, 4.0, 12.0)
+    );
+    assert_eq!(
+        m.sub_broadcast_rows(&row),
+        Matrix2x3::new(0.0, 4.0, -1.0, 3.0, 8.0, 4.0)
+    );
+    assert_eq!(
+        m.component_mul_broadcast_rows(&row),
+        Matrix2x3::new(1.0, -4.0, 12.0, 4.0, -12.0, 32.0)
+    );
+    assert_eq!(
+        m.component_div_broadcast_rows(&row),
+        Matrix2x3::new(1.0, -1.0, 0.75, 4.0, -3.0, 2.0)
+    );
+
+    assert_eq!(
+        m.add_broadcast_columns(&col),
+        Matrix2x3::new(3.0, 4.0, 5.0, 3.0, 5.0, 7.0)
+    );
+    assert_eq!(
+        m.sub_broadcast_columns(&col),
+        Matrix2x3::new(-1.0, 0.0, 1.0, 5.0, 7.0, 9.0)
+    );
+    assert_eq!(
+        m.component_mul_broadcast_columns(&col),
+        Matrix2x3::new(2.0, 4.0, 6.0, -4.0, -6.0, -8.0)
+    );
+    assert_eq!(
+        m.component_div_broadcast_columns(&col),
+        Matrix2x3::new(0.5, 1.0, 1.5, -4.0, -6.0, -8.0)
+    );
+
+    // In-place operations on slices, with dynamically-sized operands.
+    let mut d = DMatrix::from_fn(4, 5, |i, j| (i * 5 + j) as f64);
+    let expected = d.clone();
+    let centered_rows = DVector::from_fn(2, |i, _| i as f64 + 0.5);
+    let mut block = d.slice_mut((1, 1), (2, 3));
+    block.add_broadcast_columns_mut(&centered_rows);
+    block.sub_broadcast_columns_mut(&centered_rows);
+    block.component_mul_broadcast_rows_mut(&RowVector3::new(2.0, 4.0, 8.0));
+    block.component_div_broadcast_rows_mut(&na::RowDVector::repeat(3, 2.0));
+    assert_eq!(
+        d.slice((1, 1), (2, 3)),
+        Matrix2x3::new(6.0, 14.0, 32.0, 11.0, 24.0, 52.0)
+    );
+    d.slice_mut((1, 1), (2, 3))
+        .copy_from(&expected.slice((1, 1), (2, 3)));
+    assert_eq!(d, expected);
+
+    let mut data = DMatrix::from_fn(5, 3, |i, j| (i * i + j) as f64);
+    data.sub_broadcast_rows_mut(&data.row_mean());
+    assert!(data.row_mean().iter().all(|e| e.abs() < 1.0e-12));
+}
+
+#[test]
+#[should_panic]
+fn broadcast_dimension_mismatch() {
+    let m = DMatrix::<f64>::zeros(2, 3);
+    let _ = m.add_broadcast_columns(&DVector::zeros(3));
+}
+
 #[test]
 #[should_panic]
 fn trace_panic() {
@@ -834,6 +1117,48 @@ fn swizzle() {
     assert_eq!(c.zyz(), Vector3::new(3.0, 2.0, 3.0));
 }
 
+#[test]
+#[cfg(feature = "full-swizzle")]
+fn full_swizzle() {
+    let a = Vector2::new(1.0f32, 2.0);
+    let c = Vector4::new(1.0f32, 2.0, 3.0, 4.0);
+    let p = na::Point4::new(1.0f32, 2.0, 3.0, 4.0);
+
+    assert_eq!(a.xyxy(), Vector4::new(1.0, 2.0, 1.0, 2.0));
+    assert_eq!(c.wzyx(), Vector4::new(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(c.xw(), Vector2::new(1.0, 4.0));
+    assert_eq!(c.wwz(), Vector3::new(4.0, 4.0, 3.0));
+    assert_eq!(p.wxyz(), na::Point4::new(4.0, 1.0, 2.0, 3.0));
+
+    let mut b = Vector3::new(1.0f32, 2.0, 3.0);
+    b.set_zx(10.0, 20.0);
+    assert_eq!(b, Vector3::new(20.0, 2.0, 10.0));
+
+    let mut c = c;
+    c.set_wy(5.0, 6.0);
+    c.fixed_rows_mut::<3>(0).set_xz(7.0, 8.0);
+    assert_eq!(c, Vector4::new(7.0, 6.0, 8.0, 5.0));
+
+    let mut p = p;
+    p.set_xyzw(0.0, 1.0, 2.0, 3.0);
+    assert_eq!(p, na::Point4::new(0.0, 1.0, 2.0, 3.0));
+}
+
+#[test]
+fn column_iter_mut_from_both_ends() {
+    let mut m = DMatrix::from_fn(2, 5, |i, j| (i + 2 * j) as i32);
+    let mut iter = m.column_iter_mut();
+    assert_eq!(iter.len(), 5);
+
+    iter.next_back().unwrap().fill(-1);
+    iter.next().unwrap().fill(-2);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.rev().map(|col| col[0]).collect::<Vec<_>>(), [6, 4, 2]);
+
+    assert_eq!(m.column(0).sum(), -4);
+    assert_eq!(m.column(4).sum(), -2);
+}
+
 #[cfg(feature = "proptest-support")]
 mod transposition_tests {
     use super::*;
@@ -873,6 +1198,15 @@ mod transposition_tests {
             }
         }
 
+        #[test]
+        fn transpose_in_place_is_transpose_dyn(m in dmatrix()) {
+            let mut mm = m.clone();
+            mm.transpose_in_place();
+            prop_assert_eq!(&mm, &m.transpose());
+            mm.transpose_in_place();
+            prop_assert_eq!(mm, m)
+        }
+
         #[test]
         fn tr_mul_is_transpose_then_mul(m in matrix(PROPTEST_F64, Const::<4>, Const::<6>), v in vector4()) {
             prop_assert!(relative_eq!(m.transpose() * v, m.tr_mul(&v), epsilon = 1.0e-7))
@@ -957,6 +1291,124 @@ mod normalization_tests {
     }
 }
 
+#[test]
+fn angle_to_and_orthonormal_basis_edge_cases() {
+    let x = Vector3::new(1.0, 0.0, 0.0);
+    assert_eq!(x.angle_to(&Vector3::zeros()), 0.0);
+    assert_eq!(x.angle_to(&(x * 2.0)), 0.0);
+    assert_eq!(x.angle_to(&-x), std::f64::consts::PI);
+    assert_relative_eq!(
+        x.angle_to(&Vector3::new(-1.0, 1.0e-12, 0.0)),
+        std::f64::consts::PI - 1.0e-12,
+        epsilon = 1.0e-15
+    );
+
+    for n in [
+        Vector3::<f64>::z_axis(),
+        -Vector3::z_axis(),
+        Vector3::x_axis(),
+    ] {
+        let (b1, b2) = n.any_orthonormal_basis();
+        let frame = Matrix3::from_columns(&[b1.into_inner(), b2.into_inner(), n.into_inner()]);
+        assert_relative_eq!(frame.determinant(), 1.0);
+        assert_relative_eq!(frame.tr_mul(&frame), Matrix3::identity());
+    }
+}
+
+#[test]
+fn slerp_opposite_unit_vectors() {
+    let v1 = Unit::new_normalize(Vector4::new(1.0, -2.0, 0.5, 3.0));
+    let v2 = -v1;
+
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let v = v1.slerp(&v2, t);
+        assert_relative_eq!(v.norm(), 1.0, epsilon = 1.0e-12);
+        assert_relative_eq!(v.angle(&v1), t * std::f64::consts::PI, epsilon = 1.0e-7);
+    }
+
+    assert_relative_eq!(v1.slerp(&v2, 1.0), v2, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        v1.slerp_clamped_angle(&v2, 1.0).angle(&v1),
+        1.0,
+        epsilon = 1.0e-7
+    );
+
+    // The dot product of opposite vectors is often rounded below -1.
+    for i in 0..100 {
+        for j in 0..100 {
+            let v = Unit::new_normalize(Vector3::new(i as f64 * 0.01, -(j as f64) * 0.01, 0.7));
+            let s = v.slerp(&-v, 0.5);
+            assert!(s.iter().all(|x| x.is_finite()));
+            assert_relative_eq!(s.norm(), 1.0, epsilon = 1.0e-12);
+            assert_relative_eq!(s.dot(&v), 0.0, epsilon = 1.0e-7);
+
+            // And the dot product of a vector with itself may be rounded below 1.
+            let s = v.try_slerp(&v, 0.5, 1.0e-6).unwrap();
+            assert_relative_eq!(s, v, epsilon = 1.0e-15);
+        }
+    }
+
+    // There is no path between opposite vectors in dimension 1.
+    let v1 = Vector1::x_axis();
+    assert_eq!(v1.slerp(&-v1, 0.5), v1);
+}
+
+#[cfg(feature = "proptest-support")]
+mod angle_and_basis_tests {
+    use crate::proptest::*;
+    use na::{Matrix3, Unit};
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn angle_to_matches_angle(a in vector3(), b in vector3()) {
+            let angle = a.angle_to(&b);
+            prop_assert!(angle >= 0.0 && angle <= std::f64::consts::PI);
+            prop_assert!(relative_eq!(angle, b.angle_to(&a)));
+            prop_assert!(relative_eq!(angle, a.angle(&b), epsilon = 1.0e-7));
+        }
+
+        #[test]
+        fn slerp_clamped_angle(a in vector3(), b in vector3(), max_angle in 0.0..4.0) {
+            if let (Some(a), Some(b)) = (Unit::try_new(a, 1.0e-10), Unit::try_new(b, 1.0e-10)) {
+                let v = a.slerp_clamped_angle(&b, max_angle);
+                let angle = a.angle_to(&b);
+
+                if angle <= max_angle {
+                    prop_assert!(relative_eq!(v, b, epsilon = 1.0e-7));
+                } else {
+                    prop_assert!(relative_eq!(a.angle_to(&v), max_angle, epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(v.angle_to(&b), angle - max_angle, epsilon = 1.0e-7));
+                }
+            }
+        }
+
+        #[test]
+        fn slerp_opposite_is_unit(v in vector3(), t in 0.0..=1.0) {
+            if let Some(v) = Unit::try_new(v, 1.0e-10) {
+                let s = v.slerp(&-v, t);
+                prop_assert!(s.iter().all(|x| x.is_finite()));
+                prop_assert!(relative_eq!(s.norm(), 1.0, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(v.angle(&s), t * std::f64::consts::PI, epsilon = 1.0e-7));
+            }
+        }
+
+        #[test]
+        fn any_orthonormal_basis_is_right_handed(v in vector3()) {
+            if let Some(n) = Unit::try_new(v, 1.0e-10) {
+                let (b1, b2) = n.any_orthonormal_basis();
+                let frame =
+                    Matrix3::from_columns(&[b1.into_inner(), b2.into_inner(), n.into_inner()]);
+                let identity = Matrix3::identity();
+
+                prop_assert!(relative_eq!(frame.tr_mul(&frame), identity, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(frame.determinant(), 1.0, epsilon = 1.0e-7));
+            }
+        }
+    }
+}
+
 #[cfg(all(feature = "proptest-support", feature = "alga"))]
 // TODO: move this to alga ?
 mod finite_dim_inner_space_tests {
@@ -1136,3 +1588,105 @@ fn omatrix_to_string() {
         (svec.to_string(), smatr.to_string())
     );
 }
+
+#[test]
+fn from_rows_and_columns_iter() {
+    let expected = DMatrix::from_row_slice(3, 2, &[1, 2, 3, 4, 5, 6]);
+
+    let rows = [
+        RowVector2::new(1, 2),
+        RowVector2::new(3, 4),
+        RowVector2::new(5, 6),
+    ];
+    assert_eq!(DMatrix::from_rows_iter(2, &rows), expected);
+    assert_eq!(
+        DMatrix::from_rows_iter(2, (0..3).map(|i| vec![2 * i + 1, 2 * i + 2])),
+        expected
+    );
+
+    let columns: [&[i32]; 2] = [&[1, 3, 5], &[2, 4, 6]];
+    assert_eq!(
+        DMatrix::from_columns_iter(3, columns.iter().copied()),
+        expected
+    );
+    let column_slices: Vec<_> = expected.column_iter().collect();
+    assert_eq!(DMatrix::from_columns_iter(3, &column_slices), expected);
+
+    let empty: Vec<Vec<f64>> = Vec::new();
+    assert_eq!(DMatrix::<f64>::from_rows_iter(4, &empty).shape(), (0, 4));
+    assert_eq!(DMatrix::<f64>::from_columns_iter(4, &empty).shape(), (4, 0));
+}
+
+#[test]
+#[should_panic(expected = "Row 1 does not have exactly 2 components.")]
+fn from_rows_iter_ragged() {
+    let _ = DMatrix::from_rows_iter(2, vec![vec![1, 2], vec![3, 4, 5]]);
+}
+
+#[test]
+#[should_panic(expected = "Column 0 does not have exactly 3 components.")]
+fn from_columns_iter_too_short() {
+    let _ = DMatrix::from_columns_iter(3, std::iter::once([1.0, 2.0]));
+}
+
+#[test]
+fn structure_predicates() {
+    let sym = DMatrix::from_fn(5, 5, |i, j| (i + j) as f64 + 1.0);
+    assert!(sym.is_symmetric(1.0e-12));
+    assert!(sym.is_hermitian(1.0e-12));
+    assert!(!sym.is_positive_definite(1.0e-12));
+
+    let spd = sym.tr_mul(&sym) + DMatrix::identity(5, 5);
+    assert!(spd.is_positive_definite(1.0e-10));
+
+    let mut nonsym = spd.clone();
+    nonsym[(4, 0)] += 1.0;
+    assert!(!nonsym.is_symmetric(1.0e-10));
+    assert!(!nonsym.is_positive_definite(1.0e-10));
+
+    let upper = sym.upper_triangle();
+    assert!(upper.is_upper_triangular(0.0));
+    assert!(!upper.is_lower_triangular(0.0));
+    assert!(upper.transpose().is_lower_triangular(0.0));
+    assert!(Matrix2x4::new(1.0, 2.0, 3.0, 4.0, 0.0, 5.0, 6.0, 7.0).is_upper_triangular(0.0));
+    assert!(Matrix4x3::<f64>::identity().is_lower_triangular(0.0));
+
+    let dominant = DMatrix::from_fn(4, 4, |i, j| if i == j { 3.0 } else { -1.0 });
+    assert!(dominant.is_diagonal_dominant());
+    assert!(!dominant.add_scalar(-0.1).is_diagonal_dominant());
+    assert!(!Matrix2x3::<f64>::zeros().is_diagonal_dominant());
+}
+
+#[test]
+fn any_matrix() {
+    use na::{AnyMatrix, AnyMatrixMut};
+
+    fn describe<M: AnyMatrix<i32>>(m: M) -> (usize, usize, i32) {
+        let (nrows, ncols) = m.shape();
+        (nrows, ncols, m.as_dynamic_slice().sum())
+    }
+
+    fn double<M: AnyMatrixMut<i32>>(mut m: M) {
+        m.as_dynamic_slice_mut().apply(|e| *e *= 2);
+    }
+
+    let mut a = Matrix3x4::from_fn(|i, j| (i * 4 + j) as i32);
+    let mut d = DMatrix::from_fn(3, 4, |i, j| (i * 4 + j) as i32);
+
+    assert_eq!(describe(&a), (3, 4, 66));
+    assert_eq!(describe(d.clone()), (3, 4, 66));
+    assert_eq!(describe(a.rows_with_step(0, 2, 1)), (2, 4, 44));
+    assert_eq!(describe(&&a.transpose()), (4, 3, 66));
+    assert_eq!(describe(DMatrix::<i32>::zeros(0, 3)), (0, 3, 0));
+    assert_eq!(a.as_dynamic_slice(), d);
+    assert_eq!(AnyMatrix::nrows(&a.column(1)), 3);
+
+    double(&mut a);
+    double(d.columns_with_step_mut(0, 2, 1));
+    assert_eq!(a.columns_with_step(0, 2, 1), d.columns_with_step(0, 2, 1));
+    assert_eq!(a.column(1), d.column(1) * 2);
+
+    let mut fixed = a.fixed_slice_mut::<2, 2>(1, 1);
+    double(&mut fixed);
+    assert_eq!(fixed.as_matrix_mut()[(0, 0)], 20);
+}
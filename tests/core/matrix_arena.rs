@@ -0,0 +1,67 @@
+use na::{DMatrix, DVector, Matrix2x3, MatrixArena};
+
+#[test]
+fn matrix_arena_allocations_are_disjoint() {
+    let arena = MatrixArena::with_capacity(8);
+    let mut a = arena.alloc_zeros(2, 2);
+    let mut b = arena.alloc_zeros_vector(3);
+    let c = arena.alloc_copy(&Matrix2x3::new(1, 2, 3, 4, 5, 6));
+
+    a.fill(1);
+    b.fill(2);
+
+    assert_eq!(a, DMatrix::from_element(2, 2, 1));
+    assert_eq!(b, DVector::from_element(3, 2));
+    assert_eq!(c, Matrix2x3::new(1, 2, 3, 4, 5, 6));
+    assert_eq!(arena.allocated_len(), 13);
+    // The third matrix did not fit in the first chunk.
+    assert!(arena.capacity() >= 8 + 6);
+}
+
+#[test]
+fn matrix_arena_reset() {
+    let mut arena = MatrixArena::with_capacity(4);
+
+    {
+        let mut a = arena.alloc_zeros(2, 2);
+        let mut b = arena.alloc_zeros(3, 3);
+        a.fill(1.0);
+        b.fill(2.0);
+    }
+
+    let capacity = arena.capacity();
+    assert!(capacity >= 13);
+    arena.reset();
+    assert_eq!(arena.allocated_len(), 0);
+    assert_eq!(arena.capacity(), capacity);
+
+    // The chunks were merged, and the reused memory is zeroed.
+    let a = arena.alloc_zeros(2, 2);
+    let b = arena.alloc_zeros(3, 3);
+    assert_eq!(a, DMatrix::zeros(2, 2));
+    assert_eq!(b, DMatrix::zeros(3, 3));
+    assert_eq!(arena.capacity(), capacity);
+}
+
+#[test]
+fn matrix_arena_gemm() {
+    let a = DMatrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+    let b = DMatrix::from_fn(4, 2, |i, j| (i + j) as f64);
+    let arena = MatrixArena::new();
+
+    let mut ab = arena.alloc_zeros(3, 2);
+    ab.gemm(1.0, &a, &b, 0.0);
+    let mut ab_col = arena.alloc_zeros_vector(3);
+    ab_col.gemv(1.0, &a, &b.column(1), 0.0);
+
+    assert_eq!(ab, &a * &b);
+    assert_eq!(ab_col, ab.column(1));
+}
+
+#[test]
+#[should_panic]
+fn matrix_arena_size_overflow() {
+    // `nrows × ncols` overflows `usize`, and would wrap around to zero in release builds.
+    let arena = MatrixArena::<f64>::new();
+    let _ = arena.alloc_zeros(usize::MAX / 2 + 1, 2);
+}
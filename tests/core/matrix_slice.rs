@@ -1,5 +1,8 @@
 #![allow(non_snake_case)]
 
+use std::ptr::NonNull;
+
+use na::{Const, Dynamic, MatrixSlice};
 use na::{
     DMatrix, DMatrixSlice, DMatrixSliceMut, Matrix2, Matrix2x3, Matrix2x4, Matrix2x6, Matrix3,
     Matrix3x2, Matrix3x4, Matrix4x2, Matrix6x2, MatrixSlice2, MatrixSlice2x3, MatrixSlice2xX,
@@ -280,6 +283,33 @@ fn new_slice_mut() {
     assert!(data_mut == expected2x3);
 }
 
+#[test]
+#[rustfmt::skip]
+fn new_slice_from_raw_parts() {
+    // A row-major 2x3 matrix with rows padded to 4 components, as in an image buffer.
+    let mut data = [ 1.0, 2.0, 3.0, -1.0,
+                     4.0, 5.0, 6.0, -1.0 ];
+    let expected = Matrix2x3::new(1.0, 2.0, 3.0,
+                                  4.0, 5.0, 6.0);
+
+    let m = unsafe {
+        MatrixSlice::from_raw_parts_generic(data.as_ptr(), Const::<2>, Const::<3>, Dynamic::new(4), Const::<1>)
+    };
+    assert!(m.eq(&expected));
+
+    let mut m = unsafe {
+        DMatrixSliceMut::from_raw_parts_generic(data.as_mut_ptr(), Dynamic::new(2), Dynamic::new(3), Dynamic::new(4), Dynamic::new(1))
+    };
+    m.fill(0.0);
+    assert!(data == [ 0.0, 0.0, 0.0, -1.0,
+                      0.0, 0.0, 0.0, -1.0 ]);
+
+    let empty = unsafe {
+        DMatrixSlice::<f64>::from_raw_parts_generic(NonNull::dangling().as_ptr(), Dynamic::new(0), Dynamic::new(3), Const::<1>, Dynamic::new(0))
+    };
+    assert_eq!(empty.shape(), (0, 3));
+}
+
 #[test]
 #[should_panic]
 fn row_out_of_bounds() {
@@ -335,3 +365,117 @@ fn slice_with_steps_out_of_bounds() {
     let a = Matrix3x4::<f32>::zeros();
     a.slice_with_steps((1, 2), (2, 2), (0, 1));
 }
+
+#[test]
+#[rustfmt::skip]
+fn diagonal_and_band_views() {
+    let mut a = Matrix3x4::new(11.0, 12.0, 13.0, 14.0,
+                               21.0, 22.0, 23.0, 24.0,
+                               31.0, 32.0, 33.0, 34.0);
+
+    assert_eq!(a.diagonal_view(), Vector3::new(11.0, 22.0, 33.0));
+    assert_eq!(a.transpose().diagonal_view(), Vector3::new(11.0, 22.0, 33.0));
+
+    // Views of views.
+    let s = a.fixed_slice::<2, 3>(1, 1);
+    assert_eq!(s.diagonal_view(), na::Vector2::new(22.0, 33.0));
+    assert_eq!(s.band_view(0, 2).diagonal(1), na::Vector2::new(23.0, 34.0));
+
+    let band = a.band_view(1, 2);
+    assert_eq!(band.diagonal(-1), na::Vector2::new(21.0, 32.0));
+    assert_eq!(band.diagonal(2), na::Vector2::new(13.0, 24.0));
+    assert!(band.contains(0, 2) && !band.contains(0, 3) && !band.contains(2, 0));
+    assert!(!band.contains(3, 3));
+    assert_eq!(band.iter().count(), 10);
+    assert_eq!(band.clone_owned(), Matrix3x4::new(11.0, 12.0, 13.0,  0.0,
+                                                  21.0, 22.0, 23.0, 24.0,
+                                                   0.0, 32.0, 33.0, 34.0));
+
+    assert_eq!(a.upper_triangle_view().clone_owned(), a.upper_triangle());
+    assert_eq!(a.lower_triangle_view().clone_owned(), a.lower_triangle());
+    assert_eq!(a.upper_triangle_view().diagonal(3).len(), 1);
+    assert_eq!(a.lower_triangle_view().diagonal(-2).len(), 1);
+
+    a.diagonal_view_mut().add_scalar_mut(100.0);
+    assert_eq!(a.diagonal_view(), Vector3::new(111.0, 122.0, 133.0));
+
+    a.lower_triangle_view_mut().fill(0.0);
+    assert_eq!(a, Matrix3x4::new(0.0, 12.0, 13.0, 14.0,
+                                 0.0,  0.0, 23.0, 24.0,
+                                 0.0,  0.0,  0.0, 34.0));
+
+    let mut band = a.band_view_mut(0, 1);
+    band.diagonal_mut(1).fill(1.0);
+    *band.get_mut(0, 0).unwrap() = 2.0;
+    assert!(band.get_mut(0, 2).is_none());
+    assert_eq!(a, Matrix3x4::new(2.0,  1.0, 13.0, 14.0,
+                                 0.0,  0.0,  1.0, 24.0,
+                                 0.0,  0.0,  0.0,  1.0));
+
+    // Empty diagonals.
+    let d = DMatrix::<f64>::zeros(2, 5);
+    assert_eq!(d.upper_triangle_view().diagonal(4).len(), 1);
+    assert_eq!(d.band_view(3, 0).diagonal(-3).len(), 0);
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).diagonal_view().len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn band_view_diagonal_out_of_band() {
+    let a = Matrix3x4::<f32>::zeros();
+    let _ = a.band_view(1, 1).diagonal(2);
+}
+
+#[test]
+fn checked_slicing() {
+    use na::base::indexing::IndexError;
+
+    let mut a = DMatrix::from_fn(3, 4, |i, j| i * 10 + j);
+
+    assert_eq!(a.try_slice((1, 2), (2, 2)), Ok(a.slice((1, 2), (2, 2))));
+    assert_eq!(a.try_rows(1, 2), Ok(a.rows(1, 2)));
+    assert_eq!(a.try_columns(0, 4), Ok(a.columns(0, 4)));
+    assert_eq!(a.try_slice((3, 4), (0, 0)).map(|s| s.len()), Ok(0));
+
+    assert_eq!(
+        a.try_slice((2, 0), (2, 1)),
+        Err(IndexError::RowsOutOfBounds {
+            rows: 2..4,
+            nrows: 3
+        })
+    );
+    assert_eq!(
+        a.try_columns(1, usize::MAX),
+        Err(IndexError::ColumnsOutOfBounds {
+            columns: 1..usize::MAX,
+            ncols: 4
+        })
+    );
+    assert_eq!(
+        a.try_rows_mut(4, 0).unwrap_err().to_string(),
+        "the rows 4..4 are out of bounds for a matrix with 3 rows"
+    );
+
+    a.try_slice_mut((0, 1), (2, 2)).unwrap().fill(0);
+    a.try_rows_mut(2, 1).unwrap().fill(1);
+    a.try_columns_mut(3, 1).unwrap().fill(2);
+    assert!(a.try_columns_mut(4, 1).is_err());
+
+    assert_eq!(a.try_set((0, 0), 5), Ok(()));
+    assert_eq!(
+        a.try_set((0, 4), 5),
+        Err(IndexError::ColumnsOutOfBounds {
+            columns: 4..5,
+            ncols: 4
+        })
+    );
+    assert_eq!(a.get((0, 4)), None);
+
+    #[rustfmt::skip]
+    let expected = DMatrix::from_row_slice(3, 4, &[
+        5,  0, 0, 2,
+        10, 0, 0, 2,
+        1,  1, 1, 2,
+    ]);
+    assert_eq!(a, expected);
+}
@@ -1,14 +1,33 @@
 #[cfg(feature = "abomonation-serialize")]
 mod abomonation;
+mod array_vec_storage;
 mod blas;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 mod cg;
+mod comparison;
+mod complex_embedding;
 mod conversion;
+#[cfg(feature = "io")]
+mod csv;
 mod edition;
 mod empty;
+#[cfg(feature = "convert-glam018")]
+mod glam;
+#[cfg(feature = "bytemuck")]
+mod mapped_storage;
 mod matrix;
+mod matrix_arena;
 mod matrix_slice;
 #[cfg(feature = "mint")]
 mod mint;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "io")]
+mod npy;
+#[cfg(feature = "parallel")]
+mod par_iter;
+mod row_major;
 mod serde;
 
 #[cfg(feature = "compare")]
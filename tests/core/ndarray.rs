@@ -0,0 +1,67 @@
+use std::convert::TryFrom;
+
+use na::{DMatrix, DMatrixSlice, DMatrixSliceMut, DVector, DVectorSlice, Dynamic, Matrix3x4};
+use ndarray::{s, Array1, Array2, ArrayView1, ArrayView2, ArrayViewMut2, ShapeBuilder};
+
+#[test]
+fn ndarray_owned_conversion() {
+    let m = DMatrix::from_fn(3, 4, |i, j| i * 10 + j);
+    let a = Array2::from(m.clone());
+
+    assert_eq!(a.dim(), (3, 4));
+    assert_eq!(a[(2, 1)], 21);
+    assert_eq!(DMatrix::from(a), m);
+
+    let c_order = Array2::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+    let f_order = Array2::from_shape_fn((3, 4).f(), |(i, j)| i * 10 + j);
+    assert_eq!(DMatrix::from(c_order.view()), m);
+    assert_eq!(DMatrix::from(f_order), m);
+    assert_eq!(
+        DMatrix::from(c_order.slice(s![.., ..;2])),
+        m.select_columns(&[0, 2])
+    );
+
+    let v = DVector::from_fn(5, |i, _| i);
+    let a = Array1::from(v.clone());
+    assert_eq!(a.len(), 5);
+    assert_eq!(DVector::from(a), v);
+}
+
+#[test]
+fn ndarray_views_from_matrices() {
+    let mut m = Matrix3x4::from_fn(|i, j| i * 10 + j);
+    let slice = m.fixed_slice::<2, 2>(1, 1);
+    let view = ArrayView2::from(&slice);
+
+    assert_eq!(view.dim(), (2, 2));
+    assert_eq!(view[(1, 0)], 21);
+
+    let column = m.column(2);
+    let view = ArrayView1::from(&column);
+    assert_eq!(view.to_vec(), vec![2, 12, 22]);
+
+    let mut view = ArrayViewMut2::from(&mut m);
+    view[(0, 3)] = 100;
+    assert_eq!(m[(0, 3)], 100);
+}
+
+#[test]
+fn ndarray_views_to_slices() {
+    let mut a = Array2::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+    let m = DMatrix::from_fn(3, 4, |i, j| i * 10 + j);
+
+    let slice = DMatrixSlice::<_, Dynamic, Dynamic>::try_from(a.view()).unwrap();
+    assert_eq!(slice, m);
+
+    let slice = DMatrixSlice::<_, Dynamic, Dynamic>::try_from(a.slice(s![1.., ..;2])).unwrap();
+    assert_eq!(slice, m.slice((1, 0), (2, 4)).select_columns(&[0, 2]));
+
+    let column = DVectorSlice::<_, Dynamic, Dynamic>::try_from(a.column(1)).unwrap();
+    assert_eq!(column, m.column(1));
+
+    assert!(DMatrixSlice::<_, Dynamic, Dynamic>::try_from(a.slice(s![..;-1, ..])).is_err());
+
+    let mut slice = DMatrixSliceMut::<_, Dynamic, Dynamic>::try_from(a.view_mut()).unwrap();
+    slice[(2, 3)] = 100;
+    assert_eq!(a[(2, 3)], 100);
+}
@@ -0,0 +1,158 @@
+use std::convert::TryInto;
+
+use na::io::{
+    read_npy, read_npy_vector, read_npz, write_npy, write_npy_vector, write_npz, NpyError,
+};
+use na::{Complex, DMatrix, DVector, Matrix2x3};
+
+fn npy_bytes(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+    let order = if fortran_order { "True" } else { "False" };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+        descr, order, shape
+    );
+    while (header.len() + 11) % 64 != 0 {
+        header.push(' ');
+    }
+    header.push('\n');
+
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+#[test]
+fn npy_roundtrip() {
+    let m = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let mut bytes = Vec::new();
+    write_npy(&m, &mut bytes).unwrap();
+    assert_eq!(bytes.len() % 64, 48);
+    assert_eq!(read_npy::<f64, _>(&bytes[..]).unwrap(), m);
+
+    let m = DMatrix::from_fn(4, 3, |i, j| (i * 3 + j) as i32 - 5);
+    let mut bytes = Vec::new();
+    write_npy(&m, &mut bytes).unwrap();
+    assert_eq!(read_npy::<i32, _>(&bytes[..]).unwrap(), m);
+
+    let m = DMatrix::from_fn(2, 2, |i, j| Complex::new(i as f32, j as f32));
+    let mut bytes = Vec::new();
+    write_npy(&m, &mut bytes).unwrap();
+    assert_eq!(read_npy::<Complex<f32>, _>(&bytes[..]).unwrap(), m);
+
+    let v = DVector::from_vec(vec![1u8, 2, 3]);
+    let mut bytes = Vec::new();
+    write_npy_vector(&v, &mut bytes).unwrap();
+    assert_eq!(read_npy_vector::<u8, _>(&bytes[..]).unwrap(), v);
+    assert_eq!(
+        read_npy::<u8, _>(&bytes[..]).unwrap(),
+        DMatrix::from_column_slice(3, 1, &[1, 2, 3])
+    );
+}
+
+#[test]
+fn npy_layouts_and_dtypes() {
+    let data: Vec<u8> = (1..=6)
+        .flat_map(|i| (i as f64).to_le_bytes().to_vec())
+        .collect();
+    let row_major = npy_bytes("<f8", false, "(2, 3)", &data);
+    let col_major = npy_bytes("<f8", true, "(2, 3)", &data);
+
+    assert_eq!(
+        read_npy::<f64, _>(&row_major[..]).unwrap(),
+        DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    );
+    assert_eq!(
+        read_npy::<f64, _>(&col_major[..]).unwrap(),
+        DMatrix::from_column_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    );
+
+    let data: Vec<u8> = (1..=3)
+        .flat_map(|i| (i as i16).to_be_bytes().to_vec())
+        .collect();
+    let big_endian = npy_bytes(">i2", false, "(3,)", &data);
+    assert_eq!(
+        read_npy_vector::<i16, _>(&big_endian[..]).unwrap(),
+        DVector::from_vec(vec![1, 2, 3])
+    );
+
+    match read_npy::<f32, _>(&row_major[..]) {
+        Err(NpyError::DTypeMismatch { expected, found }) => {
+            assert_eq!(expected, "<f4");
+            assert_eq!(found, "<f8");
+        }
+        _ => panic!("expected a dtype mismatch"),
+    }
+
+    let cube = npy_bytes("<f8", false, "(1, 2, 3)", &data);
+    assert!(read_npy::<f64, _>(&cube[..]).is_err());
+    assert!(read_npy::<f64, _>(&data[..]).is_err());
+
+    // The shape of a crafted header must not result in a huge allocation.
+    let huge = npy_bytes("<f8", false, "(1000000000000, 1000)", &data);
+    assert!(matches!(
+        read_npy::<f64, _>(&huge[..]),
+        Err(NpyError::InvalidFormat(_))
+    ));
+
+    // Neither must the header length of the version 2.0 of the format.
+    let mut huge_header = b"\x93NUMPY\x02\x00".to_vec();
+    huge_header.extend_from_slice(&u32::MAX.to_le_bytes());
+    assert!(matches!(
+        read_npy::<f64, _>(&huge_header[..]),
+        Err(NpyError::InvalidFormat(_))
+    ));
+}
+
+#[test]
+fn npz_roundtrip() {
+    let a = DMatrix::from_fn(3, 2, |i, j| (i + 10 * j) as f64);
+    let b = DMatrix::from_row_slice(1, 2, &[-1.0, 1.0]);
+    let mut bytes = Vec::new();
+    write_npz(&[("a", &a), ("b", &b)], &mut bytes).unwrap();
+
+    let arrays = read_npz::<f64, _>(&bytes[..]).unwrap();
+    assert_eq!(arrays, vec![("a".to_string(), a), ("b".to_string(), b)]);
+
+    // Zip archives store the length of the names on two bytes.
+    let long_name = "a".repeat(usize::from(u16::MAX));
+    assert!(matches!(
+        write_npz(
+            &[(&long_name, &DMatrix::<f64>::zeros(1, 1))],
+            &mut Vec::new()
+        ),
+        Err(NpyError::InvalidFormat(_))
+    ));
+
+    // Corrupt the data of the first array, which starts after its 30-bytes zip header and name.
+    bytes[30 + "a.npy".len() + 100] ^= 1;
+    assert!(read_npz::<f64, _>(&bytes[..]).is_err());
+}
+
+#[test]
+fn npz_invalid_zip64_size() {
+    let a = DMatrix::from_fn(3, 2, |i, j| (i + 10 * j) as f64);
+    let mut bytes = Vec::new();
+    write_npz(&[("a", &a)], &mut bytes).unwrap();
+
+    // Make the compressed size of the only entry a zip64 size of `u64::MAX`, stored in an extra
+    // field of its central directory header, which is followed by the 22-bytes end of central
+    // directory record.
+    let eocd = bytes.len() - 22;
+    let central = u32::from_le_bytes(bytes[eocd + 16..eocd + 20].try_into().unwrap()) as usize;
+    bytes[central + 20..central + 24].copy_from_slice(&u32::MAX.to_le_bytes());
+    bytes[central + 30..central + 32].copy_from_slice(&12u16.to_le_bytes());
+    let extra: Vec<u8> = [1u16, 8]
+        .iter()
+        .flat_map(|e| e.to_le_bytes().to_vec())
+        .chain(u64::MAX.to_le_bytes().iter().cloned())
+        .collect();
+    let extra_start = central + 46 + "a.npy".len();
+    let _ = bytes.splice(extra_start..extra_start, extra);
+
+    assert!(matches!(
+        read_npz::<f64, _>(&bytes[..]),
+        Err(NpyError::InvalidFormat(_))
+    ));
+}
@@ -0,0 +1,24 @@
+use na::{DMatrix, Matrix3x4};
+use rayon::prelude::*;
+
+#[test]
+fn par_column_iter_matches_column_iter() {
+    let m = DMatrix::from_fn(17, 123, |i, j| (i * 1000 + j) as f64);
+    let sums: Vec<f64> = m.par_column_iter().map(|col| col.sum()).collect();
+    let expected: Vec<f64> = m.column_iter().map(|col| col.sum()).collect();
+
+    assert_eq!(sums, expected);
+}
+
+#[test]
+fn par_column_iter_mut_on_slice() {
+    let mut m = Matrix3x4::from_fn(|i, j| (i + 3 * j) as i32);
+    let mut expected = m;
+    expected.columns_mut(1, 2).iter_mut().for_each(|e| *e *= -1);
+
+    m.columns_mut(1, 2)
+        .par_column_iter_mut()
+        .for_each(|mut col| col.neg_mut());
+
+    assert_eq!(m, expected);
+}
@@ -0,0 +1,75 @@
+use na::{DMatrix, DMatrixRowMajor, DVector, Matrix2x3, Matrix3, Vector3};
+
+#[test]
+fn row_major_layout() {
+    let mut m = DMatrixRowMajor::from_row_major_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(m, Matrix2x3::new(1, 2, 3, 4, 5, 6));
+    assert_eq!(m[(1, 0)], 4);
+    assert_eq!(m.row(1), Matrix2x3::new(1, 2, 3, 4, 5, 6).row(1));
+    assert_eq!(m.column(2).iter().copied().collect::<Vec<_>>(), vec![3, 6]);
+    assert_eq!(
+        m.iter().copied().collect::<Vec<_>>(),
+        vec![1, 4, 2, 5, 3, 6]
+    );
+
+    m[(0, 1)] = 20;
+    m.column_mut(2).fill(0);
+    assert_eq!(m.as_row_major_slice(), &[1, 20, 0, 4, 5, 0]);
+    assert_eq!(m.into_row_major_vec(), vec![1, 20, 0, 4, 5, 0]);
+}
+
+#[test]
+fn row_major_conversions() {
+    let m = DMatrix::from_fn(3, 4, |i, j| (i * 10 + j) as f64);
+    let row_major = m.to_row_major();
+
+    assert_eq!(row_major, m);
+    assert_eq!(row_major.clone_owned(), m);
+    assert_eq!(row_major.clone().into_owned(), m);
+    assert_eq!(row_major.transpose(), m.transpose());
+
+    // The transposition reinterpretations don't copy anything.
+    let ptr = row_major.as_ptr();
+    let t = row_major.transpose_into_column_major();
+    assert_eq!(t, m.transpose());
+    assert_eq!(t.as_ptr(), ptr);
+
+    let ptr = t.as_ptr();
+    let row_major = t.transpose_into_row_major();
+    assert_eq!(row_major, m);
+    assert_eq!(row_major.as_ptr(), ptr);
+}
+
+#[test]
+fn row_major_ops() {
+    let a = Matrix3::new(4.0, 1.0, 2.0, 1.0, 5.0, 3.0, 2.0, 3.0, 6.0);
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    let mut row_major =
+        DMatrixRowMajor::from_row_major_vec(3, 3, a.transpose().as_slice().to_vec());
+    assert_eq!(row_major, a);
+
+    assert_eq!(&row_major * b, a * b);
+    assert_eq!(&row_major + a, a * 2.0);
+    assert_eq!(row_major.tr_mul(&b), a.tr_mul(&b));
+    assert_eq!(row_major.determinant(), a.determinant());
+
+    let x = row_major.clone().lu().solve(&b).unwrap();
+    assert_relative_eq!(a * x, b, epsilon = 1.0e-10);
+    let l = row_major.clone().cholesky().unwrap().l();
+    assert_relative_eq!(
+        &l * l.transpose(),
+        row_major.clone_owned(),
+        epsilon = 1.0e-10
+    );
+    let eigen = row_major.clone().symmetric_eigen();
+    assert_relative_eq!(
+        eigen.recompose(),
+        row_major.clone_owned(),
+        epsilon = 1.0e-10
+    );
+
+    row_major *= 2.0;
+    row_major += DVector::from_element(3, 1.0) * DVector::from_element(3, 1.0).transpose();
+    assert_eq!(row_major, a * 2.0 + Matrix3::repeat(1.0));
+}
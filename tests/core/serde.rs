@@ -1,9 +1,10 @@
 #![cfg(feature = "serde-serialize")]
 
 use na::{
-    DMatrix, Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, Matrix2x3, Matrix3x4, Point2,
-    Point3, Quaternion, Rotation2, Rotation3, Similarity2, Similarity3, SimilarityMatrix2,
-    SimilarityMatrix3, Translation2, Translation3, Unit, Vector2,
+    Cholesky, DMatrix, DVector, Dynamic, Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3,
+    Matrix2x3, Matrix3x4, Point2, Point3, Quaternion, Rotation2, Rotation3, Similarity2,
+    Similarity3, SimilarityMatrix2, SimilarityMatrix3, Translation2, Translation3, Unit, Vector2,
+    LU, QR, SVD,
 };
 use rand;
 use serde::{Deserialize, Serialize};
@@ -74,6 +75,56 @@ test_serde!(
     serde_similarity_matrix2, SimilarityMatrix2;
 );
 
+#[test]
+fn serde_decompositions() {
+    let m = DMatrix::<f64>::new_random(4, 4);
+    let spd = &m * m.transpose() + DMatrix::identity(4, 4);
+    let b = DVector::<f64>::new_random(4);
+
+    let lu = m.clone().lu();
+    let lu: LU<f64, Dynamic, Dynamic> =
+        serde_json::from_str(&serde_json::to_string(&lu).unwrap()).unwrap();
+    assert_relative_eq!(&m * lu.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+
+    let chol = spd.clone().cholesky().unwrap();
+    let chol: Cholesky<f64, Dynamic> =
+        serde_json::from_str(&serde_json::to_string(&chol).unwrap()).unwrap();
+    assert_relative_eq!(&spd * chol.solve(&b), b, epsilon = 1.0e-7);
+
+    let qr = m.clone().qr();
+    let qr: QR<f64, Dynamic, Dynamic> =
+        serde_json::from_str(&serde_json::to_string(&qr).unwrap()).unwrap();
+    assert_relative_eq!(&m * qr.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+
+    let svd = m.clone().svd(true, true);
+    let svd: SVD<f64, Dynamic, Dynamic> =
+        serde_json::from_str(&serde_json::to_string(&svd).unwrap()).unwrap();
+    assert_relative_eq!(svd.recompose().unwrap(), m, epsilon = 1.0e-7);
+}
+
+#[test]
+fn decomposition_parts_roundtrip() {
+    let m = DMatrix::<f64>::new_random(4, 4);
+    let spd = &m * m.transpose() + DMatrix::identity(4, 4);
+    let b = DVector::<f64>::new_random(4);
+
+    let (lu, p) = m.clone().lu().into_parts();
+    let lu = LU::from_parts(lu, p);
+    assert_relative_eq!(&m * lu.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+
+    let l = spd.clone().cholesky().unwrap().into_parts();
+    let chol = Cholesky::from_parts(l);
+    assert_relative_eq!(&spd * chol.solve(&b), b, epsilon = 1.0e-7);
+
+    let (qr, diag) = m.clone().qr().into_parts();
+    let qr = QR::from_parts(qr, diag);
+    assert_relative_eq!(&m * qr.solve(&b).unwrap(), b, epsilon = 1.0e-7);
+
+    let (u, singular_values, v_t) = m.clone().svd(true, true).into_parts();
+    let svd = SVD::from_parts(u, singular_values, v_t);
+    assert_relative_eq!(svd.recompose().unwrap(), m, epsilon = 1.0e-7);
+}
+
 #[test]
 fn serde_flat() {
     // The actual storage is hidden behind three layers of wrapper types that shouldn't appear in serialized form.
@@ -1,7 +1,9 @@
 #![cfg(feature = "proptest-support")]
 #![allow(non_snake_case)]
 
-use na::{DualQuaternion, Point3, UnitDualQuaternion, Vector3};
+use na::{
+    DualQuaternion, Isometry3, Point3, Quaternion, Translation3, UnitDualQuaternion, Vector3,
+};
 
 use crate::proptest::*;
 use proptest::{prop_assert, proptest};
@@ -15,6 +17,36 @@ proptest!(
         prop_assert!(relative_eq!(iso * v, dq * v, epsilon = 1.0e-7));
     }
 
+    #[test]
+    fn isometry_round_trip(iso in isometry3(), dq in unit_dual_quaternion(), p in point3()) {
+        let from_iso = UnitDualQuaternion::from_isometry(&iso);
+        let from_dq = UnitDualQuaternion::from(dq.to_isometry());
+
+        prop_assert!(relative_eq!(from_iso.to_isometry(), iso, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(Isometry3::from(from_iso), iso, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(from_dq * p, dq * p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(from_dq.to_isometry(), dq.to_isometry(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn renormalize_rigid(dq in unit_dual_quaternion(), p in point3(), e in vector4()) {
+        // Simulate drift by perturbing both parts.
+        let drift = Quaternion::from(e * 1.0e-3);
+        let mut drifted = UnitDualQuaternion::new_unchecked(
+            DualQuaternion::from_real_and_dual(dq.real + drift, dq.dual - drift)
+        );
+        drifted.renormalize_rigid();
+
+        prop_assert!(relative_eq!(drifted.real.norm(), 1.0, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(drifted.real.coords.dot(&drifted.dual.coords), 0.0, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(drifted, UnitDualQuaternion::from_isometry(&drifted.to_isometry()), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(drifted * p, drifted.to_isometry() * p, epsilon = 1.0e-7));
+
+        let mut exact = dq;
+        exact.renormalize_rigid();
+        prop_assert!(relative_eq!(exact, dq, epsilon = 1.0e-7));
+    }
+
     #[test]
     fn inverse_is_identity(i in unit_dual_quaternion(), p in point3(), v in vector3()) {
         let ii = i.inverse();
@@ -74,6 +106,30 @@ proptest!(
         prop_assert!(relative_eq!((dq * t) * p, dq * (t * p), epsilon = 1.0e-7));
     }
 
+    #[test]
+    fn sclerp_pure_translation(dq in unit_dual_quaternion(), t in translation3(), s in 0.0..1.0f64) {
+        let other = dq * t;
+        let interp = dq.sclerp(&other, s);
+        let expected = dq * Translation3::from(t.vector * s);
+
+        prop_assert!(relative_eq!(interp, expected, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn blend_identical_and_antipodal(dq in unit_dual_quaternion(), w1 in 0.1..1.0f64, w2 in 0.1..1.0f64) {
+        let blend = UnitDualQuaternion::blend(&[dq, -dq], &[w1, w2]).unwrap();
+
+        prop_assert!(relative_eq!(blend, dq, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn blend_is_unit(dq1 in unit_dual_quaternion(), dq2 in unit_dual_quaternion(), w in 0.0..1.0f64) {
+        let blend = UnitDualQuaternion::blend(&[dq1, dq2], &[w, 1.0 - w]).unwrap();
+        let iso = blend.to_isometry();
+
+        prop_assert!(relative_eq!(UnitDualQuaternion::from_isometry(&iso), blend, epsilon = 1.0e-7));
+    }
+
     #[cfg_attr(rustfmt, rustfmt_skip)]
     #[test]
     fn all_op_exist(
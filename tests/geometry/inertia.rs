@@ -0,0 +1,49 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{Isometry3, Matrix3, Point3, Vector3};
+
+use crate::proptest::*;
+use proptest::{prop_assert, proptest};
+
+/// The inertia tensor of unit point masses with respect to the origin.
+fn point_masses_inertia(points: &[Point3<f64>]) -> Matrix3<f64> {
+    points.iter().fold(Matrix3::zeros(), |acc, p| {
+        acc + Matrix3::from_diagonal_element(p.coords.norm_squared())
+            - p.coords * p.coords.transpose()
+    })
+}
+
+proptest!(
+    #[test]
+    fn inertia_transform_by_point_masses(iso in isometry3(), a in vector3(), b in vector3()) {
+        // Four unit point masses centered at the origin.
+        let local = [Point3::from(a), Point3::from(-a), Point3::from(b), Point3::from(-b)];
+        let global: Vec<_> = local.iter().map(|p| iso * p).collect();
+
+        let inertia = point_masses_inertia(&local).inertia_transform_by(4.0, &iso);
+        let expected = point_masses_inertia(&global);
+        prop_assert!(relative_eq!(inertia, expected, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn inertia_principal_axes_recompose(a in vector3(), b in vector3(), c in vector3()) {
+        let inertia = point_masses_inertia(&[Point3::from(a), Point3::from(b), Point3::from(c)]);
+        let (moments, axes) = inertia.inertia_principal_axes();
+
+        prop_assert!(moments.x <= moments.y && moments.y <= moments.z);
+        prop_assert!(relative_eq!(axes.matrix().determinant(), 1.0, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(
+            axes * Matrix3::from_diagonal(&moments) * axes.inverse(),
+            inertia,
+            epsilon = 1.0e-7
+        ));
+
+        // The tensor is diagonal in the principal frame.
+        let to_principal = Isometry3::from_parts(Vector3::zeros().into(), axes.inverse().into());
+        prop_assert!(relative_eq!(
+            inertia.inertia_transform_by(0.0, &to_principal),
+            Matrix3::from_diagonal(&moments),
+            epsilon = 1.0e-7
+        ));
+    }
+);
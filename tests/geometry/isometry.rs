@@ -1,7 +1,10 @@
 #![cfg(feature = "proptest-support")]
 #![allow(non_snake_case)]
 
-use na::{Isometry3, Point3, Vector3};
+use na::{
+    Isometry3, Matrix6, Point3, Projective3, Translation3, Twist, UnitComplex, UnitQuaternion,
+    Vector3, Vector6, Wrench,
+};
 
 use crate::proptest::*;
 use proptest::{prop_assert, prop_assert_eq, proptest};
@@ -48,6 +51,142 @@ proptest!(
             ))
     }
 
+    #[test]
+    fn look_to_3(eye in point3(), dir in vector3(), up in vector3()) {
+        let view_rh = Isometry3::look_to_rh(&eye, &dir, &up);
+        let view_lh = Isometry3::look_to_lh(&eye, &dir, &up);
+
+        let view_at_rh = Isometry3::look_at_rh(&eye, &(eye + dir), &up);
+
+        prop_assert!(relative_eq!(view_rh, view_at_rh, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(view_lh * eye, Point3::origin(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((view_lh * dir).normalize(), Vector3::z(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn look_at_collinear_up_3(eye in point3(), dir in vector3(), s in PROPTEST_F64) {
+        // `up` is collinear to the view direction, or zero.
+        let up = dir * s;
+        let view = Isometry3::look_to_rh(&eye, &dir, &up);
+        let observer = Isometry3::face_towards(&eye, &(eye + dir), &up);
+
+        prop_assert!(view.rotation.coords.iter().all(|e| e.is_finite()));
+        prop_assert!(relative_eq!(view.rotation.norm(), 1.0, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((view * dir).normalize(), -Vector3::z(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(observer * Vector3::z(), dir.normalize(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn exp_log_3(i in isometry3()) {
+        prop_assert!(relative_eq!(Isometry3::exp(&i.log()), i, epsilon = 1.0e-7))
+    }
+
+    #[test]
+    fn exp_is_matrix_exp_3(v in vector6()) {
+        let twist = v / 100.0;
+        let iso = Isometry3::exp(&twist);
+        let hat = Isometry3::hat(&twist);
+
+        prop_assert!(relative_eq!(iso.to_homogeneous(), hat.exp(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(Isometry3::vee(&hat), twist, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(iso.log(), twist, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn jacobians_small_angles_3(rho in vector3(), axis in vector3(), theta in 1.0e-5f64..2.0) {
+        // The left Jacobian is the series `Σₙ ad(ξ)ⁿ / (n + 1)!` of the adjoint `ad(ξ)` of the
+        // twist `ξ`, including at the switch between the series and the closed forms of `θ = 1`.
+        if let Some(axis) = axis.try_normalize(1.0e-3) {
+            let phi = axis * theta;
+            let twist = Vector6::new(rho.x, rho.y, rho.z, phi.x, phi.y, phi.z);
+            let mut ad = Matrix6::zeros();
+            ad.fixed_slice_mut::<3, 3>(0, 0).copy_from(&phi.cross_matrix());
+            ad.fixed_slice_mut::<3, 3>(0, 3).copy_from(&rho.cross_matrix());
+            ad.fixed_slice_mut::<3, 3>(3, 3).copy_from(&phi.cross_matrix());
+
+            let mut term = Matrix6::identity();
+            let mut expected = Matrix6::identity();
+            for n in 1..60 {
+                term = term * ad / (n + 1) as f64;
+                expected += term;
+            }
+
+            let jl = Isometry3::left_jacobian(&twist);
+            prop_assert!(relative_eq!(jl, expected, epsilon = 1.0e-11, max_relative = 1.0e-13));
+            prop_assert!(relative_eq!(jl * Isometry3::left_jacobian_inverse(&twist), Matrix6::identity(), epsilon = 1.0e-9));
+        }
+    }
+
+    #[test]
+    fn jacobians_3(v in vector6(), d in vector6()) {
+        let twist = v / 100.0;
+        let delta = d * 1.0e-8;
+        let exp = Isometry3::exp(&(twist + delta));
+        let jl = Isometry3::left_jacobian(&twist);
+        let jr = Isometry3::right_jacobian(&twist);
+
+        prop_assert!(relative_eq!(exp, Isometry3::exp(&(jl * delta)) * Isometry3::exp(&twist), epsilon = 1.0e-10));
+        prop_assert!(relative_eq!(exp, Isometry3::exp(&twist) * Isometry3::exp(&(jr * delta)), epsilon = 1.0e-10));
+        prop_assert!(relative_eq!(jl * Isometry3::left_jacobian_inverse(&twist), Matrix6::identity(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(jr * Isometry3::right_jacobian_inverse(&twist), Matrix6::identity(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn twist_wrench_transform_3(i in isometry3(), v in vector6(), f in vector6()) {
+        let twist = Twist::from_vector(&v);
+        let wrench = Wrench::from_vector(&f);
+        let twist_a = twist.transform_by(&i);
+        let wrench_a = wrench.transform_by(&i);
+
+        prop_assert!(relative_eq!(twist_a.to_vector(), i.adjoint() * v, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(twist_a.inverse_transform_by(&i).to_vector(), v, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(wrench_a.inverse_transform_by(&i).to_vector(), f, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(twist_a.power(&wrench_a), twist.power(&wrench), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn screw_axis_3(i in isometry3(), t in vector3()) {
+        let (point, axis, angle, translation) = i.screw_axis().unwrap();
+        let screw = Translation3::from(point.coords + axis.scale(translation))
+            * UnitQuaternion::from_axis_angle(&axis, angle)
+            * Translation3::from(-point.coords);
+
+        prop_assert!(relative_eq!(screw, i, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(point.coords.dot(&axis), 0.0, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(i * point, point + axis.scale(translation), epsilon = 1.0e-7));
+
+        let (point, axis, angle, translation) = Isometry3::from(Translation3::from(t)).screw_axis().unwrap();
+        prop_assert_eq!((point, angle), (Point3::origin(), 0.0));
+        prop_assert!(relative_eq!(axis.scale(translation), t, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn planar_isometry_3(i in isometry2(), j in isometry2(), p in point2(), z in PROPTEST_F64) {
+        let i3 = i.to_isometry3_xy();
+
+        prop_assert!(relative_eq!(i3 * p.to_3d(z), (i * p).to_3d(z), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(i3.project_to_xy_plane(), i, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(i3 * j.to_isometry3_xy(), (i * j).to_isometry3_xy(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn project_to_xy_plane_3(i in isometry3(), r in PROPTEST_F64) {
+        // Rotations about `z` are preserved by the projection.
+        let yaw = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), r);
+        let projected = i.project_to_xy_plane();
+
+        prop_assert!(relative_eq!(
+            (Isometry3::from_parts(Translation3::identity(), yaw) * i).project_to_xy_plane(),
+            UnitComplex::new(r) * projected,
+            epsilon = 1.0e-7
+        ));
+        prop_assert!(relative_eq!(
+            projected.translation.vector,
+            i.translation.vector.xy(),
+            epsilon = 1.0e-7
+        ));
+    }
+
     #[test]
     fn inverse_is_identity(i in isometry3(), p in point3(), v in vector3()) {
         let ii = i.inverse();
@@ -82,6 +221,53 @@ proptest!(
             ))
     }
 
+    #[test]
+    fn batch_transform_equals_transform(
+        i in isometry3(),
+        s in similarity3(),
+        r in rotation3(),
+        p in point3(),
+        q in point3(),
+        v in vector3()
+    ) {
+        let proj = Projective3::from_matrix_unchecked(s.to_homogeneous());
+        let mut points = [p, q];
+        let mut vectors = [v, p.coords];
+
+        i.transform_points(&mut points);
+        i.transform_vectors(&mut vectors);
+        prop_assert!(relative_eq!(points[0], i * p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(points[1], i * q, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(vectors[0], i * v, epsilon = 1.0e-7));
+        i.inverse_transform_points(&mut points);
+        i.inverse_transform_vectors(&mut vectors);
+        prop_assert!(relative_eq!(points[0], p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(vectors[0], v, epsilon = 1.0e-7));
+
+        s.transform_points(&mut points);
+        s.transform_vectors(&mut vectors);
+        prop_assert!(relative_eq!(points[0], s * p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(vectors[0], s * v, epsilon = 1.0e-7));
+        s.inverse_transform_points(&mut points);
+        s.inverse_transform_vectors(&mut vectors);
+        prop_assert!(relative_eq!(points[0], p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(vectors[0], v, epsilon = 1.0e-7));
+
+        r.transform_points(&mut points);
+        r.transform_vectors(&mut vectors);
+        prop_assert!(relative_eq!(points[0], r * p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(vectors[0], r * v, epsilon = 1.0e-7));
+        r.inverse_transform_points(&mut points);
+        r.inverse_transform_vectors(&mut vectors);
+        prop_assert!(relative_eq!(points[0], p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(vectors[0], v, epsilon = 1.0e-7));
+
+        proj.transform_points(&mut points);
+        proj.transform_vectors(&mut vectors);
+        prop_assert!(relative_eq!(points[0], proj * p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(vectors[0], proj * v, epsilon = 1.0e-7));
+    }
+
     #[test]
     #[cfg_attr(rustfmt, rustfmt_skip)]
     fn composition2(
@@ -0,0 +1,48 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{Manifold, Vector1};
+
+use crate::proptest::*;
+use proptest::{prop_assert, proptest};
+
+proptest!(
+    #[test]
+    fn unit_complex_oplus_ominus(a in unit_complex(), b in unit_complex(), s in PROPTEST_F64) {
+        let delta = Vector1::new(s / 100.0);
+
+        prop_assert!(relative_eq!(a.oplus(&b.ominus(&a)), b, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(a.oplus(&delta).ominus(&a), delta, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn unit_quaternion_oplus_ominus(a in unit_quaternion(), b in unit_quaternion(), v in vector3()) {
+        let delta = v / 100.0;
+
+        prop_assert!(relative_eq!(a.oplus(&b.ominus(&a)), b, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(a.oplus(&delta).ominus(&a), delta, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn rotation3_oplus_ominus(a in rotation3(), b in rotation3(), v in vector3()) {
+        let delta = v / 100.0;
+
+        prop_assert!(relative_eq!(a.oplus(&b.ominus(&a)), b, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(a.oplus(&delta).ominus(&a), delta, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn isometry2_oplus_ominus(a in isometry2(), b in isometry2(), v in vector3()) {
+        let delta = v / 100.0;
+
+        prop_assert!(relative_eq!(a.oplus(&b.ominus(&a)), b, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(a.oplus(&delta).ominus(&a), delta, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn isometry3_oplus_ominus(a in isometry3(), b in isometry3(), v in vector6()) {
+        let delta = v / 100.0;
+
+        prop_assert!(relative_eq!(a.oplus(&b.ominus(&a)), b, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(a.oplus(&delta).ominus(&a), delta, epsilon = 1.0e-7));
+    }
+);
@@ -1,8 +1,14 @@
 mod dual_quaternion;
+mod inertia;
 mod isometry;
+mod manifold;
 mod point;
 mod projection;
 mod quaternion;
+mod reflection;
 mod rotation;
+mod shear;
 mod similarity;
+mod spline;
+mod transform;
 mod unit_complex;
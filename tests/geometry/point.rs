@@ -1,4 +1,7 @@
-use na::{Point3, Vector3, Vector4};
+use na::{
+    Matrix2, Matrix3, Point1, Point2, Point3, Quaternion, Translation3, UnitQuaternion, Vector2,
+    Vector3, Vector4,
+};
 use num::Zero;
 
 #[test]
@@ -92,3 +95,140 @@ fn to_homogeneous() {
 
     assert_eq!(a.to_homogeneous(), expected);
 }
+
+#[test]
+fn const_constructors() {
+    const POINTS: [Point2<f32>; 2] = [Point2::new(1.0, 2.0), Point2::new(3.0, 4.0)];
+    const ORIGIN: Point1<u32> = Point1::new(0);
+    const P: Point3<f64> = Point3::new(1.0, 2.0, 3.0);
+    const V: Vector2<f64> = Vector2::new(1.0, 2.0);
+    const M: Matrix3<f64> = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    const ROT90: [Matrix2<i32>; 2] = [Matrix2::new(0, -1, 1, 0), Matrix2::new(0, 1, -1, 0)];
+    const T: Translation3<f64> = Translation3::new(1.0, 2.0, 3.0);
+    const Q: UnitQuaternion<f64> =
+        UnitQuaternion::new_unchecked(Quaternion::new(1.0, 0.0, 0.0, 0.0));
+
+    assert_eq!(POINTS[1].y, 4.0);
+    assert_eq!(ORIGIN.x, 0);
+    assert_eq!(T * P, Point3::new(2.0, 4.0, 6.0));
+    assert_eq!(M * Vector3::new(0.0, V.x, 0.0), Vector3::new(2.0, 5.0, 8.0));
+    assert_eq!(ROT90[0] * ROT90[1], Matrix2::identity());
+    assert_eq!(Q * P, P);
+}
+
+#[test]
+fn point_centroid() {
+    let points = [
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(-1.0, 4.0, 0.0),
+        Point3::new(3.0, 0.0, 6.0),
+    ];
+    let expected = Point3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(Point3::centroid(&points), Some(expected));
+    assert_eq!(Point3::centroid_iter(points.iter()), Some(expected));
+    assert_eq!(
+        Point3::centroid_iter(points.iter().cloned()),
+        Some(expected)
+    );
+    assert_eq!(Point3::<f64>::centroid(&[]), None);
+
+    // Uniform weights match the unweighted centroid, whatever their scale.
+    let weights = [2.5; 3];
+    assert_eq!(Point3::weighted_centroid(&points, &weights), Some(expected));
+    assert_eq!(
+        Point3::weighted_centroid(&points, &[1.0, 0.0, 1.0]),
+        Some(Point3::new(2.0, 1.0, 4.5))
+    );
+    assert_eq!(
+        Point3::weighted_centroid_iter(points.iter().map(|p| (p, -1.0))),
+        Some(expected)
+    );
+    assert_eq!(Point3::weighted_centroid(&points, &[0.0; 3]), None);
+}
+
+#[test]
+fn point_centroid_compensated() {
+    // The small coordinates are completely lost by a naive summation.
+    let points = [
+        Point2::new(1.0, -1.0),
+        Point2::new(1.0e100, 1.0e100),
+        Point2::new(1.0, -1.0),
+        Point2::new(-1.0e100, -1.0e100),
+    ];
+
+    let naive = points
+        .iter()
+        .fold(Vector2::zeros(), |acc, p| acc + p.coords)
+        / 4.0;
+    assert_eq!(naive, Vector2::zeros());
+    assert_eq!(Point2::centroid(&points), Some(Point2::new(0.5, -0.5)));
+
+    let weights = [2.0, 1.0, 2.0, 1.0];
+    assert_eq!(
+        Point2::weighted_centroid(&points, &weights),
+        Some(Point2::new(2.0 / 3.0, -2.0 / 3.0))
+    );
+}
+
+#[test]
+#[should_panic]
+fn point_weighted_centroid_length_mismatch() {
+    let points = [Point2::new(1.0, 2.0), Point2::new(3.0, 4.0)];
+    let _ = Point2::weighted_centroid(&points, &[1.0]);
+}
+
+#[test]
+fn point_barycentric_coordinates() {
+    let a = Point3::new(1.0, -2.0, 0.5);
+    let b = Point3::new(3.0, 1.0, -1.0);
+    let c = Point3::new(-2.0, 4.0, 2.0);
+    let bary = Vector3::new(0.2, 0.5, 0.3);
+
+    // A point on the plane of the triangle, and another one off the plane.
+    let p = Point3::from_barycentric_coordinates(&a, &b, &c, &bary);
+    let normal = (b - a).cross(&(c - a));
+    let q = p + normal * 2.0;
+
+    assert_relative_eq!(
+        p.barycentric_coordinates(&a, &b, &c).unwrap(),
+        bary,
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        q.barycentric_coordinates(&a, &b, &c).unwrap(),
+        bary,
+        epsilon = 1.0e-12
+    );
+    assert!(p.is_in_triangle(&a, &b, &c));
+    assert!(q.is_in_triangle(&a, &b, &c));
+
+    // The vertices and the points outside of the triangle.
+    assert_eq!(
+        a.barycentric_coordinates(&a, &b, &c),
+        Some(Vector3::new(1.0, 0.0, 0.0))
+    );
+    let outside = Point3::from_barycentric_coordinates(&a, &b, &c, &Vector3::new(0.6, 0.6, -0.2));
+    assert!(!outside.is_in_triangle(&a, &b, &c));
+
+    // Degenerate triangles.
+    let mid = Point3::from_barycentric_coordinates(&a, &b, &c, &Vector3::new(0.5, 0.5, 0.0));
+    assert_eq!(p.barycentric_coordinates(&a, &b, &mid), None);
+    assert_eq!(p.barycentric_coordinates(&a, &a, &c), None);
+    assert!(!a.is_in_triangle(&a, &a, &a));
+
+    // Interpolation of per-vertex attributes.
+    let uvs = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    assert_relative_eq!(
+        na::barycentric_interpolation(&bary, uvs[0], uvs[1], uvs[2]),
+        Vector2::new(0.5, 0.3)
+    );
+    assert_relative_eq!(
+        na::barycentric_interpolation(&bary, a.coords, b.coords, c.coords),
+        p.coords
+    );
+}
@@ -1,4 +1,4 @@
-use na::{Orthographic3, Perspective3, Point3};
+use na::{Orthographic3, Perspective3, Point3, Vector2};
 
 #[test]
 fn perspective_inverse() {
@@ -10,6 +10,35 @@ fn perspective_inverse() {
     assert!(id.is_identity(1.0e-7));
 }
 
+#[test]
+fn perspective_from_intrinsics_inverse() {
+    let proj = Perspective3::from_intrinsics(500.0, 520.0, 300.0, 250.0, 640.0, 480.0, 0.1, 100.0);
+    let inv = proj.inverse();
+
+    let id = inv * proj.into_inner();
+
+    assert!(id.is_identity(1.0e-7));
+}
+
+#[test]
+fn perspective_intrinsics() {
+    // A centered principal point matches a symmetric frustum.
+    let proj = Perspective3::from_intrinsics(400.0, 400.0, 320.0, 240.0, 640.0, 480.0, 1.0, 10.0);
+    let fovx = 2.0 * (320.0f64 / 400.0).atan();
+    let expected = Perspective3::from_fov_x(640.0 / 480.0, fovx, 1.0, 10.0);
+
+    assert_relative_eq!(proj.into_inner(), expected.into_inner(), epsilon = 1.0e-10);
+    assert_relative_eq!(proj.fovx(), fovx, epsilon = 1.0e-10);
+    assert_relative_eq!(proj.znear(), 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(proj.zfar(), 10.0, epsilon = 1.0e-10);
+
+    let (fx, fy, cx, cy) = expected.intrinsics(640.0, 480.0);
+    assert_relative_eq!(fx, 400.0, epsilon = 1.0e-10);
+    assert_relative_eq!(fy, 400.0, epsilon = 1.0e-10);
+    assert_relative_eq!(cx, 320.0, epsilon = 1.0e-10);
+    assert_relative_eq!(cy, 240.0, epsilon = 1.0e-10);
+}
+
 #[test]
 fn orthographic_inverse() {
     let proj = Orthographic3::new(1.0, 2.0, -3.0, -2.5, 10.0, 900.0);
@@ -20,6 +49,57 @@ fn orthographic_inverse() {
     assert!(id.is_identity(1.0e-7));
 }
 
+#[test]
+fn projection_jitter() {
+    let offset = Vector2::new(0.25 / 800.0, -0.75 / 600.0);
+    let offset2 = Vector2::new(-0.5 / 800.0, 0.25 / 600.0);
+
+    // Off-center projections, whose jitter cannot be told apart from their offset.
+    let pers = Perspective3::from_intrinsics(500.0, 500.0, 300.0, 250.0, 800.0, 600.0, 1.0, 1000.0);
+    let pers_jittered = pers.jittered(&offset);
+    assert_eq!(pers.jitter(&pers), Vector2::zeros());
+    assert_relative_eq!(pers_jittered.jitter(&pers), offset, epsilon = 1.0e-15);
+    assert!((pers_jittered.inverse() * pers_jittered.into_inner()).is_identity(1.0e-7));
+
+    // The jitter is replaced, and the other parameters are kept.
+    let mut pers2 = pers_jittered;
+    pers2.set_znear_and_zfar(2.0, 500.0);
+    pers2.set_jitter(&pers, &offset2);
+    assert_relative_eq!(pers2.jitter(&pers), offset2, epsilon = 1.0e-15);
+    assert_eq!(pers2.znear(), 2.0);
+    pers2.clear_jitter(&pers);
+    pers2.set_znear_and_zfar(1.0, 1000.0);
+    assert_relative_eq!(pers2.as_matrix(), pers.as_matrix(), epsilon = 1.0e-12);
+
+    let ortho = Orthographic3::new(1.0, 2.0, -3.0, -2.5, 10.0, 900.0);
+    let ortho_jittered = ortho.jittered(&offset);
+    let inv = ortho_jittered.inverse();
+    assert!((inv * ortho_jittered.into_inner()).is_identity(1.0e-7));
+    assert_eq!(ortho.jitter(&ortho), Vector2::zeros());
+    assert_relative_eq!(ortho_jittered.jitter(&ortho), offset, epsilon = 1.0e-15);
+
+    let mut ortho2 = ortho_jittered;
+    ortho2.set_znear_and_zfar(20.0, 500.0);
+    ortho2.set_jitter(&ortho, &offset2);
+    assert_relative_eq!(ortho2.jitter(&ortho), offset2, epsilon = 1.0e-15);
+    assert_relative_eq!(ortho2.znear(), 20.0, epsilon = 1.0e-10);
+    ortho2.clear_jitter(&ortho);
+    assert_eq!(ortho2.left(), ortho.left());
+    assert_eq!(ortho2.bottom(), ortho.bottom());
+
+    let pt = Point3::new(1.5, -2.75, -20.0);
+    assert_relative_eq!(
+        pers_jittered.project_point(&pt),
+        pers.project_point(&pt) + offset.push(0.0),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        ortho_jittered.project_point(&pt),
+        ortho.project_point(&pt) + offset.push(0.0),
+        epsilon = 1.0e-10
+    );
+}
+
 #[test]
 fn perspective_matrix_point_transformation() {
     // https://github.com/dimforge/nalgebra/issues/640
@@ -35,7 +115,7 @@ fn perspective_matrix_point_transformation() {
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
-    use na::{Orthographic3, Perspective3};
+    use na::{Orthographic3, Perspective3, Vector2};
 
     use crate::proptest::*;
     use proptest::{prop_assert, proptest};
@@ -51,6 +131,38 @@ mod proptest_tests {
             prop_assert!(relative_eq!(pt, unprojected, epsilon = 1.0e-7))
         }
 
+        #[test]
+        fn perspective_from_intrinsics_project_unproject(pt in point3()) {
+            let proj =
+                Perspective3::from_intrinsics(500.0, 520.0, 300.0, 250.0, 640.0, 480.0, 0.1, 100.0);
+
+            let projected   = proj.project_point(&pt);
+            let unprojected = proj.unproject_point(&projected);
+
+            prop_assert!(relative_eq!(pt, unprojected, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(projected, proj.as_projective() * pt, epsilon = 1.0e-7))
+        }
+
+        #[test]
+        fn ray_for_pixel(px in 0.0..640.0, py in 0.0..480.0, t in 0.0..100.0) {
+            let viewport = Vector2::new(640.0, 480.0);
+            let pers =
+                Perspective3::from_intrinsics(500.0, 520.0, 300.0, 250.0, 640.0, 480.0, 0.1, 100.0);
+            let ortho = Orthographic3::new(1.0, 2.0, -3.0, -2.5, 10.0, 900.0);
+
+            let (origin, dir) = pers.ray_for_pixel(&viewport, px, py);
+            let ndc = pers.project_point(&(origin + dir.into_inner() * t));
+            prop_assert!(relative_eq!(ndc.x, 2.0 * px / 640.0 - 1.0, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(ndc.y, 1.0 - 2.0 * py / 480.0, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(pers.project_point(&origin).z, -1.0, epsilon = 1.0e-7));
+
+            let (origin, dir) = ortho.ray_for_pixel(&viewport, px, py);
+            let ndc = ortho.project_point(&(origin + dir.into_inner() * t));
+            prop_assert!(relative_eq!(ndc.x, 2.0 * px / 640.0 - 1.0, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(ndc.y, 1.0 - 2.0 * py / 480.0, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(ortho.project_point(&origin).z, -1.0, epsilon = 1.0e-7));
+        }
+
         #[test]
         fn orthographic_project_unproject(pt in point3()) {
             let proj = Orthographic3::new(1.0, 2.0, -3.0, -2.5, 10.0, 900.0);
@@ -1,7 +1,7 @@
 #![cfg(feature = "proptest-support")]
 #![allow(non_snake_case)]
 
-use na::{Unit, UnitQuaternion};
+use na::{Unit, UnitQuaternion, Vector3};
 
 use crate::proptest::*;
 use proptest::{prop_assert, proptest};
@@ -127,6 +127,71 @@ proptest!(
         prop_assert!(mq == q && mq.angle() == q.angle() && mq.axis() == q.axis())
     }
 
+    /*
+     *
+     * Swing-twist decomposition.
+     *
+     */
+    #[test]
+    fn unit_quaternion_swing_twist(q in unit_quaternion(), v in vector3()) {
+        if let Some(axis) = Unit::try_new(v, 1.0e-7) {
+            let (swing, twist) = q.swing_twist(&axis);
+
+            prop_assert!(relative_eq!(swing * twist, q, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(twist.imag().cross(&axis), Vector3::zeros(), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(swing.imag().dot(&axis), 0.0, epsilon = 1.0e-7));
+        }
+    }
+
+    /*
+     *
+     * Spline interpolation.
+     *
+     */
+    #[test]
+    fn unit_quaternion_squad_endpoints(
+        q0 in unit_quaternion(),
+        q1 in unit_quaternion(),
+        q2 in unit_quaternion(),
+        q3 in unit_quaternion()
+    ) {
+        let a = UnitQuaternion::squad_control_point(&q0, &q1, &q2);
+        let b = UnitQuaternion::squad_control_point(&q1, &q2, &q3);
+
+        prop_assert!(relative_eq!(q1.squad(&q2, &a, &b, 0.0), q1, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(q1.squad(&q2, &a, &b, 1.0), q2, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn unit_quaternion_cubic_bspline_continuity(
+        q0 in unit_quaternion(),
+        q1 in unit_quaternion(),
+        q2 in unit_quaternion(),
+        q3 in unit_quaternion(),
+        q4 in unit_quaternion()
+    ) {
+        let end = UnitQuaternion::cubic_bspline(&[q0, q1, q2, q3], 1.0);
+        let start = UnitQuaternion::cubic_bspline(&[q1, q2, q3, q4], 0.0);
+
+        prop_assert!(relative_eq!(end, start, epsilon = 1.0e-7));
+    }
+
+    /*
+     *
+     * Rotation averaging.
+     *
+     */
+    #[test]
+    fn unit_quaternion_weighted_mean_of(q in unit_quaternion(), v in vector3(), w in 0.1f64..10.0) {
+        let delta = UnitQuaternion::new(v / 200.0);
+        let antipodal = UnitQuaternion::new_unchecked(-(q * delta.inverse()).into_inner());
+        let quaternions = [q * delta, q, antipodal];
+        let mean = UnitQuaternion::weighted_mean_of(&quaternions, &[w, 1.0, w]);
+
+        prop_assert!(relative_eq!(mean, q, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(UnitQuaternion::mean_of(quaternions), q, epsilon = 1.0e-7));
+    }
+
     // Test that all operators (incl. all combinations of references) work.
     // See the top comment on `geometry/quaternion_ops.rs` for details on which operations are
     // supported.
@@ -263,4 +328,23 @@ proptest!(
             && uqMuv == uq * &uv
             && uqMuv == &uq * uv)
     }
+
+    /*
+     *
+     * Rotate towards.
+     *
+     */
+    #[test]
+    fn rotate_towards(q1 in unit_quaternion(), q2 in unit_quaternion(), max_angle in 0.0..4.0) {
+        let q = q1.rotate_towards(&q2, max_angle);
+        let angle = q1.angle_to(&q2);
+
+        if angle <= max_angle {
+            prop_assert!(q == q2);
+        } else {
+            // The result is on the shortest path from `q1` to `q2`.
+            prop_assert!(relative_eq!(q1.angle_to(&q), max_angle, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(q.angle_to(&q2), angle - max_angle, epsilon = 1.0e-7));
+        }
+    }
 );
@@ -0,0 +1,38 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{Affine2, Affine3, Reflection2, Reflection3, Unit};
+
+use crate::proptest::*;
+use proptest::{prop_assert, proptest};
+
+proptest!(
+    #[test]
+    fn reflection2_transformation(n in vector2(), o in point2(), p in point2(), i in isometry2()) {
+        if let Some(normal) = Unit::try_new(n, 1.0e-7) {
+            let refl = Reflection2::new_containing_point(normal, &o);
+            let affine = Affine2::from(refl);
+
+            prop_assert!(relative_eq!(refl * (refl * p), p, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(refl * o, o, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(affine * p, refl * p, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!((i * refl) * p, i * (refl * p), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!((refl * i) * p, refl * (i * p), epsilon = 1.0e-7));
+        }
+    }
+
+    #[test]
+    fn reflection3_transformation(n in vector3(), o in point3(), p in point3(), v in vector3(), i in isometry3()) {
+        if let Some(normal) = Unit::try_new(n, 1.0e-7) {
+            let refl = Reflection3::new_containing_point(normal, &o);
+            let affine = Affine3::from(refl);
+
+            prop_assert!(relative_eq!(refl * (refl * p), p, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(refl * o, o, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(refl * v, refl.to_linear_matrix() * v, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(affine * p, refl * p, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!((i * refl) * p, i * (refl * p), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!((affine * refl) * p, p, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(refl.to_linear_matrix().determinant(), -1.0, epsilon = 1.0e-7));
+        }
+    }
+);
@@ -1,4 +1,4 @@
-use na::{Quaternion, RealField, UnitQuaternion, Vector2, Vector3};
+use na::{Matrix3, Quaternion, RealField, Rotation3, UnitQuaternion, Vector2, Vector3};
 
 #[test]
 fn angle_2() {
@@ -30,9 +30,107 @@ fn quaternion_euler_angles_issue_494() {
     assert_eq!(angs.2, 0.0);
 }
 
+#[test]
+fn from_matrix_nearest_reflection() {
+    // A reflection has no nearest rotation with the unconstrained polar factor.
+    let reflection = Matrix3::from_diagonal(&Vector3::new(1.0, 1.0, -1.0));
+    let rot = Rotation3::from_matrix_nearest(&reflection);
+    assert_relative_eq!(rot.matrix().determinant(), 1.0, epsilon = 1.0e-10);
+    assert!(rot.matrix().is_orthogonal(1.0e-10));
+
+    // Scaling and rotating yields the rotation back.
+    let expected = Rotation3::from_euler_angles(0.1, -0.4, 1.2);
+    let m = expected.matrix() * Matrix3::from_diagonal(&Vector3::new(2.0, 0.5, 3.0));
+    let rot = Rotation3::from_matrix_nearest(&(m * expected.matrix().transpose()));
+    assert_relative_eq!(rot, Rotation3::identity(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn renormalize_drifted_rotation3() {
+    let expected = Rotation3::from_euler_angles(0.3, 0.2, -1.5);
+    let noise = Matrix3::new(1.0, -2.0, 0.5, 0.3, 1.5, -1.0, 2.0, 0.1, -0.7);
+
+    for scale in [1.0e-8, 1.0e-4, 1.0e-1, 2.0] {
+        let mut rot = Rotation3::from_matrix_unchecked(expected.matrix() + noise * scale);
+        rot.renormalize();
+        assert!(rot.matrix().is_special_orthogonal(1.0e-12));
+        assert_relative_eq!(
+            rot,
+            Rotation3::from_matrix_nearest(&(expected.matrix() + noise * scale)),
+            epsilon = 1.0e-9
+        );
+    }
+}
+
+#[test]
+fn quaternion_rotation_kinematics() {
+    let q = UnitQuaternion::from_euler_angles(0.3, -1.2, 2.0);
+    let rot = q.to_rotation_matrix();
+    let omega = Vector3::new(1.5, -0.5, 3.0);
+    let dt = 0.25;
+
+    // Integration in the local and global frames.
+    let q1 = q.integrate(&omega, dt);
+    assert_relative_eq!(
+        q1.to_rotation_matrix(),
+        rot.integrate(&omega, dt),
+        epsilon = 1.0e-12
+    );
+    assert_relative_eq!(
+        q1,
+        UnitQuaternion::new(q * omega * dt) * q,
+        epsilon = 1.0e-12
+    );
+
+    // Integrating many small steps is the same as one big step for a constant velocity.
+    let mut q_steps = q;
+    for _ in 0..100 {
+        q_steps = q_steps.integrate(&omega, dt / 100.0);
+    }
+    assert_relative_eq!(q_steps, q1, epsilon = 1.0e-12);
+
+    // Derivatives, compared to central finite differences.
+    let h = 1.0e-6;
+    let dq =
+        (q.integrate(&omega, h).into_inner() - q.integrate(&omega, -h).into_inner()) / (2.0 * h);
+    let drot = (rot.integrate(&omega, h).into_inner() - rot.integrate(&omega, -h).into_inner())
+        / (2.0 * h);
+    assert_relative_eq!(q.derivative(&omega), dq, epsilon = 1.0e-8);
+    assert_relative_eq!(rot.derivative(&omega), drot, epsilon = 1.0e-8);
+    assert_relative_eq!(
+        q.derivative(&omega),
+        Quaternion::from_imag(q * omega) * q.into_inner() * 0.5,
+        epsilon = 1.0e-12
+    );
+}
+
+#[test]
+fn left_jacobian_small_angles() {
+    // The left Jacobian is the series `Σₙ hat(φ)ⁿ / (n + 1)!`, which converges quickly for these
+    // angles, including at the switch between the series and the closed forms of `θ = 1`.
+    for &theta in &[1.0e-5, 1.0e-4, 1.0e-3, 1.0e-2, 0.1, 0.5, 0.999, 1.001, 2.0] {
+        let phi = Vector3::new(1.0, -2.0, 2.0) * (theta / 3.0);
+        let hat = Rotation3::hat(&phi);
+        let mut term = Matrix3::identity();
+        let mut expected = Matrix3::identity();
+        for n in 1..30 {
+            term = term * hat / (n + 1) as f64;
+            expected += term;
+        }
+
+        let jl = Rotation3::left_jacobian(&phi);
+        assert_relative_eq!(jl, expected, epsilon = 1.0e-15);
+        assert_relative_eq!(
+            jl * Rotation3::left_jacobian_inverse(&phi),
+            Matrix3::identity(),
+            epsilon = 1.0e-15
+        );
+    }
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
-    use na::{self, Rotation2, Rotation3, Unit};
+    use na::{self, Matrix3, Rotation2, Rotation3, Unit};
     use simba::scalar::RealField;
     use std::f64;
 
@@ -229,5 +327,38 @@ mod proptest_tests {
                 prop_assert_eq!(r, Rotation3::identity())
             }
         }
+
+        /*
+         *
+         * Jacobians of the exponential map.
+         *
+         */
+        #[test]
+        fn jacobians_rotation_3(axisangle in vector3(), d in vector3()) {
+            let phi = axisangle / 50.0;
+            let delta = d * 1.0e-8;
+            let exp = Rotation3::new(phi + delta);
+            let jl = Rotation3::left_jacobian(&phi);
+            let jr = Rotation3::right_jacobian(&phi);
+
+            prop_assert!(relative_eq!(exp, Rotation3::new(jl * delta) * Rotation3::new(phi), epsilon = 1.0e-10));
+            prop_assert!(relative_eq!(exp, Rotation3::new(phi) * Rotation3::new(jr * delta), epsilon = 1.0e-10));
+            prop_assert!(relative_eq!(jl * Rotation3::left_jacobian_inverse(&phi), Matrix3::identity(), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(jr * Rotation3::right_jacobian_inverse(&phi), Matrix3::identity(), epsilon = 1.0e-7));
+        }
+
+        /*
+         *
+         * Rotation averaging.
+         *
+         */
+        #[test]
+        fn chordal_mean_of_symmetric_rotations(r in rotation3(), axisangle in vector3(), w in 0.1f64..10.0) {
+            let delta = Rotation3::new(axisangle / 200.0);
+            let rotations = [r * delta, r, r * delta.inverse()];
+            let mean = Rotation3::chordal_mean_of(&rotations, &[w, 1.0, w]);
+
+            prop_assert!(relative_eq!(mean, r, epsilon = 1.0e-7));
+        }
     }
 }
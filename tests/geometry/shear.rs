@@ -0,0 +1,87 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{Affine2, Point2, Shear2, Shear3, Transform2, Transform3, Vector3};
+
+use crate::proptest::*;
+use proptest::{prop_assert, proptest};
+
+proptest!(
+    #[test]
+    fn shear2_transformation(xy in PROPTEST_F64, yx in PROPTEST_F64, p in point2(), i in isometry2()) {
+        let shear = Shear2::new(xy / 100.0, yx / 100.0);
+        let transform = Transform2::from(shear);
+
+        prop_assert!(relative_eq!(shear * p, transform * p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(
+            (Affine2::from_matrix_unchecked(i.to_homogeneous()) * shear) * p,
+            i * (shear * p),
+            epsilon = 1.0e-7
+        ));
+
+        if let Some(inv) = shear.try_inverse() {
+            prop_assert!(relative_eq!(inv * (shear * p), p, epsilon = 1.0e-7));
+            let affine = shear.try_to_affine().unwrap();
+            prop_assert!(relative_eq!(affine * p, shear * p, epsilon = 1.0e-7));
+        }
+    }
+
+    #[test]
+    fn shear3_transformation(v in vector6(), p in point3(), i in isometry3()) {
+        let f = v / 100.0;
+        let shear = Shear3::new(f[0], f[1], f[2], f[3], f[4], f[5]);
+        let transform = Transform3::from(shear);
+        let iso = Transform3::from_matrix_unchecked(i.to_homogeneous());
+
+        prop_assert!(relative_eq!(shear * p, transform * p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(shear * p.coords, shear.transform_vector(&p.coords), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((shear * iso) * p, shear * (i * p), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((iso * shear) * p, i * (shear * p), epsilon = 1.0e-7));
+
+        if let Some(inv) = shear.try_inverse() {
+            prop_assert!(relative_eq!(inv * (shear * p), p, epsilon = 1.0e-7));
+            let affine = shear.try_to_affine().unwrap();
+            prop_assert!(relative_eq!(affine * p, shear * p, epsilon = 1.0e-7));
+        }
+    }
+);
+
+#[test]
+fn shear_factors() {
+    let mut shear = Shear3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(shear.factor(1, 2), 4.0);
+
+    shear.set_factor(1, 2, 7.0);
+    assert_eq!(
+        shear * Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(2.0, 7.0, 1.0)
+    );
+    assert_eq!(Shear2::new(2.0, 3.0) * Point2::origin(), Point2::origin());
+}
+
+#[test]
+fn singular_shear_products() {
+    // `xy * yx == 1`: the shear collapses the plane onto a line.
+    let shear = Shear2::new(2.0, 0.5);
+    assert!(shear.try_inverse().is_none());
+    assert!(shear.try_to_affine().is_none());
+    assert!(Transform2::from(shear).try_inverse().is_none());
+    assert!(Shear3::new(1.0, 0.0, 1.0, 0.0, 0.0, 0.0)
+        .try_to_affine()
+        .is_none());
+
+    let affine = Shear2::new(2.0, 0.25).try_to_affine().unwrap();
+    assert_relative_eq!(
+        affine.inverse() * affine,
+        Affine2::identity(),
+        epsilon = 1.0e-12
+    );
+
+    let left: Transform2<f64> = Affine2::identity() * shear;
+    let right: Transform2<f64> = shear * Affine2::identity();
+    assert!(left.try_inverse().is_none());
+    assert!(right.try_inverse().is_none());
+
+    let mut t = Transform2::identity();
+    t *= shear;
+    assert_eq!(t, left);
+}
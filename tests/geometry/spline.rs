@@ -0,0 +1,106 @@
+use na::{HermiteSpline, Isometry3, IsometrySpline3, Point3, UnitQuaternion, Vector3};
+
+#[test]
+fn catmull_rom_interpolates_points() {
+    let points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(3.0, 1.0, -1.0),
+        Point3::new(4.0, 4.0, 2.0),
+    ];
+    let spline = HermiteSpline::catmull_rom(points.clone());
+
+    for (i, p) in points.iter().enumerate() {
+        assert_relative_eq!(spline.eval(i as f64), *p, epsilon = 1.0e-12);
+    }
+
+    // Out-of-range parameters are clamped.
+    assert_eq!(spline.eval(-1.0), points[0]);
+    assert_eq!(spline.eval(10.0), points[3]);
+
+    // The tangents of a Catmull-Rom spline.
+    assert_relative_eq!(spline.derivative(1.0), Vector3::new(1.5, 0.5, -0.5));
+    assert_relative_eq!(spline.derivative(0.0), points[1] - points[0]);
+
+    // Collinear, evenly spaced points give a straight line at constant speed.
+    let line = HermiteSpline::catmull_rom(vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+    ]);
+    assert_relative_eq!(line.eval(0.25), Point3::new(0.25, 0.0, 0.0));
+    assert_relative_eq!(line.eval(1.6), Point3::new(1.6, 0.0, 0.0));
+
+    let single = HermiteSpline::catmull_rom(vec![Point3::new(1.0, 2.0, 3.0)]);
+    assert_eq!(single.eval(0.5), Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(single.max_parameter(), 0.0);
+}
+
+#[test]
+fn hermite_spline_derivative() {
+    let spline = HermiteSpline::new(
+        vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+        vec![Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 3.0, 1.0)],
+    );
+
+    assert_relative_eq!(spline.derivative(0.0), Vector3::new(2.0, 0.0, 0.0));
+    assert_relative_eq!(spline.derivative(1.0), Vector3::new(0.0, 3.0, 1.0));
+
+    let h = 1.0e-6;
+    for &t in &[0.2, 0.5, 0.7] {
+        let fd = (spline.eval(t + h) - spline.eval(t - h)) / (2.0 * h);
+        assert_relative_eq!(spline.derivative(t), fd, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn arc_length_parameterization() {
+    // Unevenly spaced collinear points.
+    let spline = HermiteSpline::catmull_rom(vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(4.0, 0.0, 0.0),
+    ]);
+    let arc_length = spline.arc_length_parameterization(256);
+
+    assert_relative_eq!(arc_length.length(), 4.0, epsilon = 1.0e-9);
+    assert_eq!(arc_length.parameter_at(-1.0), 0.0);
+    assert_eq!(arc_length.parameter_at(5.0), 2.0);
+
+    for &s in &[0.5, 1.0, 2.5, 3.9] {
+        let p = spline.eval(arc_length.parameter_at(s));
+        assert_relative_eq!(p, Point3::new(s, 0.0, 0.0), epsilon = 1.0e-3);
+    }
+}
+
+#[test]
+fn isometry_spline_interpolates_keyframes() {
+    let keyframes = [
+        Isometry3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.0)),
+        Isometry3::new(Vector3::new(1.0, 0.0, 1.0), Vector3::new(0.0, 1.0, 0.0)),
+        Isometry3::new(Vector3::new(2.0, 1.0, 1.0), Vector3::new(0.5, 2.0, 0.0)),
+        Isometry3::new(Vector3::new(2.0, 3.0, 0.0), Vector3::new(1.0, 2.5, 0.5)),
+    ];
+    let spline = IsometrySpline3::catmull_rom(&keyframes);
+
+    for (i, k) in keyframes.iter().enumerate() {
+        assert_relative_eq!(spline.eval(i as f64), *k, epsilon = 1.0e-10);
+    }
+
+    // The rotations are interpolated continuously across keyframes.
+    for i in 1..3 {
+        let t = i as f64;
+        let before = spline.eval(t - 1.0e-6).rotation;
+        let after = spline.eval(t + 1.0e-6).rotation;
+        assert!(before.angle_to(&after) < 1.0e-4);
+    }
+
+    // Rotations about a single axis at a constant rate are interpolated at a constant rate.
+    let axis = Vector3::y_axis();
+    let keyframes: Vec<_> = (0..4)
+        .map(|i| Isometry3::from_parts(na::one(), UnitQuaternion::from_axis_angle(&axis, i as f64)))
+        .collect();
+    let spline = IsometrySpline3::catmull_rom(&keyframes);
+    let expected = UnitQuaternion::from_axis_angle(&axis, 1.3);
+    assert_relative_eq!(spline.eval(1.3).rotation, expected, epsilon = 1.0e-10);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{Matrix4, Transform3, TransformKind, Vector3};
+
+use crate::proptest::*;
+use proptest::{prop_assert, prop_assert_eq, proptest};
+
+proptest!(
+    #[test]
+    fn transform_kind_and_inverse(i in isometry3(), s in vector3(), p in point3()) {
+        let iso = Transform3::from_matrix_unchecked(i.to_homogeneous());
+        prop_assert_eq!(iso.kind(1.0e-7), TransformKind::Isometry);
+        prop_assert!(relative_eq!(
+            iso.try_inverse_eps(1.0e-7).unwrap().into_inner(),
+            i.inverse().to_homogeneous(),
+            epsilon = 1.0e-7
+        ));
+
+        let scaling = s.map(|e| 1.0 + e.abs());
+        let m = i.to_homogeneous() * Matrix4::new_nonuniform_scaling(&scaling);
+        let affine = Transform3::from_matrix_unchecked(m);
+        let inv = affine.try_inverse_eps(1.0e-10).unwrap();
+
+        prop_assert_eq!(affine.kind(1.0e-10), TransformKind::Affine);
+        prop_assert!(relative_eq!(inv * (affine * p), p, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(affine.try_inverse().unwrap(), inv, epsilon = 1.0e-7));
+
+        let projective = Transform3::from_matrix_unchecked(m * 2.0);
+        let normalized = projective.try_into_affine(1.0e-7).unwrap();
+        prop_assert!(relative_eq!(normalized.into_inner(), m, epsilon = 1.0e-7));
+    }
+);
+
+#[test]
+fn transform_singular() {
+    let m = Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 1.0e-12, 1.0));
+    let t = Transform3::from_matrix_unchecked(m);
+
+    assert_eq!(t.kind(1.0e-7), TransformKind::Singular);
+    assert!(t.try_inverse().is_some());
+    assert!(t.try_inverse_eps(1.0e-7).is_none());
+    assert!(t.try_into_projective(1.0e-7).is_none());
+    assert!(t.try_into_affine(1.0e-7).is_none());
+}
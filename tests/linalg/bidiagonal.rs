@@ -49,6 +49,34 @@ macro_rules! gen_tests(
 
                     prop_assert!(relative_eq!(m, &u * d * &v_t, epsilon = 1.0e-7))
                 }
+
+                #[test]
+                fn bidiagonal_solve_least_squares(m in dmatrix_($scalar)) {
+                    let singular_values = m.singular_values();
+
+                    if m.nrows() >= m.ncols() && singular_values.min() > 1.0e-3 * singular_values.max() {
+                        let b = m.column(0).map(|e| e * e);
+                        let x = m.clone().bidiagonalize().solve_least_squares(&b).unwrap();
+
+                        let normal_residual = m.ad_mul(&(&m * x - &b));
+
+                        prop_assert!(normal_residual.norm() <= 1.0e-10 * m.norm() * b.norm())
+                    }
+                }
+
+                #[test]
+                fn bidiagonal_solve_least_squares_static_5_3(m in matrix5x3_($scalar)) {
+                    let singular_values = m.singular_values();
+
+                    if singular_values.min() > 1.0e-3 * singular_values.max() {
+                        let b = m.map(|e| e * e);
+                        let x = m.bidiagonalize().solve_least_squares(&b).unwrap();
+
+                        let normal_residual = m.ad_mul(&(m * x - b));
+
+                        prop_assert!(normal_residual.norm() <= 1.0e-10 * m.norm() * b.norm())
+                    }
+                }
             }
         }
     }
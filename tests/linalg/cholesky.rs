@@ -46,6 +46,17 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(&m * &sol2, b2, epsilon = 1.0e-7));
                 }
 
+                #[test]
+                fn cholesky_tr_solve(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+
+                    let chol = m.clone().cholesky().unwrap();
+                    let b = DMatrix::<$scalar>::new_random(n, nb).map(|e| e.0);
+                    let sol = chol.tr_solve(&b);
+
+                    prop_assert!(relative_eq!(m.transpose() * &sol, b, epsilon = 1.0e-7));
+                }
+
                 #[test]
                 fn cholesky_solve_static(_n in PROPTEST_MATRIX_DIM) {
                     let m = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
@@ -100,6 +111,25 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(lu_det.real(), chol_det, epsilon = 1.0e-7));
                 }
 
+                #[test]
+                fn cholesky_ln_determinant(n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let chol = m.cholesky().unwrap();
+
+                    prop_assert!(relative_eq!(chol.ln_determinant(), chol.determinant().ln(), epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn cholesky_inverse_to(n in PROPTEST_MATRIX_DIM) {
+                    let m = RandomSDP::new(Dynamic::new(n), || random::<$scalar>().0).unwrap();
+                    let chol = m.clone().cholesky().unwrap();
+                    let mut m1 = DMatrix::<$scalar>::new_random(n, n).map(|e| e.0);
+                    chol.inverse_to(&mut m1);
+
+                    prop_assert!(relative_eq!(m1, chol.inverse(), epsilon = 1.0e-7));
+                    prop_assert!((&m * &m1).is_identity(1.0e-7));
+                }
+
                 #[test]
                 fn cholesky_rank_one_update(_n in PROPTEST_MATRIX_DIM) {
                     let mut m = RandomSDP::new(Const::<4>, || random::<$scalar>().0).unwrap();
@@ -161,3 +191,35 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, RandComplex<f64>);
 gen_tests!(f64, RandScalar<f64>);
+
+#[test]
+fn cholesky_blocked() {
+    use na::{Complex, DMatrix, DVector};
+
+    for &n in &[64, 65, 150, 200] {
+        let a = DMatrix::from_fn(n, n, |i, j| {
+            Complex::new(
+                ((i * 13 + j * 7) % 17) as f64 - 8.0,
+                ((i + 3 * j) % 5) as f64,
+            )
+        });
+        let m = a.ad_mul(&a) + DMatrix::identity(n, n) * Complex::from(n as f64);
+        let chol = m.clone().cholesky().unwrap();
+        let l = chol.l();
+
+        assert_relative_eq!(
+            &l * l.adjoint(),
+            m,
+            epsilon = 1.0e-7,
+            max_relative = 1.0e-10
+        );
+
+        let b = DVector::from_fn(n, |i, _| Complex::new(i as f64, 1.0));
+        assert_relative_eq!(&m * chol.solve(&b), b, epsilon = 1.0e-7);
+    }
+
+    // Not definite-positive, with the failure in a trailing block.
+    let mut m = DMatrix::<f64>::identity(150, 150);
+    m[(120, 120)] = -1.0;
+    assert!(m.cholesky().is_none());
+}
@@ -101,6 +101,25 @@ mod proptest_tests {
                         }
                     }
 
+                    #[test]
+                    fn col_piv_qr_solve_least_squares(n in PROPTEST_MATRIX_DIM, k in PROPTEST_MATRIX_DIM, r in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::min(n, 20); // To avoid slowing down the test too much.
+                        let k = cmp::min(k, 20); // To avoid slowing down the test too much.
+                        let r = cmp::min(r, 20); // To avoid slowing down the test too much.
+                        let a = DMatrix::<$scalar_type>::new_random(n, r).map(|e| e.0);
+                        let c = DMatrix::<$scalar_type>::new_random(r, k).map(|e| e.0);
+                        let m = a * c;
+                        let b = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+
+                        let col_piv_qr = m.clone().col_piv_qr();
+                        let (x, residual) = col_piv_qr.solve_least_squares(&b, 1.0e-7);
+                        let error = &m * x - &b;
+
+                        prop_assert!(col_piv_qr.rank(1.0e-7) <= r);
+                        prop_assert!(relative_eq!(error.norm(), residual, epsilon = 1.0e-6));
+                        prop_assert!(relative_eq!(m.adjoint() * error, DVector::zeros(k), epsilon = 1.0e-6));
+                    }
+
                     #[test]
                     fn col_piv_qr_solve_static(m in matrix4_($scalar)) {
                          let col_piv_qr = m.col_piv_qr();
@@ -1,4 +1,6 @@
-use na::{DVector, Vector2, Vector3, Vector4, Vector5};
+use na::{
+    ConvolutionBoundary, ConvolutionMode, DMatrix, DVector, Vector2, Vector3, Vector4, Vector5,
+};
 use std::panic;
 
 //
@@ -117,3 +119,178 @@ fn convolve_valid_check() {
     })
     .is_err());
 }
+
+// >>> convolve([1,2,3,4,5],[1,1,1,1],"same")
+// array([ 3,  6, 10, 14, 12])
+// >>> correlate([1,2,3,4,5],[1,0,-1],"full")
+// array([-1, -2, -2, -2, -2,  4,  5])
+#[test]
+fn convolve_fft_check() {
+    let signal = DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    let zero = ConvolutionBoundary::Zero;
+
+    let same = signal.convolve_fft(Vector4::repeat(1.0), ConvolutionMode::Same, zero);
+    assert!(relative_eq!(
+        same,
+        DVector::from_vec(vec![3.0, 6.0, 10.0, 14.0, 12.0]),
+        epsilon = 1.0e-10
+    ));
+
+    let corr = signal.correlate_fft(Vector3::new(1.0, 0.0, -1.0), ConvolutionMode::Full, zero);
+    assert!(relative_eq!(
+        corr,
+        DVector::from_vec(vec![-1.0, -2.0, -2.0, -2.0, -2.0, 4.0, 5.0]),
+        epsilon = 1.0e-10
+    ));
+
+    // The `Same` mode is centered, unlike `convolve_same` for kernels of length 4 or more.
+    let kernel = Vector4::new(1.0, 0.0, 0.0, 0.0);
+    let same = signal.convolve_fft(kernel, ConvolutionMode::Same, zero);
+    assert!(relative_eq!(
+        same,
+        DVector::from_vec(vec![2.0, 3.0, 4.0, 5.0, 0.0]),
+        epsilon = 1.0e-10
+    ));
+    assert_eq!(
+        signal.convolve_same(kernel),
+        DVector::from_vec(vec![3.0, 4.0, 5.0, 0.0, 0.0])
+    );
+
+    // Same as the direct convolutions.
+    let kernel = DVector::from_vec(vec![0.5, -1.0, 2.0]);
+    assert!(relative_eq!(
+        signal.convolve_fft(kernel.clone(), ConvolutionMode::Same, zero),
+        signal.convolve_same(kernel.clone()),
+        epsilon = 1.0e-10
+    ));
+    let full = signal.convolve_fft(kernel.clone(), ConvolutionMode::Full, zero);
+    let valid = signal.convolve_fft(kernel.clone(), ConvolutionMode::Valid, zero);
+    assert!(relative_eq!(
+        full,
+        signal.convolve_full(kernel.clone()),
+        epsilon = 1.0e-10
+    ));
+    assert!(relative_eq!(
+        valid,
+        signal.convolve_valid(kernel),
+        epsilon = 1.0e-10
+    ));
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = DVector::from_vec(vec![1.0, 2.0]).convolve_fft(
+            DVector::from_vec(vec![1.0, 2.0, 3.0]),
+            ConvolutionMode::Valid,
+            ConvolutionBoundary::Zero,
+        );
+    })
+    .is_err());
+
+    assert!(panic::catch_unwind(|| {
+        let _ = DVector::from_vec(vec![1.0, 2.0]).convolve_fft(
+            DVector::<f64>::from_vec(vec![]),
+            ConvolutionMode::Full,
+            ConvolutionBoundary::Zero,
+        );
+    })
+    .is_err());
+}
+
+/// The direct 2D convolution of `signal` extended with `boundary`.
+fn convolve_2d_naive(
+    signal: &DMatrix<f64>,
+    kernel: &DMatrix<f64>,
+    mode: ConvolutionMode,
+    boundary: ConvolutionBoundary,
+) -> DMatrix<f64> {
+    let extend = |i: isize, len: usize| -> Option<usize> {
+        let len = len as isize;
+        match boundary {
+            ConvolutionBoundary::Zero => Some(i).filter(|i| *i >= 0 && *i < len),
+            ConvolutionBoundary::Periodic => Some(i.rem_euclid(len)),
+            ConvolutionBoundary::Symmetric => {
+                let i = i.rem_euclid(2 * len);
+                Some(if i < len { i } else { 2 * len - 1 - i })
+            }
+        }
+        .map(|i| i as usize)
+    };
+    let range = |len: usize, klen: usize| match mode {
+        ConvolutionMode::Full => (0, len + klen - 1),
+        ConvolutionMode::Same => ((klen - 1) / 2, len),
+        ConvolutionMode::Valid => (klen - 1, len + 1 - klen),
+    };
+    let (start_i, len_i) = range(signal.nrows(), kernel.nrows());
+    let (start_j, len_j) = range(signal.ncols(), kernel.ncols());
+
+    DMatrix::from_fn(len_i, len_j, |i, j| {
+        let mut res = 0.0;
+
+        for ki in 0..kernel.nrows() {
+            for kj in 0..kernel.ncols() {
+                let si = extend((start_i + i) as isize - ki as isize, signal.nrows());
+                let sj = extend((start_j + j) as isize - kj as isize, signal.ncols());
+
+                if let (Some(si), Some(sj)) = (si, sj) {
+                    res += signal[(si, sj)] * kernel[(ki, kj)];
+                }
+            }
+        }
+
+        res
+    })
+}
+
+#[test]
+fn convolve_2d_fft_check() {
+    let modes = [
+        ConvolutionMode::Full,
+        ConvolutionMode::Same,
+        ConvolutionMode::Valid,
+    ];
+    let boundaries = [
+        ConvolutionBoundary::Zero,
+        ConvolutionBoundary::Periodic,
+        ConvolutionBoundary::Symmetric,
+    ];
+
+    for &(nrows, ncols, knrows, kncols) in &[(1, 1, 1, 1), (7, 5, 3, 2), (6, 9, 6, 4), (3, 8, 1, 5)]
+    {
+        let signal = DMatrix::from_fn(nrows, ncols, |i, j| ((i * 7 + j * 3) % 5) as f64 - 1.5);
+        let kernel = DMatrix::from_fn(knrows, kncols, |i, j| (i + 2 * j) as f64 * 0.5 - 1.0);
+        let flipped = DMatrix::from_fn(knrows, kncols, |i, j| {
+            kernel[(knrows - 1 - i, kncols - 1 - j)]
+        });
+
+        for &mode in &modes {
+            for &boundary in &boundaries {
+                let expected = convolve_2d_naive(&signal, &kernel, mode, boundary);
+                let conv = signal.convolve_2d_fft(kernel.clone(), mode, boundary);
+                assert!(relative_eq!(conv, expected, epsilon = 1.0e-10));
+
+                let expected = convolve_2d_naive(&signal, &flipped, mode, boundary);
+                let corr = signal.correlate_2d_fft(kernel.clone(), mode, boundary);
+                assert!(relative_eq!(corr, expected, epsilon = 1.0e-10));
+            }
+        }
+
+        // Vectors are convolved like single-column matrices.
+        let column = signal.column(0);
+        let kernel_column = kernel.column(0);
+
+        for &mode in &modes {
+            for &boundary in &boundaries {
+                let conv = column.convolve_fft(kernel_column, mode, boundary);
+                let expected =
+                    signal
+                        .columns(0, 1)
+                        .convolve_2d_fft(kernel.columns(0, 1), mode, boundary);
+                assert!(relative_eq!(
+                    conv.as_slice(),
+                    expected.as_slice(),
+                    epsilon = 1.0e-10
+                ));
+            }
+        }
+    }
+}
@@ -24,6 +24,19 @@ mod proptest_tests {
                         prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5))
                     }
 
+                    #[test]
+                    fn symmetric_tridiagonal_eigen(n in PROPTEST_MATRIX_DIM) {
+                        let n      = cmp::max(1, cmp::min(n, 10));
+                        let m      = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let tri    = m.clone().symmetric_tridiagonalize();
+                        let vals   = tri.eigenvalues();
+                        let eig    = tri.eigen();
+                        let recomp = eig.recompose();
+
+                        prop_assert!(vals == eig.eigenvalues);
+                        prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5))
+                    }
+
                     #[test]
                     fn symmetric_eigen_singular(n in PROPTEST_MATRIX_DIM) {
                         let n      = cmp::max(1, cmp::min(n, 10));
@@ -201,3 +214,338 @@ fn symmetric_eigen_singular_24x24() {
 //
 //     relative_eq!(eig.eigenvectors, mv, epsilon = 1.0e-5)
 // }
+
+#[test]
+fn dominant_eigen() {
+    use na::{Complex, DVector, DominantEigen, FnOperator, ProductOperator};
+
+    let q = DMatrix::from_fn(12, 12, |i, j| ((i * 5 + j * 7) % 11) as f64 - 5.0)
+        .qr()
+        .q();
+    let spectrum = DVector::from_fn(12, |i, _| if i % 2 == 0 { i as f64 } else { -(i as f64) });
+    let m = &q * DMatrix::from_diagonal(&spectrum) * q.transpose();
+
+    // Eigenvalues sorted by decreasing modulus, including negative ones.
+    let eigen = m.try_dominant_eigen(4, 1.0e-10, 10_000).unwrap();
+    assert_relative_eq!(
+        eigen.eigenvalues,
+        DVector::from_vec(vec![-11.0, 10.0, -9.0, 8.0]),
+        epsilon = 1.0e-8
+    );
+    assert!(eigen.eigenvectors.is_orthogonal(1.0e-10));
+    for j in 0..4 {
+        let v = eigen.eigenvectors.column(j);
+        assert_relative_eq!(&m * v, v * eigen.eigenvalues[j], epsilon = 1.0e-8);
+    }
+
+    // Applying the operator implicitly gives the same result.
+    let implicit = DominantEigen::try_new_with_operator(
+        &FnOperator::new_hermitian(12, |x: &DMatrix<f64>| {
+            &q * DMatrix::from_diagonal(&spectrum) * q.tr_mul(x)
+        }),
+        4,
+        1.0e-10,
+        10_000,
+    )
+    .unwrap();
+    assert_relative_eq!(implicit.recompose(), eigen.recompose(), epsilon = 1.0e-8);
+
+    // Same with a composition of operators.
+    let diagonal = DMatrix::from_diagonal(&spectrum);
+    let q_t = q.transpose();
+    let composed = ProductOperator::new(&q, ProductOperator::new(&diagonal, &q_t));
+    let composed = DominantEigen::try_new_with_operator(&composed, 4, 1.0e-10, 10_000).unwrap();
+    assert_relative_eq!(composed.recompose(), eigen.recompose(), epsilon = 1.0e-8);
+
+    // All the eigenvectors.
+    let full = m.try_dominant_eigen(12, 1.0e-10, 10_000).unwrap();
+    assert_relative_eq!(full.recompose(), m, epsilon = 1.0e-8);
+
+    // The eigenvalues 11 and -11 have the same modulus, so they can't be separated.
+    let mut ambiguous = spectrum.clone();
+    ambiguous[10] = 11.0;
+    let ambiguous = &q * DMatrix::from_diagonal(&ambiguous) * q.transpose();
+    assert!(ambiguous.try_dominant_eigen(1, 1.0e-10, 100).is_none());
+
+    let c = DMatrix::from_fn(5, 5, |i, j| {
+        Complex::new((i + j) as f64, i as f64 - j as f64)
+    });
+    let eigen = c.try_dominant_eigen(2, 1.0e-10, 10_000).unwrap();
+    let expected = c.clone().symmetric_eigen();
+    let largest = expected
+        .eigenvalues
+        .iter()
+        .fold(0.0f64, |a, b| a.max(b.abs()));
+    assert_relative_eq!(eigen.eigenvalues[0].abs(), largest, epsilon = 1.0e-8);
+}
+
+#[test]
+fn symmetric_eigen_jacobi() {
+    use na::{Complex, SymmetricEigen};
+
+    let m = DMatrix::from_fn(9, 9, |i, j| ((i * 3 + j * 5) % 7) as f64 - 3.0).symmetric_part();
+    let jacobi = SymmetricEigen::new_jacobi(m.clone());
+    assert!(jacobi.eigenvectors.is_orthogonal(1.0e-12));
+    assert_relative_eq!(jacobi.recompose(), m, epsilon = 1.0e-12);
+
+    let mut expected: Vec<f64> = m.symmetric_eigenvalues().iter().copied().collect();
+    let mut computed: Vec<f64> = jacobi.eigenvalues.iter().copied().collect();
+    expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    computed.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for (a, b) in computed.iter().zip(expected.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1.0e-10);
+    }
+
+    // Only the lower-triangular part is read.
+    let mut lower = m.clone();
+    lower.fill_upper_triangle(f64::NAN, 1);
+    assert_relative_eq!(
+        SymmetricEigen::new_jacobi(lower).recompose(),
+        m,
+        epsilon = 1.0e-12
+    );
+
+    let c = DMatrix::from_fn(6, 6, |i, j| {
+        Complex::new((i * j % 4) as f64, i as f64 - j as f64)
+    })
+    .hermitian_part();
+    let jacobi = SymmetricEigen::new_jacobi(c.clone());
+    assert!(jacobi.eigenvectors.is_orthogonal(1.0e-12));
+    assert_relative_eq!(jacobi.recompose(), c, epsilon = 1.0e-12);
+
+    assert!(SymmetricEigen::try_new_jacobi(m, 1.0e-15, 1).is_none());
+}
+
+#[test]
+fn symmetric_eigen_jacobi_graded() {
+    use na::{DVector, SymmetricEigen};
+
+    // `D * A * D` with a well-conditioned `A` and a strongly graded `D`.
+    let a = DMatrix::from_fn(6, 6, |i, j| {
+        if i == j {
+            2.0
+        } else {
+            0.3 / (1.0 + (i + j) as f64)
+        }
+    });
+    let d = DMatrix::from_diagonal(&DVector::from_fn(6, |i, _| 10.0f64.powi(4 * i as i32)));
+    let m = &d * &a * &d;
+
+    // The determinant is exactly known, and equals the product of the eigenvalues, so the
+    // eigenvalues must all be computed with a high relative accuracy.
+    let jacobi = SymmetricEigen::new_jacobi(m.clone());
+    let det = a.determinant() * d.diagonal().iter().map(|e| e * e).product::<f64>();
+    let product = jacobi.eigenvalues.iter().product::<f64>();
+    assert_relative_eq!(product, det, max_relative = 1.0e-12);
+    assert!(jacobi.eigenvalues.iter().all(|e| *e > 0.0));
+}
+
+#[test]
+fn symmetric_eigen_progress() {
+    use std::ops::ControlFlow;
+
+    let m = DMatrix::from_fn(15, 15, |i, j| ((i + j) % 7) as f64 + (i * j % 5) as f64);
+    let mut reports = Vec::new();
+    let eig = m
+        .clone()
+        .try_symmetric_eigen_with(1.0e-15, 0, |niter, off_diag_norm| {
+            reports.push((niter, off_diag_norm));
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+
+    assert!(!reports.is_empty());
+    assert_eq!(reports.last().unwrap().1, 0.0);
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+
+    let cancelled = m.try_symmetric_eigen_with(1.0e-15, 0, |_, _| ControlFlow::Break(()));
+    assert!(cancelled.is_none());
+}
+
+#[test]
+fn symmetric_eigen_from_tridiagonal() {
+    let diagonal = na::DVector::from_fn(20, |i, _| ((i * 7) % 5) as f64 - 2.0);
+    let off_diagonal = na::DVector::from_fn(19, |i, _| ((i * 3) % 4) as f64 + 0.5);
+
+    let mut m = DMatrix::from_diagonal(&diagonal);
+    for i in 0..19 {
+        m[(i, i + 1)] = off_diagonal[i];
+        m[(i + 1, i)] = off_diagonal[i];
+    }
+
+    let eigenvalues =
+        na::SymmetricEigen::tridiagonal_eigenvalues(diagonal.clone(), off_diagonal.clone());
+    let eig = na::SymmetricEigen::from_tridiagonal(diagonal, off_diagonal);
+
+    assert_eq!(eig.eigenvalues, eigenvalues);
+    assert!(eig.eigenvectors.is_orthogonal(1.0e-12));
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-12);
+
+    let mut expected = m.symmetric_eigenvalues();
+    let mut eigenvalues = eigenvalues;
+    expected.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+    eigenvalues.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(eigenvalues, expected, epsilon = 1.0e-12);
+}
+
+#[test]
+fn symmetric_eigen_from_tridiagonal_small_2x2_block() {
+    // The trailing 2×2 block has a tiny off-diagonal element, relative to the whole matrix but
+    // not to the block, so one of its eigenvectors is computed from nearly cancelling terms.
+    for diagonal in &[[1.0, 0.0, 1.0e-3], [1.0, 1.0e-3, 0.0]] {
+        let diagonal = na::DVector::from_row_slice(diagonal);
+        let off_diagonal = na::DVector::from_row_slice(&[0.0, 1.0e-17]);
+
+        let mut m = DMatrix::from_diagonal(&diagonal);
+        m[(1, 2)] = off_diagonal[1];
+        m[(2, 1)] = off_diagonal[1];
+
+        let eig = na::SymmetricEigen::from_tridiagonal(diagonal, off_diagonal);
+        assert!(eig.eigenvectors.is_orthogonal(1.0e-12));
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-12);
+    }
+}
+
+#[test]
+fn symmetric_eigen_divide_and_conquer() {
+    use na::{Complex, SymmetricEigen};
+
+    fn check(m: DMatrix<f64>) {
+        let eig = SymmetricEigen::new_divide_and_conquer(m.clone());
+        assert!(eig.eigenvectors.is_orthogonal(1.0e-11));
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+
+        let mut expected = m.symmetric_eigenvalues();
+        expected.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_relative_eq!(eig.eigenvalues, expected, epsilon = 1.0e-10);
+    }
+
+    for n in [1, 7, 25, 26, 60, 150] {
+        check(DMatrix::from_fn(n, n, |i, j| ((i * 3 + j * 5) % 7) as f64 - 3.0).symmetric_part());
+        // Heavily repeated eigenvalues trigger the deflation.
+        check(DMatrix::from_fn(n, n, |i, j| ((i + j) % 3) as f64));
+    }
+
+    check(DMatrix::identity(80, 80));
+    check(DMatrix::zeros(40, 40));
+
+    // The 1D discrete laplacian, which is already tridiagonal.
+    let n = 100;
+    let laplacian = DMatrix::from_fn(n, n, |i, j| match i as isize - j as isize {
+        0 => 2.0,
+        1 | -1 => -1.0,
+        _ => 0.0,
+    });
+    check(laplacian);
+
+    let c = DMatrix::from_fn(70, 70, |i, j| {
+        Complex::new((i * j % 4) as f64, i as f64 - j as f64)
+    })
+    .hermitian_part();
+    let eig = SymmetricEigen::new_divide_and_conquer(c.clone());
+    assert!(eig.eigenvectors.is_orthogonal(1.0e-11));
+    assert_relative_eq!(eig.recompose(), c, epsilon = 1.0e-9);
+}
+
+#[test]
+fn symmetric_eigen_closed_form() {
+    use na::{Complex, Matrix2, Matrix3, SymmetricEigen};
+
+    fn check2(m: Matrix2<f64>) {
+        let eig = m.symmetric_eigen();
+        let mut expected = SymmetricEigen::try_new(m, 1.0e-15, 0).unwrap().eigenvalues;
+        let mut eigenvalues = eig.eigenvalues;
+        expected.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+        eigenvalues.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert!(eig.eigenvectors.is_orthogonal(1.0e-14));
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-14 * m.amax());
+        assert_relative_eq!(eigenvalues, expected, epsilon = 1.0e-14 * m.amax());
+        assert_eq!(m.symmetric_eigenvalues(), eig.eigenvalues);
+    }
+
+    fn check3(m: Matrix3<f64>) {
+        let eig = m.symmetric_eigen();
+        let mut expected = SymmetricEigen::try_new(m, 1.0e-15, 0).unwrap().eigenvalues;
+        let mut eigenvalues = eig.eigenvalues;
+        expected.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+        eigenvalues.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert!(eig.eigenvectors.is_orthogonal(1.0e-14));
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-14 * m.amax());
+        assert_relative_eq!(eigenvalues, expected, epsilon = 1.0e-14 * m.amax());
+        assert_eq!(m.symmetric_eigenvalues(), eig.eigenvalues);
+    }
+
+    check2(Matrix2::new(2.0, 1.0, 1.0, 2.0));
+    check2(Matrix2::new(1.0, 0.0, 0.0, 3.0));
+    check2(Matrix2::new(3.0, 0.0, 0.0, -1.0));
+    check2(Matrix2::new(1.0, 1.0e-10, 1.0e-10, 1.0));
+    check2(Matrix2::identity() * 5.0);
+    check2(Matrix2::zeros());
+    check2(Matrix2::new(1.0e200, -3.0e199, -3.0e199, 2.0e199));
+
+    check3(Matrix3::new(2.0, 1.0, 0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0));
+    check3(Matrix3::new(4.0, -2.0, 1.0, -2.0, 5.0, 3.0, 1.0, 3.0, -6.0));
+    check3(Matrix3::from_diagonal_element(7.0));
+    check3(Matrix3::from_diagonal(&na::Vector3::new(1.0, 3.0, 2.0)));
+    check3(Matrix3::zeros());
+    // Repeated eigenvalues.
+    check3(Matrix3::repeat(1.0));
+    check3(Matrix3::new(2.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0));
+    check3(Matrix3::new(1.0, 1.0e-9, 0.0, 1.0e-9, 1.0, 1.0e-9, 0.0, 1.0e-9, 1.0));
+    check3(Matrix3::new(1.0e-150, 3.0e-151, 0.0, 3.0e-151, -2.0e-150, 1.0e-151, 0.0, 1.0e-151, 0.5e-150));
+
+    // Only the lower-triangular part is read.
+    let m = Matrix3::new(4.0, f64::NAN, f64::NAN, -2.0, 5.0, f64::NAN, 1.0, 3.0, -6.0);
+    let eig = m.symmetric_eigen();
+    assert_relative_eq!(
+        eig.recompose(),
+        m.lower_triangle() + m.lower_triangle().transpose() - Matrix3::from_diagonal(&m.diagonal()),
+        epsilon = 1.0e-13
+    );
+
+    let c = Matrix3::new(
+        Complex::new(2.0, 0.0), Complex::new(1.0, 1.0), Complex::new(0.0, -2.0),
+        Complex::new(1.0, -1.0), Complex::new(-1.0, 0.0), Complex::new(3.0, 0.5),
+        Complex::new(0.0, 2.0), Complex::new(3.0, -0.5), Complex::new(4.0, 0.0),
+    );
+    let eig = c.symmetric_eigen();
+    assert!(eig.eigenvectors.is_orthogonal(1.0e-14));
+    assert_relative_eq!(eig.recompose(), c, epsilon = 1.0e-13);
+
+    let c2 = Matrix2::new(
+        Complex::new(1.0, 0.0), Complex::new(2.0, -3.0),
+        Complex::new(2.0, 3.0), Complex::new(-4.0, 0.0),
+    );
+    let eig = c2.symmetric_eigen();
+    assert!(eig.eigenvectors.is_orthogonal(1.0e-14));
+    assert_relative_eq!(eig.recompose(), c2, epsilon = 1.0e-13);
+}
+
+#[test]
+fn symmetric_eigen_recompose_with() {
+    let a = DMatrix::from_fn(6, 6, |i, j| ((i * 5 + j * 3) % 7) as f64 - 3.0);
+    let m = a.hermitian_part();
+    let eig = m.clone().symmetric_eigen();
+
+    assert_relative_eq!(eig.recompose_with(|e| e), eig.recompose());
+    assert_relative_eq!(eig.recompose_with(|e| e * e), &m * &m, epsilon = 1.0e-10);
+
+    // Projection onto the positive-semidefinite cone.
+    let psd = eig.recompose_with(|e| e.max(0.0));
+    let psd_eigenvalues = psd.symmetric_eigenvalues();
+    assert!(psd_eigenvalues.iter().all(|e| *e >= -1.0e-10));
+    assert!(psd_eigenvalues.iter().any(|e| *e < 1.0e-10));
+
+    // Square root and inverse of a positive-definite matrix.
+    let spd = &a * a.transpose() + DMatrix::identity(6, 6);
+    let eig = spd.clone().symmetric_eigen();
+    let sqrt = eig.recompose_with(f64::sqrt);
+    assert_relative_eq!(&sqrt * &sqrt, spd, epsilon = 1.0e-9);
+    assert_relative_eq!(
+        eig.recompose_with(|e| 1.0 / e),
+        spd.try_inverse().unwrap(),
+        epsilon = 1.0e-9
+    );
+}
@@ -0,0 +1,93 @@
+use na::{DMatrix, DVector, Matrix3, Vector3, LU};
+
+#[test]
+fn equilibration_powers_of_two() {
+    let m = DMatrix::from_fn(5, 4, |i, j| {
+        10.0f64.powi(i as i32 * 3 - 6) * (j as f64 - 1.5) * 10.0f64.powi(j as i32 * 2)
+    });
+    let equilibration = m.equilibrate();
+    let mut scaled = m.clone();
+    equilibration.scale_matrix(&mut scaled);
+
+    for s in equilibration
+        .row_scales()
+        .iter()
+        .chain(equilibration.col_scales().iter())
+    {
+        assert_eq!(s.log2().fract(), 0.0);
+    }
+
+    for i in 0..5 {
+        let amax = scaled.row(i).amax();
+        assert!(amax >= 0.5f64.sqrt() && amax <= 2.0f64.sqrt());
+
+        for j in 0..4 {
+            let expected =
+                m[(i, j)] * equilibration.row_scales()[i] * equilibration.col_scales()[j];
+            assert_eq!(scaled[(i, j)], expected);
+        }
+    }
+
+    for j in 0..4 {
+        let amax = scaled.column(j).amax();
+        assert!(amax >= 0.5f64.sqrt() && amax <= 2.0f64.sqrt());
+    }
+
+    assert!(equilibration.row_condition() < 1.0e-10);
+    assert!(equilibration.col_condition() < 1.0e-3);
+}
+
+#[test]
+fn equilibration_zero_rows_and_columns() {
+    let m = Matrix3::new(0.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 0.25);
+    let equilibration = m.equilibrate();
+
+    assert_eq!(*equilibration.row_scales(), Vector3::new(1.0, 0.25, 4.0));
+    assert_eq!(*equilibration.col_scales(), Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(equilibration.col_condition(), 1.0);
+}
+
+#[test]
+fn equilibration_subnormal_entries() {
+    // The inverse of `1.0e-310` is not representable, so the factors must be clamped.
+    let m = Matrix3::<f64>::new(1.0e-310, 0.0, 0.0, 0.0, 1.0e-310, 1.0, 0.0, 0.0, 3.0);
+    let equilibration = m.equilibrate();
+    let mut scaled = m;
+    equilibration.scale_matrix(&mut scaled);
+
+    for s in equilibration
+        .row_scales()
+        .iter()
+        .chain(equilibration.col_scales().iter())
+    {
+        assert!(s.is_finite() && *s > 0.0);
+        assert_eq!(s.log2().fract(), 0.0);
+    }
+
+    assert_eq!(equilibration.row_scales()[0], 2.0f64.powi(1023));
+    assert!(scaled.iter().all(|e| e.is_finite()));
+    assert!(scaled[(0, 0)] >= 0.5f64.sqrt() && scaled[(0, 0)] <= 2.0f64.sqrt());
+    assert!(scaled[(1, 1)] > 0.0);
+}
+
+#[test]
+fn lu_solve_equilibrated_badly_scaled() {
+    // A matrix whose rows and columns have wildly different magnitudes.
+    let n = 6;
+    let r = DVector::from_fn(n, |i, _| 10.0f64.powi(i as i32 * 4 - 10));
+    let c = DVector::from_fn(n, |j, _| 10.0f64.powi(8 - j as i32 * 3));
+    let base = DMatrix::from_fn(n, n, |i, j| {
+        1.0 / (i + j + 1) as f64 + (i == j) as u8 as f64
+    });
+    let m = DMatrix::from_diagonal(&r) * base * DMatrix::from_diagonal(&c);
+
+    let x = DVector::from_fn(n, |i, _| (i as f64 + 1.0) / c[i]);
+    let b = &m * &x;
+
+    let (lu, equilibration) = LU::new_equilibrated(m.clone());
+    let solution = lu.solve_equilibrated(&equilibration, &b).unwrap();
+
+    for i in 0..n {
+        assert_relative_eq!(solution[i], x[i], max_relative = 1.0e-10);
+    }
+}
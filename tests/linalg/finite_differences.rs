@@ -0,0 +1,71 @@
+use na::{hessian, jacobian, DMatrix, DVector, Matrix3, Vector2, Vector3};
+
+#[test]
+fn jacobian_of_linear_map() {
+    let a = DMatrix::from_fn(4, 3, |i, j| (i * 3 + j) as f64 - 5.0);
+    let x = DVector::from_vec(vec![1.0e3, -2.0, 0.5]);
+    let j = jacobian(|x: &DVector<f64>| &a * x, &x, 1.0e-5);
+
+    assert_eq!(j.shape(), (4, 3));
+    assert_relative_eq!(j, a, epsilon = 1.0e-7);
+
+    // An empty input gives an empty Jacobian with the number of rows of the output.
+    let empty = jacobian(
+        |_: &DVector<f64>| DVector::zeros(2),
+        &DVector::zeros(0),
+        1.0e-5,
+    );
+    assert_eq!(empty.shape(), (2, 0));
+}
+
+#[test]
+fn jacobian_of_nonlinear_map() {
+    // Spherical to cartesian coordinates.
+    let f = |x: &Vector3<f64>| {
+        let (r, theta, phi) = (x.x, x.y, x.z);
+        Vector3::new(
+            r * theta.sin() * phi.cos(),
+            r * theta.sin() * phi.sin(),
+            r * theta.cos(),
+        )
+    };
+    let x = Vector3::new(2.0f64, 0.7, -1.2);
+    let (r, theta, phi) = (x.x, x.y, x.z);
+    let expected = Matrix3::new(
+        theta.sin() * phi.cos(),
+        r * theta.cos() * phi.cos(),
+        -r * theta.sin() * phi.sin(),
+        theta.sin() * phi.sin(),
+        r * theta.cos() * phi.sin(),
+        r * theta.sin() * phi.cos(),
+        theta.cos(),
+        -r * theta.sin(),
+        0.0,
+    );
+
+    assert_relative_eq!(jacobian(f, &x, 1.0e-5), expected, epsilon = 1.0e-9);
+}
+
+#[test]
+fn hessian_of_quadratic_and_nonlinear_functions() {
+    let a = Matrix3::new(4.0, 1.0, -2.0, 1.0, 3.0, 0.5, -2.0, 0.5, 5.0);
+    let b = Vector3::new(1.0, -1.0, 2.0);
+    let x = Vector3::new(-15.0, 3.0, 0.25);
+    let quadratic = |x: &Vector3<f64>| 0.5 * x.dot(&(a * x)) + b.dot(x);
+    let h = hessian(quadratic, &x, 1.0e-4);
+
+    assert_eq!(h, h.transpose());
+    assert_relative_eq!(h, a, epsilon = 1.0e-4);
+
+    // Rosenbrock function.
+    let f = |x: &Vector2<f64>| (1.0 - x.x).powi(2) + 100.0 * (x.y - x.x * x.x).powi(2);
+    let x = Vector2::new(-1.2, 1.0);
+    let expected = na::Matrix2::new(
+        1200.0 * x.x * x.x - 400.0 * x.y + 2.0,
+        -400.0 * x.x,
+        -400.0 * x.x,
+        200.0,
+    );
+
+    assert_relative_eq!(hessian(f, &x, 1.0e-4), expected, epsilon = 1.0e-4);
+}
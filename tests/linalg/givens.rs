@@ -0,0 +1,91 @@
+use na::{Complex, Const, DMatrix, Dynamic, GivensRotation, Matrix2x3};
+
+#[test]
+fn givens_apply_left_right() {
+    let m = DMatrix::from_fn(4, 5, |i, j| {
+        Complex::new((i * 5 + j) as f64, i as f64 - j as f64)
+    });
+    let (rot, _) = GivensRotation::new(Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5));
+    let rot_left = rot.to_matrix_generic(Dynamic::new(4), 3, 1);
+    let rot_right = rot.to_matrix_generic(Dynamic::new(5), 0, 4);
+
+    assert!(rot_left.is_orthogonal(1.0e-12));
+
+    let mut left = m.clone();
+    rot.apply_left(&mut left, 3, 1);
+    assert_relative_eq!(left, &rot_left * &m, epsilon = 1.0e-12);
+
+    let mut right = m.clone();
+    rot.apply_right(&mut right, 0, 4);
+    assert_relative_eq!(right, &m * &rot_right, epsilon = 1.0e-12);
+
+    // Consistency with the rotations of matrices with exactly two rows or columns.
+    let a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let (rot, _) = GivensRotation::new(0.6, 0.8);
+    let mut expected = a;
+    rot.rotate(&mut expected);
+    let mut res = a;
+    rot.apply_left(&mut res, 0, 1);
+    assert_eq!(res, expected);
+
+    let mut expected = a.transpose();
+    rot.rotate_rows(&mut expected);
+    let mut res = a.transpose();
+    rot.apply_right(&mut res, 0, 1);
+    assert_eq!(res, expected);
+    assert_eq!(
+        rot.to_matrix_generic(Const::<2>, 0, 1),
+        na::Matrix2::new(0.6, -0.8, 0.8, 0.6)
+    );
+}
+
+#[test]
+fn givens_accumulated_jacobi_sweep() {
+    // Cyclic Jacobi sweeps diagonalizing a symmetric matrix with rotations only.
+    let a = DMatrix::from_fn(4, 4, |i, j| 1.0 / (i + j + 1) as f64);
+    let mut d = a.clone();
+    let mut q = DMatrix::identity(4, 4);
+
+    for _ in 0..10 {
+        for p in 0..4 {
+            for r in p + 1..4 {
+                if d[(p, r)] == 0.0 {
+                    continue;
+                }
+
+                let theta = (d[(r, r)] - d[(p, p)]) / (2.0 * d[(p, r)]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let rot = GivensRotation::new_unchecked(c, -t * c);
+
+                rot.apply_right(&mut d, p, r);
+                rot.inverse().apply_left(&mut d, p, r);
+                rot.apply_right(&mut q, p, r);
+            }
+        }
+    }
+
+    assert!(q.is_orthogonal(1.0e-12));
+    assert_relative_eq!(DMatrix::from_diagonal(&d.diagonal()), d, epsilon = 1.0e-12);
+    assert_relative_eq!(&q * &d * q.transpose(), a, epsilon = 1.0e-12);
+}
+
+#[test]
+fn givens_apply_skips_zeros() {
+    let mut m = DMatrix::from_row_slice(2, 3, &[1.0, 0.0, f64::NAN, 2.0, 0.0, f64::NAN]);
+    let (rot, norm) = GivensRotation::new(1.0, 2.0);
+    rot.inverse().apply_left(&mut m, 0, 1);
+
+    assert_relative_eq!(m[(0, 0)], norm, epsilon = 1.0e-12);
+    assert_relative_eq!(m[(1, 0)], 0.0, epsilon = 1.0e-12);
+    assert_eq!(m[(0, 1)], 0.0);
+    assert_eq!(m[(1, 1)], 0.0);
+    assert!(m[(0, 2)].is_nan());
+}
+
+#[test]
+#[should_panic]
+fn givens_apply_same_rows() {
+    let mut m = DMatrix::<f64>::zeros(3, 3);
+    GivensRotation::identity().apply_left(&mut m, 1, 1);
+}
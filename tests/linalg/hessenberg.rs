@@ -29,6 +29,28 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(m, &p * h * p.adjoint(), epsilon = 1.0e-7))
                 }
 
+                #[test]
+                fn hessenberg_solve_shifted(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM, sigma in $scalar) {
+                    let n = n.max(1);
+                    let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                    let b = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+                    let hess = m.clone().hessenberg();
+
+                    for sigma in [sigma, 0.0.into()] {
+                        let shifted = &m - DMatrix::identity(n, n) * sigma;
+
+                        if shifted.clone().try_inverse().map_or(false, |inv| inv.norm() < 1.0e4) {
+                            let sol = hess.solve_shifted(&b, sigma).unwrap();
+                            prop_assert!(relative_eq!(shifted * sol, &b, epsilon = 1.0e-6));
+                        }
+                    }
+
+                    if m.clone().try_inverse().map_or(false, |inv| inv.norm() < 1.0e4) {
+                        let sol = hess.solve(&b).unwrap();
+                        prop_assert!(relative_eq!(&m * sol, b, epsilon = 1.0e-6));
+                    }
+                }
+
                 #[test]
                 fn hessenberg_static_mat2(m in matrix2_($scalar)) {
                     let hess = m.hessenberg();
@@ -140,3 +140,33 @@ fn matrix5_try_inverse_scaled_identity() {
 
     assert_relative_eq!(a_inv, expected_inverse);
 }
+
+#[test]
+fn matrix4_f32_try_inverse() {
+    let matrices = [
+        Matrix4::<f32>::from_fn(|i, j| ((i * 7 + j * 3) % 5) as f32 - 1.5),
+        Matrix4::new_perspective(1.5, 0.8, 0.1, 100.0),
+        Matrix4::new_rotation(na::Vector3::new(0.3, -1.2, 0.4))
+            .append_translation(&na::Vector3::new(1.0, 2.0, -3.0)),
+        Matrix4::from_diagonal(&na::Vector4::new(1.0e-20, 2.0, 1.0e10, -3.0)),
+    ];
+
+    for m in matrices.iter() {
+        let inv = m.try_inverse().expect("Matrix is invertible");
+        let expected = m.cast::<f64>().try_inverse().unwrap().cast::<f32>();
+        assert_relative_eq!(inv, expected, max_relative = 1.0e-5, epsilon = 1.0e-6);
+        assert_relative_eq!(inv * m, Matrix4::identity(), epsilon = 1.0e-5);
+
+        // Strided views are inverted in-place the same way.
+        let mut m5 = Matrix5::<f32>::repeat(-1.0);
+        m5.fixed_slice_mut::<4, 4>(1, 0).copy_from(m);
+        assert!(m5.fixed_slice_mut::<4, 4>(1, 0).try_inverse_mut());
+        assert_eq!(m5.fixed_slice::<4, 4>(1, 0), inv);
+        assert_eq!(m5.row(0), Matrix5::<f32>::repeat(-1.0).row(0));
+    }
+
+    // A failed inversion leaves the matrix untouched.
+    let mut singular = Matrix4::<f32>::from_fn(|i, j| (i + j) as f32);
+    assert!(!singular.try_inverse_mut());
+    assert_eq!(singular, Matrix4::from_fn(|i, j| (i + j) as f32));
+}
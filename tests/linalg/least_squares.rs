@@ -0,0 +1,151 @@
+use na::{Complex, DMatrix, DVector, Matrix4x2, Vector4};
+
+#[test]
+fn qr_least_squares_normal_equations() {
+    let m = Matrix4x2::new(1.0, 1.0, 1.0, 2.0, 1.0, 3.0, 1.0, 4.0);
+    let b = Vector4::new(6.0, 5.0, 7.0, 10.0);
+    let x = m.qr().solve_least_squares(&b).unwrap();
+
+    assert_relative_eq!(m.transpose() * (m * x - b), na::zero(), epsilon = 1.0e-10);
+    assert_relative_eq!(x, na::Vector2::new(3.5, 1.4), epsilon = 1.0e-10);
+}
+
+#[test]
+fn qr_least_squares_rank_deficient() {
+    let m = Matrix4x2::new(1.0, 0.0, 2.0, 0.0, 3.0, 0.0, 4.0, 0.0);
+    assert!(m.qr().solve_least_squares(&Vector4::repeat(1.0)).is_none());
+}
+
+#[test]
+fn weighted_least_squares_normal_equations() {
+    let m = DMatrix::from_fn(6, 3, |i, j| ((i * 7 + j * 3) % 5) as f64 - 1.5 + j as f64);
+    let b = DMatrix::from_fn(6, 2, |i, j| (i as f64 - j as f64).sin());
+    let w = DVector::from_fn(6, |i, _| 0.5 + i as f64);
+    let x = m.solve_weighted_least_squares(&b, &w).unwrap();
+
+    // Aᵀ W (A x - b) = 0
+    let residual = DMatrix::from_diagonal(&w) * (&m * &x - &b);
+    assert_relative_eq!(
+        m.transpose() * residual,
+        DMatrix::zeros(3, 2),
+        epsilon = 1.0e-10
+    );
+
+    // Unit weights give the ordinary least-squares solution.
+    let unweighted = m.solve_weighted_least_squares(&b, &DVector::repeat(6, 1.0));
+    assert_relative_eq!(
+        unweighted.unwrap(),
+        m.clone().qr().solve_least_squares(&b).unwrap(),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+#[should_panic]
+fn weighted_least_squares_negative_weight() {
+    let m = Matrix4x2::new(1.0, 1.0, 1.0, 2.0, 1.0, 3.0, 1.0, 4.0);
+    let w = Vector4::new(1.0, -1.0, 1.0, 1.0);
+    let _ = m.solve_weighted_least_squares(&Vector4::repeat(1.0), &w);
+}
+
+#[test]
+fn constrained_least_squares_kkt() {
+    let m = DMatrix::from_fn(7, 4, |i, j| {
+        ((i * 5 + j * 2) % 7) as f64 - 2.0 + (i == j) as u8 as f64
+    });
+    let b = DMatrix::from_fn(7, 2, |i, j| (i + j) as f64 * 0.5 - 1.0);
+    let c = DMatrix::from_row_slice(2, 4, &[1.0, 1.0, 1.0, 1.0, 1.0, -2.0, 0.0, 3.0]);
+    let d = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, -1.0, 0.5]);
+    let x = m.solve_constrained_least_squares(&b, &c, &d).unwrap();
+
+    assert_relative_eq!(&c * &x, d, epsilon = 1.0e-10);
+
+    // [AᵀA  Cᵀ] [x]   [Aᵀb]
+    // [C    0 ] [λ] = [d  ]
+    let mut kkt = DMatrix::zeros(6, 6);
+    kkt.slice_mut((0, 0), (4, 4))
+        .copy_from(&(m.transpose() * &m));
+    kkt.slice_mut((0, 4), (4, 2)).copy_from(&c.transpose());
+    kkt.slice_mut((4, 0), (2, 4)).copy_from(&c);
+    let mut rhs = DMatrix::zeros(6, 2);
+    rhs.rows_mut(0, 4).copy_from(&(m.transpose() * &b));
+    rhs.rows_mut(4, 2).copy_from(&d);
+    let expected = kkt.lu().solve(&rhs).unwrap();
+
+    assert_relative_eq!(x, expected.rows(0, 4).into_owned(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn constrained_least_squares_fully_determined() {
+    // As many independent constraints as unknowns: `self` is irrelevant.
+    let m = DMatrix::<f64>::zeros(1, 2);
+    let c = DMatrix::from_row_slice(2, 2, &[2.0, 1.0, 1.0, 3.0]);
+    let d = DVector::from_column_slice(&[3.0, 4.0]);
+    let x = m
+        .solve_constrained_least_squares(&DVector::zeros(1), &c, &d)
+        .unwrap();
+
+    assert_relative_eq!(
+        x,
+        DVector::from_column_slice(&[1.0, 1.0]),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn constrained_least_squares_rank_deficient() {
+    let m = DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 2.0, 0.0, 3.0, 0.0]);
+    let b = DVector::repeat(3, 1.0);
+
+    // The second constraint is empty.
+    let c = DMatrix::from_row_slice(2, 2, &[1.0, 1.0, 0.0, 0.0]);
+    let d = DVector::from_column_slice(&[1.0, 0.0]);
+    assert!(m.solve_constrained_least_squares(&b, &c, &d).is_none());
+
+    // The second unknown is determined by neither `m` nor the constraint.
+    let c = DMatrix::from_row_slice(1, 2, &[1.0, 0.0]);
+    let d = DVector::from_column_slice(&[1.0]);
+    assert!(m.solve_constrained_least_squares(&b, &c, &d).is_none());
+}
+
+#[test]
+fn ridge_normal_equations() {
+    for &(nrows, ncols) in &[(7, 4), (3, 5)] {
+        let m = DMatrix::from_fn(nrows, ncols, |i, j| {
+            Complex::new(
+                ((i * 5 + j * 3) % 7) as f64 - 3.0,
+                (i as f64 - j as f64) * 0.5,
+            )
+        });
+        let b = DMatrix::from_fn(nrows, 2, |i, j| Complex::new(i as f64, 1.0 - j as f64));
+        let lambdas = [0.25, 1.0, 4.0];
+        let path = m.solve_ridge_path(&b, &lambdas);
+
+        assert_eq!(path.len(), lambdas.len());
+
+        for (x, lambda) in path.iter().zip(lambdas.iter()) {
+            // (Aᴴ A + λ I) x = Aᴴ b
+            let lhs = m.ad_mul(&m) + DMatrix::identity(ncols, ncols) * Complex::from(*lambda);
+            assert_relative_eq!(lhs * x, m.ad_mul(&b), epsilon = 1.0e-9);
+            assert_relative_eq!(*x, m.solve_ridge(&b, *lambda), epsilon = 1.0e-12);
+        }
+    }
+}
+
+#[test]
+fn ridge_without_regularization() {
+    // Without regularization, this is the minimum-norm least-squares solution.
+    let m = DMatrix::from_row_slice(3, 3, &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0]);
+    let b = DVector::from_column_slice(&[1.0, 0.0, 2.0]);
+    let x = m.solve_ridge(&b, 0.0);
+    let expected = m.clone().pseudo_inverse(1.0e-12).unwrap() * &b;
+
+    assert_relative_eq!(x, expected, epsilon = 1.0e-10);
+}
+
+#[test]
+#[should_panic]
+fn ridge_negative_regularization() {
+    let m = Matrix4x2::new(1.0, 1.0, 1.0, 2.0, 1.0, 3.0, 1.0, 4.0);
+    let _ = m.solve_ridge(&Vector4::repeat(1.0), -1.0);
+}
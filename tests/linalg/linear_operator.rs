@@ -0,0 +1,66 @@
+use na::{
+    Complex, DMatrix, FnOperator, LinearOperator, Matrix3x2, ProductOperator, ScaledOperator,
+    SumOperator,
+};
+
+#[test]
+fn linear_operator_dense_matrix() {
+    let m = Matrix3x2::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let x = DMatrix::from_fn(2, 3, |i, j| (i + 2 * j) as f64);
+    let y = DMatrix::from_fn(3, 2, |i, j| i as f64 - j as f64);
+
+    assert_eq!(LinearOperator::<f64>::shape(&m), (3, 2));
+    assert_eq!(m.apply(&x), m * &x);
+    assert_eq!(m.apply_adjoint(&y), m.transpose() * &y);
+}
+
+#[test]
+fn linear_operator_compositions() {
+    let a = DMatrix::from_fn(4, 3, |i, j| {
+        Complex::new((i * 3 + j) as f64, i as f64 - 1.0)
+    });
+    let b = DMatrix::from_fn(3, 4, |i, j| {
+        Complex::new(i as f64 - j as f64, (i + j) as f64)
+    });
+    let c = DMatrix::from_fn(4, 4, |i, j| {
+        Complex::new(((i * 5 + j) % 3) as f64, j as f64)
+    });
+    let alpha = Complex::new(2.0, -3.0);
+
+    // `alpha * (A * B) + C`, with `C` given by closures.
+    let c_op = FnOperator::new(
+        4,
+        4,
+        |x: &DMatrix<Complex<f64>>| &c * x,
+        |x: &DMatrix<Complex<f64>>| c.ad_mul(x),
+    );
+    let op = SumOperator::new(
+        ScaledOperator::new(alpha, ProductOperator::new(&a, &b)),
+        c_op,
+    );
+    let expected = (&a * &b) * alpha + &c;
+
+    let x = DMatrix::from_fn(4, 2, |i, j| Complex::new((i + j) as f64, 1.0));
+    assert_eq!(op.shape(), (4, 4));
+    assert_relative_eq!(op.apply(&x), &expected * &x, epsilon = 1.0e-10);
+    assert_relative_eq!(op.apply_adjoint(&x), expected.ad_mul(&x), epsilon = 1.0e-10);
+
+    // Trait objects.
+    let dyn_op: &dyn LinearOperator<Complex<f64>> = &op;
+    assert_relative_eq!(dyn_op.apply(&x), &expected * &x, epsilon = 1.0e-10);
+}
+
+#[test]
+#[should_panic]
+fn linear_operator_sum_shape_mismatch() {
+    let a = DMatrix::<f64>::zeros(3, 2);
+    let b = DMatrix::<f64>::zeros(2, 3);
+    let _ = SumOperator::new(&a, &b);
+}
+
+#[test]
+#[should_panic]
+fn linear_operator_product_shape_mismatch() {
+    let a = DMatrix::<f64>::zeros(3, 2);
+    let _ = ProductOperator::new(&a, &a);
+}
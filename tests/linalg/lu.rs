@@ -38,6 +38,39 @@ fn lu_simple_with_pivot() {
     assert!(relative_eq!(m, lu, epsilon = 1.0e-7));
 }
 
+#[test]
+fn lu_blocked() {
+    use na::{DMatrix, DVector};
+
+    for &(nrows, ncols) in &[(65, 65), (200, 200), (200, 130), (130, 200)] {
+        let m = DMatrix::from_fn(nrows, ncols, |i, j| {
+            ((i * 37 + j * 11) % 29) as f64 - 14.0 + (i == j) as u8 as f64 * 0.5
+        });
+        let (p, l, u) = m.clone().lu().unpack();
+        let mut lu = l * u;
+        p.inv_permute_rows(&mut lu);
+
+        assert_relative_eq!(m, lu, epsilon = 1.0e-9);
+    }
+
+    let m = DMatrix::from_fn(150, 150, |i, j| {
+        1.0 / (i + j + 1) as f64 + (i == j) as u8 as f64 * 3.0
+    });
+    let b = DVector::from_fn(150, |i, _| i as f64);
+    let x = m.clone().lu().solve(&b).unwrap();
+    assert_relative_eq!(&m * x, b, epsilon = 1.0e-9);
+
+    // A zero column in a trailing panel makes the matrix singular.
+    let mut m = m;
+    m.column_mut(100).fill(0.0);
+    let lu = m.clone().lu();
+    assert!(!lu.is_invertible());
+    let (p, l, u) = lu.unpack();
+    let mut lu = l * u;
+    p.inv_permute_rows(&mut lu);
+    assert_relative_eq!(m, lu, epsilon = 1.0e-9);
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
@@ -117,6 +150,33 @@ mod proptest_tests {
                          prop_assert!(sol2.is_none() || relative_eq!(&m * sol2.unwrap(), b2, epsilon = 1.0e-6));
                     }
 
+                    #[test]
+                    fn lu_solve_equilibrated(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+
+                        let (lu, equilibration) = na::LU::new_equilibrated(m.clone());
+                        let b = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        let sol = lu.solve_equilibrated(&equilibration, &b);
+
+                        prop_assert!(sol.is_none() || relative_eq!(&m * sol.unwrap(), b, epsilon = 1.0e-6));
+                    }
+
+                    #[test]
+                    fn lu_tr_solve(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+
+                        let lu = m.clone().lu();
+                        let b1 = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let b2 = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        let sol1 = lu.tr_solve(&b1);
+                        let sol2 = lu.ad_solve(&b2);
+
+                        prop_assert!(sol1.is_none() || relative_eq!(m.transpose() * sol1.unwrap(), b1, epsilon = 1.0e-6));
+                        prop_assert!(sol2.is_none() || relative_eq!(m.adjoint() * sol2.unwrap(), b2, epsilon = 1.0e-6));
+                    }
+
                     #[test]
                     fn lu_inverse(n in PROPTEST_MATRIX_DIM) {
                         let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
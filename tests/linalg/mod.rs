@@ -4,11 +4,19 @@ mod cholesky;
 mod col_piv_qr;
 mod convolution;
 mod eigen;
+mod equilibration;
 mod exp;
+mod finite_differences;
 mod full_piv_lu;
+mod givens;
 mod hessenberg;
 mod inverse;
+mod least_squares;
+mod linear_operator;
 mod lu;
+mod nearest_spd;
+mod polynomial;
+mod pow;
 mod qr;
 mod schur;
 mod solve;
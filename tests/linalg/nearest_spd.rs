@@ -0,0 +1,59 @@
+use na::{Complex, DMatrix, Matrix2, Matrix3};
+
+#[test]
+fn nearest_spd_repairs_indefinite_matrix() {
+    // Symmetric with eigenvalues 3 and -1.
+    let m = Matrix2::new(1.0, 2.0, 2.0, 1.0);
+    assert!(m.cholesky().is_none());
+
+    let spd = m.nearest_spd().unwrap();
+    assert!(spd.is_symmetric(0.0));
+    assert!(spd.cholesky().is_some());
+    // The nearest positive-semidefinite matrix is the projection on the eigenvector of 3.
+    assert_relative_eq!(spd, Matrix2::repeat(1.5), epsilon = 1.0e-10);
+}
+
+#[test]
+fn nearest_spd_of_spd_matrix_is_itself() {
+    let m = Matrix3::new(4.0, 1.0, 0.5, 1.0, 3.0, 0.2, 0.5, 0.2, 2.0);
+    assert_relative_eq!(m.nearest_spd().unwrap(), m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn nearest_spd_of_non_symmetric_matrix() {
+    let m = DMatrix::from_fn(6, 6, |i, j| ((i * 7 + j * 3) % 5) as f64 - 2.0);
+    let spd = m.nearest_spd().unwrap();
+    assert!(spd.is_symmetric(0.0));
+    assert!(spd.clone().cholesky().is_some());
+    // The result is at least as close to the symmetric part as the symmetric part is to `m`.
+    assert!((&spd - m.symmetric_part()).norm() <= (&m - m.symmetric_part()).norm() + 1.0);
+
+    let zero = DMatrix::<f64>::zeros(3, 3);
+    assert!(zero.nearest_spd().unwrap().cholesky().is_some());
+
+    let mut nan = m;
+    nan[(2, 3)] = f64::NAN;
+    assert!(nan.nearest_spd().is_none());
+}
+
+#[test]
+fn nearest_spd_complex() {
+    let m = Matrix2::new(
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, 2.0),
+        Complex::new(0.0, -2.0),
+        Complex::new(1.0, 0.0),
+    );
+    assert!(!m.is_positive_definite(1.0e-12));
+
+    let spd = m.nearest_spd().unwrap();
+    assert!(spd.is_positive_definite(1.0e-12));
+}
+
+#[test]
+fn symmetrize() {
+    let mut m = DMatrix::from_fn(4, 4, |i, j| (i * 4 + j) as f64);
+    let expected = m.symmetric_part();
+    m.symmetrize();
+    assert_eq!(m, expected);
+}
@@ -0,0 +1,86 @@
+use na::{polynomial_roots, Complex, DMatrix, Matrix2, Vector3};
+
+#[test]
+fn characteristic_polynomial_2x2() {
+    let m = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+    // λ² - trace * λ + det
+    assert_relative_eq!(
+        m.characteristic_polynomial(),
+        Vector3::new(-2.0, -5.0, 1.0),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn characteristic_polynomial_of_companion_matrix() {
+    let coeffs = [3.0, -1.0, 0.0, 2.0, 4.0, 2.0];
+    let p = DMatrix::companion_matrix(&coeffs).characteristic_polynomial();
+    let expected: Vec<f64> = coeffs.iter().map(|c| c / 2.0).collect();
+    assert_relative_eq!(p.as_slice(), &expected[..], epsilon = 1.0e-10);
+
+    let empty = DMatrix::<f64>::zeros(0, 0);
+    assert_eq!(empty.characteristic_polynomial().as_slice(), &[1.0]);
+}
+
+#[test]
+fn polynomial_roots_known_roots() {
+    // 2 * (x - 3) * (x + 0.5) * x * (x² - 2x + 5), with roots 3, -0.5, 0, and 1 ± 2i.
+    let coeffs = [0.0, -15.0, -19.0, 17.0, -9.0, 2.0, 0.0, 0.0];
+    let roots = polynomial_roots(&coeffs);
+    let expected = [
+        Complex::new(3.0, 0.0),
+        Complex::new(-0.5, 0.0),
+        Complex::new(0.0, 0.0),
+        Complex::new(1.0, 2.0),
+        Complex::new(1.0, -2.0),
+    ];
+
+    assert_eq!(roots.len(), expected.len());
+    for e in &expected {
+        assert!(roots.iter().any(|r| (r - e).norm() < 1.0e-8));
+    }
+
+    assert_eq!(polynomial_roots(&[4.0, 0.0]).len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn polynomial_roots_zero_polynomial() {
+    let _ = polynomial_roots(&[0.0, 0.0]);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    macro_rules! gen_tests(
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
+            mod $module {
+                use na::DMatrix;
+                #[allow(unused_imports)]
+                use crate::core::helper::{RandScalar, RandComplex};
+
+                use crate::proptest::*;
+                use proptest::{prop_assert, proptest};
+
+                proptest! {
+                    #[test]
+                    fn characteristic_polynomial(n in 1..8usize, lambda in $scalar) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let p = m.characteristic_polynomial();
+                        let det = (DMatrix::identity(n, n) * lambda - &m).determinant();
+                        let mut value = p[n];
+
+                        for i in (0..n).rev() {
+                            value = value * lambda + p[i];
+                        }
+
+                        prop_assert!(relative_eq!(p[n - 1], -m.trace(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(value, det, epsilon = 1.0e-7, max_relative = 1.0e-7));
+                    }
+                }
+            }
+        }
+    );
+
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+}
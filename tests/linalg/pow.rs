@@ -0,0 +1,43 @@
+use na::{DMatrix, Matrix2, Matrix3};
+
+#[test]
+fn pow_integral_exponents() {
+    let m = Matrix2::new(1.0, 1.0, 0.0, 1.0);
+
+    for e in -5i32..=5 {
+        let expected = Matrix2::new(1.0, e as f64, 0.0, 1.0);
+        assert_relative_eq!(m.pow(e).unwrap(), expected, epsilon = 1.0e-12);
+    }
+
+    let a = Matrix3::new(2.0, -1.0, 0.5, 0.0, 1.5, 1.0, 1.0, 0.0, 1.0);
+    assert_relative_eq!(a.pow(3u8).unwrap(), a * a * a, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        a.pow(-3i64).unwrap(),
+        (a * a * a).try_inverse().unwrap(),
+        epsilon = 1.0e-12
+    );
+    assert_eq!(Matrix2::<f64>::zeros().pow(-1), None);
+}
+
+#[test]
+fn checked_pow_counts_paths() {
+    // Adjacency matrix of a directed cycle 0 -> 1 -> 2 -> 0, with an extra edge 0 -> 2.
+    let adjacency = Matrix3::new(0u32, 1, 1, 0, 0, 1, 1, 0, 0);
+    let mut expected = Matrix3::identity();
+
+    for exp in 0..10 {
+        assert_eq!(adjacency.checked_pow(exp), Some(expected));
+        expected *= adjacency;
+    }
+
+    let dynamic = DMatrix::from_element(3, 3, 1i8);
+    assert_eq!(
+        dynamic.checked_pow(5),
+        Some(DMatrix::from_element(3, 3, 81))
+    );
+    assert_eq!(dynamic.checked_pow(6), None);
+    assert_eq!(
+        DMatrix::<i8>::zeros(0, 0).checked_pow(5),
+        Some(DMatrix::zeros(0, 0))
+    );
+}
@@ -83,6 +83,23 @@ macro_rules! gen_tests(
                      }
                 }
 
+                #[test]
+                fn qr_tr_solve(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                    let m  = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+
+                    let qr = m.clone().qr();
+                    let b1 = DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                    let b2 = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                    if qr.is_invertible() {
+                        let sol1 = qr.tr_solve(&b1).unwrap();
+                        let sol2 = qr.ad_solve(&b2).unwrap();
+
+                        prop_assert!(relative_eq!(m.transpose() * sol1, b1, epsilon = 1.0e-6));
+                        prop_assert!(relative_eq!(m.adjoint() * sol2, b2, epsilon = 1.0e-6));
+                    }
+                }
+
                 #[test]
                 fn qr_inverse(n in PROPTEST_MATRIX_DIM) {
                     let n = cmp::max(1, cmp::min(n, 15)); // To avoid slowing down the test too much.
@@ -129,3 +129,114 @@ fn schur_singular() {
     let (vecs, vals) = m.clone().schur().unpack();
     assert!(relative_eq!(&vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7))
 }
+
+#[test]
+fn schur_progress() {
+    use std::ops::ControlFlow;
+
+    let m = DMatrix::from_fn(10, 10, |i, j| ((i * 3 + j * 7) % 11) as f64 - 5.0);
+    let mut niters = 0;
+    let schur = m
+        .clone()
+        .try_schur_with(1.0e-15, 0, |niter, subdiag_norm| {
+            assert!(subdiag_norm >= 0.0);
+            niters = niter;
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+
+    assert!(niters > 0);
+    let (vecs, vals) = schur.unpack();
+    assert_relative_eq!(&vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7);
+
+    let cancelled = m.try_schur_with(1.0e-15, 0, |niter, _| {
+        if niter < 2 {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+    assert!(cancelled.is_none());
+}
+
+#[test]
+fn schur_options() {
+    use na::{SchurOptions, SchurShift};
+
+    // A cyclic permutation, on which the Francis double shifts do not make any progress.
+    let cyclic = DMatrix::from_fn(6, 6, |i, j| if i == (j + 1) % 6 { 1.0 } else { 0.0 });
+    assert!(cyclic.clone().try_schur(1.0e-15, 200).is_none());
+
+    for shift in [SchurShift::Francis, SchurShift::Wilkinson] {
+        let options = SchurOptions::new()
+            .max_niter(200)
+            .shift(shift)
+            .exceptional_shift_period(10);
+        let (vecs, vals) = cyclic
+            .clone()
+            .try_schur_with_options(&options)
+            .unwrap()
+            .unpack();
+        assert_relative_eq!(&vecs * vals * vecs.transpose(), cyclic, epsilon = 1.0e-10);
+    }
+
+    let m = DMatrix::from_fn(12, 12, |i, j| ((i * 3 + j * 7) % 11) as f64 - 5.0);
+    let options = SchurOptions::new().shift(SchurShift::Wilkinson);
+    let (vecs, vals) = m.clone().try_schur_with_options(&options).unwrap().unpack();
+    assert_relative_eq!(&vecs * vals * vecs.transpose(), m, epsilon = 1.0e-10);
+
+    assert!(m
+        .try_schur_with_options(&SchurOptions::new().max_niter(1))
+        .is_none());
+}
+
+#[test]
+fn schur_aggressive_early_deflation() {
+    use na::{Complex, SchurOptions};
+
+    let options = SchurOptions::new().aggressive_early_deflation(true);
+
+    for n in [17, 40, 100] {
+        let m = DMatrix::from_fn(n, n, |i, j| {
+            ((i * 3 + j * 7) % 11) as f64 - 5.0 + 1.0 / (1 + i + j) as f64
+        });
+        let (vecs, vals) = m.clone().try_schur_with_options(&options).unwrap().unpack();
+        assert!(vecs.is_orthogonal(1.0e-10));
+        assert_relative_eq!(&vecs * &vals * vecs.transpose(), m, epsilon = 1.0e-9);
+
+        // `vals` is quasi-upper-triangular.
+        for j in 0..n {
+            for i in j + 2..n {
+                assert_eq!(vals[(i, j)], 0.0);
+            }
+        }
+        for i in 1..n - 1 {
+            assert!(vals[(i, i - 1)] == 0.0 || vals[(i + 1, i)] == 0.0);
+        }
+
+        let mut expected: Vec<_> = m
+            .complex_eigenvalues()
+            .iter()
+            .map(|e| (e.re, e.im.abs()))
+            .collect();
+        let schur = m.try_schur_with_options(&options).unwrap();
+        let mut computed: Vec<_> = schur
+            .complex_eigenvalues()
+            .iter()
+            .map(|e| (e.re, e.im.abs()))
+            .collect();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        computed.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (a, b) in computed.iter().zip(expected.iter()) {
+            assert_relative_eq!(a.0, b.0, epsilon = 1.0e-8);
+            assert_relative_eq!(a.1, b.1, epsilon = 1.0e-8);
+        }
+    }
+
+    let c = DMatrix::from_fn(30, 30, |i, j| {
+        Complex::new((i * j % 5) as f64, i as f64 - 2.0 * j as f64)
+    });
+    let (vecs, vals) = c.clone().try_schur_with_options(&options).unwrap().unpack();
+    assert!(vecs.is_orthogonal(1.0e-10));
+    assert_relative_eq!(&vecs * vals * vecs.adjoint(), c, epsilon = 1.0e-8);
+}
@@ -1,4 +1,4 @@
-use na::{DMatrix, Matrix6};
+use na::{DMatrix, DVector, Matrix6};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
@@ -28,6 +28,18 @@ mod proptest_tests {
                         prop_assert!(relative_eq!(m, recomp_m, epsilon = 1.0e-5));
                     }
 
+                    #[test]
+                    fn operator_norms(m in dmatrix_($scalar)) {
+                        let s = m.singular_values();
+                        let (norm_one, norm_inf) = (m.norm_one(), m.norm_inf());
+                        let spectral_norm = m.spectral_norm(1.0e-12, 10_000);
+
+                        prop_assert!(relative_eq!(m.nuclear_norm(), s.sum(), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(spectral_norm, s.max(), epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!(norm_one, m.adjoint().norm_inf(), epsilon = 1.0e-7));
+                        prop_assert!(spectral_norm <= (norm_one * norm_inf).sqrt() + 1.0e-7);
+                    }
+
                     #[test]
                     fn svd_static_5_3(m in matrix5x3_($scalar)) {
                         let svd = m.svd(true, true);
@@ -265,6 +277,25 @@ fn svd_zeros() {
     assert_eq!(Ok(m), svd.recompose());
 }
 
+#[test]
+fn spectral_norm_start_vector() {
+    // The row of largest norm is orthogonal to the dominant right singular vector `[1, 0]`.
+    let mut m = DMatrix::from_element(201, 2, 0.0);
+    m[(0, 1)] = 10.0;
+    m.rows_mut(1, 200).column_mut(0).fill(1.0);
+    assert_relative_eq!(m.spectral_norm(1.0e-12, 0), 200.0f64.sqrt(), epsilon = 1.0e-9);
+
+    // The sum of the rows is zero.
+    let m = na::Matrix2::new(1.0, 1.0, -1.0, -1.0);
+    assert_relative_eq!(m.spectral_norm(1.0e-12, 0), 2.0, epsilon = 1.0e-9);
+
+    assert_eq!(DMatrix::<f64>::zeros(3, 2).spectral_norm(1.0e-12, 0), 0.0);
+
+    // Non-finite values stop the iteration even without any limit on the number of iterations.
+    let m = DMatrix::from_row_slice(2, 2, &[1.0, f64::NAN, 0.0, 1.0]);
+    assert!(m.spectral_norm(1.0e-12, 0).is_nan());
+}
+
 #[test]
 fn svd_identity() {
     let m = DMatrix::<f64>::identity(10, 10);
@@ -344,3 +375,255 @@ fn svd_err() {
         svd.clone().pseudo_inverse(-1.0)
     );
 }
+
+#[test]
+fn svd_truncated() {
+    let m = DMatrix::from_fn(7, 5, |i, j| {
+        ((i * 5 + j * 3) % 11) as f64 - 4.0 + (i * j) as f64
+    });
+    let svd = m.clone().svd(true, true);
+    let mut singular_values: Vec<f64> = svd.singular_values.iter().copied().collect();
+    singular_values.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    for k in 1..=5 {
+        let (u, sigma, v_t) = svd.truncated(k).unwrap();
+        assert_eq!(sigma.as_slice(), &singular_values[..k]);
+
+        // Eckart–Young: the error is given by the discarded singular values.
+        let approx = u * DMatrix::from_diagonal(&sigma) * v_t;
+        let expected_err = singular_values[k..]
+            .iter()
+            .map(|s| s * s)
+            .sum::<f64>()
+            .sqrt();
+        assert_relative_eq!((&m - approx).norm(), expected_err, epsilon = 1.0e-8);
+    }
+
+    assert_eq!(svd.truncated(10).unwrap().1.len(), 5);
+    assert_eq!(
+        Err("SVD truncation: V^t has not been computed."),
+        m.svd(true, false).truncated(2)
+    );
+}
+
+#[test]
+fn incremental_svd() {
+    use na::{Complex, IncrementalSVD};
+
+    let m = DMatrix::from_fn(8, 12, |i, j| {
+        ((i * 7 + j * 3) % 5) as f64 + 0.5 * ((i + 2 * j) % 3) as f64
+    });
+    let mut svd = IncrementalSVD::new(8, 8, 1.0e-12);
+    svd.push_columns(&m.columns(0, 5));
+    svd.push_column(&DVector::zeros(8));
+    svd.push_columns(&m.columns(5, 7));
+
+    assert_eq!((svd.nrows(), svd.ncols()), (8, 13));
+    let expected = m.clone().insert_column(5, 0.0);
+    assert_relative_eq!(svd.recompose(), expected, epsilon = 1.0e-9);
+    assert_relative_eq!(
+        svd.singular_values().clone(),
+        expected
+            .clone()
+            .svd(true, true)
+            .truncated(svd.rank())
+            .unwrap()
+            .1,
+        epsilon = 1.0e-9
+    );
+    assert!(svd.u().is_orthogonal(1.0e-9));
+    assert!(svd.v_t().transpose().is_orthogonal(1.0e-9));
+
+    // With a bounded rank, only the dominant subspace is tracked.
+    let low_rank = DMatrix::from_fn(8, 30, |i, j| {
+        (i as f64 + 1.0) * (j as f64).cos() + (i as f64).sin() * (j as f64 * 0.3).sin()
+    });
+    let noise = DMatrix::from_fn(8, 30, |i, j| ((i * 13 + j * 7) % 17) as f64 * 1.0e-6);
+    let mut svd = IncrementalSVD::new(8, 2, 1.0e-12);
+    svd.push_columns(&(&low_rank + noise));
+    assert_eq!(svd.rank(), 2);
+    assert_relative_eq!(svd.recompose(), low_rank, epsilon = 1.0e-4);
+
+    let c = DMatrix::from_fn(3, 4, |i, j| Complex::new(i as f64 - 1.0, (i * j) as f64));
+    let mut svd = IncrementalSVD::new(3, 3, 1.0e-12);
+    svd.push_columns(&c);
+    assert_relative_eq!(svd.recompose(), c, epsilon = 1.0e-9);
+}
+
+#[test]
+fn singular_values_dqds() {
+    use na::{Complex, Matrix3, Matrix4x3};
+
+    fn sorted_svd_values(m: &DMatrix<f64>) -> DVector<f64> {
+        let mut values: Vec<f64> = m.clone().svd(false, false).singular_values.data.into();
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        DVector::from_vec(values)
+    }
+
+    for (nrows, ncols) in [(1, 1), (2, 2), (3, 7), (10, 4), (40, 40)] {
+        let m = DMatrix::from_fn(nrows, ncols, |i, j| {
+            ((i * 7 + j * 13) % 19) as f64 - 9.0 + (i as f64 * 0.3).sin()
+        });
+        let s = m.singular_values();
+
+        assert!(s.iter().zip(s.iter().skip(1)).all(|(a, b)| a >= b));
+        assert_relative_eq!(s, sorted_svd_values(&m), epsilon = 1.0e-10);
+    }
+
+    // Rank-deficient and clustered singular values.
+    let m = DMatrix::from_fn(6, 5, |i, j| (i + j) as f64);
+    assert_relative_eq!(
+        m.singular_values(),
+        sorted_svd_values(&m),
+        epsilon = 1.0e-10
+    );
+    assert_eq!(m.rank(1.0e-10), 2);
+
+    let m = DMatrix::<f64>::identity(7, 7) * 3.0;
+    assert_eq!(m.singular_values(), DVector::repeat(7, 3.0));
+
+    let m = Matrix4x3::<f64>::zeros();
+    assert_eq!(m.singular_values(), na::Vector3::zeros());
+
+    // A graded matrix: the smallest singular value is computed with a high relative accuracy.
+    let m = Matrix3::new(1.0, 1.0e-5, 0.0, 0.0, 1.0e-10, 1.0e-15, 0.0, 0.0, 1.0e-20);
+    let s = m.singular_values();
+    assert_relative_eq!(s.iter().product::<f64>(), 1.0e-30, max_relative = 1.0e-12);
+
+    let c = DMatrix::from_fn(5, 3, |i, j| {
+        Complex::new(i as f64 - 2.0, (i * j) as f64 + 1.0)
+    });
+    let expected = {
+        let mut values: Vec<f64> = c.clone().svd(false, false).singular_values.data.into();
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        DVector::from_vec(values)
+    };
+    assert_relative_eq!(c.singular_values(), expected, epsilon = 1.0e-10);
+}
+
+#[test]
+fn svd_progress() {
+    use std::ops::ControlFlow;
+
+    let m = DMatrix::from_fn(12, 9, |i, j| ((i * 5 + j * 7) % 13) as f64 - 6.0);
+    let mut reports = Vec::new();
+    let svd = m
+        .clone()
+        .try_svd_with(true, true, 1.0e-15, 0, |niter, off_diag_norm| {
+            reports.push((niter, off_diag_norm));
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+
+    assert!(!reports.is_empty());
+    assert!(reports.iter().enumerate().all(|(i, r)| r.0 == i + 1));
+    assert!(reports.iter().all(|r| r.1 >= 0.0));
+    assert_eq!(reports.last().unwrap().1, 0.0);
+    assert_relative_eq!(svd.recompose().unwrap(), m, epsilon = 1.0e-10);
+
+    let mut ncalls = 0;
+    let cancelled = m.try_svd_with(true, true, 1.0e-15, 0, |_, _| {
+        ncalls += 1;
+        ControlFlow::Break(())
+    });
+    assert!(cancelled.is_none());
+    assert_eq!(ncalls, 1);
+}
+
+#[test]
+fn svd_closed_form() {
+    use na::{Complex, Matrix2, Matrix3x2, SVD};
+
+    let matrices = [
+        Matrix3x2::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0),
+        Matrix3x2::new(1.0, 2.0, 2.0, 4.0, 3.0, 6.0),
+        Matrix3x2::new(0.0, 1.0, 0.0, 0.0, 0.0, 0.0),
+        Matrix3x2::new(-3.0, 0.0, 0.0, 0.0, 0.0, 4.0),
+        Matrix3x2::new(1.0e-200, 2.0e-200, -3.0e-200, 4.0e-200, 0.0, 1.0e-210),
+        Matrix3x2::new(1.0e200, 2.0e200, -3.0e200, 4.0e200, 0.0, 1.0e190),
+    ];
+
+    for m in matrices.iter() {
+        let svd = m.svd(true, true);
+        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+        let mut expected = SVD::try_new(*m, false, false, 1.0e-15, 0).unwrap().singular_values;
+        expected.as_mut_slice().sort_by(|a: &f64, b| b.partial_cmp(a).unwrap());
+
+        assert!(s.iter().all(|e| *e >= 0.0));
+        assert!(u.is_orthogonal(1.0e-14));
+        assert!(v_t.is_orthogonal(1.0e-14));
+        assert_relative_eq!(u * Matrix2::from_diagonal(&s) * v_t, m, epsilon = 1.0e-14 * m.amax());
+        assert_relative_eq!(m.singular_values(), expected, epsilon = 1.0e-14 * m.amax());
+
+        let m2 = m.fixed_rows::<2>(0).into_owned();
+        let svd = m2.svd(true, false);
+        let svd_v = m2.svd(false, true);
+        let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd_v.v_t.unwrap());
+        assert_eq!(s, svd_v.singular_values);
+        assert!(u.is_orthogonal(1.0e-14));
+        assert!(v_t.is_orthogonal(1.0e-14));
+        assert_relative_eq!(
+            u * Matrix2::from_diagonal(&s) * v_t,
+            m2,
+            epsilon = 1.0e-14 * m2.amax()
+        );
+    }
+
+    let c = Matrix3x2::new(
+        Complex::new(1.0, 2.0), Complex::new(0.0, -1.0),
+        Complex::new(-3.0, 0.5), Complex::new(2.0, 2.0),
+        Complex::new(0.0, 1.0), Complex::new(1.0, 0.0),
+    );
+    let svd = c.svd(true, true);
+    let (u, s, v_t) = (svd.u.unwrap(), svd.singular_values, svd.v_t.unwrap());
+    let ds = Matrix2::from_diagonal(&s.map(|e| Complex::new(e, 0.0)));
+    assert!(u.is_orthogonal(1.0e-14));
+    assert!(v_t.is_orthogonal(1.0e-14));
+    assert_relative_eq!(u * ds * v_t, c, epsilon = 1.0e-13);
+}
+
+#[test]
+fn decomposition_capabilities_in_generic_code() {
+    use na::{
+        ComplexField, DefaultAllocator, Dim, DimMin, DimMinimum, DimSub, Matrix2, Matrix3x2,
+        OMatrix, SVDCapable, SchurCapable, U1,
+    };
+
+    fn rank<T, R, C>(m: OMatrix<T, R, C>) -> usize
+    where
+        T: ComplexField,
+        R: DimMin<C>,
+        C: Dim,
+        DimMinimum<R, C>: DimSub<U1>,
+        DefaultAllocator: SVDCapable<T, R, C>,
+    {
+        m.rank(na::convert(1.0e-10))
+    }
+
+    fn num_real_eigenvalues<T, D>(m: OMatrix<T, D, D>) -> Option<usize>
+    where
+        T: ComplexField,
+        D: DimSub<U1>,
+        DefaultAllocator: SchurCapable<T, D>,
+    {
+        m.eigenvalues().map(|e| e.len())
+    }
+
+    assert_eq!(rank(Matrix3x2::new(1.0, 2.0, 2.0, 4.0, 3.0, 6.0)), 1);
+    assert_eq!(
+        rank(DMatrix::from_row_slice(
+            2,
+            3,
+            &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0]
+        )),
+        2
+    );
+    assert_eq!(
+        num_real_eigenvalues(Matrix2::new(2.0, 1.0, 1.0, 2.0)),
+        Some(2)
+    );
+    assert_eq!(
+        num_real_eigenvalues(DMatrix::<f64>::identity(3, 3)),
+        Some(3)
+    );
+}
@@ -38,11 +38,12 @@ mod proptest_tests {
     use crate::core::helper::{RandComplex, RandScalar};
 
     macro_rules! gen_tests(
-        ($module: ident, $scalar: expr) => {
+        ($module: ident, $scalar: expr, $scalar_type: ty) => {
             mod $module {
                 #[allow(unused_imports)]
                 use crate::core::helper::{RandScalar, RandComplex};
                 use crate::proptest::*;
+                use na::{ComplexField, DMatrix};
                 use proptest::{prop_assert, proptest};
 
                 proptest! {
@@ -51,7 +52,7 @@ mod proptest_tests {
                         let m = &m * m.adjoint();
 
                         if let Some(udu) = m.clone().udu() {
-                            let p = &udu.u * &udu.d_matrix() * &udu.u.transpose();
+                            let p = &udu.u * &udu.d_matrix() * &udu.u.adjoint();
                             println!("m: {}, p: {}", m, p);
 
                             prop_assert!(relative_eq!(m, p, epsilon = 1.0e-7));
@@ -63,14 +64,32 @@ mod proptest_tests {
                         let m = m.hermitian_part();
 
                         if let Some(udu) = m.udu() {
-                            let p = udu.u * udu.d_matrix() * udu.u.transpose();
+                            let p = udu.u * udu.d_matrix() * udu.u.adjoint();
                             prop_assert!(relative_eq!(m, p, epsilon = 1.0e-7));
                         }
                     }
+
+                    #[test]
+                    fn udu_solve(n in PROPTEST_MATRIX_DIM, nb in PROPTEST_MATRIX_DIM) {
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let b = DMatrix::<$scalar_type>::new_random(n, nb).map(|e| e.0);
+
+                        if n > 0 && m.clone().lu().try_inverse().map_or(false, |inv| inv.norm() < 1.0e4) {
+                            if let Some(udu) = m.clone().udu() {
+                                let sol = udu.solve(&b);
+                                let det = m.clone().determinant();
+
+                                prop_assert!(relative_eq!(&m * sol, b, epsilon = 1.0e-6));
+                                prop_assert!(relative_eq!(&m * udu.inverse(), DMatrix::identity(n, n), epsilon = 1.0e-6));
+                                prop_assert!(relative_eq!(det.real(), udu.determinant(), epsilon = 1.0e-6, max_relative = 1.0e-6));
+                            }
+                        }
+                    }
                 }
             }
         }
     );
 
-    gen_tests!(f64, PROPTEST_F64);
+    gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+    gen_tests!(complex, complex_f64(), RandComplex<f64>);
 }